@@ -0,0 +1,147 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// Timing of a single pipeline stage.
+#[derive(Debug, Clone, Copy)]
+pub struct StageConfig {
+    /// Simulated work done for every frame.
+    pub work: Duration,
+    /// Upper bound of the stage deadline.
+    pub deadline: Duration,
+}
+
+/// Configuration of the pipeline, loaded from a `key = value` file.
+#[derive(Debug, Clone)]
+pub struct PipelineConfig {
+    pub frame_period: Duration,
+    pub heartbeat_tolerance: Duration,
+    pub acquire: StageConfig,
+    pub process: StageConfig,
+    pub publish: StageConfig,
+    pub supervisor_api_cycle: Duration,
+    pub internal_processing_cycle: Duration,
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            frame_period: Duration::from_millis(100),
+            heartbeat_tolerance: Duration::from_millis(40),
+            acquire: StageConfig {
+                work: Duration::from_millis(10),
+                deadline: Duration::from_millis(30),
+            },
+            process: StageConfig {
+                work: Duration::from_millis(20),
+                deadline: Duration::from_millis(50),
+            },
+            publish: StageConfig {
+                work: Duration::from_millis(5),
+                deadline: Duration::from_millis(20),
+            },
+            supervisor_api_cycle: Duration::from_millis(50),
+            internal_processing_cycle: Duration::from_millis(50),
+        }
+    }
+}
+
+impl PipelineConfig {
+    /// Loads the configuration from the file. Keys not present in the file keep their default values.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        Self::parse(&content)
+    }
+
+    /// Parses the configuration from `key = value` lines. Empty lines and lines starting with `#` are ignored.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut values = HashMap::new();
+        for (line_no, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", line_no + 1))?;
+            let value: u64 = value
+                .trim()
+                .parse()
+                .map_err(|e| format!("line {}: invalid value: {e}", line_no + 1))?;
+            values.insert(key.trim().to_string(), Duration::from_millis(value));
+        }
+
+        let mut config = Self::default();
+        for (key, value) in values {
+            let field = match key.as_str() {
+                "frame_period_ms" => &mut config.frame_period,
+                "heartbeat_tolerance_ms" => &mut config.heartbeat_tolerance,
+                "acquire_work_ms" => &mut config.acquire.work,
+                "acquire_deadline_ms" => &mut config.acquire.deadline,
+                "process_work_ms" => &mut config.process.work,
+                "process_deadline_ms" => &mut config.process.deadline,
+                "publish_work_ms" => &mut config.publish.work,
+                "publish_deadline_ms" => &mut config.publish.deadline,
+                "supervisor_api_cycle_ms" => &mut config.supervisor_api_cycle,
+                "internal_processing_cycle_ms" => &mut config.internal_processing_cycle,
+                _ => return Err(format!("unknown key `{key}`")),
+            };
+            *field = value;
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.heartbeat_tolerance >= self.frame_period {
+            return Err("heartbeat_tolerance_ms must be shorter than frame_period_ms".into());
+        }
+
+        let busy = self.acquire.work + self.process.work + self.publish.work;
+        if busy >= self.frame_period {
+            return Err("sum of stage work must be shorter than frame_period_ms".into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_overrides_defaults() {
+        let config = PipelineConfig::parse("# comment\n\nframe_period_ms = 200\n process_work_ms=30 \n").unwrap();
+        assert_eq!(config.frame_period, Duration::from_millis(200));
+        assert_eq!(config.process.work, Duration::from_millis(30));
+        assert_eq!(config.acquire.work, PipelineConfig::default().acquire.work);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_input() {
+        assert!(PipelineConfig::parse("frame_period_ms").is_err());
+        assert!(PipelineConfig::parse("frame_period_ms = fast").is_err());
+        assert!(PipelineConfig::parse("unknown_ms = 10").is_err());
+        assert!(PipelineConfig::parse("frame_period_ms = 30").is_err());
+    }
+
+    #[test]
+    fn shipped_config_is_valid() {
+        PipelineConfig::parse(include_str!("../pipeline.conf")).unwrap();
+    }
+}
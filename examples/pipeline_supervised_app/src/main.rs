@@ -0,0 +1,184 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Reference application supervising a three stage pipeline (acquire -> process -> publish).
+//!
+//! - every stage runs in its own thread and processes each frame inside its own deadline,
+//!   the three deadlines form a chain covering the whole frame processing - stop of a stage deadline starts
+//!   the deadline of the next stage, so its range includes the time the frame waits for the next stage,
+//! - every stage has its own heartbeat monitor proving the thread keeps up with the frame rate,
+//! - timing is loaded from a configuration file (see `pipeline.conf`),
+//! - `SIGTERM`/`SIGINT` stop the source stage, remaining frames are drained and all threads are joined.
+//!
+//! Mode changes of the pipeline are only logged, the health monitoring library does not provide a logic monitor
+//! to supervise them.
+
+mod config;
+mod stage;
+
+use clap::Parser;
+use config::PipelineConfig;
+use health_monitoring_lib::deadline::DeadlineMonitorBuilder;
+use health_monitoring_lib::heartbeat::HeartbeatMonitorBuilder;
+use health_monitoring_lib::*;
+use score_log::{error, info, ScoreDebug};
+use signal_hook::flag;
+use stage::Stage;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+
+const DEADLINE_MONITOR: MonitorTag = MonitorTag::new("pipeline");
+const STAGES: [(&str, DeadlineTag, MonitorTag); 3] = [
+    (
+        "acquire",
+        DeadlineTag::new("acquire"),
+        MonitorTag::new("acquire_heartbeat"),
+    ),
+    (
+        "process",
+        DeadlineTag::new("process"),
+        MonitorTag::new("process_heartbeat"),
+    ),
+    (
+        "publish",
+        DeadlineTag::new("publish"),
+        MonitorTag::new("publish_heartbeat"),
+    ),
+];
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Path to the pipeline configuration file. Defaults are used when not provided.
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+}
+
+/// Operating mode of the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScoreDebug)]
+enum Mode {
+    Starting,
+    Running,
+    Draining,
+    Stopped,
+}
+
+fn switch_mode(current: &mut Mode, next: Mode) {
+    // The health monitoring library does not provide a logic monitor yet, so transitions are only logged here.
+    info!("Pipeline mode {:?} -> {:?}", *current, next);
+    *current = next;
+}
+
+fn build_health_monitor(config: &PipelineConfig) -> Result<HealthMonitor, HealthMonitorError> {
+    let stage_configs = [config.acquire, config.process, config.publish];
+
+    let mut deadline_monitor_builder = DeadlineMonitorBuilder::new();
+    for ((_, deadline_tag, _), stage_config) in STAGES.iter().zip(stage_configs) {
+        deadline_monitor_builder = deadline_monitor_builder.add_deadline(
+            *deadline_tag,
+            TimeRange::new(core::time::Duration::ZERO, stage_config.deadline),
+        );
+    }
+    for pair in STAGES.windows(2) {
+        deadline_monitor_builder = deadline_monitor_builder.chain(pair[0].1, pair[1].1);
+    }
+
+    let tolerance = TimeRange::new(config.heartbeat_tolerance, config.heartbeat_tolerance);
+    let heartbeat_range = TimeRange::from_interval(config.frame_period, tolerance);
+    let mut builder = HealthMonitorBuilder::new()
+        .add_deadline_monitor(DEADLINE_MONITOR, deadline_monitor_builder)
+        .with_supervisor_api_cycle(config.supervisor_api_cycle)
+        .with_internal_processing_cycle(config.internal_processing_cycle);
+    for (_, _, heartbeat_tag) in STAGES {
+        builder = builder.add_heartbeat_monitor(heartbeat_tag, HeartbeatMonitorBuilder::new(heartbeat_range));
+    }
+
+    builder.build()
+}
+
+fn main_logic(config: PipelineConfig, stop: Arc<AtomicBool>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut mode = Mode::Starting;
+
+    let mut hm = build_health_monitor(&config).map_err(|e| format!("Failed to build health monitor: {e:?}"))?;
+    let deadline_monitor = Arc::new(
+        hm.get_deadline_monitor(DEADLINE_MONITOR)
+            .ok_or("Failed to get deadline monitor")?,
+    );
+
+    let stage_configs = [config.acquire, config.process, config.publish];
+    let mut stages = Vec::new();
+    for ((name, deadline_tag, heartbeat_tag), stage_config) in STAGES.into_iter().zip(stage_configs) {
+        stages.push(Stage {
+            name,
+            deadline_tag,
+            config: stage_config,
+            deadline_monitor: Arc::clone(&deadline_monitor),
+            heartbeat_producer: hm
+                .get_heartbeat_monitor(heartbeat_tag)
+                .ok_or("Failed to get heartbeat monitor")?
+                .producer()
+                .map_err(|_| "Failed to get heartbeat producer")?,
+        });
+    }
+
+    hm.start()
+        .map_err(|e| format!("Failed to start health monitor: {e:?}"))?;
+
+    if !lifecycle_client_rs::report_execution_state_running() {
+        error!("Pipeline app FAILED to report execution state!");
+        return Err("Failed to report execution state".into());
+    }
+
+    let (acquired_tx, acquired_rx) = mpsc::channel();
+    let (processed_tx, processed_rx) = mpsc::channel();
+    let mut stages = stages.into_iter();
+    let (acquire, process, publish) = (stages.next().unwrap(), stages.next().unwrap(), stages.next().unwrap());
+
+    switch_mode(&mut mode, Mode::Running);
+    let period = config.frame_period;
+    let source_stop = Arc::clone(&stop);
+    let threads = [
+        std::thread::spawn(move || acquire.run_source(period, source_stop, acquired_tx)),
+        std::thread::spawn(move || process.run(acquired_rx, Some(processed_tx))),
+        std::thread::spawn(move || publish.run(processed_rx, None)),
+    ];
+
+    while !stop.load(Ordering::Relaxed) {
+        std::thread::sleep(core::time::Duration::from_millis(100));
+    }
+
+    switch_mode(&mut mode, Mode::Draining);
+    for thread in threads {
+        thread.join().map_err(|_| "Pipeline stage panicked")?;
+    }
+    switch_mode(&mut mode, Mode::Stopped);
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    stdout_logger::StdoutLoggerBuilder::new().set_as_default_logger();
+
+    let args = Args::parse();
+    let config = match &args.config {
+        Some(path) => PipelineConfig::load(path)?,
+        None => PipelineConfig::default(),
+    };
+
+    let stop = Arc::new(AtomicBool::new(false));
+    flag::register(signal_hook::consts::SIGTERM, Arc::clone(&stop))?;
+    flag::register(signal_hook::consts::SIGINT, Arc::clone(&stop))?;
+
+    main_logic(config, stop)
+}
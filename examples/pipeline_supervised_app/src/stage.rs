@@ -0,0 +1,104 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::config::StageConfig;
+use health_monitoring_lib::deadline::{Deadline, DeadlineMonitor};
+use health_monitoring_lib::heartbeat::HeartbeatProducer;
+use health_monitoring_lib::DeadlineTag;
+use score_log::{error, info};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Single frame travelling through the pipeline.
+#[derive(Debug, Clone, Copy)]
+pub struct Frame {
+    pub id: u64,
+}
+
+/// A pipeline stage running in its own thread.
+///
+/// Every frame is processed inside the stage deadline, which together with the deadlines of the other stages forms
+/// a chain covering the whole frame processing. The stage heartbeat proves the thread keeps up with the frame rate.
+pub struct Stage {
+    pub name: &'static str,
+    pub deadline_tag: DeadlineTag,
+    pub config: StageConfig,
+    pub deadline_monitor: Arc<DeadlineMonitor>,
+    pub heartbeat_producer: HeartbeatProducer,
+}
+
+impl Stage {
+    /// Produces frames with the given period until `stop` is set. Dropping `output` afterwards drains the pipeline.
+    pub fn run_source(self, period: Duration, stop: Arc<AtomicBool>, output: Sender<Frame>) {
+        let mut deadline = self.acquire_deadline();
+        let mut next_frame = Instant::now();
+        let mut id = 0;
+
+        while !stop.load(Ordering::Relaxed) {
+            next_frame += period;
+            std::thread::sleep(next_frame.saturating_duration_since(Instant::now()));
+
+            let frame = Frame { id };
+            self.process(&mut deadline, frame);
+            if output.send(frame).is_err() {
+                break;
+            }
+            id += 1;
+        }
+
+        info!("Stage {} produced {} frames", self.name, id);
+    }
+
+    /// Processes frames until the upstream stage closes the channel.
+    pub fn run(self, input: Receiver<Frame>, output: Option<Sender<Frame>>) {
+        let mut deadline = self.acquire_deadline();
+        let mut processed = 0;
+
+        for frame in input {
+            self.process(&mut deadline, frame);
+            if let Some(output) = &output {
+                if output.send(frame).is_err() {
+                    break;
+                }
+            }
+            processed += 1;
+        }
+
+        info!("Stage {} processed {} frames", self.name, processed);
+    }
+
+    fn acquire_deadline(&self) -> Deadline {
+        self.deadline_monitor
+            .get_deadline(self.deadline_tag)
+            .expect("Failed to get stage deadline")
+    }
+
+    fn process(&self, deadline: &mut Deadline, frame: Frame) {
+        match deadline.start() {
+            Ok(handle) => {
+                std::thread::sleep(self.config.work);
+                handle.stop();
+            },
+            Err(_) => {
+                error!(
+                    "Stage {} deadline already failed, frame {} not supervised",
+                    self.name, frame.id
+                );
+                std::thread::sleep(self.config.work);
+            },
+        }
+
+        self.heartbeat_producer.heartbeat();
+    }
+}
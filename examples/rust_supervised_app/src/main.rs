@@ -18,6 +18,7 @@ use libc::{c_long, nanosleep, time_t, timespec};
 use score_log::{error, info};
 use signal_hook::flag;
 use std::env;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -27,6 +28,14 @@ struct Args {
     /// The app is configured to measure deadline between 50ms and 150ms. You configure the delay inside this deadline measurement.
     #[arg(short, long)]
     delay: u32,
+
+    /// Run without the launch manager - execution state is not reported.
+    #[arg(long)]
+    standalone: bool,
+
+    /// Write the health monitor snapshot to this file every loop iteration, for `hm-inspect`.
+    #[arg(long)]
+    snapshot_file: Option<PathBuf>,
 }
 
 fn interruptible_sleep(delay: timespec) {
@@ -54,7 +63,20 @@ fn set_process_name() {
     }
 }
 
-fn main_logic(args: &Args, stop: Arc<AtomicBool>) -> Result<(), Box<dyn std::error::Error>> {
+/// Replace the snapshot file atomically, so readers never see a partially written snapshot.
+fn write_snapshot(hm: &HealthMonitor, path: &Path) -> std::io::Result<()> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    std::fs::write(&tmp_path, hm.snapshot().to_json())?;
+    std::fs::rename(&tmp_path, path)
+}
+
+fn main_logic(
+    args: &Args,
+    stop: Arc<AtomicBool>,
+    pause: Arc<AtomicBool>,
+    dump: Arc<AtomicBool>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let mut builder = deadline::DeadlineMonitorBuilder::new();
     builder = builder.add_deadline(
         DeadlineTag::from("deadline1"),
@@ -64,31 +86,70 @@ fn main_logic(args: &Args, stop: Arc<AtomicBool>) -> Result<(), Box<dyn std::err
         ),
     );
 
+    // Heartbeat is provided at the end of every loop iteration, i.e. every `delay` milliseconds.
+    // Internal processing cycle is kept well below it so two heartbeats never fall into the same cycle.
+    let heartbeat_range = TimeRange::new(
+        std::time::Duration::from_millis(30),
+        std::time::Duration::from_millis(u64::from(args.delay) + 100),
+    );
+
     let mut hm = HealthMonitorBuilder::new()
         .add_deadline_monitor(MonitorTag::from("mon1"), builder)
+        .add_heartbeat_monitor(
+            MonitorTag::from("hb1"),
+            heartbeat::HeartbeatMonitorBuilder::new(heartbeat_range),
+        )
         .with_supervisor_api_cycle(std::time::Duration::from_millis(50))
-        .with_internal_processing_cycle(std::time::Duration::from_millis(50))
+        .with_internal_processing_cycle(std::time::Duration::from_millis(10))
         .build()
         .expect("Failed to build health monitor");
 
     let mon = hm
         .get_deadline_monitor(MonitorTag::from("mon1"))
         .expect("Failed to get monitor");
+    let heartbeat_producer = hm
+        .get_heartbeat_monitor(MonitorTag::from("hb1"))
+        .expect("Failed to get heartbeat monitor")
+        .producer()
+        .expect("Failed to get heartbeat producer");
 
     hm.start().expect("Failed to start health monitor");
 
-    if !lifecycle_client_rs::report_execution_state_running() {
+    if !args.standalone && !lifecycle_client_rs::report_execution_state_running() {
         error!("Rust app FAILED to report execution state!");
         return Err("Failed to report execution state".into());
     }
 
+    let mut paused = false;
     while !stop.load(Ordering::Relaxed) {
+        // SIGUSR2 dumps the live supervision state.
+        if dump.swap(false, Ordering::Relaxed) {
+            info!("Health monitor snapshot: {}", hm.snapshot().to_json().as_str());
+        }
+        if let Some(path) = &args.snapshot_file {
+            if let Err(e) = write_snapshot(&hm, path) {
+                error!("Failed to write health monitor snapshot: {}", e.to_string().as_str());
+            }
+        }
+
+        // SIGUSR1 stops all checkpoints to simulate a hanging application.
+        if pause.load(Ordering::Relaxed) {
+            if !paused {
+                info!("Checkpoints stopped");
+                paused = true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(args.delay.into()));
+            continue;
+        }
+
         let mut deadline = mon
             .get_deadline(DeadlineTag::from("deadline1"))
             .expect("Failed to get deadline");
 
         let _res = deadline.start();
         std::thread::sleep(std::time::Duration::from_millis(args.delay.into()));
+
+        heartbeat_producer.heartbeat();
     }
 
     Ok(())
@@ -103,6 +164,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
     let stop = Arc::new(AtomicBool::new(false));
     flag::register(signal_hook::consts::SIGTERM, Arc::clone(&stop))?;
+    let pause = Arc::new(AtomicBool::new(false));
+    flag::register(signal_hook::consts::SIGUSR1, Arc::clone(&pause))?;
+    let dump = Arc::new(AtomicBool::new(false));
+    flag::register(signal_hook::consts::SIGUSR2, Arc::clone(&dump))?;
 
-    main_logic(&args, stop.clone())
+    main_logic(&args, stop.clone(), pause, dump)
 }
@@ -0,0 +1,118 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Multi-process liveness test.
+//!
+//! Launches `rust_supervised_app` as a child process, stops its checkpoints with `SIGUSR1` and checks the stub
+//! supervisor stops receiving alive notifications within the configured heartbeat window.
+//!
+//! The application must be built with the stub supervisor API client, which appends a line to the file given by
+//! `HM_STUB_SUPERVISOR_ALIVE_FILE` on every alive notification. The test is ignored by default:
+//!
+//! ```text
+//! cargo test -p rust_supervised_app --features stub_supervisor_api_client --test liveness -- --ignored
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Application loop period, heartbeat is provided once per period.
+const DELAY_MS: u64 = 100;
+/// Longest accepted interval between heartbeats, as configured by the application.
+const HEARTBEAT_MAX_MS: u64 = DELAY_MS + 100;
+/// Internal processing cycle of the application health monitor.
+const PROCESSING_CYCLE_MS: u64 = 10;
+/// Allowed scheduling latency on top of the configured timing.
+const MARGIN_MS: u64 = 200;
+
+/// Kills the child process when dropped, also when an assertion fails.
+struct App(Child);
+
+impl Drop for App {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn alive_file() -> PathBuf {
+    std::env::temp_dir().join(format!("hm_liveness_{}.alive", std::process::id()))
+}
+
+fn alive_count(path: &Path) -> usize {
+    std::fs::read_to_string(path).map_or(0, |content| content.lines().count())
+}
+
+fn send_signal(app: &App, signal: libc::c_int) {
+    // SAFETY: `kill` has no memory safety preconditions, PID belongs to the running child.
+    let result = unsafe { libc::kill(app.0.id() as libc::pid_t, signal) };
+    assert_eq!(result, 0, "failed to send signal {signal}");
+}
+
+/// Waits until `condition` is met or `timeout` elapses.
+fn wait_for(timeout: Duration, mut condition: impl FnMut() -> bool) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < timeout {
+        if condition() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    condition()
+}
+
+#[test]
+#[ignore = "spawns the example application, run explicitly"]
+fn liveness_lost_after_checkpoints_stop() {
+    let path = alive_file();
+    let _ = std::fs::remove_file(&path);
+
+    let mut app = App(Command::new(env!("CARGO_BIN_EXE_rust_supervised_app"))
+        .args(["--delay", &DELAY_MS.to_string(), "--standalone"])
+        .env("HM_STUB_SUPERVISOR_ALIVE_FILE", &path)
+        .stdout(Stdio::null())
+        .spawn()
+        .expect("failed to spawn rust_supervised_app"));
+
+    // Application is alive while checkpoints are reported.
+    assert!(
+        wait_for(Duration::from_secs(5), || alive_count(&path) >= 5),
+        "no alive notifications observed"
+    );
+
+    send_signal(&app, libc::SIGUSR1);
+    let stopped_at = Instant::now();
+
+    // Loss of liveness - notifications stop within the window.
+    // Checkpoints may still be reported for one more loop iteration after the signal.
+    let window = Duration::from_millis(DELAY_MS + HEARTBEAT_MAX_MS + PROCESSING_CYCLE_MS + MARGIN_MS);
+    std::thread::sleep(window);
+    let count_after_window = alive_count(&path);
+    std::thread::sleep(Duration::from_millis(500));
+    assert_eq!(
+        alive_count(&path),
+        count_after_window,
+        "alive notifications continued {:?} after checkpoints stopped",
+        stopped_at.elapsed()
+    );
+
+    // Loss of liveness is detected while the process is still running.
+    assert!(app.0.try_wait().expect("failed to query child").is_none());
+
+    send_signal(&app, libc::SIGTERM);
+    let status = app.0.wait().expect("failed to wait for child");
+    assert!(status.success(), "application exited with {status}");
+
+    let _ = std::fs::remove_file(&path);
+}
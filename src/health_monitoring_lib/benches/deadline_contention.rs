@@ -0,0 +1,96 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Concurrent start/stop benchmark for deadlines of a single monitor.
+//!
+//! Every thread owns a different deadline, so the only shared data are the neighbouring deadline states.
+//! Compare the results with and without padding:
+//!
+//! ```text
+//! cargo bench -p health_monitoring_lib --bench deadline_contention
+//! cargo bench -p health_monitoring_lib --bench deadline_contention --features cache_padded_deadline_state
+//! ```
+
+use core::time::Duration;
+use health_monitoring_lib::deadline::{DeadlineMonitor, DeadlineMonitorBuilder};
+use health_monitoring_lib::{DeadlineTag, HealthMonitorBuilder, MonitorTag, TimeRange};
+use std::sync::{Arc, Barrier};
+use std::time::Instant;
+
+const THREADS: usize = 8;
+const ITERATIONS: usize = 2_000_000;
+const RUNS: usize = 5;
+
+fn deadline_tag(index: usize) -> DeadlineTag {
+    DeadlineTag::from(format!("deadline_{index}"))
+}
+
+fn create_monitor() -> DeadlineMonitor {
+    let range = TimeRange::new(Duration::ZERO, Duration::from_secs(3600));
+    let deadline_monitor_builder = (0..THREADS).fold(DeadlineMonitorBuilder::new(), |builder, i| {
+        builder.add_deadline(deadline_tag(i), range)
+    });
+
+    let monitor_tag = MonitorTag::from("bench_monitor");
+    let mut health_monitor = HealthMonitorBuilder::new()
+        .add_deadline_monitor(monitor_tag, deadline_monitor_builder)
+        .build()
+        .expect("failed to build health monitor");
+    health_monitor
+        .get_deadline_monitor(monitor_tag)
+        .expect("deadline monitor not available")
+}
+
+/// Runs a single measurement and returns the time all threads needed to finish.
+fn run_once(monitor: &Arc<DeadlineMonitor>) -> Duration {
+    let barrier = Arc::new(Barrier::new(THREADS + 1));
+    let workers: Vec<_> = (0..THREADS)
+        .map(|i| {
+            let monitor = Arc::clone(monitor);
+            let barrier = Arc::clone(&barrier);
+            std::thread::spawn(move || {
+                let mut deadline = monitor.get_deadline(deadline_tag(i)).expect("deadline not available");
+                barrier.wait();
+                for _ in 0..ITERATIONS {
+                    let handle = deadline.start().expect("deadline failed");
+                    handle.stop();
+                }
+            })
+        })
+        .collect();
+
+    barrier.wait();
+    let start = Instant::now();
+    for worker in workers {
+        worker.join().expect("worker panicked");
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let padded = cfg!(feature = "cache_padded_deadline_state");
+    let monitor = Arc::new(create_monitor());
+
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        best = best.min(run_once(&monitor));
+    }
+
+    let operations = (THREADS * ITERATIONS) as f64;
+    println!(
+        "deadline_contention (padded: {padded}): {THREADS} threads x {ITERATIONS} start/stop, best of {RUNS}: \
+         {best:?} ({:.1} ns/op, {:.2} Mops/s)",
+        best.as_nanos() as f64 / operations * THREADS as f64,
+        operations / best.as_secs_f64() / 1e6,
+    );
+}
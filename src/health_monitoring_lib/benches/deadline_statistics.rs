@@ -0,0 +1,87 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Split of deadline statistics cost between the hot path and the aggregation.
+//!
+//! Stopping a deadline only records the measured duration, aggregation into the histogram is done
+//! once per worker cycle or when statistics are requested. Both are measured separately:
+//!
+//! ```text
+//! cargo bench -p health_monitoring_lib --bench deadline_statistics
+//! ```
+
+use core::time::Duration;
+use health_monitoring_lib::deadline::{DeadlineMonitor, DeadlineMonitorBuilder};
+use health_monitoring_lib::{DeadlineTag, HealthMonitorBuilder, MonitorTag, TimeRange};
+use std::time::Instant;
+
+/// Activations between aggregations, fits into the pending tier so no duration is overwritten.
+const BATCH: usize = 512;
+const BATCHES: usize = 4_000;
+const RUNS: usize = 5;
+
+fn create_monitor(deadline_tag: DeadlineTag) -> DeadlineMonitor {
+    let range = TimeRange::new(Duration::ZERO, Duration::from_secs(3600));
+    let monitor_tag = MonitorTag::from("bench_monitor");
+    let mut health_monitor = HealthMonitorBuilder::new()
+        .add_deadline_monitor(
+            monitor_tag,
+            DeadlineMonitorBuilder::new().add_deadline(deadline_tag, range),
+        )
+        .build()
+        .expect("failed to build health monitor");
+    health_monitor
+        .get_deadline_monitor(monitor_tag)
+        .expect("deadline monitor not available")
+}
+
+/// Runs a single measurement and returns time spent in start/stop and in aggregation.
+fn run_once(monitor: &DeadlineMonitor, deadline_tag: DeadlineTag) -> (Duration, Duration) {
+    let mut deadline = monitor.get_deadline(deadline_tag).expect("deadline not available");
+    let mut hot_path = Duration::ZERO;
+    let mut aggregation = Duration::ZERO;
+    for _ in 0..BATCHES {
+        let start = Instant::now();
+        for _ in 0..BATCH {
+            let handle = deadline.start().expect("deadline failed");
+            handle.stop();
+        }
+        hot_path += start.elapsed();
+
+        let start = Instant::now();
+        let statistics = monitor.stats(deadline_tag).expect("deadline not registered");
+        aggregation += start.elapsed();
+        assert!(statistics.count > 0);
+    }
+    (hot_path, aggregation)
+}
+
+fn main() {
+    let deadline_tag = DeadlineTag::from("deadline");
+    let monitor = create_monitor(deadline_tag);
+
+    let mut best = (Duration::MAX, Duration::MAX);
+    for _ in 0..RUNS {
+        let (hot_path, aggregation) = run_once(&monitor, deadline_tag);
+        best = (best.0.min(hot_path), best.1.min(aggregation));
+    }
+
+    let activations = (BATCH * BATCHES) as f64;
+    println!(
+        "deadline_statistics: {BATCHES} batches x {BATCH} start/stop, best of {RUNS}: \
+         start/stop {:.1} ns/op, aggregation {:.1} ns/activation ({:.1} us/batch)",
+        best.0.as_nanos() as f64 / activations,
+        best.1.as_nanos() as f64 / activations,
+        best.1.as_nanos() as f64 / BATCHES as f64 / 1e3,
+    );
+}
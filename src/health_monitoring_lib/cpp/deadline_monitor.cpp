@@ -12,6 +12,8 @@
  ********************************************************************************/
 #include "score/hm/deadline/deadline_monitor.h"
 
+#include <algorithm>
+
 namespace
 {
 extern "C" {
@@ -27,12 +29,29 @@ FFICode deadline_monitor_builder_add_deadline(FFIHandle deadline_monitor_builder
                                               const DeadlineTag* deadline_tag,
                                               uint32_t min_ms,
                                               uint32_t max_ms);
+FFICode deadline_monitor_builder_add_deadline_concurrent(FFIHandle deadline_monitor_builder_handle,
+                                                         const DeadlineTag* deadline_tag,
+                                                         uint32_t min_ms,
+                                                         uint32_t max_ms,
+                                                         uint32_t max_instances);
+FFICode deadline_monitor_builder_with_thread_attribution(FFIHandle deadline_monitor_builder_handle);
+FFICode deadline_monitor_builder_with_violation_backtrace(FFIHandle deadline_monitor_builder_handle,
+                                                          uint32_t max_bytes);
+FFICode deadline_monitor_builder_with_scheduling_statistics(FFIHandle deadline_monitor_builder_handle);
+FFICode deadline_monitor_builder_with_max_extensions(FFIHandle deadline_monitor_builder_handle,
+                                                     uint32_t max_extensions);
 FFICode deadline_monitor_get_deadline(FFIHandle deadline_monitor_handle,
                                       const DeadlineTag* deadline_tag,
                                       FFIHandle* deadline_handle_out);
+FFICode deadline_monitor_get_deadline_holder(FFIHandle deadline_monitor_handle,
+                                             const DeadlineTag* deadline_tag,
+                                             uint8_t* deadline_holder_out);
 FFICode deadline_monitor_destroy(FFIHandle deadline_monitor_handle);
 FFICode deadline_destroy(FFIHandle deadline_handle);
 FFICode deadline_start(FFIHandle deadline_handle);
+FFICode deadline_start_with_id(FFIHandle deadline_handle, uint64_t correlation_id);
+FFICode deadline_start_at(FFIHandle deadline_handle, uint64_t timestamp_ns, uint64_t now_ns);
+FFICode deadline_extend(FFIHandle deadline_handle, uint32_t extension_ms);
 FFICode deadline_stop(FFIHandle deadline_handle);
 }
 
@@ -66,6 +85,64 @@ DeadlineMonitorBuilder DeadlineMonitorBuilder::add_deadline(const DeadlineTag& d
     return std::move(*this);
 }
 
+DeadlineMonitorBuilder DeadlineMonitorBuilder::add_deadline_concurrent(const DeadlineTag& deadline_tag,
+                                                                       const TimeRange& range,
+                                                                       uint32_t max_instances) &&
+{
+    auto handle = monitor_builder_handler_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result{deadline_monitor_builder_add_deadline_concurrent(
+        handle.value(), &deadline_tag, range.min_ms(), range.max_ms(), max_instances)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+DeadlineMonitorBuilder DeadlineMonitorBuilder::with_thread_attribution() &&
+{
+    auto handle = monitor_builder_handler_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result{deadline_monitor_builder_with_thread_attribution(handle.value())};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+DeadlineMonitorBuilder DeadlineMonitorBuilder::with_violation_backtrace(uint32_t max_bytes) &&
+{
+    auto handle = monitor_builder_handler_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result{deadline_monitor_builder_with_violation_backtrace(handle.value(), max_bytes)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+DeadlineMonitorBuilder DeadlineMonitorBuilder::with_scheduling_statistics() &&
+{
+    auto handle = monitor_builder_handler_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result{deadline_monitor_builder_with_scheduling_statistics(handle.value())};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+DeadlineMonitorBuilder DeadlineMonitorBuilder::with_max_extensions(uint32_t max_extensions) &&
+{
+    auto handle = monitor_builder_handler_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result{deadline_monitor_builder_with_max_extensions(handle.value(), max_extensions)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
 DeadlineMonitor::DeadlineMonitor(FFIHandle handle) : monitor_handle_(handle, &deadline_monitor_destroy) {}
 
 score::cpp::expected<Deadline, score::hm::Error> DeadlineMonitor::get_deadline(const DeadlineTag& deadline_tag)
@@ -83,6 +160,22 @@ score::cpp::expected<Deadline, score::hm::Error> DeadlineMonitor::get_deadline(c
     return score::cpp::expected<Deadline, score::hm::Error>(Deadline{ret});
 }
 
+score::cpp::expected<DeadlineHolder, score::hm::Error> DeadlineMonitor::get_deadline_holder(
+    const DeadlineTag& deadline_tag)
+{
+    auto handle = monitor_handle_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    uint8_t holder{0};
+    auto result = deadline_monitor_get_deadline_holder(handle.value(), &deadline_tag, &holder);
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return static_cast<DeadlineHolder>(holder);
+}
+
 Deadline::Deadline(FFIHandle handle) : deadline_handle_(handle, &deadline_destroy), has_handle_(false) {}
 
 Deadline::~Deadline()
@@ -111,6 +204,49 @@ score::cpp::expected<DeadlineHandle, score::hm::Error> Deadline::start()
     return score::cpp::expected<DeadlineHandle, score::hm::Error>(DeadlineHandle{*this});
 }
 
+score::cpp::expected<DeadlineHandle, score::hm::Error> Deadline::start_with_id(std::uint64_t correlation_id)
+{
+    // Cannot start a deadline that is already started
+    if (has_handle_)
+    {
+        return score::cpp::unexpected(::score::hm::Error::WrongState);
+    }
+
+    auto handle = deadline_handle_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result = deadline_start_with_id(handle.value(), correlation_id);
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    has_handle_ = true;
+    return score::cpp::expected<DeadlineHandle, score::hm::Error>(DeadlineHandle{*this});
+}
+
+score::cpp::expected<DeadlineHandle, score::hm::Error> Deadline::start_at(std::uint64_t timestamp_ns,
+                                                                         std::uint64_t now_ns)
+{
+    // Cannot start a deadline that is already started
+    if (has_handle_)
+    {
+        return score::cpp::unexpected(::score::hm::Error::WrongState);
+    }
+
+    auto handle = deadline_handle_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result = deadline_start_at(handle.value(), timestamp_ns, now_ns);
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    has_handle_ = true;
+    return score::cpp::expected<DeadlineHandle, score::hm::Error>(DeadlineHandle{*this});
+}
+
 DeadlineHandle::DeadlineHandle(Deadline& deadline) : was_stopped_(false), deadline_(deadline) {}
 
 void DeadlineHandle::stop()
@@ -128,6 +264,26 @@ void DeadlineHandle::stop()
     }
 }
 
+score::cpp::expected_blank<Error> DeadlineHandle::extend(std::chrono::milliseconds extension)
+{
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(deadline_.has_value());
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(!was_stopped_);
+
+    auto handle = deadline_.value().get().deadline_handle_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    // Extension is clamped to the range of the FFI parameter.
+    const auto extension_ms{
+        static_cast<uint32_t>(std::clamp<std::chrono::milliseconds::rep>(extension.count(), 0, UINT32_MAX))};
+    auto result{deadline_extend(handle.value(), extension_ms)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
 DeadlineHandle::DeadlineHandle(DeadlineHandle&& other)
     : was_stopped_(other.was_stopped_), deadline_(std::move(other.deadline_))
 {
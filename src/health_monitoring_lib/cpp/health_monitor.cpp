@@ -14,6 +14,23 @@
 
 namespace
 {
+
+// Must match `crate::ffi::FFIMonitorStatistics`.
+struct FFIMonitorStatistics
+{
+    uint64_t too_early;
+    uint64_t too_late;
+    uint64_t multiple_heartbeats;
+    uint64_t invalid_transitions;
+    uint64_t stale_artifacts;
+    uint64_t unavailable_artifacts;
+    uint64_t exhausted_budgets;
+    uint64_t idle_periods;
+    uint64_t max_offset_ms;
+    bool has_failed;
+    uint64_t last_failure_ms;
+};
+
 extern "C" {
 using namespace score::hm;
 using namespace score::hm::internal;
@@ -40,7 +57,21 @@ FFICode health_monitor_get_deadline_monitor(FFIHandle health_monitor_handle,
 FFICode health_monitor_get_heartbeat_monitor(FFIHandle health_monitor_handle,
                                              const MonitorTag* monitor_tag,
                                              FFIHandle* heartbeat_monitor_handle_out);
+FFICode health_monitor_set_monitor_enabled(FFIHandle health_monitor_handle,
+                                           const MonitorTag* monitor_tag,
+                                           bool enabled);
+FFICode health_monitor_get_monitor_statistics(FFIHandle health_monitor_handle,
+                                              const MonitorTag* monitor_tag,
+                                              FFIMonitorStatistics* statistics_out);
+FFICode health_monitor_get_snapshot(FFIHandle health_monitor_handle,
+                                    uint8_t* buffer,
+                                    size_t buffer_size,
+                                    size_t* snapshot_size_out);
+const char* health_monitor_library_version();
+FFICode health_monitor_enabled_features(uint8_t* buffer, size_t buffer_size, size_t* features_size_out);
 FFICode health_monitor_start(FFIHandle health_monitor_handle);
+FFICode health_monitor_stop(FFIHandle health_monitor_handle);
+FFICode health_monitor_restart(FFIHandle health_monitor_handle);
 FFICode health_monitor_destroy(FFIHandle health_monitor_handle);
 }
 
@@ -92,13 +123,13 @@ HealthMonitorBuilder HealthMonitorBuilder::add_heartbeat_monitor(const MonitorTa
     return std::move(*this);
 }
 
-HealthMonitorBuilder HealthMonitorBuilder::with_internal_processing_cycle(std::chrono::milliseconds cycle_duration) &&
+HealthMonitorBuilder HealthMonitorBuilder::with_internal_processing_cycle(std::chrono::microseconds cycle_duration) &&
 {
     internal_processing_cycle_duration_ = cycle_duration;
     return std::move(*this);
 }
 
-HealthMonitorBuilder HealthMonitorBuilder::with_supervisor_api_cycle(std::chrono::milliseconds cycle_duration) &&
+HealthMonitorBuilder HealthMonitorBuilder::with_supervisor_api_cycle(std::chrono::microseconds cycle_duration) &&
 {
     supervisor_api_cycle_duration_ = cycle_duration;
     return std::move(*this);
@@ -109,8 +140,8 @@ HealthMonitor HealthMonitorBuilder::build() &&
     auto health_monitor_builder_handle = health_monitor_builder_handle_.drop_by_rust();
     SCORE_LANGUAGE_FUTURECPP_PRECONDITION(health_monitor_builder_handle.has_value());
 
-    uint32_t supervisor_duration_ms = static_cast<uint32_t>(supervisor_api_cycle_duration_.count());
-    uint32_t internal_duration_ms = static_cast<uint32_t>(internal_processing_cycle_duration_.count());
+    uint32_t supervisor_duration_ms = to_ffi_ms_ceil(supervisor_api_cycle_duration_);
+    uint32_t internal_duration_ms = to_ffi_ms_ceil(internal_processing_cycle_duration_);
 
     FFIHandle health_monitor_handle{nullptr};
     auto result{health_monitor_builder_build(
@@ -161,6 +192,99 @@ void HealthMonitor::start()
     SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
 }
 
+score::cpp::expected_blank<Error> HealthMonitor::stop()
+{
+    auto result{health_monitor_stop(health_monitor_)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected_blank<Error> HealthMonitor::restart()
+{
+    auto result{health_monitor_restart(health_monitor_)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected_blank<Error> HealthMonitor::disable_monitor(const MonitorTag& monitor_tag)
+{
+    auto result{health_monitor_set_monitor_enabled(health_monitor_, &monitor_tag, false)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected_blank<Error> HealthMonitor::enable_monitor(const MonitorTag& monitor_tag)
+{
+    auto result{health_monitor_set_monitor_enabled(health_monitor_, &monitor_tag, true)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected<MonitorStatistics, Error> HealthMonitor::statistics(const MonitorTag& monitor_tag) const
+{
+    FFIMonitorStatistics ffi_statistics{};
+    auto result{health_monitor_get_monitor_statistics(health_monitor_, &monitor_tag, &ffi_statistics)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    MonitorStatistics statistics{};
+    statistics.too_early = ffi_statistics.too_early;
+    statistics.too_late = ffi_statistics.too_late;
+    statistics.multiple_heartbeats = ffi_statistics.multiple_heartbeats;
+    statistics.invalid_transitions = ffi_statistics.invalid_transitions;
+    statistics.stale_artifacts = ffi_statistics.stale_artifacts;
+    statistics.unavailable_artifacts = ffi_statistics.unavailable_artifacts;
+    statistics.exhausted_budgets = ffi_statistics.exhausted_budgets;
+    statistics.idle_periods = ffi_statistics.idle_periods;
+    statistics.max_offset = std::chrono::milliseconds{ffi_statistics.max_offset_ms};
+    if (ffi_statistics.has_failed)
+    {
+        statistics.last_failure = std::chrono::milliseconds{ffi_statistics.last_failure_ms};
+    }
+    return score::cpp::expected<MonitorStatistics, Error>(statistics);
+}
+
+std::string HealthMonitor::snapshot() const
+{
+    size_t snapshot_size{0};
+    auto result{health_monitor_get_snapshot(health_monitor_, nullptr, 0, &snapshot_size)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess || static_cast<Error>(result) == Error::InvalidArgument);
+
+    // Snapshot can grow between the calls, e.g. by recorded violations, so retry until it fits.
+    std::string snapshot(snapshot_size, '\0');
+    while (true)
+    {
+        result = health_monitor_get_snapshot(
+            health_monitor_, reinterpret_cast<uint8_t*>(snapshot.data()), snapshot.size(), &snapshot_size);
+        if (static_cast<Error>(result) != Error::InvalidArgument)
+        {
+            break;
+        }
+        snapshot.resize(snapshot_size);
+    }
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+    snapshot.resize(snapshot_size);
+    return snapshot;
+}
+
 HealthMonitor::~HealthMonitor()
 {
     if (health_monitor_ != nullptr)
@@ -183,4 +307,25 @@ HealthMonitor& HealthMonitor::operator=(HealthMonitor&& other)
     return *this;
 }
 
+std::string_view library_version()
+{
+    return std::string_view{health_monitor_library_version()};
+}
+
+std::string enabled_features()
+{
+    size_t features_size{0};
+    auto result{health_monitor_enabled_features(nullptr, 0, &features_size)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess || static_cast<Error>(result) == Error::InvalidArgument);
+
+    std::string features(features_size, '\0');
+    if (features_size > 0)
+    {
+        result = health_monitor_enabled_features(
+            reinterpret_cast<uint8_t*>(features.data()), features.size(), &features_size);
+        SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+    }
+    return features;
+}
+
 }  // namespace score::hm
@@ -12,6 +12,8 @@
  ********************************************************************************/
 #include "score/hm/heartbeat/heartbeat_monitor.h"
 
+#include <algorithm>
+
 namespace {
 extern "C" {
 using namespace score::hm;
@@ -20,8 +22,21 @@ using namespace score::hm::heartbeat;
 
 FFICode heartbeat_monitor_builder_create(uint32_t range_min_ms, uint32_t range_max_ms, FFIHandle* heartbeat_monitor_builder_handle_out);
 FFICode heartbeat_monitor_builder_destroy(FFIHandle heartbeat_monitor_builder_handle);
+FFICode heartbeat_monitor_builder_with_max_update_attempts(FFIHandle heartbeat_monitor_builder_handle, uint32_t max_attempts);
+FFICode heartbeat_monitor_builder_with_thread_attribution(FFIHandle heartbeat_monitor_builder_handle);
+FFICode heartbeat_monitor_builder_with_allowed_misses(FFIHandle heartbeat_monitor_builder_handle, uint32_t allowed_misses);
+FFICode heartbeat_monitor_builder_with_expected_count(FFIHandle heartbeat_monitor_builder_handle,
+                                                      uint32_t min_count,
+                                                      uint32_t max_count,
+                                                      uint32_t per_ms);
+FFICode heartbeat_monitor_builder_with_first_beat_deadline(FFIHandle heartbeat_monitor_builder_handle, uint32_t deadline_ms);
+FFICode heartbeat_monitor_builder_add_source(FFIHandle heartbeat_monitor_builder_handle, const SourceTag* source_tag);
 FFICode heartbeat_monitor_destroy(FFIHandle heartbeat_monitor_builder_handle);
 FFICode heartbeat_monitor_heartbeat(FFIHandle heartbeat_monitor_builder_handle);
+FFICode heartbeat_monitor_update_range(FFIHandle heartbeat_monitor_handle, uint32_t range_min_ms, uint32_t range_max_ms);
+FFICode heartbeat_monitor_suspend(FFIHandle heartbeat_monitor_handle);
+FFICode heartbeat_monitor_resume(FFIHandle heartbeat_monitor_handle);
+FFICode heartbeat_monitor_source_heartbeat(FFIHandle heartbeat_monitor_handle, const SourceTag* source_tag);
 }
 
 FFIHandle heartbeat_monitor_builder_create_wrapper(uint32_t range_min_ms, uint32_t range_max_ms)
@@ -42,6 +57,65 @@ HeartbeatMonitorBuilder::HeartbeatMonitorBuilder(const TimeRange& range)
 {
 }
 
+HeartbeatMonitorBuilder HeartbeatMonitorBuilder::with_max_update_attempts(uint32_t max_attempts) &&
+{
+    auto handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_builder_with_max_update_attempts(handle.value(), max_attempts) ==
+                                    kSuccess);
+    return std::move(*this);
+}
+
+HeartbeatMonitorBuilder HeartbeatMonitorBuilder::with_thread_attribution() &&
+{
+    auto handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_builder_with_thread_attribution(handle.value()) == kSuccess);
+    return std::move(*this);
+}
+
+HeartbeatMonitorBuilder HeartbeatMonitorBuilder::with_allowed_misses(uint32_t allowed_misses) &&
+{
+    auto handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_builder_with_allowed_misses(handle.value(), allowed_misses) ==
+                                    kSuccess);
+    return std::move(*this);
+}
+
+HeartbeatMonitorBuilder HeartbeatMonitorBuilder::with_expected_count(uint32_t min_count,
+                                                                     uint32_t max_count,
+                                                                     std::chrono::milliseconds per) &&
+{
+    auto handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+    // Window is clamped to the range of the FFI parameter.
+    const auto per_ms{static_cast<uint32_t>(std::clamp<std::chrono::milliseconds::rep>(per.count(), 0, UINT32_MAX))};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(
+        heartbeat_monitor_builder_with_expected_count(handle.value(), min_count, max_count, per_ms) == kSuccess);
+    return std::move(*this);
+}
+
+HeartbeatMonitorBuilder HeartbeatMonitorBuilder::with_first_beat_deadline(std::chrono::milliseconds deadline) &&
+{
+    auto handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+    // Deadline is clamped to the range of the FFI parameter.
+    const auto deadline_ms{
+        static_cast<uint32_t>(std::clamp<std::chrono::milliseconds::rep>(deadline.count(), 0, UINT32_MAX))};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_builder_with_first_beat_deadline(handle.value(), deadline_ms) ==
+                                    kSuccess);
+    return std::move(*this);
+}
+
+HeartbeatMonitorBuilder HeartbeatMonitorBuilder::add_source(const SourceTag& source_tag) &&
+{
+    auto handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_builder_add_source(handle.value(), &source_tag) == kSuccess);
+    return std::move(*this);
+}
+
 HeartbeatMonitor::HeartbeatMonitor(FFIHandle monitor_handle)
     : monitor_handle_{monitor_handle, &heartbeat_monitor_destroy}
 {
@@ -54,4 +128,44 @@ void HeartbeatMonitor::heartbeat()
     SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_heartbeat(monitor_handle.value()) == kSuccess);
 }
 
+score::cpp::expected_blank<Error> HeartbeatMonitor::heartbeat_from(const SourceTag& source_tag)
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+    auto result{heartbeat_monitor_source_heartbeat(monitor_handle.value(), &source_tag)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected_blank<Error> HeartbeatMonitor::update_range(const TimeRange& range)
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+    auto result{heartbeat_monitor_update_range(monitor_handle.value(), range.min_ms(), range.max_ms())};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+void HeartbeatMonitor::suspend()
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_suspend(monitor_handle.value()) == kSuccess);
+}
+
+void HeartbeatMonitor::resume()
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(heartbeat_monitor_resume(monitor_handle.value()) == kSuccess);
+}
+
 }  // namespace score::hm::heartbeat
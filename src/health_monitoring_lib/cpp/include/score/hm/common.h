@@ -15,6 +15,8 @@
 
 #include <cassert>
 #include <chrono>
+#include <cstdint>
+#include <limits>
 #include <optional>
 
 namespace score::hm
@@ -48,6 +50,24 @@ class RustDroppable
     }
 };
 
+/// Convert a duration into milliseconds expected by `*_ms` FFI parameters, rounding down.
+/// Accepts any duration implicitly convertible to microseconds (e.g. `std::chrono::milliseconds`, `seconds`).
+inline uint32_t to_ffi_ms_floor(std::chrono::microseconds duration)
+{
+    const auto duration_ms{std::chrono::floor<std::chrono::milliseconds>(duration)};
+    assert(duration_ms.count() >= 0 && duration_ms.count() <= std::numeric_limits<uint32_t>::max());
+    return static_cast<uint32_t>(duration_ms.count());
+}
+
+/// Convert a duration into milliseconds expected by `*_ms` FFI parameters, rounding up.
+/// Accepts any duration implicitly convertible to microseconds (e.g. `std::chrono::milliseconds`, `seconds`).
+inline uint32_t to_ffi_ms_ceil(std::chrono::microseconds duration)
+{
+    const auto duration_ms{std::chrono::ceil<std::chrono::milliseconds>(duration)};
+    assert(duration_ms.count() >= 0 && duration_ms.count() <= std::numeric_limits<uint32_t>::max());
+    return static_cast<uint32_t>(duration_ms.count());
+}
+
 /// Wrapper for FFIHandle that ensures proper dropping via provided drop function
 class DroppableFFIHandle
 {
@@ -88,29 +108,45 @@ enum class Error : internal::FFICode
 };
 
 ///
-/// Time range representation with minimum and maximum durations in milliseconds.
+/// Time range representation with minimum and maximum durations.
+///
+/// Time range `<min; max>`, both bounds are accepted.
 ///
+/// Accepts any `std::chrono` duration implicitly convertible to microseconds, so callers never pass raw integers.
+/// Bounds are kept in microseconds and converted to milliseconds only when passed to the library:
+/// `min_ms()` rounds down with `internal::to_ffi_ms_floor`, `max_ms()` rounds up with `internal::to_ffi_ms_ceil`,
+/// so the range passed to the library is never narrower than the requested one.
 class TimeRange
 {
   public:
-    TimeRange(std::chrono::milliseconds min_ms, std::chrono::milliseconds max_ms) : min_ms_(min_ms), max_ms_(max_ms)
+    TimeRange(std::chrono::microseconds min, std::chrono::microseconds max) : min_(min), max_(max)
+    {
+        assert(min_ <= max_);
+    }
+
+    /// Extend range by `jitter` on both sides.
+    /// Created range: `<min - jitter; max + jitter>`, `min` saturates at zero.
+    TimeRange with_tolerance(std::chrono::microseconds jitter) const
     {
-        assert(min_ms_ <= max_ms_);
+        const auto min = (min_ > jitter) ? (min_ - jitter) : std::chrono::microseconds::zero();
+        return TimeRange{min, max_ + jitter};
     }
 
+    /// Lower bound in milliseconds, rounded down.
     const uint32_t min_ms() const
     {
-        return min_ms_.count();
+        return internal::to_ffi_ms_floor(min_);
     }
 
+    /// Upper bound in milliseconds, rounded up.
     const uint32_t max_ms() const
     {
-        return max_ms_.count();
+        return internal::to_ffi_ms_ceil(max_);
     }
 
   private:
-    const std::chrono::milliseconds min_ms_;
-    const std::chrono::milliseconds max_ms_;
+    const std::chrono::microseconds min_;
+    const std::chrono::microseconds max_;
 };
 
 }  // namespace score::hm
@@ -16,6 +16,8 @@
 #include <score/expected.hpp>
 #include <score/hm/common.h>
 #include <score/hm/tag.h>
+#include <chrono>
+#include <cstdint>
 #include <functional>
 #include <optional>
 
@@ -34,6 +36,18 @@ class DeadlineMonitor;
 class DeadlineHandle;
 class Deadline;
 
+/// Side of the language boundary currently holding a deadline.
+/// Names and values must match `crate::deadline::DeadlineHolder`.
+enum class DeadlineHolder : uint8_t
+{
+    /// Deadline is not acquired.
+    None = 0,
+    /// Deadline acquired by Rust code.
+    Rust = 1,
+    /// Deadline acquired through the FFI, e.g. by these C++ bindings.
+    Ffi = 2,
+};
+
 /// DeadlineMonitorBuilder for constructing DeadlineMonitor instance
 class DeadlineMonitorBuilder final : public internal::RustDroppable<DeadlineMonitorBuilder>
 {
@@ -50,6 +64,29 @@ class DeadlineMonitorBuilder final : public internal::RustDroppable<DeadlineMoni
     /// Adds a deadline with the given tag and duration range to the monitor.
     DeadlineMonitorBuilder add_deadline(const DeadlineTag& deadline_tag, const TimeRange& range) &&;
 
+    /// Adds a deadline which can be acquired up to `max_instances` times at once.
+    ///
+    /// - `max_instances` - number of concurrent instances, must not be zero.
+    DeadlineMonitorBuilder add_deadline_concurrent(const DeadlineTag& deadline_tag,
+                                                   const TimeRange& range,
+                                                   uint32_t max_instances) &&;
+
+    /// Attribute violations to the OS thread which last operated on the deadline.
+    DeadlineMonitorBuilder with_thread_attribution() &&;
+
+    /// Capture a backtrace of the thread stopping a deadline too late.
+    ///
+    /// - `max_bytes` - maximum size of the rendered backtrace.
+    DeadlineMonitorBuilder with_violation_backtrace(uint32_t max_bytes) &&;
+
+    /// Report run time, run delay and involuntary context switches of the thread with deadline violations.
+    /// Supported on Linux only.
+    DeadlineMonitorBuilder with_scheduling_statistics() &&;
+
+    /// Allow running deadlines to be extended up to `max_extensions` times per activation,
+    /// see `DeadlineHandle::extend`. Deadlines cannot be extended by default.
+    DeadlineMonitorBuilder with_max_extensions(uint32_t max_extensions) &&;
+
   protected:
     std::optional<internal::FFIHandle> _drop_by_rust_impl()
     {
@@ -76,8 +113,14 @@ class DeadlineMonitor final
     DeadlineMonitor(DeadlineMonitor&& other) noexcept = default;
     DeadlineMonitor& operator=(DeadlineMonitor&& other) noexcept = default;
 
+    /// Acquires a deadline instance for the given tag.
+    /// A deadline can be held only once at a time - the second acquirer gets `Error::AlreadyExists`,
+    /// regardless of whether the deadline is held on the C++ or on the Rust side.
     ::score::cpp::expected<Deadline, score::hm::Error> get_deadline(const DeadlineTag& deadline_tag);
 
+    /// Returns the current holder of the deadline with the given tag.
+    ::score::cpp::expected<DeadlineHolder, score::hm::Error> get_deadline_holder(const DeadlineTag& deadline_tag);
+
   private:
     explicit DeadlineMonitor(internal::FFIHandle handle);
 
@@ -102,6 +145,15 @@ class Deadline final
     //  After this call the Deadline instance cannot be used until connected DeadlineHandle is destroyed
     ::score::cpp::expected<DeadlineHandle, Error> start();
 
+    /// Starts the deadline monitoring with an opaque correlation ID, e.g. ID of a request or frame in a tracing
+    /// system. ID is logged and carried into violation records if this activation of the deadline is violated.
+    ::score::cpp::expected<DeadlineHandle, Error> start_with_id(std::uint64_t correlation_id);
+
+    /// Starts the deadline monitoring at an externally captured time, e.g. hardware timestamp of an interrupt.
+    /// `timestamp_ns` and `now_ns` are readings of the same clock, `now_ns` must be read right before the call.
+    /// Returns InvalidArgument if the timestamp is in the future or before the monitor was built.
+    ::score::cpp::expected<DeadlineHandle, Error> start_at(std::uint64_t timestamp_ns, std::uint64_t now_ns);
+
   private:
     explicit Deadline(internal::FFIHandle handle);
 
@@ -121,6 +173,12 @@ class DeadlineHandle final
     /// Stops the deadline monitoring.
     void stop();
 
+    /// Extends the running deadline by `extension`, pushing out the upper bound of its range,
+    /// e.g. to declare an operation with data-dependent duration is still making progress.
+    /// Returns Failed if the deadline is already past its range,
+    /// WrongState if it was extended the maximum number of times in this activation.
+    score::cpp::expected_blank<Error> extend(std::chrono::milliseconds extension);
+
     /// Destructor that ensures the deadline is stopped if not already done.
     ~DeadlineHandle();
 
@@ -17,12 +17,32 @@
 #include <score/hm/deadline/deadline_monitor.h>
 #include <score/hm/heartbeat/heartbeat_monitor.h>
 #include <score/hm/tag.h>
+#include <string>
+#include <string_view>
 
 namespace score::hm
 {
 
 class HealthMonitor;
 
+/// Failure counters of a single monitor, accumulated since the health monitor was built.
+struct MonitorStatistics
+{
+    uint64_t too_early{0};
+    uint64_t too_late{0};
+    uint64_t multiple_heartbeats{0};
+    uint64_t invalid_transitions{0};
+    uint64_t stale_artifacts{0};
+    uint64_t unavailable_artifacts{0};
+    uint64_t exhausted_budgets{0};
+    uint64_t idle_periods{0};
+    /// Longest time outside of the range of a deadline or heartbeat, zero if none.
+    std::chrono::milliseconds max_offset{0};
+    /// Time of the most recent failure, relative to the start of the health monitor.
+    /// Empty if the monitor never failed.
+    std::optional<std::chrono::milliseconds> last_failure;
+};
+
 ///
 /// Builder for HealthMonitor instances.
 ///
@@ -49,11 +69,13 @@ class HealthMonitorBuilder final
 
     /// Sets the cycle duration for supervisor API notifications.
     /// This duration determines how often the health monitor notifies the supervisor that the system is alive.
-    HealthMonitorBuilder with_supervisor_api_cycle(std::chrono::milliseconds cycle_duration) &&;
+    /// Sub-millisecond parts are rounded up.
+    HealthMonitorBuilder with_supervisor_api_cycle(std::chrono::microseconds cycle_duration) &&;
 
     /// Sets the internal processing cycle duration.
     /// This duration determines how often the health monitor checks deadlines.
-    HealthMonitorBuilder with_internal_processing_cycle(std::chrono::milliseconds cycle_duration) &&;
+    /// Sub-millisecond parts are rounded up.
+    HealthMonitorBuilder with_internal_processing_cycle(std::chrono::microseconds cycle_duration) &&;
 
     /// Build a new `HealthMonitor` instance based on provided parameters.
     HealthMonitor build() &&;
@@ -61,8 +83,8 @@ class HealthMonitorBuilder final
   private:
     internal::DroppableFFIHandle health_monitor_builder_handle_;
 
-    std::chrono::milliseconds supervisor_api_cycle_duration_;
-    std::chrono::milliseconds internal_processing_cycle_duration_;
+    std::chrono::microseconds supervisor_api_cycle_duration_;
+    std::chrono::microseconds internal_processing_cycle_duration_;
 };
 
 class HealthMonitor final
@@ -81,6 +103,30 @@ class HealthMonitor final
 
     void start();
 
+    /// Stop monitoring, monitors become available again.
+    /// Returns `Error::WrongState` if monitoring is not started.
+    score::cpp::expected_blank<Error> stop();
+
+    /// Restart monitoring with a fresh supervisor cycle, monitors remain taken.
+    /// Returns `Error::WrongState` if monitoring is not started.
+    score::cpp::expected_blank<Error> restart();
+
+    /// Suspend supervision of the monitor, e.g. during calibration or diagnostic sessions.
+    /// Returns `Error::NotFound` if the monitor does not exist.
+    score::cpp::expected_blank<Error> disable_monitor(const MonitorTag& monitor_tag);
+
+    /// Resume supervision of the monitor, activity while disabled is not evaluated.
+    /// Returns `Error::NotFound` if the monitor does not exist.
+    score::cpp::expected_blank<Error> enable_monitor(const MonitorTag& monitor_tag);
+
+    /// Failure counters of the monitor, all zero if the monitor never failed.
+    /// Returns `Error::NotFound` if the monitor does not exist.
+    score::cpp::expected<MonitorStatistics, Error> statistics(const MonitorTag& monitor_tag) const;
+
+    /// Snapshot of the live supervision state as a JSON document, for diagnostics.
+    /// Contains all monitors with their configuration, current state and recent violations.
+    std::string snapshot() const;
+
   private:
     // Allow only the builder to create HealthMonitor instances.
     friend class HealthMonitorBuilder;
@@ -90,6 +136,14 @@ class HealthMonitor final
     internal::FFIHandle health_monitor_;
 };
 
+/// Version of the library, e.g. to audit deployed binaries.
+std::string_view library_version();
+
+/// Optional features the library was built with, separated by commas.
+/// Supervisor API client of the platform is used if neither `stub_supervisor_api_client`
+/// nor `supervisor_api_client_selection` is listed.
+std::string enabled_features();
+
 }  // namespace score::hm
 
 #endif  // SCORE_HM_HEALTH_MONITOR_H
@@ -15,6 +15,9 @@
 
 #include <score/expected.hpp>
 #include <score/hm/common.h>
+#include <score/hm/tag.h>
+#include <chrono>
+#include <cstdint>
 
 namespace score::hm
 {
@@ -43,6 +46,38 @@ class HeartbeatMonitorBuilder final : public internal::RustDroppable<HeartbeatMo
     HeartbeatMonitorBuilder(HeartbeatMonitorBuilder&&) = default;
     HeartbeatMonitorBuilder& operator=(HeartbeatMonitorBuilder&&) = delete;
 
+    /// Limit the number of attempts to update the heartbeat state.
+    ///
+    /// - `max_attempts` - maximum number of update attempts, must be greater than zero.
+    HeartbeatMonitorBuilder with_max_update_attempts(uint32_t max_attempts) &&;
+
+    /// Attribute violations to the OS thread which provided the last heartbeat.
+    HeartbeatMonitorBuilder with_thread_attribution() &&;
+
+    /// Tolerate consecutive late or missed heartbeats before reporting an error.
+    ///
+    /// - `allowed_misses` - number of consecutive late or missed heartbeats tolerated.
+    HeartbeatMonitorBuilder with_allowed_misses(uint32_t allowed_misses) &&;
+
+    /// Supervise the number of heartbeats per reference window instead of the time between heartbeats.
+    ///
+    /// - `min_count` - minimum number of heartbeats per window.
+    /// - `max_count` - maximum number of heartbeats per window, must not be smaller than `min_count`.
+    /// - `per` - duration of the window, must be longer than the internal processing cycle.
+    HeartbeatMonitorBuilder with_expected_count(uint32_t min_count,
+                                                uint32_t max_count,
+                                                std::chrono::milliseconds per) &&;
+
+    /// Require the first heartbeat within the startup window, so hung initialization is reported.
+    ///
+    /// - `deadline` - startup window, must be longer than the internal processing cycle.
+    HeartbeatMonitorBuilder with_first_beat_deadline(std::chrono::milliseconds deadline) &&;
+
+    /// Register a source which must provide a heartbeat every cycle.
+    ///
+    /// - `source_tag` - unique tag of the source.
+    HeartbeatMonitorBuilder add_source(const SourceTag& source_tag) &&;
+
   protected:
     std::optional<internal::FFIHandle> _drop_by_rust_impl()
     {
@@ -71,6 +106,20 @@ class HeartbeatMonitor final
 
     void heartbeat();
 
+    /// Provide a heartbeat of a source registered with `HeartbeatMonitorBuilder::add_source`.
+    /// Returns NotFound if the source is not registered.
+    score::cpp::expected_blank<Error> heartbeat_from(const SourceTag& source_tag);
+
+    /// Replace the range between heartbeats.
+    /// Returns InvalidArgument if the range is shorter than allowed by the internal processing cycle.
+    score::cpp::expected_blank<Error> update_range(const TimeRange& range);
+
+    /// Suspend evaluation of the monitor until resumed.
+    void suspend();
+
+    /// Resume evaluation of a suspended monitor, starting a new cycle now.
+    void resume();
+
   private:
     explicit HeartbeatMonitor(internal::FFIHandle monitor_handle);
 
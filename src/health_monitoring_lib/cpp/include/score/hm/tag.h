@@ -49,6 +49,13 @@ class DeadlineTag : public Tag
     using Tag::Tag;
 };
 
+/// Heartbeat source tag.
+class SourceTag : public Tag
+{
+  public:
+    using Tag::Tag;
+};
+
 }  // namespace score::hm
 
 #endif  // SCORE_HM_TAG_H
@@ -42,7 +42,8 @@ TEST_F(HealthMonitorTest, TestName)
             .add_deadline(DeadlineTag("deadline_1"),
                           TimeRange(std::chrono::milliseconds(100), std::chrono::milliseconds(200)))
             .add_deadline(DeadlineTag("deadline_2"),
-                          TimeRange(std::chrono::milliseconds(100), std::chrono::milliseconds(200)));
+                          TimeRange(std::chrono::milliseconds(100), std::chrono::milliseconds(200)))
+            .with_max_extensions(1);
 
     // Setup heartbeat monitor construction.
     const MonitorTag heartbeat_monitor_tag{"heartbeat_monitor"};
@@ -85,12 +86,64 @@ TEST_F(HealthMonitorTest, TestName)
 
     heartbeat_monitor.heartbeat();
 
+    EXPECT_EQ(deadline_mon.get_deadline_holder(DeadlineTag("deadline_1")).value(), deadline::DeadlineHolder::None);
     auto deadline_res = deadline_mon.get_deadline(DeadlineTag("deadline_1"));
+    EXPECT_EQ(deadline_mon.get_deadline_holder(DeadlineTag("deadline_1")).value(), deadline::DeadlineHolder::Ffi);
+    EXPECT_EQ(deadline_mon.get_deadline(DeadlineTag("deadline_1")).error(), ::score::hm::Error::AlreadyExists);
+    EXPECT_EQ(deadline_mon.get_deadline_holder(DeadlineTag("unknown")).error(), ::score::hm::Error::NotFound);
 
     {
         auto deadline_guard = deadline_res.value().start().value();
 
         EXPECT_EQ(deadline_res.value().start().error(), ::score::hm::Error::WrongState);
+        EXPECT_TRUE(deadline_guard.extend(std::chrono::milliseconds(100)).has_value());
+        EXPECT_EQ(deadline_guard.extend(std::chrono::milliseconds(100)).error(), ::score::hm::Error::WrongState);
         deadline_guard.stop();
     }
 }
+
+TEST_F(HealthMonitorTest, TimeRangeConvertsChronoDurations)
+{
+    RecordProperty("Description",
+                   "This test verifies that TimeRange accepts different std::chrono units and never narrows the range "
+                   "when converting it to milliseconds passed to the library.");
+
+    const TimeRange range_ms{std::chrono::milliseconds{100}, std::chrono::milliseconds{200}};
+    EXPECT_EQ(range_ms.min_ms(), 100U);
+    EXPECT_EQ(range_ms.max_ms(), 200U);
+
+    const TimeRange range_us{std::chrono::microseconds{100'900}, std::chrono::microseconds{200'100}};
+    EXPECT_EQ(range_us.min_ms(), 100U);
+    EXPECT_EQ(range_us.max_ms(), 201U);
+
+    const TimeRange range_s{std::chrono::seconds{1}, std::chrono::seconds{2}};
+    EXPECT_EQ(range_s.min_ms(), 1000U);
+    EXPECT_EQ(range_s.max_ms(), 2000U);
+
+    EXPECT_EQ(internal::to_ffi_ms_ceil(std::chrono::microseconds{1}), 1U);
+    EXPECT_EQ(internal::to_ffi_ms_floor(std::chrono::microseconds{999}), 0U);
+}
+
+TEST_F(HealthMonitorTest, TimeRangeWithTolerance)
+{
+    RecordProperty("Description",
+                   "This test verifies that TimeRange tolerance extends both bounds and lower bound saturates at zero.");
+
+    const TimeRange range{std::chrono::milliseconds{100}, std::chrono::milliseconds{200}};
+    const auto widened{range.with_tolerance(std::chrono::milliseconds{5})};
+    EXPECT_EQ(widened.min_ms(), 95U);
+    EXPECT_EQ(widened.max_ms(), 205U);
+
+    const auto saturated{range.with_tolerance(std::chrono::milliseconds{150})};
+    EXPECT_EQ(saturated.min_ms(), 0U);
+    EXPECT_EQ(saturated.max_ms(), 350U);
+}
+
+TEST_F(HealthMonitorTest, BuildInfo)
+{
+    RecordProperty("Description",
+                   "This test verifies that the library reports its version and the features it was built with.");
+
+    EXPECT_FALSE(library_version().empty());
+    EXPECT_NE(enabled_features().find("stub_supervisor_api_client"), std::string::npos);
+}
@@ -0,0 +1,218 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::{MonitorEvaluationError, MonitorEvaluator, ViolationContext};
+use crate::config_summary::ConfigDigest;
+use crate::deadline::DeadlineEvaluationError;
+use crate::log::{error, info, warn};
+use crate::tag::MonitorTag;
+use crate::HealthMonitorError;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use core::time::Duration;
+use std::time::Instant;
+
+/// Two-stage start, monitoring is armed with [`crate::HealthMonitor::arm`]
+/// and strict supervision begins with [`crate::HealthMonitor::go`].
+///
+/// While armed, the supervisor is notified, but violations of all monitors are only logged and stored in the history,
+/// so components of a process can be brought up under relaxed supervision.
+/// Time from arming to go is supervised instead, exceeding the timeout is reported as a deadline violation
+/// under the guard tag and begins strict supervision.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArmedStart {
+    guard_tag: MonitorTag,
+    timeout: Duration,
+}
+
+impl ArmedStart {
+    /// Create two-stage start configuration.
+    ///
+    /// - `guard_tag` - tag under which exceeding of the timeout is reported, must not be used by a monitor.
+    /// - `timeout` - longest allowed time from arming to go.
+    pub fn new(guard_tag: MonitorTag, timeout: Duration) -> Self {
+        Self { guard_tag, timeout }
+    }
+
+    pub(crate) fn guard_tag(&self) -> MonitorTag {
+        self.guard_tag
+    }
+
+    pub(crate) fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_str(self.guard_tag.as_str());
+        digest.write_duration(self.timeout);
+    }
+}
+
+/// Armed state shared by the health monitor and the monitoring logic.
+/// Evaluated as a monitor supervising the time from arming to go.
+pub(crate) struct ArmGuard {
+    config: ArmedStart,
+    /// Reference point of `armed_at_ms`.
+    reference: Instant,
+    armed: AtomicBool,
+    armed_at_ms: AtomicU64,
+}
+
+impl ArmGuard {
+    pub(crate) fn new(config: ArmedStart) -> Self {
+        Self {
+            config,
+            reference: Instant::now(),
+            armed: AtomicBool::new(false),
+            armed_at_ms: AtomicU64::new(0),
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        self.reference.elapsed().as_millis() as u64
+    }
+
+    /// Arm the monitoring, timeout applies from now.
+    pub(crate) fn arm(&self) {
+        self.armed_at_ms.store(self.now_ms(), Ordering::Relaxed);
+        self.armed.store(true, Ordering::Release);
+        info!(
+            "Monitoring armed, go expected within {} ms.",
+            self.config.timeout.as_millis() as u64
+        );
+    }
+
+    /// Begin strict supervision, returns time spent armed.
+    /// Returns [`HealthMonitorError::WrongState`] if monitoring is not armed, e.g. after the timeout was exceeded.
+    pub(crate) fn go(&self) -> Result<Duration, HealthMonitorError> {
+        let elapsed_ms = self.now_ms().saturating_sub(self.armed_at_ms.load(Ordering::Relaxed));
+        if self
+            .armed
+            .compare_exchange(true, false, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            error!("Strict supervision cannot begin, monitoring is not armed.");
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        info!("Strict supervision began after {} ms armed.", elapsed_ms);
+        Ok(Duration::from_millis(elapsed_ms))
+    }
+
+    /// Leave the armed state without reporting, e.g. when monitoring is started in a single stage.
+    pub(crate) fn disarm(&self) {
+        self.armed.store(false, Ordering::Release);
+    }
+
+    /// Monitoring is armed, violations of all monitors are advisory.
+    pub(crate) fn is_armed(&self) -> bool {
+        self.armed.load(Ordering::Acquire)
+    }
+}
+
+impl MonitorEvaluator for ArmGuard {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.config.guard_tag
+    }
+
+    fn evaluate(
+        &self,
+        _hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
+        if !self.is_armed() {
+            return;
+        }
+
+        let elapsed_ms = self.now_ms().saturating_sub(self.armed_at_ms.load(Ordering::Relaxed));
+        if elapsed_ms <= self.config.timeout.as_millis() as u64 {
+            return;
+        }
+
+        // Strict supervision begins, so monitors are not masked by a component never reporting ready.
+        if self
+            .armed
+            .compare_exchange(true, false, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            return;
+        }
+        warn!(
+            "Go not received within {} ms of arming, strict supervision began.",
+            self.config.timeout.as_millis() as u64
+        );
+        on_error(
+            &self.config.guard_tag,
+            DeadlineEvaluationError::TooLate.into(),
+            ViolationContext::default(),
+        );
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::armed_start::{ArmGuard, ArmedStart};
+    use crate::common::{MonitorEvaluator, ViolationContext};
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::tag::MonitorTag;
+    use crate::HealthMonitorError;
+    use core::time::Duration;
+    use std::time::Instant;
+
+    fn def_arm_guard(timeout: Duration) -> ArmGuard {
+        ArmGuard::new(ArmedStart::new(MonitorTag::from("arm_guard"), timeout))
+    }
+
+    #[test]
+    fn arm_guard_arm_and_go() {
+        let guard = def_arm_guard(Duration::from_secs(1));
+        assert!(!guard.is_armed());
+        assert_eq!(guard.go(), Err(HealthMonitorError::WrongState));
+
+        guard.arm();
+        assert!(guard.is_armed());
+        assert!(guard.go().is_ok());
+        assert!(!guard.is_armed());
+        assert_eq!(guard.go(), Err(HealthMonitorError::WrongState));
+
+        guard.arm();
+        guard.disarm();
+        assert!(!guard.is_armed());
+    }
+
+    #[test]
+    fn arm_guard_reports_exceeded_timeout() {
+        let guard = def_arm_guard(Duration::from_millis(20));
+        let hmon_starting_point = Instant::now();
+        let mut violations = Vec::new();
+        let mut on_error = |monitor_tag: &MonitorTag, error, _context: ViolationContext| {
+            violations.push((*monitor_tag, error));
+        };
+
+        // Nothing is reported while disarmed or within the timeout.
+        guard.evaluate(hmon_starting_point, &mut on_error);
+        guard.arm();
+        guard.evaluate(hmon_starting_point, &mut on_error);
+
+        std::thread::sleep(Duration::from_millis(40));
+        guard.evaluate(hmon_starting_point, &mut on_error);
+        guard.evaluate(hmon_starting_point, &mut on_error);
+        assert_eq!(
+            violations,
+            vec![(MonitorTag::from("arm_guard"), DeadlineEvaluationError::TooLate.into())]
+        );
+
+        // Strict supervision began with the exceeded timeout.
+        assert!(!guard.is_armed());
+        assert_eq!(guard.go(), Err(HealthMonitorError::WrongState));
+    }
+}
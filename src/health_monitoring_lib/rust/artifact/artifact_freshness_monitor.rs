@@ -0,0 +1,531 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::{InternalFault, MonitorEvaluationError, MonitorEvaluator, ViolationContext};
+use crate::config_summary::ConfigDigest;
+use crate::log::{error, warn, ScoreDebug};
+use crate::protected_memory::ProtectedMemoryAllocator;
+use crate::tag::MonitorTag;
+use crate::validation::{ConfigIssue, ValidationReport};
+use crate::HealthMonitorError;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime};
+
+/// Directory where shared memory segments are visible as files.
+const SHM_DIRECTORY: &str = "/dev/shm";
+
+/// Artifact evaluation errors.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ScoreDebug)]
+pub enum ArtifactEvaluationError {
+    /// Artifact was not updated within its maximum age.
+    Stale,
+    /// Artifact could not be read for longer than its maximum age.
+    Unavailable,
+}
+
+/// Way of detecting updates of an artifact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Probe {
+    /// Modification time of the file.
+    ModificationTime,
+    /// Little-endian `u64` sequence counter stored in the file at the offset.
+    Sequence { offset: u64 },
+}
+
+/// Declared artifact.
+#[derive(Debug, Clone)]
+struct ArtifactSpec {
+    path: PathBuf,
+    probe: Probe,
+    max_age: Duration,
+}
+
+/// Builder for [`ArtifactFreshnessMonitor`].
+///
+/// Artifact freshness monitor supervises components which cannot be instrumented directly,
+/// using periodic updates of files or shared memory segments they produce as a liveness proxy.
+/// Artifacts are checked on every internal processing cycle, the monitor is not taken by the application.
+#[derive(Debug, Default)]
+pub struct ArtifactFreshnessMonitorBuilder {
+    artifacts: Vec<ArtifactSpec>,
+}
+
+impl ArtifactFreshnessMonitorBuilder {
+    /// Create a new [`ArtifactFreshnessMonitorBuilder`].
+    pub fn new() -> Self {
+        Self { artifacts: Vec::new() }
+    }
+
+    /// Add a file which must be modified at least every `max_age`.
+    /// Age is measured from the file modification time.
+    ///
+    /// - `path` - path to the file.
+    /// - `max_age` - maximum allowed age of the file, must be longer than the internal processing cycle.
+    pub fn add_file(mut self, path: impl Into<PathBuf>, max_age: Duration) -> Self {
+        self.add_file_internal(path.into(), max_age);
+        self
+    }
+
+    /// Add a POSIX shared memory segment which must be modified at least every `max_age`.
+    /// Segment is checked as a file in `/dev/shm`, so it is supported on Linux only.
+    ///
+    /// - `name` - name of the segment, as passed to `shm_open`.
+    /// - `max_age` - maximum allowed age of the segment, must be longer than the internal processing cycle.
+    pub fn add_shm_segment(mut self, name: &str, max_age: Duration) -> Self {
+        self.add_file_internal(Path::new(SHM_DIRECTORY).join(name.trim_start_matches('/')), max_age);
+        self
+    }
+
+    /// Add a sequence counter which must change at least every `max_age`.
+    /// Counter is a little-endian `u64` stored in a file or shared memory segment.
+    /// Useful when modification time is not updated, e.g. for memory mapped files.
+    ///
+    /// - `path` - path to the file containing the counter.
+    /// - `offset` - offset of the counter in the file.
+    /// - `max_age` - maximum allowed time without counter change, must be longer than the internal processing cycle.
+    pub fn add_sequence(mut self, path: impl Into<PathBuf>, offset: u64, max_age: Duration) -> Self {
+        self.add_sequence_internal(path.into(), offset, max_age);
+        self
+    }
+
+    /// Build the [`ArtifactFreshnessMonitor`].
+    ///
+    /// - `monitor_tag` - tag of this monitor.
+    /// - `internal_processing_cycle` - health monitor processing cycle.
+    /// - `_allocator` - protected memory allocator.
+    pub(crate) fn build(
+        self,
+        monitor_tag: MonitorTag,
+        internal_processing_cycle: Duration,
+        _allocator: &ProtectedMemoryAllocator,
+    ) -> Result<ArtifactFreshnessMonitor, HealthMonitorError> {
+        let mut report = ValidationReport::new();
+        self.validate(monitor_tag, internal_processing_cycle, &mut report);
+        if let Some(error) = report.error() {
+            return Err(error);
+        }
+
+        Ok(ArtifactFreshnessMonitor::new(monitor_tag, self.artifacts))
+    }
+
+    /// Check the configuration, found issues are added to the `report`.
+    ///
+    /// - `monitor_tag` - tag of this monitor.
+    /// - `internal_processing_cycle` - health monitor processing cycle.
+    /// - `report` - report to add found issues to.
+    pub(crate) fn validate(
+        &self,
+        monitor_tag: MonitorTag,
+        internal_processing_cycle: Duration,
+        report: &mut ValidationReport,
+    ) {
+        for artifact in self.artifacts.iter() {
+            if artifact.max_age <= internal_processing_cycle {
+                error!(
+                    "Maximum age of artifact {} ({} ms) must be longer than internal processing cycle ({} ms).",
+                    artifact.path.to_string_lossy().into_owned(),
+                    artifact.max_age.as_millis() as u64,
+                    internal_processing_cycle.as_millis() as u64
+                );
+                report.push(ConfigIssue::ArtifactMaxAgeTooShort(monitor_tag));
+                return;
+            }
+        }
+    }
+
+    /// Number of declared artifacts.
+    pub(crate) fn num_artifacts(&self) -> usize {
+        self.artifacts.len()
+    }
+
+    /// Write the configuration into the `digest`, artifacts are written in declaration order.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_u64(self.artifacts.len() as u64);
+        for artifact in self.artifacts.iter() {
+            digest.write_str(&artifact.path.to_string_lossy());
+            match artifact.probe {
+                Probe::ModificationTime => digest.write_u64(0),
+                Probe::Sequence { offset } => {
+                    digest.write_u64(1);
+                    digest.write_u64(offset);
+                },
+            }
+            digest.write_duration(artifact.max_age);
+        }
+    }
+
+    // Used by FFI and config parsing code which prefer not to move builder instance
+
+    pub(crate) fn add_file_internal(&mut self, path: PathBuf, max_age: Duration) {
+        self.artifacts.push(ArtifactSpec {
+            path,
+            probe: Probe::ModificationTime,
+            max_age,
+        });
+    }
+
+    pub(crate) fn add_sequence_internal(&mut self, path: PathBuf, offset: u64, max_age: Duration) {
+        self.artifacts.push(ArtifactSpec {
+            path,
+            probe: Probe::Sequence { offset },
+            max_age,
+        });
+    }
+}
+
+/// Evaluation state of a single artifact, accessed only by the monitoring logic.
+#[derive(Default)]
+struct ArtifactState {
+    /// Last time the artifact was readable, or evaluation start.
+    last_available: Option<Instant>,
+    /// Last observed sequence value.
+    last_sequence: Option<u64>,
+    /// Last time the sequence value changed, or evaluation start.
+    last_sequence_change: Option<Instant>,
+    /// Error already reported, reported again only after the artifact recovers.
+    reported_error: Option<ArtifactEvaluationError>,
+    /// Last clock gap, modification time age is accounted from it at most.
+    rebaselined_at: Option<Instant>,
+}
+
+struct Artifact {
+    spec: ArtifactSpec,
+    state: Mutex<ArtifactState>,
+}
+
+/// Observation of an artifact.
+enum Observation {
+    /// Age of the artifact based on its modification time.
+    Age(Duration),
+    /// Current sequence value.
+    Sequence(u64),
+}
+
+impl Artifact {
+    fn observe(&self) -> std::io::Result<Observation> {
+        match self.spec.probe {
+            Probe::ModificationTime => {
+                let modified = std::fs::metadata(&self.spec.path)?.modified()?;
+                // Modification time in the future is treated as fresh.
+                let age = SystemTime::now().duration_since(modified).unwrap_or(Duration::ZERO);
+                Ok(Observation::Age(age))
+            },
+            Probe::Sequence { offset } => {
+                let mut file = File::open(&self.spec.path)?;
+                file.seek(SeekFrom::Start(offset))?;
+                let mut bytes = [0; size_of::<u64>()];
+                file.read_exact(&mut bytes)?;
+                Ok(Observation::Sequence(u64::from_le_bytes(bytes)))
+            },
+        }
+    }
+
+    /// Evaluate the artifact, returns error only when it is detected for the first time.
+    fn evaluate(&self, now: Instant) -> Option<ArtifactEvaluationError> {
+        let observation = self.observe();
+
+        // Evaluation is not concurrent, poisoning can only be caused by a panic in a previous evaluation.
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let last_available = *state.last_available.get_or_insert(now);
+        let error = match observation {
+            Ok(observation) => {
+                state.last_available = Some(now);
+                match observation {
+                    Observation::Age(age) => {
+                        let age = state
+                            .rebaselined_at
+                            .map_or(age, |rebaselined_at| age.min(now.duration_since(rebaselined_at)));
+                        (age > self.spec.max_age).then_some(ArtifactEvaluationError::Stale)
+                    },
+                    Observation::Sequence(sequence) => {
+                        if state.last_sequence != Some(sequence) {
+                            state.last_sequence = Some(sequence);
+                            state.last_sequence_change = Some(now);
+                        }
+                        let last_change = *state.last_sequence_change.get_or_insert(now);
+                        (now.duration_since(last_change) > self.spec.max_age).then_some(ArtifactEvaluationError::Stale)
+                    },
+                }
+            },
+            Err(_) => {
+                (now.duration_since(last_available) > self.spec.max_age).then_some(ArtifactEvaluationError::Unavailable)
+            },
+        };
+
+        let newly_detected = error.filter(|error| state.reported_error != Some(*error));
+        state.reported_error = error;
+        newly_detected
+    }
+
+    /// Account artifact age from `now`, time spent in a clock gap is not accounted.
+    fn rebaseline(&self, now: Instant) {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.last_available = Some(now);
+        state.last_sequence_change = Some(now);
+        state.rebaselined_at = Some(now);
+    }
+}
+
+/// Monitor checking declared artifacts are updated periodically.
+/// Not taken by the application, only evaluated by the health monitor.
+pub(crate) struct ArtifactFreshnessMonitor {
+    monitor_tag: MonitorTag,
+    artifacts: Box<[Artifact]>,
+    /// Evaluation of the monitor is enabled.
+    enabled: AtomicBool,
+    /// Evaluation was skipped because the monitor is disabled, artifacts are re-baselined once enabled.
+    suspended: AtomicBool,
+}
+
+impl ArtifactFreshnessMonitor {
+    fn new(monitor_tag: MonitorTag, artifacts: Vec<ArtifactSpec>) -> Self {
+        Self {
+            monitor_tag,
+            artifacts: artifacts
+                .into_iter()
+                .map(|spec| Artifact {
+                    spec,
+                    state: Mutex::new(ArtifactState::default()),
+                })
+                .collect(),
+            enabled: AtomicBool::new(true),
+            suspended: AtomicBool::new(false),
+        }
+    }
+
+    /// Enable or disable evaluation of this monitor.
+    /// Time spent disabled is not accounted in the artifact age.
+    pub(crate) fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Evaluation of this monitor is enabled.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Declared artifacts with their maximum age, in order of declaration.
+    pub(crate) fn artifacts(&self) -> Vec<(PathBuf, Duration)> {
+        self.artifacts
+            .iter()
+            .map(|artifact| (artifact.spec.path.clone(), artifact.spec.max_age))
+            .collect()
+    }
+}
+
+impl MonitorEvaluator for ArtifactFreshnessMonitor {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.monitor_tag
+    }
+
+    fn evaluate(
+        &self,
+        _hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
+        if !self.enabled.load(Ordering::Relaxed) {
+            self.suspended.store(true, Ordering::Relaxed);
+            return;
+        }
+
+        let now = Instant::now();
+        let resumed = self.suspended.swap(false, Ordering::Relaxed);
+        for artifact in self.artifacts.iter() {
+            if resumed {
+                artifact.rebaseline(now);
+            }
+            if let Some(error) = artifact.evaluate(now) {
+                warn!(
+                    "Artifact {} of monitor {:?} failed: {:?}.",
+                    artifact.spec.path.to_string_lossy().into_owned(),
+                    self.monitor_tag,
+                    error
+                );
+                on_error(&self.monitor_tag, error.into(), ViolationContext::default());
+            }
+        }
+    }
+
+    fn collect_internal_faults(&self, _on_fault: &mut dyn FnMut(&MonitorTag, InternalFault)) {}
+
+    fn rebaseline(&self, _hmon_starting_point: Instant, _gap: Duration) {
+        let now = Instant::now();
+        for artifact in self.artifacts.iter() {
+            artifact.rebaseline(now);
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::artifact::{ArtifactEvaluationError, ArtifactFreshnessMonitor, ArtifactFreshnessMonitorBuilder};
+    use crate::common::{MonitorEvaluationError, MonitorEvaluator, ViolationContext};
+    use crate::protected_memory::ProtectedMemoryAllocator;
+    use crate::tag::MonitorTag;
+    use crate::HealthMonitorError;
+    use core::time::Duration;
+    use std::path::PathBuf;
+    use std::time::{Instant, SystemTime};
+
+    /// Temporary file removed on drop.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("hmon_artifact_{}_{}", std::process::id(), name));
+            std::fs::write(&path, 0u64.to_le_bytes()).unwrap();
+            Self(path)
+        }
+
+        fn write_sequence(&self, sequence: u64) {
+            std::fs::write(&self.0, sequence.to_le_bytes()).unwrap();
+        }
+
+        fn set_modified(&self, modified: SystemTime) {
+            let file = std::fs::File::options().write(true).open(&self.0).unwrap();
+            file.set_modified(modified).unwrap();
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn build(builder: ArtifactFreshnessMonitorBuilder) -> ArtifactFreshnessMonitor {
+        builder
+            .build(
+                MonitorTag::from("artifact_monitor"),
+                Duration::from_millis(10),
+                &ProtectedMemoryAllocator {},
+            )
+            .unwrap()
+    }
+
+    fn evaluate(monitor: &ArtifactFreshnessMonitor) -> Vec<MonitorEvaluationError> {
+        let mut errors = Vec::new();
+        monitor.evaluate(Instant::now(), &mut |monitor_tag, error, context| {
+            assert_eq!(*monitor_tag, MonitorTag::from("artifact_monitor"));
+            assert_eq!(context, ViolationContext::default());
+            errors.push(error);
+        });
+        errors
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_builder_max_age_too_short() {
+        let result = ArtifactFreshnessMonitorBuilder::new()
+            .add_file("/tmp/artifact", Duration::from_millis(10))
+            .build(
+                MonitorTag::from("artifact_monitor"),
+                Duration::from_millis(10),
+                &ProtectedMemoryAllocator {},
+            );
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_builder_shm_segment_path() {
+        let builder = ArtifactFreshnessMonitorBuilder::new().add_shm_segment("/segment", Duration::from_secs(1));
+        assert_eq!(builder.artifacts[0].path, PathBuf::from("/dev/shm/segment"));
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_file_fresh() {
+        let file = TempFile::new("file_fresh");
+        let monitor = build(ArtifactFreshnessMonitorBuilder::new().add_file(&file.0, Duration::from_secs(5)));
+        assert!(evaluate(&monitor).is_empty());
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_file_stale_reported_once() {
+        let file = TempFile::new("file_stale");
+        file.set_modified(SystemTime::now() - Duration::from_secs(10));
+        let monitor = build(ArtifactFreshnessMonitorBuilder::new().add_file(&file.0, Duration::from_secs(5)));
+
+        let stale = vec![MonitorEvaluationError::Artifact(ArtifactEvaluationError::Stale)];
+        assert_eq!(evaluate(&monitor), stale);
+        assert!(evaluate(&monitor).is_empty());
+
+        // Reported again after recovery.
+        file.set_modified(SystemTime::now());
+        assert!(evaluate(&monitor).is_empty());
+        file.set_modified(SystemTime::now() - Duration::from_secs(10));
+        assert_eq!(evaluate(&monitor), stale);
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_rebaseline() {
+        let file = TempFile::new("rebaseline");
+        file.set_modified(SystemTime::now() - Duration::from_secs(10));
+        let monitor = build(ArtifactFreshnessMonitorBuilder::new().add_file(&file.0, Duration::from_millis(20)));
+
+        // Age is accounted from the gap.
+        monitor.rebaseline(Instant::now(), Duration::from_secs(10));
+        assert!(evaluate(&monitor).is_empty());
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(
+            evaluate(&monitor),
+            vec![MonitorEvaluationError::Artifact(ArtifactEvaluationError::Stale)]
+        );
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_file_unavailable() {
+        let path = std::env::temp_dir().join(format!("hmon_artifact_{}_missing", std::process::id()));
+        let monitor = build(ArtifactFreshnessMonitorBuilder::new().add_file(path, Duration::from_millis(20)));
+
+        // Missing artifact is tolerated for its maximum age.
+        assert!(evaluate(&monitor).is_empty());
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(
+            evaluate(&monitor),
+            vec![MonitorEvaluationError::Artifact(ArtifactEvaluationError::Unavailable)]
+        );
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_sequence() {
+        let file = TempFile::new("sequence");
+        let monitor = build(ArtifactFreshnessMonitorBuilder::new().add_sequence(&file.0, 0, Duration::from_millis(20)));
+
+        assert!(evaluate(&monitor).is_empty());
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(
+            evaluate(&monitor),
+            vec![MonitorEvaluationError::Artifact(ArtifactEvaluationError::Stale)]
+        );
+
+        file.write_sequence(1);
+        assert!(evaluate(&monitor).is_empty());
+    }
+
+    #[test]
+    fn artifact_freshness_monitor_sequence_out_of_bounds() {
+        let file = TempFile::new("sequence_out_of_bounds");
+        let monitor = build(ArtifactFreshnessMonitorBuilder::new().add_sequence(&file.0, 4, Duration::from_millis(20)));
+
+        assert!(evaluate(&monitor).is_empty());
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(
+            evaluate(&monitor),
+            vec![MonitorEvaluationError::Artifact(ArtifactEvaluationError::Unavailable)]
+        );
+    }
+}
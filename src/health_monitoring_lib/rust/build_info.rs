@@ -0,0 +1,65 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+/// Version of the library, null-terminated for FFI.
+pub(crate) const LIBRARY_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// Optional features the library was built with.
+const ENABLED_FEATURES: &[&str] = &[
+    #[cfg(feature = "stub_supervisor_api_client")]
+    "stub_supervisor_api_client",
+    #[cfg(feature = "supervisor_api_client_selection")]
+    "supervisor_api_client_selection",
+    #[cfg(feature = "cache_padded_deadline_state")]
+    "cache_padded_deadline_state",
+    #[cfg(feature = "async")]
+    "async",
+    #[cfg(feature = "fault_injection")]
+    "fault_injection",
+];
+
+/// Version of the library, e.g. to audit deployed binaries.
+pub fn library_version() -> &'static str {
+    LIBRARY_VERSION.trim_end_matches('\0')
+}
+
+/// Optional features the library was built with, in order of declaration.
+/// Supervisor API client of the platform is used if neither `stub_supervisor_api_client`
+/// nor `supervisor_api_client_selection` is enabled.
+pub fn enabled_features() -> &'static [&'static str] {
+    ENABLED_FEATURES
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::build_info::{enabled_features, library_version};
+
+    #[test]
+    fn build_info_library_version() {
+        assert_eq!(library_version(), env!("CARGO_PKG_VERSION"));
+        assert!(!library_version().contains('\0'));
+    }
+
+    #[test]
+    fn build_info_enabled_features() {
+        assert_eq!(
+            enabled_features().contains(&"cache_padded_deadline_state"),
+            cfg!(feature = "cache_padded_deadline_state")
+        );
+        assert_eq!(
+            enabled_features().contains(&"stub_supervisor_api_client"),
+            cfg!(feature = "stub_supervisor_api_client")
+        );
+    }
+}
@@ -0,0 +1,125 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use core::time::Duration;
+use std::sync::{Arc, Condvar, Mutex};
+
+/// Response of the monitoring logic to a challenge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChallengeResponse {
+    /// Identifier the challenge was issued with.
+    pub challenge_id: u64,
+    /// No monitor failed in the evaluation triggered by the challenge.
+    pub healthy: bool,
+}
+
+#[derive(Default)]
+struct ChallengeState {
+    /// Challenge waiting for evaluation, only the latest one is kept.
+    pending: Mutex<Option<u64>>,
+    wake: Condvar,
+    last_response: Mutex<Option<ChallengeResponse>>,
+}
+
+/// Handle to challenge the monitoring logic of a [`crate::HealthMonitor`], e.g. by a supervisor
+/// running challenge/response style aliveness checks in addition to periodic notifications.
+///
+/// A challenge triggers an immediate out-of-cycle evaluation of all monitors.
+/// Result is kept as [`Self::last_response`] and sent to the supervisor if its client supports responses.
+/// Launch manager daemon does not observe challenge responses, so they are reported as
+/// [`crate::InternalFault::NotifyFailure`] and have to be read from [`Self::last_response`].
+/// Challenges issued while monitoring is stopped are answered once it is started.
+#[derive(Clone, Default)]
+pub struct ChallengeHandle {
+    state: Arc<ChallengeState>,
+}
+
+impl ChallengeHandle {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request an immediate evaluation, challenge not answered yet is replaced.
+    ///
+    /// - `challenge_id` - identifier returned in the response.
+    pub fn challenge(&self, challenge_id: u64) {
+        *self.state.pending.lock().unwrap_or_else(|e| e.into_inner()) = Some(challenge_id);
+        self.state.wake.notify_all();
+    }
+
+    /// Response to the most recently answered challenge, [`None`] if no challenge was answered yet.
+    pub fn last_response(&self) -> Option<ChallengeResponse> {
+        *self.state.last_response.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Sleep for `timeout` unless a challenge is issued.
+    /// Returns the challenge to answer, if any.
+    pub(crate) fn wait(&self, timeout: Duration) -> Option<u64> {
+        let pending = self.state.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let (mut pending, _) = self
+            .state
+            .wake
+            .wait_timeout_while(pending, timeout, |pending| pending.is_none())
+            .unwrap_or_else(|e| e.into_inner());
+        pending.take()
+    }
+
+    /// Store the response to the challenge.
+    pub(crate) fn respond(&self, response: ChallengeResponse) {
+        *self.state.last_response.lock().unwrap_or_else(|e| e.into_inner()) = Some(response);
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::challenge::{ChallengeHandle, ChallengeResponse};
+    use core::time::Duration;
+    use std::time::Instant;
+
+    #[test]
+    fn challenge_handle_wait_timeout() {
+        let handle = ChallengeHandle::new();
+        let start = Instant::now();
+        assert_eq!(handle.wait(Duration::from_millis(20)), None);
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn challenge_handle_wait_challenged() {
+        let handle = ChallengeHandle::new();
+        let challenger = handle.clone();
+        let thread = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            challenger.challenge(1);
+            challenger.challenge(2);
+        });
+
+        let start = Instant::now();
+        let challenge_id = handle.wait(Duration::from_secs(5));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(challenge_id.is_some());
+        thread.join().unwrap();
+
+        handle.respond(ChallengeResponse {
+            challenge_id: 2,
+            healthy: true,
+        });
+        assert_eq!(
+            handle.last_response(),
+            Some(ChallengeResponse {
+                challenge_id: 2,
+                healthy: true
+            })
+        );
+    }
+}
@@ -0,0 +1,81 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::log::info;
+use core::time::Duration;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+/// Default multiple of the clock granularity monitored ranges must not be shorter than,
+/// see [`crate::HealthMonitorBuilder::with_clock_granularity_multiple`].
+pub const DEFAULT_CLOCK_GRANULARITY_MULTIPLE: u32 = 10;
+
+/// Number of clock ticks observed by the probe, the finest one is taken.
+const PROBE_TICKS: usize = 3;
+
+static GRANULARITY: OnceLock<Duration> = OnceLock::new();
+
+/// Granularity of the monotonic clock, probed once per process.
+pub(crate) fn clock_granularity() -> Duration {
+    *GRANULARITY.get_or_init(|| {
+        let granularity = probe_granularity();
+        info!("Monotonic clock granularity: {} ns.", granularity.as_nanos() as u64);
+        granularity
+    })
+}
+
+/// Measure the shortest observable step of [`Instant`].
+/// Takes up to [`PROBE_TICKS`] clock ticks, e.g. 30 ms on targets with 10 ms granularity.
+fn probe_granularity() -> Duration {
+    (0..PROBE_TICKS)
+        .map(|_| {
+            let start = Instant::now();
+            loop {
+                let step = Instant::now().duration_since(start);
+                if !step.is_zero() {
+                    break step;
+                }
+            }
+        })
+        .min()
+        .unwrap_or(Duration::ZERO)
+}
+
+/// Shortest range supported with `granularity` of the clock, ranges must be at least `multiple` ticks long.
+pub(crate) fn shortest_supported_range(granularity: Duration, multiple: u32) -> Duration {
+    granularity.saturating_mul(multiple)
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::clock_granularity::{clock_granularity, shortest_supported_range};
+    use core::time::Duration;
+
+    #[test]
+    fn clock_granularity_probed_once() {
+        let granularity = clock_granularity();
+        assert!(!granularity.is_zero());
+        assert!(granularity < Duration::from_millis(100));
+        assert_eq!(clock_granularity(), granularity);
+    }
+
+    #[test]
+    fn shortest_supported_range_multiple_of_granularity() {
+        assert_eq!(
+            shortest_supported_range(Duration::from_millis(10), 10),
+            Duration::from_millis(100)
+        );
+        assert_eq!(shortest_supported_range(Duration::from_millis(10), 0), Duration::ZERO);
+        assert_eq!(shortest_supported_range(Duration::MAX, 2), Duration::MAX);
+    }
+}
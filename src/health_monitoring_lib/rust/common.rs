@@ -11,16 +11,23 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use crate::deadline::DeadlineEvaluationError;
+use crate::artifact::ArtifactEvaluationError;
+use crate::deadline::{DeadlineEvaluationError, SchedulingStatistics};
 use crate::heartbeat::HeartbeatEvaluationError;
 use crate::log::ScoreDebug;
-use crate::tag::MonitorTag;
+use crate::tag::{DeadlineTag, MonitorTag, SourceTag};
+use crate::thread_attribution::ReporterThread;
 use core::hash::Hash;
 use core::time::Duration;
 use std::sync::Arc;
 use std::time::Instant;
 
 /// Range of accepted time.
+///
+/// Range is a closed interval `<min; max>` - both bounds are accepted.
+/// Evaluation is performed with millisecond resolution: `min` is rounded down and `max` is rounded up,
+/// so the evaluated range is never narrower than the requested one.
+/// Observed time points are rounded down to milliseconds.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct TimeRange {
     pub min: Duration,
@@ -38,6 +45,102 @@ impl TimeRange {
         Self::new_internal(min, max).expect("TimeRange min must be less than or equal to max")
     }
 
+    /// Create [`TimeRange`] with both bounds accepted.
+    /// Created range: `<min; max>`.
+    ///
+    /// Equivalent to [`TimeRange::new`], provided to make interval semantics explicit at call site.
+    ///
+    /// # Panics
+    ///
+    /// `max` cannot be smaller than `min`.
+    pub fn inclusive(min: Duration, max: Duration) -> Self {
+        Self::new(min, max)
+    }
+
+    /// Create [`TimeRange`] without lower bound, for "not slower than" supervision.
+    /// Created range: `<0; max>`.
+    pub fn at_most(max: Duration) -> Self {
+        Self {
+            min: Duration::ZERO,
+            max,
+        }
+    }
+
+    /// Create [`TimeRange`] without lower bound, for "must finish within" supervision.
+    /// Created range: `<0; max>`.
+    ///
+    /// Equivalent to [`TimeRange::at_most`], provided to make the open side explicit at call site.
+    pub fn up_to(max: Duration) -> Self {
+        Self::at_most(max)
+    }
+
+    /// Create [`TimeRange`] without upper bound, for "not faster than" supervision.
+    /// Created range: `<min; inf)`, represented with `max` set to [`Duration::MAX`].
+    /// Deadlines and heartbeats with such range are never late.
+    ///
+    /// # Note
+    ///
+    /// Custom deadlines require an upper bound, see [`crate::deadline::DeadlineMonitor::create_custom_deadline`].
+    pub fn at_least(min: Duration) -> Self {
+        Self {
+            min,
+            max: Duration::MAX,
+        }
+    }
+
+    /// Range has an upper bound, see [`TimeRange::at_least`].
+    pub fn has_upper_bound(&self) -> bool {
+        self.max != Duration::MAX
+    }
+
+    /// Extend range by `jitter` on both sides.
+    /// Created range: `<min - jitter; max + jitter>`, `min` saturates at zero.
+    pub fn with_tolerance(self, jitter: Duration) -> Self {
+        Self {
+            min: self.min.saturating_sub(jitter),
+            max: self.max.saturating_add(jitter),
+        }
+    }
+
+    /// Check if `elapsed` time is within the range.
+    /// Uses the same rules as monitor evaluation, see [`TimeRange`].
+    pub fn contains(&self, elapsed: Duration) -> bool {
+        self.position(duration_to_int(elapsed)) == RangePosition::Within
+    }
+
+    /// Lower bound in milliseconds, rounded down.
+    pub(crate) fn min_ms<T>(&self) -> T
+    where
+        T: TryFrom<u128>,
+        <T as TryFrom<u128>>::Error: core::fmt::Debug,
+    {
+        duration_to_int(self.min)
+    }
+
+    /// Upper bound in milliseconds, rounded up.
+    pub(crate) fn max_ms<T>(&self) -> T
+    where
+        T: TryFrom<u128>,
+        <T as TryFrom<u128>>::Error: core::fmt::Debug,
+    {
+        let max_ms = duration_to_int::<u128>(self.max) + u128::from(!self.max.subsec_nanos().is_multiple_of(1_000_000));
+        T::try_from(max_ms).expect("Duration is too big for the integer of this type")
+    }
+
+    /// Upper bound in milliseconds, rounded up, [`u64::MAX`] if the range has no upper bound.
+    pub(crate) fn max_ms_or_unbounded(&self) -> u64 {
+        if self.has_upper_bound() {
+            self.max_ms()
+        } else {
+            u64::MAX
+        }
+    }
+
+    /// Get position of `elapsed_ms` in relation to this range.
+    pub(crate) fn position(&self, elapsed_ms: u64) -> RangePosition {
+        range_position(self.min_ms(), self.max_ms_or_unbounded(), elapsed_ms)
+    }
+
     /// Create [`TimeRange`] with specified interval and tolerance.
     /// Created range: `<interval - tolerance.min; interval + tolerance.max>`.
     ///
@@ -50,7 +153,7 @@ impl TimeRange {
             "TimeRange interval must be greater than tolerance min"
         );
         let min = interval - tolerance.min;
-        let max = interval + tolerance.max;
+        let max = interval.saturating_add(tolerance.max);
         Self { min, max }
     }
 
@@ -65,6 +168,29 @@ impl TimeRange {
     }
 }
 
+/// Position of a time point in relation to a closed range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RangePosition {
+    /// Before range, contains distance to `min`.
+    Before(u64),
+    /// Within range, both bounds included.
+    Within,
+    /// After range, contains distance to `max`.
+    After(u64),
+}
+
+/// Get position of `value` in relation to `<min; max>` range.
+/// Single place defining boundary semantics for all monitors.
+pub(crate) fn range_position(min: u64, max: u64, value: u64) -> RangePosition {
+    if value < min {
+        RangePosition::Before(min - value)
+    } else if value > max {
+        RangePosition::After(value - max)
+    } else {
+        RangePosition::Within
+    }
+}
+
 /// A monitor with an evaluation handle available.
 pub(crate) trait Monitor {
     /// Get an evaluation handle for this monitor.
@@ -73,15 +199,37 @@ pub(crate) trait Monitor {
     ///
     /// This evaluation handle is intended to be called from a background thread periodically.
     fn get_eval_handle(&self) -> MonitorEvalHandle;
+
+    /// Create another instance of this monitor sharing its state.
+    /// Kept by the health monitor to hand the monitor out again after monitoring is stopped.
+    fn clone_shared(&self) -> Self
+    where
+        Self: Sized;
+
+    /// Check if `other` is an instance of this monitor, sharing its state.
+    fn shares_state(&self, other: &Self) -> bool
+    where
+        Self: Sized;
+
+    /// Enable or disable evaluation of this monitor, shared with all its evaluation handles.
+    /// Disabled monitor reports no errors, supervision is resumed from scratch once enabled.
+    fn set_enabled(&self, enabled: bool);
+
+    /// Evaluation of this monitor is enabled.
+    fn is_enabled(&self) -> bool;
 }
 
 /// Errors that can occur during monitor evaluation.
 /// Contains failing monitor type.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
-#[allow(dead_code)]
-pub(crate) enum MonitorEvaluationError {
+pub enum MonitorEvaluationError {
+    /// Deadline monitor failed.
     Deadline(DeadlineEvaluationError),
+    /// Heartbeat monitor failed.
     Heartbeat(HeartbeatEvaluationError),
+    /// Artifact freshness monitor failed.
+    Artifact(ArtifactEvaluationError),
+    /// Logic monitor failed.
     Logic,
 }
 
@@ -97,13 +245,93 @@ impl From<HeartbeatEvaluationError> for MonitorEvaluationError {
     }
 }
 
+impl From<ArtifactEvaluationError> for MonitorEvaluationError {
+    fn from(value: ArtifactEvaluationError) -> Self {
+        MonitorEvaluationError::Artifact(value)
+    }
+}
+
+/// Faults of the health monitoring library itself.
+/// Reported separately from [`MonitorEvaluationError`], which are violations of the supervised application.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum InternalFault {
+    /// Monitor evaluation handle could not be stored.
+    MonitorHandlePush,
+    /// Monitor state violates its invariants.
+    StateCorruption,
+    /// Evaluation took longer than the internal processing cycle.
+    WorkerOverrun,
+    /// Supervisor could not be notified about process liveness.
+    NotifyFailure,
+    /// Heartbeat was dropped, state update did not land within the allowed number of attempts.
+    HeartbeatUpdateExhausted,
+    /// Monitor was not evaluated, evaluation pass exceeded the internal processing cycle.
+    EvaluationSkipped,
+    /// Monotonic clock jumped forward, e.g. after system suspend.
+    /// Evaluation pass is skipped and all monitors are re-baselined instead of reporting violations.
+    ClockGapDetected,
+    /// Failure callback panicked, panic was caught and monitoring continued.
+    CallbackPanicked,
+    /// Failure callback returned later than its time budget, delaying monitor evaluation.
+    CallbackOverrun,
+}
+
 /// Trait for evaluating monitors and reporting errors to be used by HealthMonitor.
 pub(crate) trait MonitorEvaluator {
+    /// Tag of the evaluated monitor.
+    fn monitor_tag(&self) -> MonitorTag;
+
     /// Run monitor evaluation.
     ///
     /// - `hmon_starting_point` - starting point of all monitors.
-    /// - `on_error` - error handling, containing tag of failing object and error code.
-    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError));
+    /// - `on_error` - error handling, containing tag of failing object, error code and violation context.
+    ///   Reporting thread is only provided by monitors with thread attribution enabled.
+    fn evaluate(
+        &self,
+        hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    );
+
+    /// Report internal faults detected since the last call.
+    ///
+    /// - `on_fault` - fault handling, containing tag of faulty monitor and fault.
+    fn collect_internal_faults(&self, _on_fault: &mut dyn FnMut(&MonitorTag, InternalFault)) {}
+
+    /// Shift time references of the monitor after a clock gap, so time spent in the gap is not accounted.
+    ///
+    /// - `hmon_starting_point` - starting point of all monitors.
+    /// - `gap` - time elapsed over the expected evaluation interval.
+    fn rebaseline(&self, _hmon_starting_point: Instant, _gap: Duration) {}
+}
+
+/// Context of a violation reported by a monitor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ViolationContext {
+    /// Last thread which reported to the failing object, [`None`] if thread attribution is disabled.
+    pub(crate) reporter: Option<ReporterThread>,
+    /// Correlation ID the failing object was started with, [`None`] if not provided.
+    pub(crate) correlation_id: Option<u64>,
+    /// Scheduler statistics of the failing activation, [`None`] if not sampled.
+    pub(crate) scheduling: Option<SchedulingStatistics>,
+    /// Tag of the failing deadline, [`None`] for other monitors.
+    pub(crate) deadline_tag: Option<DeadlineTag>,
+    /// Tag of the silent heartbeat source, [`None`] for other violations.
+    pub(crate) source_tag: Option<SourceTag>,
+    /// Time outside of the range, [`None`] for errors without a range.
+    pub(crate) offset: Option<Duration>,
+}
+
+impl From<Option<ReporterThread>> for ViolationContext {
+    fn from(reporter: Option<ReporterThread>) -> Self {
+        Self {
+            reporter,
+            correlation_id: None,
+            scheduling: None,
+            deadline_tag: None,
+            source_tag: None,
+            offset: None,
+        }
+    }
 }
 
 /// Handle to a monitor evaluator, allowing for dynamic dispatch.
@@ -118,9 +346,25 @@ impl MonitorEvalHandle {
 }
 
 impl MonitorEvaluator for MonitorEvalHandle {
-    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError)) {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.inner.monitor_tag()
+    }
+
+    fn evaluate(
+        &self,
+        hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
         self.inner.evaluate(hmon_starting_point, on_error)
     }
+
+    fn collect_internal_faults(&self, on_fault: &mut dyn FnMut(&MonitorTag, InternalFault)) {
+        self.inner.collect_internal_faults(on_fault)
+    }
+
+    fn rebaseline(&self, hmon_starting_point: Instant, gap: Duration) {
+        self.inner.rebaseline(hmon_starting_point, gap)
+    }
 }
 
 /// Get offset between two time points.
@@ -146,7 +390,7 @@ where
 
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::{duration_to_int, time_offset, TimeRange};
+    use crate::common::{duration_to_int, range_position, time_offset, RangePosition, TimeRange};
     use core::time::Duration;
     use std::time::Instant;
 
@@ -184,6 +428,107 @@ mod tests {
         let _ = TimeRange::from_interval(interval, tolerance);
     }
 
+    #[test]
+    fn time_range_inclusive_same_as_new() {
+        let min = Duration::from_millis(100);
+        let max = Duration::from_millis(200);
+        assert_eq!(TimeRange::inclusive(min, max), TimeRange::new(min, max));
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeRange min must be less than or equal to max")]
+    fn time_range_inclusive_wrong_order() {
+        let _ = TimeRange::inclusive(Duration::from_millis(200), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn time_range_with_tolerance() {
+        let range = TimeRange::new(Duration::from_millis(100), Duration::from_millis(200))
+            .with_tolerance(Duration::from_millis(5));
+        assert_eq!(range.min, Duration::from_millis(95));
+        assert_eq!(range.max, Duration::from_millis(205));
+    }
+
+    #[test]
+    fn time_range_with_tolerance_saturates_min() {
+        let range = TimeRange::new(Duration::from_millis(3), Duration::from_millis(10))
+            .with_tolerance(Duration::from_millis(5));
+        assert_eq!(range.min, Duration::ZERO);
+        assert_eq!(range.max, Duration::from_millis(15));
+    }
+
+    #[test]
+    fn time_range_at_most() {
+        let range = TimeRange::at_most(Duration::from_millis(200));
+        assert!(range.has_upper_bound());
+        assert!(range.contains(Duration::ZERO));
+        assert!(range.contains(Duration::from_millis(200)));
+        assert!(!range.contains(Duration::from_millis(201)));
+        assert_eq!(TimeRange::up_to(Duration::from_millis(200)), range);
+    }
+
+    #[test]
+    fn time_range_at_least() {
+        let range = TimeRange::at_least(Duration::from_millis(100));
+        assert!(!range.has_upper_bound());
+        assert_eq!(range.max_ms_or_unbounded(), u64::MAX);
+        assert!(!range.contains(Duration::from_millis(99)));
+        assert!(range.contains(Duration::from_millis(100)));
+        assert!(range.contains(Duration::from_secs(u64::MAX / 1000)));
+    }
+
+    #[test]
+    fn time_range_at_least_stays_unbounded() {
+        let range = TimeRange::at_least(Duration::from_millis(100)).with_tolerance(Duration::from_millis(5));
+        assert_eq!(range.min, Duration::from_millis(95));
+        assert!(!range.has_upper_bound());
+
+        let range = TimeRange::from_interval(Duration::from_millis(100), TimeRange::at_least(Duration::ZERO));
+        assert_eq!(range.min, Duration::from_millis(100));
+        assert!(!range.has_upper_bound());
+    }
+
+    #[test]
+    fn time_range_contains_boundaries() {
+        let range = TimeRange::inclusive(Duration::from_millis(100), Duration::from_millis(200));
+        assert!(!range.contains(Duration::from_millis(99)));
+        assert!(range.contains(Duration::from_millis(100)));
+        assert!(range.contains(Duration::from_millis(200)));
+        assert!(!range.contains(Duration::from_millis(201)));
+        // Observed time is rounded down to milliseconds.
+        assert!(!range.contains(Duration::from_micros(99_999)));
+        assert!(range.contains(Duration::from_micros(200_999)));
+    }
+
+    #[test]
+    fn time_range_contains_with_tolerance_boundaries() {
+        let range = TimeRange::inclusive(Duration::from_millis(100), Duration::from_millis(200))
+            .with_tolerance(Duration::from_millis(2));
+        assert!(!range.contains(Duration::from_millis(97)));
+        assert!(range.contains(Duration::from_millis(98)));
+        assert!(range.contains(Duration::from_millis(202)));
+        assert!(!range.contains(Duration::from_millis(203)));
+    }
+
+    #[test]
+    fn time_range_sub_millisecond_bounds_never_narrowed() {
+        let range = TimeRange::inclusive(Duration::from_micros(100_900), Duration::from_micros(200_100));
+        assert_eq!(range.min_ms::<u64>(), 100);
+        assert_eq!(range.max_ms::<u64>(), 201);
+        assert!(range.contains(Duration::from_millis(100)));
+        assert!(range.contains(Duration::from_millis(201)));
+        assert!(!range.contains(Duration::from_millis(202)));
+    }
+
+    #[test]
+    fn range_position_boundaries() {
+        assert_eq!(range_position(10, 20, 9), RangePosition::Before(1));
+        assert_eq!(range_position(10, 20, 10), RangePosition::Within);
+        assert_eq!(range_position(10, 20, 20), RangePosition::Within);
+        assert_eq!(range_position(10, 20, 21), RangePosition::After(1));
+        assert_eq!(range_position(0, 0, 0), RangePosition::Within);
+    }
+
     #[test]
     fn time_offset_valid() {
         let monitor_starting_point = Instant::now();
@@ -0,0 +1,316 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::artifact::ArtifactFreshnessMonitorBuilder;
+use crate::common::TimeRange;
+use crate::deadline::DeadlineMonitorBuilder;
+use crate::health_monitor_config::HealthMonitorConfig;
+use crate::log::ScoreDebug;
+use crate::tag::MonitorTag;
+use core::time::Duration;
+
+/// FNV-1a offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Digest of the health monitor configuration.
+/// Uses FNV-1a, so the same configuration produces the same digest across processes, builds and targets.
+/// Callers must write items in a stable order.
+pub(crate) struct ConfigDigest {
+    state: u64,
+}
+
+impl ConfigDigest {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= u64::from(*byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    /// String is length-prefixed, so adjacent strings cannot collide by shifting bytes between them.
+    pub(crate) fn write_str(&mut self, value: &str) {
+        self.write_u64(value.len() as u64);
+        self.write_bytes(value.as_bytes());
+    }
+
+    pub(crate) fn write_duration(&mut self, value: Duration) {
+        self.write_u64(value.as_secs());
+        self.write_u64(u64::from(value.subsec_nanos()));
+    }
+
+    pub(crate) fn write_time_range(&mut self, value: &TimeRange) {
+        self.write_duration(value.min);
+        self.write_duration(value.max);
+    }
+
+    pub(crate) fn write_option<T>(&mut self, value: Option<T>, write: impl FnOnce(&mut Self, T)) {
+        match value {
+            Some(value) => {
+                self.write_u64(1);
+                write(self, value);
+            },
+            None => self.write_u64(0),
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// Summary of the health monitor configuration.
+/// Logged once the [`crate::HealthMonitor`] is built, so field logs carry enough context to interpret violations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ScoreDebug)]
+pub struct ConfigSummary {
+    /// Number of deadline monitors.
+    pub deadline_monitors: usize,
+    /// Number of deadlines across all deadline monitors.
+    pub deadlines: usize,
+    /// Number of heartbeat monitors.
+    pub heartbeat_monitors: usize,
+    /// Number of artifact freshness monitors.
+    pub artifact_freshness_monitors: usize,
+    /// Number of artifacts across all artifact freshness monitors.
+    pub artifacts: usize,
+    /// Number of monitors with thread attribution enabled.
+    pub thread_attribution_monitors: usize,
+    /// Number of supervisor notification channels.
+    pub notification_channels: usize,
+    /// Interval between supervisor API notifications in milliseconds.
+    pub supervisor_api_cycle_ms: u64,
+    /// Interval between evaluations in milliseconds.
+    pub internal_processing_cycle_ms: u64,
+    /// Number of violations kept in the history.
+    pub violation_history_capacity: usize,
+    /// Number of internal faults kept in the history.
+    pub internal_fault_history_capacity: usize,
+    /// Digest of the complete configuration, including monitor and deadline tags and ranges.
+    /// Equal digests indicate equal configurations.
+    pub digest: u64,
+}
+
+/// Summarize the health monitor configuration, items are written into the digest in order of their tags.
+pub(crate) fn summarize_health_monitor_config(config: &HealthMonitorConfig) -> ConfigSummary {
+    let mut digest = ConfigDigest::new();
+    digest.write_duration(config.supervisor_api_cycle);
+    digest.write_duration(config.internal_processing_cycle);
+    digest.write_option(config.clock_gap_threshold, ConfigDigest::write_duration);
+    digest.write_duration(config.failure_callback_budget);
+    digest.write_option(config.worker_stall_threshold, ConfigDigest::write_duration);
+    digest.write_u64(u64::from(config.panic_reporting));
+    digest.write_option(config.startup_grace, ConfigDigest::write_duration);
+    digest.write_option(config.armed_start.as_ref(), |digest, armed_start| {
+        armed_start.write_digest(digest)
+    });
+    digest.write_option(config.health_trajectory.as_ref(), |digest, health_trajectory| {
+        health_trajectory.write_digest(digest)
+    });
+    digest.write_option(config.supervisor_identity.as_deref(), ConfigDigest::write_str);
+    digest.write_option(config.fail_fast.as_ref(), |digest, fail_fast| {
+        fail_fast.write_digest(digest)
+    });
+    config.resource_budget.write_digest(&mut digest);
+
+    let mut deadline_monitor_builders: Vec<_> = config.deadline_monitor_builders.iter().collect();
+    deadline_monitor_builders.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+    digest.write_u64(deadline_monitor_builders.len() as u64);
+    for (monitor_tag, builder) in deadline_monitor_builders {
+        digest.write_str(monitor_tag.as_str());
+        builder.write_digest(&mut digest);
+    }
+
+    let mut heartbeat_monitor_builders: Vec<_> = config.heartbeat_monitor_builders.iter().collect();
+    heartbeat_monitor_builders.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+    digest.write_u64(heartbeat_monitor_builders.len() as u64);
+    for (monitor_tag, builder) in heartbeat_monitor_builders {
+        digest.write_str(monitor_tag.as_str());
+        builder.write_digest(&mut digest);
+    }
+
+    let mut early_monitors: Vec<_> = config
+        .early_supervision
+        .iter()
+        .flat_map(|early_supervision| {
+            early_supervision
+                .deadline_monitors()
+                .map(|(tag, (_, config))| (tag, config))
+                .chain(
+                    early_supervision
+                        .heartbeat_monitors()
+                        .map(|(tag, (_, config))| (tag, config)),
+                )
+        })
+        .collect();
+    early_monitors.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+    digest.write_u64(early_monitors.len() as u64);
+    for (monitor_tag, config) in early_monitors.iter() {
+        digest.write_str(monitor_tag.as_str());
+        digest.write_u64(config.digest);
+    }
+
+    let mut artifact_freshness_monitor_builders: Vec<_> = config.artifact_freshness_monitor_builders.iter().collect();
+    artifact_freshness_monitor_builders.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+    digest.write_u64(artifact_freshness_monitor_builders.len() as u64);
+    for (monitor_tag, builder) in artifact_freshness_monitor_builders {
+        digest.write_str(monitor_tag.as_str());
+        builder.write_digest(&mut digest);
+    }
+
+    let mut notification_channels: Vec<_> = config.notification_channels.iter().collect();
+    notification_channels.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+    digest.write_u64(notification_channels.len() as u64);
+    for (channel_tag, monitor_tags) in notification_channels {
+        digest.write_str(channel_tag.as_str());
+        let mut monitor_tags: Vec<_> = monitor_tags.iter().map(MonitorTag::as_str).collect();
+        monitor_tags.sort_unstable();
+        digest.write_u64(monitor_tags.len() as u64);
+        for monitor_tag in monitor_tags {
+            digest.write_str(monitor_tag);
+        }
+    }
+
+    let mut monitor_groups: Vec<_> = config.monitor_groups.iter().collect();
+    monitor_groups.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+    digest.write_u64(monitor_groups.len() as u64);
+    for (group_tag, group) in monitor_groups {
+        digest.write_str(group_tag.as_str());
+        group.write_digest(&mut digest);
+    }
+
+    digest.write_option(config.failure_tolerance, |digest, tolerance| {
+        tolerance.write_digest(digest)
+    });
+    digest.write_option(config.health_forwarder.as_ref(), |digest, health_forwarder| {
+        health_forwarder.write_digest(digest)
+    });
+    config.recovery_policy.write_digest(&mut digest);
+    digest.write_option(config.update_mode.as_ref(), |digest, update_mode| {
+        update_mode.write_digest(digest)
+    });
+    let mut monitor_failure_tolerances: Vec<_> = config.monitor_failure_tolerances.iter().collect();
+    monitor_failure_tolerances.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+    digest.write_u64(monitor_failure_tolerances.len() as u64);
+    for (monitor_tag, tolerance) in monitor_failure_tolerances {
+        digest.write_str(monitor_tag.as_str());
+        tolerance.write_digest(&mut digest);
+    }
+
+    let thread_attribution_monitors = config
+        .deadline_monitor_builders
+        .values()
+        .filter(|builder| builder.has_thread_attribution())
+        .count()
+        + config
+            .heartbeat_monitor_builders
+            .values()
+            .filter(|builder| builder.has_thread_attribution())
+            .count()
+        + early_monitors
+            .iter()
+            .filter(|(_, config)| config.thread_attribution)
+            .count();
+    let (early_deadline_monitors, early_heartbeat_monitors) =
+        config.early_supervision.as_ref().map_or((0, 0), |early_supervision| {
+            (
+                early_supervision.deadline_monitors().count(),
+                early_supervision.heartbeat_monitors().count(),
+            )
+        });
+
+    ConfigSummary {
+        deadline_monitors: config.deadline_monitor_builders.len() + early_deadline_monitors,
+        deadlines: config
+            .deadline_monitor_builders
+            .values()
+            .map(DeadlineMonitorBuilder::num_deadlines)
+            .chain(early_monitors.iter().map(|(_, config)| config.deadlines))
+            .sum(),
+        heartbeat_monitors: config.heartbeat_monitor_builders.len() + early_heartbeat_monitors,
+        artifact_freshness_monitors: config.artifact_freshness_monitor_builders.len(),
+        artifacts: config
+            .artifact_freshness_monitor_builders
+            .values()
+            .map(ArtifactFreshnessMonitorBuilder::num_artifacts)
+            .sum(),
+        thread_attribution_monitors,
+        notification_channels: config.notification_channels.len(),
+        supervisor_api_cycle_ms: config.supervisor_api_cycle.as_millis() as u64,
+        internal_processing_cycle_ms: config.internal_processing_cycle.as_millis() as u64,
+        violation_history_capacity: config.resource_budget.violation_history_capacity(),
+        internal_fault_history_capacity: config.resource_budget.internal_fault_history_capacity(),
+        digest: digest.finish(),
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::TimeRange;
+    use crate::config_summary::ConfigDigest;
+    use core::time::Duration;
+
+    #[test]
+    fn config_digest_fnv1a() {
+        // Reference values of 64-bit FNV-1a.
+        let mut digest = ConfigDigest::new();
+        assert_eq!(digest.finish(), 0xcbf2_9ce4_8422_2325);
+        digest.write_bytes(b"a");
+        assert_eq!(digest.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn config_digest_str_boundaries() {
+        let mut digest1 = ConfigDigest::new();
+        digest1.write_str("ab");
+        digest1.write_str("c");
+        let mut digest2 = ConfigDigest::new();
+        digest2.write_str("a");
+        digest2.write_str("bc");
+        assert_ne!(digest1.finish(), digest2.finish());
+    }
+
+    #[test]
+    fn config_digest_time_range() {
+        let range = TimeRange::new(Duration::from_millis(100), Duration::from_millis(200));
+        let mut digest1 = ConfigDigest::new();
+        digest1.write_time_range(&range);
+        let mut digest2 = ConfigDigest::new();
+        digest2.write_time_range(&range);
+        assert_eq!(digest1.finish(), digest2.finish());
+
+        let mut digest3 = ConfigDigest::new();
+        digest3.write_time_range(&range.with_tolerance(Duration::from_micros(1)));
+        assert_ne!(digest1.finish(), digest3.finish());
+    }
+
+    #[test]
+    fn config_digest_option() {
+        let mut digest1 = ConfigDigest::new();
+        digest1.write_option(None::<u64>, ConfigDigest::write_u64);
+        let mut digest2 = ConfigDigest::new();
+        digest2.write_option(Some(0), ConfigDigest::write_u64);
+        assert_ne!(digest1.finish(), digest2.finish());
+    }
+}
@@ -0,0 +1,222 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::log::warn;
+use crate::tag::DeadlineTag;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Execution budget of a window, see [`crate::deadline::DeadlineMonitorBuilder::add_budget`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct BudgetConfig {
+    pub(super) budget: Duration,
+    pub(super) window: Duration,
+}
+
+impl BudgetConfig {
+    /// Budget is not zero and fits into a non-empty window.
+    pub(super) fn is_valid(&self) -> bool {
+        !self.budget.is_zero() && self.budget <= self.window
+    }
+}
+
+// Usage layout (u64) = | window index: u32 | time used in the window, microseconds: u32 |
+const USED_MASK: u64 = u32::MAX as u64;
+
+/// Time accumulated by sections of a budget in the current window.
+///
+/// Multiple writers - sections running in any thread, single reader - evaluation of the monitor.
+pub(super) struct BudgetSlot {
+    budget_us: u64,
+    window_us: u64,
+    usage: AtomicU64,
+    /// Windows in which the budget was exhausted, not yet reported.
+    exhausted: AtomicU64,
+}
+
+impl BudgetSlot {
+    pub(super) fn new(config: BudgetConfig) -> Self {
+        Self {
+            budget_us: config.budget.as_micros() as u64,
+            window_us: (config.window.as_micros() as u64).max(1),
+            usage: AtomicU64::new(0),
+            exhausted: AtomicU64::new(0),
+        }
+    }
+
+    /// Index of the window containing `now`, wraps around.
+    fn window_index(&self, now: Duration) -> u64 {
+        (now.as_micros() as u64 / self.window_us) & USED_MASK
+    }
+
+    /// Add `elapsed` to the window containing `now`, time of a section spanning windows is accounted to the last one.
+    /// Returns `true` if the budget of the window got exhausted by this section.
+    pub(super) fn record(&self, now: Duration, elapsed: Duration) -> bool {
+        let window_index = self.window_index(now);
+        let elapsed_us = (elapsed.as_micros() as u64).min(USED_MASK);
+        let mut used_before = 0;
+        let mut used_after = 0;
+        let _ = self.usage.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |usage| {
+            used_before = if usage >> u32::BITS == window_index {
+                usage & USED_MASK
+            } else {
+                0
+            };
+            used_after = (used_before + elapsed_us).min(USED_MASK);
+            Some((window_index << u32::BITS) | used_after)
+        });
+
+        let is_exhausted = used_before <= self.budget_us && used_after > self.budget_us;
+        if is_exhausted {
+            self.exhausted.fetch_add(1, Ordering::Relaxed);
+        }
+        is_exhausted
+    }
+
+    /// Time used in the window containing `now`.
+    pub(super) fn used(&self, now: Duration) -> Duration {
+        let usage = self.usage.load(Ordering::Relaxed);
+        if usage >> u32::BITS == self.window_index(now) {
+            Duration::from_micros(usage & USED_MASK)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Take number of windows in which the budget was exhausted since the last call.
+    pub(super) fn take_exhausted(&self) -> u64 {
+        self.exhausted.swap(0, Ordering::Relaxed)
+    }
+}
+
+/// Execution budget shared by multiple sections, e.g. all callbacks of a cycle together.
+/// Time measured by sections is accumulated per window and a violation is reported
+/// once per window in which the budget is exhausted.
+/// Acquired with [`crate::deadline::DeadlineMonitor::get_budget`], can be cloned and used from multiple threads.
+///
+/// # Note
+///
+/// Time is accounted when a section ends, a section which never ends is not reported. Supervise it with a deadline.
+#[derive(Clone)]
+pub struct BudgetMonitor {
+    budget_tag: DeadlineTag,
+    slot: Arc<BudgetSlot>,
+    monitor_starting_point: Instant,
+}
+
+impl BudgetMonitor {
+    pub(super) fn new(budget_tag: DeadlineTag, slot: Arc<BudgetSlot>, monitor_starting_point: Instant) -> Self {
+        Self {
+            budget_tag,
+            slot,
+            monitor_starting_point,
+        }
+    }
+
+    /// Starts a section accounted to the budget when the returned guard is dropped.
+    pub fn start(&self) -> BudgetSection<'_> {
+        BudgetSection {
+            monitor: self,
+            started: Instant::now(),
+        }
+    }
+
+    /// Runs `f` as a section accounted to the budget.
+    pub fn measure<R>(&self, f: impl FnOnce() -> R) -> R {
+        let _section = self.start();
+        f()
+    }
+
+    /// Time used in the current window.
+    pub fn used(&self) -> Duration {
+        self.slot.used(self.monitor_starting_point.elapsed())
+    }
+
+    fn record(&self, elapsed: Duration) {
+        if self.slot.record(self.monitor_starting_point.elapsed(), elapsed) {
+            warn!(
+                "Budget {:?} exhausted by section of {} us",
+                self.budget_tag,
+                elapsed.as_micros() as u64
+            );
+        }
+    }
+}
+
+impl core::fmt::Debug for BudgetMonitor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BudgetMonitor")
+            .field("budget_tag", &self.budget_tag)
+            .finish()
+    }
+}
+
+/// A running section of a [`BudgetMonitor`]. When dropped, its duration is accounted to the budget.
+pub struct BudgetSection<'a> {
+    monitor: &'a BudgetMonitor,
+    started: Instant,
+}
+
+impl BudgetSection<'_> {
+    /// Ends the section. This is equivalent to dropping the section.
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for BudgetSection<'_> {
+    fn drop(&mut self) {
+        self.monitor.record(self.started.elapsed());
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::budget_monitor::{BudgetConfig, BudgetSlot};
+    use core::time::Duration;
+
+    fn config(budget_ms: u64, window_ms: u64) -> BudgetConfig {
+        BudgetConfig {
+            budget: Duration::from_millis(budget_ms),
+            window: Duration::from_millis(window_ms),
+        }
+    }
+
+    #[test]
+    fn budget_config_is_valid() {
+        assert!(config(8, 100).is_valid());
+        assert!(config(100, 100).is_valid());
+        assert!(!config(0, 100).is_valid());
+        assert!(!config(8, 0).is_valid());
+        assert!(!config(101, 100).is_valid());
+    }
+
+    #[test]
+    fn budget_slot_exhausted_once_per_window() {
+        let slot = BudgetSlot::new(config(8, 100));
+        assert!(!slot.record(Duration::from_millis(10), Duration::from_millis(5)));
+        assert!(slot.record(Duration::from_millis(20), Duration::from_millis(5)));
+        assert!(!slot.record(Duration::from_millis(30), Duration::from_millis(5)));
+        assert_eq!(slot.used(Duration::from_millis(40)), Duration::from_millis(15));
+        assert_eq!(slot.take_exhausted(), 1);
+        assert_eq!(slot.take_exhausted(), 0);
+
+        // Usage starts over in the next window.
+        assert_eq!(slot.used(Duration::from_millis(110)), Duration::ZERO);
+        assert!(!slot.record(Duration::from_millis(110), Duration::from_millis(8)));
+        assert!(slot.record(Duration::from_millis(250), Duration::from_millis(9)));
+        assert_eq!(slot.take_exhausted(), 1);
+    }
+}
@@ -0,0 +1,148 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::TimeRange;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use std::sync::OnceLock;
+
+/// What to do with the range suggested at the end of calibration.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CalibrationPolicy {
+    /// Only log and expose the suggested range, configured range stays in use.
+    Suggest,
+    /// Use the suggested range for all activations after calibration.
+    AutoApply,
+}
+
+/// Calibration settings of a deadline monitor.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CalibrationConfig {
+    pub(super) activations: u32,
+    pub(super) margin_percent: u32,
+    pub(super) policy: CalibrationPolicy,
+}
+
+/// Durations measured during calibration of a single deadline.
+///
+/// Concurrent instances of a deadline record at once, each recording claims a sample of its own.
+pub(super) struct CalibrationSlot {
+    samples: Box<[AtomicU32]>,
+    claimed: AtomicU32,
+    recorded: AtomicU32,
+    suggestion: OnceLock<TimeRange>,
+}
+
+impl CalibrationSlot {
+    pub(super) fn new(activations: u32) -> Self {
+        Self {
+            samples: (0..activations).map(|_| AtomicU32::new(0)).collect(),
+            claimed: AtomicU32::new(0),
+            recorded: AtomicU32::new(0),
+            suggestion: OnceLock::new(),
+        }
+    }
+
+    /// Deadline is still being calibrated.
+    pub(super) fn is_calibrating(&self) -> bool {
+        (self.recorded.load(Ordering::Acquire) as usize) < self.samples.len()
+    }
+
+    /// Record duration of a single activation.
+    /// Returns suggested range once the last sample is recorded.
+    pub(super) fn record(&self, elapsed_ms: u32, margin_percent: u32) -> Option<TimeRange> {
+        let index = self.claimed.fetch_add(1, Ordering::Relaxed) as usize;
+        let sample = self.samples.get(index)?;
+        sample.store(elapsed_ms, Ordering::Relaxed);
+        // Suggestion is computed by the recording completing the last sample.
+        let recorded = self.recorded.fetch_add(1, Ordering::AcqRel) as usize + 1;
+        if recorded < self.samples.len() {
+            return None;
+        }
+
+        let range = self.compute_suggestion(margin_percent);
+        let _ = self.suggestion.set(range);
+        Some(range)
+    }
+
+    /// Range suggested after calibration, [`None`] until calibration is finished.
+    pub(super) fn suggestion(&self) -> Option<TimeRange> {
+        self.suggestion.get().copied()
+    }
+
+    /// Range from the shortest sample reduced by margin up to p99 extended by margin.
+    fn compute_suggestion(&self, margin_percent: u32) -> TimeRange {
+        let mut samples: Vec<u64> = self
+            .samples
+            .iter()
+            .map(|sample| u64::from(sample.load(Ordering::Relaxed)))
+            .collect();
+        samples.sort_unstable();
+
+        let p99_index = (samples.len() * 99).div_ceil(100).saturating_sub(1);
+        let p99 = samples[p99_index];
+        let margin = u64::from(margin_percent);
+        let max_ms = (p99 * (100 + margin)).div_ceil(100);
+        let min_ms = samples[0] * 100u64.saturating_sub(margin) / 100;
+
+        TimeRange::new(Duration::from_millis(min_ms), Duration::from_millis(max_ms))
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::TimeRange;
+    use crate::deadline::calibration::CalibrationSlot;
+    use core::time::Duration;
+
+    #[test]
+    fn calibration_slot_suggests_after_last_sample() {
+        let slot = CalibrationSlot::new(3);
+        assert!(slot.is_calibrating());
+        assert!(slot.record(10, 0).is_none());
+        assert!(slot.record(30, 0).is_none());
+        assert!(slot.suggestion().is_none());
+
+        let range = slot.record(20, 0).unwrap();
+        assert_eq!(
+            range,
+            TimeRange::new(Duration::from_millis(10), Duration::from_millis(30))
+        );
+        assert_eq!(slot.suggestion(), Some(range));
+        assert!(!slot.is_calibrating());
+    }
+
+    #[test]
+    fn calibration_slot_applies_margin_to_p99() {
+        let slot = CalibrationSlot::new(200);
+        for elapsed in 1..=200 {
+            let _ = slot.record(elapsed * 10, 10);
+        }
+
+        // p99 of 200 samples is the 198th sample.
+        let range = slot.suggestion().unwrap();
+        assert_eq!(range.max, Duration::from_millis(1980 * 110 / 100));
+        assert_eq!(range.min, Duration::from_millis(9));
+    }
+
+    #[test]
+    fn calibration_slot_ignores_samples_after_calibration() {
+        let slot = CalibrationSlot::new(1);
+        assert!(slot.record(5, 0).is_some());
+        assert!(slot.record(500, 0).is_none());
+        assert_eq!(
+            slot.suggestion(),
+            Some(TimeRange::new(Duration::from_millis(5), Duration::from_millis(5)))
+        );
+    }
+}
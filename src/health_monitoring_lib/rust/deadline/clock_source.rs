@@ -0,0 +1,174 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::duration_to_int;
+use crate::deadline::sys;
+use crate::log::warn;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Provider of an external time base, e.g. network time synchronized over PTP.
+///
+/// Time must not go backwards, time before the monitor starting point is read as the starting point.
+pub trait TimeProvider: Send + Sync {
+    /// Current time of the time base in nanoseconds.
+    fn now_ns(&self) -> u64;
+}
+
+/// Clock a deadline monitor measures deadlines with, see [`super::DeadlineMonitorBuilder::with_clock_source`].
+/// Deadline owners and the evaluation of the monitor read the same clock.
+#[derive(Clone, Default)]
+pub enum ClockSource {
+    /// Monotonic clock of [`Instant`].
+    #[default]
+    Monotonic,
+    /// Coarse monotonic clock (`CLOCK_MONOTONIC_COARSE`), cheaper to read, with a resolution of a scheduler tick.
+    /// Platforms without a coarse clock use [`Self::Monotonic`] instead.
+    MonotonicCoarse,
+    /// External time base.
+    External(Arc<dyn TimeProvider>),
+}
+
+impl core::fmt::Debug for ClockSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Monotonic => f.write_str("Monotonic"),
+            Self::MonotonicCoarse => f.write_str("MonotonicCoarse"),
+            Self::External(_) => f.write_str("External"),
+        }
+    }
+}
+
+impl ClockSource {
+    /// Stable identifier of the kind of the clock source, used in configuration digests.
+    pub(super) fn kind(&self) -> u64 {
+        match self {
+            Self::Monotonic => 0,
+            Self::MonotonicCoarse => 1,
+            Self::External(_) => 2,
+        }
+    }
+}
+
+/// Clock of a deadline monitor, counting milliseconds since the monitor starting point.
+pub(super) struct MonitorClock {
+    source: ClockSource,
+    /// Starting point of the monotonic clock.
+    starting_point: Instant,
+    /// Starting point of coarse and external clocks, in nanoseconds.
+    starting_point_ns: u64,
+}
+
+impl MonitorClock {
+    pub(super) fn new(source: ClockSource) -> Self {
+        let source = match source {
+            ClockSource::MonotonicCoarse if !sys::COARSE_CLOCK_SUPPORTED => {
+                warn!("Coarse monotonic clock is not supported on this platform, using monotonic clock instead.");
+                ClockSource::Monotonic
+            },
+            ClockSource::MonotonicCoarse if sys::read_coarse_clock_ns().is_none() => {
+                warn!("Coarse monotonic clock is not readable, using monotonic clock instead.");
+                ClockSource::Monotonic
+            },
+            source => source,
+        };
+        let starting_point_ns = Self::read_ns(&source).unwrap_or(0);
+        Self {
+            source,
+            starting_point: Instant::now(),
+            starting_point_ns,
+        }
+    }
+
+    /// Monotonic starting point of the monitor.
+    pub(super) fn starting_point(&self) -> Instant {
+        self.starting_point
+    }
+
+    /// Milliseconds elapsed since the monitor starting point.
+    /// Falls back to the monotonic clock if the coarse clock cannot be read, instead of reporting time zero.
+    pub(super) fn now_ms(&self) -> u64 {
+        match Self::read_ns(&self.source) {
+            Some(now_ns) => now_ns.saturating_sub(self.starting_point_ns) / 1_000_000,
+            None => duration_to_int(self.starting_point.elapsed()),
+        }
+    }
+
+    /// Milliseconds since the monitor starting point of a monotonic `instant` in the past,
+    /// [`None`] if it is not within the lifetime of the monitor.
+    pub(super) fn instant_to_ms(&self, instant: Instant) -> Option<u64> {
+        let now = Instant::now();
+        if instant > now {
+            return None;
+        }
+        match self.source {
+            ClockSource::Monotonic => instant.checked_duration_since(self.starting_point).map(duration_to_int),
+            _ => self.now_ms().checked_sub(duration_to_int(now - instant)),
+        }
+    }
+
+    /// Read coarse and external clocks in nanoseconds, [`None`] for the monotonic clock or an unreadable clock.
+    fn read_ns(source: &ClockSource) -> Option<u64> {
+        match source {
+            ClockSource::Monotonic => None,
+            ClockSource::MonotonicCoarse => sys::read_coarse_clock_ns(),
+            ClockSource::External(provider) => Some(provider.now_ns()),
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::clock_source::{ClockSource, MonitorClock, TimeProvider};
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use core::time::Duration;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    #[derive(Default)]
+    struct ManualTime(AtomicU64);
+
+    impl TimeProvider for ManualTime {
+        fn now_ns(&self) -> u64 {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    #[test]
+    fn monitor_clock_external_source() {
+        let time = Arc::new(ManualTime::default());
+        time.0.store(5_000_000_000, Ordering::Relaxed);
+        let clock = MonitorClock::new(ClockSource::External(time.clone()));
+        assert_eq!(clock.now_ms(), 0);
+
+        time.0.store(5_042_000_000, Ordering::Relaxed);
+        assert_eq!(clock.now_ms(), 42);
+
+        // Steps back of the time base do not go before the starting point.
+        time.0.store(1_000_000_000, Ordering::Relaxed);
+        assert_eq!(clock.now_ms(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn monitor_clock_monotonic_sources() {
+        for source in [ClockSource::Monotonic, ClockSource::MonotonicCoarse] {
+            let clock = MonitorClock::new(source);
+            std::thread::sleep(Duration::from_millis(50));
+            let now_ms = clock.now_ms();
+            assert!((30..1000).contains(&now_ms), "Unexpected time {now_ms}");
+            assert!(clock.instant_to_ms(Instant::now() + Duration::from_secs(1)).is_none());
+            assert!(clock.instant_to_ms(Instant::now()).is_some_and(|ms| ms >= now_ms));
+        }
+    }
+}
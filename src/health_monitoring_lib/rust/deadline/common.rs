@@ -12,11 +12,26 @@
 // *******************************************************************************
 use core::{
     ops::Deref,
-    sync::atomic::{AtomicBool, Ordering},
+    sync::atomic::{AtomicU8, Ordering},
 };
 
+use crate::log::ScoreDebug;
 use crate::TimeRange;
 
+/// Side of the language boundary currently holding a deadline.
+/// Names and values must match `score::hm::deadline::DeadlineHolder` of the C++ bindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+#[repr(u8)]
+pub enum DeadlineHolder {
+    /// Deadline acquired with [`crate::deadline::DeadlineMonitor::get_deadline`].
+    Rust = 1,
+    /// Deadline acquired through the FFI (e.g. C++ bindings).
+    Ffi = 2,
+}
+
+/// Value of [`DeadlineTemplate::holder`] when deadline is not acquired.
+const NO_HOLDER: u8 = 0;
+
 /// Index type for identifying states associated with deadlines.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(super) struct StateIndex(usize);
@@ -36,38 +51,59 @@ impl Deref for StateIndex {
 }
 
 /// Template for a deadline, managing its range and usage state.
+///
+/// Each instance of the deadline has its own state, instances occupy consecutive states
+/// starting from `assigned_state_index`.
 pub(super) struct DeadlineTemplate {
     range: TimeRange,
-    is_in_use: AtomicBool,
+    /// Current holders of the deadline instances, [`NO_HOLDER`] if not in use.
+    holders: Box<[AtomicU8]>,
     pub assigned_state_index: StateIndex,
+    /// Index of data shared by all instances of the deadline, e.g. statistics.
+    pub template_index: usize,
 }
 
 impl DeadlineTemplate {
-    pub(super) fn new(range: TimeRange, state_index: StateIndex) -> Self {
+    pub(super) fn new(range: TimeRange, state_index: StateIndex, template_index: usize, max_instances: usize) -> Self {
         Self {
             range,
-            is_in_use: AtomicBool::new(false),
+            holders: (0..max_instances).map(|_| AtomicU8::new(NO_HOLDER)).collect(),
             assigned_state_index: state_index,
+            template_index,
         }
     }
 
-    /// Attempts to acquire the deadline for use.
-    /// Returns Some(TimeRange) if successful, None if already in use.
-    pub(super) fn acquire_deadline(&self) -> Option<TimeRange> {
-        if self
-            .is_in_use
-            .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
-            .is_ok()
-        {
-            Some(self.range)
-        } else {
-            None
-        }
+    /// Attempts to acquire a free instance of the deadline for use by the given holder.
+    /// There is a single arbitration point for all holders - acquirer fails if all instances are in use,
+    /// regardless of its side.
+    /// Returns Some((TimeRange, StateIndex)) with state of the acquired instance if successful,
+    /// None if all instances are in use.
+    pub(super) fn acquire_deadline(&self, holder: DeadlineHolder) -> Option<(TimeRange, StateIndex)> {
+        let instance = self.holders.iter().position(|slot| {
+            slot.compare_exchange(NO_HOLDER, holder as u8, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        })?;
+        Some((self.range, StateIndex::new(*self.assigned_state_index + instance)))
+    }
+
+    /// Configured range of the deadline.
+    pub(super) fn range(&self) -> TimeRange {
+        self.range
+    }
+
+    /// Releases the instance of the deadline with the given state, marking it as not in use.
+    pub(super) fn release_deadline(&self, state_index: StateIndex) {
+        self.holders[*state_index - *self.assigned_state_index].store(NO_HOLDER, Ordering::Relaxed);
     }
 
-    /// Releases the deadline, marking it as not in use.
-    pub(super) fn release_deadline(&self) {
-        self.is_in_use.store(false, Ordering::Relaxed);
+    /// Holder of the first instance of the deadline in use, [`None`] if no instance is in use.
+    pub(super) fn holder(&self) -> Option<DeadlineHolder> {
+        self.holders.iter().find_map(|slot| match slot.load(Ordering::Relaxed) {
+            NO_HOLDER => None,
+            1 => Some(DeadlineHolder::Rust),
+            2 => Some(DeadlineHolder::Ffi),
+            other => unreachable!("Invalid deadline holder value: {}", other),
+        })
     }
 }
 
@@ -83,27 +119,70 @@ mod tests {
     fn new_and_fields() {
         let range = TimeRange::new(Duration::from_secs(1), Duration::from_secs(2));
         let idx = StateIndex::new(7);
-        let tmpl = DeadlineTemplate::new(range, idx);
+        let tmpl = DeadlineTemplate::new(range, idx, 0, 1);
         assert_eq!(tmpl.range.min, Duration::from_secs(1));
         assert_eq!(tmpl.range.max, Duration::from_secs(2));
         assert_eq!(*tmpl.assigned_state_index, 7);
-        assert!(!tmpl.is_in_use.load(Ordering::Relaxed));
+        assert_eq!(tmpl.holder(), None);
     }
 
     #[test]
     fn acquire_and_release_deadline() {
         let range = TimeRange::new(Duration::from_secs(3), Duration::from_secs(4));
         let idx = StateIndex::new(0);
-        let tmpl = Arc::new(DeadlineTemplate::new(range, idx));
+        let tmpl = Arc::new(DeadlineTemplate::new(range, idx, 0, 1));
 
         // First acquire should succeed
-        assert_eq!(tmpl.acquire_deadline(), Some(range));
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Rust), Some((range, idx)));
+        assert_eq!(tmpl.holder(), Some(DeadlineHolder::Rust));
         // Second acquire should fail
-        assert_eq!(tmpl.acquire_deadline(), None);
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Rust), None);
 
         // Release and acquire again
-        tmpl.release_deadline();
-        assert_eq!(tmpl.acquire_deadline(), Some(range));
+        tmpl.release_deadline(idx);
+        assert_eq!(tmpl.holder(), None);
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Rust), Some((range, idx)));
+    }
+
+    #[test]
+    fn acquire_deadline_is_arbitrated_across_holders() {
+        let range = TimeRange::new(Duration::from_secs(3), Duration::from_secs(4));
+        let idx = StateIndex::new(0);
+        let tmpl = DeadlineTemplate::new(range, idx, 0, 1);
+
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Ffi), Some((range, idx)));
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Rust), None);
+        assert_eq!(tmpl.holder(), Some(DeadlineHolder::Ffi));
+
+        tmpl.release_deadline(idx);
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Rust), Some((range, idx)));
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Ffi), None);
+        assert_eq!(tmpl.holder(), Some(DeadlineHolder::Rust));
+    }
+
+    #[test]
+    fn acquire_deadline_instances() {
+        let range = TimeRange::new(Duration::from_secs(3), Duration::from_secs(4));
+        let tmpl = DeadlineTemplate::new(range, StateIndex::new(2), 1, 2);
+
+        // Instances are acquired in order of their states, until all are in use.
+        assert_eq!(
+            tmpl.acquire_deadline(DeadlineHolder::Rust),
+            Some((range, StateIndex::new(2)))
+        );
+        assert_eq!(
+            tmpl.acquire_deadline(DeadlineHolder::Ffi),
+            Some((range, StateIndex::new(3)))
+        );
+        assert_eq!(tmpl.acquire_deadline(DeadlineHolder::Rust), None);
+
+        // Released instance is reused.
+        tmpl.release_deadline(StateIndex::new(2));
+        assert_eq!(tmpl.holder(), Some(DeadlineHolder::Ffi));
+        assert_eq!(
+            tmpl.acquire_deadline(DeadlineHolder::Rust),
+            Some((range, StateIndex::new(2)))
+        );
     }
 
     #[test]
@@ -111,12 +190,12 @@ mod tests {
         use std::thread;
         let range = TimeRange::new(Duration::from_secs(5), Duration::from_secs(6));
         let idx = StateIndex::new(1);
-        let tmpl = Arc::new(DeadlineTemplate::new(range, idx));
+        let tmpl = Arc::new(DeadlineTemplate::new(range, idx, 0, 1));
 
         let tmpl1 = tmpl.clone();
         let tmpl2 = tmpl.clone();
-        let h1 = thread::spawn(move || tmpl1.acquire_deadline());
-        let h2 = thread::spawn(move || tmpl2.acquire_deadline());
+        let h1 = thread::spawn(move || tmpl1.acquire_deadline(DeadlineHolder::Rust));
+        let h2 = thread::spawn(move || tmpl2.acquire_deadline(DeadlineHolder::Ffi));
         let r1 = h1.join().unwrap();
         let r2 = h2.join().unwrap();
         // Only one thread should succeed
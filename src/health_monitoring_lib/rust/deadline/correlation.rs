@@ -0,0 +1,71 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Correlation ID of the current deadline activation, e.g. ID of a request or frame in a tracing system.
+///
+/// Single writer - the deadline owner, single reader - evaluation of the monitor.
+pub(super) struct CorrelationSlot {
+    id: AtomicU64,
+    present: AtomicBool,
+}
+
+impl CorrelationSlot {
+    pub(super) fn new() -> Self {
+        Self {
+            id: AtomicU64::new(0),
+            present: AtomicBool::new(false),
+        }
+    }
+
+    /// Store ID of a new activation, [`None`] if started without ID.
+    pub(super) fn store(&self, correlation_id: Option<u64>) {
+        match correlation_id {
+            Some(id) => {
+                self.id.store(id, Ordering::Relaxed);
+                self.present.store(true, Ordering::Release);
+            },
+            None => self.present.store(false, Ordering::Release),
+        }
+    }
+
+    /// ID of the last activation, [`None`] if started without ID.
+    pub(super) fn load(&self) -> Option<u64> {
+        self.present
+            .load(Ordering::Acquire)
+            .then(|| self.id.load(Ordering::Relaxed))
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::correlation::CorrelationSlot;
+
+    #[test]
+    fn correlation_slot_empty() {
+        assert_eq!(CorrelationSlot::new().load(), None);
+    }
+
+    #[test]
+    fn correlation_slot_store_and_clear() {
+        let slot = CorrelationSlot::new();
+        slot.store(Some(0));
+        assert_eq!(slot.load(), Some(0));
+        slot.store(Some(u64::MAX));
+        assert_eq!(slot.load(), Some(u64::MAX));
+        slot.store(None);
+        assert_eq!(slot.load(), None);
+    }
+}
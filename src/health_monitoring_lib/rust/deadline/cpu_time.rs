@@ -0,0 +1,198 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::deadline::sys::{self, ClockId};
+use core::sync::atomic::{fence, AtomicBool, AtomicI32, AtomicU32, AtomicU64, Ordering};
+
+/// Thread CPU-time clocks are supported on this platform.
+pub(super) fn is_supported() -> bool {
+    sys::THREAD_CPU_CLOCK_SUPPORTED
+}
+
+/// Value of [`CpuTimeSlot::stopped_overrun_ms`] if the activation was not stopped too late.
+const NO_OVERRUN: u32 = u32::MAX;
+
+/// Number of attempts to read a consistent activation while the owner restarts it.
+const READ_ATTEMPTS: u32 = 4;
+
+/// CPU-time measurement of the current activation.
+struct Activation {
+    /// CPU-time clock of the thread which started the deadline.
+    clock_id: ClockId,
+    baseline_ns: u64,
+    max_ms: u32,
+    /// Overrun measured when the deadline was stopped too late.
+    stopped_overrun_ms: Option<u32>,
+}
+
+/// CPU time of the thread running a deadline, accumulated since the deadline was started.
+/// Measured on the clock of the starting thread, also when the deadline is stopped or evaluated by another thread.
+///
+/// Single writer - the deadline owner, single reader - evaluation of the monitor.
+/// Activation is published with a sequence counter, odd while the owner starts a new activation.
+pub(super) struct CpuTimeSlot {
+    sequence: AtomicU64,
+    active: AtomicBool,
+    clock_id: AtomicI32,
+    baseline_ns: AtomicU64,
+    max_ms: AtomicU32,
+    stopped_overrun_ms: AtomicU32,
+}
+
+impl CpuTimeSlot {
+    pub(super) fn new() -> Self {
+        Self {
+            sequence: AtomicU64::new(0),
+            active: AtomicBool::new(false),
+            clock_id: AtomicI32::new(0),
+            baseline_ns: AtomicU64::new(0),
+            max_ms: AtomicU32::new(0),
+            stopped_overrun_ms: AtomicU32::new(NO_OVERRUN),
+        }
+    }
+
+    /// Start measuring CPU time of the current thread, activation exceeds its range after `max_ms`.
+    pub(super) fn start(&self, max_ms: u32) {
+        let activation = sys::current_thread_cpu_clock()
+            .and_then(|clock_id| sys::read_clock_ns(clock_id).map(|baseline_ns| (clock_id, baseline_ns)));
+
+        let sequence = self.sequence.load(Ordering::Relaxed);
+        self.sequence.store(sequence.wrapping_add(1), Ordering::Relaxed);
+        fence(Ordering::Release);
+        self.active.store(activation.is_some(), Ordering::Relaxed);
+        if let Some((clock_id, baseline_ns)) = activation {
+            self.clock_id.store(clock_id, Ordering::Relaxed);
+            self.baseline_ns.store(baseline_ns, Ordering::Relaxed);
+            self.max_ms.store(max_ms, Ordering::Relaxed);
+            self.stopped_overrun_ms.store(NO_OVERRUN, Ordering::Relaxed);
+        }
+        self.sequence.store(sequence.wrapping_add(2), Ordering::Release);
+    }
+
+    /// Consistent copy of the current activation, [`None`] if not started or restarted during every attempt.
+    fn load(&self) -> Option<Activation> {
+        for _ in 0..READ_ATTEMPTS {
+            let sequence = self.sequence.load(Ordering::Acquire);
+            if sequence % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+            let active = self.active.load(Ordering::Relaxed);
+            let activation = Activation {
+                clock_id: self.clock_id.load(Ordering::Relaxed),
+                baseline_ns: self.baseline_ns.load(Ordering::Relaxed),
+                max_ms: self.max_ms.load(Ordering::Relaxed),
+                stopped_overrun_ms: match self.stopped_overrun_ms.load(Ordering::Relaxed) {
+                    NO_OVERRUN => None,
+                    overrun_ms => Some(overrun_ms),
+                },
+            };
+            fence(Ordering::Acquire);
+            if self.sequence.load(Ordering::Relaxed) == sequence {
+                return active.then_some(activation);
+            }
+        }
+        None
+    }
+
+    /// CPU time consumed since the start in milliseconds, [`None`] if it cannot be measured.
+    pub(super) fn elapsed_ms(&self) -> Option<u32> {
+        let activation = self.load()?;
+        let elapsed_ns = sys::read_clock_ns(activation.clock_id)?.saturating_sub(activation.baseline_ns);
+        Some(u32::try_from(elapsed_ns / 1_000_000).unwrap_or(u32::MAX))
+    }
+
+    /// Push out the range of the activation by `extension_ms`.
+    pub(super) fn extend(&self, extension_ms: u32) {
+        if self.active.load(Ordering::Relaxed) {
+            let max_ms = self.max_ms.load(Ordering::Relaxed);
+            self.max_ms
+                .store(max_ms.saturating_add(extension_ms), Ordering::Release);
+        }
+    }
+
+    /// Record overrun of the activation stopped too late, the deadline stays failed.
+    pub(super) fn stop_overrun(&self, overrun_ms: u32) {
+        if self.active.load(Ordering::Relaxed) {
+            self.stopped_overrun_ms
+                .store(overrun_ms.min(NO_OVERRUN - 1), Ordering::Release);
+        }
+    }
+
+    /// Time by which the activation exceeded its range, measured on stop or while still running.
+    /// [`None`] if within range or not measurable.
+    pub(super) fn overrun_ms(&self) -> Option<u32> {
+        let activation = self.load()?;
+        if let Some(overrun_ms) = activation.stopped_overrun_ms {
+            return Some(overrun_ms);
+        }
+        let elapsed_ns = sys::read_clock_ns(activation.clock_id)?.saturating_sub(activation.baseline_ns);
+        let elapsed_ms = u32::try_from(elapsed_ns / 1_000_000).unwrap_or(u32::MAX);
+        elapsed_ms
+            .checked_sub(activation.max_ms)
+            .filter(|overrun_ms| *overrun_ms > 0)
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::cpu_time::CpuTimeSlot;
+    use core::time::Duration;
+    use std::time::Instant;
+
+    fn spin(duration: Duration) {
+        let start = Instant::now();
+        while start.elapsed() < duration {
+            core::hint::spin_loop();
+        }
+    }
+
+    #[test]
+    fn cpu_time_slot_not_started() {
+        let slot = CpuTimeSlot::new();
+        assert_eq!(slot.elapsed_ms(), None);
+        assert_eq!(slot.overrun_ms(), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn cpu_time_slot_ignores_sleep() {
+        let slot = CpuTimeSlot::new();
+        slot.start(20);
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(slot.elapsed_ms().is_some_and(|elapsed_ms| elapsed_ms < 20));
+        assert_eq!(slot.overrun_ms(), None);
+
+        spin(Duration::from_millis(40));
+        assert!(slot.elapsed_ms().is_some_and(|elapsed_ms| elapsed_ms >= 20));
+        assert!(slot.overrun_ms().is_some());
+
+        slot.stop_overrun(7);
+        assert_eq!(slot.overrun_ms(), Some(7));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn cpu_time_slot_read_from_other_thread() {
+        let slot = CpuTimeSlot::new();
+        slot.start(10);
+        spin(Duration::from_millis(20));
+
+        // Clock of the starting thread is read, not of the reading one.
+        std::thread::scope(|scope| {
+            scope.spawn(|| assert!(slot.overrun_ms().is_some()));
+        });
+    }
+}
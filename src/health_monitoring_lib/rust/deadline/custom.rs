@@ -0,0 +1,80 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::tag::DeadlineTag;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Spare deadline state, claimed by a custom deadline for its lifetime.
+///
+/// Tag is written by the claiming thread and read by evaluation for reporting only,
+/// evaluation never blocks on it.
+pub(super) struct CustomSlot {
+    in_use: AtomicBool,
+    deadline_tag: Mutex<Option<DeadlineTag>>,
+}
+
+impl CustomSlot {
+    pub(super) fn new() -> Self {
+        Self {
+            in_use: AtomicBool::new(false),
+            deadline_tag: Mutex::new(None),
+        }
+    }
+
+    /// Claim the slot for a custom deadline with `deadline_tag`.
+    /// Returns `false` if the slot is already in use.
+    pub(super) fn claim(&self, deadline_tag: DeadlineTag) -> bool {
+        if self
+            .in_use
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return false;
+        }
+        *self.deadline_tag.lock().unwrap_or_else(|e| e.into_inner()) = Some(deadline_tag);
+        true
+    }
+
+    /// Release the slot, so it can be claimed by another custom deadline.
+    pub(super) fn release(&self) {
+        *self.deadline_tag.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        self.in_use.store(false, Ordering::Release);
+    }
+
+    /// Tag of the custom deadline holding the slot, [`None`] if not in use or being claimed.
+    pub(super) fn deadline_tag(&self) -> Option<DeadlineTag> {
+        self.deadline_tag.try_lock().ok().and_then(|deadline_tag| *deadline_tag)
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::custom::CustomSlot;
+    use crate::tag::DeadlineTag;
+
+    #[test]
+    fn custom_slot_claim_and_release() {
+        let slot = CustomSlot::new();
+        assert_eq!(slot.deadline_tag(), None);
+
+        assert!(slot.claim(DeadlineTag::from("custom1")));
+        assert!(!slot.claim(DeadlineTag::from("custom2")));
+        assert_eq!(slot.deadline_tag(), Some(DeadlineTag::from("custom1")));
+
+        slot.release();
+        assert_eq!(slot.deadline_tag(), None);
+        assert!(slot.claim(DeadlineTag::from("custom2")));
+        assert_eq!(slot.deadline_tag(), Some(DeadlineTag::from("custom2")));
+    }
+}
@@ -10,24 +10,51 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
-use crate::common::{duration_to_int, Monitor, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator, TimeRange};
-use crate::deadline::common::{DeadlineTemplate, StateIndex};
+use crate::common::{
+    duration_to_int, range_position, InternalFault, Monitor, MonitorEvalHandle, MonitorEvaluationError,
+    MonitorEvaluator, RangePosition, TimeRange, ViolationContext,
+};
+use crate::config_summary::ConfigDigest;
+use crate::deadline::budget_monitor::{BudgetConfig, BudgetMonitor, BudgetSlot};
+use crate::deadline::calibration::{CalibrationConfig, CalibrationPolicy, CalibrationSlot};
+use crate::deadline::clock_source::{ClockSource, MonitorClock};
+use crate::deadline::common::{DeadlineHolder, DeadlineTemplate, StateIndex};
+use crate::deadline::correlation::CorrelationSlot;
+use crate::deadline::cpu_time::{self, CpuTimeSlot};
+use crate::deadline::custom::CustomSlot;
 use crate::deadline::deadline_state::{DeadlineState, DeadlineStateSnapshot};
-use crate::log::{error, warn, ScoreDebug};
+use crate::deadline::extension::ExtensionSlot;
+#[cfg(feature = "fault_injection")]
+use crate::deadline::fault_injection::{InjectedViolation, InjectionSlot};
+use crate::deadline::idleness::{IdleConfig, IdleSeverity, IdleSlot};
+use crate::deadline::scheduling::{SchedulingSlot, SchedulingStatistics};
+use crate::deadline::statistics::{DeadlineStatistics, StatisticsSlot};
+use crate::deadline::violation_backtrace::{BacktraceSlot, ViolationBacktrace};
+use crate::deadline::violation_callback::{DeadlineCallback, OffsetSlot};
+use crate::deadline::warning::{WarningCallback, WarningSlot};
+use crate::heartbeat::HeartbeatProducer;
+use crate::log::{error, info, warn, ScoreDebug};
 use crate::protected_memory::ProtectedMemoryAllocator;
 use crate::tag::{DeadlineTag, MonitorTag};
+use crate::thread_attribution::{ReporterSlot, ReporterThread};
 use core::hash::Hash;
-use std::collections::HashMap;
+use core::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use core::time::Duration;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 
 /// Deadline evaluation errors.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ScoreDebug)]
-pub(crate) enum DeadlineEvaluationError {
+pub enum DeadlineEvaluationError {
     /// Finished too early.
     TooEarly,
     /// Finished too late.
     TooLate,
+    /// Sections of a budget used more time than the budget within a window.
+    BudgetExhausted,
+    /// No deadline of the monitor was started for longer than its maximum idle time.
+    Idle,
 }
 
 ///
@@ -40,18 +67,86 @@ pub enum DeadlineMonitorError {
 
     /// The requested deadline was not registered during builder phase
     DeadlineNotFound,
+
+    /// All states reserved for custom deadlines are in use, or none were reserved
+    NoCustomDeadlineSlot,
+
+    /// Range of the custom deadline has no upper bound
+    InvalidRange,
+
+    /// The deadline was acquired but could not be started, as it was already missed before
+    DeadlineAlreadyFailed,
+
+    /// The monitor is evaluated by a started health monitor, see [`DeadlineMonitor::evaluate_now`]
+    WrongState,
 }
 
 /// Errors that can occur when working with Deadline instances
 #[derive(Debug, PartialEq, ScoreDebug, Eq, Clone, Copy, Hash)]
 pub enum DeadlineError {
     DeadlineAlreadyFailed,
+    /// External start timestamp is in the future or before the monitor was built.
+    ImplausibleTimestamp,
+    /// Deadline was already extended the maximum number of times in the current activation,
+    /// see [`DeadlineMonitorBuilder::with_max_extensions`].
+    ExtensionLimitReached,
+    /// Deadline was not added as cyclic, see [`DeadlineMonitorBuilder::add_cyclic_deadline`].
+    NotCyclic,
+}
+
+/// Duration of the last stopped activation of a deadline, see [`Deadline::last_measurement`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ScoreDebug)]
+pub struct DeadlineMeasurement {
+    /// Measured duration in milliseconds resolution, CPU time of the running thread for CPU-time deadlines.
+    pub elapsed: Duration,
+    /// Measured duration was within the range of the deadline, including its extensions.
+    /// Always `true` while the deadline is calibrated, as the range is not supervised.
+    pub in_range: bool,
+}
+
+/// Summary of deadline activity while the monitor was disabled.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, ScoreDebug)]
+pub struct DisabledIntervalSummary {
+    /// Number of deadlines started while disabled.
+    pub activations: u64,
+    /// Number of deadlines stopped outside of their range while disabled.
+    pub violations: u64,
 }
 
 /// Builder for DeadlineMonitor
 #[derive(Debug, Default)]
 pub struct DeadlineMonitorBuilder {
     deadlines: HashMap<DeadlineTag, TimeRange>,
+    /// Number of concurrent instances of deadlines, deadlines not listed have a single instance.
+    max_instances: HashMap<DeadlineTag, usize>,
+    /// Number of spare states for custom deadlines.
+    custom_deadlines: usize,
+    thread_attribution: bool,
+    backtrace_max_bytes: Option<usize>,
+    calibration: Option<CalibrationConfig>,
+    scheduling_statistics: bool,
+    /// Callbacks invoked on violations of single deadlines.
+    callbacks: HashMap<DeadlineTag, DeadlineCallback>,
+    /// Deadlines measuring CPU time of the running thread instead of wall-clock time.
+    cpu_deadlines: HashSet<DeadlineTag>,
+    /// Deadlines re-armed by each checkpoint, see [`Deadline::checkpoint`].
+    cyclic_deadlines: HashSet<DeadlineTag>,
+    /// Number of extensions allowed per activation of a deadline.
+    max_extensions: u32,
+    /// Heartbeats provided on successful activations of deadlines, wired by the health monitor builder.
+    heartbeats: HashMap<DeadlineTag, Vec<HeartbeatProducer>>,
+    /// Execution budgets accumulated over multiple sections.
+    budgets: HashMap<DeadlineTag, BudgetConfig>,
+    /// Idleness detection, [`None`] if disabled.
+    idle: Option<IdleConfig>,
+    /// Warning thresholds of deadlines.
+    warnings: HashMap<DeadlineTag, Duration>,
+    /// Callback invoked on near misses of deadlines.
+    warning_callback: Option<WarningCallback>,
+    /// Deadlines started when their upstream deadline, the key, is stopped.
+    chains: HashMap<DeadlineTag, DeadlineTag>,
+    /// Clock deadlines are measured with.
+    clock_source: ClockSource,
 }
 
 impl DeadlineMonitorBuilder {
@@ -59,6 +154,23 @@ impl DeadlineMonitorBuilder {
     pub fn new() -> Self {
         Self {
             deadlines: HashMap::new(),
+            max_instances: HashMap::new(),
+            custom_deadlines: 0,
+            thread_attribution: false,
+            backtrace_max_bytes: None,
+            calibration: None,
+            scheduling_statistics: false,
+            callbacks: HashMap::new(),
+            cpu_deadlines: HashSet::new(),
+            cyclic_deadlines: HashSet::new(),
+            max_extensions: 0,
+            heartbeats: HashMap::new(),
+            budgets: HashMap::new(),
+            idle: None,
+            warnings: HashMap::new(),
+            warning_callback: None,
+            chains: HashMap::new(),
+            clock_source: ClockSource::Monotonic,
         }
     }
 
@@ -68,16 +180,431 @@ impl DeadlineMonitorBuilder {
         self
     }
 
+    /// Adds a deadline measuring CPU time consumed by the thread which started it, instead of wall-clock time,
+    /// so time the thread is preempted or blocked does not count against the range.
+    /// Running deadline is evaluated on the CPU-time clock of the starting thread, also when stopped by another thread.
+    /// Supported on Linux and QNX, wall-clock time is measured on other platforms.
+    ///
+    /// # Note
+    ///
+    /// Start timestamps given by [`Deadline::start_at`] are ignored, CPU time is measured from the call.
+    pub fn add_deadline_cpu(mut self, deadline_tag: DeadlineTag, range: TimeRange) -> Self {
+        self.add_deadline_cpu_internal(deadline_tag, range);
+        self
+    }
+
+    /// Adds a deadline invoking `callback` on its violations, e.g. to reduce workload of the supervised subsystem.
+    /// Callback is invoked from the monitoring thread with the error and time outside of the range -
+    /// overrun measured when the deadline was stopped too late or is still running past its range,
+    /// or time missing to the range when stopped too early.
+    /// Violations are reported to the health monitor as well.
+    ///
+    /// # Note
+    ///
+    /// Callback delays monitor evaluation, it should return quickly. Same reentrancy rules as for
+    /// [`crate::HealthMonitorBuilder::with_failure_callback`] apply.
+    /// Panics of the callback are caught and reported as [`InternalFault::CallbackPanicked`].
+    pub fn add_deadline_with_callback<F>(mut self, deadline_tag: DeadlineTag, range: TimeRange, callback: F) -> Self
+    where
+        F: FnMut(DeadlineEvaluationError, Duration) + Send + 'static,
+    {
+        self.add_deadline_with_callback_internal(deadline_tag, range, callback);
+        self
+    }
+
+    /// Adds a deadline supervising the period of a cyclic task, which calls [`Deadline::checkpoint`] once per cycle
+    /// instead of starting and stopping the deadline. Each checkpoint stops the current cycle and re-arms the deadline
+    /// for the next one, starting when the previous one was stopped, so no time between cycles is lost.
+    /// Checkpoint missing past the upper bound of `range` is reported as [`DeadlineEvaluationError::TooLate`]
+    /// and a cycle shorter than the lower bound as [`DeadlineEvaluationError::TooEarly`],
+    /// so both the period and its jitter are supervised.
+    pub fn add_cyclic_deadline(mut self, deadline_tag: DeadlineTag, range: TimeRange) -> Self {
+        self.add_cyclic_deadline_internal(deadline_tag, range);
+        self
+    }
+
+    /// Adds a deadline which can be held up to `max_instances` times at once,
+    /// e.g. to monitor the same code path executed by multiple worker threads.
+    /// Each instance is started, stopped and evaluated on its own, statistics and calibration are shared.
+    pub fn add_deadline_concurrent(
+        mut self,
+        deadline_tag: DeadlineTag,
+        range: TimeRange,
+        max_instances: usize,
+    ) -> Self {
+        self.add_deadline_concurrent_internal(deadline_tag, range, max_instances);
+        self
+    }
+
+    /// Adds an execution budget accumulated over multiple sections, e.g. all callbacks of a cycle together.
+    /// Budget is exhausted if its sections use more than `budget` within a `window`,
+    /// which is reported once per window, see [`BudgetMonitor`].
+    /// Windows are aligned to the start of the monitor.
+    ///
+    /// - `budget` - time sections may use per window, must not be zero or longer than `window`.
+    pub fn add_budget(mut self, budget_tag: DeadlineTag, budget: Duration, window: Duration) -> Self {
+        self.add_budget_internal(budget_tag, budget, window);
+        self
+    }
+
+    /// Reserves states for up to `max_custom` custom deadlines existing at once,
+    /// see [`DeadlineMonitor::create_custom_deadline`].
+    pub fn with_custom_deadlines(mut self, max_custom: usize) -> Self {
+        self.with_custom_deadlines_internal(max_custom);
+        self
+    }
+
+    /// Attributes violations to the OS thread which last started or stopped the deadline.
+    /// Thread ID and name are captured once per thread and included in violation reports.
+    /// Supported on Linux only, no attribution is reported on other platforms.
+    pub fn with_thread_attribution(mut self) -> Self {
+        self.with_thread_attribution_internal();
+        self
+    }
+
+    /// Captures stack of the thread stopping a deadline too late.
+    /// At most one backtrace per deadline is kept until taken with [`DeadlineMonitor::take_violation_backtrace`].
+    /// Stopping thread only stores up to [`crate::deadline::MAX_BACKTRACE_FRAMES`] return addresses without
+    /// allocating, symbols are resolved by the reader and rendering is limited to `max_bytes`.
+    /// Supported on Linux with glibc only, backtraces carry no frames on other platforms.
+    ///
+    /// # Note
+    ///
+    /// Backtrace is captured when the deadline is stopped, so a deadline hanging forever produces no backtrace.
+    /// Such violations are still reported by the evaluation.
+    pub fn with_violation_backtrace(mut self, max_bytes: usize) -> Self {
+        self.with_violation_backtrace_internal(max_bytes);
+        self
+    }
+
+    /// Samples scheduler statistics of the thread starting a deadline, reported with violations of the deadline.
+    /// Run time, run delay and involuntary context switches during the activation distinguish sections
+    /// which were too slow from sections which did not get the CPU.
+    /// Sampling reads `/proc` on every start, so it adds to the duration of supervised sections.
+    /// Supported on Linux only, no statistics are reported on other platforms.
+    pub fn with_scheduling_statistics(mut self) -> Self {
+        self.with_scheduling_statistics_internal();
+        self
+    }
+
+    /// Calibrates deadlines during their first `activations` activations.
+    /// No violations are raised while calibrating, measured durations are recorded instead.
+    /// Afterwards a range from the shortest duration to p99 of durations, both widened by `margin_percent`,
+    /// is suggested and, depending on `policy`, used in place of the configured one.
+    pub fn with_calibration(mut self, activations: u32, margin_percent: u32, policy: CalibrationPolicy) -> Self {
+        self.with_calibration_internal(activations, margin_percent, policy);
+        self
+    }
+
+    /// Allows running deadlines to be extended up to `max_extensions` times per activation,
+    /// e.g. by operations with data-dependent duration which are still making progress,
+    /// see [`DeadlineHandle::extend`]. Deadlines cannot be extended by default.
+    pub fn with_max_extensions(mut self, max_extensions: u32) -> Self {
+        self.with_max_extensions_internal(max_extensions);
+        self
+    }
+
+    /// Detects a monitor in which no deadline is started for longer than `max_idle`,
+    /// e.g. a supervised feature silently never running. Each idle period is reported once with `severity`.
+    /// Idle time is counted from the first evaluation of the enabled monitor and restarted when it is enabled again.
+    /// Detection is disabled by default or if `max_idle` is zero.
+    pub fn with_max_idle(mut self, max_idle: Duration, severity: IdleSeverity) -> Self {
+        self.with_max_idle_internal(max_idle, severity);
+        self
+    }
+
+    /// Sets a warning threshold of the deadline with the given tag, within its range.
+    /// Activations stopped within the range, but later than `warning_at`, are near misses - not violations,
+    /// but logged, counted in [`DeadlineStatistics::near_misses`] and reported to the callback set with
+    /// [`Self::with_warning_callback`], so margins can be tuned before they turn into failures.
+    pub fn with_warning_at(mut self, deadline_tag: DeadlineTag, warning_at: Duration) -> Self {
+        self.with_warning_at_internal(deadline_tag, warning_at);
+        self
+    }
+
+    /// Sets a callback invoked from the monitoring thread on near misses of deadlines with a warning threshold,
+    /// see [`Self::with_warning_at`]. Callback is invoked once per evaluation for each deadline with near misses,
+    /// with the longest of them. Near misses while the monitor is disabled are counted, but not reported.
+    ///
+    /// # Note
+    ///
+    /// Same rules as for callbacks of [`Self::add_deadline_with_callback`] apply.
+    pub fn with_warning_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(DeadlineTag, Duration) + Send + 'static,
+    {
+        self.with_warning_callback_internal(callback);
+        self
+    }
+
+    /// Chains deadline `to_tag` to deadline `from_tag` - each stop of `from_tag` starts `to_tag` at the same time,
+    /// so an end-to-end pipeline is supervised across modules without them knowing about each other.
+    /// Holder of the downstream deadline joins the chained activation by starting the deadline as usual
+    /// and stops it when done. Violations are reported with the tag of the failing link of the chain,
+    /// correlation ID of the upstream activation is carried along.
+    ///
+    /// # Note
+    ///
+    /// Downstream deadline must have a single instance, must not be cyclic or measure CPU time,
+    /// and can be chained to a single upstream deadline.
+    pub fn chain(mut self, from_tag: DeadlineTag, to_tag: DeadlineTag) -> Self {
+        self.chain_internal(from_tag, to_tag);
+        self
+    }
+
+    /// Sets the clock deadlines of the monitor are measured with, [`ClockSource::Monotonic`] by default.
+    /// Coarse clock reduces the cost of starting and stopping deadlines, with a resolution of a scheduler tick,
+    /// so short ranges should be avoided. External time base synchronizes supervision to e.g. network time.
+    ///
+    /// # Note
+    ///
+    /// Budgets and CPU-time deadlines are not affected, timestamps passed to [`Deadline::start_at`] are related
+    /// to the clock source by the current time.
+    pub fn with_clock_source(mut self, clock_source: ClockSource) -> Self {
+        self.with_clock_source_internal(clock_source);
+        self
+    }
+
     /// Builds the DeadlineMonitor with the configured deadlines.
     pub(crate) fn build(self, monitor_tag: MonitorTag, _allocator: &ProtectedMemoryAllocator) -> DeadlineMonitor {
-        let inner = Arc::new(DeadlineMonitorInner::new(monitor_tag, self.deadlines));
+        let inner = Arc::new(DeadlineMonitorInner::new(monitor_tag, self));
         DeadlineMonitor::new(inner)
     }
 
+    /// Number of configured deadlines.
+    pub(crate) fn num_deadlines(&self) -> usize {
+        self.deadlines.len()
+    }
+
+    /// Number of configured budgets.
+    pub(crate) fn num_budgets(&self) -> usize {
+        self.budgets.len()
+    }
+
+    /// Any budget is zero or longer than its window.
+    pub(crate) fn has_invalid_budget(&self) -> bool {
+        self.budgets.values().any(|config| !config.is_valid())
+    }
+
+    /// Any warning threshold is set for an unknown deadline or is not within its range.
+    pub(crate) fn has_invalid_warning(&self) -> bool {
+        self.warnings.iter().any(|(deadline_tag, warning_at)| {
+            self.deadlines
+                .get(deadline_tag)
+                .is_none_or(|range| *warning_at < range.min || *warning_at >= range.max)
+        })
+    }
+
+    /// Any chain links an unknown deadline or a deadline to itself, or its downstream deadline
+    /// cannot be started by the upstream one, see [`Self::chain`].
+    pub(crate) fn has_invalid_chain(&self) -> bool {
+        let mut downstream = HashSet::new();
+        self.chains.iter().any(|(from_tag, to_tag)| {
+            from_tag == to_tag
+                || !self.deadlines.contains_key(from_tag)
+                || !self.deadlines.contains_key(to_tag)
+                || self.instances(to_tag) != 1
+                || self.cyclic_deadlines.contains(to_tag)
+                || self.cpu_deadlines.contains(to_tag)
+                || !downstream.insert(*to_tag)
+        })
+    }
+
+    /// Any deadline is added with zero concurrent instances.
+    pub(crate) fn has_empty_instance_pool(&self) -> bool {
+        self.max_instances.values().any(|max_instances| *max_instances == 0)
+    }
+
+    pub(crate) fn has_thread_attribution(&self) -> bool {
+        self.thread_attribution
+    }
+
+    /// Deadline with `deadline_tag` is configured.
+    pub(crate) fn has_deadline(&self, deadline_tag: &DeadlineTag) -> bool {
+        self.deadlines.contains_key(deadline_tag)
+    }
+
+    /// Provide heartbeats to `producer` on successful activations of deadline with `deadline_tag`.
+    pub(crate) fn with_heartbeat_internal(&mut self, deadline_tag: DeadlineTag, producer: HeartbeatProducer) {
+        self.heartbeats.entry(deadline_tag).or_default().push(producer);
+    }
+
+    /// Shortest upper bound of the configured deadlines, [`None`] if there are no bounded deadlines.
+    pub(crate) fn shortest_deadline(&self) -> Option<Duration> {
+        self.deadlines
+            .values()
+            .filter(|range| range.has_upper_bound())
+            .map(|range| range.max)
+            .min()
+    }
+
+    /// Write the configuration into the `digest`, deadlines are written in order of their tags.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        let mut deadlines: Vec<_> = self.deadlines.iter().collect();
+        deadlines.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+
+        digest.write_u64(deadlines.len() as u64);
+        for (deadline_tag, range) in deadlines {
+            digest.write_str(deadline_tag.as_str());
+            digest.write_time_range(range);
+            digest.write_u64(self.instances(deadline_tag) as u64);
+            digest.write_u64(u64::from(self.callbacks.contains_key(deadline_tag)));
+            digest.write_u64(u64::from(self.cpu_deadlines.contains(deadline_tag)));
+            digest.write_u64(u64::from(self.cyclic_deadlines.contains(deadline_tag)));
+        }
+        digest.write_u64(self.custom_deadlines as u64);
+        digest.write_u64(u64::from(self.thread_attribution));
+        digest.write_option(self.backtrace_max_bytes, |digest, max_bytes| {
+            digest.write_u64(max_bytes as u64)
+        });
+        digest.write_option(self.calibration, |digest, calibration| {
+            digest.write_u64(u64::from(calibration.activations));
+            digest.write_u64(u64::from(calibration.margin_percent));
+            digest.write_u64(u64::from(calibration.policy == CalibrationPolicy::AutoApply));
+        });
+        digest.write_u64(u64::from(self.scheduling_statistics));
+        digest.write_u64(u64::from(self.max_extensions));
+
+        let mut budgets: Vec<_> = self.budgets.iter().collect();
+        budgets.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+        digest.write_u64(budgets.len() as u64);
+        for (budget_tag, config) in budgets {
+            digest.write_str(budget_tag.as_str());
+            digest.write_duration(config.budget);
+            digest.write_duration(config.window);
+        }
+        digest.write_option(self.idle, |digest, idle| {
+            digest.write_duration(idle.max_idle);
+            digest.write_u64(u64::from(idle.severity == IdleSeverity::Error));
+        });
+
+        let mut warnings: Vec<_> = self.warnings.iter().collect();
+        warnings.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+        digest.write_u64(warnings.len() as u64);
+        for (deadline_tag, warning_at) in warnings {
+            digest.write_str(deadline_tag.as_str());
+            digest.write_duration(*warning_at);
+        }
+        digest.write_u64(u64::from(self.warning_callback.is_some()));
+
+        let mut chains: Vec<_> = self.chains.iter().collect();
+        chains.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+        digest.write_u64(chains.len() as u64);
+        for (from_tag, to_tag) in chains {
+            digest.write_str(from_tag.as_str());
+            digest.write_str(to_tag.as_str());
+        }
+        digest.write_u64(self.clock_source.kind());
+    }
+
+    /// Number of concurrent instances of the deadline.
+    fn instances(&self, deadline_tag: &DeadlineTag) -> usize {
+        self.max_instances.get(deadline_tag).copied().unwrap_or(1)
+    }
+
     // Used by FFI and config parsing code which prefer not to move builder instance
 
     pub(super) fn add_deadline_internal(&mut self, deadline_tag: DeadlineTag, range: TimeRange) {
         self.deadlines.insert(deadline_tag, range);
+        self.max_instances.remove(&deadline_tag);
+        self.callbacks.remove(&deadline_tag);
+        self.cpu_deadlines.remove(&deadline_tag);
+        self.cyclic_deadlines.remove(&deadline_tag);
+    }
+
+    pub(super) fn add_deadline_cpu_internal(&mut self, deadline_tag: DeadlineTag, range: TimeRange) {
+        self.add_deadline_internal(deadline_tag, range);
+        self.cpu_deadlines.insert(deadline_tag);
+    }
+
+    pub(super) fn add_cyclic_deadline_internal(&mut self, deadline_tag: DeadlineTag, range: TimeRange) {
+        self.add_deadline_internal(deadline_tag, range);
+        self.cyclic_deadlines.insert(deadline_tag);
+    }
+
+    pub(super) fn add_deadline_with_callback_internal<F>(
+        &mut self,
+        deadline_tag: DeadlineTag,
+        range: TimeRange,
+        callback: F,
+    ) where
+        F: FnMut(DeadlineEvaluationError, Duration) + Send + 'static,
+    {
+        self.add_deadline_internal(deadline_tag, range);
+        self.callbacks.insert(deadline_tag, DeadlineCallback::new(callback));
+    }
+
+    pub(super) fn add_deadline_concurrent_internal(
+        &mut self,
+        deadline_tag: DeadlineTag,
+        range: TimeRange,
+        max_instances: usize,
+    ) {
+        self.deadlines.insert(deadline_tag, range);
+        self.max_instances.insert(deadline_tag, max_instances);
+        self.callbacks.remove(&deadline_tag);
+        self.cpu_deadlines.remove(&deadline_tag);
+        self.cyclic_deadlines.remove(&deadline_tag);
+    }
+
+    pub(super) fn add_budget_internal(&mut self, budget_tag: DeadlineTag, budget: Duration, window: Duration) {
+        self.budgets.insert(budget_tag, BudgetConfig { budget, window });
+    }
+
+    pub(super) fn with_custom_deadlines_internal(&mut self, max_custom: usize) {
+        self.custom_deadlines = max_custom;
+    }
+
+    pub(super) fn with_thread_attribution_internal(&mut self) {
+        self.thread_attribution = true;
+    }
+
+    pub(super) fn with_violation_backtrace_internal(&mut self, max_bytes: usize) {
+        self.backtrace_max_bytes = Some(max_bytes);
+    }
+
+    pub(super) fn with_scheduling_statistics_internal(&mut self) {
+        self.scheduling_statistics = true;
+    }
+
+    pub(super) fn with_max_extensions_internal(&mut self, max_extensions: u32) {
+        self.max_extensions = max_extensions;
+    }
+
+    pub(super) fn with_max_idle_internal(&mut self, max_idle: Duration, severity: IdleSeverity) {
+        self.idle = (!max_idle.is_zero()).then_some(IdleConfig { max_idle, severity });
+    }
+
+    pub(super) fn with_warning_at_internal(&mut self, deadline_tag: DeadlineTag, warning_at: Duration) {
+        self.warnings.insert(deadline_tag, warning_at);
+    }
+
+    pub(super) fn with_warning_callback_internal<F>(&mut self, callback: F)
+    where
+        F: FnMut(DeadlineTag, Duration) + Send + 'static,
+    {
+        self.warning_callback = Some(WarningCallback::new(callback));
+    }
+
+    pub(super) fn chain_internal(&mut self, from_tag: DeadlineTag, to_tag: DeadlineTag) {
+        self.chains.insert(from_tag, to_tag);
+    }
+
+    pub(super) fn with_clock_source_internal(&mut self, clock_source: ClockSource) {
+        self.clock_source = clock_source;
+    }
+
+    pub(super) fn with_calibration_internal(
+        &mut self,
+        activations: u32,
+        margin_percent: u32,
+        policy: CalibrationPolicy,
+    ) {
+        self.calibration = (activations > 0).then_some(CalibrationConfig {
+            activations,
+            margin_percent,
+            policy,
+        });
     }
 }
 
@@ -92,18 +619,297 @@ impl DeadlineMonitor {
     }
 
     /// Acquires a deadline instance for the given tag.
+    /// A deadline can be held only once at a time, unless added with [`DeadlineMonitorBuilder::add_deadline_concurrent`].
+    /// Acquirer of a deadline with no free instance gets `DeadlineInUse`,
+    /// regardless of whether the instances are held on the Rust or on the FFI side.
     /// # Returns
     ///  - Ok(Deadline) - if the deadline was acquired successfully.
-    ///  - Err(DeadlineMonitorError::DeadlineInUse) - if the deadline is already in use
+    ///  - Err(DeadlineMonitorError::DeadlineInUse) - if all instances of the deadline are in use
     ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
     pub fn get_deadline(&self, deadline_tag: DeadlineTag) -> Result<Deadline, DeadlineMonitorError> {
-        self.inner.get_deadline(deadline_tag)
+        self.inner.get_deadline(deadline_tag, DeadlineHolder::Rust)
+    }
+
+    /// Acquires and starts a deadline instance for the given tag.
+    /// Returned guard owns the deadline, so it can be moved across function boundaries.
+    /// Deadline is stopped and released when the guard is dropped.
+    /// # Returns
+    ///  - Ok(DeadlineGuard) - if the deadline was acquired and started successfully.
+    ///  - Err(DeadlineMonitorError::DeadlineInUse) - if all instances of the deadline are in use
+    ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
+    ///  - Err(DeadlineMonitorError::DeadlineAlreadyFailed) - if the deadline was already missed before
+    pub fn get_deadline_guard(&self, deadline_tag: DeadlineTag) -> Result<DeadlineGuard, DeadlineMonitorError> {
+        let mut deadline = self.get_deadline(deadline_tag)?;
+        // SAFETY: Guard owns the deadline, which is stopped exactly once when the guard is dropped.
+        unsafe { deadline.start_internal(None) }.map_err(|_| DeadlineMonitorError::DeadlineAlreadyFailed)?;
+        Ok(DeadlineGuard(deadline))
+    }
+
+    /// Acquires deadline instances for all given tags and starts them together, at a single timestamp.
+    /// Returned group owns the deadlines, which are stopped together and released when the group is dropped.
+    /// Group is started either completely or not at all - deadlines started before a failing one are discarded
+    /// without evaluation.
+    /// # Returns
+    ///  - Ok(DeadlineGroup) - if all deadlines were acquired and started successfully.
+    ///  - Err(DeadlineMonitorError) - as for [`Self::get_deadline_guard`], for the first failing deadline.
+    pub fn start_group(&self, deadline_tags: &[DeadlineTag]) -> Result<DeadlineGroup, DeadlineMonitorError> {
+        let mut deadlines = deadline_tags
+            .iter()
+            .map(|deadline_tag| self.get_deadline(*deadline_tag))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let now = self.inner.clock.now_ms();
+        for index in 0..deadlines.len() {
+            if deadlines[index].start_at_ms(None, now).is_err() {
+                for deadline in deadlines[..index].iter_mut() {
+                    deadline.discard_at_ms(now);
+                }
+                return Err(DeadlineMonitorError::DeadlineAlreadyFailed);
+            }
+        }
+        Ok(DeadlineGroup(deadlines))
+    }
+
+    /// Runs `f` under the deadline for the given tag, the deadline is stopped once `f` returns.
+    /// # Returns
+    ///  - Ok(R) - result of `f`, if the deadline was acquired and started successfully.
+    ///  - Err(DeadlineMonitorError) - as for [`Self::get_deadline_guard`], `f` is not run.
+    pub fn measure<R>(&self, deadline_tag: DeadlineTag, f: impl FnOnce() -> R) -> Result<R, DeadlineMonitorError> {
+        let _guard = self.get_deadline_guard(deadline_tag)?;
+        Ok(f())
+    }
+
+    /// Returns the execution budget with the given tag, see [`DeadlineMonitorBuilder::add_budget`].
+    /// Budget can be acquired any number of times, all instances account to the same budget.
+    /// # Returns
+    ///  - Ok(BudgetMonitor) - if the budget is registered.
+    ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the budget tag is not registered
+    pub fn get_budget(&self, budget_tag: DeadlineTag) -> Result<BudgetMonitor, DeadlineMonitorError> {
+        self.inner
+            .budgets
+            .iter()
+            .find(|(tag, _)| *tag == budget_tag)
+            .map(|(tag, slot)| BudgetMonitor::new(*tag, Arc::clone(slot), self.inner.clock.starting_point()))
+            .ok_or(DeadlineMonitorError::DeadlineNotFound)
+    }
+
+    /// Creates a deadline with a tag and range not registered during builder phase, e.g. for ad-hoc measurements.
+    /// Deadline holds one of the states reserved with [`DeadlineMonitorBuilder::with_custom_deadlines`]
+    /// until dropped, and is supervised same as registered deadlines.
+    /// Custom deadlines are neither calibrated nor included in [`Self::stats`].
+    /// # Returns
+    ///  - Ok(Deadline) - if the deadline was created successfully.
+    ///  - Err(DeadlineMonitorError::NoCustomDeadlineSlot) - if all reserved states are in use
+    ///  - Err(DeadlineMonitorError::InvalidRange) - if the range has no upper bound
+    pub fn create_custom_deadline(
+        &self,
+        deadline_tag: DeadlineTag,
+        range: TimeRange,
+    ) -> Result<Deadline, DeadlineMonitorError> {
+        self.inner.create_custom_deadline(deadline_tag, range)
+    }
+
+    /// Takes backtrace captured when the deadline with the given tag was stopped too late.
+    /// Next violation of this deadline is captured only after the previous backtrace is taken.
+    /// # Returns
+    ///  - Ok(Some(ViolationBacktrace)) - if a backtrace was captured since the last call.
+    ///  - Ok(None) - if nothing was captured or capture is not enabled.
+    ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
+    pub fn take_violation_backtrace(
+        &self,
+        deadline_tag: DeadlineTag,
+    ) -> Result<Option<ViolationBacktrace>, DeadlineMonitorError> {
+        let template = self
+            .inner
+            .deadlines
+            .get(&deadline_tag)
+            .ok_or(DeadlineMonitorError::DeadlineNotFound)?;
+        Ok(self
+            .inner
+            .backtraces
+            .as_ref()
+            .and_then(|backtraces| backtraces[template.template_index].take()))
+    }
+
+    /// Returns range suggested by calibration of the deadline with the given tag.
+    /// # Returns
+    ///  - Ok(Some(TimeRange)) - if calibration of the deadline is finished.
+    ///  - Ok(None) - if the deadline is still calibrating or calibration is not enabled.
+    ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
+    pub fn calibration_suggestion(&self, deadline_tag: DeadlineTag) -> Result<Option<TimeRange>, DeadlineMonitorError> {
+        let template = self
+            .inner
+            .deadlines
+            .get(&deadline_tag)
+            .ok_or(DeadlineMonitorError::DeadlineNotFound)?;
+        Ok(self
+            .inner
+            .calibration_slot(template.template_index)
+            .and_then(CalibrationSlot::suggestion))
+    }
+
+    /// Returns statistics of durations measured for the deadline with the given tag,
+    /// including activations stopped outside of the range and during calibration.
+    /// # Returns
+    ///  - Ok(DeadlineStatistics) - statistics of all activations stopped so far.
+    ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
+    pub fn stats(&self, deadline_tag: DeadlineTag) -> Result<DeadlineStatistics, DeadlineMonitorError> {
+        let template = self
+            .inner
+            .deadlines
+            .get(&deadline_tag)
+            .ok_or(DeadlineMonitorError::DeadlineNotFound)?;
+        let near_misses = self.inner.warnings[template.template_index]
+            .as_ref()
+            .map_or(0, WarningSlot::near_misses);
+        Ok(DeadlineStatistics {
+            near_misses,
+            ..self.inner.statistics[template.template_index].statistics()
+        })
+    }
+
+    /// Evaluates deadlines of this monitor now and passes their violations to `on_error`, with the tag of this monitor,
+    /// e.g. from the main loop of an application which cannot spawn the monitoring thread.
+    /// Monitor is acquired from a [`crate::HealthMonitor`] which is not started, so it is evaluated by the caller only.
+    /// Same as in the monitoring thread, deadlines stay failed and are reported by each evaluation.
+    /// Internal faults are not reported.
+    /// # Returns
+    ///  - Ok(()) - if the monitor was evaluated.
+    ///  - Err(DeadlineMonitorError::WrongState) - if the monitor is evaluated by a started health monitor,
+    ///    or concurrently by another call of this method.
+    pub fn evaluate_now(
+        &self,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError),
+    ) -> Result<(), DeadlineMonitorError> {
+        if self
+            .inner
+            .evaluators
+            .compare_exchange(0, MANUAL_EVALUATION, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            warn!(
+                "Deadline monitor {:?} cannot be evaluated now, it is evaluated by a health monitor",
+                self.inner.monitor_tag
+            );
+            return Err(DeadlineMonitorError::WrongState);
+        }
+
+        self.inner.evaluate(Instant::now(), &mut |monitor_tag, error, _| {
+            on_error(monitor_tag, error)
+        });
+        self.inner.evaluators.store(0, Ordering::Release);
+        Ok(())
+    }
+
+    /// Forces the next evaluation of the monitor to report `violation` of the deadline with the given tag,
+    /// regardless of its state, e.g. to exercise the reaction of the supervisor in integration tests.
+    /// Violation is reported once, with a zero offset, and is dropped if the monitor is disabled.
+    /// # Returns
+    ///  - Ok(()) - if the violation was injected.
+    ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
+    #[cfg(feature = "fault_injection")]
+    pub fn inject_violation(
+        &self,
+        deadline_tag: DeadlineTag,
+        violation: InjectedViolation,
+    ) -> Result<(), DeadlineMonitorError> {
+        let template = self
+            .inner
+            .deadlines
+            .get(&deadline_tag)
+            .ok_or(DeadlineMonitorError::DeadlineNotFound)?;
+        warn!("Injecting {:?} violation of deadline {:?}", violation, deadline_tag);
+        self.inner.injections[template.template_index].store(violation);
+        Ok(())
+    }
+
+    /// Returns the current holder of the deadline with the given tag.
+    /// Holder of the first instance in use is returned for deadlines with concurrent instances.
+    /// # Returns
+    ///  - Ok(Some(DeadlineHolder)) - if the deadline is in use.
+    ///  - Ok(None) - if the deadline is not in use.
+    ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
+    pub fn deadline_holder(&self, deadline_tag: DeadlineTag) -> Result<Option<DeadlineHolder>, DeadlineMonitorError> {
+        self.inner
+            .deadlines
+            .get(&deadline_tag)
+            .map(DeadlineTemplate::holder)
+            .ok_or(DeadlineMonitorError::DeadlineNotFound)
+    }
+
+    /// Acquires a deadline instance for the given tag on behalf of the FFI side.
+    pub(super) fn get_deadline_ffi(&self, deadline_tag: DeadlineTag) -> Result<Deadline, DeadlineMonitorError> {
+        self.inner.get_deadline(deadline_tag, DeadlineHolder::Ffi)
+    }
+
+    /// Deadlines of this monitor with their ranges, in order of their evaluation.
+    /// Range suggested by calibration is reported if it was auto-applied.
+    pub(crate) fn deadline_ranges(&self) -> Vec<(DeadlineTag, TimeRange)> {
+        self.inner
+            .active_deadlines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (deadline_tag, _))| {
+                let template = self.inner.deadlines.get(deadline_tag)?;
+                // Concurrent instances are reported once.
+                if *template.assigned_state_index != index {
+                    return None;
+                }
+                let range = self.inner.effective_range(template.template_index, template.range());
+                Some((*deadline_tag, range))
+            })
+            .collect()
+    }
+
+    /// Disables evaluation of this monitor.
+    /// Deadlines can still be started and stopped, activations and violations are only counted.
+    pub(crate) fn disable(&self) {
+        self.inner.enabled.store(false, Ordering::Relaxed);
+    }
+
+    /// Enables evaluation of this monitor.
+    /// Returns summary of the disabled interval, [`None`] if the monitor was not disabled.
+    pub(crate) fn enable(&self) -> Option<DisabledIntervalSummary> {
+        if self.inner.enabled.swap(true, Ordering::Relaxed) {
+            return None;
+        }
+
+        let summary = DisabledIntervalSummary {
+            activations: self.inner.disabled_activations.swap(0, Ordering::Relaxed),
+            violations: self.inner.disabled_violations.swap(0, Ordering::Relaxed),
+        };
+        info!(
+            "Deadline monitor {:?} enabled, while disabled: {:?}",
+            self.inner.monitor_tag, summary
+        );
+        Some(summary)
     }
 }
 
 impl Monitor for DeadlineMonitor {
     fn get_eval_handle(&self) -> MonitorEvalHandle {
-        MonitorEvalHandle::new(Arc::clone(&self.inner))
+        MonitorEvalHandle::new(Arc::new(DeadlineMonitorHandle::new(Arc::clone(&self.inner))))
+    }
+
+    fn clone_shared(&self) -> Self {
+        Self::new(Arc::clone(&self.inner))
+    }
+
+    fn shares_state(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    fn set_enabled(&self, enabled: bool) {
+        if enabled {
+            // Summary is logged by `enable`.
+            let _ = self.enable();
+        } else {
+            self.disable();
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.inner.enabled.load(Ordering::Relaxed)
     }
 }
 
@@ -112,7 +918,17 @@ pub struct Deadline {
     range: TimeRange,
     deadline_tag: DeadlineTag,
     state_index: StateIndex,
+    /// Template of the deadline, [`None`] for custom deadlines.
+    template_index: Option<usize>,
     monitor: Arc<DeadlineMonitorInner>,
+    /// Measurement of the last stopped activation.
+    last_measurement: Option<DeadlineMeasurement>,
+    /// Deadline is re-armed by checkpoints, see [`Deadline::checkpoint`].
+    is_cyclic: bool,
+    /// Cycle of the cyclic deadline is running.
+    is_armed: bool,
+    /// Running deadline is paused, see [`DeadlineHandle::pause`].
+    is_paused: bool,
 }
 
 /// A handle representing a started deadline. When dropped, it stops the deadline.
@@ -120,68 +936,523 @@ pub struct DeadlineHandle<'a>(&'a mut Deadline);
 
 impl DeadlineHandle<'_> {
     /// Stops the deadline. This is equivalent to dropping the handle.
-    pub fn stop(self) {
-        drop(self);
-    }
-}
-
-impl Drop for DeadlineHandle<'_> {
-    fn drop(&mut self) {
-        self.0.stop_internal();
+    /// Returns the measurement of the stopped activation, see [`Deadline::last_measurement`].
+    pub fn stop(self) -> Option<DeadlineMeasurement> {
+        // Handle only borrows the deadline, so nothing is leaked by not dropping it.
+        let mut handle = core::mem::ManuallyDrop::new(self);
+        handle.0.stop_internal();
+        handle.0.last_measurement()
     }
-}
 
-impl Deadline {
     ///
-    /// Starts the deadline - it will be monitored by health monitoring system.
+    /// Extends the running deadline by `extension`, pushing out the upper bound of its range,
+    /// e.g. to declare an operation with data-dependent duration is still making progress.
+    /// Lower bound of the range is kept.
     ///
     /// # Returns
-    ///  - Ok(DeadlineHandle) - if the deadline was started successfully.
-    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline was already missed before
+    ///  - Ok(()) - if the deadline was extended.
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline is already past its range
+    ///  - Err(DeadlineError::ExtensionLimitReached) - if the deadline cannot be extended any further
     ///
-    pub fn start(&mut self) -> Result<DeadlineHandle<'_>, DeadlineError> {
-        // Safety: We ensure that the caller upholds the safety contract for FFI usage by using &'a mut self lifetime in DeadlineHandle
-        unsafe { self.start_internal().map(|_| DeadlineHandle(self)) }
+    pub fn extend(&mut self, extension: Duration) -> Result<(), DeadlineError> {
+        self.0.extend_internal(extension)
     }
 
-    /// Starts the deadline - it will be monitored by health monitoring system.
-    /// This function is for FFI usage only!
     ///
-    /// # Safety
+    /// Pauses the running deadline around a declared wait section, e.g. waiting for external IO
+    /// outside of the supervised budget. Time until [`Self::resume`] is not counted against the range
+    /// and the paused deadline is not reported as missed. Stopping a paused deadline resumes it first.
+    /// CPU time of CPU-time deadlines does not advance in blocking waits anyway, only their wall-clock bound is paused.
     ///
-    /// Caller must ensure that deadline is not used until it's stopped.
-    /// After this call You shall assure there's only a single owner of the `Deadline` instance and it does not call start before stopping.
-    pub(super) unsafe fn start_internal(&mut self) -> Result<(), DeadlineError> {
-        let now = duration_to_int::<u32>(self.monitor.monitor_starting_point.elapsed());
-        let max_time = now + self.range.max.as_millis() as u32;
-
-        let mut is_broken = false;
-        let _ = self.monitor.active_deadlines[*self.state_index].1.update(|current| {
-            if current.is_running() || current.is_underrun() {
-                is_broken = true;
-                return None; // Deadline is already missed, do nothing
-            }
+    /// # Returns
+    ///  - Ok(()) - if the deadline was paused or is already paused.
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline is already past its range
+    ///
+    pub fn pause(&mut self) -> Result<(), DeadlineError> {
+        self.0.pause_internal()
+    }
 
-            let mut new = DeadlineStateSnapshot::default();
-            new.set_timestamp_ms(max_time);
-            new.set_running();
-            Some(new)
-        });
+    /// Resumes the paused deadline, see [`Self::pause`]. Resuming a deadline which is not paused does nothing.
+    pub fn resume(&mut self) {
+        self.0.resume_internal();
+    }
+}
 
-        if is_broken {
-            warn!("Trying to start deadline {:?} that already failed", self.deadline_tag);
-            Err(DeadlineError::DeadlineAlreadyFailed)
+impl Drop for DeadlineHandle<'_> {
+    fn drop(&mut self) {
+        self.0.stop_internal();
+    }
+}
+
+/// A started deadline owned by the guard, see [`DeadlineMonitor::get_deadline_guard`].
+/// When dropped, it stops and releases the deadline.
+pub struct DeadlineGuard(Deadline);
+
+impl DeadlineGuard {
+    /// Stops the deadline. This is equivalent to dropping the guard.
+    pub fn stop(self) {
+        drop(self);
+    }
+
+    /// Extends the running deadline by `extension`, see [`DeadlineHandle::extend`].
+    pub fn extend(&mut self, extension: Duration) -> Result<(), DeadlineError> {
+        self.0.extend_internal(extension)
+    }
+
+    /// Pauses the running deadline around a declared wait section, see [`DeadlineHandle::pause`].
+    pub fn pause(&mut self) -> Result<(), DeadlineError> {
+        self.0.pause_internal()
+    }
+
+    /// Resumes the paused deadline, see [`DeadlineHandle::resume`].
+    pub fn resume(&mut self) {
+        self.0.resume_internal();
+    }
+}
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        self.0.stop_internal();
+    }
+}
+
+/// A started deadline owning the [`Deadline`], see [`Deadline::start_owned`].
+/// Can be moved into another thread and stopped there. When dropped, it stops and releases the deadline.
+pub struct StartedDeadline(Option<Deadline>);
+
+impl StartedDeadline {
+    /// Stops the deadline and returns it for reuse.
+    pub fn stop(mut self) -> Deadline {
+        // Deadline is taken only here, so it is always present.
+        let mut deadline = self.0.take().expect("started deadline is stopped once");
+        deadline.stop_internal();
+        deadline
+    }
+
+    /// Extends the running deadline by `extension`, see [`DeadlineHandle::extend`].
+    pub fn extend(&mut self, extension: Duration) -> Result<(), DeadlineError> {
+        // Deadline is taken only when stopping, so it is always present.
+        self.0
+            .as_mut()
+            .expect("started deadline is stopped once")
+            .extend_internal(extension)
+    }
+
+    /// Pauses the running deadline around a declared wait section, see [`DeadlineHandle::pause`].
+    pub fn pause(&mut self) -> Result<(), DeadlineError> {
+        // Deadline is taken only when stopping, so it is always present.
+        self.0
+            .as_mut()
+            .expect("started deadline is stopped once")
+            .pause_internal()
+    }
+
+    /// Resumes the paused deadline, see [`DeadlineHandle::resume`].
+    pub fn resume(&mut self) {
+        // Deadline is taken only when stopping, so it is always present.
+        self.0
+            .as_mut()
+            .expect("started deadline is stopped once")
+            .resume_internal();
+    }
+}
+
+impl Drop for StartedDeadline {
+    fn drop(&mut self) {
+        if let Some(deadline) = self.0.as_mut() {
+            deadline.stop_internal();
+        }
+    }
+}
+
+/// Deadlines started together, see [`DeadlineMonitor::start_group`].
+/// When dropped, it stops all deadlines at a single timestamp and releases them.
+pub struct DeadlineGroup(Vec<Deadline>);
+
+impl DeadlineGroup {
+    /// Stops all deadlines of the group. This is equivalent to dropping the group.
+    /// Returns measurements of the stopped activations, in the order of the tags the group was started with,
+    /// see [`Deadline::last_measurement`].
+    pub fn stop(mut self) -> Vec<Option<DeadlineMeasurement>> {
+        let mut deadlines = core::mem::take(&mut self.0);
+        Self::stop_all(&mut deadlines);
+        deadlines.iter().map(Deadline::last_measurement).collect()
+    }
+
+    /// Number of deadlines in the group.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Group has no deadlines.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn stop_all(deadlines: &mut [Deadline]) {
+        let Some(first) = deadlines.first() else {
+            return;
+        };
+        let now = first.monitor.clock.now_ms();
+        for deadline in deadlines.iter_mut() {
+            deadline.stop_at_ms(now);
+        }
+    }
+}
+
+impl Drop for DeadlineGroup {
+    fn drop(&mut self) {
+        Self::stop_all(&mut self.0);
+    }
+}
+
+impl Deadline {
+    ///
+    /// Starts the deadline - it will be monitored by health monitoring system.
+    ///
+    /// # Returns
+    ///  - Ok(DeadlineHandle) - if the deadline was started successfully.
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline was already missed before
+    ///
+    pub fn start(&mut self) -> Result<DeadlineHandle<'_>, DeadlineError> {
+        // Safety: We ensure that the caller upholds the safety contract for FFI usage by using &'a mut self lifetime in DeadlineHandle
+        unsafe { self.start_internal(None).map(|_| DeadlineHandle(self)) }
+    }
+
+    ///
+    /// Starts the deadline with an opaque correlation ID, e.g. ID of a request or frame in a tracing system.
+    /// ID is logged and carried into [`crate::ViolationRecord`] if this activation of the deadline is violated.
+    ///
+    /// # Returns
+    ///  - Ok(DeadlineHandle) - if the deadline was started successfully.
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline was already missed before
+    ///
+    pub fn start_with_id(&mut self, correlation_id: u64) -> Result<DeadlineHandle<'_>, DeadlineError> {
+        // SAFETY: We ensure that the caller upholds the safety contract for FFI usage by using &'a mut self lifetime in DeadlineHandle
+        unsafe { self.start_internal(Some(correlation_id)).map(|_| DeadlineHandle(self)) }
+    }
+
+    ///
+    /// Starts the deadline and moves it into the returned [`StartedDeadline`],
+    /// so it can be stopped in another thread. Deadline is returned for reuse by [`StartedDeadline::stop`].
+    ///
+    /// # Returns
+    ///  - Ok(StartedDeadline) - if the deadline was started successfully.
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline was already missed before, deadline is released
+    ///
+    pub fn start_owned(mut self) -> Result<StartedDeadline, DeadlineError> {
+        // SAFETY: StartedDeadline owns the deadline, which is stopped exactly once.
+        unsafe { self.start_internal(None) }.map(|_| StartedDeadline(Some(self)))
+    }
+
+    ///
+    /// Starts the deadline at an externally captured time, e.g. hardware timestamp of an interrupt,
+    /// so the latency from the event to the completion is supervised.
+    /// Timestamps of other clocks are converted with [`super::ExternalClock`].
+    ///
+    /// # Returns
+    ///  - Ok(DeadlineHandle) - if the deadline was started successfully.
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline was already missed before
+    ///  - Err(DeadlineError::ImplausibleTimestamp) - if `started_at` is in the future or before the monitor was built
+    ///
+    pub fn start_at(&mut self, started_at: Instant) -> Result<DeadlineHandle<'_>, DeadlineError> {
+        // SAFETY: We ensure that the caller upholds the safety contract for FFI usage by using &'a mut self lifetime in DeadlineHandle
+        unsafe { self.start_at_internal(started_at).map(|_| DeadlineHandle(self)) }
+    }
+
+    /// Measured duration of the last stopped activation and whether it was within the range,
+    /// e.g. to log or adapt to actual timings. [`None`] if not stopped yet or if the duration could not be measured.
+    pub fn last_measurement(&self) -> Option<DeadlineMeasurement> {
+        self.last_measurement
+    }
+
+    ///
+    /// Ends the current cycle of a cyclic deadline and re-arms it for the next one,
+    /// see [`DeadlineMonitorBuilder::add_cyclic_deadline`]. First checkpoint arms the deadline.
+    /// Cyclic deadline is not started or stopped otherwise, dropping it ends supervision without evaluating the cycle.
+    ///
+    /// # Returns
+    ///  - Ok(Some(DeadlineMeasurement)) - measured period of the ended cycle.
+    ///  - Ok(None) - if the deadline was armed by this checkpoint.
+    ///  - Err(DeadlineError::NotCyclic) - if the deadline was not added as cyclic
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline was already missed before, it is not re-armed
+    ///
+    pub fn checkpoint(&mut self) -> Result<Option<DeadlineMeasurement>, DeadlineError> {
+        if !self.is_cyclic {
+            warn!("Checkpoint of deadline {:?} which is not cyclic", self.deadline_tag);
+            return Err(DeadlineError::NotCyclic);
+        }
+
+        // Next cycle starts when the previous one is stopped.
+        let now = self.monitor.clock.now_ms();
+        let measurement = if self.is_armed {
+            self.is_armed = false;
+            self.stop_at_ms(now);
+            self.last_measurement
+        } else {
+            None
+        };
+        self.start_at_ms(None, now)?;
+        self.is_armed = true;
+        Ok(measurement)
+    }
+
+    /// Ends supervision of the current cycle of a cyclic deadline without evaluating it, e.g. when the cyclic task
+    /// is paused. Cycle already past its range is kept for reporting. Next [`Self::checkpoint`] arms the deadline again.
+    pub fn disarm(&mut self) {
+        if !self.is_armed {
+            return;
+        }
+        self.is_armed = false;
+        let now = self.monitor.clock.now_ms();
+        self.discard_at_ms(now);
+    }
+
+    /// Ends the running activation at `now` ms since the monitor starting point without evaluating it.
+    /// Activation already past its range is kept for reporting.
+    fn discard_at_ms(&mut self, now: u64) {
+        let _ = self.monitor.active_deadlines[*self.state_index].1.update(|current| {
+            let is_missed = !current.is_unbounded() && now > current.timestamp_ms();
+            (current.is_running() && !is_missed).then(DeadlineStateSnapshot::default)
+        });
+    }
+
+    /// Starts the deadline at an externally captured time.
+    /// This function is for FFI usage only!
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::start_internal`].
+    pub(super) unsafe fn start_at_internal(&mut self, started_at: Instant) -> Result<(), DeadlineError> {
+        let Some(started_ms) = self.monitor.clock.instant_to_ms(started_at) else {
+            warn!(
+                "Trying to start deadline {:?} at implausible timestamp",
+                self.deadline_tag
+            );
+            return Err(DeadlineError::ImplausibleTimestamp);
+        };
+        self.start_at_ms(None, started_ms)
+    }
+
+    /// Starts the deadline - it will be monitored by health monitoring system.
+    /// This function is for FFI usage only!
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that deadline is not used until it's stopped.
+    /// After this call You shall assure there's only a single owner of the `Deadline` instance and it does not call start before stopping.
+    pub(super) unsafe fn start_internal(&mut self, correlation_id: Option<u64>) -> Result<(), DeadlineError> {
+        let now = self.monitor.clock.now_ms();
+        self.start_at_ms(correlation_id, now)
+    }
+
+    /// Starts the deadline at `started_ms` since the monitor starting point.
+    fn start_at_ms(&mut self, correlation_id: Option<u64>, started_ms: u64) -> Result<(), DeadlineError> {
+        self.monitor.record_reporter(self.state_index);
+        // Deadline without upper bound keeps its start time, as it is never late.
+        let is_unbounded = !self.range.has_upper_bound();
+        let max_time = if is_unbounded {
+            started_ms
+        } else {
+            started_ms + self.range.max_ms::<u64>()
+        };
+
+        let mut is_broken = false;
+        let mut is_joined = false;
+        let _ = self.monitor.active_deadlines[*self.state_index]
+            .1
+            .update(|mut current| {
+                is_joined = current.is_chained()
+                    && current.is_running()
+                    && (current.is_unbounded() || started_ms <= current.timestamp_ms());
+                if is_joined {
+                    // Activation started by the upstream deadline is continued.
+                    current.set_chained(false);
+                    return Some(current);
+                }
+                if current.is_running() || current.is_underrun() {
+                    is_broken = true;
+                    return None; // Deadline is already missed, do nothing
+                }
+
+                let mut new = DeadlineStateSnapshot::default();
+                new.set_timestamp_ms(max_time);
+                new.set_running();
+                if is_unbounded {
+                    new.set_unbounded();
+                }
+                Some(new)
+            });
+
+        if is_broken {
+            warn!("Trying to start deadline {:?} that already failed", self.deadline_tag);
+            Err(DeadlineError::DeadlineAlreadyFailed)
+        } else if is_joined {
+            // Start and correlation ID of the chained activation are kept, unless a new ID is given.
+            if correlation_id.is_some() {
+                self.monitor.correlations[*self.state_index].store(correlation_id);
+            }
+            self.monitor.start_scheduling(self.state_index);
+            Ok(())
         } else {
+            self.monitor.correlations[*self.state_index].store(correlation_id);
+            self.monitor.start_scheduling(self.state_index);
+            self.monitor.clear_offset(self.state_index);
+            self.monitor.clear_extensions(self.state_index);
+            let max_ms = u32::try_from(self.range.max_ms_or_unbounded()).unwrap_or(u32::MAX);
+            self.monitor.start_cpu_time(self.state_index, max_ms);
+            if let Some(idle) = &self.monitor.idle {
+                idle.record_activity(started_ms);
+            }
+            if !self.monitor.enabled.load(Ordering::Relaxed) {
+                self.monitor.disabled_activations.fetch_add(1, Ordering::Relaxed);
+            }
             Ok(())
         }
     }
 
+    /// Extends the running deadline by `extension`.
+    pub(super) fn extend_internal(&mut self, extension: Duration) -> Result<(), DeadlineError> {
+        self.monitor.record_reporter(self.state_index);
+        let Some(extension_slot) = self
+            .monitor
+            .extension_slot(*self.state_index)
+            .filter(|slot| slot.can_extend(self.monitor.max_extensions))
+        else {
+            warn!("Deadline {:?} cannot be extended any further", self.deadline_tag);
+            return Err(DeadlineError::ExtensionLimitReached);
+        };
+        if !self.range.has_upper_bound() {
+            // Nothing to extend, deadline without upper bound is never late.
+            return Ok(());
+        }
+        let extension_ms = u32::try_from(extension.as_millis()).unwrap_or(u32::MAX);
+        let now = self.monitor.clock.now_ms();
+        let cpu_time_slot = self.monitor.cpu_time_slot(*self.state_index);
+        // CPU-time deadlines are past their range only if their CPU time is.
+        let cpu_time_missed = cpu_time_slot.map(|slot| slot.overrun_ms().is_some());
+
+        let result = self.monitor.active_deadlines[*self.state_index]
+            .1
+            .update(|mut current| {
+                // Paused deadline holds its remaining time, which is never missed.
+                let is_missed = cpu_time_missed.unwrap_or_else(|| !current.is_paused() && now > current.timestamp_ms());
+                if !current.is_running() || current.is_underrun() || is_missed {
+                    return None; // Deadline past its range is already reported, do nothing
+                }
+                current.set_timestamp_ms(current.timestamp_ms() + u64::from(extension_ms));
+                Some(current)
+            });
+
+        if result.is_err() {
+            warn!("Trying to extend deadline {:?} that already failed", self.deadline_tag);
+            return Err(DeadlineError::DeadlineAlreadyFailed);
+        }
+        extension_slot.record(extension_ms);
+        if let Some(cpu_time_slot) = cpu_time_slot {
+            cpu_time_slot.extend(extension_ms);
+        }
+        Ok(())
+    }
+
+    /// Pauses the running deadline.
+    pub(super) fn pause_internal(&mut self) -> Result<(), DeadlineError> {
+        if self.is_paused {
+            return Ok(());
+        }
+        self.monitor.record_reporter(self.state_index);
+        let now = self.monitor.clock.now_ms();
+        let cpu_time_missed = self
+            .monitor
+            .cpu_time_slot(*self.state_index)
+            .map(|slot| slot.overrun_ms().is_some());
+
+        let result = self.monitor.active_deadlines[*self.state_index]
+            .1
+            .update(|mut current| {
+                let is_missed =
+                    cpu_time_missed.unwrap_or_else(|| !current.is_unbounded() && now > current.timestamp_ms());
+                if !current.is_running() || current.is_underrun() || is_missed {
+                    return None; // Deadline past its range is already reported, do nothing
+                }
+                // Remaining time, or elapsed time of an unbounded deadline, is kept relative to the pause.
+                current.set_timestamp_ms(current.timestamp_ms().abs_diff(now));
+                current.set_paused(true);
+                Some(current)
+            });
+
+        if result.is_err() {
+            warn!("Trying to pause deadline {:?} that already failed", self.deadline_tag);
+            return Err(DeadlineError::DeadlineAlreadyFailed);
+        }
+        self.is_paused = true;
+        Ok(())
+    }
+
+    /// Resumes the paused deadline.
+    pub(super) fn resume_internal(&mut self) {
+        let now = self.monitor.clock.now_ms();
+        self.resume_at_ms(now);
+    }
+
+    /// Resumes the paused deadline at `now` ms since the monitor starting point.
+    fn resume_at_ms(&mut self, now: u64) {
+        if !self.is_paused {
+            return;
+        }
+        self.is_paused = false;
+        self.monitor.record_reporter(self.state_index);
+        let _ = self.monitor.active_deadlines[*self.state_index]
+            .1
+            .update(|mut current| {
+                if !current.is_paused() {
+                    return None;
+                }
+                // Paused time pushes out both the start and the expected end.
+                let timestamp = if current.is_unbounded() {
+                    now.saturating_sub(current.timestamp_ms())
+                } else {
+                    now + current.timestamp_ms()
+                };
+                current.set_timestamp_ms(timestamp);
+                current.set_paused(false);
+                Some(current)
+            });
+    }
+
     pub(super) fn stop_internal(&mut self) {
-        let now = duration_to_int::<u32>(self.monitor.monitor_starting_point.elapsed());
-        let max = self.range.max.as_millis() as u32;
-        let min = self.range.min.as_millis() as u32;
+        let now = self.monitor.clock.now_ms();
+        self.stop_at_ms(now);
+    }
+
+    /// Stops the deadline at `now` ms since the monitor starting point.
+    fn stop_at_ms(&mut self, now: u64) {
+        self.resume_at_ms(now);
+        self.monitor.record_reporter(self.state_index);
+        // Upper bound of the range is pushed out by extensions of the activation, [`None`] if unbounded.
+        let max = self.range.has_upper_bound().then(|| {
+            self.range
+                .max_ms::<u32>()
+                .saturating_add(self.monitor.extended_ms(self.state_index))
+        });
 
         let mut possible_err = (None, 0);
+        let mut measured = None;
+        self.last_measurement = None;
+        // Violations of a disabled monitor are only counted, state is reset so the deadline can be reused.
+        let is_enabled = self.monitor.enabled.load(Ordering::Relaxed);
+
+        // CPU time of the running thread replaces wall-clock time, if measured.
+        let cpu_elapsed = self
+            .monitor
+            .cpu_time_slot(*self.state_index)
+            .and_then(CpuTimeSlot::elapsed_ms);
+
+        if let Some(template_index) = self.template_index.filter(|index| self.monitor.is_calibrating(*index)) {
+            self.stop_calibrating(template_index, now, max.unwrap_or(0), cpu_elapsed);
+            self.start_chained(now);
+            return;
+        }
 
         let _ = self.monitor.active_deadlines[*self.state_index]
             .1
@@ -192,34 +1463,151 @@ impl Deadline {
                     self.deadline_tag
                 );
 
-                let expected = current.timestamp_ms();
-                if expected < now {
-                    possible_err = (Some(DeadlineEvaluationError::TooLate), now - expected);
-                    return None; // Deadline missed, let state as is for BG thread to report
-                }
-
-                let start_time = expected - max;
-                let earliest_time = start_time + min;
-
-                if now < earliest_time {
-                    // Finished too early, leave it for reporting by BG thread
+                // Unbounded deadline holds its start time.
+                let start_time = current.timestamp_ms() - u64::from(max.unwrap_or(0));
+                let elapsed = cpu_elapsed.unwrap_or_else(|| elapsed_ms(start_time, now));
+                measured = Some(elapsed);
 
-                    current.set_underrun();
-                    possible_err = (Some(DeadlineEvaluationError::TooEarly), earliest_time - now);
-                    return Some(current);
+                match range_position(self.range.min_ms(), max.map_or(u64::MAX, u64::from), u64::from(elapsed)) {
+                    RangePosition::After(offset) => {
+                        possible_err = (Some(DeadlineEvaluationError::TooLate), offset as u32);
+                        if !is_enabled {
+                            return Some(DeadlineStateSnapshot::default());
+                        }
+                        None // Deadline missed, let state as is for BG thread to report
+                    },
+                    RangePosition::Before(offset) => {
+                        // Finished too early, leave it for reporting by BG thread
+                        possible_err = (Some(DeadlineEvaluationError::TooEarly), offset as u32);
+                        if !is_enabled {
+                            return Some(DeadlineStateSnapshot::default());
+                        }
+                        current.set_underrun();
+                        Some(current)
+                    },
+                    RangePosition::Within => Some(DeadlineStateSnapshot::default()), // Reset to stopped state as all fine
                 }
-
-                Some(DeadlineStateSnapshot::default()) // Reset to stopped state as all fine
             });
 
+        if is_enabled && possible_err.0.is_some() {
+            self.monitor.record_offset(self.state_index, possible_err.1);
+        }
+        if let (true, (Some(DeadlineEvaluationError::TooLate), overrun)) = (is_enabled, possible_err) {
+            if let Some(cpu_time_slot) = self.monitor.cpu_time_slot(*self.state_index) {
+                cpu_time_slot.stop_overrun(overrun);
+            }
+        }
+
         match possible_err {
             (Some(DeadlineEvaluationError::TooEarly), val) => {
                 error!("Deadline {:?} stopped too early by {} ms", self.deadline_tag, val);
+                self.log_correlation_id();
+                self.stop_scheduling();
             },
             (Some(DeadlineEvaluationError::TooLate), val) => {
                 error!("Deadline {:?} stopped too late by {} ms", self.deadline_tag, val);
+                self.log_correlation_id();
+                self.stop_scheduling();
+                if is_enabled
+                    && self
+                        .template_index
+                        .is_some_and(|template_index| self.monitor.capture_backtrace(template_index, val))
+                {
+                    info!("Backtrace of deadline {:?} captured", self.deadline_tag);
+                }
             },
-            (None, _) => {},
+            // Not produced by stopping a deadline.
+            (Some(DeadlineEvaluationError::BudgetExhausted | DeadlineEvaluationError::Idle), _) | (None, _) => {},
+        }
+
+        self.last_measurement = measured.map(|elapsed| DeadlineMeasurement {
+            elapsed: Duration::from_millis(u64::from(elapsed)),
+            in_range: possible_err.0.is_none(),
+        });
+        if let (Some(elapsed), Some(template_index)) = (measured, self.template_index) {
+            self.monitor.statistics[template_index].record(elapsed);
+            if possible_err.0.is_none() {
+                self.monitor.provide_heartbeats(template_index);
+                if self.monitor.warnings[template_index]
+                    .as_ref()
+                    .is_some_and(|warning| warning.record(elapsed))
+                {
+                    warn!(
+                        "Deadline {:?} stopped close to its range after {} ms",
+                        self.deadline_tag, elapsed
+                    );
+                }
+            }
+        }
+
+        if !is_enabled && possible_err.0.is_some() {
+            self.monitor.disabled_violations.fetch_add(1, Ordering::Relaxed);
+        }
+        self.start_chained(now);
+    }
+
+    /// Start the deadline chained to this one at `now`, carrying the correlation ID of the stopped activation.
+    fn start_chained(&self, now: u64) {
+        if let Some(template_index) = self.template_index {
+            let correlation_id = self.monitor.correlations[*self.state_index].load();
+            self.monitor.start_chained(template_index, now, correlation_id);
+        }
+    }
+
+    /// Log correlation ID of the current activation, if it was started with one.
+    fn log_correlation_id(&self) {
+        if let Some(correlation_id) = self.monitor.correlations[*self.state_index].load() {
+            error!("Deadline {:?} correlation ID: {}", self.deadline_tag, correlation_id);
+        }
+    }
+
+    /// Capture and log scheduler statistics of the violated activation, if sampled.
+    fn stop_scheduling(&self) {
+        let Some(statistics) = self.monitor.stop_scheduling(self.state_index) else {
+            return;
+        };
+        error!(
+            "Deadline {:?} scheduling: run time {} ms, run delay {} ms, involuntary context switches {}",
+            self.deadline_tag,
+            statistics.run_time.as_millis() as u64,
+            statistics.run_delay.as_millis() as u64,
+            statistics.involuntary_switches
+        );
+    }
+
+    /// Record duration of the activation and reset state without raising violations.
+    fn stop_calibrating(&mut self, template_index: usize, now: u64, max: u32, cpu_elapsed: Option<u32>) {
+        let mut elapsed = 0;
+        let _ = self.monitor.active_deadlines[*self.state_index].1.update(|current| {
+            elapsed = cpu_elapsed.unwrap_or_else(|| elapsed_ms(current.timestamp_ms() - u64::from(max), now));
+            Some(DeadlineStateSnapshot::default())
+        });
+        self.last_measurement = Some(DeadlineMeasurement {
+            elapsed: Duration::from_millis(u64::from(elapsed)),
+            in_range: true,
+        });
+        self.monitor.statistics[template_index].record(elapsed);
+        self.monitor.provide_heartbeats(template_index);
+
+        let Some(calibration) = self.monitor.calibration.as_ref() else {
+            return;
+        };
+        let Some(range) = self
+            .monitor
+            .calibration_slot(template_index)
+            .and_then(|slot| slot.record(elapsed, calibration.margin_percent))
+        else {
+            return;
+        };
+
+        info!(
+            "Deadline {:?} calibrated, suggested range: {} - {} ms",
+            self.deadline_tag,
+            range.min_ms::<u64>(),
+            range.max_ms::<u64>()
+        );
+        if calibration.policy == CalibrationPolicy::AutoApply {
+            self.range = range;
         }
     }
 
@@ -232,288 +1620,2431 @@ impl core::fmt::Debug for Deadline {
             .field("range", &self.range)
             .field("tag", &self.deadline_tag)
             .field("state_index", &self.state_index)
+            .field("template_index", &self.template_index)
             .finish()
     }
 }
 
 impl Drop for Deadline {
     fn drop(&mut self) {
-        self.monitor.release_deadline(self.deadline_tag);
+        self.disarm();
+        self.monitor.release_deadline(self.deadline_tag, self.state_index);
+    }
+}
+
+/// Evaluation handle of a deadline monitor held by a health monitor.
+/// Evaluation of the monitor is single reader, so [`DeadlineMonitor::evaluate_now`] fails while a handle exists.
+struct DeadlineMonitorHandle {
+    inner: Arc<DeadlineMonitorInner>,
+}
+
+impl DeadlineMonitorHandle {
+    fn new(inner: Arc<DeadlineMonitorInner>) -> Self {
+        // Wait for a running manual evaluation to finish.
+        while inner
+            .evaluators
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |evaluators| {
+                (evaluators != MANUAL_EVALUATION).then_some(evaluators + 1)
+            })
+            .is_err()
+        {
+            std::thread::yield_now();
+        }
+        Self { inner }
+    }
+}
+
+impl Drop for DeadlineMonitorHandle {
+    fn drop(&mut self) {
+        self.inner.evaluators.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl MonitorEvaluator for DeadlineMonitorHandle {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.inner.monitor_tag()
+    }
+
+    fn evaluate(
+        &self,
+        hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
+        self.inner.evaluate(hmon_starting_point, on_error)
+    }
+
+    fn collect_internal_faults(&self, on_fault: &mut dyn FnMut(&MonitorTag, InternalFault)) {
+        self.inner.collect_internal_faults(on_fault)
+    }
+
+    fn rebaseline(&self, hmon_starting_point: Instant, gap: Duration) {
+        self.inner.rebaseline(hmon_starting_point, gap)
     }
 }
 
+/// Value of [`DeadlineMonitorInner::evaluators`] while the monitor is evaluated with [`DeadlineMonitor::evaluate_now`].
+const MANUAL_EVALUATION: u32 = u32::MAX;
+
 struct DeadlineMonitorInner {
     /// Tag of this monitor.
     monitor_tag: MonitorTag,
 
-    /// Monitor starting point.
-    monitor_starting_point: Instant,
+    /// Number of evaluation handles held by health monitors, or [`MANUAL_EVALUATION`].
+    evaluators: AtomicU32,
+
+    /// Clock of the monitor, counting from the monitor starting point.
+    clock: MonitorClock,
 
     // Templates for deadlines registered in the monitor to create `Deadline` instances.
     deadlines: HashMap<DeadlineTag, DeadlineTemplate>,
 
-    // This is shared state. Each deadline template has assigned index into this array.
+    // This is shared state. Each deadline template has assigned index into this array, followed by its further instances.
     // Each deadline instance updates its state (under given index) and the deadline pointing to a state is Single-Producer
     // On the other side there is background thread evaluating all deadlines states - this is Single-Consumer for each given state.
     active_deadlines: Arc<[(DeadlineTag, DeadlineState)]>,
-}
 
-impl MonitorEvaluator for DeadlineMonitorInner {
-    fn evaluate(&self, _hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError)) {
-        for (deadline_tag, deadline) in self.active_deadlines.iter() {
-            let snapshot = deadline.snapshot();
-            if snapshot.is_underrun() {
-                // Deadline finished too early, report
-                warn!("Deadline ({:?}) finished too early!", deadline_tag);
+    /// Evaluation of the monitor is enabled.
+    enabled: AtomicBool,
 
-                // Here we would normally report the underrun to the monitoring system
-                on_error(&self.monitor_tag, DeadlineEvaluationError::TooEarly.into());
-            } else if snapshot.is_running() {
-                debug_assert!(
-                    snapshot.is_stopped(),
-                    "Deadline snapshot cannot be both running and stopped"
-                );
+    /// Deadlines started while the monitor was disabled.
+    disabled_activations: AtomicU64,
 
-                let now = duration_to_int::<u32>(self.monitor_starting_point.elapsed());
-                let expected = snapshot.timestamp_ms();
-                if now > expected {
-                    // Deadline missed, report
-                    warn!(
-                        "Deadline ({:?}) missed! Expected: {}, now: {}",
-                        deadline_tag, expected, now
-                    );
+    /// Deadlines stopped outside of their range while the monitor was disabled.
+    disabled_violations: AtomicU64,
 
-                    // Here we would normally report the missed deadline to the monitoring system
-                    on_error(&self.monitor_tag, DeadlineEvaluationError::TooLate.into());
-                }
+    /// Corrupted deadline states detected by evaluation, not yet reported as internal faults.
+    state_corruptions: AtomicU64,
+
+    /// Last thread using each deadline, indexed same as `active_deadlines`.
+    /// [`None`] if thread attribution is disabled.
+    reporters: Option<Box<[ReporterSlot]>>,
+
+    /// Correlation IDs of current activations of deadlines, indexed same as `active_deadlines`.
+    correlations: Box<[CorrelationSlot]>,
+
+    /// Backtraces of deadlines stopped too late, indexed by template and shared by its instances.
+    /// [`None`] if backtrace capture is disabled.
+    backtraces: Option<Box<[BacktraceSlot]>>,
+
+    /// Calibration settings, [`None`] if calibration is disabled.
+    calibration: Option<CalibrationConfig>,
+
+    /// Calibration state of deadlines, indexed by template and shared by its instances.
+    /// [`None`] if calibration is disabled.
+    calibrations: Option<Box<[CalibrationSlot]>>,
+
+    /// Scheduler statistics of current activations of deadlines, indexed same as `active_deadlines`.
+    /// [`None`] if scheduler statistics are disabled.
+    schedulings: Option<Box<[SchedulingSlot]>>,
+
+    /// Statistics of measured durations of deadlines, indexed by template and shared by its instances.
+    statistics: Box<[StatisticsSlot]>,
+
+    /// Index of the first state reserved for custom deadlines, reserved states follow states of all templates.
+    custom_base: usize,
+
+    /// Custom deadlines holding reserved states, indexed from `custom_base`.
+    custom_slots: Box<[CustomSlot]>,
+
+    /// Callbacks invoked on violations, indexed by template and shared by its instances.
+    callbacks: Box<[Option<DeadlineCallback>]>,
+
+    /// Deadlines re-armed by checkpoints, indexed by template.
+    cyclic: Box<[bool]>,
+
+    /// Time outside of the range measured on stop, indexed same as `active_deadlines`.
+    offsets: Box<[OffsetSlot]>,
+
+    /// Panics of callbacks, not yet reported as internal faults.
+    callback_panics: AtomicU64,
+
+    /// CPU time of current activations, indexed same as `active_deadlines`, [`None`] for wall-clock deadlines.
+    /// [`None`] if no deadline measures CPU time.
+    cpu_times: Option<Box<[Option<CpuTimeSlot>]>>,
+
+    /// Number of extensions allowed per activation of a deadline.
+    max_extensions: u32,
+
+    /// Extensions of current activations, indexed same as `active_deadlines`.
+    /// [`None`] if extensions are not allowed.
+    extensions: Option<Box<[ExtensionSlot]>>,
+
+    /// Heartbeats provided on successful activations, indexed by template and shared by its instances.
+    heartbeats: Box<[Box<[HeartbeatProducer]>]>,
+
+    /// Execution budgets, in order of their evaluation.
+    budgets: Box<[(DeadlineTag, Arc<BudgetSlot>)]>,
+
+    /// Time of the last start of any deadline, [`None`] if idleness detection is disabled.
+    idle: Option<IdleSlot>,
+
+    /// Near misses of deadlines, indexed by template and shared by its instances, [`None`] without warning threshold.
+    warnings: Box<[Option<WarningSlot>]>,
+
+    /// Callback invoked on near misses, [`None`] if not set.
+    warning_callback: Option<WarningCallback>,
+
+    /// Deadlines started on stops, indexed by template of the upstream deadline, [`None`] if not chained.
+    chains: Box<[Option<DeadlineTag>]>,
+
+    /// Violations injected into deadlines, indexed by template.
+    #[cfg(feature = "fault_injection")]
+    injections: Box<[InjectionSlot]>,
+}
+
+impl MonitorEvaluator for DeadlineMonitorInner {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.monitor_tag
+    }
+
+    fn evaluate(
+        &self,
+        _hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
+        // Aggregation is moved out of the hot path of stopping deadlines.
+        for statistics in self.statistics.iter() {
+            statistics.aggregate();
+        }
+
+        if !self.enabled.load(Ordering::Relaxed) {
+            // Violations are accounted when deadlines are stopped, budgets exhausted while disabled are dropped.
+            for (_, budget) in self.budgets.iter() {
+                budget.take_exhausted();
+            }
+            if let Some(idle) = &self.idle {
+                idle.reset();
+            }
+            for warning in self.warnings.iter().flatten() {
+                warning.take_longest();
+            }
+            #[cfg(feature = "fault_injection")]
+            for injection in self.injections.iter() {
+                injection.take();
+            }
+            return;
+        }
+
+        #[cfg(feature = "fault_injection")]
+        for (deadline_tag, template) in self.deadlines.iter() {
+            let Some(violation) = self.injections[template.template_index].take() else {
+                continue;
+            };
+            let error = DeadlineEvaluationError::from(violation);
+            warn!("Deadline ({:?}) injected violation {:?}!", deadline_tag, error);
+            let index = *template.assigned_state_index;
+            on_error(
+                &self.monitor_tag,
+                error.into(),
+                self.violation_context(deadline_tag, index, 0),
+            );
+            self.invoke_callback(deadline_tag, index, error, 0);
+        }
+
+        for (deadline_tag, template) in self.deadlines.iter() {
+            let Some(longest_ms) = self.warnings[template.template_index]
+                .as_ref()
+                .and_then(WarningSlot::take_longest)
+            else {
+                continue;
+            };
+            if let Some(callback) = &self.warning_callback {
+                if !callback.invoke(*deadline_tag, Duration::from_millis(u64::from(longest_ms))) {
+                    self.callback_panics.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+
+        if let Some(idle) = &self.idle {
+            let now = self.clock.now_ms();
+            if let Some(idle_ms) = idle.check(now) {
+                match idle.severity() {
+                    IdleSeverity::Info => {
+                        info!(
+                            "Deadline monitor {:?} idle, no deadline started for {} ms",
+                            self.monitor_tag, idle_ms
+                        );
+                    },
+                    IdleSeverity::Error => {
+                        warn!(
+                            "Deadline monitor {:?} idle, no deadline started for {} ms!",
+                            self.monitor_tag, idle_ms
+                        );
+                        on_error(
+                            &self.monitor_tag,
+                            DeadlineEvaluationError::Idle.into(),
+                            ViolationContext::default(),
+                        );
+                    },
+                }
+            }
+        }
+
+        for (budget_tag, budget) in self.budgets.iter() {
+            let exhausted = budget.take_exhausted();
+            if exhausted > 0 {
+                warn!("Budget ({:?}) exhausted in {} windows!", budget_tag, exhausted);
+                on_error(
+                    &self.monitor_tag,
+                    DeadlineEvaluationError::BudgetExhausted.into(),
+                    ViolationContext::default(),
+                );
+            }
+        }
+
+        for (index, (deadline_tag, deadline)) in self.active_deadlines.iter().enumerate() {
+            let custom_tag = self.custom_deadline_tag(index);
+            let deadline_tag = custom_tag.as_ref().unwrap_or(deadline_tag);
+            if index < self.custom_base
+                && self.calibrations.is_some()
+                && self
+                    .deadlines
+                    .get(deadline_tag)
+                    .is_some_and(|template| self.is_calibrating(template.template_index))
+            {
+                // Calibrating deadlines never raise violations.
+                continue;
+            }
+
+            let snapshot = deadline.snapshot();
+            if snapshot.is_underrun() {
+                // Deadline finished too early, report
+                warn!("Deadline ({:?}) finished too early!", deadline_tag);
+
+                // Here we would normally report the underrun to the monitoring system
+                let offset_ms = self.stopped_offset(index).unwrap_or(0);
+                on_error(
+                    &self.monitor_tag,
+                    DeadlineEvaluationError::TooEarly.into(),
+                    self.violation_context(deadline_tag, index, offset_ms),
+                );
+                self.invoke_callback(deadline_tag, index, DeadlineEvaluationError::TooEarly, offset_ms);
+            } else if snapshot.is_running() {
+                if !snapshot.is_stopped() {
+                    // Running state is always derived from stopped state.
+                    error!("Deadline ({:?}) state is corrupted: {:?}", deadline_tag, snapshot);
+                    self.state_corruptions.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+                if snapshot.is_unbounded() {
+                    // Deadline without upper bound is never late.
+                    continue;
+                }
+
+                if let Some(cpu_time_slot) = self.cpu_time_slot(index) {
+                    // Wall-clock time of CPU-time deadlines only bounds their CPU time.
+                    let Some(overrun_ms) = cpu_time_slot.overrun_ms() else {
+                        continue;
+                    };
+                    warn!(
+                        "Deadline ({:?}) missed! CPU time exceeded by {} ms",
+                        deadline_tag, overrun_ms
+                    );
+                    on_error(
+                        &self.monitor_tag,
+                        DeadlineEvaluationError::TooLate.into(),
+                        self.violation_context(deadline_tag, index, overrun_ms),
+                    );
+                    self.invoke_callback(deadline_tag, index, DeadlineEvaluationError::TooLate, overrun_ms);
+                    continue;
+                }
+
+                if snapshot.is_paused() {
+                    // Paused deadline holds its remaining time instead of the expected end.
+                    continue;
+                }
+
+                let now = self.clock.now_ms();
+                let expected = snapshot.timestamp_ms();
+                if now > expected {
+                    // Deadline missed, report
+                    warn!(
+                        "Deadline ({:?}) missed! Expected: {}, now: {}",
+                        deadline_tag, expected, now
+                    );
+
+                    if snapshot.is_chained() {
+                        warn!(
+                            "Deadline ({:?}) missed before its holder joined the chained activation!",
+                            deadline_tag
+                        );
+                    }
+
+                    // Here we would normally report the missed deadline to the monitoring system
+                    let offset_ms = self.stopped_offset(index).unwrap_or_else(|| elapsed_ms(expected, now));
+                    on_error(
+                        &self.monitor_tag,
+                        DeadlineEvaluationError::TooLate.into(),
+                        self.violation_context(deadline_tag, index, offset_ms),
+                    );
+                    self.invoke_callback(deadline_tag, index, DeadlineEvaluationError::TooLate, offset_ms);
+                }
+            }
+        }
+    }
+
+    fn collect_internal_faults(&self, on_fault: &mut dyn FnMut(&MonitorTag, InternalFault)) {
+        if self.state_corruptions.swap(0, Ordering::Relaxed) > 0 {
+            on_fault(&self.monitor_tag, InternalFault::StateCorruption);
+        }
+        if self.callback_panics.swap(0, Ordering::Relaxed) > 0 {
+            on_fault(&self.monitor_tag, InternalFault::CallbackPanicked);
+        }
+    }
+
+    fn rebaseline(&self, _hmon_starting_point: Instant, gap: Duration) {
+        // Running deadlines are extended by the gap, as if they were started after it.
+        let gap_ms = duration_to_int::<u64>(gap);
+        for (_, deadline) in self.active_deadlines.iter() {
+            let _ = deadline.update(|mut current| {
+                // Paused deadlines are relative to their pause, which the gap does not move.
+                if !current.is_running() || current.is_underrun() || current.is_paused() {
+                    return None;
+                }
+                current.set_timestamp_ms(current.timestamp_ms() + gap_ms);
+                Some(current)
+            });
+        }
+        if let Some(idle) = &self.idle {
+            idle.rebaseline(gap_ms);
+        }
+    }
+}
+
+impl DeadlineMonitorInner {
+    fn new(monitor_tag: MonitorTag, builder: DeadlineMonitorBuilder) -> Self {
+        let DeadlineMonitorBuilder {
+            deadlines,
+            max_instances,
+            custom_deadlines,
+            thread_attribution,
+            backtrace_max_bytes,
+            calibration,
+            scheduling_statistics,
+            mut callbacks,
+            mut cpu_deadlines,
+            cyclic_deadlines,
+            max_extensions,
+            mut heartbeats,
+            budgets,
+            idle,
+            mut warnings,
+            warning_callback,
+            mut chains,
+            clock_source,
+        } = builder;
+        if !cpu_deadlines.is_empty() && !cpu_time::is_supported() {
+            warn!("CPU-time deadlines are not supported on this platform, measuring wall-clock time instead.");
+            cpu_deadlines.clear();
+        }
+        let mut active_deadlines = vec![];
+        let num_templates = deadlines.len();
+        let mut template_callbacks: Vec<_> = (0..num_templates).map(|_| None).collect();
+        let mut template_cyclic = vec![false; num_templates];
+        let mut template_warnings: Vec<_> = (0..num_templates).map(|_| None).collect();
+        let mut template_chains = vec![None; num_templates];
+        let mut template_heartbeats: Vec<Box<[HeartbeatProducer]>> =
+            (0..num_templates).map(|_| Box::default()).collect();
+        // States of CPU-time deadlines, indexed same as `active_deadlines`.
+        let mut cpu_states = vec![];
+
+        let deadlines = deadlines
+            .into_iter()
+            .enumerate()
+            .map(|(template_index, (deadline_tag, range))| {
+                let instances = max_instances.get(&deadline_tag).copied().unwrap_or(1);
+                template_callbacks[template_index] = callbacks.remove(&deadline_tag);
+                template_cyclic[template_index] = cyclic_deadlines.contains(&deadline_tag);
+                template_warnings[template_index] = warnings.remove(&deadline_tag).map(WarningSlot::new);
+                template_chains[template_index] = chains.remove(&deadline_tag);
+                if let Some(producers) = heartbeats.remove(&deadline_tag) {
+                    template_heartbeats[template_index] = producers.into();
+                }
+                cpu_states.resize(
+                    active_deadlines.len() + instances,
+                    cpu_deadlines.contains(&deadline_tag),
+                );
+                let state_index = StateIndex::new(active_deadlines.len());
+                active_deadlines.extend((0..instances).map(|_| (deadline_tag, DeadlineState::new())));
+                let template = DeadlineTemplate::new(range, state_index, template_index, instances);
+                (deadline_tag, template)
+            })
+            .collect();
+
+        // Reserved states are reported under the tag of the custom deadline holding them.
+        let custom_base = active_deadlines.len();
+        active_deadlines.extend((0..custom_deadlines).map(|_| (DeadlineTag::from("custom"), DeadlineState::new())));
+        let custom_slots = (0..custom_deadlines).map(|_| CustomSlot::new()).collect();
+
+        let reporters = thread_attribution.then(|| active_deadlines.iter().map(|_| ReporterSlot::new()).collect());
+        let correlations = active_deadlines.iter().map(|_| CorrelationSlot::new()).collect();
+        let statistics = (0..num_templates).map(|_| StatisticsSlot::new()).collect();
+        let backtraces =
+            backtrace_max_bytes.map(|max_bytes| (0..num_templates).map(|_| BacktraceSlot::new(max_bytes)).collect());
+        let schedulings =
+            scheduling_statistics.then(|| active_deadlines.iter().map(|_| SchedulingSlot::new()).collect());
+        let cpu_times = cpu_states.contains(&true).then(|| {
+            (0..active_deadlines.len())
+                .map(|index| cpu_states.get(index).copied().unwrap_or(false).then(CpuTimeSlot::new))
+                .collect()
+        });
+        let offsets = active_deadlines.iter().map(|_| OffsetSlot::new()).collect();
+        let extensions = (max_extensions > 0).then(|| active_deadlines.iter().map(|_| ExtensionSlot::new()).collect());
+        let calibrations = calibration.map(|calibration| {
+            (0..num_templates)
+                .map(|_| CalibrationSlot::new(calibration.activations))
+                .collect()
+        });
+
+        Self {
+            monitor_tag,
+            evaluators: AtomicU32::new(0),
+            deadlines,
+            active_deadlines: active_deadlines.into(),
+            clock: MonitorClock::new(clock_source),
+            enabled: AtomicBool::new(true),
+            disabled_activations: AtomicU64::new(0),
+            disabled_violations: AtomicU64::new(0),
+            state_corruptions: AtomicU64::new(0),
+            reporters,
+            correlations,
+            backtraces,
+            calibration,
+            calibrations,
+            schedulings,
+            statistics,
+            custom_base,
+            custom_slots,
+            callbacks: template_callbacks.into(),
+            cyclic: template_cyclic.into(),
+            offsets,
+            callback_panics: AtomicU64::new(0),
+            cpu_times,
+            max_extensions,
+            extensions,
+            heartbeats: template_heartbeats.into(),
+            budgets: budgets
+                .into_iter()
+                .map(|(budget_tag, config)| (budget_tag, Arc::new(BudgetSlot::new(config))))
+                .collect(),
+            idle: idle.map(IdleSlot::new),
+            warnings: template_warnings.into(),
+            warning_callback,
+            chains: template_chains.into(),
+            #[cfg(feature = "fault_injection")]
+            injections: (0..num_templates).map(|_| InjectionSlot::new()).collect(),
+        }
+    }
+
+    /// Custom slot reserving state under `index`, [`None`] if the state belongs to a template.
+    fn custom_slot(&self, index: usize) -> Option<&CustomSlot> {
+        index
+            .checked_sub(self.custom_base)
+            .and_then(|custom_index| self.custom_slots.get(custom_index))
+    }
+
+    /// Tag of the custom deadline holding state under `index`, [`None`] if the state belongs to a template.
+    fn custom_deadline_tag(&self, index: usize) -> Option<DeadlineTag> {
+        self.custom_slot(index).and_then(CustomSlot::deadline_tag)
+    }
+
+    /// Calibration state of deadline under `template_index`, [`None`] if calibration is disabled.
+    fn calibration_slot(&self, template_index: usize) -> Option<&CalibrationSlot> {
+        self.calibrations
+            .as_ref()
+            .map(|calibrations| &calibrations[template_index])
+    }
+
+    /// Deadline under `template_index` is still being calibrated.
+    fn is_calibrating(&self, template_index: usize) -> bool {
+        self.calibration_slot(template_index)
+            .is_some_and(CalibrationSlot::is_calibrating)
+    }
+
+    /// Range used by deadline under `template_index`, suggested range replaces the configured one if auto-applied.
+    fn effective_range(&self, template_index: usize, configured: TimeRange) -> TimeRange {
+        match self.calibration {
+            Some(CalibrationConfig {
+                policy: CalibrationPolicy::AutoApply,
+                ..
+            }) => self
+                .calibration_slot(template_index)
+                .and_then(CalibrationSlot::suggestion)
+                .unwrap_or(configured),
+            _ => configured,
+        }
+    }
+
+    /// Record current thread as the last one using deadline under `state_index`.
+    fn record_reporter(&self, state_index: StateIndex) {
+        if let Some(reporters) = &self.reporters {
+            reporters[*state_index].record();
+        }
+    }
+
+    /// Capture backtrace of the current thread for deadline under `template_index`.
+    /// Returns `true` if backtrace was captured.
+    fn capture_backtrace(&self, template_index: usize, overrun_ms: u32) -> bool {
+        self.backtraces
+            .as_ref()
+            .is_some_and(|backtraces| backtraces[template_index].capture(Duration::from_millis(u64::from(overrun_ms))))
+    }
+
+    /// Sample scheduler statistics of the current thread starting deadline under `state_index`.
+    fn start_scheduling(&self, state_index: StateIndex) {
+        if let Some(schedulings) = &self.schedulings {
+            schedulings[*state_index].start();
+        }
+    }
+
+    /// Capture scheduler statistics of deadline under `state_index` stopped outside of its range.
+    fn stop_scheduling(&self, state_index: StateIndex) -> Option<SchedulingStatistics> {
+        self.schedulings
+            .as_ref()
+            .and_then(|schedulings| schedulings[*state_index].stop())
+    }
+
+    /// Forget time outside of the range of the previous activation of deadline under `state_index`.
+    fn clear_offset(&self, state_index: StateIndex) {
+        self.offsets[*state_index].clear();
+    }
+
+    /// Record time outside of the range of deadline under `state_index` stopped outside of its range.
+    fn record_offset(&self, state_index: StateIndex, offset_ms: u32) {
+        self.offsets[*state_index].store(offset_ms);
+    }
+
+    /// Time outside of the range measured when deadline under `index` was stopped.
+    fn stopped_offset(&self, index: usize) -> Option<u32> {
+        self.offsets[index].load()
+    }
+
+    /// Invoke callback of deadline under `index` violated by `offset_ms`, if it has one.
+    fn invoke_callback(
+        &self,
+        deadline_tag: &DeadlineTag,
+        index: usize,
+        error: DeadlineEvaluationError,
+        offset_ms: u32,
+    ) {
+        if index >= self.custom_base {
+            return;
+        }
+        let Some(callback) = self
+            .deadlines
+            .get(deadline_tag)
+            .and_then(|template| self.callbacks[template.template_index].as_ref())
+        else {
+            return;
+        };
+        if !callback.invoke(deadline_tag, error, Duration::from_millis(u64::from(offset_ms))) {
+            self.callback_panics.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// CPU time of deadline under `index`, [`None`] if it measures wall-clock time.
+    fn cpu_time_slot(&self, index: usize) -> Option<&CpuTimeSlot> {
+        self.cpu_times.as_ref().and_then(|cpu_times| cpu_times[index].as_ref())
+    }
+
+    /// Start measuring CPU time of the current thread running deadline under `state_index`.
+    fn start_cpu_time(&self, state_index: StateIndex, max_ms: u32) {
+        if let Some(cpu_time_slot) = self.cpu_time_slot(*state_index) {
+            cpu_time_slot.start(max_ms);
+        }
+    }
+
+    /// Extensions of deadline under `index`, [`None`] if extensions are not allowed.
+    fn extension_slot(&self, index: usize) -> Option<&ExtensionSlot> {
+        self.extensions.as_ref().map(|extensions| &extensions[index])
+    }
+
+    /// Forget extensions of the previous activation of deadline under `state_index`.
+    fn clear_extensions(&self, state_index: StateIndex) {
+        if let Some(extension_slot) = self.extension_slot(*state_index) {
+            extension_slot.clear();
+        }
+    }
+
+    /// Total extension of the current activation of deadline under `state_index` in milliseconds.
+    fn extended_ms(&self, state_index: StateIndex) -> u32 {
+        self.extension_slot(*state_index).map_or(0, ExtensionSlot::extended_ms)
+    }
+
+    /// Start the deadline chained to the deadline under `template_index` at `now` on behalf of the upstream deadline.
+    /// Chained deadline still running from its previous activation is not started again.
+    fn start_chained(&self, template_index: usize, now: u64, correlation_id: Option<u64>) {
+        let Some((chained_tag, template)) = self.chains[template_index]
+            .and_then(|chained_tag| self.deadlines.get(&chained_tag).map(|template| (chained_tag, template)))
+        else {
+            return;
+        };
+        let state_index = template.assigned_state_index;
+        let range = self.effective_range(template.template_index, template.range());
+        // Deadline without upper bound keeps its start time, as it is never late.
+        let is_unbounded = !range.has_upper_bound();
+        let max_time = if is_unbounded { now } else { now + range.max_ms::<u64>() };
+
+        let result = self.active_deadlines[*state_index].1.update(|current| {
+            if current.is_running() || current.is_underrun() {
+                return None; // Previous activation is not finished or not reported yet
+            }
+            let mut new = DeadlineStateSnapshot::default();
+            new.set_timestamp_ms(max_time);
+            new.set_running();
+            new.set_chained(true);
+            if is_unbounded {
+                new.set_unbounded();
+            }
+            Some(new)
+        });
+        if result.is_err() {
+            warn!(
+                "Chained deadline {:?} is not finished yet, it is not started again",
+                chained_tag
+            );
+            return;
+        }
+
+        self.record_reporter(state_index);
+        self.correlations[*state_index].store(correlation_id);
+        self.clear_offset(state_index);
+        self.clear_extensions(state_index);
+        if let Some(idle) = &self.idle {
+            idle.record_activity(now);
+        }
+        if !self.enabled.load(Ordering::Relaxed) {
+            self.disabled_activations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Provide heartbeats derived from a successful activation of deadline under `template_index`.
+    fn provide_heartbeats(&self, template_index: usize) {
+        for producer in self.heartbeats[template_index].iter() {
+            producer.heartbeat();
+        }
+    }
+
+    /// Last thread using deadline under `index`.
+    fn last_reporter(&self, index: usize) -> Option<ReporterThread> {
+        self.reporters.as_ref().and_then(|reporters| reporters[index].load())
+    }
+
+    /// Context of a violation of deadline under `index`, outside of its range by `offset_ms`.
+    fn violation_context(&self, deadline_tag: &DeadlineTag, index: usize, offset_ms: u32) -> ViolationContext {
+        let correlation_id = self.correlations[index].load();
+        if let Some(correlation_id) = correlation_id {
+            warn!("Deadline ({:?}) correlation ID: {}", deadline_tag, correlation_id);
+        }
+        ViolationContext {
+            reporter: self.last_reporter(index),
+            correlation_id,
+            scheduling: self
+                .schedulings
+                .as_ref()
+                .and_then(|schedulings| schedulings[index].load()),
+            deadline_tag: Some(*deadline_tag),
+            source_tag: None,
+            offset: Some(Duration::from_millis(u64::from(offset_ms))),
+        }
+    }
+
+    fn release_deadline(&self, deadline_tag: DeadlineTag, state_index: StateIndex) {
+        if let Some(custom_slot) = self.custom_slot(*state_index) {
+            custom_slot.release();
+        } else if let Some(template) = self.deadlines.get(&deadline_tag) {
+            template.release_deadline(state_index);
+        } else {
+            unreachable!("Releasing unknown deadline tag: {:?}", deadline_tag);
+        }
+    }
+
+    pub(crate) fn get_deadline(
+        self: &Arc<Self>,
+        deadline_tag: DeadlineTag,
+        holder: DeadlineHolder,
+    ) -> Result<Deadline, DeadlineMonitorError> {
+        if let Some(template) = self.deadlines.get(&deadline_tag) {
+            match template.acquire_deadline(holder) {
+                Some((range, state_index)) => Ok(Deadline {
+                    range: self.effective_range(template.template_index, range),
+                    deadline_tag,
+                    monitor: self.clone(),
+                    state_index,
+                    template_index: Some(template.template_index),
+                    last_measurement: None,
+                    is_cyclic: self.cyclic[template.template_index],
+                    is_armed: false,
+                    is_paused: false,
+                }),
+                None => Err(DeadlineMonitorError::DeadlineInUse),
             }
+        } else {
+            Err(DeadlineMonitorError::DeadlineNotFound)
+        }
+    }
+
+    fn create_custom_deadline(
+        self: &Arc<Self>,
+        deadline_tag: DeadlineTag,
+        range: TimeRange,
+    ) -> Result<Deadline, DeadlineMonitorError> {
+        if !range.has_upper_bound() {
+            error!("Custom deadline {:?} must have an upper bound.", deadline_tag);
+            return Err(DeadlineMonitorError::InvalidRange);
         }
+
+        // States left failed by earlier custom deadlines are kept for reporting.
+        let custom_index = (0..self.custom_slots.len())
+            .find(|custom_index| {
+                let snapshot = self.active_deadlines[self.custom_base + custom_index].1.snapshot();
+                !snapshot.is_running()
+                    && !snapshot.is_underrun()
+                    && self.custom_slots[*custom_index].claim(deadline_tag)
+            })
+            .ok_or_else(|| {
+                warn!("No state available for custom deadline {:?}.", deadline_tag);
+                DeadlineMonitorError::NoCustomDeadlineSlot
+            })?;
+
+        Ok(Deadline {
+            range,
+            deadline_tag,
+            monitor: self.clone(),
+            state_index: StateIndex::new(self.custom_base + custom_index),
+            template_index: None,
+            last_measurement: None,
+            is_cyclic: false,
+            is_armed: false,
+            is_paused: false,
+        })
     }
 }
 
-impl DeadlineMonitorInner {
-    fn new(monitor_tag: MonitorTag, deadlines: HashMap<DeadlineTag, TimeRange>) -> Self {
-        let mut active_deadlines = vec![];
+/// Milliseconds elapsed from `start_ms` to `now_ms`, saturated to the range of `u32`.
+fn elapsed_ms(start_ms: u64, now_ms: u64) -> u32 {
+    u32::try_from(now_ms.saturating_sub(start_ms)).unwrap_or(u32::MAX)
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+    use crate::deadline::ExternalClock;
+
+    fn create_monitor_with_deadlines() -> DeadlineMonitor {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline_long"),
+                TimeRange::new(core::time::Duration::from_secs(1), core::time::Duration::from_secs(50)),
+            )
+            .add_deadline(
+                DeadlineTag::from("deadline_fast"),
+                TimeRange::new(
+                    core::time::Duration::from_millis(0),
+                    core::time::Duration::from_millis(50),
+                ),
+            )
+            .build(monitor_tag, &allocator)
+    }
+
+    fn create_monitor_with_multiple_running_deadlines() -> DeadlineMonitor {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("slow"),
+                TimeRange::new(core::time::Duration::from_secs(0), core::time::Duration::from_secs(50)),
+            )
+            .add_deadline(
+                DeadlineTag::from("deadline_fast1"),
+                TimeRange::new(
+                    core::time::Duration::from_millis(0),
+                    core::time::Duration::from_millis(50),
+                ),
+            )
+            .add_deadline(
+                DeadlineTag::from("deadline_fast2"),
+                TimeRange::new(
+                    core::time::Duration::from_millis(0),
+                    core::time::Duration::from_millis(34),
+                ),
+            )
+            .add_deadline(
+                DeadlineTag::from("deadline_fast3"),
+                TimeRange::new(
+                    core::time::Duration::from_millis(0),
+                    core::time::Duration::from_millis(10),
+                ),
+            )
+            .build(monitor_tag, &allocator)
+    }
+
+    #[test]
+    fn get_deadline_unknown_tag() {
+        let monitor = create_monitor_with_deadlines();
+        let result = monitor.get_deadline(DeadlineTag::from("unknown"));
+        assert_eq!(result.err(), Some(DeadlineMonitorError::DeadlineNotFound));
+    }
+
+    #[test]
+    fn get_deadline_in_use_and_holder() {
+        let monitor = create_monitor_with_deadlines();
+        let deadline_tag = DeadlineTag::from("deadline_long");
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(None));
+
+        let deadline = monitor.get_deadline(deadline_tag).unwrap();
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(Some(DeadlineHolder::Rust)));
+        assert_eq!(
+            monitor.get_deadline(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+        assert_eq!(
+            monitor.get_deadline_ffi(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+
+        drop(deadline);
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(None));
+        assert_eq!(
+            monitor.deadline_holder(DeadlineTag::from("unknown")),
+            Err(DeadlineMonitorError::DeadlineNotFound)
+        );
+    }
+
+    fn create_monitor_with_concurrent_deadline() -> DeadlineMonitor {
+        let allocator = ProtectedMemoryAllocator {};
+        DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("single"),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(50)),
+            )
+            .add_deadline_concurrent(
+                DeadlineTag::from("concurrent"),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(50)),
+                2,
+            )
+            .build(MonitorTag::from("deadline_monitor"), &allocator)
+    }
+
+    #[test]
+    fn deadline_guard_holds_started_deadline() {
+        let monitor = create_monitor_with_deadlines();
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+
+        let guard = monitor.get_deadline_guard(deadline_tag).unwrap();
+        assert!(monitor
+            .inner
+            .active_deadlines
+            .iter()
+            .any(|(tag, state)| *tag == deadline_tag && state.snapshot().is_running()));
+        assert_eq!(
+            monitor.get_deadline(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+
+        // Guard is stopped and released when moved out and dropped elsewhere.
+        let stop = move |guard: DeadlineGuard| guard.stop();
+        stop(guard);
+        assert!(monitor
+            .inner
+            .active_deadlines
+            .iter()
+            .all(|(_, state)| !state.snapshot().is_running()));
+        assert!(monitor.get_deadline(deadline_tag).is_ok());
+        assert_eq!(
+            monitor.get_deadline_guard(DeadlineTag::from("unknown")).err(),
+            Some(DeadlineMonitorError::DeadlineNotFound)
+        );
+    }
+
+    #[test]
+    fn deadline_guard_of_failed_deadline() {
+        let monitor = create_monitor_with_deadlines();
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let hmon_starting_point = Instant::now();
+
+        let guard = monitor.get_deadline_guard(deadline_tag).unwrap();
+        std::thread::sleep(Duration::from_millis(80));
+        drop(guard);
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, _, _| {});
+
+        assert_eq!(
+            monitor.get_deadline_guard(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineAlreadyFailed)
+        );
+        // Deadline is released after the failed start.
+        assert!(monitor.get_deadline(deadline_tag).is_ok());
+    }
+
+    #[test]
+    fn started_deadline_moved_across_threads() {
+        fn assert_send<T: Send>() {}
+        assert_send::<StartedDeadline>();
+
+        let monitor = create_monitor_with_deadlines();
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let hmon_starting_point = Instant::now();
+
+        let started = monitor.get_deadline(deadline_tag).unwrap().start_owned().unwrap();
+        let deadline = std::thread::spawn(move || started.stop()).join().unwrap();
+        let started = deadline.start_owned().unwrap();
+        assert_eq!(
+            monitor.get_deadline(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+
+        // Dropped started deadline is stopped and released.
+        std::thread::spawn(move || drop(started)).join().unwrap();
+        assert!(monitor.get_deadline(deadline_tag).is_ok());
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+        assert_eq!(monitor.stats(deadline_tag).unwrap().count, 2);
+    }
+
+    #[test]
+    fn deadline_start_at_external_timestamp() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        std::thread::sleep(Duration::from_millis(80));
+
+        // Latency from the event already exceeds the range when the deadline is started.
+        let clock = ExternalClock::sync(1_000_000_000);
+        let started_at = clock.to_instant(1_000_000_000 - 70_000_000).unwrap();
+        deadline.start_at(started_at).unwrap().stop();
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn deadline_start_at_implausible_timestamp() {
+        let monitor = create_monitor_with_deadlines();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+
+        let future = Instant::now() + Duration::from_secs(1);
+        assert_eq!(
+            deadline.start_at(future).err(),
+            Some(DeadlineError::ImplausibleTimestamp)
+        );
+        let before_monitor = monitor.inner.clock.starting_point() - Duration::from_millis(1);
+        assert_eq!(
+            deadline.start_at(before_monitor).err(),
+            Some(DeadlineError::ImplausibleTimestamp)
+        );
+        assert!(deadline.start_at(Instant::now()).is_ok());
+    }
+
+    #[test]
+    fn measure_runs_closure_under_deadline() {
+        let monitor = create_monitor_with_deadlines();
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let hmon_starting_point = Instant::now();
+
+        assert_eq!(monitor.measure(deadline_tag, || 42), Ok(42));
+        assert_eq!(monitor.stats(deadline_tag).unwrap().count, 1);
+
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        let result = monitor.measure(deadline_tag, || std::thread::sleep(Duration::from_millis(80)));
+        assert!(result.is_ok());
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn concurrent_deadline_instances_up_to_limit() {
+        let monitor = create_monitor_with_concurrent_deadline();
+        let deadline_tag = DeadlineTag::from("concurrent");
+        assert_eq!(monitor.inner.active_deadlines.len(), 3);
+        assert_eq!(monitor.deadline_ranges().len(), 2);
+
+        let first = monitor.get_deadline(deadline_tag).unwrap();
+        let second = monitor.get_deadline_ffi(deadline_tag).unwrap();
+        assert_ne!(first.state_index, second.state_index);
+        assert_eq!(first.template_index, second.template_index);
+        assert_eq!(
+            monitor.get_deadline(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+
+        // Released instance can be acquired again.
+        drop(first);
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(Some(DeadlineHolder::Ffi)));
+        let first = monitor.get_deadline(deadline_tag).unwrap();
+        drop((first, second));
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(None));
+    }
+
+    #[test]
+    fn concurrent_deadline_instances_run_and_evaluate_separately() {
+        let monitor = create_monitor_with_concurrent_deadline();
+        let deadline_tag = DeadlineTag::from("concurrent");
+        let hmon_starting_point = Instant::now();
+
+        std::thread::scope(|scope| {
+            for sleep_ms in [5, 80] {
+                let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+                scope.spawn(move || {
+                    let handle = deadline.start().unwrap();
+                    std::thread::sleep(Duration::from_millis(sleep_ms));
+                    drop(handle);
+                });
+            }
+        });
+
+        // Only the late instance is reported, durations of both are recorded.
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+        let statistics = monitor.stats(deadline_tag).unwrap();
+        assert_eq!(statistics.count, 2);
+        assert!(statistics.max >= Duration::from_millis(80));
+    }
+
+    #[test]
+    fn deadline_callback_invoked_with_offset() {
+        let calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let calls_clone = calls.clone();
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline_with_callback(
+                DeadlineTag::from("with_callback"),
+                TimeRange::new(Duration::from_millis(50), Duration::from_millis(100)),
+                move |error, offset| calls_clone.lock().unwrap().push((error, offset)),
+            )
+            .add_deadline(
+                DeadlineTag::from("without_callback"),
+                TimeRange::new(Duration::from_millis(50), Duration::from_millis(100)),
+            )
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+
+        for deadline_tag in ["with_callback", "without_callback"] {
+            let mut deadline = monitor.get_deadline(DeadlineTag::from(deadline_tag)).unwrap();
+            deadline.start().unwrap().stop();
+        }
+
+        // Both deadlines are reported, only the one with callback invokes it.
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(Instant::now(), &mut |_, error, _| errors.push(error));
+        assert_eq!(errors.len(), 2);
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, DeadlineEvaluationError::TooEarly);
+        assert!(calls[0].1 > Duration::from_millis(40) && calls[0].1 <= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn deadline_callback_panic_reported() {
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline_with_callback(
+                DeadlineTag::from("deadline"),
+                TimeRange::new(Duration::ZERO, Duration::from_millis(10)),
+                |_, _| panic!("callback failed"),
+            )
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline")).unwrap();
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(Instant::now(), &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+        let mut faults = vec![];
+        monitor
+            .inner
+            .collect_internal_faults(&mut |_, fault| faults.push(fault));
+        assert_eq!(faults, vec![InternalFault::CallbackPanicked]);
+        drop(handle);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn cpu_deadline_measures_thread_cpu_time() {
+        let deadline_tag = DeadlineTag::from("cpu");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline_cpu(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(20)))
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+        let mut errors = vec![];
+
+        // Blocked thread does not consume CPU time, running and stopped deadline is within range.
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(40));
+        monitor
+            .inner
+            .evaluate(Instant::now(), &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+        handle.stop();
+        monitor
+            .inner
+            .evaluate(Instant::now(), &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+        assert!(monitor.stats(deadline_tag).unwrap().max < Duration::from_millis(20));
+
+        // Busy thread exceeds the range while running.
+        let _handle = deadline.start().unwrap();
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_millis(40) {
+            core::hint::spin_loop();
+        }
+        monitor
+            .inner
+            .evaluate(Instant::now(), &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    fn create_monitor_with_custom_deadlines() -> DeadlineMonitor {
+        DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline_long"),
+                TimeRange::new(Duration::from_secs(1), Duration::from_secs(50)),
+            )
+            .with_custom_deadlines(2)
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {})
+    }
+
+    #[test]
+    fn custom_deadlines_up_to_reserved() {
+        let monitor = create_monitor_with_custom_deadlines();
+        let range = TimeRange::new(Duration::from_millis(0), Duration::from_secs(1));
+
+        let first = monitor.create_custom_deadline(DeadlineTag::from("custom1"), range);
+        let second = monitor.create_custom_deadline(DeadlineTag::from("custom2"), range);
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+        assert_eq!(
+            monitor
+                .create_custom_deadline(DeadlineTag::from("custom3"), range)
+                .err(),
+            Some(DeadlineMonitorError::NoCustomDeadlineSlot)
+        );
+
+        // Dropped custom deadline releases its state.
+        drop(first);
+        assert!(monitor
+            .create_custom_deadline(DeadlineTag::from("custom3"), range)
+            .is_ok());
+    }
+
+    #[test]
+    fn custom_deadline_without_reserved_states() {
+        let monitor = create_monitor_with_deadlines();
+        let range = TimeRange::new(Duration::from_millis(0), Duration::from_secs(1));
+        assert_eq!(
+            monitor.create_custom_deadline(DeadlineTag::from("custom"), range).err(),
+            Some(DeadlineMonitorError::NoCustomDeadlineSlot)
+        );
+    }
+
+    #[test]
+    fn custom_deadline_unbounded_range() {
+        let monitor = create_monitor_with_custom_deadlines();
+        assert_eq!(
+            monitor
+                .create_custom_deadline(DeadlineTag::from("custom"), TimeRange::at_least(Duration::ZERO))
+                .err(),
+            Some(DeadlineMonitorError::InvalidRange)
+        );
+    }
+
+    #[test]
+    fn custom_deadline_too_late_reported() {
+        let monitor = create_monitor_with_custom_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor
+            .create_custom_deadline(
+                DeadlineTag::from("custom"),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(10)),
+            )
+            .unwrap();
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+        drop(handle);
+
+        // Failed state is kept for reporting, so only the other reserved state is available.
+        drop(deadline);
+        let range = TimeRange::new(Duration::ZERO, Duration::from_secs(1));
+        let _other = monitor
+            .create_custom_deadline(DeadlineTag::from("other"), range)
+            .unwrap();
+        assert_eq!(
+            monitor.create_custom_deadline(DeadlineTag::from("custom"), range).err(),
+            Some(DeadlineMonitorError::NoCustomDeadlineSlot)
+        );
+        assert_eq!(
+            monitor.stats(DeadlineTag::from("custom")),
+            Err(DeadlineMonitorError::DeadlineNotFound)
+        );
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn deadline_violation_attributed_to_thread() {
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline_long"),
+                TimeRange::new(core::time::Duration::from_secs(1), core::time::Duration::from_secs(50)),
+            )
+            .with_thread_attribution()
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        std::thread::scope(|scope| {
+            std::thread::Builder::new()
+                .name("dl_worker".to_string())
+                .spawn_scoped(scope, || deadline.start().unwrap().stop())
+                .unwrap();
+        });
+
+        let mut reporters = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            assert_eq!(error, DeadlineEvaluationError::TooEarly.into());
+            reporters.push(context.reporter);
+        });
+        assert_eq!(reporters.len(), 1);
+        let reporter = reporters[0].expect("reporter not attributed");
+        assert_eq!(reporter.name(), Some("dl_worker"));
+    }
+
+    #[test]
+    fn deadline_violation_without_thread_attribution() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        deadline.start().unwrap().stop();
+
+        let mut reporters = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, _, context| {
+            reporters.push(context.reporter)
+        });
+        assert_eq!(reporters, vec![None]);
+    }
+
+    #[test]
+    fn deadline_violation_carries_offset_and_deadline_tag() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let deadline_tag = DeadlineTag::from("deadline_long");
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+        deadline.start().unwrap().stop();
+
+        let mut contexts = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            assert_eq!(error, DeadlineEvaluationError::TooEarly.into());
+            contexts.push((context.deadline_tag, context.offset));
+        });
+        assert_eq!(contexts.len(), 1);
+        let (violated_tag, offset) = contexts[0];
+        assert_eq!(violated_tag, Some(deadline_tag));
+        let offset = offset.expect("offset not reported");
+        assert!(offset > Duration::from_millis(900) && offset <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn deadline_violation_carries_correlation_id() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        let mut correlation_ids = vec![];
+        deadline.start_with_id(0xDEAD_BEEF).unwrap().stop();
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            assert_eq!(error, DeadlineEvaluationError::TooEarly.into());
+            correlation_ids.push(context.correlation_id);
+        });
+        assert_eq!(correlation_ids, vec![Some(0xDEAD_BEEF)]);
+    }
+
+    #[test]
+    fn deadline_started_without_id_clears_correlation_id() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        deadline.start_with_id(1).unwrap().stop();
+        let handle = deadline.start().unwrap();
+
+        std::thread::sleep(Duration::from_millis(60));
+        let mut correlation_ids = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            assert_eq!(error, DeadlineEvaluationError::TooLate.into());
+            correlation_ids.push(context.correlation_id);
+        });
+        assert_eq!(correlation_ids, vec![None]);
+        handle.stop();
+    }
+
+    #[test]
+    fn deadline_violation_without_scheduling_statistics() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        let mut schedulings = vec![];
+        deadline.start().unwrap().stop();
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, _, context| {
+            schedulings.push(context.scheduling);
+        });
+        assert_eq!(schedulings, vec![None]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn deadline_violation_carries_scheduling_statistics() {
+        let deadline_tag = DeadlineTag::from("deadline_short");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(10)))
+            .with_scheduling_statistics()
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+
+        // Busy section, the thread accumulates run time.
+        let handle = deadline.start().unwrap();
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_millis(30) {
+            core::hint::spin_loop();
+        }
+        handle.stop();
+
+        let mut schedulings = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            assert_eq!(error, DeadlineEvaluationError::TooLate.into());
+            schedulings.push(context.scheduling);
+        });
+        assert_eq!(schedulings.len(), 1);
+        assert!(schedulings[0].is_some_and(|scheduling| scheduling.run_time > Duration::ZERO));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn late_stop_captures_backtrace() {
+        let deadline_tag = DeadlineTag::from("deadline_short");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                deadline_tag,
+                TimeRange::new(core::time::Duration::ZERO, core::time::Duration::from_millis(10)),
+            )
+            .with_violation_backtrace(crate::deadline::DEFAULT_BACKTRACE_MAX_BYTES)
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+        assert_eq!(monitor.take_violation_backtrace(deadline_tag), Ok(None));
+
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(core::time::Duration::from_millis(30));
+        handle.stop();
+
+        let backtrace = monitor.take_violation_backtrace(deadline_tag).unwrap().unwrap();
+        assert!(backtrace.overrun >= core::time::Duration::from_millis(10));
+        assert!(backtrace.frames.len() <= crate::deadline::DEFAULT_BACKTRACE_MAX_BYTES);
+        assert_eq!(monitor.take_violation_backtrace(deadline_tag), Ok(None));
+        assert_eq!(
+            monitor.take_violation_backtrace(DeadlineTag::from("unknown")),
+            Err(DeadlineMonitorError::DeadlineNotFound)
+        );
+    }
+
+    #[test]
+    fn late_stop_without_backtrace_capture() {
+        let monitor = create_monitor_with_deadlines();
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(core::time::Duration::from_millis(60));
+        handle.stop();
+
+        assert_eq!(monitor.take_violation_backtrace(deadline_tag), Ok(None));
+    }
+
+    #[test]
+    fn calibration_suppresses_violations_and_suggests_range() {
+        let deadline_tag = DeadlineTag::from("deadline_short");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                deadline_tag,
+                TimeRange::new(core::time::Duration::ZERO, core::time::Duration::from_millis(5)),
+            )
+            .with_calibration(2, 50, CalibrationPolicy::Suggest)
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+        let hmon_starting_point = Instant::now();
+
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+        for _ in 0..2 {
+            assert_eq!(monitor.calibration_suggestion(deadline_tag), Ok(None));
+            let handle = deadline.start().unwrap();
+            std::thread::sleep(core::time::Duration::from_millis(20));
+            monitor.inner.evaluate(hmon_starting_point, &mut |_, error, _| {
+                panic!("Unexpected error: {error:?}")
+            });
+            handle.stop();
+        }
+
+        let suggestion = monitor.calibration_suggestion(deadline_tag).unwrap().unwrap();
+        assert!(suggestion.max >= core::time::Duration::from_millis(30));
+
+        // Configured range stays in use, late stop is a violation again.
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(core::time::Duration::from_millis(20));
+        handle.stop();
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+        assert_eq!(
+            monitor.calibration_suggestion(DeadlineTag::from("unknown")),
+            Err(DeadlineMonitorError::DeadlineNotFound)
+        );
+    }
+
+    #[test]
+    fn calibration_auto_applies_suggested_range() {
+        let deadline_tag = DeadlineTag::from("deadline_short");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                deadline_tag,
+                TimeRange::new(core::time::Duration::ZERO, core::time::Duration::from_millis(5)),
+            )
+            .with_calibration(1, 100, CalibrationPolicy::AutoApply)
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {});
+
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(core::time::Duration::from_millis(20));
+        handle.stop();
+
+        let suggestion = monitor.calibration_suggestion(deadline_tag).unwrap().unwrap();
+        assert_eq!(deadline.range, suggestion);
+
+        // Newly acquired deadline uses the suggested range as well.
+        drop(deadline);
+        let deadline = monitor.get_deadline(deadline_tag).unwrap();
+        assert_eq!(deadline.range, suggestion);
+    }
+
+    #[test]
+    fn start_stop_deadline_within_range_works() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let handle = deadline.start().unwrap();
+
+        std::thread::sleep(core::time::Duration::from_millis(1001)); // Sleep to simulate work within the deadline range
+
+        drop(handle); // stop the deadline
+
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
+                panic!(
+                    "Deadline {:?} should not have failed or underrun({:?})",
+                    monitor_tag, deadline_failure
+                );
+            });
+    }
+
+    #[test]
+    fn stats_record_measured_durations() {
+        let monitor = create_monitor_with_deadlines();
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        assert_eq!(monitor.stats(deadline_tag).unwrap(), DeadlineStatistics::default());
+
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+        for sleep_ms in [5, 10, 80] {
+            let handle = deadline.start().unwrap();
+            std::thread::sleep(core::time::Duration::from_millis(sleep_ms));
+            drop(handle);
+            // Stopped too late, state is reset as if reported.
+            let _ = monitor.inner.active_deadlines[0]
+                .1
+                .update(|_| Some(DeadlineStateSnapshot::default()));
+            let _ = monitor.inner.active_deadlines[1]
+                .1
+                .update(|_| Some(DeadlineStateSnapshot::default()));
+        }
+
+        let stats = monitor.stats(deadline_tag).unwrap();
+        assert_eq!(stats.count, 3);
+        assert!(stats.min >= core::time::Duration::from_millis(5));
+        assert!(stats.max >= core::time::Duration::from_millis(80));
+        assert!(stats.mean >= core::time::Duration::from_millis(31));
+        assert_eq!(stats.p99, stats.max);
+        assert_eq!(
+            monitor.stats(DeadlineTag::from("unknown")),
+            Err(DeadlineMonitorError::DeadlineNotFound)
+        );
+    }
+
+    #[test]
+    fn disabled_monitor_counts_activations_and_violations() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        monitor.disable();
+        // Stopped too early - counted, not reported.
+        deadline.start().unwrap().stop();
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Disabled monitor {:?} reported {:?}", monitor_tag, error);
+            });
+
+        // Deadline is reset and can be started again.
+        deadline.start().unwrap().stop();
+
+        let summary = monitor.enable();
+        assert_eq!(
+            summary,
+            Some(DisabledIntervalSummary {
+                activations: 2,
+                violations: 2
+            })
+        );
+        assert_eq!(monitor.enable(), None);
+
+        // Counters are reset after enable.
+        monitor.disable();
+        assert_eq!(monitor.enable(), Some(DisabledIntervalSummary::default()));
+    }
+
+    #[test]
+    fn enabled_monitor_reports_after_disabled_interval() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        monitor.disable();
+        let _ = monitor.enable();
+        deadline.start().unwrap().stop();
+
+        let mut reported = false;
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, _| {
+            assert_eq!(error, DeadlineEvaluationError::TooEarly.into());
+            reported = true;
+        });
+        assert!(reported);
+    }
+
+    #[test]
+    fn corrupted_state_is_reported_as_internal_fault() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+
+        // Running without stopped bit never happens in a valid state.
+        let _ = monitor.inner.active_deadlines[0].1.update(|_| {
+            let mut corrupted = DeadlineStateSnapshot::new(0);
+            corrupted.set_running();
+            Some(corrupted)
+        });
+
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Corrupted state of {:?} reported as violation {:?}", monitor_tag, error);
+            });
+
+        let mut faults = vec![];
+        monitor
+            .inner
+            .collect_internal_faults(&mut |_, fault| faults.push(fault));
+        assert_eq!(faults, vec![InternalFault::StateCorruption]);
+
+        // Fault is reported only once.
+        monitor.inner.collect_internal_faults(&mut |_, fault| {
+            panic!("Unexpected internal fault {:?}", fault);
+        });
+    }
+
+    #[test]
+    fn start_stop_deadline_outside_ranges_is_error_when_dropped_before_evaluate() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let handle = deadline.start().unwrap();
+
+        drop(handle); // stop the deadline
+
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
+                assert_eq!(
+                    deadline_failure,
+                    DeadlineEvaluationError::TooEarly.into(),
+                    "Deadline {:?} should not have failed({:?})",
+                    monitor_tag,
+                    deadline_failure
+                );
+            });
+    }
+    #[test]
+    fn deadline_outside_time_range_is_error_when_dropped_after_evaluate() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let handle = deadline.start().unwrap();
+
+        // So deadline stop happens after evaluate, still it should be reported as failed
+
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
+                assert_eq!(
+                    deadline_failure,
+                    DeadlineEvaluationError::TooEarly.into(),
+                    "Deadline {:?} should not have failed({:?})",
+                    monitor_tag,
+                    deadline_failure
+                );
+            });
+
+        drop(handle); // stop the deadline
+    }
+
+    #[test]
+    fn deadline_failed_on_first_run_and_then_restarted_is_evaluated_as_error() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let handle = deadline.start().unwrap();
+
+        // So deadline failed, then we start it again so it shall be already expired and also evaluation shall work
+        drop(handle); // stop the deadline
+        drop(deadline); // drop the deadline to release it
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let handle = deadline.start();
+        assert_eq!(handle.err(), Some(DeadlineError::DeadlineAlreadyFailed));
+
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
+                assert_eq!(
+                    deadline_failure,
+                    DeadlineEvaluationError::TooEarly.into(),
+                    "Deadline {:?} should not have failed ({:?})",
+                    monitor_tag,
+                    deadline_failure
+                );
+            });
+    }
+
+    #[test]
+    fn start_stop_deadline_outside_ranges_is_evaluated_as_error() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let handle = deadline.start().unwrap();
+
+        drop(handle); // stop the deadline
+
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
+                assert_eq!(
+                    deadline_failure,
+                    DeadlineEvaluationError::TooLate.into(),
+                    "Deadline {:?} should not have failed({:?})",
+                    monitor_tag,
+                    deadline_failure
+                );
+            });
+    }
+
+    #[test]
+    fn rebaseline_extends_running_deadlines() {
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let handle = deadline.start().unwrap();
+
+        // Time spent in the gap is not accounted.
+        std::thread::sleep(Duration::from_millis(80));
+        monitor
+            .inner
+            .rebaseline(hmon_starting_point, Duration::from_millis(100));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} after rebaseline");
+            });
+
+        drop(handle);
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} after rebaseline");
+            });
+    }
+
+    #[test]
+    fn deadline_extend_pushes_out_range() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline_fast"),
+                TimeRange::new(Duration::ZERO, Duration::from_millis(50)),
+            )
+            .with_max_extensions(2)
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+
+        let mut handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(handle.extend(Duration::from_millis(100)), Ok(()));
+        std::thread::sleep(Duration::from_millis(40));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for extended deadline");
+            });
+
+        assert_eq!(handle.extend(Duration::from_millis(100)), Ok(()));
+        assert_eq!(
+            handle.extend(Duration::from_millis(100)),
+            Err(DeadlineError::ExtensionLimitReached)
+        );
+        drop(handle);
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for extended deadline");
+            });
+
+        // Extensions are counted per activation.
+        let mut handle = deadline.start().unwrap();
+        assert_eq!(handle.extend(Duration::from_millis(100)), Ok(()));
+    }
+
+    #[test]
+    fn deadline_pause_excludes_wait_section() {
+        let allocator = ProtectedMemoryAllocator {};
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(50)))
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+
+        let mut handle = deadline.start().unwrap();
+        assert_eq!(handle.pause(), Ok(()));
+        assert_eq!(handle.pause(), Ok(()));
+        std::thread::sleep(Duration::from_millis(80));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for paused deadline");
+            });
+
+        handle.resume();
+        handle.resume();
+        let measurement = handle.stop().unwrap();
+        assert!(measurement.in_range);
+        assert!(measurement.elapsed < Duration::from_millis(50));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for resumed deadline");
+            });
+
+        // Stopping a paused deadline resumes it first.
+        let mut handle = deadline.start().unwrap();
+        assert_eq!(handle.pause(), Ok(()));
+        std::thread::sleep(Duration::from_millis(80));
+        assert!(handle.stop().unwrap().in_range);
+    }
+
+    #[test]
+    fn deadline_pause_rejected_after_miss() {
+        let allocator = ProtectedMemoryAllocator {};
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(20)))
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+
+        let mut handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(handle.pause(), Err(DeadlineError::DeadlineAlreadyFailed));
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn deadline_timestamps_beyond_u32_range() {
+        // Monitor running for longer than ~49.7 days.
+        const HUNDRED_DAYS_AS_MS: u64 = 100 * 24 * 60 * 60 * 1000;
+        let allocator = ProtectedMemoryAllocator {};
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(50)))
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+
+        deadline.start_at_ms(None, HUNDRED_DAYS_AS_MS).unwrap();
+        let snapshot = monitor.inner.active_deadlines[*deadline.state_index].1.snapshot();
+        assert_eq!(snapshot.timestamp_ms(), HUNDRED_DAYS_AS_MS + 50);
+        deadline.stop_at_ms(HUNDRED_DAYS_AS_MS + 30);
+        assert_eq!(
+            deadline.last_measurement,
+            Some(DeadlineMeasurement {
+                elapsed: Duration::from_millis(30),
+                in_range: true,
+            })
+        );
+
+        deadline.start_at_ms(None, HUNDRED_DAYS_AS_MS).unwrap();
+        deadline.stop_at_ms(HUNDRED_DAYS_AS_MS + 80);
+        assert_eq!(
+            deadline.last_measurement,
+            Some(DeadlineMeasurement {
+                elapsed: Duration::from_millis(80),
+                in_range: false,
+            })
+        );
+    }
+
+    #[test]
+    fn deadline_measured_with_external_clock_source() {
+        struct ManualTime(AtomicU64);
+
+        impl crate::deadline::TimeProvider for ManualTime {
+            fn now_ns(&self) -> u64 {
+                self.0.load(Ordering::Relaxed)
+            }
+        }
+
+        let allocator = ProtectedMemoryAllocator {};
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+        let time = Arc::new(ManualTime(AtomicU64::new(1_000_000_000)));
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(50)))
+            .with_clock_source(ClockSource::External(time.clone()))
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+
+        let handle = deadline.start().unwrap();
+        time.0.fetch_add(30_000_000, Ordering::Relaxed);
+        let measurement = handle.stop().unwrap();
+        assert_eq!(measurement.elapsed, Duration::from_millis(30));
+        assert!(measurement.in_range);
+
+        let _handle = deadline.start().unwrap();
+        time.0.fetch_add(80_000_000, Ordering::Relaxed);
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn deadline_group_started_and_stopped_together() {
+        let monitor = create_monitor_with_multiple_running_deadlines();
+        let hmon_starting_point = Instant::now();
+        let deadline_tags = [DeadlineTag::from("deadline_fast1"), DeadlineTag::from("deadline_fast2")];
 
-        let deadlines = deadlines
-            .into_iter()
-            .enumerate()
-            .map(|(index, (deadline_tag, range))| {
-                active_deadlines.push((deadline_tag, DeadlineState::new()));
-                (deadline_tag, DeadlineTemplate::new(range, StateIndex::new(index)))
-            })
-            .collect();
+        let group = monitor.start_group(&deadline_tags).unwrap();
+        assert_eq!(group.len(), 2);
+        // Deadlines of a running group are held by it.
+        assert_eq!(
+            monitor.get_deadline(deadline_tags[0]).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+        let measurements = group.stop();
+        assert_eq!(measurements.len(), 2);
+        assert!(measurements
+            .iter()
+            .all(|measurement| measurement.is_some_and(|m| m.in_range)));
+        assert_eq!(measurements[0], measurements[1]);
 
-        Self {
-            monitor_tag,
-            deadlines,
-            active_deadlines: active_deadlines.into(),
-            monitor_starting_point: Instant::now(),
-        }
-    }
+        // Failing deadline fails the whole group, deadlines started before it are discarded.
+        let mut failed = monitor.get_deadline(deadline_tags[1]).unwrap();
+        let handle = failed.start().unwrap();
+        std::thread::sleep(Duration::from_millis(60));
+        drop(handle);
+        drop(failed);
+        assert_eq!(
+            monitor.start_group(&deadline_tags).err(),
+            Some(DeadlineMonitorError::DeadlineAlreadyFailed)
+        );
+        let state_index = monitor
+            .inner
+            .deadlines
+            .get(&deadline_tags[0])
+            .unwrap()
+            .assigned_state_index;
+        assert!(!monitor.inner.active_deadlines[*state_index].1.snapshot().is_running());
 
-    fn release_deadline(&self, deadline_tag: DeadlineTag) {
-        if let Some(template) = self.deadlines.get(&deadline_tag) {
-            template.release_deadline();
-        } else {
-            unreachable!("Releasing unknown deadline tag: {:?}", deadline_tag);
-        }
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
     }
 
-    pub(crate) fn get_deadline(self: &Arc<Self>, deadline_tag: DeadlineTag) -> Result<Deadline, DeadlineMonitorError> {
-        if let Some(template) = self.deadlines.get(&deadline_tag) {
-            match template.acquire_deadline() {
-                Some(range) => Ok(Deadline {
-                    range,
-                    deadline_tag,
-                    monitor: self.clone(),
-                    state_index: template.assigned_state_index,
-                }),
-                None => Err(DeadlineMonitorError::DeadlineInUse),
-            }
-        } else {
+    #[test]
+    #[cfg(feature = "fault_injection")]
+    fn injected_violation_reported_once() {
+        use crate::deadline::InjectedViolation;
+
+        let monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let deadline_tag = DeadlineTag::from("deadline_fast");
+
+        assert_eq!(
+            monitor.inject_violation(DeadlineTag::from("unknown"), InjectedViolation::TooLate),
             Err(DeadlineMonitorError::DeadlineNotFound)
-        }
+        );
+        assert_eq!(
+            monitor.inject_violation(deadline_tag, InjectedViolation::TooEarly),
+            Ok(())
+        );
+
+        let mut errors = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            errors.push((error, context.deadline_tag));
+        });
+        assert_eq!(
+            errors,
+            vec![(DeadlineEvaluationError::TooEarly.into(), Some(deadline_tag))]
+        );
+
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} after injected violation");
+            });
     }
-}
 
-#[score_testing_macros::test_mod_with_log]
-#[cfg(all(test, not(loom)))]
-mod tests {
-    use super::*;
+    #[test]
+    fn chained_deadline_started_by_upstream_stop() {
+        let allocator = ProtectedMemoryAllocator {};
+        let upstream_tag = DeadlineTag::from("upstream");
+        let downstream_tag = DeadlineTag::from("downstream");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(upstream_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(50)))
+            .add_deadline(
+                downstream_tag,
+                TimeRange::new(Duration::ZERO, Duration::from_millis(50)),
+            )
+            .chain(upstream_tag, downstream_tag)
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        let mut upstream = monitor.get_deadline(upstream_tag).unwrap();
+        let mut downstream = monitor.get_deadline(downstream_tag).unwrap();
 
-    fn create_monitor_with_deadlines() -> DeadlineMonitor {
+        upstream.start().unwrap().stop();
+        std::thread::sleep(Duration::from_millis(30));
+        // Holder of the downstream deadline joins the activation started by the upstream stop.
+        let handle = downstream.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+        let measurement = handle.stop().unwrap();
+        assert!(!measurement.in_range);
+
+        let mut errors = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            errors.push((error, context.deadline_tag));
+        });
+        assert_eq!(
+            errors,
+            vec![(DeadlineEvaluationError::TooLate.into(), Some(downstream_tag))]
+        );
+    }
+
+    #[test]
+    fn chained_deadline_missed_before_joined() {
         let allocator = ProtectedMemoryAllocator {};
-        let monitor_tag = MonitorTag::from("deadline_monitor");
-        DeadlineMonitorBuilder::new()
+        let upstream_tag = DeadlineTag::from("upstream");
+        let downstream_tag = DeadlineTag::from("downstream");
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(upstream_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(50)))
             .add_deadline(
-                DeadlineTag::from("deadline_long"),
-                TimeRange::new(core::time::Duration::from_secs(1), core::time::Duration::from_secs(50)),
+                downstream_tag,
+                TimeRange::new(Duration::ZERO, Duration::from_millis(20)),
             )
+            .chain(upstream_tag, downstream_tag)
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        let mut upstream = monitor.get_deadline(upstream_tag).unwrap();
+
+        upstream.start_with_id(0xDEAD_BEEF).unwrap().stop();
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for running chained deadline");
+            });
+
+        std::thread::sleep(Duration::from_millis(30));
+        let mut errors = vec![];
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, context| {
+            errors.push((error, context.deadline_tag, context.correlation_id));
+        });
+        assert_eq!(
+            errors,
+            vec![(
+                DeadlineEvaluationError::TooLate.into(),
+                Some(downstream_tag),
+                Some(0xDEAD_BEEF)
+            )]
+        );
+    }
+
+    #[test]
+    fn deadline_extend_rejected() {
+        let monitor = create_monitor_with_deadlines();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let mut handle = deadline.start().unwrap();
+        // Deadlines cannot be extended by default.
+        assert_eq!(
+            handle.extend(Duration::from_millis(100)),
+            Err(DeadlineError::ExtensionLimitReached)
+        );
+        drop(handle);
+
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = DeadlineMonitorBuilder::new()
             .add_deadline(
                 DeadlineTag::from("deadline_fast"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(50),
-                ),
+                TimeRange::new(Duration::ZERO, Duration::from_millis(20)),
             )
-            .build(monitor_tag, &allocator)
+            .with_max_extensions(1)
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let mut handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+        // Deadline past its range cannot be extended.
+        assert_eq!(
+            handle.extend(Duration::from_millis(100)),
+            Err(DeadlineError::DeadlineAlreadyFailed)
+        );
     }
 
-    fn create_monitor_with_multiple_running_deadlines() -> DeadlineMonitor {
+    #[test]
+    fn budget_exhausted_by_sections_is_evaluated_as_error() {
         let allocator = ProtectedMemoryAllocator {};
-        let monitor_tag = MonitorTag::from("deadline_monitor");
-        DeadlineMonitorBuilder::new()
-            .add_deadline(
-                DeadlineTag::from("slow"),
-                TimeRange::new(core::time::Duration::from_secs(0), core::time::Duration::from_secs(50)),
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_budget(
+                DeadlineTag::from("cycle"),
+                Duration::from_millis(8),
+                Duration::from_secs(10),
             )
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        assert_eq!(
+            monitor.get_budget(DeadlineTag::from("unknown")).err(),
+            Some(DeadlineMonitorError::DeadlineNotFound)
+        );
+        let budget = monitor.get_budget(DeadlineTag::from("cycle")).unwrap();
+        let budget_clone = budget.clone();
+
+        budget.measure(|| std::thread::sleep(Duration::from_millis(5)));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} within budget");
+            });
+
+        // Sections of all instances are accumulated.
+        budget_clone.start().stop();
+        budget_clone.measure(|| std::thread::sleep(Duration::from_millis(5)));
+        budget.measure(|| std::thread::sleep(Duration::from_millis(5)));
+        assert!(budget.used() >= Duration::from_millis(15));
+
+        let mut errors = Vec::new();
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::BudgetExhausted.into()]);
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} twice");
+            });
+    }
+
+    #[test]
+    fn deadline_stop_reports_measurement() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = DeadlineMonitorBuilder::new()
             .add_deadline(
-                DeadlineTag::from("deadline_fast1"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(50),
-                ),
+                DeadlineTag::from("deadline"),
+                TimeRange::new(Duration::from_millis(10), Duration::from_millis(100)),
             )
-            .add_deadline(
-                DeadlineTag::from("deadline_fast2"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(34),
-                ),
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline")).unwrap();
+        assert_eq!(deadline.last_measurement(), None);
+
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        let measurement = handle.stop().unwrap();
+        assert!(measurement.in_range);
+        assert!(measurement.elapsed >= Duration::from_millis(20));
+        assert_eq!(deadline.last_measurement(), Some(measurement));
+
+        // Measurement of an activation stopped by dropping the handle.
+        drop(deadline.start().unwrap());
+        let measurement = deadline.last_measurement().unwrap();
+        assert!(!measurement.in_range);
+        assert!(measurement.elapsed < Duration::from_millis(10));
+    }
+
+    fn create_monitor_with_cyclic_deadline() -> DeadlineMonitor {
+        DeadlineMonitorBuilder::new()
+            .add_cyclic_deadline(
+                DeadlineTag::from("cycle"),
+                TimeRange::new(Duration::from_millis(10), Duration::from_millis(50)),
             )
             .add_deadline(
-                DeadlineTag::from("deadline_fast3"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(10),
-                ),
+                DeadlineTag::from("deadline"),
+                TimeRange::new(Duration::ZERO, Duration::from_millis(50)),
             )
-            .build(monitor_tag, &allocator)
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {})
     }
 
     #[test]
-    fn get_deadline_unknown_tag() {
-        let monitor = create_monitor_with_deadlines();
-        let result = monitor.get_deadline(DeadlineTag::from("unknown"));
-        assert_eq!(result.err(), Some(DeadlineMonitorError::DeadlineNotFound));
+    fn cyclic_deadline_checkpoints_rearm() {
+        let monitor = create_monitor_with_cyclic_deadline();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("cycle")).unwrap();
+        assert_eq!(deadline.checkpoint(), Ok(None));
+        for _ in 0..3 {
+            std::thread::sleep(Duration::from_millis(20));
+            let measurement = deadline.checkpoint().unwrap().unwrap();
+            assert!(measurement.in_range);
+            assert!(measurement.elapsed >= Duration::from_millis(20));
+        }
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for periodic checkpoints");
+            });
+
+        // Dropped deadline ends supervision of the running cycle.
+        drop(deadline);
+        std::thread::sleep(Duration::from_millis(60));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for released deadline");
+            });
     }
 
     #[test]
-    fn start_stop_deadline_within_range_works() {
-        let monitor = create_monitor_with_deadlines();
+    fn cyclic_deadline_missed_checkpoint_and_jitter() {
+        let monitor = create_monitor_with_cyclic_deadline();
         let hmon_starting_point = Instant::now();
-        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
-        let handle = deadline.start().unwrap();
-
-        std::thread::sleep(core::time::Duration::from_millis(1001)); // Sleep to simulate work within the deadline range
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("cycle")).unwrap();
 
-        drop(handle); // stop the deadline
+        // Missing checkpoint is reported while the cycle is running.
+        deadline.checkpoint().unwrap();
+        std::thread::sleep(Duration::from_millis(60));
+        let mut errors = Vec::new();
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+        assert_eq!(deadline.checkpoint(), Err(DeadlineError::DeadlineAlreadyFailed));
+        drop(deadline);
 
+        // Cycle shorter than the lower bound of the range.
+        let monitor = create_monitor_with_cyclic_deadline();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("cycle")).unwrap();
+        deadline.checkpoint().unwrap();
+        assert_eq!(deadline.checkpoint(), Err(DeadlineError::DeadlineAlreadyFailed));
+        assert!(deadline
+            .last_measurement()
+            .is_some_and(|measurement| !measurement.in_range));
+        let mut errors = Vec::new();
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
-                panic!(
-                    "Deadline {:?} should not have failed or underrun({:?})",
-                    monitor_tag, deadline_failure
-                );
-            });
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooEarly.into()]);
     }
 
     #[test]
-    fn start_stop_deadline_outside_ranges_is_error_when_dropped_before_evaluate() {
-        let monitor = create_monitor_with_deadlines();
+    fn cyclic_deadline_disarm_and_not_cyclic() {
+        let monitor = create_monitor_with_cyclic_deadline();
         let hmon_starting_point = Instant::now();
-        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
-        let handle = deadline.start().unwrap();
-
-        drop(handle); // stop the deadline
-
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("cycle")).unwrap();
+        deadline.checkpoint().unwrap();
+        deadline.disarm();
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(deadline.checkpoint(), Ok(None));
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
-                assert_eq!(
-                    deadline_failure,
-                    DeadlineEvaluationError::TooEarly.into(),
-                    "Deadline {:?} should not have failed({:?})",
-                    monitor_tag,
-                    deadline_failure
-                );
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for disarmed deadline");
             });
+
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline")).unwrap();
+        assert_eq!(deadline.checkpoint(), Err(DeadlineError::NotCyclic));
     }
+
     #[test]
-    fn deadline_outside_time_range_is_error_when_dropped_after_evaluate() {
-        let monitor = create_monitor_with_deadlines();
+    fn deadline_with_open_bounds() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("at_least"),
+                TimeRange::at_least(Duration::from_millis(20)),
+            )
+            .add_deadline(DeadlineTag::from("up_to"), TimeRange::up_to(Duration::from_millis(50)))
+            .with_max_extensions(1)
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
         let hmon_starting_point = Instant::now();
-        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
-        let handle = deadline.start().unwrap();
-
-        // So deadline stop happens after evaluate, still it should be reported as failed
+        let mut at_least = monitor.get_deadline(DeadlineTag::from("at_least")).unwrap();
+        let mut up_to = monitor.get_deadline(DeadlineTag::from("up_to")).unwrap();
 
+        // Deadline without upper bound is never late, one without lower bound never early.
+        let mut handle = at_least.start().unwrap();
+        assert_eq!(handle.extend(Duration::from_millis(10)), Ok(()));
+        up_to.start().unwrap().stop();
+        std::thread::sleep(Duration::from_millis(30));
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
-                assert_eq!(
-                    deadline_failure,
-                    DeadlineEvaluationError::TooEarly.into(),
-                    "Deadline {:?} should not have failed({:?})",
-                    monitor_tag,
-                    deadline_failure
-                );
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for open bound");
             });
+        let measurement = handle.stop().unwrap();
+        assert!(measurement.in_range);
+        assert!(measurement.elapsed >= Duration::from_millis(30));
 
-        drop(handle); // stop the deadline
+        // Lower bound is still supervised.
+        at_least.start().unwrap().stop();
+        let mut errors = Vec::new();
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooEarly.into()]);
     }
 
     #[test]
-    fn deadline_failed_on_first_run_and_then_restarted_is_evaluated_as_error() {
-        let monitor = create_monitor_with_deadlines();
+    fn deadline_near_miss_is_counted_and_reported() {
+        let allocator = ProtectedMemoryAllocator {};
+        let deadline_tag = DeadlineTag::from("deadline");
+        let near_misses = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let near_misses_clone = Arc::clone(&near_misses);
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(100)))
+            .with_warning_at(deadline_tag, Duration::from_millis(20))
+            .with_warning_callback(move |deadline_tag, longest| {
+                near_misses_clone.lock().unwrap().push((deadline_tag, longest))
+            })
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
         let hmon_starting_point = Instant::now();
-        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
-        let handle = deadline.start().unwrap();
+        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
 
-        // So deadline failed, then we start it again so it shall be already expired and also evaluation shall work
-        drop(handle); // stop the deadline
-        drop(deadline); // drop the deadline to release it
-        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
-        let handle = deadline.start();
-        assert_eq!(handle.err(), Some(DeadlineError::DeadlineAlreadyFailed));
+        deadline.start().unwrap().stop();
+        let handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(handle.stop().unwrap().in_range);
 
+        // Near misses are not violations.
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
-                assert_eq!(
-                    deadline_failure,
-                    DeadlineEvaluationError::TooEarly.into(),
-                    "Deadline {:?} should not have failed ({:?})",
-                    monitor_tag,
-                    deadline_failure
-                );
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} for a near miss");
             });
+        let reported = near_misses.lock().unwrap().clone();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].0, deadline_tag);
+        assert!(reported[0].1 >= Duration::from_millis(30));
+        assert_eq!(monitor.stats(deadline_tag).unwrap().near_misses, 1);
+
+        // Each near miss is reported once.
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, _, _| {});
+        assert_eq!(near_misses.lock().unwrap().len(), 1);
     }
 
     #[test]
-    fn start_stop_deadline_outside_ranges_is_evaluated_as_error() {
-        let monitor = create_monitor_with_deadlines();
+    fn idle_monitor_is_evaluated_as_error_once() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline_fast"),
+                TimeRange::new(Duration::ZERO, Duration::from_millis(50)),
+            )
+            .with_max_idle(Duration::from_millis(20), IdleSeverity::Error)
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
         let hmon_starting_point = Instant::now();
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
-        let handle = deadline.start().unwrap();
 
-        drop(handle); // stop the deadline
+        let mut errors = Vec::new();
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        std::thread::sleep(Duration::from_millis(30));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![DeadlineEvaluationError::Idle.into()]);
 
+        // Started deadline ends the idle period.
+        deadline.start().unwrap().stop();
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
-                assert_eq!(
-                    deadline_failure,
-                    DeadlineEvaluationError::TooLate.into(),
-                    "Deadline {:?} should not have failed({:?})",
-                    monitor_tag,
-                    deadline_failure
-                );
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("Monitor {monitor_tag:?} reported error {error:?} after activity");
             });
     }
 
+    #[test]
+    fn idle_monitor_with_info_severity_is_not_evaluated_as_error() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline_fast"),
+                TimeRange::new(Duration::ZERO, Duration::from_millis(50)),
+            )
+            .with_max_idle(Duration::from_millis(10), IdleSeverity::Info)
+            .build(MonitorTag::from("deadline_monitor"), &allocator);
+        let hmon_starting_point = Instant::now();
+        for _ in 0..3 {
+            monitor
+                .inner
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                    panic!("Monitor {monitor_tag:?} reported error {error:?} for informational idleness");
+                });
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
     #[test]
     fn monitor_with_multiple_running_deadlines() {
         let monitor = create_monitor_with_multiple_running_deadlines();
@@ -534,7 +4065,7 @@ mod tests {
 
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
                 cnt += 1;
                 assert_eq!(
                     deadline_failure,
@@ -547,4 +4078,49 @@ mod tests {
 
         assert_eq!(cnt, 3, "All three deadlines should have been evaluated");
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn evaluation_latency_is_bounded_under_reporter_contention() {
+        // Reporting and evaluation share only atomics - no lock a preempted reporter could hold against the evaluator.
+        let monitor = Arc::new(create_monitor_with_multiple_running_deadlines());
+        let hmon_starting_point = Instant::now();
+        let stop = Arc::new(core::sync::atomic::AtomicBool::new(false));
+
+        let reporters: Vec<_> = ["slow", "deadline_fast1", "deadline_fast2"]
+            .into_iter()
+            .map(|tag| {
+                let monitor = monitor.clone();
+                let stop = stop.clone();
+                std::thread::spawn(move || {
+                    let mut deadline = monitor.get_deadline(DeadlineTag::from(tag)).unwrap();
+                    while !stop.load(core::sync::atomic::Ordering::Relaxed) {
+                        if let Ok(handle) = deadline.start() {
+                            handle.stop();
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        let mut max_latency = core::time::Duration::ZERO;
+        for _ in 0..200 {
+            let start = Instant::now();
+            monitor.inner.evaluate(hmon_starting_point, &mut |_, _, _| {});
+            max_latency = max_latency.max(start.elapsed());
+            std::thread::sleep(core::time::Duration::from_micros(100));
+        }
+
+        stop.store(true, core::sync::atomic::Ordering::Relaxed);
+        for reporter in reporters {
+            reporter.join().unwrap();
+        }
+
+        // Generous bound - covers scheduler preemption of the evaluating thread on loaded CI machines.
+        assert!(
+            max_latency < core::time::Duration::from_millis(100),
+            "Evaluation took {:?}",
+            max_latency
+        );
+    }
 }
@@ -18,49 +18,109 @@ use core::{
 #[derive(Clone, Copy)]
 pub(super) struct DeadlineStateSnapshot(u64);
 
-// Deadline State layout (u64) = | timestamp: u32 | reserved: 28 bits | finished_too_early: 1 bit | reserved: 1 bit | stopped: 1 bit | running: 1 bit |
-const DEADLINE_STATE_MASK: u64 = 0b0000_1111;
+// Deadline State layout (u64) = | timestamp: 48 bits | reserved: 10 bits | chained: 1 bit | paused: 1 bit | finished_too_early: 1 bit | unbounded: 1 bit | stopped: 1 bit | running: 1 bit |
+const DEADLINE_STATE_MASK: u64 = 0b0011_1111;
 const DEADLINE_STATE_RUNNING: u64 = 0b0000_0010;
 const DEADLINE_STATE_STOPPED: u64 = 0b0000_0001;
+const DEADLINE_STATE_UNBOUNDED: u64 = 0b0000_0100;
 const DEADLINE_STATE_FINISHED_TOO_EARLY: u64 = 0b0000_1000;
+const DEADLINE_STATE_PAUSED: u64 = 0b0001_0000;
+const DEADLINE_STATE_CHAINED: u64 = 0b0010_0000;
+const DEADLINE_STATE_TIMESTAMP_OFFSET: u32 = 16;
 
 impl DeadlineStateSnapshot {
+    #[inline]
     pub(super) fn new(val: u64) -> Self {
         Self(val)
     }
 
+    #[inline]
     pub(super) fn as_u64(&self) -> u64 {
         self.0
     }
 
+    #[inline]
     pub(super) fn is_running(&self) -> bool {
         (self.0 & DEADLINE_STATE_RUNNING) != 0
     }
 
+    #[inline]
     pub(super) fn is_stopped(&self) -> bool {
         (self.0 & DEADLINE_STATE_STOPPED) != 0
     }
 
+    /// Running deadline has no upper bound, its timestamp is the start time instead of the expected end.
+    #[inline]
+    pub(super) fn is_unbounded(&self) -> bool {
+        (self.0 & DEADLINE_STATE_UNBOUNDED) != 0
+    }
+
+    #[inline]
     pub(super) fn is_underrun(&self) -> bool {
         (self.0 & DEADLINE_STATE_FINISHED_TOO_EARLY) != 0
     }
 
+    /// Running deadline is paused, its timestamp is relative to the time it was paused.
+    #[inline]
+    pub(super) fn is_paused(&self) -> bool {
+        (self.0 & DEADLINE_STATE_PAUSED) != 0
+    }
+
+    /// Running deadline was started by its upstream deadline and is not joined by its own holder yet.
+    #[inline]
+    pub(super) fn is_chained(&self) -> bool {
+        (self.0 & DEADLINE_STATE_CHAINED) != 0
+    }
+
     /// Get timestamp in milliseconds. This is a offset from an start timer that is stored in DeadlineMonitor
-    pub(super) fn timestamp_ms(&self) -> u32 {
-        ((self.0 & !DEADLINE_STATE_MASK) >> u32::BITS) as u32
+    #[inline]
+    pub(super) fn timestamp_ms(&self) -> u64 {
+        self.0 >> DEADLINE_STATE_TIMESTAMP_OFFSET
     }
 
-    pub(super) fn set_timestamp_ms(&mut self, timestamp: u32) {
-        self.0 = ((timestamp as u64) << u32::BITS) | (self.0 & DEADLINE_STATE_MASK);
+    /// Set timestamp in milliseconds.
+    /// Value is 48-bit, covering thousands of years of monitor uptime.
+    #[inline]
+    pub(super) fn set_timestamp_ms(&mut self, timestamp: u64) {
+        debug_assert!(
+            timestamp < 1 << (u64::BITS - DEADLINE_STATE_TIMESTAMP_OFFSET),
+            "provided deadline timestamp is out of range"
+        );
+        self.0 = (timestamp << DEADLINE_STATE_TIMESTAMP_OFFSET) | (self.0 & DEADLINE_STATE_MASK);
     }
 
+    #[inline]
     pub(super) fn set_running(&mut self) {
         self.0 |= DEADLINE_STATE_RUNNING;
     }
 
+    #[inline]
+    pub(super) fn set_unbounded(&mut self) {
+        self.0 |= DEADLINE_STATE_UNBOUNDED;
+    }
+
+    #[inline]
     pub(super) fn set_underrun(&mut self) {
         self.0 |= DEADLINE_STATE_FINISHED_TOO_EARLY;
     }
+
+    #[inline]
+    pub(super) fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.0 |= DEADLINE_STATE_PAUSED;
+        } else {
+            self.0 &= !DEADLINE_STATE_PAUSED;
+        }
+    }
+
+    #[inline]
+    pub(super) fn set_chained(&mut self, chained: bool) {
+        if chained {
+            self.0 |= DEADLINE_STATE_CHAINED;
+        } else {
+            self.0 &= !DEADLINE_STATE_CHAINED;
+        }
+    }
 }
 
 impl Debug for DeadlineStateSnapshot {
@@ -69,7 +129,10 @@ impl Debug for DeadlineStateSnapshot {
             .field("timestamp", &self.timestamp_ms())
             .field("is_running", &self.is_running())
             .field("is_stopped", &self.is_stopped())
+            .field("is_unbounded", &self.is_unbounded())
             .field("is_underrun", &self.is_underrun())
+            .field("is_paused", &self.is_paused())
+            .field("is_chained", &self.is_chained())
             .finish()
     }
 }
@@ -80,7 +143,10 @@ impl crate::log::ScoreDebug for DeadlineStateSnapshot {
             .field("timestamp", &self.timestamp_ms())
             .field("is_running", &self.is_running())
             .field("is_stopped", &self.is_stopped())
+            .field("is_unbounded", &self.is_unbounded())
             .field("is_underrun", &self.is_underrun())
+            .field("is_paused", &self.is_paused())
+            .field("is_chained", &self.is_chained())
             .finish()
     }
 }
@@ -91,6 +157,11 @@ impl Default for DeadlineStateSnapshot {
     }
 }
 
+/// Atomic representation of [`DeadlineStateSnapshot`].
+///
+/// With the `cache_padded_deadline_state` feature enabled each state occupies its own cache line, so deadlines
+/// updated concurrently from different threads do not invalidate each other (false sharing).
+#[cfg_attr(feature = "cache_padded_deadline_state", repr(align(64)))]
 pub(super) struct DeadlineState(AtomicU64);
 
 impl DeadlineState {
@@ -100,6 +171,7 @@ impl DeadlineState {
     }
 
     /// Returns a snapshot of the current deadline state.
+    #[inline]
     pub(super) fn snapshot(&self) -> DeadlineStateSnapshot {
         DeadlineStateSnapshot::new(self.0.load(Ordering::Relaxed))
     }
@@ -198,6 +270,63 @@ mod tests {
         assert!(snap.is_stopped()); // Default is stopped, running is set as a flag
     }
 
+    #[test]
+    fn set_unbounded() {
+        let mut snap = DeadlineStateSnapshot::default();
+        snap.set_running();
+        snap.set_unbounded();
+        snap.set_timestamp_ms(42);
+        assert!(snap.is_unbounded());
+        assert!(snap.is_running());
+        assert!(!snap.is_underrun());
+        assert_eq!(snap.timestamp_ms(), 42);
+    }
+
+    #[test]
+    fn set_paused() {
+        let mut snap = DeadlineStateSnapshot::default();
+        snap.set_running();
+        snap.set_paused(true);
+        snap.set_timestamp_ms(42);
+        assert!(snap.is_paused());
+        assert!(snap.is_running());
+        assert_eq!(snap.timestamp_ms(), 42);
+
+        snap.set_paused(false);
+        assert!(!snap.is_paused());
+        assert!(snap.is_running());
+        assert_eq!(snap.timestamp_ms(), 42);
+    }
+
+    #[test]
+    fn set_chained() {
+        let mut snap = DeadlineStateSnapshot::default();
+        snap.set_running();
+        snap.set_chained(true);
+        snap.set_timestamp_ms(42);
+        assert!(snap.is_chained());
+        assert!(!snap.is_paused());
+
+        snap.set_chained(false);
+        assert!(!snap.is_chained());
+        assert!(snap.is_running());
+        assert_eq!(snap.timestamp_ms(), 42);
+    }
+
+    #[test]
+    fn timestamp_beyond_u32_range() {
+        // Monitor running for longer than ~49.7 days.
+        const HUNDRED_DAYS_AS_MS: u64 = 100 * 24 * 60 * 60 * 1000;
+        let mut snap = DeadlineStateSnapshot::default();
+        snap.set_running();
+        snap.set_unbounded();
+        snap.set_timestamp_ms(HUNDRED_DAYS_AS_MS);
+        assert_eq!(snap.timestamp_ms(), HUNDRED_DAYS_AS_MS);
+        assert!(snap.is_running());
+        assert!(snap.is_unbounded());
+        assert!(snap.is_stopped());
+    }
+
     #[test]
     fn as_u64_and_new() {
         let mut snap = DeadlineStateSnapshot::default();
@@ -208,4 +337,11 @@ mod tests {
         assert_eq!(snap2.timestamp_ms(), 42);
         assert!(snap2.is_running());
     }
+
+    #[test]
+    #[cfg(feature = "cache_padded_deadline_state")]
+    fn deadline_state_is_cache_line_aligned() {
+        assert_eq!(core::mem::align_of::<DeadlineState>(), 64);
+        assert_eq!(core::mem::size_of::<(crate::DeadlineTag, DeadlineState)>() % 64, 0);
+    }
 }
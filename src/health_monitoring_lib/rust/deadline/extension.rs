@@ -0,0 +1,74 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Extensions of the current activation of a deadline.
+///
+/// Written and read by the deadline owner only.
+pub(super) struct ExtensionSlot {
+    count: AtomicU32,
+    extended_ms: AtomicU32,
+}
+
+impl ExtensionSlot {
+    pub(super) fn new() -> Self {
+        Self {
+            count: AtomicU32::new(0),
+            extended_ms: AtomicU32::new(0),
+        }
+    }
+
+    /// Forget extensions of the previous activation.
+    pub(super) fn clear(&self) {
+        self.count.store(0, Ordering::Relaxed);
+        self.extended_ms.store(0, Ordering::Relaxed);
+    }
+
+    /// Another extension is allowed with at most `max_extensions` per activation.
+    pub(super) fn can_extend(&self, max_extensions: u32) -> bool {
+        self.count.load(Ordering::Relaxed) < max_extensions
+    }
+
+    /// Record extension of the current activation by `extension_ms`.
+    pub(super) fn record(&self, extension_ms: u32) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        let extended_ms = self.extended_ms.load(Ordering::Relaxed).saturating_add(extension_ms);
+        self.extended_ms.store(extended_ms, Ordering::Relaxed);
+    }
+
+    /// Total extension of the current activation in milliseconds.
+    pub(super) fn extended_ms(&self) -> u32 {
+        self.extended_ms.load(Ordering::Relaxed)
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::extension::ExtensionSlot;
+
+    #[test]
+    fn extension_slot_bounded_and_cleared() {
+        let slot = ExtensionSlot::new();
+        assert!(slot.can_extend(2));
+        slot.record(10);
+        slot.record(u32::MAX);
+        assert!(!slot.can_extend(2));
+        assert_eq!(slot.extended_ms(), u32::MAX);
+
+        slot.clear();
+        assert!(slot.can_extend(2));
+        assert!(!slot.can_extend(0));
+        assert_eq!(slot.extended_ms(), 0);
+    }
+}
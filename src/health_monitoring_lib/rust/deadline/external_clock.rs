@@ -0,0 +1,78 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use core::time::Duration;
+use std::time::Instant;
+
+/// Mapping of an external clock counting nanoseconds, e.g. `CLOCK_BOOTTIME` or a hardware timestamp counter,
+/// to [`Instant`], so externally captured timestamps can start deadlines with [`super::Deadline::start_at`].
+///
+/// Clocks are related by a single pair of readings, drift between them is not compensated.
+/// Long-lived mappings of drifting clocks should be re-synchronized periodically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExternalClock {
+    reference_ns: u64,
+    reference: Instant,
+}
+
+impl ExternalClock {
+    /// Synchronize with the external clock, `now_ns` must be read right before the call.
+    pub fn sync(now_ns: u64) -> Self {
+        Self::from_reference(now_ns, Instant::now())
+    }
+
+    /// Relate the external clock by a pair of readings taken at the same time.
+    ///
+    /// - `reference_ns` - reading of the external clock.
+    /// - `reference` - reading of the monotonic clock.
+    pub fn from_reference(reference_ns: u64, reference: Instant) -> Self {
+        Self {
+            reference_ns,
+            reference,
+        }
+    }
+
+    /// Convert `timestamp_ns` of the external clock, [`None`] if not representable as [`Instant`].
+    pub fn to_instant(&self, timestamp_ns: u64) -> Option<Instant> {
+        if timestamp_ns >= self.reference_ns {
+            self.reference
+                .checked_add(Duration::from_nanos(timestamp_ns - self.reference_ns))
+        } else {
+            self.reference
+                .checked_sub(Duration::from_nanos(self.reference_ns - timestamp_ns))
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::external_clock::ExternalClock;
+    use core::time::Duration;
+    use std::time::Instant;
+
+    #[test]
+    fn external_clock_to_instant() {
+        let reference = Instant::now();
+        let clock = ExternalClock::from_reference(5_000_000_000, reference);
+
+        assert_eq!(clock.to_instant(5_000_000_000), Some(reference));
+        assert_eq!(
+            clock.to_instant(5_002_000_000),
+            Some(reference + Duration::from_millis(2))
+        );
+        assert_eq!(
+            clock.to_instant(4_997_000_000),
+            reference.checked_sub(Duration::from_millis(3))
+        );
+    }
+}
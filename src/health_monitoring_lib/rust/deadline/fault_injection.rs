@@ -0,0 +1,82 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::deadline::DeadlineEvaluationError;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Violation forced with [`super::DeadlineMonitor::inject_violation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InjectedViolation {
+    /// Deadline is reported as finished too early.
+    TooEarly,
+    /// Deadline is reported as finished too late.
+    TooLate,
+}
+
+impl From<InjectedViolation> for DeadlineEvaluationError {
+    fn from(violation: InjectedViolation) -> Self {
+        match violation {
+            InjectedViolation::TooEarly => DeadlineEvaluationError::TooEarly,
+            InjectedViolation::TooLate => DeadlineEvaluationError::TooLate,
+        }
+    }
+}
+
+const NO_INJECTION: u8 = 0;
+const INJECTED_TOO_EARLY: u8 = 1;
+const INJECTED_TOO_LATE: u8 = 2;
+
+/// Violation injected into a deadline, reported by the next evaluation of the monitor.
+///
+/// Multiple writers - test code, single reader - evaluation of the monitor.
+pub(super) struct InjectionSlot(AtomicU8);
+
+impl InjectionSlot {
+    pub(super) fn new() -> Self {
+        Self(AtomicU8::new(NO_INJECTION))
+    }
+
+    /// Inject `violation`, replacing a violation injected before and not reported yet.
+    pub(super) fn store(&self, violation: InjectedViolation) {
+        let value = match violation {
+            InjectedViolation::TooEarly => INJECTED_TOO_EARLY,
+            InjectedViolation::TooLate => INJECTED_TOO_LATE,
+        };
+        self.0.store(value, Ordering::Relaxed);
+    }
+
+    /// Take the injected violation, [`None`] if no violation is injected.
+    pub(super) fn take(&self) -> Option<InjectedViolation> {
+        match self.0.swap(NO_INJECTION, Ordering::Relaxed) {
+            INJECTED_TOO_EARLY => Some(InjectedViolation::TooEarly),
+            INJECTED_TOO_LATE => Some(InjectedViolation::TooLate),
+            _ => None,
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::fault_injection::{InjectedViolation, InjectionSlot};
+
+    #[test]
+    fn injection_slot_taken_once() {
+        let slot = InjectionSlot::new();
+        assert_eq!(slot.take(), None);
+
+        slot.store(InjectedViolation::TooEarly);
+        slot.store(InjectedViolation::TooLate);
+        assert_eq!(slot.take(), Some(InjectedViolation::TooLate));
+        assert_eq!(slot.take(), None);
+    }
+}
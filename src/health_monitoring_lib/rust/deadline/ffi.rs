@@ -11,8 +11,8 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 use crate::deadline::deadline_monitor::Deadline;
-use crate::deadline::{DeadlineMonitor, DeadlineMonitorBuilder, DeadlineMonitorError};
-use crate::ffi::{FFIBorrowed, FFICode, FFIHandle};
+use crate::deadline::{DeadlineError, DeadlineMonitor, DeadlineMonitorBuilder, DeadlineMonitorError, ExternalClock};
+use crate::ffi::{ffi_destroy, ffi_method, FFIBorrowed, FFICode, FFIHandle};
 use crate::tag::DeadlineTag;
 use crate::TimeRange;
 use core::time::Duration;
@@ -29,13 +29,26 @@ impl DeadlineMonitorCpp {
     }
 
     pub(crate) fn get_deadline(&self, deadline_tag: DeadlineTag) -> Result<FFIHandle, FFICode> {
-        match self.monitor.get_deadline(deadline_tag) {
+        match self.monitor.get_deadline_ffi(deadline_tag) {
             Ok(deadline) => {
                 // Now we allocate at runtime. As next step we will add a memory pool for deadlines into self and this way we will not need allocate anymore
                 Ok(Box::into_raw(Box::new(deadline)).cast())
             },
             Err(DeadlineMonitorError::DeadlineInUse) => Err(FFICode::AlreadyExists),
             Err(DeadlineMonitorError::DeadlineNotFound) => Err(FFICode::NotFound),
+            Err(DeadlineMonitorError::NoCustomDeadlineSlot | DeadlineMonitorError::InvalidRange) => {
+                Err(FFICode::InvalidArgument)
+            },
+            Err(DeadlineMonitorError::DeadlineAlreadyFailed | DeadlineMonitorError::WrongState) => {
+                Err(FFICode::WrongState)
+            },
+        }
+    }
+
+    pub(crate) fn get_deadline_holder(&self, deadline_tag: DeadlineTag) -> Result<u8, FFICode> {
+        match self.monitor.deadline_holder(deadline_tag) {
+            Ok(holder) => Ok(holder.map_or(0, |holder| holder as u8)),
+            Err(_) => Err(FFICode::NotFound),
         }
     }
 }
@@ -54,28 +67,81 @@ pub extern "C" fn deadline_monitor_builder_create(deadline_monitor_builder_handl
     FFICode::Success
 }
 
+ffi_destroy!(
+    /// Destroys a builder created by `deadline_monitor_builder_create`.
+    fn deadline_monitor_builder_destroy(DeadlineMonitorBuilder)
+);
+
+ffi_method!(
+    fn deadline_monitor_builder_with_thread_attribution(builder: &mut DeadlineMonitorBuilder) {
+        builder.with_thread_attribution_internal();
+        FFICode::Success
+    }
+);
+
+ffi_method!(
+    fn deadline_monitor_builder_with_violation_backtrace(builder: &mut DeadlineMonitorBuilder, max_bytes: u32) {
+        builder.with_violation_backtrace_internal(max_bytes as usize);
+        FFICode::Success
+    }
+);
+
+ffi_method!(
+    fn deadline_monitor_builder_with_scheduling_statistics(builder: &mut DeadlineMonitorBuilder) {
+        builder.with_scheduling_statistics_internal();
+        FFICode::Success
+    }
+);
+
+ffi_method!(
+    fn deadline_monitor_builder_with_max_extensions(builder: &mut DeadlineMonitorBuilder, max_extensions: u32) {
+        builder.with_max_extensions_internal(max_extensions);
+        FFICode::Success
+    }
+);
+
 #[unsafe(no_mangle)]
-pub extern "C" fn deadline_monitor_builder_destroy(deadline_monitor_builder_handle: FFIHandle) -> FFICode {
-    if deadline_monitor_builder_handle.is_null() {
+pub extern "C" fn deadline_monitor_builder_add_deadline(
+    deadline_monitor_builder_handle: FFIHandle,
+    deadline_tag: *const DeadlineTag,
+    min_ms: u32,
+    max_ms: u32,
+) -> FFICode {
+    if deadline_monitor_builder_handle.is_null() || deadline_tag.is_null() {
         return FFICode::NullParameter;
     }
 
     // SAFETY:
     // Validity of the pointer is ensured.
+    // `DeadlineTag` type must be compatible between C++ and Rust.
+    let deadline_tag = unsafe { *deadline_tag };
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
     // It is assumed that the pointer was created by a call to `deadline_monitor_builder_create`.
-    unsafe {
-        let _ = Box::from_raw(deadline_monitor_builder_handle as *mut DeadlineMonitorBuilder);
-    }
+    // It is assumed that the pointer was not consumed by a call to `deadline_monitor_builder_destroy`.
+    let mut deadline_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(deadline_monitor_builder_handle as *mut DeadlineMonitorBuilder) });
+
+    let range_min = Duration::from_millis(min_ms as u64);
+    let range_max = Duration::from_millis(max_ms as u64);
+    let range = match TimeRange::new_internal(range_min, range_max) {
+        Some(range) => range,
+        None => return FFICode::InvalidArgument,
+    };
+
+    deadline_monitor_builder.add_deadline_internal(deadline_tag, range);
 
     FFICode::Success
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn deadline_monitor_builder_add_deadline(
+pub extern "C" fn deadline_monitor_builder_add_deadline_concurrent(
     deadline_monitor_builder_handle: FFIHandle,
     deadline_tag: *const DeadlineTag,
     min_ms: u32,
     max_ms: u32,
+    max_instances: u32,
 ) -> FFICode {
     if deadline_monitor_builder_handle.is_null() || deadline_tag.is_null() {
         return FFICode::NullParameter;
@@ -99,8 +165,11 @@ pub extern "C" fn deadline_monitor_builder_add_deadline(
         Some(range) => range,
         None => return FFICode::InvalidArgument,
     };
+    if max_instances == 0 {
+        return FFICode::InvalidArgument;
+    }
 
-    deadline_monitor_builder.add_deadline_internal(deadline_tag, range);
+    deadline_monitor_builder.add_deadline_concurrent_internal(deadline_tag, range, max_instances as usize);
 
     FFICode::Success
 }
@@ -138,82 +207,117 @@ pub extern "C" fn deadline_monitor_get_deadline(
     }
 }
 
+/// Returns the current holder of the deadline: 0 - not in use, 1 - Rust, 2 - FFI.
 #[unsafe(no_mangle)]
-pub extern "C" fn deadline_monitor_destroy(deadline_monitor_handle: FFIHandle) -> FFICode {
-    if deadline_monitor_handle.is_null() {
+pub extern "C" fn deadline_monitor_get_deadline_holder(
+    deadline_monitor_handle: FFIHandle,
+    deadline_tag: *const DeadlineTag,
+    deadline_holder_out: *mut u8,
+) -> FFICode {
+    if deadline_monitor_handle.is_null() || deadline_tag.is_null() || deadline_holder_out.is_null() {
         return FFICode::NullParameter;
     }
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // It is assumed that the pointer was created by a call to `health_monitor_get_deadline_monitor`.
-    unsafe {
-        let _ = Box::from_raw(deadline_monitor_handle as *mut DeadlineMonitorCpp);
-    }
-
-    FFICode::Success
-}
-
-#[unsafe(no_mangle)]
-pub extern "C" fn deadline_start(deadline_handle: FFIHandle) -> FFICode {
-    if deadline_handle.is_null() {
-        return FFICode::NullParameter;
-    }
+    // `DeadlineTag` type must be compatible between C++ and Rust.
+    let deadline_tag = unsafe { *deadline_tag };
 
     // SAFETY:
     // Validity of this pointer is ensured.
-    // It is assumed that the pointer was created by a call to `deadline_monitor_get_deadline`.
-    // It is assumed that the pointer was not consumed by a call to `deadline_destroy`.
-    let mut deadline = FFIBorrowed::new(unsafe { Box::from_raw(deadline_handle as *mut Deadline) });
+    // It is assumed that the pointer was created by a call to `health_monitor_get_deadline_monitor`.
+    // It is assumed that the pointer was not consumed by a call to `deadline_monitor_destroy`.
+    let deadline_monitor =
+        FFIBorrowed::new(unsafe { Box::from_raw(deadline_monitor_handle as *mut DeadlineMonitorCpp) });
 
-    // SAFETY: `Deadline` has move-only semantic, as multiple owners are not allowed.
-    match unsafe { deadline.start_internal() } {
-        Ok(()) => FFICode::Success,
-        Err(_err) => FFICode::Failed,
+    match deadline_monitor.get_deadline_holder(deadline_tag) {
+        Ok(holder) => {
+            // SAFETY:
+            // Validity of the pointer is ensured.
+            // `u8` has no alignment requirements and is valid for any value written.
+            unsafe {
+                *deadline_holder_out = holder;
+            }
+            FFICode::Success
+        },
+        Err(e) => e,
     }
 }
 
-#[unsafe(no_mangle)]
-pub extern "C" fn deadline_stop(deadline_handle: FFIHandle) -> FFICode {
-    if deadline_handle.is_null() {
-        return FFICode::NullParameter;
+ffi_destroy!(
+    /// Destroys a monitor created by `health_monitor_get_deadline_monitor`.
+    fn deadline_monitor_destroy(DeadlineMonitorCpp)
+);
+
+ffi_method!(
+    fn deadline_start(deadline: &mut Deadline) {
+        // SAFETY: `Deadline` has move-only semantic, as multiple owners are not allowed.
+        match unsafe { deadline.start_internal(None) } {
+            Ok(()) => FFICode::Success,
+            Err(_err) => FFICode::Failed,
+        }
     }
-
-    // SAFETY:
-    // Validity of this pointer is ensured.
-    // It is assumed that the pointer was created by a call to `deadline_monitor_get_deadline`.
-    // It is assumed that the pointer was not consumed by a call to `deadline_destroy`.
-    let mut deadline = FFIBorrowed::new(unsafe { Box::from_raw(deadline_handle as *mut Deadline) });
-
-    deadline.stop_internal();
-
-    FFICode::Success
-}
-
-#[unsafe(no_mangle)]
-pub extern "C" fn deadline_destroy(deadline_handle: FFIHandle) -> FFICode {
-    if deadline_handle.is_null() {
-        return FFICode::NullParameter;
+);
+
+ffi_method!(
+    fn deadline_start_with_id(deadline: &mut Deadline, correlation_id: u64) {
+        // SAFETY: `Deadline` has move-only semantic, as multiple owners are not allowed.
+        match unsafe { deadline.start_internal(Some(correlation_id)) } {
+            Ok(()) => FFICode::Success,
+            Err(_err) => FFICode::Failed,
+        }
+    }
+);
+
+ffi_method!(
+    fn deadline_start_at(deadline: &mut Deadline, timestamp_ns: u64, now_ns: u64) {
+        let Some(started_at) = ExternalClock::sync(now_ns).to_instant(timestamp_ns) else {
+            return FFICode::InvalidArgument;
+        };
+        // SAFETY: `Deadline` has move-only semantic, as multiple owners are not allowed.
+        match unsafe { deadline.start_at_internal(started_at) } {
+            Ok(()) => FFICode::Success,
+            Err(DeadlineError::ImplausibleTimestamp) => FFICode::InvalidArgument,
+            Err(_err) => FFICode::Failed,
+        }
     }
+);
+
+ffi_method!(
+    fn deadline_extend(deadline: &mut Deadline, extension_ms: u32) {
+        match deadline.extend_internal(Duration::from_millis(u64::from(extension_ms))) {
+            Ok(()) => FFICode::Success,
+            Err(DeadlineError::ExtensionLimitReached) => FFICode::WrongState,
+            Err(_err) => FFICode::Failed,
+        }
+    }
+);
 
-    // SAFETY:
-    // Validity of this pointer is ensured.
-    // It is assumed that the pointer was created by a call to `deadline_monitor_get_deadline`.
-    unsafe {
-        let _ = Box::from_raw(deadline_handle as *mut Deadline);
+ffi_method!(
+    fn deadline_stop(deadline: &mut Deadline) {
+        deadline.stop_internal();
+        FFICode::Success
     }
+);
 
-    FFICode::Success
-}
+ffi_destroy!(
+    /// Destroys a deadline created by `deadline_monitor_get_deadline`.
+    fn deadline_destroy(Deadline)
+);
 
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
     use crate::deadline::ffi::{
-        deadline_destroy, deadline_monitor_builder_add_deadline, deadline_monitor_builder_create,
-        deadline_monitor_builder_destroy, deadline_monitor_destroy, deadline_monitor_get_deadline, deadline_start,
-        deadline_stop,
+        deadline_destroy, deadline_extend, deadline_monitor_builder_add_deadline,
+        deadline_monitor_builder_add_deadline_concurrent, deadline_monitor_builder_create,
+        deadline_monitor_builder_destroy, deadline_monitor_builder_with_max_extensions,
+        deadline_monitor_builder_with_scheduling_statistics, deadline_monitor_builder_with_thread_attribution,
+        deadline_monitor_builder_with_violation_backtrace, deadline_monitor_destroy, deadline_monitor_get_deadline,
+        deadline_monitor_get_deadline_holder, deadline_start, deadline_start_at, deadline_start_with_id, deadline_stop,
+        DeadlineMonitorCpp,
     };
+    use crate::deadline::{DeadlineHolder, DeadlineMonitorError};
     use crate::ffi::{
         health_monitor_builder_add_deadline_monitor, health_monitor_builder_build, health_monitor_builder_create,
         health_monitor_destroy, health_monitor_get_deadline_monitor, FFICode, FFIHandle,
@@ -248,6 +352,49 @@ mod tests {
         assert_eq!(deadline_monitor_builder_destroy_result, FFICode::NullParameter);
     }
 
+    #[test]
+    fn deadline_monitor_builder_options_succeed() {
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+
+        let with_thread_attribution_result =
+            deadline_monitor_builder_with_thread_attribution(deadline_monitor_builder_handle);
+        assert_eq!(with_thread_attribution_result, FFICode::Success);
+        let with_violation_backtrace_result =
+            deadline_monitor_builder_with_violation_backtrace(deadline_monitor_builder_handle, 1024);
+        assert_eq!(with_violation_backtrace_result, FFICode::Success);
+        let with_scheduling_statistics_result =
+            deadline_monitor_builder_with_scheduling_statistics(deadline_monitor_builder_handle);
+        assert_eq!(with_scheduling_statistics_result, FFICode::Success);
+        let with_max_extensions_result =
+            deadline_monitor_builder_with_max_extensions(deadline_monitor_builder_handle, 2);
+        assert_eq!(with_max_extensions_result, FFICode::Success);
+
+        // Clean-up.
+        deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
+    }
+
+    #[test]
+    fn deadline_monitor_builder_options_null_builder() {
+        assert_eq!(
+            deadline_monitor_builder_with_thread_attribution(null_mut()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            deadline_monitor_builder_with_violation_backtrace(null_mut(), 1024),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            deadline_monitor_builder_with_scheduling_statistics(null_mut()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            deadline_monitor_builder_with_max_extensions(null_mut(), 2),
+            FFICode::NullParameter
+        );
+    }
+
     #[test]
     fn deadline_monitor_builder_add_deadline_succeeds() {
         let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
@@ -286,6 +433,58 @@ mod tests {
         deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
     }
 
+    #[test]
+    fn deadline_monitor_builder_add_deadline_concurrent_succeeds() {
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let deadline_tag = DeadlineTag::from("deadline_1");
+
+        assert_eq!(
+            deadline_monitor_builder_add_deadline_concurrent(
+                deadline_monitor_builder_handle,
+                &deadline_tag as *const DeadlineTag,
+                100,
+                200,
+                4
+            ),
+            FFICode::Success
+        );
+        assert_eq!(
+            deadline_monitor_builder_add_deadline_concurrent(
+                deadline_monitor_builder_handle,
+                &deadline_tag as *const DeadlineTag,
+                100,
+                200,
+                0
+            ),
+            FFICode::InvalidArgument
+        );
+        assert_eq!(
+            deadline_monitor_builder_add_deadline_concurrent(
+                deadline_monitor_builder_handle,
+                &deadline_tag as *const DeadlineTag,
+                10000,
+                100,
+                4
+            ),
+            FFICode::InvalidArgument
+        );
+        assert_eq!(
+            deadline_monitor_builder_add_deadline_concurrent(
+                null_mut(),
+                &deadline_tag as *const DeadlineTag,
+                100,
+                200,
+                4
+            ),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
+    }
+
     #[test]
     fn deadline_monitor_builder_add_deadline_null_builder() {
         let deadline_tag = DeadlineTag::from("deadline_1");
@@ -559,6 +758,117 @@ mod tests {
         health_monitor_destroy(health_monitor_handle);
     }
 
+    #[test]
+    fn deadline_start_with_id_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_handle: FFIHandle = null_mut();
+
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_add_deadline(
+            deadline_monitor_builder_handle,
+            &deadline_tag as *const DeadlineTag,
+            100,
+            200,
+        );
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_deadline_monitor(
+            health_monitor_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            &mut deadline_monitor_handle as *mut FFIHandle,
+        );
+        let _ = deadline_monitor_get_deadline(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut deadline_handle as *mut FFIHandle,
+        );
+
+        let deadline_start_result = deadline_start_with_id(deadline_handle, u64::MAX);
+        assert_eq!(deadline_start_result, FFICode::Success);
+
+        // Clean-up.
+        deadline_destroy(deadline_handle);
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn deadline_start_at_validates_timestamp() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_handle: FFIHandle = null_mut();
+
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_add_deadline(
+            deadline_monitor_builder_handle,
+            &deadline_tag as *const DeadlineTag,
+            100,
+            200,
+        );
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_deadline_monitor(
+            health_monitor_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            &mut deadline_monitor_handle as *mut FFIHandle,
+        );
+        let _ = deadline_monitor_get_deadline(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut deadline_handle as *mut FFIHandle,
+        );
+        std::thread::sleep(core::time::Duration::from_millis(20));
+
+        // Timestamps of an external clock, in the future and before the monitor was built.
+        let now_ns = 50_000_000_000;
+        assert_eq!(
+            deadline_start_at(deadline_handle, now_ns + 5_000_000, now_ns),
+            FFICode::InvalidArgument
+        );
+        assert_eq!(
+            deadline_start_at(deadline_handle, now_ns - 10_000_000_000, now_ns),
+            FFICode::InvalidArgument
+        );
+        assert_eq!(
+            deadline_start_at(deadline_handle, now_ns - 5_000_000, now_ns),
+            FFICode::Success
+        );
+
+        // Clean-up.
+        deadline_destroy(deadline_handle);
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
     #[test]
     fn deadline_start_already_started() {
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
@@ -665,6 +975,63 @@ mod tests {
         health_monitor_destroy(health_monitor_handle);
     }
 
+    #[test]
+    fn deadline_extend_bounded() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_handle: FFIHandle = null_mut();
+
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_add_deadline(
+            deadline_monitor_builder_handle,
+            &deadline_tag as *const DeadlineTag,
+            0,
+            200,
+        );
+        let _ = deadline_monitor_builder_with_max_extensions(deadline_monitor_builder_handle, 1);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_deadline_monitor(
+            health_monitor_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            &mut deadline_monitor_handle as *mut FFIHandle,
+        );
+        let _ = deadline_monitor_get_deadline(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut deadline_handle as *mut FFIHandle,
+        );
+        let _ = deadline_start(deadline_handle);
+
+        assert_eq!(deadline_extend(deadline_handle, 100), FFICode::Success);
+        assert_eq!(deadline_extend(deadline_handle, 100), FFICode::WrongState);
+        let _ = deadline_stop(deadline_handle);
+
+        // Clean-up.
+        deadline_destroy(deadline_handle);
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn deadline_extend_null_deadline() {
+        assert_eq!(deadline_extend(null_mut(), 100), FFICode::NullParameter);
+    }
+
     #[test]
     fn deadline_stop_null_deadline() {
         let deadline_stop_result = deadline_stop(null_mut());
@@ -676,4 +1043,164 @@ mod tests {
         let deadline_destroy_result = deadline_destroy(null_mut());
         assert_eq!(deadline_destroy_result, FFICode::NullParameter);
     }
+
+    fn create_deadline_monitor(deadline_tag: DeadlineTag) -> (FFIHandle, FFIHandle) {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_handle: FFIHandle = null_mut();
+
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_add_deadline(
+            deadline_monitor_builder_handle,
+            &deadline_tag as *const DeadlineTag,
+            100,
+            200,
+        );
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_deadline_monitor(
+            health_monitor_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            &mut deadline_monitor_handle as *mut FFIHandle,
+        );
+
+        (health_monitor_handle, deadline_monitor_handle)
+    }
+
+    fn deadline_holder(deadline_monitor_handle: FFIHandle, deadline_tag: DeadlineTag) -> u8 {
+        let mut holder = u8::MAX;
+        let result = deadline_monitor_get_deadline_holder(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut holder as *mut u8,
+        );
+        assert_eq!(result, FFICode::Success);
+        holder
+    }
+
+    #[test]
+    fn deadline_monitor_get_deadline_held_by_rust() {
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let (health_monitor_handle, deadline_monitor_handle) = create_deadline_monitor(deadline_tag);
+        // SAFETY: handle created above, not destroyed until the end of the test.
+        let deadline_monitor = unsafe { &*(deadline_monitor_handle as *const DeadlineMonitorCpp) };
+
+        let rust_deadline = deadline_monitor.monitor.get_deadline(deadline_tag).unwrap();
+        assert_eq!(
+            deadline_holder(deadline_monitor_handle, deadline_tag),
+            DeadlineHolder::Rust as u8
+        );
+
+        let mut deadline_handle: FFIHandle = null_mut();
+        let deadline_monitor_get_deadline_result = deadline_monitor_get_deadline(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut deadline_handle as *mut FFIHandle,
+        );
+        assert_eq!(deadline_monitor_get_deadline_result, FFICode::AlreadyExists);
+        assert!(deadline_handle.is_null());
+
+        drop(rust_deadline);
+        assert_eq!(deadline_holder(deadline_monitor_handle, deadline_tag), 0);
+        let deadline_monitor_get_deadline_result = deadline_monitor_get_deadline(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut deadline_handle as *mut FFIHandle,
+        );
+        assert_eq!(deadline_monitor_get_deadline_result, FFICode::Success);
+
+        // Clean-up.
+        deadline_destroy(deadline_handle);
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn deadline_monitor_get_deadline_held_by_ffi() {
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let (health_monitor_handle, deadline_monitor_handle) = create_deadline_monitor(deadline_tag);
+        // SAFETY: handle created above, not destroyed until the end of the test.
+        let deadline_monitor = unsafe { &*(deadline_monitor_handle as *const DeadlineMonitorCpp) };
+
+        let mut deadline_handle: FFIHandle = null_mut();
+        let _ = deadline_monitor_get_deadline(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut deadline_handle as *mut FFIHandle,
+        );
+        assert_eq!(
+            deadline_holder(deadline_monitor_handle, deadline_tag),
+            DeadlineHolder::Ffi as u8
+        );
+        assert_eq!(
+            deadline_monitor.monitor.get_deadline(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+
+        deadline_destroy(deadline_handle);
+        assert_eq!(deadline_holder(deadline_monitor_handle, deadline_tag), 0);
+        assert!(deadline_monitor.monitor.get_deadline(deadline_tag).is_ok());
+
+        // Clean-up.
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn deadline_monitor_get_deadline_holder_unknown_deadline() {
+        let (health_monitor_handle, deadline_monitor_handle) = create_deadline_monitor(DeadlineTag::from("deadline_1"));
+
+        let deadline_tag = DeadlineTag::from("deadline_2");
+        let mut holder: u8 = 0;
+        let result = deadline_monitor_get_deadline_holder(
+            deadline_monitor_handle,
+            &deadline_tag as *const DeadlineTag,
+            &mut holder as *mut u8,
+        );
+        assert_eq!(result, FFICode::NotFound);
+
+        // Clean-up.
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn deadline_monitor_get_deadline_holder_null_parameters() {
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let (health_monitor_handle, deadline_monitor_handle) = create_deadline_monitor(deadline_tag);
+        let mut holder: u8 = 0;
+
+        assert_eq!(
+            deadline_monitor_get_deadline_holder(null_mut(), &deadline_tag as *const DeadlineTag, &mut holder),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            deadline_monitor_get_deadline_holder(deadline_monitor_handle, core::ptr::null(), &mut holder),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            deadline_monitor_get_deadline_holder(
+                deadline_monitor_handle,
+                &deadline_tag as *const DeadlineTag,
+                null_mut()
+            ),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
 }
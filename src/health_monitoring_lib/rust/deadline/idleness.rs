@@ -0,0 +1,153 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use core::time::Duration;
+
+/// How an idle deadline monitor is reported, see [`crate::deadline::DeadlineMonitorBuilder::with_max_idle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdleSeverity {
+    /// Idle monitor is logged only.
+    Info,
+    /// Idle monitor is reported as a failure of the monitor.
+    Error,
+}
+
+/// Idleness detection settings of a monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct IdleConfig {
+    pub(super) max_idle: Duration,
+    pub(super) severity: IdleSeverity,
+}
+
+/// No deadline started since idleness detection was (re)started.
+const NO_ACTIVITY: u64 = u64::MAX;
+
+/// Time of the last start of any deadline of the monitor, in milliseconds since the monitor starting point.
+///
+/// Multiple writers - deadline owners, single reader - evaluation of the monitor.
+pub(super) struct IdleSlot {
+    config: IdleConfig,
+    last_activity_ms: AtomicU64,
+    /// Current idle period was already reported.
+    reported: AtomicBool,
+}
+
+impl IdleSlot {
+    pub(super) fn new(config: IdleConfig) -> Self {
+        Self {
+            config,
+            last_activity_ms: AtomicU64::new(NO_ACTIVITY),
+            reported: AtomicBool::new(false),
+        }
+    }
+
+    pub(super) fn severity(&self) -> IdleSeverity {
+        self.config.severity
+    }
+
+    /// Record a deadline started at `now_ms`, ending the current idle period.
+    pub(super) fn record_activity(&self, now_ms: u64) {
+        self.last_activity_ms
+            .store(now_ms.min(NO_ACTIVITY - 1), Ordering::Relaxed);
+        self.reported.store(false, Ordering::Relaxed);
+    }
+
+    /// Restart idleness detection, e.g. while the monitor is disabled.
+    pub(super) fn reset(&self) {
+        self.last_activity_ms.store(NO_ACTIVITY, Ordering::Relaxed);
+        self.reported.store(false, Ordering::Relaxed);
+    }
+
+    /// Do not count the `gap_ms` in which the process was not running as idle time.
+    pub(super) fn rebaseline(&self, gap_ms: u64) {
+        let _ = self
+            .last_activity_ms
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last_activity_ms| {
+                (last_activity_ms != NO_ACTIVITY).then(|| last_activity_ms.saturating_add(gap_ms).min(NO_ACTIVITY - 1))
+            });
+    }
+
+    /// Check idleness at `now_ms`, detection starts with the first check after a reset.
+    /// Returns idle time if the monitor became idle, each idle period is returned once.
+    pub(super) fn check(&self, now_ms: u64) -> Option<u64> {
+        let last_activity_ms = self.last_activity_ms.load(Ordering::Relaxed);
+        if last_activity_ms == NO_ACTIVITY {
+            // Deadline started concurrently is kept.
+            let _ = self.last_activity_ms.compare_exchange(
+                NO_ACTIVITY,
+                now_ms.min(NO_ACTIVITY - 1),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+            return None;
+        }
+
+        let idle_ms = now_ms.saturating_sub(last_activity_ms);
+        let max_idle_ms = u64::try_from(self.config.max_idle.as_millis()).unwrap_or(u64::MAX);
+        if idle_ms > max_idle_ms && !self.reported.swap(true, Ordering::Relaxed) {
+            Some(idle_ms)
+        } else {
+            None
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::idleness::{IdleConfig, IdleSeverity, IdleSlot};
+    use core::time::Duration;
+
+    fn slot() -> IdleSlot {
+        IdleSlot::new(IdleConfig {
+            max_idle: Duration::from_millis(100),
+            severity: IdleSeverity::Error,
+        })
+    }
+
+    #[test]
+    fn idle_slot_reported_once_per_idle_period() {
+        let slot = slot();
+        assert_eq!(slot.check(1000), None);
+        assert_eq!(slot.check(1100), None);
+        assert_eq!(slot.check(1101), Some(101));
+        assert_eq!(slot.check(1500), None);
+
+        slot.record_activity(1600);
+        assert_eq!(slot.check(1650), None);
+        assert_eq!(slot.check(1800), Some(200));
+    }
+
+    #[test]
+    fn idle_slot_reset_and_rebaseline() {
+        let slot = slot();
+        slot.record_activity(0);
+        slot.rebaseline(1000);
+        assert_eq!(slot.check(1050), None);
+
+        // Detection starts over with the first check after reset.
+        slot.reset();
+        slot.rebaseline(1000);
+        assert_eq!(slot.check(5000), None);
+        assert_eq!(slot.check(5200), Some(200));
+    }
+
+    #[test]
+    fn idle_slot_beyond_u32_range() {
+        const HUNDRED_DAYS_AS_MS: u64 = 100 * 24 * 60 * 60 * 1000;
+        let slot = slot();
+        slot.record_activity(HUNDRED_DAYS_AS_MS);
+        assert_eq!(slot.check(HUNDRED_DAYS_AS_MS + 100), None);
+        assert_eq!(slot.check(HUNDRED_DAYS_AS_MS + 150), Some(150));
+    }
+}
@@ -0,0 +1,307 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::deadline::{Deadline, DeadlineMonitor, DeadlineMonitorError};
+use crate::tag::DeadlineTag;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// What is supervised by the deadline of an [`Instrumented`] future.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Supervision {
+    /// From the first poll until the future completes.
+    Completion,
+    /// Time between polls of the future which is not completed yet.
+    PollGap,
+}
+
+/// A future supervised by a deadline, see [`DeadlineMonitor::instrument`] and [`DeadlineMonitor::instrument_poll_gap`].
+/// Deadline is released when the future completes or is dropped.
+/// Supervised future is stored inline and pinned together with this future, no allocation is made.
+pub struct Instrumented<F: Future> {
+    /// Structurally pinned - never moved out of a pinned [`Instrumented`], `Drop` does not touch it.
+    future: F,
+    deadline: Option<Deadline>,
+    supervision: Supervision,
+    is_polled: bool,
+    is_running: bool,
+}
+
+impl<F: Future> Instrumented<F> {
+    fn new(deadline: Deadline, future: F, supervision: Supervision) -> Self {
+        Self {
+            future,
+            deadline: Some(deadline),
+            supervision,
+            is_polled: false,
+            is_running: false,
+        }
+    }
+
+    fn start(&mut self) {
+        if let Some(deadline) = self.deadline.as_mut() {
+            // SAFETY: Deadline is owned by this future and stopped exactly once, before it is started again or released.
+            self.is_running = unsafe { deadline.start_internal(None) }.is_ok();
+        }
+    }
+
+    fn stop(&mut self) {
+        if let Some(deadline) = self.deadline.as_mut().filter(|_| self.is_running) {
+            deadline.stop_internal();
+        }
+        self.is_running = false;
+    }
+}
+
+impl<F: Future> Future for Instrumented<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: Only `future` is pinned, it is not moved and is polled through a pinned reference below.
+        // Other fields are not structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this.supervision {
+            Supervision::Completion if !this.is_polled => this.start(),
+            Supervision::Completion => {},
+            Supervision::PollGap => this.stop(),
+        }
+        this.is_polled = true;
+
+        // SAFETY: `this` is pinned and `future` is structurally pinned, see above.
+        let poll = unsafe { Pin::new_unchecked(&mut this.future) }.poll(cx);
+        match (&poll, this.supervision) {
+            (Poll::Ready(_), _) => {
+                this.stop();
+                this.deadline = None;
+            },
+            (Poll::Pending, Supervision::PollGap) => this.start(),
+            (Poll::Pending, Supervision::Completion) => {},
+        }
+        poll
+    }
+}
+
+impl<F: Future> Drop for Instrumented<F> {
+    fn drop(&mut self) {
+        // Cancelled future stops its deadline, same as a dropped deadline handle.
+        self.stop();
+    }
+}
+
+impl<F: Future> core::fmt::Debug for Instrumented<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Instrumented")
+            .field("deadline", &self.deadline)
+            .field("supervision", &self.supervision)
+            .field("is_polled", &self.is_polled)
+            .field("is_running", &self.is_running)
+            .finish()
+    }
+}
+
+impl DeadlineMonitor {
+    /// Supervises `future` with the deadline for the given tag.
+    /// Deadline is started when the future is first polled and stopped when it completes,
+    /// so the range applies to the whole asynchronous operation, including time spent waiting.
+    /// Deadline is held until the future completes or is dropped, dropping a started future stops its deadline.
+    /// # Returns
+    ///  - Ok(Instrumented) - if the deadline was acquired successfully.
+    ///  - Err(DeadlineMonitorError) - as for [`Self::get_deadline`].
+    pub fn instrument<F: Future>(
+        &self,
+        deadline_tag: DeadlineTag,
+        future: F,
+    ) -> Result<Instrumented<F>, DeadlineMonitorError> {
+        let deadline = self.get_deadline(deadline_tag)?;
+        Ok(Instrumented::new(deadline, future, Supervision::Completion))
+    }
+
+    /// Supervises that `future` is polled again within the range of the deadline for the given tag,
+    /// e.g. to detect a starved executor. Deadline is started each time the future returns [`Poll::Pending`]
+    /// and stopped when it is polled next. Time spent in polls is not supervised.
+    /// # Returns
+    ///  - Ok(Instrumented) - if the deadline was acquired successfully.
+    ///  - Err(DeadlineMonitorError) - as for [`Self::get_deadline`].
+    pub fn instrument_poll_gap<F: Future>(
+        &self,
+        deadline_tag: DeadlineTag,
+        future: F,
+    ) -> Result<Instrumented<F>, DeadlineMonitorError> {
+        let deadline = self.get_deadline(deadline_tag)?;
+        Ok(Instrumented::new(deadline, future, Supervision::PollGap))
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::{Monitor, MonitorEvaluationError, MonitorEvaluator};
+    use crate::deadline::{DeadlineEvaluationError, DeadlineHolder, DeadlineMonitor, DeadlineMonitorBuilder};
+    use crate::protected_memory::ProtectedMemoryAllocator;
+    use crate::tag::{DeadlineTag, MonitorTag};
+    use crate::TimeRange;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use core::time::Duration;
+    use std::time::Instant;
+
+    /// Future sleeping in each of its `polls` polls, pending until the last one.
+    struct Sleeping {
+        polls: u32,
+        sleep: Duration,
+    }
+
+    impl Future for Sleeping {
+        type Output = u32;
+
+        fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+            std::thread::sleep(self.sleep);
+            self.polls -= 1;
+            if self.polls == 0 {
+                Poll::Ready(42)
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Poll `future` to completion, sleeping `gap` between polls.
+    fn block_on<F: Future>(future: F, gap: Duration) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            std::thread::sleep(gap);
+        }
+    }
+
+    fn create_monitor() -> DeadlineMonitor {
+        DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline"),
+                TimeRange::new(Duration::ZERO, Duration::from_millis(40)),
+            )
+            .build(MonitorTag::from("deadline_monitor"), &ProtectedMemoryAllocator {})
+    }
+
+    fn evaluate(monitor: &DeadlineMonitor) -> Vec<MonitorEvaluationError> {
+        let mut errors = Vec::new();
+        monitor
+            .get_eval_handle()
+            .evaluate(Instant::now(), &mut |_, error, _| errors.push(error));
+        errors
+    }
+
+    #[test]
+    fn instrument_unknown_tag() {
+        let monitor = create_monitor();
+        let future = Sleeping {
+            polls: 1,
+            sleep: Duration::ZERO,
+        };
+        let result = monitor.instrument(DeadlineTag::from("unknown"), future);
+        assert_eq!(
+            result.err(),
+            Some(crate::deadline::DeadlineMonitorError::DeadlineNotFound)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn instrument_supervises_completion() {
+        let monitor = create_monitor();
+        let deadline_tag = DeadlineTag::from("deadline");
+        let future = Sleeping {
+            polls: 3,
+            sleep: Duration::from_millis(5),
+        };
+        let instrumented = monitor.instrument(deadline_tag, future).unwrap();
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(Some(DeadlineHolder::Rust)));
+        assert_eq!(block_on(instrumented, Duration::from_millis(5)), 42);
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(None));
+        assert_eq!(evaluate(&monitor), vec![]);
+
+        // Waiting between polls counts towards the deadline.
+        let future = Sleeping {
+            polls: 3,
+            sleep: Duration::from_millis(5),
+        };
+        let instrumented = monitor.instrument(deadline_tag, future).unwrap();
+        assert_eq!(block_on(instrumented, Duration::from_millis(30)), 42);
+        assert_eq!(evaluate(&monitor), vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn instrument_poll_gap_supervises_time_between_polls() {
+        let monitor = create_monitor();
+        let deadline_tag = DeadlineTag::from("deadline");
+
+        // Long polls with short gaps are fine.
+        let future = Sleeping {
+            polls: 3,
+            sleep: Duration::from_millis(30),
+        };
+        let instrumented = monitor.instrument_poll_gap(deadline_tag, future).unwrap();
+        assert_eq!(block_on(instrumented, Duration::from_millis(5)), 42);
+        assert_eq!(evaluate(&monitor), vec![]);
+
+        let future = Sleeping {
+            polls: 2,
+            sleep: Duration::ZERO,
+        };
+        let instrumented = monitor.instrument_poll_gap(deadline_tag, future).unwrap();
+        assert_eq!(block_on(instrumented, Duration::from_millis(60)), 42);
+        assert_eq!(evaluate(&monitor), vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn instrument_pins_future_inline() {
+        let monitor = create_monitor();
+        let deadline_tag = DeadlineTag::from("deadline");
+        // Self-referential future, not `Unpin`.
+        let future = async {
+            let value = 42;
+            let reference = &value;
+            Sleeping {
+                polls: 2,
+                sleep: Duration::ZERO,
+            }
+            .await;
+            *reference
+        };
+        let instrumented = monitor.instrument(deadline_tag, future).unwrap();
+        assert_eq!(block_on(instrumented, Duration::ZERO), 42);
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(None));
+    }
+
+    #[test]
+    fn instrument_dropped_releases_deadline() {
+        let monitor = create_monitor();
+        let deadline_tag = DeadlineTag::from("deadline");
+        let future = Sleeping {
+            polls: 2,
+            sleep: Duration::ZERO,
+        };
+        let mut instrumented = Box::pin(monitor.instrument(deadline_tag, future).unwrap());
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(instrumented.as_mut().poll(&mut cx), Poll::Pending);
+        drop(instrumented);
+        assert_eq!(monitor.deadline_holder(deadline_tag), Ok(None));
+        assert_eq!(evaluate(&monitor), vec![]);
+    }
+}
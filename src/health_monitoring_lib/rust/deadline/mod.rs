@@ -11,14 +11,47 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+mod budget_monitor;
+mod calibration;
+mod clock_source;
 mod common;
+mod correlation;
+mod cpu_time;
+mod custom;
 mod deadline_monitor;
 mod deadline_state;
+mod extension;
+mod external_clock;
+#[cfg(feature = "fault_injection")]
+mod fault_injection;
+mod idleness;
+#[cfg(feature = "async")]
+mod instrument;
+mod scheduling;
+mod statistics;
+mod sys;
+mod violation_backtrace;
+mod violation_callback;
+mod warning;
 
-pub(crate) use deadline_monitor::DeadlineEvaluationError;
+pub use budget_monitor::{BudgetMonitor, BudgetSection};
+pub use calibration::CalibrationPolicy;
+pub use clock_source::{ClockSource, TimeProvider};
+pub use common::DeadlineHolder;
 pub use deadline_monitor::{
-    DeadlineError, DeadlineHandle, DeadlineMonitor, DeadlineMonitorBuilder, DeadlineMonitorError,
+    Deadline, DeadlineError, DeadlineEvaluationError, DeadlineGroup, DeadlineGuard, DeadlineHandle,
+    DeadlineMeasurement, DeadlineMonitor, DeadlineMonitorBuilder, DeadlineMonitorError, DisabledIntervalSummary,
+    StartedDeadline,
 };
+pub use external_clock::ExternalClock;
+#[cfg(feature = "fault_injection")]
+pub use fault_injection::InjectedViolation;
+pub use idleness::IdleSeverity;
+#[cfg(feature = "async")]
+pub use instrument::Instrumented;
+pub use scheduling::SchedulingStatistics;
+pub use statistics::DeadlineStatistics;
+pub use violation_backtrace::{ViolationBacktrace, DEFAULT_BACKTRACE_MAX_BYTES, MAX_BACKTRACE_FRAMES};
 
 // FFI bindings
 pub(super) mod ffi;
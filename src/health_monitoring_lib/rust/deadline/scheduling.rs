@@ -0,0 +1,219 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::thread_attribution;
+use core::time::Duration;
+use std::sync::{Mutex, MutexGuard};
+
+/// Scheduler statistics of the thread running a deadline, accumulated since the deadline was started.
+///
+/// Distinguishes sections which were too slow from sections which did not get the CPU,
+/// e.g. due to priority inversion or CPU overload.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SchedulingStatistics {
+    /// Time the thread spent running on a CPU.
+    pub run_time: Duration,
+    /// Time the thread spent runnable, waiting for a CPU.
+    pub run_delay: Duration,
+    /// Number of times the thread was preempted.
+    pub involuntary_switches: u64,
+}
+
+/// Cumulative scheduler counters of a thread.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct SchedulerSample {
+    run_time_ns: u64,
+    run_delay_ns: u64,
+    involuntary_switches: u64,
+}
+
+impl SchedulerSample {
+    /// Statistics accumulated since the `baseline` sample.
+    fn since(&self, baseline: &SchedulerSample) -> SchedulingStatistics {
+        SchedulingStatistics {
+            run_time: Duration::from_nanos(self.run_time_ns.saturating_sub(baseline.run_time_ns)),
+            run_delay: Duration::from_nanos(self.run_delay_ns.saturating_sub(baseline.run_delay_ns)),
+            involuntary_switches: self.involuntary_switches.saturating_sub(baseline.involuntary_switches),
+        }
+    }
+
+    /// Parse `schedstat` and `status` files of a thread.
+    fn parse(schedstat: &str, status: &str) -> Option<Self> {
+        // `schedstat` format: `<run time ns> <run delay ns> <timeslices>`.
+        let mut schedstat = schedstat.split_whitespace();
+        let run_time_ns = schedstat.next()?.parse().ok()?;
+        let run_delay_ns = schedstat.next()?.parse().ok()?;
+        let involuntary_switches = status
+            .lines()
+            .find_map(|line| line.strip_prefix("nonvoluntary_ctxt_switches:"))?
+            .trim()
+            .parse()
+            .ok()?;
+        Some(Self {
+            run_time_ns,
+            run_delay_ns,
+            involuntary_switches,
+        })
+    }
+
+    /// Read counters of thread `tid` of the current process.
+    #[cfg(target_os = "linux")]
+    fn read(tid: u32) -> Option<Self> {
+        let schedstat = std::fs::read_to_string(format!("/proc/self/task/{tid}/schedstat")).ok()?;
+        let status = std::fs::read_to_string(format!("/proc/self/task/{tid}/status")).ok()?;
+        Self::parse(&schedstat, &status)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read(_tid: u32) -> Option<Self> {
+        // Scheduler statistics are not supported on this platform.
+        None
+    }
+}
+
+/// State of the current deadline activation.
+#[derive(Default)]
+struct Activation {
+    /// Thread which started the deadline with its counters at that time.
+    baseline: Option<(u32, SchedulerSample)>,
+    /// Statistics captured when the deadline was stopped outside of its range.
+    stopped: Option<SchedulingStatistics>,
+}
+
+/// Scheduler statistics of the current deadline activation.
+///
+/// Single writer - the deadline owner, single reader - evaluation of the monitor.
+/// Lock is held only to copy the sample, files are read outside of it.
+pub(super) struct SchedulingSlot {
+    activation: Mutex<Activation>,
+}
+
+impl SchedulingSlot {
+    pub(super) fn new() -> Self {
+        Self {
+            activation: Mutex::new(Activation::default()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Activation> {
+        self.activation.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Sample counters of the current thread when the deadline is started.
+    pub(super) fn start(&self) {
+        let baseline =
+            thread_attribution::current_tid().and_then(|tid| SchedulerSample::read(tid).map(|sample| (tid, sample)));
+        *self.lock() = Activation {
+            baseline,
+            stopped: None,
+        };
+    }
+
+    /// Capture statistics when the deadline is stopped outside of its range, so they do not grow afterwards.
+    pub(super) fn stop(&self) -> Option<SchedulingStatistics> {
+        let statistics = self.since_start();
+        self.lock().stopped = statistics;
+        statistics
+    }
+
+    /// Statistics of the current activation, captured on stop or sampled now if the deadline is still running.
+    /// [`None`] if the thread could not be sampled.
+    pub(super) fn load(&self) -> Option<SchedulingStatistics> {
+        let activation = self.lock();
+        if activation.stopped.is_some() {
+            return activation.stopped;
+        }
+        drop(activation);
+        self.since_start()
+    }
+
+    fn since_start(&self) -> Option<SchedulingStatistics> {
+        let (tid, baseline) = self.lock().baseline?;
+        Some(SchedulerSample::read(tid)?.since(&baseline))
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::scheduling::{SchedulerSample, SchedulingSlot, SchedulingStatistics};
+    use core::time::Duration;
+
+    #[test]
+    fn scheduler_sample_parse() {
+        let status = "Name:\tworker\nvoluntary_ctxt_switches:\t10\nnonvoluntary_ctxt_switches:\t3\n";
+        let sample = SchedulerSample::parse("2000000 5000000 7\n", status).unwrap();
+        assert_eq!(
+            sample,
+            SchedulerSample {
+                run_time_ns: 2_000_000,
+                run_delay_ns: 5_000_000,
+                involuntary_switches: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn scheduler_sample_parse_invalid() {
+        assert!(SchedulerSample::parse("", "nonvoluntary_ctxt_switches:\t3\n").is_none());
+        assert!(SchedulerSample::parse("1 2 3", "voluntary_ctxt_switches:\t3\n").is_none());
+    }
+
+    #[test]
+    fn scheduler_sample_since() {
+        let baseline = SchedulerSample {
+            run_time_ns: 1_000_000,
+            run_delay_ns: 1_000_000,
+            involuntary_switches: 1,
+        };
+        let sample = SchedulerSample {
+            run_time_ns: 3_000_000,
+            run_delay_ns: 11_000_000,
+            involuntary_switches: 4,
+        };
+        assert_eq!(
+            sample.since(&baseline),
+            SchedulingStatistics {
+                run_time: Duration::from_millis(2),
+                run_delay: Duration::from_millis(10),
+                involuntary_switches: 3,
+            }
+        );
+        // Counters never go backwards, but a sample of another thread must not underflow.
+        assert_eq!(baseline.since(&sample), SchedulingStatistics::default());
+    }
+
+    #[test]
+    fn scheduling_slot_not_started() {
+        assert!(SchedulingSlot::new().load().is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn scheduling_slot_start_and_stop() {
+        let slot = SchedulingSlot::new();
+        slot.start();
+        // Busy loop, so the thread accumulates run time.
+        let start = std::time::Instant::now();
+        while start.elapsed() < Duration::from_millis(20) {
+            core::hint::spin_loop();
+        }
+
+        let stopped = slot.stop().unwrap();
+        assert!(stopped.run_time > Duration::ZERO);
+        assert_eq!(slot.load(), Some(stopped));
+
+        // New activation discards statistics of the previous one.
+        slot.start();
+        assert!(slot.load().unwrap().run_time < stopped.run_time);
+    }
+}
@@ -0,0 +1,281 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+use std::sync::Mutex;
+
+/// Durations below this value have a bucket each.
+const EXACT_BUCKETS: u32 = 16;
+/// Number of buckets each power of two above [`EXACT_BUCKETS`] is divided into, as a power of two.
+const SUB_BUCKET_BITS: u32 = 3;
+/// Total number of buckets, covering the whole `u32` range of milliseconds.
+const NUM_BUCKETS: usize =
+    (EXACT_BUCKETS + (u32::BITS - EXACT_BUCKETS.trailing_zeros()) * (1 << SUB_BUCKET_BITS)) as usize;
+
+/// Statistics of durations measured between start and stop of a deadline.
+///
+/// Percentiles are approximated by a histogram, with an error below 12.5% of the value.
+/// Durations are aggregated by the monitoring worker, durations recorded faster than aggregated
+/// are counted, but do not contribute to the other values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DeadlineStatistics {
+    /// Number of measured activations.
+    pub count: u64,
+    /// Shortest measured duration.
+    pub min: Duration,
+    /// Longest measured duration.
+    pub max: Duration,
+    /// Mean of measured durations.
+    pub mean: Duration,
+    /// 95th percentile of measured durations.
+    pub p95: Duration,
+    /// 99th percentile of measured durations.
+    pub p99: Duration,
+    /// Number of activations stopped within the range, but later than the warning threshold of the deadline,
+    /// see [`crate::deadline::DeadlineMonitorBuilder::with_warning_at`].
+    pub near_misses: u64,
+}
+
+/// Number of durations buffered between aggregations, older durations are overwritten when full.
+const PENDING_CAPACITY: usize = 1024;
+
+/// Durations measured for a single deadline, stored in two tiers.
+///
+/// Stopping a deadline only appends the duration to a ring of pending durations, so the hot path
+/// costs a single counter increment and store. Pending durations are aggregated into a histogram
+/// by the monitoring worker once per cycle, or when statistics are requested.
+/// Durations overwritten before aggregation, e.g. while the worker is not running, are counted but not aggregated.
+pub(super) struct StatisticsSlot {
+    /// Ring of pending durations, each tagged with the lap of the ring it was written in.
+    pending: Box<[AtomicU64]>,
+    /// Number of durations recorded so far, position of the next write into `pending`.
+    recorded: AtomicU64,
+    aggregated: Mutex<Aggregate>,
+}
+
+/// Durations aggregated from the pending tier.
+struct Aggregate {
+    buckets: Box<[u64]>,
+    /// Number of pending durations taken, aggregated or overwritten.
+    drained: u64,
+    count: u64,
+    sum_ms: u64,
+    min_ms: u32,
+    max_ms: u32,
+}
+
+impl StatisticsSlot {
+    pub(super) fn new() -> Self {
+        Self {
+            pending: (0..PENDING_CAPACITY).map(|_| AtomicU64::new(0)).collect(),
+            recorded: AtomicU64::new(0),
+            aggregated: Mutex::new(Aggregate {
+                buckets: vec![0; NUM_BUCKETS].into_boxed_slice(),
+                drained: 0,
+                count: 0,
+                sum_ms: 0,
+                min_ms: u32::MAX,
+                max_ms: 0,
+            }),
+        }
+    }
+
+    /// Record duration of a single activation.
+    pub(super) fn record(&self, elapsed_ms: u32) {
+        let position = self.recorded.fetch_add(1, Ordering::Relaxed);
+        self.pending[position as usize % PENDING_CAPACITY].store(tagged(position, elapsed_ms), Ordering::Release);
+    }
+
+    /// Aggregate pending durations into the histogram.
+    /// Durations still being written are left for the next aggregation.
+    pub(super) fn aggregate(&self) {
+        let mut aggregated = self.aggregated.lock().unwrap_or_else(|e| e.into_inner());
+        let recorded = self.recorded.load(Ordering::Acquire);
+        // Durations older than the ring are overwritten, skipped without reading.
+        aggregated.drained = aggregated.drained.max(recorded.saturating_sub(PENDING_CAPACITY as u64));
+        while aggregated.drained < recorded {
+            let position = aggregated.drained;
+            let value = self.pending[position as usize % PENDING_CAPACITY].load(Ordering::Acquire);
+            let lap_offset = ((value >> u32::BITS) as u32).wrapping_sub(lap(position)) as i32;
+            if lap_offset < 0 {
+                // Position was taken, but the duration is not written yet.
+                break;
+            }
+            aggregated.drained += 1;
+            if lap_offset == 0 {
+                aggregated.add(value as u32);
+            }
+        }
+    }
+
+    /// Statistics of all recorded durations, aggregating pending durations first.
+    pub(super) fn statistics(&self) -> DeadlineStatistics {
+        self.aggregate();
+        let aggregated = self.aggregated.lock().unwrap_or_else(|e| e.into_inner());
+        if aggregated.count == 0 {
+            return DeadlineStatistics {
+                count: aggregated.drained,
+                ..DeadlineStatistics::default()
+            };
+        }
+
+        let count = aggregated.count;
+        let min_ms = aggregated.min_ms;
+        let max_ms = aggregated.max_ms;
+        let percentile = |percent: u64| {
+            let rank = (count * percent).div_ceil(100);
+            let mut seen = 0;
+            for (index, bucket) in aggregated.buckets.iter().enumerate() {
+                seen += bucket;
+                if seen >= rank {
+                    return bucket_upper_bound(index).clamp(min_ms, max_ms);
+                }
+            }
+            max_ms
+        };
+        let to_duration = |ms: u32| Duration::from_millis(u64::from(ms));
+
+        DeadlineStatistics {
+            count: aggregated.drained,
+            min: to_duration(min_ms),
+            max: to_duration(max_ms),
+            mean: Duration::from_millis(aggregated.sum_ms / count),
+            p95: to_duration(percentile(95)),
+            p99: to_duration(percentile(99)),
+            near_misses: 0,
+        }
+    }
+}
+
+impl Aggregate {
+    fn add(&mut self, elapsed_ms: u32) {
+        self.buckets[bucket_index(elapsed_ms)] += 1;
+        self.count += 1;
+        self.sum_ms += u64::from(elapsed_ms);
+        self.min_ms = self.min_ms.min(elapsed_ms);
+        self.max_ms = self.max_ms.max(elapsed_ms);
+    }
+}
+
+/// Lap of the pending ring the duration at `position` is written in, starting at 1 so unwritten entries are older.
+fn lap(position: u64) -> u32 {
+    ((position / PENDING_CAPACITY as u64) as u32).wrapping_add(1)
+}
+
+/// Pending duration tagged with its lap.
+fn tagged(position: u64, elapsed_ms: u32) -> u64 {
+    (u64::from(lap(position)) << u32::BITS) | u64::from(elapsed_ms)
+}
+
+/// Index of the bucket holding `value_ms`.
+fn bucket_index(value_ms: u32) -> usize {
+    if value_ms < EXACT_BUCKETS {
+        return value_ms as usize;
+    }
+    let magnitude = u32::BITS - 1 - value_ms.leading_zeros();
+    let sub_bucket = (value_ms >> (magnitude - SUB_BUCKET_BITS)) & ((1 << SUB_BUCKET_BITS) - 1);
+    (EXACT_BUCKETS + (magnitude - EXACT_BUCKETS.trailing_zeros()) * (1 << SUB_BUCKET_BITS) + sub_bucket) as usize
+}
+
+/// Largest value held by the bucket under `index`.
+fn bucket_upper_bound(index: usize) -> u32 {
+    let index = index as u32;
+    if index < EXACT_BUCKETS {
+        return index;
+    }
+    let magnitude = (index - EXACT_BUCKETS) / (1 << SUB_BUCKET_BITS) + EXACT_BUCKETS.trailing_zeros();
+    let sub_bucket = (index - EXACT_BUCKETS) % (1 << SUB_BUCKET_BITS);
+    let width_bits = magnitude - SUB_BUCKET_BITS;
+    let lower = (1u64 << magnitude) + (u64::from(sub_bucket) << width_bits);
+    (lower + (1u64 << width_bits) - 1).min(u64::from(u32::MAX)) as u32
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::statistics::{
+        bucket_index, bucket_upper_bound, tagged, StatisticsSlot, NUM_BUCKETS, PENDING_CAPACITY,
+    };
+    use crate::deadline::DeadlineStatistics;
+    use core::sync::atomic::Ordering;
+    use core::time::Duration;
+
+    #[test]
+    fn bucket_index_covers_u32_range() {
+        assert_eq!(bucket_index(0), 0);
+        assert_eq!(bucket_index(15), 15);
+        assert_eq!(bucket_index(16), 16);
+        assert_eq!(bucket_index(u32::MAX), NUM_BUCKETS - 1);
+        assert_eq!(bucket_upper_bound(NUM_BUCKETS - 1), u32::MAX);
+
+        for value in [16, 17, 100, 1000, 12345, 1 << 20, u32::MAX / 3] {
+            let index = bucket_index(value);
+            assert!(value <= bucket_upper_bound(index));
+            assert!(value > bucket_upper_bound(index - 1));
+        }
+    }
+
+    #[test]
+    fn statistics_slot_empty() {
+        let slot = StatisticsSlot::new();
+        assert_eq!(slot.statistics(), DeadlineStatistics::default());
+    }
+
+    #[test]
+    fn statistics_slot_percentiles() {
+        let slot = StatisticsSlot::new();
+        for elapsed in 1..=100 {
+            slot.record(elapsed);
+        }
+
+        let statistics = slot.statistics();
+        assert_eq!(statistics.count, 100);
+        assert_eq!(statistics.min, Duration::from_millis(1));
+        assert_eq!(statistics.max, Duration::from_millis(100));
+        assert_eq!(statistics.mean, Duration::from_millis(50));
+        // 95 and 99 fall into buckets 88 - 95 and 96 - 103.
+        assert_eq!(statistics.p95, Duration::from_millis(95));
+        assert_eq!(statistics.p99, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn statistics_slot_overwritten_before_aggregation() {
+        let slot = StatisticsSlot::new();
+        slot.record(1000);
+        slot.aggregate();
+        for _ in 0..PENDING_CAPACITY + 10 {
+            slot.record(5);
+        }
+
+        // Only the durations left in the ring are aggregated, all are counted.
+        let statistics = slot.statistics();
+        assert_eq!(statistics.count, PENDING_CAPACITY as u64 + 11);
+        assert_eq!(statistics.min, Duration::from_millis(5));
+        assert_eq!(statistics.max, Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn statistics_slot_leaves_unwritten_for_next_aggregation() {
+        let slot = StatisticsSlot::new();
+        slot.record(3);
+        // Position is taken by a concurrent recording, which did not store its duration yet.
+        slot.recorded.fetch_add(1, Ordering::Relaxed);
+        slot.aggregate();
+        assert_eq!(slot.statistics().count, 1);
+
+        slot.pending[1].store(tagged(1, 7), Ordering::Release);
+        let statistics = slot.statistics();
+        assert_eq!(statistics.count, 2);
+        assert_eq!(statistics.max, Duration::from_millis(7));
+    }
+}
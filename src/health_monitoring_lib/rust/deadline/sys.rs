@@ -0,0 +1,136 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! POSIX clocks used by deadline monitors, built on the declarations of `libc`.
+
+/// Identifier of a POSIX clock.
+#[cfg(any(target_os = "linux", target_os = "nto"))]
+pub(super) type ClockId = libc::clockid_t;
+#[cfg(not(any(target_os = "linux", target_os = "nto")))]
+pub(super) type ClockId = i32;
+
+/// Thread CPU-time clocks are supported on this platform.
+pub(super) const THREAD_CPU_CLOCK_SUPPORTED: bool = cfg!(any(target_os = "linux", target_os = "nto"));
+
+/// CPU-time clock of the current thread, equivalent to `CLOCK_THREAD_CPUTIME_ID` of this thread,
+/// but readable from other threads. [`None`] if not supported on this platform.
+#[cfg(any(target_os = "linux", target_os = "nto"))]
+pub(super) fn current_thread_cpu_clock() -> Option<ClockId> {
+    let mut clock_id: ClockId = 0;
+    // SAFETY:
+    // `pthread_self` is always valid for the calling thread, `clock_id` is a valid writable pointer.
+    let result = unsafe { libc::pthread_getcpuclockid(libc::pthread_self(), &mut clock_id) };
+    (result == 0).then_some(clock_id)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "nto")))]
+pub(super) fn current_thread_cpu_clock() -> Option<ClockId> {
+    // Thread CPU-time clocks are not supported on this platform.
+    None
+}
+
+/// Read `clock_id` in nanoseconds, [`None`] if the clock cannot be read, e.g. its thread exited.
+#[cfg(any(target_os = "linux", target_os = "nto"))]
+pub(super) fn read_clock_ns(clock_id: ClockId) -> Option<u64> {
+    let mut time = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY:
+    // `time` is a valid writable pointer, invalid clocks are rejected with an error.
+    let result = unsafe { libc::clock_gettime(clock_id, &mut time) };
+    if result != 0 {
+        return None;
+    }
+    let seconds = u64::try_from(time.tv_sec).ok()?;
+    let nanoseconds = u64::try_from(time.tv_nsec).ok()?;
+    Some(seconds.saturating_mul(1_000_000_000).saturating_add(nanoseconds))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "nto")))]
+pub(super) fn read_clock_ns(_clock_id: ClockId) -> Option<u64> {
+    None
+}
+
+/// Coarse monotonic clock (`CLOCK_MONOTONIC_COARSE`) is supported on this platform.
+pub(super) const COARSE_CLOCK_SUPPORTED: bool = cfg!(target_os = "linux");
+
+/// Read `CLOCK_MONOTONIC_COARSE` in nanoseconds, [`None`] if not supported or the clock cannot be read.
+#[cfg(target_os = "linux")]
+pub(super) fn read_coarse_clock_ns() -> Option<u64> {
+    read_clock_ns(libc::CLOCK_MONOTONIC_COARSE)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(super) fn read_coarse_clock_ns() -> Option<u64> {
+    // Coarse monotonic clock is not supported on this platform.
+    None
+}
+
+/// Capture return addresses of the current thread into `frames`, innermost first.
+/// Only walks the stack, never resolves symbols. Returns number of captured frames, zero if not supported.
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+pub(super) fn capture_stack(frames: &mut [usize]) -> usize {
+    let size = libc::c_int::try_from(frames.len()).unwrap_or(libc::c_int::MAX);
+    // SAFETY:
+    // `usize` and pointers have the same size and alignment, `frames` is valid for writes of `size` entries.
+    let captured = unsafe { libc::backtrace(frames.as_mut_ptr().cast::<*mut libc::c_void>(), size) };
+    usize::try_from(captured).unwrap_or(0)
+}
+
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+pub(super) fn capture_stack(_frames: &mut [usize]) -> usize {
+    // Stack capture is not supported on this platform.
+    0
+}
+
+/// Symbol and object file containing a code address.
+#[derive(Default)]
+pub(super) struct ResolvedAddress {
+    /// Symbol name and offset of the address within the symbol.
+    pub(super) symbol: Option<(String, usize)>,
+    /// Object file path and offset of the address within the file, allowing to resolve the address offline.
+    pub(super) file: Option<(String, usize)>,
+}
+
+/// Resolve `address` with symbols exported by loaded objects, parts not found are [`None`].
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+pub(super) fn resolve_address(address: usize) -> ResolvedAddress {
+    let mut info = libc::Dl_info {
+        dli_fname: core::ptr::null(),
+        dli_fbase: core::ptr::null_mut(),
+        dli_sname: core::ptr::null(),
+        dli_saddr: core::ptr::null_mut(),
+    };
+    // SAFETY:
+    // `info` is a valid writable pointer, unknown addresses are rejected with zero.
+    if unsafe { libc::dladdr(address as *const libc::c_void, &mut info) } == 0 {
+        return ResolvedAddress::default();
+    }
+
+    let name = |name: *const libc::c_char| {
+        // SAFETY:
+        // Non-null names set by `dladdr` are null-terminated strings owned by the dynamic linker.
+        unsafe { core::ffi::CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned()
+    };
+    ResolvedAddress {
+        symbol: (!info.dli_sname.is_null())
+            .then(|| (name(info.dli_sname), address.wrapping_sub(info.dli_saddr as usize))),
+        file: (!info.dli_fname.is_null())
+            .then(|| (name(info.dli_fname), address.wrapping_sub(info.dli_fbase as usize))),
+    }
+}
+
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+pub(super) fn resolve_address(_address: usize) -> ResolvedAddress {
+    ResolvedAddress::default()
+}
@@ -0,0 +1,177 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::deadline::sys;
+use core::fmt::Write;
+use core::time::Duration;
+use std::sync::Mutex;
+
+/// Default maximum size of a rendered violation backtrace.
+pub const DEFAULT_BACKTRACE_MAX_BYTES: usize = 4096;
+
+/// Maximum number of stack frames captured per violation.
+pub const MAX_BACKTRACE_FRAMES: usize = 32;
+
+/// Stack of the thread which stopped a deadline too late.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViolationBacktrace {
+    /// Time by which the deadline was exceeded.
+    pub overrun: Duration,
+    /// Rendered backtrace, one frame per line, limited to configured size.
+    /// Frames are resolved with exported symbols only, offsets within object files allow resolving them offline.
+    pub frames: String,
+    /// Backtrace was longer than configured size and was cut.
+    pub truncated: bool,
+}
+
+/// Captured, not yet rendered backtrace.
+struct Captured {
+    overrun: Duration,
+    addresses: [usize; MAX_BACKTRACE_FRAMES],
+    len: usize,
+}
+
+/// Slot holding at most one backtrace per deadline.
+///
+/// Capturing thread only stores up to [`MAX_BACKTRACE_FRAMES`] return addresses into preallocated storage,
+/// symbol resolution is deferred to the reader. Further violations are not captured until the stored backtrace
+/// is taken, which bounds the overhead.
+pub(super) struct BacktraceSlot {
+    max_bytes: usize,
+    captured: Mutex<Option<Captured>>,
+}
+
+impl BacktraceSlot {
+    pub(super) fn new(max_bytes: usize) -> Self {
+        // First capture may load the unwinder, do it now instead of on the supervised thread.
+        sys::capture_stack(&mut [0; 1]);
+        Self {
+            max_bytes,
+            captured: Mutex::new(None),
+        }
+    }
+
+    /// Capture backtrace of the current thread.
+    /// Returns `false` if a previous backtrace was not taken yet or slot is in use.
+    pub(super) fn capture(&self, overrun: Duration) -> bool {
+        // Never block the supervised thread.
+        let Ok(mut captured) = self.captured.try_lock() else {
+            return false;
+        };
+        if captured.is_some() {
+            return false;
+        }
+
+        let mut addresses = [0; MAX_BACKTRACE_FRAMES];
+        let len = sys::capture_stack(&mut addresses);
+        *captured = Some(Captured {
+            overrun,
+            addresses,
+            len,
+        });
+        true
+    }
+
+    /// Take stored backtrace, rendering it and limiting to configured size.
+    pub(super) fn take(&self) -> Option<ViolationBacktrace> {
+        let captured = self
+            .captured
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()?;
+
+        let mut frames = String::new();
+        for (index, address) in captured.addresses[..captured.len].iter().enumerate() {
+            if frames.len() > self.max_bytes {
+                break;
+            }
+            let resolved = sys::resolve_address(*address);
+            let _ = write!(frames, "{index:>2}: {address:#x}");
+            if let Some((symbol, offset)) = resolved.symbol {
+                let _ = write!(frames, " {symbol}+{offset:#x}");
+            }
+            if let Some((file, offset)) = resolved.file {
+                let _ = write!(frames, " ({file}+{offset:#x})");
+            }
+            frames.push('\n');
+        }
+
+        let truncated = frames.len() > self.max_bytes;
+        if truncated {
+            let mut end = self.max_bytes;
+            while !frames.is_char_boundary(end) {
+                end -= 1;
+            }
+            frames.truncate(end);
+        }
+
+        Some(ViolationBacktrace {
+            overrun: captured.overrun,
+            frames,
+            truncated,
+        })
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::violation_backtrace::{BacktraceSlot, DEFAULT_BACKTRACE_MAX_BYTES, MAX_BACKTRACE_FRAMES};
+    use core::time::Duration;
+
+    /// Return addresses of the current thread can be captured on this platform, see `sys::capture_stack`.
+    const STACK_CAPTURE_SUPPORTED: bool = cfg!(all(target_os = "linux", target_env = "gnu"));
+
+    #[test]
+    fn backtrace_slot_empty() {
+        let slot = BacktraceSlot::new(DEFAULT_BACKTRACE_MAX_BYTES);
+        assert!(slot.take().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn backtrace_slot_capture_and_take() {
+        let slot = BacktraceSlot::new(DEFAULT_BACKTRACE_MAX_BYTES);
+        assert!(slot.capture(Duration::from_millis(12)));
+
+        let backtrace = slot.take().unwrap();
+        assert_eq!(backtrace.overrun, Duration::from_millis(12));
+        assert!(backtrace.frames.len() <= DEFAULT_BACKTRACE_MAX_BYTES);
+        assert!(backtrace.frames.lines().count() <= MAX_BACKTRACE_FRAMES);
+        assert_eq!(!backtrace.frames.is_empty(), STACK_CAPTURE_SUPPORTED);
+        assert!(slot.take().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn backtrace_slot_keeps_first_until_taken() {
+        let slot = BacktraceSlot::new(DEFAULT_BACKTRACE_MAX_BYTES);
+        assert!(slot.capture(Duration::from_millis(1)));
+        assert!(!slot.capture(Duration::from_millis(2)));
+        assert_eq!(slot.take().unwrap().overrun, Duration::from_millis(1));
+
+        assert!(slot.capture(Duration::from_millis(3)));
+        assert_eq!(slot.take().unwrap().overrun, Duration::from_millis(3));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn backtrace_slot_limits_size() {
+        let slot = BacktraceSlot::new(16);
+        assert!(slot.capture(Duration::from_millis(1)));
+
+        let backtrace = slot.take().unwrap();
+        assert!(backtrace.frames.len() <= 16);
+        assert_eq!(backtrace.truncated, STACK_CAPTURE_SUPPORTED);
+    }
+}
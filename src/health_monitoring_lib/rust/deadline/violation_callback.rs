@@ -0,0 +1,141 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::deadline::DeadlineEvaluationError;
+use crate::log::error;
+use crate::tag::DeadlineTag;
+use crate::worker::MonitoringCallbackScope;
+use core::panic::AssertUnwindSafe;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use std::panic::catch_unwind;
+use std::sync::Mutex;
+
+/// Callback of a single deadline, invoked from the monitoring thread on its violations.
+pub(super) struct DeadlineCallback(Mutex<Box<dyn FnMut(DeadlineEvaluationError, Duration) + Send>>);
+
+impl DeadlineCallback {
+    pub(super) fn new(callback: impl FnMut(DeadlineEvaluationError, Duration) + Send + 'static) -> Self {
+        Self(Mutex::new(Box::new(callback)))
+    }
+
+    /// Invoke the callback with the `error` and the time outside of the range, containing its panics.
+    /// Returns `false` if the callback panicked.
+    pub(super) fn invoke(&self, deadline_tag: &DeadlineTag, error: DeadlineEvaluationError, offset: Duration) -> bool {
+        let Ok(mut callback) = self.0.lock() else {
+            error!(
+                "Callback of deadline {:?} is unusable after an earlier panic.",
+                deadline_tag
+            );
+            return true;
+        };
+
+        // Panic is caught while the lock is held, so the callback is not poisoned and is invoked again on next violation.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _scope = MonitoringCallbackScope::enter();
+            callback(error, offset)
+        }));
+        if result.is_err() {
+            error!("Callback of deadline {:?} panicked.", deadline_tag);
+            return false;
+        }
+        true
+    }
+}
+
+impl core::fmt::Debug for DeadlineCallback {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("DeadlineCallback")
+    }
+}
+
+/// No time outside of the range measured in the current activation.
+const NO_OFFSET: u32 = u32::MAX;
+
+/// Time outside of the range measured when the deadline was stopped, in milliseconds.
+///
+/// Single writer - the deadline owner, single reader - evaluation of the monitor.
+pub(super) struct OffsetSlot(AtomicU32);
+
+impl OffsetSlot {
+    pub(super) fn new() -> Self {
+        Self(AtomicU32::new(NO_OFFSET))
+    }
+
+    /// Forget offset of the previous activation.
+    pub(super) fn clear(&self) {
+        self.0.store(NO_OFFSET, Ordering::Relaxed);
+    }
+
+    /// Store offset of the stopped activation.
+    pub(super) fn store(&self, offset_ms: u32) {
+        self.0.store(offset_ms.min(NO_OFFSET - 1), Ordering::Relaxed);
+    }
+
+    /// Offset of the last activation, [`None`] if it was not stopped outside of the range.
+    pub(super) fn load(&self) -> Option<u32> {
+        Some(self.0.load(Ordering::Relaxed)).filter(|offset_ms| *offset_ms != NO_OFFSET)
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::violation_callback::{DeadlineCallback, OffsetSlot};
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::tag::DeadlineTag;
+    use core::time::Duration;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn deadline_callback_contains_panics() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let calls_clone = calls.clone();
+        let callback = DeadlineCallback::new(move |error, offset| {
+            calls_clone.lock().unwrap().push((error, offset));
+            if error == DeadlineEvaluationError::TooEarly {
+                panic!("callback failed");
+            }
+        });
+        let deadline_tag = DeadlineTag::from("deadline");
+
+        assert!(!callback.invoke(
+            &deadline_tag,
+            DeadlineEvaluationError::TooEarly,
+            Duration::from_millis(1)
+        ));
+        assert!(callback.invoke(
+            &deadline_tag,
+            DeadlineEvaluationError::TooLate,
+            Duration::from_millis(2)
+        ));
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                (DeadlineEvaluationError::TooEarly, Duration::from_millis(1)),
+                (DeadlineEvaluationError::TooLate, Duration::from_millis(2))
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_slot_store_and_clear() {
+        let slot = OffsetSlot::new();
+        assert_eq!(slot.load(), None);
+        slot.store(0);
+        assert_eq!(slot.load(), Some(0));
+        slot.store(u32::MAX);
+        assert_eq!(slot.load(), Some(u32::MAX - 1));
+        slot.clear();
+        assert_eq!(slot.load(), None);
+    }
+}
@@ -0,0 +1,118 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::log::error;
+use crate::tag::DeadlineTag;
+use crate::worker::MonitoringCallbackScope;
+use core::panic::AssertUnwindSafe;
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use core::time::Duration;
+use std::panic::catch_unwind;
+use std::sync::Mutex;
+
+/// No near miss measured since the last evaluation, near misses are always longer than the warning threshold.
+const NO_NEAR_MISS: u32 = 0;
+
+/// Near misses of a deadline - activations stopped within the range, but later than its warning threshold.
+///
+/// Multiple writers - instances of the deadline, single reader - evaluation of the monitor.
+pub(super) struct WarningSlot {
+    warning_at_ms: u32,
+    near_misses: AtomicU64,
+    /// Longest near miss since the last evaluation, [`NO_NEAR_MISS`] if none.
+    longest_ms: AtomicU32,
+}
+
+impl WarningSlot {
+    pub(super) fn new(warning_at: Duration) -> Self {
+        Self {
+            warning_at_ms: u32::try_from(warning_at.as_millis()).unwrap_or(u32::MAX),
+            near_misses: AtomicU64::new(0),
+            longest_ms: AtomicU32::new(NO_NEAR_MISS),
+        }
+    }
+
+    /// Record an activation stopped within the range after `elapsed_ms`.
+    /// Returns `true` if it was a near miss.
+    pub(super) fn record(&self, elapsed_ms: u32) -> bool {
+        if elapsed_ms <= self.warning_at_ms {
+            return false;
+        }
+        self.near_misses.fetch_add(1, Ordering::Relaxed);
+        self.longest_ms.fetch_max(elapsed_ms, Ordering::Relaxed);
+        true
+    }
+
+    /// Longest near miss since the previous call, [`None`] if there was none.
+    pub(super) fn take_longest(&self) -> Option<u32> {
+        Some(self.longest_ms.swap(NO_NEAR_MISS, Ordering::Relaxed)).filter(|longest_ms| *longest_ms != NO_NEAR_MISS)
+    }
+
+    /// Number of near misses so far.
+    pub(super) fn near_misses(&self) -> u64 {
+        self.near_misses.load(Ordering::Relaxed)
+    }
+}
+
+/// Callback of a monitor, invoked from the monitoring thread on near misses of its deadlines.
+pub(super) struct WarningCallback(Mutex<Box<dyn FnMut(DeadlineTag, Duration) + Send>>);
+
+impl WarningCallback {
+    pub(super) fn new(callback: impl FnMut(DeadlineTag, Duration) + Send + 'static) -> Self {
+        Self(Mutex::new(Box::new(callback)))
+    }
+
+    /// Invoke the callback with the `deadline_tag` and the longest near miss, containing its panics.
+    /// Returns `false` if the callback panicked.
+    pub(super) fn invoke(&self, deadline_tag: DeadlineTag, longest: Duration) -> bool {
+        let Ok(mut callback) = self.0.lock() else {
+            error!("Warning callback is unusable after an earlier panic.");
+            return true;
+        };
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _scope = MonitoringCallbackScope::enter();
+            callback(deadline_tag, longest)
+        }));
+        if result.is_err() {
+            error!("Warning callback panicked on deadline {:?}.", deadline_tag);
+            return false;
+        }
+        true
+    }
+}
+
+impl core::fmt::Debug for WarningCallback {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("WarningCallback")
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::warning::WarningSlot;
+    use core::time::Duration;
+
+    #[test]
+    fn warning_slot_records_near_misses() {
+        let slot = WarningSlot::new(Duration::from_millis(80));
+        assert!(!slot.record(80));
+        assert_eq!(slot.take_longest(), None);
+
+        assert!(slot.record(90));
+        assert!(slot.record(85));
+        assert_eq!(slot.take_longest(), Some(90));
+        assert_eq!(slot.take_longest(), None);
+        assert_eq!(slot.near_misses(), 2);
+    }
+}
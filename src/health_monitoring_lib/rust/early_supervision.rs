@@ -0,0 +1,355 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::{Monitor, MonitorEvalHandle, MonitorEvaluator};
+use crate::config_summary::ConfigDigest;
+use crate::deadline::{DeadlineMonitor, DeadlineMonitorBuilder};
+use crate::handoff_queue::HandoffQueue;
+use crate::heartbeat::{HeartbeatMonitor, HeartbeatMonitorBuilder};
+use crate::log::{error, info, warn};
+use crate::ordered_map::OrderedMap;
+use crate::protected_memory::ProtectedMemoryAllocator;
+use crate::tag::MonitorTag;
+use crate::violation_history::ViolationHistory;
+use crate::worker::StopToken;
+use crate::{HealthMonitorError, InternalFaultRecord, ResourceBudget, ViolationRecord};
+use core::time::Duration;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Configuration of a monitor created by [`EarlySupervision`], kept for the configuration summary.
+pub(crate) struct EarlyMonitorConfig {
+    pub(crate) deadlines: usize,
+    pub(crate) thread_attribution: bool,
+    pub(crate) digest: u64,
+}
+
+/// Supervision of monitors created during early initialization, before the [`crate::HealthMonitor`] is built.
+///
+/// Monitors are evaluated by a minimal ticker thread and violations are logged and stored in the history.
+/// Nothing is reported to the supervisor.
+/// Once added with [`crate::HealthMonitorBuilder::with_early_supervision`], monitors are handed over
+/// with their state to the health monitor when it is started.
+/// Violations reported so far are carried over into its history,
+/// their timestamps are relative to creation of the [`EarlySupervision`].
+pub struct EarlySupervision {
+    internal_processing_cycle: Duration,
+    deadline_monitors: OrderedMap<MonitorTag, (DeadlineMonitor, EarlyMonitorConfig)>,
+    heartbeat_monitors: OrderedMap<MonitorTag, (HeartbeatMonitor, EarlyMonitorConfig)>,
+    /// Monitors added since the previous pass, handed over to the ticker.
+    new_monitors: Arc<HandoffQueue<MonitorEvalHandle>>,
+    violation_history: Arc<ViolationHistory>,
+    stop_token: StopToken,
+    ticker: Option<std::thread::JoinHandle<()>>,
+}
+
+impl EarlySupervision {
+    /// Create a new [`EarlySupervision`] and start its ticker.
+    ///
+    /// - `internal_processing_cycle` - interval between evaluations,
+    ///   must match the internal processing cycle of the health monitor.
+    pub fn new(internal_processing_cycle: Duration) -> Self {
+        let resource_budget = ResourceBudget::new();
+        let new_monitors = Arc::new(HandoffQueue::new());
+        let violation_history = Arc::new(ViolationHistory::new(
+            resource_budget.violation_history_capacity(),
+            resource_budget.internal_fault_history_capacity(),
+        ));
+        let stop_token = StopToken::new();
+
+        let ticker = {
+            let new_monitors = new_monitors.clone();
+            let violation_history = violation_history.clone();
+            let stop_token = stop_token.clone();
+            std::thread::spawn(move || {
+                Self::run_ticker(
+                    internal_processing_cycle,
+                    &new_monitors,
+                    &violation_history,
+                    &stop_token,
+                )
+            })
+        };
+
+        info!(
+            "Early supervision started with cycle {} ms.",
+            internal_processing_cycle.as_millis() as u64
+        );
+        Self {
+            internal_processing_cycle,
+            deadline_monitors: OrderedMap::new(),
+            heartbeat_monitors: OrderedMap::new(),
+            new_monitors,
+            violation_history,
+            stop_token,
+            ticker: Some(ticker),
+        }
+    }
+
+    /// Build a [`DeadlineMonitor`] supervised from now on.
+    ///
+    /// - `monitor_tag` - unique tag for the [`DeadlineMonitor`].
+    /// - `monitor_builder` - monitor builder to finalize.
+    ///
+    /// Returns the added monitor, already taken by the caller.
+    pub fn add_deadline_monitor(
+        &mut self,
+        monitor_tag: MonitorTag,
+        monitor_builder: DeadlineMonitorBuilder,
+    ) -> Result<DeadlineMonitor, HealthMonitorError> {
+        self.check_monitor_tag(monitor_tag)?;
+        if monitor_builder.has_empty_instance_pool() {
+            error!(
+                "Deadlines of monitor {:?} must have at least one instance.",
+                monitor_tag
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+
+        let mut digest = ConfigDigest::new();
+        monitor_builder.write_digest(&mut digest);
+        let config = EarlyMonitorConfig {
+            deadlines: monitor_builder.num_deadlines(),
+            thread_attribution: monitor_builder.has_thread_attribution(),
+            digest: digest.finish(),
+        };
+
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = monitor_builder.build(monitor_tag, &allocator);
+        self.new_monitors.push(monitor.get_eval_handle());
+        self.deadline_monitors
+            .insert(monitor_tag, (monitor.clone_shared(), config));
+        info!("Monitor {:?} added to early supervision.", monitor_tag);
+        Ok(monitor)
+    }
+
+    /// Build a [`HeartbeatMonitor`] supervised from now on.
+    ///
+    /// - `monitor_tag` - unique tag for the [`HeartbeatMonitor`].
+    /// - `monitor_builder` - monitor builder to finalize.
+    ///
+    /// Returns the added monitor, already taken by the caller.
+    pub fn add_heartbeat_monitor(
+        &mut self,
+        monitor_tag: MonitorTag,
+        monitor_builder: HeartbeatMonitorBuilder,
+    ) -> Result<HeartbeatMonitor, HealthMonitorError> {
+        self.check_monitor_tag(monitor_tag)?;
+
+        let mut digest = ConfigDigest::new();
+        monitor_builder.write_digest(&mut digest);
+        let config = EarlyMonitorConfig {
+            deadlines: 0,
+            thread_attribution: monitor_builder.has_thread_attribution(),
+            digest: digest.finish(),
+        };
+
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = monitor_builder.build(monitor_tag, self.internal_processing_cycle, &allocator)?;
+        self.new_monitors.push(monitor.get_eval_handle());
+        self.heartbeat_monitors
+            .insert(monitor_tag, (monitor.clone_shared(), config));
+        info!("Monitor {:?} added to early supervision.", monitor_tag);
+        Ok(monitor)
+    }
+
+    /// Violations reported during early supervision, oldest first.
+    pub fn violation_history(&self) -> Vec<ViolationRecord> {
+        self.violation_history.records()
+    }
+
+    pub(crate) fn internal_processing_cycle(&self) -> Duration {
+        self.internal_processing_cycle
+    }
+
+    pub(crate) fn num_monitors(&self) -> usize {
+        self.deadline_monitors.len() + self.heartbeat_monitors.len()
+    }
+
+    pub(crate) fn monitor_tags(&self) -> impl Iterator<Item = &MonitorTag> {
+        self.deadline_monitors.keys().chain(self.heartbeat_monitors.keys())
+    }
+
+    pub(crate) fn contains_monitor(&self, monitor_tag: &MonitorTag) -> bool {
+        self.deadline_monitors.contains_key(monitor_tag) || self.heartbeat_monitors.contains_key(monitor_tag)
+    }
+
+    pub(crate) fn deadline_monitors(
+        &self,
+    ) -> impl Iterator<Item = (&MonitorTag, &(DeadlineMonitor, EarlyMonitorConfig))> {
+        self.deadline_monitors.iter()
+    }
+
+    pub(crate) fn heartbeat_monitors(
+        &self,
+    ) -> impl Iterator<Item = (&MonitorTag, &(HeartbeatMonitor, EarlyMonitorConfig))> {
+        self.heartbeat_monitors.iter()
+    }
+
+    /// Stop the ticker and carry reported violations and internal faults over into `violation_history`.
+    /// Monitors keep their state, evaluation is continued by the health monitor.
+    pub(crate) fn hand_over(mut self, violation_history: &ViolationHistory) {
+        self.stop_ticker();
+        for record in self.violation_history.records() {
+            violation_history.push(record);
+        }
+        for record in self.violation_history.internal_faults() {
+            violation_history.push_internal_fault(record);
+        }
+        info!("Early supervision handed over {} monitors.", self.num_monitors());
+    }
+
+    fn check_monitor_tag(&self, monitor_tag: MonitorTag) -> Result<(), HealthMonitorError> {
+        if self.contains_monitor(&monitor_tag) {
+            error!("Monitor {:?} already exists.", monitor_tag);
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    fn stop_ticker(&mut self) {
+        self.stop_token.stop();
+        if let Some(ticker) = self.ticker.take() {
+            if ticker.join().is_err() {
+                error!("Early supervision ticker panicked.");
+            }
+        }
+    }
+
+    fn run_ticker(
+        interval: Duration,
+        new_monitors: &HandoffQueue<MonitorEvalHandle>,
+        violation_history: &ViolationHistory,
+        stop_token: &StopToken,
+    ) {
+        let starting_point = Instant::now();
+        let mut monitors = Vec::new();
+        while !stop_token.is_stopped() {
+            std::thread::sleep(interval);
+            // Monitors are created after the ticker is started, so each monitor is evaluated
+            // from the point it was taken by the ticker.
+            let now = Instant::now();
+            monitors.extend(new_monitors.take_all().into_iter().map(|monitor| (monitor, now)));
+
+            for (monitor, monitor_starting_point) in monitors.iter() {
+                monitor.evaluate(*monitor_starting_point, &mut |monitor_tag, error, context| {
+                    warn!(
+                        "Monitor with tag {:?} reported error during early supervision: {:?}.",
+                        monitor_tag, error
+                    );
+                    violation_history.push(ViolationRecord {
+                        monitor_tag: *monitor_tag,
+                        error,
+                        timestamp: starting_point.elapsed(),
+                        reporter: context.reporter,
+                        correlation_id: context.correlation_id,
+                        scheduling: context.scheduling,
+                        deadline_tag: context.deadline_tag,
+                        source_tag: context.source_tag,
+                        offset: context.offset,
+                    });
+                });
+                monitor.collect_internal_faults(&mut |monitor_tag, fault| {
+                    error!(
+                        "Internal fault {:?} of monitor {:?} during early supervision.",
+                        fault, monitor_tag
+                    );
+                    violation_history.push_internal_fault(InternalFaultRecord {
+                        monitor_tag: Some(*monitor_tag),
+                        fault,
+                        timestamp: starting_point.elapsed(),
+                    });
+                });
+            }
+        }
+    }
+}
+
+impl Drop for EarlySupervision {
+    fn drop(&mut self) {
+        self.stop_ticker();
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::TimeRange;
+    use crate::deadline::{DeadlineEvaluationError, DeadlineMonitorBuilder};
+    use crate::early_supervision::EarlySupervision;
+    use crate::heartbeat::{HeartbeatEvaluationError, HeartbeatMonitorBuilder};
+    use crate::tag::{DeadlineTag, MonitorTag};
+    use crate::{HealthMonitorError, MonitorEvaluationError};
+    use core::time::Duration;
+
+    #[test]
+    fn early_supervision_reports_violations() {
+        let mut early_supervision = EarlySupervision::new(Duration::from_millis(10));
+        let deadline_monitor = early_supervision
+            .add_deadline_monitor(
+                MonitorTag::from("deadline_monitor"),
+                DeadlineMonitorBuilder::new().add_deadline(
+                    DeadlineTag::from("deadline"),
+                    TimeRange::new(Duration::ZERO, Duration::from_millis(20)),
+                ),
+            )
+            .unwrap();
+        let _heartbeat_monitor = early_supervision
+            .add_heartbeat_monitor(
+                MonitorTag::from("heartbeat_monitor"),
+                HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_millis(10), Duration::from_millis(30))),
+            )
+            .unwrap();
+
+        let mut deadline = deadline_monitor.get_deadline(DeadlineTag::from("deadline")).unwrap();
+        let _handle = deadline.start().unwrap();
+        std::thread::sleep(Duration::from_millis(150));
+
+        let history = early_supervision.violation_history();
+        assert!(history
+            .iter()
+            .any(|record| record.monitor_tag == MonitorTag::from("deadline_monitor")
+                && record.error == MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooLate)));
+        assert!(history
+            .iter()
+            .any(|record| record.monitor_tag == MonitorTag::from("heartbeat_monitor")
+                && record.error == MonitorEvaluationError::Heartbeat(HeartbeatEvaluationError::TooLate)));
+    }
+
+    #[test]
+    fn early_supervision_duplicate_monitor_tag() {
+        let mut early_supervision = EarlySupervision::new(Duration::from_millis(10));
+        let monitor_tag = MonitorTag::from("monitor");
+        let _monitor = early_supervision
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .unwrap();
+
+        let result = early_supervision.add_heartbeat_monitor(
+            monitor_tag,
+            HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_millis(10), Duration::from_millis(30))),
+        );
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn early_supervision_unbounded_deadline() {
+        let mut early_supervision = EarlySupervision::new(Duration::from_millis(10));
+        let result = early_supervision.add_deadline_monitor(
+            MonitorTag::from("monitor"),
+            DeadlineMonitorBuilder::new().add_deadline(
+                DeadlineTag::from("deadline"),
+                TimeRange::at_least(Duration::from_millis(100)),
+            ),
+        );
+        assert!(result.is_ok());
+    }
+}
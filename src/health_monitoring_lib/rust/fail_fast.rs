@@ -0,0 +1,139 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::MonitorEvaluationError;
+use crate::config_summary::ConfigDigest;
+use crate::tag::MonitorTag;
+
+/// Policy terminating the process on selected monitor failures, for deployments restarting failed processes.
+///
+/// Applies to violations not tolerated by [`crate::FailureTolerance`].
+/// Violation history is flushed to the log and the supervisor is notified the process is terminating
+/// before the process exits with the configured exit code.
+/// Launch manager daemon fails the supervision of the process on the termination checkpoint (ID 2),
+/// which therefore must not be configured for the process.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailFastPolicy {
+    exit_code: i32,
+    monitors: Vec<MonitorTag>,
+    errors: Vec<MonitorEvaluationError>,
+}
+
+impl FailFastPolicy {
+    /// Create a policy applying to failures of all monitors.
+    ///
+    /// - `exit_code` - exit code of the terminated process, must not be zero.
+    pub fn new(exit_code: i32) -> Self {
+        Self {
+            exit_code,
+            monitors: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Apply the policy only to failures of `monitor_tags`, monitors already added are skipped.
+    /// Groups are selected with their monitor tag.
+    pub fn with_monitors(mut self, monitor_tags: &[MonitorTag]) -> Self {
+        for monitor_tag in monitor_tags {
+            if !self.monitors.contains(monitor_tag) {
+                self.monitors.push(*monitor_tag);
+            }
+        }
+        self
+    }
+
+    /// Apply the policy only to failures with `errors`, errors already added are skipped.
+    pub fn with_errors(mut self, errors: &[MonitorEvaluationError]) -> Self {
+        for error in errors {
+            if !self.errors.contains(error) {
+                self.errors.push(*error);
+            }
+        }
+        self
+    }
+
+    pub(crate) fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    pub(crate) fn monitors(&self) -> &[MonitorTag] {
+        &self.monitors
+    }
+
+    /// Policy applies to failure of the monitor with `error`.
+    pub(crate) fn applies_to(&self, monitor_tag: &MonitorTag, error: MonitorEvaluationError) -> bool {
+        (self.monitors.is_empty() || self.monitors.contains(monitor_tag))
+            && (self.errors.is_empty() || self.errors.contains(&error))
+    }
+
+    /// Write the policy into the `digest`, monitors and errors in a stable order.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_u64(u64::from(self.exit_code as u32));
+        let mut monitors: Vec<_> = self.monitors.iter().map(MonitorTag::as_str).collect();
+        monitors.sort_unstable();
+        digest.write_u64(monitors.len() as u64);
+        for monitor_tag in monitors {
+            digest.write_str(monitor_tag);
+        }
+        let mut errors: Vec<_> = self.errors.iter().map(|error| format!("{error:?}")).collect();
+        errors.sort_unstable();
+        digest.write_u64(errors.len() as u64);
+        for error in errors {
+            digest.write_str(&error);
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::config_summary::ConfigDigest;
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::fail_fast::FailFastPolicy;
+    use crate::heartbeat::HeartbeatEvaluationError;
+    use crate::tag::MonitorTag;
+
+    #[test]
+    fn fail_fast_policy_applies_to_all_by_default() {
+        let policy = FailFastPolicy::new(3);
+        assert!(policy.applies_to(&MonitorTag::from("monitor"), DeadlineEvaluationError::TooLate.into()));
+        assert!(policy.applies_to(&MonitorTag::from("other"), HeartbeatEvaluationError::TooEarly.into()));
+    }
+
+    #[test]
+    fn fail_fast_policy_applies_to_selected() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let policy = FailFastPolicy::new(3)
+            .with_monitors(&[monitor_tag, monitor_tag])
+            .with_errors(&[DeadlineEvaluationError::TooLate.into()]);
+        assert_eq!(policy.monitors(), &[monitor_tag]);
+        assert!(policy.applies_to(&monitor_tag, DeadlineEvaluationError::TooLate.into()));
+        assert!(!policy.applies_to(&monitor_tag, DeadlineEvaluationError::TooEarly.into()));
+        assert!(!policy.applies_to(&MonitorTag::from("other"), DeadlineEvaluationError::TooLate.into()));
+    }
+
+    #[test]
+    fn fail_fast_policy_digest_order_independent() {
+        let digest = |policy: FailFastPolicy| {
+            let mut digest = ConfigDigest::new();
+            policy.write_digest(&mut digest);
+            digest.finish()
+        };
+        let monitor1 = MonitorTag::from("monitor1");
+        let monitor2 = MonitorTag::from("monitor2");
+        assert_eq!(
+            digest(FailFastPolicy::new(3).with_monitors(&[monitor1, monitor2])),
+            digest(FailFastPolicy::new(3).with_monitors(&[monitor2, monitor1]))
+        );
+        assert_ne!(digest(FailFastPolicy::new(3)), digest(FailFastPolicy::new(4)));
+    }
+}
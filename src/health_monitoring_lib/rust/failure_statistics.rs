@@ -0,0 +1,245 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::artifact::ArtifactEvaluationError;
+use crate::common::MonitorEvaluationError;
+use crate::deadline::DeadlineEvaluationError;
+use crate::heartbeat::HeartbeatEvaluationError;
+use crate::tag::MonitorTag;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Mutex, TryLockError};
+
+/// Failure counters of a single monitor, accumulated since the health monitor was built.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MonitorStatistics {
+    /// Deadlines or heartbeats finished too early.
+    pub too_early: u64,
+    /// Deadlines or heartbeats finished too late.
+    pub too_late: u64,
+    /// Multiple heartbeats observed within a single cycle.
+    pub multiple_heartbeats: u64,
+    /// Invalid state transitions of a logic monitor.
+    pub invalid_transitions: u64,
+    /// Artifacts not updated within their maximum age.
+    pub stale_artifacts: u64,
+    /// Artifacts which could not be read for longer than their maximum age.
+    pub unavailable_artifacts: u64,
+    /// Windows in which an execution budget was exhausted.
+    pub exhausted_budgets: u64,
+    /// Periods in which no deadline of a monitor was started for longer than its maximum idle time.
+    pub idle_periods: u64,
+    /// Longest time outside of the range of a deadline or heartbeat, [`Duration::ZERO`] if none.
+    pub max_offset: Duration,
+    /// Time of the most recent failure, relative to the start of the health monitor.
+    /// [`None`] if the monitor never failed.
+    pub last_failure: Option<Duration>,
+}
+
+impl MonitorStatistics {
+    /// Total number of failures.
+    pub fn total(&self) -> u64 {
+        self.too_early
+            + self.too_late
+            + self.multiple_heartbeats
+            + self.invalid_transitions
+            + self.stale_artifacts
+            + self.unavailable_artifacts
+            + self.exhausted_budgets
+            + self.idle_periods
+    }
+
+    fn record(&mut self, error: MonitorEvaluationError, timestamp: Duration, offset: Option<Duration>) {
+        let counter = match error {
+            MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooEarly)
+            | MonitorEvaluationError::Heartbeat(HeartbeatEvaluationError::TooEarly) => &mut self.too_early,
+            MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooLate)
+            | MonitorEvaluationError::Heartbeat(HeartbeatEvaluationError::TooLate) => &mut self.too_late,
+            MonitorEvaluationError::Deadline(DeadlineEvaluationError::BudgetExhausted) => &mut self.exhausted_budgets,
+            MonitorEvaluationError::Deadline(DeadlineEvaluationError::Idle) => &mut self.idle_periods,
+            MonitorEvaluationError::Heartbeat(HeartbeatEvaluationError::MultipleHeartbeats) => {
+                &mut self.multiple_heartbeats
+            },
+            MonitorEvaluationError::Artifact(ArtifactEvaluationError::Stale) => &mut self.stale_artifacts,
+            MonitorEvaluationError::Artifact(ArtifactEvaluationError::Unavailable) => &mut self.unavailable_artifacts,
+            MonitorEvaluationError::Logic => &mut self.invalid_transitions,
+        };
+        *counter = counter.saturating_add(1);
+        self.last_failure = Some(timestamp);
+        if let Some(offset) = offset {
+            self.max_offset = self.max_offset.max(offset);
+        }
+    }
+}
+
+/// Per-monitor failure counters, shared between the monitoring logic and the health monitor.
+/// Kept across restarts of the monitoring logic.
+pub(crate) struct FailureStatistics {
+    monitors: Mutex<HashMap<MonitorTag, MonitorStatistics>>,
+}
+
+impl FailureStatistics {
+    /// Create counters with storage reserved for `capacity` monitors,
+    /// so failures are counted without allocating on the monitoring thread.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            monitors: Mutex::new(HashMap::with_capacity(capacity)),
+        }
+    }
+
+    /// Count failure of the monitor reported at `timestamp`.
+    pub(crate) fn record(
+        &self,
+        monitor_tag: MonitorTag,
+        error: MonitorEvaluationError,
+        timestamp: Duration,
+        offset: Option<Duration>,
+    ) {
+        self.monitors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(monitor_tag)
+            .or_default()
+            .record(error, timestamp, offset);
+    }
+
+    /// Counters of the monitor, all zero if the monitor never failed.
+    pub(crate) fn get(&self, monitor_tag: MonitorTag) -> MonitorStatistics {
+        self.monitors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&monitor_tag)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Counters of all monitors which failed at least once, ordered by monitor tag.
+    pub(crate) fn all(&self) -> Vec<(MonitorTag, MonitorStatistics)> {
+        let mut all: Vec<_> = self
+            .monitors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(monitor_tag, statistics)| (*monitor_tag, *statistics))
+            .collect();
+        all.sort_unstable_by(|(tag1, _), (tag2, _)| tag1.as_str().cmp(tag2.as_str()));
+        all
+    }
+
+    /// Total failures of all monitors which failed at least once, unordered.
+    /// Returns [`None`] instead of blocking if the counters are being updated, e.g. from a panic hook.
+    pub(crate) fn try_totals(&self) -> Option<Vec<(MonitorTag, u64)>> {
+        let monitors = match self.monitors.try_lock() {
+            Ok(monitors) => monitors,
+            Err(TryLockError::Poisoned(monitors)) => monitors.into_inner(),
+            Err(TryLockError::WouldBlock) => return None,
+        };
+        Some(
+            monitors
+                .iter()
+                .map(|(monitor_tag, statistics)| (*monitor_tag, statistics.total()))
+                .collect(),
+        )
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::MonitorEvaluationError;
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::failure_statistics::{FailureStatistics, MonitorStatistics};
+    use crate::heartbeat::HeartbeatEvaluationError;
+    use crate::tag::MonitorTag;
+    use core::time::Duration;
+
+    #[test]
+    fn failure_statistics_counts_per_monitor() {
+        let statistics = FailureStatistics::new(1);
+        let deadline_tag = MonitorTag::from("deadline");
+        let heartbeat_tag = MonitorTag::from("heartbeat");
+
+        statistics.record(
+            deadline_tag,
+            DeadlineEvaluationError::TooLate.into(),
+            Duration::from_millis(10),
+            None,
+        );
+        statistics.record(
+            deadline_tag,
+            DeadlineEvaluationError::TooEarly.into(),
+            Duration::from_millis(20),
+            None,
+        );
+        statistics.record(
+            heartbeat_tag,
+            HeartbeatEvaluationError::MultipleHeartbeats.into(),
+            Duration::from_millis(30),
+            None,
+        );
+        statistics.record(
+            heartbeat_tag,
+            MonitorEvaluationError::Logic,
+            Duration::from_millis(40),
+            None,
+        );
+
+        let deadline = statistics.get(deadline_tag);
+        assert_eq!(deadline.too_late, 1);
+        assert_eq!(deadline.too_early, 1);
+        assert_eq!(deadline.total(), 2);
+        assert_eq!(deadline.last_failure, Some(Duration::from_millis(20)));
+
+        let heartbeat = statistics.get(heartbeat_tag);
+        assert_eq!(heartbeat.multiple_heartbeats, 1);
+        assert_eq!(heartbeat.invalid_transitions, 1);
+        assert_eq!(heartbeat.last_failure, Some(Duration::from_millis(40)));
+
+        let all: Vec<_> = statistics.all().iter().map(|(tag, _)| *tag).collect();
+        assert_eq!(all, vec![deadline_tag, heartbeat_tag]);
+    }
+
+    #[test]
+    fn failure_statistics_keeps_max_offset() {
+        let statistics = FailureStatistics::new(1);
+        let monitor_tag = MonitorTag::from("deadline");
+
+        for (offset_ms, timestamp_ms) in [(30, 10), (70, 20), (50, 30)] {
+            statistics.record(
+                monitor_tag,
+                DeadlineEvaluationError::TooLate.into(),
+                Duration::from_millis(timestamp_ms),
+                Some(Duration::from_millis(offset_ms)),
+            );
+        }
+        statistics.record(
+            monitor_tag,
+            MonitorEvaluationError::Logic,
+            Duration::from_millis(40),
+            None,
+        );
+
+        let monitor = statistics.get(monitor_tag);
+        assert_eq!(monitor.max_offset, Duration::from_millis(70));
+        assert_eq!(monitor.last_failure, Some(Duration::from_millis(40)));
+    }
+
+    #[test]
+    fn failure_statistics_unknown_monitor_is_zero() {
+        let statistics = FailureStatistics::new(1);
+        assert_eq!(
+            statistics.get(MonitorTag::from("unknown")),
+            MonitorStatistics::default()
+        );
+        assert!(statistics.all().is_empty());
+    }
+}
@@ -10,11 +10,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
+use crate::build_info::{enabled_features, LIBRARY_VERSION};
 use crate::deadline::ffi::DeadlineMonitorCpp;
 use crate::deadline::DeadlineMonitorBuilder;
 use crate::heartbeat::HeartbeatMonitorBuilder;
 use crate::tag::MonitorTag;
-use crate::{HealthMonitor, HealthMonitorBuilder, HealthMonitorError};
+use crate::{HealthMonitor, HealthMonitorBuilder, HealthMonitorError, MonitorStatistics};
 use core::mem::ManuallyDrop;
 use core::ops::{Deref, DerefMut};
 use core::time::Duration;
@@ -47,6 +48,45 @@ impl From<HealthMonitorError> for FFICode {
     }
 }
 
+/// Failure counters of a single monitor.
+/// Must be aligned with `score::hm::internal::FFIMonitorStatistics`.
+#[repr(C)]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FFIMonitorStatistics {
+    pub too_early: u64,
+    pub too_late: u64,
+    pub multiple_heartbeats: u64,
+    pub invalid_transitions: u64,
+    pub stale_artifacts: u64,
+    pub unavailable_artifacts: u64,
+    pub exhausted_budgets: u64,
+    pub idle_periods: u64,
+    pub max_offset_ms: u64,
+    /// Monitor failed at least once, `last_failure_ms` is valid.
+    pub has_failed: bool,
+    pub last_failure_ms: u64,
+}
+
+impl From<MonitorStatistics> for FFIMonitorStatistics {
+    fn from(value: MonitorStatistics) -> Self {
+        Self {
+            too_early: value.too_early,
+            too_late: value.too_late,
+            multiple_heartbeats: value.multiple_heartbeats,
+            invalid_transitions: value.invalid_transitions,
+            stale_artifacts: value.stale_artifacts,
+            unavailable_artifacts: value.unavailable_artifacts,
+            exhausted_budgets: value.exhausted_budgets,
+            idle_periods: value.idle_periods,
+            max_offset_ms: value.max_offset.as_millis() as u64,
+            has_failed: value.last_failure.is_some(),
+            last_failure_ms: value
+                .last_failure
+                .map_or(0, |last_failure| last_failure.as_millis() as u64),
+        }
+    }
+}
+
 /// A wrapper to represent borrowed data over FFI boundary without taking ownership.
 pub struct FFIBorrowed<T> {
     data: ManuallyDrop<T>,
@@ -74,6 +114,115 @@ impl<T: DerefMut> DerefMut for FFIBorrowed<T> {
     }
 }
 
+/// Generates an `extern "C"` function destroying an object behind an FFI handle.
+///
+/// Handle is checked against null and the object is dropped.
+macro_rules! ffi_destroy {
+    ($(#[$meta:meta])* fn $fn_name:ident($object_type:ty)) => {
+        $(#[$meta])*
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $fn_name(handle: $crate::ffi::FFIHandle) -> $crate::ffi::FFICode {
+            if handle.is_null() {
+                return $crate::ffi::FFICode::NullParameter;
+            }
+
+            // SAFETY:
+            // Validity of the pointer is ensured.
+            // It is assumed that the pointer was created by the FFI function returning a handle to the object type.
+            // It is assumed that the pointer was not consumed by other call.
+            unsafe {
+                let _ = Box::from_raw(handle as *mut $object_type);
+            }
+
+            $crate::ffi::FFICode::Success
+        }
+    };
+}
+pub(crate) use ffi_destroy;
+
+/// Generates an `extern "C"` function calling into an object behind an FFI handle.
+///
+/// Handle is checked against null and the object is borrowed for the duration of the body.
+/// Remaining arguments are passed over the boundary as-is.
+///
+/// Two forms are supported:
+/// - `fn name(object: &T, args...) { ... }` - body evaluates to `FFICode`.
+/// - `fn name(object: &T, args...) -> out: O { ... }` - body evaluates to `Result<O, FFICode>`,
+///   out-parameter is appended as a last argument, checked against null and written on success.
+///
+/// Use `&mut T` to borrow the object mutably.
+macro_rules! ffi_method {
+    // Entry points - normalize mutability.
+    ($(#[$meta:meta])* fn $fn_name:ident($object:ident: &mut $object_type:ty $(, $arg:ident: $arg_type:ty)* $(,)?)
+        $(-> $out:ident: $out_type:ty)? $body:block) => {
+        $crate::ffi::ffi_method!(@impl [$(#[$meta])*] $fn_name [mut] $object $object_type [$($arg: $arg_type),*]
+            [$($out: $out_type)?] $body);
+    };
+    ($(#[$meta:meta])* fn $fn_name:ident($object:ident: &$object_type:ty $(, $arg:ident: $arg_type:ty)* $(,)?)
+        $(-> $out:ident: $out_type:ty)? $body:block) => {
+        $crate::ffi::ffi_method!(@impl [$(#[$meta])*] $fn_name [] $object $object_type [$($arg: $arg_type),*]
+            [$($out: $out_type)?] $body);
+    };
+
+    // Body evaluates to `FFICode`.
+    (@impl [$($meta:tt)*] $fn_name:ident [$($mut:tt)?] $object:ident $object_type:ty [$($arg:ident: $arg_type:ty),*]
+        [] $body:block) => {
+        $($meta)*
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $fn_name(handle: $crate::ffi::FFIHandle $(, $arg: $arg_type)*) -> $crate::ffi::FFICode {
+            if handle.is_null() {
+                return $crate::ffi::FFICode::NullParameter;
+            }
+
+            // SAFETY:
+            // Validity of the pointer is ensured.
+            // It is assumed that the pointer was created by the FFI function returning a handle to the object type.
+            // It is assumed that the pointer was not consumed by a call to the matching destroy function.
+            #[allow(unused_mut)]
+            let $($mut)? $object =
+                $crate::ffi::FFIBorrowed::new(unsafe { Box::from_raw(handle as *mut $object_type) });
+
+            $body
+        }
+    };
+
+    // Body evaluates to `Result<$out_type, FFICode>`.
+    (@impl [$($meta:tt)*] $fn_name:ident [$($mut:tt)?] $object:ident $object_type:ty [$($arg:ident: $arg_type:ty),*]
+        [$out:ident: $out_type:ty] $body:block) => {
+        $($meta)*
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $fn_name(
+            handle: $crate::ffi::FFIHandle,
+            $($arg: $arg_type,)*
+            $out: *mut $out_type,
+        ) -> $crate::ffi::FFICode {
+            if handle.is_null() || $out.is_null() {
+                return $crate::ffi::FFICode::NullParameter;
+            }
+
+            // SAFETY:
+            // Validity of the pointer is ensured.
+            // It is assumed that the pointer was created by the FFI function returning a handle to the object type.
+            // It is assumed that the pointer was not consumed by a call to the matching destroy function.
+            #[allow(unused_mut)]
+            let $($mut)? $object =
+                $crate::ffi::FFIBorrowed::new(unsafe { Box::from_raw(handle as *mut $object_type) });
+
+            let result: Result<$out_type, $crate::ffi::FFICode> = $body;
+            match result {
+                Ok(value) => {
+                    unsafe {
+                        *$out = value;
+                    }
+                    $crate::ffi::FFICode::Success
+                },
+                Err(e) => e,
+            }
+        }
+    };
+}
+pub(crate) use ffi_method;
+
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_builder_create(health_monitor_builder_handle_out: *mut FFIHandle) -> FFICode {
     if health_monitor_builder_handle_out.is_null() {
@@ -88,22 +237,10 @@ pub extern "C" fn health_monitor_builder_create(health_monitor_builder_handle_ou
     FFICode::Success
 }
 
-#[unsafe(no_mangle)]
-pub extern "C" fn health_monitor_builder_destroy(health_monitor_builder_handle: FFIHandle) -> FFICode {
-    if health_monitor_builder_handle.is_null() {
-        return FFICode::NullParameter;
-    }
-
-    // SAFETY:
-    // Validity of the pointer is ensured.
-    // It is assumed that the pointer was created by a call to `health_monitor_builder_create`.
-    // It is assumed that the pointer was not consumed by a call to `health_monitor_builder_build`.
-    unsafe {
-        let _ = Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder);
-    }
-
-    FFICode::Success
-}
+ffi_destroy!(
+    /// Destroys a builder created by `health_monitor_builder_create` and not consumed by `health_monitor_builder_build`.
+    fn health_monitor_builder_destroy(HealthMonitorBuilder)
+);
 
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_builder_build(
@@ -123,8 +260,8 @@ pub extern "C" fn health_monitor_builder_build(
     let mut health_monitor_builder =
         unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) };
 
-    health_monitor_builder.with_internal_processing_cycle_internal(Duration::from_millis(internal_cycle_ms as u64));
-    health_monitor_builder.with_supervisor_api_cycle_internal(Duration::from_millis(supervisor_cycle_ms as u64));
+    health_monitor_builder.config.internal_processing_cycle = Duration::from_millis(internal_cycle_ms as u64);
+    health_monitor_builder.config.supervisor_api_cycle = Duration::from_millis(supervisor_cycle_ms as u64);
 
     // Build instance.
     match health_monitor_builder.build() {
@@ -167,7 +304,10 @@ pub extern "C" fn health_monitor_builder_add_deadline_monitor(
     let mut health_monitor_builder =
         FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) });
 
-    health_monitor_builder.add_deadline_monitor_internal(monitor_tag, *deadline_monitor_builder);
+    health_monitor_builder
+        .config
+        .deadline_monitor_builders
+        .insert(monitor_tag, *deadline_monitor_builder);
 
     FFICode::Success
 }
@@ -201,7 +341,10 @@ pub extern "C" fn health_monitor_builder_add_heartbeat_monitor(
     let mut health_monitor_builder =
         FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) });
 
-    health_monitor_builder.add_heartbeat_monitor_internal(monitor_tag, *heartbeat_monitor_builder);
+    health_monitor_builder
+        .config
+        .heartbeat_monitor_builders
+        .insert(monitor_tag, *heartbeat_monitor_builder);
 
     FFICode::Success
 }
@@ -269,40 +412,186 @@ pub extern "C" fn health_monitor_get_heartbeat_monitor(
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn health_monitor_start(health_monitor_handle: FFIHandle) -> FFICode {
-    if health_monitor_handle.is_null() {
+pub extern "C" fn health_monitor_set_monitor_enabled(
+    health_monitor_handle: FFIHandle,
+    monitor_tag: *const MonitorTag,
+    enabled: bool,
+) -> FFICode {
+    if health_monitor_handle.is_null() || monitor_tag.is_null() {
         return FFICode::NullParameter;
     }
 
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // `MonitorTag` type must be compatible between C++ and Rust.
+    let monitor_tag = unsafe { *monitor_tag };
+
     // SAFETY:
     // Validity of the pointer is ensured.
     // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
     // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
-    let mut health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+    let health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
 
-    // Start monitoring logic.
-    match health_monitor.start() {
+    let result = if enabled {
+        health_monitor.enable_monitor(monitor_tag)
+    } else {
+        health_monitor.disable_monitor(monitor_tag)
+    };
+    match result {
         Ok(_) => FFICode::Success,
         Err(error) => error.into(),
     }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn health_monitor_destroy(health_monitor_handle: FFIHandle) -> FFICode {
-    if health_monitor_handle.is_null() {
+pub extern "C" fn health_monitor_get_monitor_statistics(
+    health_monitor_handle: FFIHandle,
+    monitor_tag: *const MonitorTag,
+    statistics_out: *mut FFIMonitorStatistics,
+) -> FFICode {
+    if health_monitor_handle.is_null() || monitor_tag.is_null() || statistics_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // `MonitorTag` type must be compatible between C++ and Rust.
+    let monitor_tag = unsafe { *monitor_tag };
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    match health_monitor.monitor_statistics(monitor_tag) {
+        Ok(statistics) => {
+            // SAFETY:
+            // Validity of the pointer is ensured.
+            // `FFIMonitorStatistics` type must be compatible between C++ and Rust.
+            unsafe {
+                *statistics_out = statistics.into();
+            }
+            FFICode::Success
+        },
+        Err(error) => error.into(),
+    }
+}
+
+/// Writes the snapshot of the health monitor as JSON into `buffer`, without terminating null character.
+/// Size of the snapshot is always written into `snapshot_size_out`.
+/// Returns [`FFICode::InvalidArgument`] and leaves `buffer` untouched if `buffer_size` is too small,
+/// `buffer` can be null to query the size only.
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_get_snapshot(
+    health_monitor_handle: FFIHandle,
+    buffer: *mut u8,
+    buffer_size: usize,
+    snapshot_size_out: *mut usize,
+) -> FFICode {
+    if health_monitor_handle.is_null() || snapshot_size_out.is_null() {
         return FFICode::NullParameter;
     }
 
     // SAFETY:
     // Validity of the pointer is ensured.
     // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    let snapshot = health_monitor.snapshot().to_json();
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    unsafe {
+        *snapshot_size_out = snapshot.len();
+    }
+    if buffer.is_null() || buffer_size < snapshot.len() {
+        return FFICode::InvalidArgument;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that `buffer` points to at least `buffer_size` writable bytes.
+    unsafe {
+        core::ptr::copy_nonoverlapping(snapshot.as_ptr(), buffer, snapshot.len());
+    }
+    FFICode::Success
+}
+
+/// Returns the version of the library as a null-terminated string with static lifetime.
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_library_version() -> *const core::ffi::c_char {
+    LIBRARY_VERSION.as_ptr().cast()
+}
+
+/// Writes optional features the library was built with into `buffer`, separated by commas,
+/// without terminating null character. Size of the feature list is always written into `features_size_out`.
+/// Returns [`FFICode::InvalidArgument`] and leaves `buffer` untouched if `buffer_size` is too small,
+/// `buffer` can be null to query the size only.
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_enabled_features(
+    buffer: *mut u8,
+    buffer_size: usize,
+    features_size_out: *mut usize,
+) -> FFICode {
+    if features_size_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    let features = enabled_features().join(",");
+    // SAFETY:
+    // Validity of the pointer is ensured.
     unsafe {
-        let _ = Box::from_raw(health_monitor_handle as *mut HealthMonitor);
+        *features_size_out = features.len();
+    }
+    if features.is_empty() {
+        return FFICode::Success;
+    }
+    if buffer.is_null() || buffer_size < features.len() {
+        return FFICode::InvalidArgument;
     }
 
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that `buffer` points to at least `buffer_size` writable bytes.
+    unsafe {
+        core::ptr::copy_nonoverlapping(features.as_ptr(), buffer, features.len());
+    }
     FFICode::Success
 }
 
+ffi_method!(
+    fn health_monitor_start(health_monitor: &mut HealthMonitor) {
+        match health_monitor.start() {
+            Ok(_) => FFICode::Success,
+            Err(error) => error.into(),
+        }
+    }
+);
+
+ffi_method!(
+    fn health_monitor_stop(health_monitor: &mut HealthMonitor) {
+        match health_monitor.stop() {
+            Ok(_) => FFICode::Success,
+            Err(error) => error.into(),
+        }
+    }
+);
+
+ffi_method!(
+    fn health_monitor_restart(health_monitor: &mut HealthMonitor) {
+        match health_monitor.restart() {
+            Ok(_) => FFICode::Success,
+            Err(error) => error.into(),
+        }
+    }
+);
+
+ffi_destroy!(
+    /// Destroys a monitor created by `health_monitor_builder_build`.
+    fn health_monitor_destroy(HealthMonitor)
+);
+
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
@@ -312,13 +601,16 @@ mod tests {
     use crate::ffi::{
         health_monitor_builder_add_deadline_monitor, health_monitor_builder_add_heartbeat_monitor,
         health_monitor_builder_build, health_monitor_builder_create, health_monitor_builder_destroy,
-        health_monitor_destroy, health_monitor_get_deadline_monitor, health_monitor_get_heartbeat_monitor,
-        health_monitor_start, FFICode, FFIHandle,
+        health_monitor_destroy, health_monitor_enabled_features, health_monitor_get_deadline_monitor,
+        health_monitor_get_heartbeat_monitor, health_monitor_get_monitor_statistics, health_monitor_get_snapshot,
+        health_monitor_library_version, health_monitor_restart, health_monitor_set_monitor_enabled,
+        health_monitor_start, health_monitor_stop, FFICode, FFIHandle, FFIMonitorStatistics,
     };
     use crate::heartbeat::ffi::{
         heartbeat_monitor_builder_create, heartbeat_monitor_builder_destroy, heartbeat_monitor_destroy,
     };
     use crate::tag::MonitorTag;
+    use core::ffi::CStr;
     use core::ptr::null_mut;
 
     #[test]
@@ -1037,6 +1329,368 @@ mod tests {
         health_monitor_destroy(health_monitor_handle);
     }
 
+    #[test]
+    fn health_monitor_stop_restart_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_deadline_monitor(
+            health_monitor_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            &mut deadline_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_start(health_monitor_handle);
+
+        let health_monitor_restart_result = health_monitor_restart(health_monitor_handle);
+        assert_eq!(health_monitor_restart_result, FFICode::Success);
+        let health_monitor_stop_result = health_monitor_stop(health_monitor_handle);
+        assert_eq!(health_monitor_stop_result, FFICode::Success);
+
+        // Clean-up.
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_stop_restart_not_started() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+
+        assert_eq!(health_monitor_stop(health_monitor_handle), FFICode::WrongState);
+        assert_eq!(health_monitor_restart(health_monitor_handle), FFICode::WrongState);
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_stop_restart_null_hmon() {
+        assert_eq!(health_monitor_stop(null_mut()), FFICode::NullParameter);
+        assert_eq!(health_monitor_restart(null_mut()), FFICode::NullParameter);
+    }
+
+    #[test]
+    fn health_monitor_set_monitor_enabled_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+
+        let unknown_monitor_tag = MonitorTag::from("unknown_monitor");
+        assert_eq!(
+            health_monitor_set_monitor_enabled(
+                health_monitor_handle,
+                &deadline_monitor_tag as *const MonitorTag,
+                false
+            ),
+            FFICode::Success
+        );
+        assert_eq!(
+            health_monitor_set_monitor_enabled(health_monitor_handle, &deadline_monitor_tag as *const MonitorTag, true),
+            FFICode::Success
+        );
+        assert_eq!(
+            health_monitor_set_monitor_enabled(health_monitor_handle, &unknown_monitor_tag as *const MonitorTag, true),
+            FFICode::NotFound
+        );
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_set_monitor_enabled_null_parameters() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        assert_eq!(
+            health_monitor_set_monitor_enabled(null_mut(), &monitor_tag as *const MonitorTag, true),
+            FFICode::NullParameter
+        );
+
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert_eq!(
+            health_monitor_set_monitor_enabled(health_monitor_handle, null_mut(), true),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_get_monitor_statistics_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+
+        let mut statistics = FFIMonitorStatistics {
+            too_late: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            health_monitor_get_monitor_statistics(
+                health_monitor_handle,
+                &deadline_monitor_tag as *const MonitorTag,
+                &mut statistics as *mut FFIMonitorStatistics
+            ),
+            FFICode::Success
+        );
+        assert_eq!(statistics, FFIMonitorStatistics::default());
+
+        let unknown_monitor_tag = MonitorTag::from("unknown_monitor");
+        assert_eq!(
+            health_monitor_get_monitor_statistics(
+                health_monitor_handle,
+                &unknown_monitor_tag as *const MonitorTag,
+                &mut statistics as *mut FFIMonitorStatistics
+            ),
+            FFICode::NotFound
+        );
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_get_monitor_statistics_null_parameters() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut statistics = FFIMonitorStatistics::default();
+        assert_eq!(
+            health_monitor_get_monitor_statistics(
+                null_mut(),
+                &monitor_tag as *const MonitorTag,
+                &mut statistics as *mut FFIMonitorStatistics
+            ),
+            FFICode::NullParameter
+        );
+
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert_eq!(
+            health_monitor_get_monitor_statistics(
+                health_monitor_handle,
+                null_mut(),
+                &mut statistics as *mut FFIMonitorStatistics
+            ),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            health_monitor_get_monitor_statistics(health_monitor_handle, &monitor_tag as *const MonitorTag, null_mut()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_get_snapshot_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+
+        // Query size only.
+        let mut snapshot_size: usize = 0;
+        assert_eq!(
+            health_monitor_get_snapshot(health_monitor_handle, null_mut(), 0, &mut snapshot_size as *mut usize),
+            FFICode::InvalidArgument
+        );
+        assert!(snapshot_size > 0);
+
+        let mut buffer = vec![0u8; snapshot_size];
+        assert_eq!(
+            health_monitor_get_snapshot(
+                health_monitor_handle,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut snapshot_size as *mut usize
+            ),
+            FFICode::Success
+        );
+        let snapshot = String::from_utf8(buffer).unwrap();
+        assert!(snapshot.starts_with("{\"status\":\"stopped\""));
+        assert!(snapshot.contains("\"tag\":\"deadline_monitor\""));
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_get_snapshot_null_parameters() {
+        let mut snapshot_size: usize = 0;
+        assert_eq!(
+            health_monitor_get_snapshot(null_mut(), null_mut(), 0, &mut snapshot_size as *mut usize),
+            FFICode::NullParameter
+        );
+
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &monitor_tag as *const MonitorTag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert_eq!(
+            health_monitor_get_snapshot(health_monitor_handle, null_mut(), 0, null_mut()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_library_version_succeeds() {
+        // SAFETY:
+        // Version is a null-terminated string with static lifetime.
+        let version = unsafe { CStr::from_ptr(health_monitor_library_version()) };
+        assert_eq!(version.to_str(), Ok(env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn health_monitor_enabled_features_succeeds() {
+        let mut features_size = usize::MAX;
+        assert_eq!(
+            health_monitor_enabled_features(null_mut(), 0, &mut features_size as *mut usize),
+            if crate::enabled_features().is_empty() {
+                FFICode::Success
+            } else {
+                FFICode::InvalidArgument
+            }
+        );
+        let expected = crate::enabled_features().join(",");
+        assert_eq!(features_size, expected.len());
+
+        let mut buffer = vec![0u8; features_size];
+        assert_eq!(
+            health_monitor_enabled_features(buffer.as_mut_ptr(), buffer.len(), &mut features_size as *mut usize),
+            FFICode::Success
+        );
+        assert_eq!(buffer, expected.into_bytes());
+    }
+
+    #[test]
+    fn health_monitor_enabled_features_null_parameter() {
+        assert_eq!(
+            health_monitor_enabled_features(null_mut(), 0, null_mut()),
+            FFICode::NullParameter
+        );
+    }
+
     #[test]
     fn health_monitor_start_null_hmon() {
         let health_monitor_start_result = health_monitor_start(null_mut());
@@ -0,0 +1,190 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::config_summary::ConfigDigest;
+use crate::log::ScoreDebug;
+use crate::tag::{GroupTag, MonitorTag};
+
+/// When a [`MonitorGroup`] is treated as failed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum GroupPolicy {
+    /// Group fails on every violation of any member.
+    #[default]
+    AnyMember,
+    /// Group fails once every member reported a violation since the previous failure of the group.
+    AllMembers,
+}
+
+/// Group of monitors reported as a single monitor.
+///
+/// Violations of members are logged and stored in the history under the member tag.
+/// Failure tolerance, notification channels, failure callback and statistics see failures of the group only,
+/// under a [`MonitorTag`] created from the [`GroupTag`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MonitorGroup {
+    policy: GroupPolicy,
+    members: Vec<MonitorTag>,
+}
+
+impl MonitorGroup {
+    /// Create an empty group with the given `policy`.
+    pub fn new(policy: GroupPolicy) -> Self {
+        Self {
+            policy,
+            members: Vec::new(),
+        }
+    }
+
+    /// Add `members` to the group, monitors already in the group are skipped.
+    /// Each monitor can be a member of at most one group, this is validated on [`crate::HealthMonitorBuilder::build`].
+    pub fn with_members(mut self, members: &[MonitorTag]) -> Self {
+        for member in members {
+            if !self.members.contains(member) {
+                self.members.push(*member);
+            }
+        }
+        self
+    }
+
+    /// Members of the group.
+    pub(crate) fn members(&self) -> &[MonitorTag] {
+        &self.members
+    }
+
+    /// Write the group into the `digest`, members in order of their tags.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_u64(match self.policy {
+            GroupPolicy::AnyMember => 0,
+            GroupPolicy::AllMembers => 1,
+        });
+        let mut members: Vec<_> = self.members.iter().map(MonitorTag::as_str).collect();
+        members.sort_unstable();
+        digest.write_u64(members.len() as u64);
+        for member in members {
+            digest.write_str(member);
+        }
+    }
+}
+
+/// Aggregates violations of group members into failures of the group.
+pub(crate) struct GroupTracker {
+    monitor_tag: MonitorTag,
+    policy: GroupPolicy,
+    members: Vec<MonitorTag>,
+    /// Members which reported a violation since the previous failure of the group.
+    failed_members: Vec<bool>,
+    /// Group failed in the current cycle, reported once per cycle.
+    failed_in_cycle: bool,
+}
+
+impl GroupTracker {
+    pub(crate) fn new(group_tag: GroupTag, group: &MonitorGroup) -> Self {
+        Self {
+            monitor_tag: MonitorTag::from(group_tag),
+            policy: group.policy,
+            members: group.members.clone(),
+            failed_members: vec![false; group.members.len()],
+            failed_in_cycle: false,
+        }
+    }
+
+    /// Tag under which failures of the group are reported.
+    pub(crate) fn monitor_tag(&self) -> MonitorTag {
+        self.monitor_tag
+    }
+
+    /// Monitor is a member of the group.
+    pub(crate) fn contains(&self, monitor_tag: &MonitorTag) -> bool {
+        self.members.contains(monitor_tag)
+    }
+
+    /// Start a new evaluation cycle.
+    pub(crate) fn start_cycle(&mut self) {
+        self.failed_in_cycle = false;
+    }
+
+    /// Record violation of a member.
+    /// Returns `true` if the group failed and was not reported in the current cycle yet.
+    pub(crate) fn record_violation(&mut self, monitor_tag: &MonitorTag) -> bool {
+        let Some(index) = self.members.iter().position(|member| member == monitor_tag) else {
+            return false;
+        };
+        self.failed_members[index] = true;
+
+        let failed = match self.policy {
+            GroupPolicy::AnyMember => true,
+            GroupPolicy::AllMembers => self.failed_members.iter().all(|failed| *failed),
+        };
+        if !failed || self.failed_in_cycle {
+            return false;
+        }
+
+        self.failed_in_cycle = true;
+        self.failed_members.fill(false);
+        true
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::group::{GroupPolicy, GroupTracker, MonitorGroup};
+    use crate::tag::{GroupTag, MonitorTag};
+
+    fn tracker(policy: GroupPolicy) -> GroupTracker {
+        let group =
+            MonitorGroup::new(policy).with_members(&[MonitorTag::from("member_a"), MonitorTag::from("member_b")]);
+        GroupTracker::new(GroupTag::from("group"), &group)
+    }
+
+    #[test]
+    fn group_tracker_reports_under_group_tag() {
+        let tracker = tracker(GroupPolicy::AnyMember);
+        assert_eq!(tracker.monitor_tag(), MonitorTag::new("group"));
+        assert!(tracker.contains(&MonitorTag::new("member_a")));
+        assert!(!tracker.contains(&MonitorTag::new("group")));
+    }
+
+    #[test]
+    fn group_tracker_any_member() {
+        let mut tracker = tracker(GroupPolicy::AnyMember);
+        tracker.start_cycle();
+        assert!(tracker.record_violation(&MonitorTag::new("member_a")));
+        assert!(!tracker.record_violation(&MonitorTag::new("member_b")));
+
+        tracker.start_cycle();
+        assert!(tracker.record_violation(&MonitorTag::new("member_b")));
+    }
+
+    #[test]
+    fn group_tracker_all_members() {
+        let mut tracker = tracker(GroupPolicy::AllMembers);
+        tracker.start_cycle();
+        assert!(!tracker.record_violation(&MonitorTag::new("member_a")));
+        assert!(!tracker.record_violation(&MonitorTag::new("member_a")));
+
+        tracker.start_cycle();
+        assert!(tracker.record_violation(&MonitorTag::new("member_b")));
+
+        // Members failed before the group failure are not counted again.
+        tracker.start_cycle();
+        assert!(!tracker.record_violation(&MonitorTag::new("member_a")));
+    }
+
+    #[test]
+    fn group_tracker_ignores_non_members() {
+        let mut tracker = tracker(GroupPolicy::AnyMember);
+        tracker.start_cycle();
+        assert!(!tracker.record_violation(&MonitorTag::new("other")));
+    }
+}
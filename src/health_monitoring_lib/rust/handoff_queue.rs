@@ -0,0 +1,155 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+struct Node<T> {
+    value: T,
+    next: *mut Node<T>,
+}
+
+/// Lock-free multi-producer single-consumer queue handing values over to the monitoring logic.
+///
+/// Producers push nodes onto an atomic stack, the consumer detaches the whole stack at once.
+/// Nodes are never accessed after they are published, except by the consumer which detached them,
+/// so the queue is not prone to ABA problems.
+pub(crate) struct HandoffQueue<T> {
+    head: AtomicPtr<Node<T>>,
+}
+
+// SAFETY: values are moved between threads, but never shared.
+unsafe impl<T: Send> Send for HandoffQueue<T> {}
+// SAFETY: nodes are only accessed by the thread owning them, ownership is transferred with atomic operations.
+unsafe impl<T: Send> Sync for HandoffQueue<T> {}
+
+impl<T> HandoffQueue<T> {
+    /// Create an empty queue.
+    pub(crate) fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Push the `value`, never blocks.
+    pub(crate) fn push(&self, value: T) {
+        let node = Box::into_raw(Box::new(Node {
+            value,
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // SAFETY: node is not published yet, this thread is its only owner.
+            unsafe { (*node).next = head };
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Take all pushed values, in order of pushing.
+    pub(crate) fn take_all(&self) -> Vec<T> {
+        let mut node = self.head.swap(ptr::null_mut(), Ordering::Acquire);
+        let mut values = Vec::new();
+        while !node.is_null() {
+            // SAFETY: node was detached from the queue, this thread is its only owner.
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next;
+            values.push(boxed.value);
+        }
+        values.reverse();
+        values
+    }
+}
+
+impl<T> Drop for HandoffQueue<T> {
+    fn drop(&mut self) {
+        self.take_all();
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::handoff_queue::HandoffQueue;
+    use std::sync::Arc;
+
+    #[test]
+    fn handoff_queue_empty() {
+        let queue = HandoffQueue::<u32>::new();
+        assert!(queue.take_all().is_empty());
+    }
+
+    #[test]
+    fn handoff_queue_keeps_push_order() {
+        let queue = HandoffQueue::new();
+        queue.push(1);
+        queue.push(2);
+        queue.push(3);
+        assert_eq!(queue.take_all(), vec![1, 2, 3]);
+        assert!(queue.take_all().is_empty());
+
+        queue.push(4);
+        assert_eq!(queue.take_all(), vec![4]);
+    }
+
+    #[test]
+    fn handoff_queue_drops_remaining_values() {
+        let value = Arc::new(());
+        let queue = HandoffQueue::new();
+        queue.push(value.clone());
+        queue.push(value.clone());
+        drop(queue);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn handoff_queue_concurrent_producers() {
+        const PRODUCERS: usize = 4;
+        const VALUES_PER_PRODUCER: usize = 1000;
+
+        let queue = Arc::new(HandoffQueue::new());
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|producer| {
+                let queue = queue.clone();
+                std::thread::spawn(move || {
+                    for index in 0..VALUES_PER_PRODUCER {
+                        queue.push((producer, index));
+                    }
+                })
+            })
+            .collect();
+
+        let mut values = Vec::new();
+        while values.len() < PRODUCERS * VALUES_PER_PRODUCER {
+            values.extend(queue.take_all());
+        }
+        for producer in producers {
+            producer.join().unwrap();
+        }
+
+        // Values of a single producer are taken in order of pushing.
+        for producer in 0..PRODUCERS {
+            let indices: Vec<_> = values
+                .iter()
+                .filter(|(p, _)| *p == producer)
+                .map(|(_, index)| *index)
+                .collect();
+            assert_eq!(indices, (0..VALUES_PER_PRODUCER).collect::<Vec<_>>());
+        }
+    }
+}
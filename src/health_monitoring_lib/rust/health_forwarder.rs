@@ -0,0 +1,290 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Forwarding of health summaries to an aggregator on another ECU.
+//! Meant for bench environments, where the full platform stack is not available.
+
+use crate::config_summary::ConfigDigest;
+use crate::log::ScoreDebug;
+use core::net::SocketAddr;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use std::io;
+use std::net::UdpSocket;
+
+/// Size of an encoded [`HealthSummary`] in bytes.
+pub const HEALTH_SUMMARY_SIZE: usize = 26;
+
+/// Identifies a health summary datagram.
+const MAGIC: [u8; 2] = *b"HM";
+
+/// Version of the health summary encoding.
+const VERSION: u8 = 1;
+
+/// Health of the process as forwarded to the aggregator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum ForwardedStatus {
+    /// No monitor failed.
+    Healthy,
+    /// Some monitors failed, failures are tolerated or limited to notification channels.
+    Degraded,
+    /// Process is treated as failed, supervisor is no longer notified.
+    Failed,
+}
+
+impl ForwardedStatus {
+    fn to_byte(self) -> u8 {
+        match self {
+            ForwardedStatus::Healthy => 0,
+            ForwardedStatus::Degraded => 1,
+            ForwardedStatus::Failed => 2,
+        }
+    }
+
+    fn from_byte(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ForwardedStatus::Healthy),
+            1 => Some(ForwardedStatus::Degraded),
+            2 => Some(ForwardedStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Errors of [`HealthSummary::parse`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum HealthSummaryParseError {
+    /// Datagram size does not match [`HEALTH_SUMMARY_SIZE`].
+    InvalidSize,
+    /// Datagram is not a health summary or has an unsupported version.
+    InvalidHeader,
+    /// Checksum does not match the content.
+    ChecksumMismatch,
+    /// Unknown health status.
+    InvalidStatus,
+}
+
+/// Compact health summary sent by [`UdpHealthForwarder`].
+///
+/// Encoded as little-endian fields: magic `HM`, version, status, source ID, sequence number,
+/// timestamp in milliseconds, number of failing monitors and CRC-32 of all preceding bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HealthSummary {
+    /// Identifier of the forwarding process, assigned by the integrator.
+    pub source_id: u32,
+    /// Incremented with every sent summary, gaps indicate lost datagrams.
+    pub sequence: u32,
+    /// Time since the start of monitoring.
+    pub timestamp: Duration,
+    /// Health of the process.
+    pub status: ForwardedStatus,
+    /// Number of monitors which failed since the start of monitoring.
+    pub failing_monitors: u16,
+}
+
+impl HealthSummary {
+    /// Encode the summary into a datagram.
+    pub fn encode(&self) -> [u8; HEALTH_SUMMARY_SIZE] {
+        let mut bytes = [0; HEALTH_SUMMARY_SIZE];
+        bytes[0..2].copy_from_slice(&MAGIC);
+        bytes[2] = VERSION;
+        bytes[3] = self.status.to_byte();
+        bytes[4..8].copy_from_slice(&self.source_id.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.sequence.to_le_bytes());
+        let timestamp_ms = u64::try_from(self.timestamp.as_millis()).unwrap_or(u64::MAX);
+        bytes[12..20].copy_from_slice(&timestamp_ms.to_le_bytes());
+        bytes[20..22].copy_from_slice(&self.failing_monitors.to_le_bytes());
+        let crc = crc32(&bytes[..22]);
+        bytes[22..26].copy_from_slice(&crc.to_le_bytes());
+        bytes
+    }
+
+    /// Parse a datagram received from a [`UdpHealthForwarder`].
+    pub fn parse(bytes: &[u8]) -> Result<Self, HealthSummaryParseError> {
+        let bytes: &[u8; HEALTH_SUMMARY_SIZE] = bytes.try_into().map_err(|_| HealthSummaryParseError::InvalidSize)?;
+        if bytes[0..2] != MAGIC || bytes[2] != VERSION {
+            return Err(HealthSummaryParseError::InvalidHeader);
+        }
+        if crc32(&bytes[..22]) != u32::from_le_bytes([bytes[22], bytes[23], bytes[24], bytes[25]]) {
+            return Err(HealthSummaryParseError::ChecksumMismatch);
+        }
+
+        let status = ForwardedStatus::from_byte(bytes[3]).ok_or(HealthSummaryParseError::InvalidStatus)?;
+        let mut timestamp_ms = [0; 8];
+        timestamp_ms.copy_from_slice(&bytes[12..20]);
+        Ok(Self {
+            source_id: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            sequence: u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+            timestamp: Duration::from_millis(u64::from_le_bytes(timestamp_ms)),
+            status,
+            failing_monitors: u16::from_le_bytes([bytes[20], bytes[21]]),
+        })
+    }
+}
+
+/// CRC-32 (IEEE 802.3) of `bytes`.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !crc32_update(u32::MAX, bytes)
+}
+
+/// Continue CRC-32 computation from the `crc` state, which starts at `u32::MAX` and is inverted when done.
+pub(crate) fn crc32_update(mut crc: u32, bytes: &[u8]) -> u32 {
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    crc
+}
+
+/// Sends a [`HealthSummary`] to the aggregator every supervisor API cycle and when the process fails.
+///
+/// Datagrams are sent from a non-blocking socket, so an unreachable aggregator never stalls monitoring.
+pub struct UdpHealthForwarder {
+    socket: UdpSocket,
+    aggregator_addr: SocketAddr,
+    source_id: u32,
+    next_sequence: AtomicU32,
+}
+
+impl UdpHealthForwarder {
+    /// Create a forwarder sending summaries to `aggregator_addr` from an ephemeral local port.
+    ///
+    /// - `aggregator_addr` - address of the aggregator.
+    /// - `source_id` - identifier of this process in the summaries.
+    pub fn new(aggregator_addr: SocketAddr, source_id: u32) -> io::Result<Self> {
+        let local_addr: SocketAddr = if aggregator_addr.is_ipv4() {
+            ([0, 0, 0, 0], 0).into()
+        } else {
+            ([0u16; 8], 0).into()
+        };
+        let socket = UdpSocket::bind(local_addr)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket,
+            aggregator_addr,
+            source_id,
+            next_sequence: AtomicU32::new(0),
+        })
+    }
+
+    /// Send summary of the current health.
+    /// Returns `false` if the datagram could not be sent.
+    pub(crate) fn forward(&self, timestamp: Duration, status: ForwardedStatus, failing_monitors: u16) -> bool {
+        let summary = HealthSummary {
+            source_id: self.source_id,
+            sequence: self.next_sequence.fetch_add(1, Ordering::Relaxed),
+            timestamp,
+            status,
+            failing_monitors,
+        };
+        self.socket
+            .send_to(&summary.encode(), self.aggregator_addr)
+            .is_ok_and(|sent| sent == HEALTH_SUMMARY_SIZE)
+    }
+
+    /// Write the configuration into the `digest`.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_str(&self.aggregator_addr.to_string());
+        digest.write_u64(u64::from(self.source_id));
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::health_forwarder::{
+        crc32, ForwardedStatus, HealthSummary, HealthSummaryParseError, UdpHealthForwarder, HEALTH_SUMMARY_SIZE,
+    };
+    use core::time::Duration;
+    use std::net::UdpSocket;
+
+    fn summary() -> HealthSummary {
+        HealthSummary {
+            source_id: 7,
+            sequence: 42,
+            timestamp: Duration::from_millis(1500),
+            status: ForwardedStatus::Degraded,
+            failing_monitors: 2,
+        }
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn health_summary_encode_parse() {
+        let bytes = summary().encode();
+        assert_eq!(HealthSummary::parse(&bytes), Ok(summary()));
+    }
+
+    #[test]
+    fn health_summary_parse_errors() {
+        let bytes = summary().encode();
+        assert_eq!(
+            HealthSummary::parse(&bytes[..HEALTH_SUMMARY_SIZE - 1]),
+            Err(HealthSummaryParseError::InvalidSize)
+        );
+
+        let mut invalid_header = bytes;
+        invalid_header[2] = 0;
+        assert_eq!(
+            HealthSummary::parse(&invalid_header),
+            Err(HealthSummaryParseError::InvalidHeader)
+        );
+
+        let mut corrupted = bytes;
+        corrupted[10] ^= 1;
+        assert_eq!(
+            HealthSummary::parse(&corrupted),
+            Err(HealthSummaryParseError::ChecksumMismatch)
+        );
+
+        let mut invalid_status = bytes;
+        invalid_status[3] = 9;
+        let crc = crc32(&invalid_status[..22]);
+        invalid_status[22..].copy_from_slice(&crc.to_le_bytes());
+        assert_eq!(
+            HealthSummary::parse(&invalid_status),
+            Err(HealthSummaryParseError::InvalidStatus)
+        );
+    }
+
+    #[test]
+    fn udp_health_forwarder_sends_sequenced_summaries() {
+        let aggregator = UdpSocket::bind("127.0.0.1:0").unwrap();
+        aggregator.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let forwarder = UdpHealthForwarder::new(aggregator.local_addr().unwrap(), 7).unwrap();
+
+        assert!(forwarder.forward(Duration::from_millis(100), ForwardedStatus::Healthy, 0));
+        assert!(forwarder.forward(Duration::from_millis(200), ForwardedStatus::Failed, 1));
+
+        let mut buffer = [0; 64];
+        let mut received = vec![];
+        for _ in 0..2 {
+            let (size, _) = aggregator.recv_from(&mut buffer).unwrap();
+            received.push(HealthSummary::parse(&buffer[..size]).unwrap());
+        }
+        assert_eq!(received[0].sequence, 0);
+        assert_eq!(received[0].status, ForwardedStatus::Healthy);
+        assert_eq!(received[1].sequence, 1);
+        assert_eq!(received[1].status, ForwardedStatus::Failed);
+        assert_eq!(received[1].source_id, 7);
+        assert_eq!(received[1].failing_monitors, 1);
+        assert_eq!(received[1].timestamp, Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,136 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::armed_start::ArmedStart;
+use crate::artifact::ArtifactFreshnessMonitorBuilder;
+use crate::clock_granularity::DEFAULT_CLOCK_GRANULARITY_MULTIPLE;
+use crate::deadline::DeadlineMonitorBuilder;
+use crate::early_supervision::EarlySupervision;
+use crate::fail_fast::FailFastPolicy;
+use crate::group::MonitorGroup;
+use crate::health_forwarder::UdpHealthForwarder;
+use crate::health_trajectory::HealthTrajectory;
+use crate::heartbeat::HeartbeatMonitorBuilder;
+use crate::ordered_map::OrderedMap;
+use crate::resource_budget::ResourceBudget;
+use crate::signal_export::SignalExport;
+use crate::tag::{ChannelTag, GroupTag, MonitorTag};
+use crate::tolerance::{FailureTolerance, RecoveryPolicy};
+use crate::update_mode::UpdateMode;
+use crate::violation_sink::ViolationSink;
+use crate::worker::{FailureCallback, WorkerFaultCallback};
+use core::time::Duration;
+use std::collections::HashMap;
+
+/// Configuration collected by the [`crate::HealthMonitorBuilder`].
+/// Set by the builder methods, and directly by FFI code which does not move the builder.
+#[derive(Default)]
+pub(crate) struct HealthMonitorConfig {
+    pub(crate) deadline_monitor_builders: OrderedMap<MonitorTag, DeadlineMonitorBuilder>,
+    pub(crate) heartbeat_monitor_builders: OrderedMap<MonitorTag, HeartbeatMonitorBuilder>,
+    pub(crate) artifact_freshness_monitor_builders: OrderedMap<MonitorTag, ArtifactFreshnessMonitorBuilder>,
+    pub(crate) supervisor_api_cycle: Duration,
+    pub(crate) internal_processing_cycle: Duration,
+    pub(crate) resource_budget: ResourceBudget,
+    pub(crate) notification_channels: HashMap<ChannelTag, Vec<MonitorTag>>,
+    pub(crate) monitor_groups: HashMap<GroupTag, MonitorGroup>,
+    pub(crate) failure_callback: Option<FailureCallback>,
+    pub(crate) failure_callback_budget: Duration,
+    pub(crate) clock_gap_threshold: Option<Duration>,
+    pub(crate) signal_export: Option<SignalExport>,
+    pub(crate) violation_sinks: Vec<Box<dyn ViolationSink>>,
+    pub(crate) violation_logging_disabled: bool,
+    pub(crate) failure_tolerance: Option<FailureTolerance>,
+    pub(crate) monitor_failure_tolerances: HashMap<MonitorTag, FailureTolerance>,
+    pub(crate) health_forwarder: Option<UdpHealthForwarder>,
+    pub(crate) recovery_policy: RecoveryPolicy,
+    pub(crate) update_mode: Option<UpdateMode>,
+    pub(crate) worker_stall_threshold: Option<Duration>,
+    pub(crate) worker_fault_callback: Option<WorkerFaultCallback>,
+    pub(crate) panic_reporting: bool,
+    pub(crate) fail_fast: Option<FailFastPolicy>,
+    pub(crate) startup_grace: Option<Duration>,
+    pub(crate) armed_start: Option<ArmedStart>,
+    pub(crate) health_trajectory: Option<HealthTrajectory>,
+    pub(crate) early_supervision: Option<EarlySupervision>,
+    pub(crate) supervisor_identity: Option<String>,
+    pub(crate) strict_validation: bool,
+    pub(crate) clock_granularity_multiple: u32,
+}
+
+impl HealthMonitorConfig {
+    /// Create a configuration with default values of the [`crate::HealthMonitorBuilder`].
+    pub(crate) fn new() -> Self {
+        Self {
+            deadline_monitor_builders: OrderedMap::new(),
+            heartbeat_monitor_builders: OrderedMap::new(),
+            artifact_freshness_monitor_builders: OrderedMap::new(),
+            supervisor_api_cycle: Duration::from_millis(500),
+            internal_processing_cycle: Duration::from_millis(100),
+            resource_budget: ResourceBudget::new(),
+            notification_channels: HashMap::new(),
+            monitor_groups: HashMap::new(),
+            failure_callback: None,
+            failure_callback_budget: Duration::from_millis(10),
+            clock_gap_threshold: None,
+            signal_export: None,
+            violation_sinks: Vec::new(),
+            violation_logging_disabled: false,
+            failure_tolerance: None,
+            monitor_failure_tolerances: HashMap::new(),
+            health_forwarder: None,
+            recovery_policy: RecoveryPolicy::Never,
+            update_mode: None,
+            worker_stall_threshold: None,
+            worker_fault_callback: None,
+            panic_reporting: false,
+            fail_fast: None,
+            startup_grace: None,
+            armed_start: None,
+            health_trajectory: None,
+            early_supervision: None,
+            supervisor_identity: None,
+            strict_validation: false,
+            clock_granularity_multiple: DEFAULT_CLOCK_GRANULARITY_MULTIPLE,
+        }
+    }
+
+    /// Number of monitors added to the configuration.
+    pub(crate) fn num_monitors(&self) -> usize {
+        self.deadline_monitor_builders.len()
+            + self.heartbeat_monitor_builders.len()
+            + self.artifact_freshness_monitor_builders.len()
+            + self
+                .early_supervision
+                .as_ref()
+                .map_or(0, EarlySupervision::num_monitors)
+    }
+
+    /// Monitor with the given tag was added to the configuration.
+    pub(crate) fn contains_monitor(&self, monitor_tag: &MonitorTag) -> bool {
+        self.deadline_monitor_builders.contains_key(monitor_tag)
+            || self.heartbeat_monitor_builders.contains_key(monitor_tag)
+            || self.artifact_freshness_monitor_builders.contains_key(monitor_tag)
+            || self
+                .early_supervision
+                .as_ref()
+                .is_some_and(|early_supervision| early_supervision.contains_monitor(monitor_tag))
+    }
+
+    /// Group reported with the given monitor tag was added to the configuration.
+    pub(crate) fn contains_group(&self, monitor_tag: &MonitorTag) -> bool {
+        self.monitor_groups
+            .keys()
+            .any(|group_tag| MonitorTag::from(*group_tag) == *monitor_tag)
+    }
+}
@@ -0,0 +1,204 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::tag::MonitorTag;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+/// Number of monitors tracked by a single status word.
+const MONITORS_PER_WORD: usize = u64::BITS as usize;
+
+/// Aggregated health status of the monitored application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// Monitoring is running and no monitor reported an error.
+    Healthy,
+    /// Listed monitors reported errors since monitoring was started.
+    Degraded(Vec<MonitorTag>),
+    /// Monitoring is not started or was stopped on request.
+    Stopped,
+}
+
+/// Health status published by the worker and read by application threads.
+/// Failing monitors are stored as a bitmask of atomic words, so reading does not block the worker.
+/// Slots of monitors registered at runtime are assigned once and never released.
+pub(crate) struct StatusBoard {
+    monitor_tags: Box<[OnceLock<MonitorTag>]>,
+    failing: Box<[AtomicU64]>,
+    running: AtomicBool,
+}
+
+impl StatusBoard {
+    /// Create a board for the given monitors, initially [`HealthStatus::Stopped`].
+    ///
+    /// - `monitor_tags` - monitors known when the board is created.
+    /// - `spare_slots` - number of monitors which can be registered later with [`Self::register`].
+    pub(crate) fn new(monitor_tags: Vec<MonitorTag>, spare_slots: usize) -> Self {
+        let num_slots = monitor_tags.len() + spare_slots;
+        let num_words = num_slots.div_ceil(MONITORS_PER_WORD);
+        Self {
+            monitor_tags: monitor_tags
+                .into_iter()
+                .map(OnceLock::from)
+                .chain((0..spare_slots).map(|_| OnceLock::new()))
+                .collect(),
+            failing: (0..num_words).map(|_| AtomicU64::new(0)).collect(),
+            running: AtomicBool::new(false),
+        }
+    }
+
+    /// Register a monitor added after the board was created.
+    /// Returns `false` if no spare slot is left.
+    pub(crate) fn register(&self, monitor_tag: MonitorTag) -> bool {
+        self.monitor_tags.iter().any(|slot| slot.set(monitor_tag).is_ok())
+    }
+
+    /// Bitmask with no failing monitors, sized for this board.
+    pub(crate) fn empty_mask(&self) -> Box<[u64]> {
+        vec![0; self.failing.len()].into_boxed_slice()
+    }
+
+    /// Mark the monitor as failing in the `mask`.
+    /// Monitors unknown to the board are ignored.
+    pub(crate) fn mark_failing(&self, mask: &mut [u64], monitor_tag: &MonitorTag) {
+        if let Some(index) = self.monitor_tags.iter().position(|tag| tag.get() == Some(monitor_tag)) {
+            mask[index / MONITORS_PER_WORD] |= 1 << (index % MONITORS_PER_WORD);
+        }
+    }
+
+    /// Mark monitoring as running with no failing monitors.
+    pub(crate) fn set_running(&self) {
+        for word in self.failing.iter() {
+            word.store(0, Ordering::Relaxed);
+        }
+        self.running.store(true, Ordering::Release);
+    }
+
+    /// Mark monitoring as stopped.
+    pub(crate) fn set_stopped(&self) {
+        self.running.store(false, Ordering::Release);
+    }
+
+    /// Publish failing monitors, `mask` must come from [`Self::empty_mask`].
+    pub(crate) fn publish(&self, mask: &[u64]) {
+        for (word, value) in self.failing.iter().zip(mask) {
+            word.store(*value, Ordering::Release);
+        }
+    }
+
+    /// Monitoring is running.
+    pub(crate) fn is_running(&self) -> bool {
+        self.running.load(Ordering::Acquire)
+    }
+
+    /// The monitor is failing, monitors unknown to the board are never failing.
+    pub(crate) fn is_failing(&self, monitor_tag: &MonitorTag) -> bool {
+        self.monitor_tags
+            .iter()
+            .position(|tag| tag.get() == Some(monitor_tag))
+            .is_some_and(|index| {
+                let word = self.failing[index / MONITORS_PER_WORD].load(Ordering::Acquire);
+                word & (1 << (index % MONITORS_PER_WORD)) != 0
+            })
+    }
+
+    /// Current health status.
+    pub(crate) fn status(&self) -> HealthStatus {
+        if !self.running.load(Ordering::Acquire) {
+            return HealthStatus::Stopped;
+        }
+
+        let failing_tags: Vec<MonitorTag> = self
+            .monitor_tags
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                let word = self.failing[index / MONITORS_PER_WORD].load(Ordering::Acquire);
+                word & (1 << (index % MONITORS_PER_WORD)) != 0
+            })
+            .filter_map(|(_, tag)| tag.get().copied())
+            .collect();
+
+        if failing_tags.is_empty() {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Degraded(failing_tags)
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::health_status::{HealthStatus, StatusBoard};
+    use crate::tag::MonitorTag;
+
+    fn many_tags(count: usize) -> Vec<MonitorTag> {
+        (0..count)
+            .map(|index| MonitorTag::from(format!("monitor_{index}").as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn status_board_initially_stopped() {
+        let board = StatusBoard::new(vec![MonitorTag::from("monitor")], 0);
+        assert_eq!(board.status(), HealthStatus::Stopped);
+    }
+
+    #[test]
+    fn status_board_running_healthy() {
+        let board = StatusBoard::new(vec![MonitorTag::from("monitor")], 0);
+        board.set_running();
+        assert_eq!(board.status(), HealthStatus::Healthy);
+
+        board.set_stopped();
+        assert_eq!(board.status(), HealthStatus::Stopped);
+    }
+
+    #[test]
+    fn status_board_degraded() {
+        let tags = many_tags(70);
+        let board = StatusBoard::new(tags.clone(), 0);
+        board.set_running();
+
+        let mut mask = board.empty_mask();
+        board.mark_failing(&mut mask, &tags[3]);
+        board.mark_failing(&mut mask, &tags[66]);
+        board.mark_failing(&mut mask, &MonitorTag::from("unknown"));
+        board.publish(&mask);
+        assert_eq!(board.status(), HealthStatus::Degraded(vec![tags[3], tags[66]]));
+        assert!(board.is_failing(&tags[66]));
+        assert!(!board.is_failing(&tags[4]));
+        assert!(!board.is_failing(&MonitorTag::from("unknown")));
+
+        // Running again clears failing monitors.
+        board.set_running();
+        assert_eq!(board.status(), HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn status_board_register() {
+        let board = StatusBoard::new(vec![MonitorTag::from("monitor")], 1);
+        board.set_running();
+        assert!(board.register(MonitorTag::from("runtime_monitor")));
+        assert!(!board.register(MonitorTag::from("no_slot_left")));
+
+        let mut mask = board.empty_mask();
+        board.mark_failing(&mut mask, &MonitorTag::from("runtime_monitor"));
+        board.mark_failing(&mut mask, &MonitorTag::from("no_slot_left"));
+        board.publish(&mask);
+        assert_eq!(
+            board.status(),
+            HealthStatus::Degraded(vec![MonitorTag::from("runtime_monitor")])
+        );
+    }
+}
@@ -0,0 +1,270 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::{MonitorEvaluationError, MonitorEvaluator, ViolationContext};
+use crate::config_summary::ConfigDigest;
+use crate::log::{warn, ScoreDebug};
+use crate::tag::MonitorTag;
+use core::sync::atomic::{AtomicU8, Ordering};
+use std::time::Instant;
+
+/// Aggregate health of the process in a single evaluation pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum HealthState {
+    /// No monitor reported a violation.
+    Healthy,
+    /// Some monitors reported violations, violations are tolerated or limited to notification channels.
+    Degraded,
+    /// Process is treated as failed, supervisor is not notified.
+    Failed,
+    /// No violations reported, failed monitors are waiting for recovery.
+    Recovering,
+}
+
+impl HealthState {
+    fn to_byte(self) -> u8 {
+        match self {
+            HealthState::Healthy => 0,
+            HealthState::Degraded => 1,
+            HealthState::Failed => 2,
+            HealthState::Recovering => 3,
+        }
+    }
+
+    fn from_byte(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(HealthState::Healthy),
+            1 => Some(HealthState::Degraded),
+            2 => Some(HealthState::Failed),
+            3 => Some(HealthState::Recovering),
+            _ => None,
+        }
+    }
+}
+
+/// Transitions allowed by [`HealthTrajectory::new`].
+const DEFAULT_TRANSITIONS: [(HealthState, HealthState); 8] = [
+    (HealthState::Healthy, HealthState::Degraded),
+    (HealthState::Healthy, HealthState::Failed),
+    (HealthState::Degraded, HealthState::Healthy),
+    (HealthState::Degraded, HealthState::Failed),
+    (HealthState::Failed, HealthState::Recovering),
+    (HealthState::Recovering, HealthState::Healthy),
+    (HealthState::Recovering, HealthState::Degraded),
+    (HealthState::Recovering, HealthState::Failed),
+];
+
+/// Supervision of the aggregate health, checked as states of a built-in logic monitor.
+///
+/// Health state of every evaluation pass is checked in the next pass,
+/// transitions not allowed are reported as logic violations under the monitor tag.
+/// Staying in the same state is always allowed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthTrajectory {
+    monitor_tag: MonitorTag,
+    allowed_transitions: Vec<(HealthState, HealthState)>,
+}
+
+impl HealthTrajectory {
+    /// Create health trajectory supervision with default transitions.
+    /// Failed process must pass [`HealthState::Recovering`] before being healthy or degraded again.
+    ///
+    /// - `monitor_tag` - tag under which invalid transitions are reported, must not be used by a monitor.
+    pub fn new(monitor_tag: MonitorTag) -> Self {
+        Self {
+            monitor_tag,
+            allowed_transitions: DEFAULT_TRANSITIONS.to_vec(),
+        }
+    }
+
+    /// Allow transition from `from` to `to`, e.g. recovery of a failed process without [`HealthState::Recovering`].
+    pub fn allow_transition(mut self, from: HealthState, to: HealthState) -> Self {
+        if !self.is_allowed(from, to) {
+            self.allowed_transitions.push((from, to));
+        }
+        self
+    }
+
+    /// Forbid transition from `from` to `to`, e.g. to require recovering processes to be healthy first.
+    pub fn forbid_transition(mut self, from: HealthState, to: HealthState) -> Self {
+        self.allowed_transitions.retain(|transition| *transition != (from, to));
+        self
+    }
+
+    pub(crate) fn monitor_tag(&self) -> MonitorTag {
+        self.monitor_tag
+    }
+
+    fn is_allowed(&self, from: HealthState, to: HealthState) -> bool {
+        from == to || self.allowed_transitions.contains(&(from, to))
+    }
+
+    /// Write the supervision into the `digest`, transitions in order of their states.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_str(self.monitor_tag.as_str());
+        let mut allowed_transitions: Vec<_> = self
+            .allowed_transitions
+            .iter()
+            .map(|(from, to)| (from.to_byte(), to.to_byte()))
+            .collect();
+        allowed_transitions.sort_unstable();
+        digest.write_u64(allowed_transitions.len() as u64);
+        for (from, to) in allowed_transitions {
+            digest.write_u64(u64::from(from));
+            digest.write_u64(u64::from(to));
+        }
+    }
+}
+
+/// No health state published since the monitoring was started.
+const NO_STATE: u8 = u8::MAX;
+
+/// Health states shared by the monitoring logic and the logic monitor checking them.
+/// Evaluated as a monitor, so invalid transitions are handled as any other violation.
+pub(crate) struct TrajectoryGuard {
+    config: HealthTrajectory,
+    /// State of the last finished evaluation pass.
+    published: AtomicU8,
+    /// State checked by the last evaluation of the guard.
+    checked: AtomicU8,
+}
+
+impl TrajectoryGuard {
+    pub(crate) fn new(config: HealthTrajectory) -> Self {
+        Self {
+            config,
+            published: AtomicU8::new(NO_STATE),
+            checked: AtomicU8::new(NO_STATE),
+        }
+    }
+
+    /// Publish health state of a finished evaluation pass.
+    pub(crate) fn publish(&self, state: HealthState) {
+        self.published.store(state.to_byte(), Ordering::Release);
+    }
+
+    /// Forget states of the previous run, so transitions are not checked across restarts.
+    pub(crate) fn reset(&self) {
+        self.published.store(NO_STATE, Ordering::Release);
+        self.checked.store(NO_STATE, Ordering::Relaxed);
+    }
+
+    /// Health state of the last finished evaluation pass, [`None`] if no pass finished since the start.
+    pub(crate) fn state(&self) -> Option<HealthState> {
+        HealthState::from_byte(self.published.load(Ordering::Acquire))
+    }
+}
+
+impl MonitorEvaluator for TrajectoryGuard {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.config.monitor_tag
+    }
+
+    fn evaluate(
+        &self,
+        _hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
+        let Some(state) = self.state() else {
+            return;
+        };
+        let Some(previous) = HealthState::from_byte(self.checked.swap(state.to_byte(), Ordering::Relaxed)) else {
+            return;
+        };
+
+        if !self.config.is_allowed(previous, state) {
+            warn!(
+                "Health state changed from {:?} to {:?}, transition is not allowed.",
+                previous, state
+            );
+            on_error(
+                &self.config.monitor_tag,
+                MonitorEvaluationError::Logic,
+                ViolationContext::default(),
+            );
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::{MonitorEvaluationError, MonitorEvaluator, ViolationContext};
+    use crate::health_trajectory::{HealthState, HealthTrajectory, TrajectoryGuard};
+    use crate::tag::MonitorTag;
+    use std::time::Instant;
+
+    fn evaluate(guard: &TrajectoryGuard) -> Vec<(MonitorTag, MonitorEvaluationError)> {
+        let mut violations = Vec::new();
+        guard.evaluate(
+            Instant::now(),
+            &mut |monitor_tag: &MonitorTag, error, _context: ViolationContext| {
+                violations.push((*monitor_tag, error));
+            },
+        );
+        violations
+    }
+
+    #[test]
+    fn trajectory_guard_default_transitions() {
+        let monitor_tag = MonitorTag::from("trajectory");
+        let guard = TrajectoryGuard::new(HealthTrajectory::new(monitor_tag));
+        assert!(evaluate(&guard).is_empty());
+
+        for state in [
+            HealthState::Healthy,
+            HealthState::Degraded,
+            HealthState::Failed,
+            HealthState::Failed,
+            HealthState::Recovering,
+            HealthState::Healthy,
+            HealthState::Failed,
+        ] {
+            guard.publish(state);
+            assert!(evaluate(&guard).is_empty());
+        }
+
+        // Failed process became healthy without recovering.
+        guard.publish(HealthState::Healthy);
+        assert_eq!(evaluate(&guard), vec![(monitor_tag, MonitorEvaluationError::Logic)]);
+        assert!(evaluate(&guard).is_empty());
+
+        // Restarted monitoring begins in any state.
+        guard.publish(HealthState::Failed);
+        guard.reset();
+        guard.publish(HealthState::Healthy);
+        assert!(evaluate(&guard).is_empty());
+    }
+
+    #[test]
+    fn trajectory_guard_configured_transitions() {
+        let monitor_tag = MonitorTag::from("trajectory");
+        let guard = TrajectoryGuard::new(
+            HealthTrajectory::new(monitor_tag)
+                .allow_transition(HealthState::Failed, HealthState::Healthy)
+                .forbid_transition(HealthState::Recovering, HealthState::Degraded),
+        );
+
+        for state in [
+            HealthState::Failed,
+            HealthState::Healthy,
+            HealthState::Failed,
+            HealthState::Recovering,
+        ] {
+            guard.publish(state);
+            assert!(evaluate(&guard).is_empty());
+        }
+
+        guard.publish(HealthState::Degraded);
+        assert_eq!(evaluate(&guard), vec![(monitor_tag, MonitorEvaluationError::Logic)]);
+    }
+}
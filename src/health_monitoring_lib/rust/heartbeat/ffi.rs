@@ -11,8 +11,9 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 use crate::common::TimeRange;
-use crate::ffi::{FFIBorrowed, FFICode, FFIHandle};
-use crate::heartbeat::{HeartbeatMonitor, HeartbeatMonitorBuilder};
+use crate::ffi::{ffi_destroy, ffi_method, FFICode, FFIHandle};
+use crate::heartbeat::{HeartbeatMonitor, HeartbeatMonitorBuilder, HeartbeatMonitorError};
+use crate::tag::SourceTag;
 use core::time::Duration;
 
 #[unsafe(no_mangle)]
@@ -40,54 +41,126 @@ pub extern "C" fn heartbeat_monitor_builder_create(
     FFICode::Success
 }
 
-#[unsafe(no_mangle)]
-pub extern "C" fn heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle: FFIHandle) -> FFICode {
-    if heartbeat_monitor_builder_handle.is_null() {
-        return FFICode::NullParameter;
+ffi_destroy!(
+    /// Destroys a builder created by `heartbeat_monitor_builder_create`.
+    fn heartbeat_monitor_builder_destroy(HeartbeatMonitorBuilder)
+);
+
+ffi_method!(
+    fn heartbeat_monitor_builder_with_max_update_attempts(builder: &mut HeartbeatMonitorBuilder, max_attempts: u32) {
+        builder.with_max_update_attempts_internal(max_attempts);
+        FFICode::Success
     }
+);
 
-    // SAFETY:
-    // Validity of the pointer is ensured.
-    // It is assumed that the pointer was created by a call to `heartbeat_monitor_builder_create`.
-    unsafe {
-        let _ = Box::from_raw(heartbeat_monitor_builder_handle as *mut HeartbeatMonitorBuilder);
+ffi_method!(
+    fn heartbeat_monitor_builder_with_thread_attribution(builder: &mut HeartbeatMonitorBuilder) {
+        builder.with_thread_attribution_internal();
+        FFICode::Success
     }
+);
 
-    FFICode::Success
-}
+ffi_method!(
+    fn heartbeat_monitor_builder_with_allowed_misses(builder: &mut HeartbeatMonitorBuilder, allowed_misses: u32) {
+        builder.with_allowed_misses_internal(allowed_misses);
+        FFICode::Success
+    }
+);
 
-#[unsafe(no_mangle)]
-pub extern "C" fn heartbeat_monitor_destroy(heartbeat_monitor_handle: FFIHandle) -> FFICode {
-    if heartbeat_monitor_handle.is_null() {
-        return FFICode::NullParameter;
+ffi_method!(
+    fn heartbeat_monitor_builder_with_expected_count(
+        builder: &mut HeartbeatMonitorBuilder,
+        min_count: u32,
+        max_count: u32,
+        per_ms: u32,
+    ) {
+        builder.with_expected_count_internal(min_count, max_count, Duration::from_millis(u64::from(per_ms)));
+        FFICode::Success
     }
+);
 
-    // SAFETY:
-    // Validity of the pointer is ensured.
-    // It is assumed that the pointer was created by a call to `health_monitor_get_heartbeat_monitor`.
-    unsafe {
-        let _ = Box::from_raw(heartbeat_monitor_handle as *mut HeartbeatMonitor);
+ffi_method!(
+    fn heartbeat_monitor_builder_with_first_beat_deadline(builder: &mut HeartbeatMonitorBuilder, deadline_ms: u32) {
+        builder.with_first_beat_deadline_internal(Duration::from_millis(u64::from(deadline_ms)));
+        FFICode::Success
     }
+);
 
-    FFICode::Success
-}
+ffi_method!(
+    fn heartbeat_monitor_builder_add_source(builder: &mut HeartbeatMonitorBuilder, source_tag: *const SourceTag) {
+        if source_tag.is_null() {
+            return FFICode::NullParameter;
+        }
 
-#[unsafe(no_mangle)]
-pub extern "C" fn heartbeat_monitor_heartbeat(heartbeat_monitor_handle: FFIHandle) -> FFICode {
-    if heartbeat_monitor_handle.is_null() {
-        return FFICode::NullParameter;
+        // SAFETY:
+        // Validity of the pointer is ensured.
+        // `SourceTag` type must be compatible between C++ and Rust.
+        let source_tag = unsafe { *source_tag };
+        builder.add_source_internal(source_tag);
+        FFICode::Success
     }
+);
 
-    // SAFETY:
-    // Validity of this pointer is ensured.
-    // It is assumed that the pointer was created by a call to `health_monitor_get_heartbeat_monitor`.
-    // It is assumed that the pointer was not consumed by a call to `heartbeat_monitor_destroy`.
-    let monitor = FFIBorrowed::new(unsafe { Box::from_raw(heartbeat_monitor_handle as *mut HeartbeatMonitor) });
+ffi_destroy!(
+    /// Destroys a monitor created by `health_monitor_get_heartbeat_monitor`.
+    fn heartbeat_monitor_destroy(HeartbeatMonitor)
+);
 
-    monitor.heartbeat();
+ffi_method!(
+    fn heartbeat_monitor_heartbeat(monitor: &HeartbeatMonitor) {
+        monitor.heartbeat();
+        FFICode::Success
+    }
+);
 
-    FFICode::Success
-}
+ffi_method!(
+    fn heartbeat_monitor_update_range(monitor: &HeartbeatMonitor, range_min_ms: u32, range_max_ms: u32) {
+        let range_min = Duration::from_millis(u64::from(range_min_ms));
+        let range_max = Duration::from_millis(u64::from(range_max_ms));
+        let Some(range) = TimeRange::new_internal(range_min, range_max) else {
+            return FFICode::InvalidArgument;
+        };
+        match monitor.update_range(range) {
+            Ok(()) => FFICode::Success,
+            Err(HeartbeatMonitorError::InvalidRange) => FFICode::InvalidArgument,
+            Err(_err) => FFICode::Failed,
+        }
+    }
+);
+
+ffi_method!(
+    fn heartbeat_monitor_suspend(monitor: &HeartbeatMonitor) {
+        monitor.suspend();
+        FFICode::Success
+    }
+);
+
+ffi_method!(
+    fn heartbeat_monitor_resume(monitor: &HeartbeatMonitor) {
+        monitor.resume();
+        FFICode::Success
+    }
+);
+
+ffi_method!(
+    fn heartbeat_monitor_source_heartbeat(monitor: &HeartbeatMonitor, source_tag: *const SourceTag) {
+        if source_tag.is_null() {
+            return FFICode::NullParameter;
+        }
+
+        // SAFETY:
+        // Validity of the pointer is ensured.
+        // `SourceTag` type must be compatible between C++ and Rust.
+        let source_tag = unsafe { *source_tag };
+        match monitor.source(source_tag) {
+            Ok(source) => {
+                source.heartbeat();
+                FFICode::Success
+            },
+            Err(_err) => FFICode::NotFound,
+        }
+    }
+);
 
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
@@ -97,10 +170,14 @@ mod tests {
         health_monitor_destroy, health_monitor_get_heartbeat_monitor, FFICode, FFIHandle,
     };
     use crate::heartbeat::ffi::{
-        heartbeat_monitor_builder_create, heartbeat_monitor_builder_destroy, heartbeat_monitor_destroy,
-        heartbeat_monitor_heartbeat,
+        heartbeat_monitor_builder_add_source, heartbeat_monitor_builder_create, heartbeat_monitor_builder_destroy,
+        heartbeat_monitor_builder_with_allowed_misses, heartbeat_monitor_builder_with_expected_count,
+        heartbeat_monitor_builder_with_first_beat_deadline, heartbeat_monitor_builder_with_max_update_attempts,
+        heartbeat_monitor_builder_with_thread_attribution, heartbeat_monitor_destroy, heartbeat_monitor_heartbeat,
+        heartbeat_monitor_resume, heartbeat_monitor_source_heartbeat, heartbeat_monitor_suspend,
+        heartbeat_monitor_update_range,
     };
-    use crate::tag::MonitorTag;
+    use crate::tag::{MonitorTag, SourceTag};
     use core::ptr::null_mut;
 
     #[test]
@@ -140,6 +217,99 @@ mod tests {
         assert_eq!(heartbeat_monitor_builder_destroy_result, FFICode::NullParameter);
     }
 
+    #[test]
+    fn heartbeat_monitor_builder_options_succeed() {
+        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
+
+        let with_max_update_attempts_result =
+            heartbeat_monitor_builder_with_max_update_attempts(heartbeat_monitor_builder_handle, 3);
+        assert_eq!(with_max_update_attempts_result, FFICode::Success);
+        let with_thread_attribution_result =
+            heartbeat_monitor_builder_with_thread_attribution(heartbeat_monitor_builder_handle);
+        assert_eq!(with_thread_attribution_result, FFICode::Success);
+        let with_allowed_misses_result =
+            heartbeat_monitor_builder_with_allowed_misses(heartbeat_monitor_builder_handle, 2);
+        assert_eq!(with_allowed_misses_result, FFICode::Success);
+        let with_expected_count_result =
+            heartbeat_monitor_builder_with_expected_count(heartbeat_monitor_builder_handle, 1, 2, 500);
+        assert_eq!(with_expected_count_result, FFICode::Success);
+        let with_first_beat_deadline_result =
+            heartbeat_monitor_builder_with_first_beat_deadline(heartbeat_monitor_builder_handle, 1000);
+        assert_eq!(with_first_beat_deadline_result, FFICode::Success);
+
+        // Clean-up.
+        heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle);
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_options_null_builder() {
+        assert_eq!(
+            heartbeat_monitor_builder_with_max_update_attempts(null_mut(), 3),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            heartbeat_monitor_builder_with_thread_attribution(null_mut()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            heartbeat_monitor_builder_with_allowed_misses(null_mut(), 2),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            heartbeat_monitor_builder_with_expected_count(null_mut(), 1, 2, 500),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            heartbeat_monitor_builder_with_first_beat_deadline(null_mut(), 1000),
+            FFICode::NullParameter
+        );
+        let source_tag = SourceTag::from("source");
+        assert_eq!(
+            heartbeat_monitor_builder_add_source(null_mut(), &source_tag as *const SourceTag),
+            FFICode::NullParameter
+        );
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_add_source_null_source_tag() {
+        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+        let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
+
+        let heartbeat_monitor_builder_add_source_result =
+            heartbeat_monitor_builder_add_source(heartbeat_monitor_builder_handle, core::ptr::null());
+        assert_eq!(heartbeat_monitor_builder_add_source_result, FFICode::NullParameter);
+
+        // Clean-up.
+        heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle);
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_zero_update_attempts_fails_build() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
+        let _ = heartbeat_monitor_builder_with_max_update_attempts(heartbeat_monitor_builder_handle, 0);
+        let _ = health_monitor_builder_add_heartbeat_monitor(
+            health_monitor_builder_handle,
+            &heartbeat_monitor_tag as *const MonitorTag,
+            heartbeat_monitor_builder_handle,
+        );
+
+        let health_monitor_builder_build_result = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert_eq!(health_monitor_builder_build_result, FFICode::InvalidArgument);
+    }
+
     #[test]
     fn heartbeat_monitor_destroy_null_monitor() {
         let heartbeat_monitor_destroy_result = heartbeat_monitor_destroy(null_mut());
@@ -186,4 +356,113 @@ mod tests {
         let heartbeat_monitor_heartbeat_result = heartbeat_monitor_heartbeat(null_mut());
         assert_eq!(heartbeat_monitor_heartbeat_result, FFICode::NullParameter);
     }
+
+    /// Create health monitor with a single heartbeat monitor, returns handles of both.
+    fn create_heartbeat_monitor(source_tag: Option<&SourceTag>) -> (FFIHandle, FFIHandle) {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+        let mut heartbeat_monitor_handle: FFIHandle = null_mut();
+
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
+        if let Some(source_tag) = source_tag {
+            let _ =
+                heartbeat_monitor_builder_add_source(heartbeat_monitor_builder_handle, source_tag as *const SourceTag);
+        }
+        let _ = health_monitor_builder_add_heartbeat_monitor(
+            health_monitor_builder_handle,
+            &heartbeat_monitor_tag as *const MonitorTag,
+            heartbeat_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            200,
+            100,
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_heartbeat_monitor(
+            health_monitor_handle,
+            &heartbeat_monitor_tag as *const MonitorTag,
+            &mut heartbeat_monitor_handle as *mut FFIHandle,
+        );
+
+        (health_monitor_handle, heartbeat_monitor_handle)
+    }
+
+    #[test]
+    fn heartbeat_monitor_update_range_succeeds() {
+        let (health_monitor_handle, heartbeat_monitor_handle) = create_heartbeat_monitor(None);
+
+        assert_eq!(
+            heartbeat_monitor_update_range(heartbeat_monitor_handle, 150, 300),
+            FFICode::Success
+        );
+        // Invalid range.
+        assert_eq!(
+            heartbeat_monitor_update_range(heartbeat_monitor_handle, 300, 150),
+            FFICode::InvalidArgument
+        );
+        // Range too short for the internal processing cycle.
+        assert_eq!(
+            heartbeat_monitor_update_range(heartbeat_monitor_handle, 20, 40),
+            FFICode::InvalidArgument
+        );
+
+        // Clean-up.
+        heartbeat_monitor_destroy(heartbeat_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn heartbeat_monitor_suspend_and_resume_succeed() {
+        let (health_monitor_handle, heartbeat_monitor_handle) = create_heartbeat_monitor(None);
+
+        assert_eq!(heartbeat_monitor_suspend(heartbeat_monitor_handle), FFICode::Success);
+        assert_eq!(heartbeat_monitor_resume(heartbeat_monitor_handle), FFICode::Success);
+
+        // Clean-up.
+        heartbeat_monitor_destroy(heartbeat_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn heartbeat_monitor_source_heartbeat_succeeds() {
+        let source_tag = SourceTag::from("source");
+        let (health_monitor_handle, heartbeat_monitor_handle) = create_heartbeat_monitor(Some(&source_tag));
+
+        assert_eq!(
+            heartbeat_monitor_source_heartbeat(heartbeat_monitor_handle, &source_tag as *const SourceTag),
+            FFICode::Success
+        );
+        let unknown_source_tag = SourceTag::from("unknown");
+        assert_eq!(
+            heartbeat_monitor_source_heartbeat(heartbeat_monitor_handle, &unknown_source_tag as *const SourceTag),
+            FFICode::NotFound
+        );
+        assert_eq!(
+            heartbeat_monitor_source_heartbeat(heartbeat_monitor_handle, core::ptr::null()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        heartbeat_monitor_destroy(heartbeat_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn heartbeat_monitor_runtime_methods_null_monitor() {
+        let source_tag = SourceTag::from("source");
+        assert_eq!(
+            heartbeat_monitor_update_range(null_mut(), 100, 200),
+            FFICode::NullParameter
+        );
+        assert_eq!(heartbeat_monitor_suspend(null_mut()), FFICode::NullParameter);
+        assert_eq!(heartbeat_monitor_resume(null_mut()), FFICode::NullParameter);
+        assert_eq!(
+            heartbeat_monitor_source_heartbeat(null_mut(), &source_tag as *const SourceTag),
+            FFICode::NullParameter
+        );
+    }
 }
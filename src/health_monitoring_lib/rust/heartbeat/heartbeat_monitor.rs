@@ -12,14 +12,20 @@
 // *******************************************************************************
 
 use crate::common::{
-    duration_to_int, time_offset, Monitor, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator, TimeRange,
+    duration_to_int, range_position, time_offset, InternalFault, Monitor, MonitorEvalHandle, MonitorEvaluationError,
+    MonitorEvaluator, RangePosition, TimeRange, ViolationContext,
 };
-use crate::heartbeat::heartbeat_state::HeartbeatState;
+use crate::config_summary::ConfigDigest;
+use crate::heartbeat::heartbeat_source::{HeartbeatSource, SourceSlot};
+use crate::heartbeat::heartbeat_state::{BoundedUpdate, HeartbeatState};
+use crate::heartbeat::jitter::{HeartbeatJitterStatistics, JitterRing};
 use crate::log::{error, warn};
 use crate::protected_memory::ProtectedMemoryAllocator;
-use crate::tag::MonitorTag;
+use crate::tag::{DeadlineTag, MonitorTag, SourceTag};
+use crate::thread_attribution::{ReporterSlot, ReporterThread};
+use crate::validation::{ConfigIssue, ValidationReport};
 use crate::HealthMonitorError;
-use core::sync::atomic::{AtomicU64, Ordering};
+use core::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use core::time::Duration;
 use score_log::ScoreDebug;
 use std::sync::Arc;
@@ -27,7 +33,7 @@ use std::time::Instant;
 
 /// Heartbeat evaluation errors.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ScoreDebug)]
-pub(crate) enum HeartbeatEvaluationError {
+pub enum HeartbeatEvaluationError {
     /// Finished too early.
     TooEarly,
     /// Finished too late.
@@ -36,11 +42,60 @@ pub(crate) enum HeartbeatEvaluationError {
     MultipleHeartbeats,
 }
 
+/// Heartbeat state update statistics.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, ScoreDebug)]
+pub struct HeartbeatUpdateStatistics {
+    /// Number of update attempts repeated due to concurrent modification of the heartbeat state.
+    pub retries: u64,
+    /// Number of heartbeats dropped because the update did not land within allowed number of attempts.
+    pub failures: u64,
+}
+
+/// Errors that can occur when working with [`HeartbeatMonitor`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ScoreDebug)]
+pub enum HeartbeatMonitorError {
+    /// Producer is already active, use [`HeartbeatProducer::share`] to provide heartbeats from multiple places.
+    ProducerInUse,
+    /// Range is shorter than allowed by the internal processing cycle, see [`HeartbeatMonitor::update_range`].
+    InvalidRange,
+    /// Source is not registered with [`HeartbeatMonitorBuilder::add_source`].
+    SourceNotFound,
+}
+
 /// Builder for [`HeartbeatMonitor`].
 #[derive(Debug)]
 pub struct HeartbeatMonitorBuilder {
     /// Time range between heartbeats.
     range: TimeRange,
+
+    /// Maximum number of heartbeat state update attempts, unbounded if [`None`].
+    max_update_attempts: Option<u32>,
+
+    /// Attribute violations to the last thread providing a heartbeat.
+    thread_attribution: bool,
+
+    /// Deadline monitor and deadline providing heartbeats, see [`Self::with_deadline_source`].
+    deadline_source: Option<(MonitorTag, DeadlineTag)>,
+
+    /// Number of consecutive late or missed heartbeats tolerated before reporting an error.
+    allowed_misses: u32,
+
+    /// Number of heartbeats expected per reference window, see [`Self::with_expected_count`].
+    expected_count: Option<ExpectedCount>,
+
+    /// Sources each providing a heartbeat every cycle, see [`Self::add_source`].
+    sources: Vec<SourceTag>,
+
+    /// Startup window for the first heartbeat, see [`Self::with_first_beat_deadline`].
+    first_beat_deadline: Option<Duration>,
+}
+
+/// Number of heartbeats expected per reference window.
+#[derive(Clone, Copy, Debug)]
+struct ExpectedCount {
+    min: u32,
+    max: u32,
+    per: Duration,
 }
 
 impl HeartbeatMonitorBuilder {
@@ -48,7 +103,97 @@ impl HeartbeatMonitorBuilder {
     ///
     /// - `range` - time range between heartbeats.
     pub fn new(range: TimeRange) -> Self {
-        Self { range }
+        Self {
+            range,
+            max_update_attempts: None,
+            thread_attribution: false,
+            deadline_source: None,
+            allowed_misses: 0,
+            expected_count: None,
+            sources: Vec::new(),
+            first_beat_deadline: None,
+        }
+    }
+
+    /// Limit the number of attempts to update the heartbeat state.
+    /// By default heartbeat retries until the update lands, regardless of contention.
+    /// With a limit, a heartbeat that cannot be stored within `max_attempts` is dropped and reported as an internal fault.
+    ///
+    /// - `max_attempts` - maximum number of update attempts, must be greater than zero.
+    pub fn with_max_update_attempts(mut self, max_attempts: u32) -> Self {
+        self.with_max_update_attempts_internal(max_attempts);
+        self
+    }
+
+    /// Attribute violations to the OS thread which provided the last heartbeat.
+    /// Thread ID and name are captured once per thread and included in violation reports.
+    /// Supported on Linux only, no attribution is reported on other platforms.
+    pub fn with_thread_attribution(mut self) -> Self {
+        self.with_thread_attribution_internal();
+        self
+    }
+
+    /// Tolerate up to `allowed_misses` consecutive late or missed heartbeats before reporting an error,
+    /// e.g. to match alive supervision tolerances of AUTOSAR. Missed heartbeat is assumed at the end of the range,
+    /// late heartbeat starts the next cycle as if it was in range. Heartbeat within the range resets the count.
+    /// Early and multiple heartbeats are reported immediately. By default no miss is tolerated.
+    pub fn with_allowed_misses(mut self, allowed_misses: u32) -> Self {
+        self.with_allowed_misses_internal(allowed_misses);
+        self
+    }
+
+    /// Supervise the number of heartbeats per reference window instead of the time between heartbeats,
+    /// e.g. for components providing heartbeats in bursts. Heartbeats are counted until the first evaluation
+    /// after the end of the window, which starts the next window. Too few heartbeats are reported as
+    /// [`HeartbeatEvaluationError::TooLate`], too many as [`HeartbeatEvaluationError::MultipleHeartbeats`].
+    /// Windows with too few heartbeats count as misses tolerated with [`Self::with_allowed_misses`].
+    /// Range of the monitor is not used for evaluation.
+    ///
+    /// - `min` - minimum number of heartbeats per window.
+    /// - `max` - maximum number of heartbeats per window, must not be smaller than `min`.
+    /// - `per` - duration of the window, must be longer than the internal processing cycle.
+    pub fn with_expected_count(mut self, min: u32, max: u32, per: Duration) -> Self {
+        self.with_expected_count_internal(min, max, per);
+        self
+    }
+
+    /// Register a source which must provide a heartbeat every cycle, e.g. a worker thread of a pool.
+    /// Cycle is complete once every registered source provided a heartbeat, the last one is judged against the range.
+    /// Sources still silent after the range are reported individually. Heartbeats of the monitor itself
+    /// and of its producer are not evaluated once a source is registered.
+    /// Cannot be combined with [`Self::with_expected_count`] or [`Self::with_deadline_source`].
+    ///
+    /// - `source_tag` - unique tag of the source, registering the same tag again has no effect.
+    pub fn add_source(mut self, source_tag: SourceTag) -> Self {
+        self.add_source_internal(source_tag);
+        self
+    }
+
+    /// Require the first heartbeat within `deadline` from the start of supervision, so hung initialization
+    /// is reported instead of being judged by the range of the first cycle only. Any number of heartbeats
+    /// is accepted within the startup window, the last one evaluated starts the first cycle.
+    /// No first heartbeat within the window is reported as [`HeartbeatEvaluationError::TooLate`]
+    /// regardless of [`Self::with_allowed_misses`].
+    /// Cannot be combined with [`Self::with_expected_count`] or [`Self::add_source`].
+    ///
+    /// - `deadline` - startup window, must be longer than the internal processing cycle.
+    pub fn with_first_beat_deadline(mut self, deadline: Duration) -> Self {
+        self.with_first_beat_deadline_internal(deadline);
+        self
+    }
+
+    /// Derive heartbeats from a deadline instead of providing them explicitly, e.g. for a component
+    /// which already runs the deadline every cycle. A heartbeat is provided each time the deadline is stopped
+    /// within its range or while calibrating, so both monitors report the same activity.
+    /// Deadline holds the producer of this monitor, so [`HeartbeatMonitor::producer`] fails.
+    ///
+    /// Supported by monitors added with [`crate::HealthMonitorBuilder::add_heartbeat_monitor`] only.
+    ///
+    /// - `deadline_monitor_tag` - tag of the deadline monitor added to the same health monitor.
+    /// - `deadline_tag` - tag of the deadline providing heartbeats.
+    pub fn with_deadline_source(mut self, deadline_monitor_tag: MonitorTag, deadline_tag: DeadlineTag) -> Self {
+        self.with_deadline_source_internal(deadline_monitor_tag, deadline_tag);
+        self
     }
 
     /// Build the [`HeartbeatMonitor`].
@@ -62,19 +207,164 @@ impl HeartbeatMonitorBuilder {
         internal_processing_cycle: Duration,
         _allocator: &ProtectedMemoryAllocator,
     ) -> Result<HeartbeatMonitor, HealthMonitorError> {
+        // Deadline source is taken by the health monitor builder wiring it.
+        if let Some((deadline_monitor_tag, deadline_tag)) = self.deadline_source {
+            error!(
+                "Heartbeat monitor {:?} derived from deadline {:?} of monitor {:?} must be added to the health monitor builder.",
+                monitor_tag, deadline_tag, deadline_monitor_tag
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+
+        let mut report = ValidationReport::new();
+        self.validate(monitor_tag, internal_processing_cycle, &mut report);
+        if let Some(error) = report.error() {
+            return Err(error);
+        }
+
+        let inner = Arc::new(HeartbeatMonitorInner::new(monitor_tag, self, internal_processing_cycle));
+        Ok(HeartbeatMonitor::new(inner))
+    }
+
+    /// Check the configuration, found issues are added to the `report`.
+    ///
+    /// - `monitor_tag` - tag of this monitor.
+    /// - `internal_processing_cycle` - health monitor processing cycle.
+    /// - `report` - report to add found issues to.
+    pub(crate) fn validate(
+        &self,
+        monitor_tag: MonitorTag,
+        internal_processing_cycle: Duration,
+        report: &mut ValidationReport,
+    ) {
         // Check range is valid.
+        // Range without lower bound accepts any number of heartbeats per cycle.
+        // Range is not used when the number of heartbeats per window is supervised.
         let range_min_ms = self.range.min.as_millis() as u64;
         let internal_processing_cycle_ms = internal_processing_cycle.as_millis() as u64;
-        if range_min_ms * 2 <= internal_processing_cycle_ms {
+        if let Some(expected_count) = self.expected_count {
+            if expected_count.min > expected_count.max || expected_count.per <= internal_processing_cycle {
+                error!(
+                    "Expected heartbeat count must have minimum not greater than maximum ({} > {}) and window ({} ms) longer than internal processing cycle ({} ms).",
+                    expected_count.min,
+                    expected_count.max,
+                    expected_count.per.as_millis() as u64,
+                    internal_processing_cycle_ms
+                );
+                report.push(ConfigIssue::InvalidHeartbeatExpectedCount(monitor_tag));
+            }
+        } else if range_min_ms > 0 && range_min_ms * 2 <= internal_processing_cycle_ms {
             error!(
                 "Internal processing cycle duration ({} ms) must be shorter than two shortest allowed ranges ({} ms).",
                 internal_processing_cycle_ms, range_min_ms
             );
-            return Err(HealthMonitorError::InvalidArgument);
+            report.push(ConfigIssue::HeartbeatRangeTooShort(monitor_tag));
         }
 
-        let inner = Arc::new(HeartbeatMonitorInner::new(monitor_tag, self.range));
-        Ok(HeartbeatMonitor::new(inner))
+        if !self.sources.is_empty() && (self.expected_count.is_some() || self.deadline_source.is_some()) {
+            error!("Heartbeat sources cannot be combined with expected heartbeat count or deadline source.");
+            report.push(ConfigIssue::InvalidHeartbeatSources(monitor_tag));
+        }
+
+        if let Some(first_beat_deadline) = self.first_beat_deadline {
+            if first_beat_deadline <= internal_processing_cycle
+                || self.expected_count.is_some()
+                || !self.sources.is_empty()
+            {
+                error!(
+                    "First heartbeat deadline ({} ms) must be longer than internal processing cycle ({} ms) and cannot be combined with expected heartbeat count or heartbeat sources.",
+                    first_beat_deadline.as_millis() as u64,
+                    internal_processing_cycle_ms
+                );
+                report.push(ConfigIssue::InvalidHeartbeatFirstBeatDeadline(monitor_tag));
+            }
+        }
+
+        if self.max_update_attempts == Some(0) {
+            error!("Maximum number of heartbeat update attempts must be greater than zero.");
+            report.push(ConfigIssue::InvalidHeartbeatUpdateAttempts(monitor_tag));
+        }
+    }
+
+    pub(crate) fn has_thread_attribution(&self) -> bool {
+        self.thread_attribution
+    }
+
+    /// Configured range between heartbeats.
+    pub(crate) fn range(&self) -> TimeRange {
+        self.range
+    }
+
+    /// Deadline monitor and deadline providing heartbeats.
+    pub(crate) fn deadline_source(&self) -> Option<(MonitorTag, DeadlineTag)> {
+        self.deadline_source
+    }
+
+    /// Take deadline source to wire it up, see [`Self::with_deadline_source`].
+    pub(crate) fn take_deadline_source(&mut self) -> Option<(MonitorTag, DeadlineTag)> {
+        self.deadline_source.take()
+    }
+
+    /// Write the configuration into the `digest`.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_time_range(&self.range);
+        digest.write_option(self.max_update_attempts, |digest, max_attempts| {
+            digest.write_u64(u64::from(max_attempts))
+        });
+        digest.write_u64(u64::from(self.thread_attribution));
+        digest.write_option(self.deadline_source, |digest, (deadline_monitor_tag, deadline_tag)| {
+            digest.write_str(deadline_monitor_tag.as_str());
+            digest.write_str(deadline_tag.as_str());
+        });
+        digest.write_u64(u64::from(self.allowed_misses));
+        digest.write_option(self.expected_count, |digest, expected_count| {
+            digest.write_u64(u64::from(expected_count.min));
+            digest.write_u64(u64::from(expected_count.max));
+            digest.write_duration(expected_count.per);
+        });
+        digest.write_u64(self.sources.len() as u64);
+        for source_tag in &self.sources {
+            digest.write_str(source_tag.as_str());
+        }
+        digest.write_option(self.first_beat_deadline, |digest, first_beat_deadline| {
+            digest.write_duration(first_beat_deadline)
+        });
+    }
+
+    // Used by FFI and config parsing code which prefer not to move builder instance
+
+    pub(crate) fn with_max_update_attempts_internal(&mut self, max_attempts: u32) {
+        self.max_update_attempts = Some(max_attempts);
+    }
+
+    pub(crate) fn with_thread_attribution_internal(&mut self) {
+        self.thread_attribution = true;
+    }
+
+    pub(crate) fn with_allowed_misses_internal(&mut self, allowed_misses: u32) {
+        self.allowed_misses = allowed_misses;
+    }
+
+    pub(crate) fn with_expected_count_internal(&mut self, min: u32, max: u32, per: Duration) {
+        self.expected_count = Some(ExpectedCount { min, max, per });
+    }
+
+    pub(crate) fn add_source_internal(&mut self, source_tag: SourceTag) {
+        if !self.sources.contains(&source_tag) {
+            self.sources.push(source_tag);
+        }
+    }
+
+    pub(crate) fn with_first_beat_deadline_internal(&mut self, deadline: Duration) {
+        self.first_beat_deadline = Some(deadline);
+    }
+
+    pub(crate) fn with_deadline_source_internal(
+        &mut self,
+        deadline_monitor_tag: MonitorTag,
+        deadline_tag: DeadlineTag,
+    ) {
+        self.deadline_source = Some((deadline_monitor_tag, deadline_tag));
     }
 }
 
@@ -89,10 +379,157 @@ impl HeartbeatMonitor {
         Self { inner }
     }
 
+    /// Provide a heartbeat bypassing the producer, for sources owned by the library, e.g. the supervision hub.
+    /// Applications provide heartbeats with a [`HeartbeatProducer`] acquired with [`Self::producer`].
+    pub(crate) fn heartbeat(&self) {
+        self.inner.heartbeat()
+    }
+
+    /// Acquire the heartbeat producer of this monitor.
+    /// Only a single producer can be acquired at a time, so accidental sharing of the monitor is detected early
+    /// instead of being reported as [`HeartbeatEvaluationError::MultipleHeartbeats`].
+    /// Producer is released when all its shares are dropped.
+    ///
+    /// # Returns
+    ///  - Ok(HeartbeatProducer) - if no other producer is active.
+    ///  - Err(HeartbeatMonitorError::ProducerInUse) - if a producer is already active.
+    pub fn producer(&self) -> Result<HeartbeatProducer, HeartbeatMonitorError> {
+        if self
+            .inner
+            .active_producers
+            .compare_exchange(0, 1, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            warn!(
+                "Heartbeat producer of monitor {:?} is already in use",
+                self.inner.monitor_tag
+            );
+            return Err(HeartbeatMonitorError::ProducerInUse);
+        }
+
+        Ok(HeartbeatProducer {
+            inner: Arc::clone(&self.inner),
+        })
+    }
+
+    /// Get a handle of a source registered with [`HeartbeatMonitorBuilder::add_source`].
+    ///
+    /// # Returns
+    ///  - Ok(HeartbeatSource) - if the source is registered.
+    ///  - Err(HeartbeatMonitorError::SourceNotFound) - if the source is not registered.
+    pub fn source(&self, source_tag: SourceTag) -> Result<HeartbeatSource, HeartbeatMonitorError> {
+        match self
+            .inner
+            .sources
+            .iter()
+            .position(|slot| slot.source_tag() == source_tag)
+        {
+            Some(index) => Ok(HeartbeatSource::new(Arc::clone(&self.inner), index)),
+            None => {
+                warn!(
+                    "Source {:?} of heartbeat monitor {:?} not found",
+                    source_tag, self.inner.monitor_tag
+                );
+                Err(HeartbeatMonitorError::SourceNotFound)
+            },
+        }
+    }
+
+    /// Replace the range between heartbeats, e.g. when the application switches between operating modes
+    /// with different cycles. Evaluation uses either the previous or the new range, never a mix of both.
+    /// New range applies to the current cycle, started by the last heartbeat accepted by the evaluation.
+    /// Range is stored with milliseconds resolution, upper bound beyond [`u32::MAX`] ms is unbounded.
+    ///
+    /// # Returns
+    ///  - Ok(()) - if the range was replaced.
+    ///  - Err(HeartbeatMonitorError::InvalidRange) - if the shortest allowed range is not longer than
+    ///    half of the internal processing cycle, same as checked by the builder.
+    pub fn update_range(&self, range: TimeRange) -> Result<(), HeartbeatMonitorError> {
+        let range_min_ms: u64 = range.min_ms();
+        if range_min_ms > 0 && range_min_ms * 2 <= self.inner.internal_processing_cycle_ms {
+            warn!(
+                "Range of heartbeat monitor {:?} not updated, internal processing cycle duration ({} ms) must be shorter than two shortest allowed ranges ({} ms).",
+                self.inner.monitor_tag, self.inner.internal_processing_cycle_ms, range_min_ms
+            );
+            return Err(HeartbeatMonitorError::InvalidRange);
+        }
+
+        self.inner.range.store(&InternalRange::from(range));
+        Ok(())
+    }
+
+    /// Current range between heartbeats, in milliseconds resolution used by evaluation.
+    pub(crate) fn range(&self) -> TimeRange {
+        TimeRange::from(&self.inner.range.load())
+    }
+
+    /// Suspend evaluation of the monitor, e.g. while the application is in a mode providing no heartbeats.
+    /// Unlike disabling the monitor with the health monitor, suspension is controlled by the owner of the monitor.
+    pub fn suspend(&self) {
+        self.inner.suspended.store(true, Ordering::Release);
+    }
+
+    /// Resume evaluation of a suspended monitor. Heartbeats provided while suspended are discarded
+    /// and a new cycle starts now, so the first heartbeat after resume is judged against the time of resume.
+    pub fn resume(&self) {
+        self.inner.resume();
+    }
+
+    /// Evaluation of the monitor is suspended with [`Self::suspend`].
+    pub fn is_suspended(&self) -> bool {
+        self.inner.suspended.load(Ordering::Acquire)
+    }
+
+    /// Deviation of the last heartbeat arrivals from the nominal period, to tune the range on measured behavior.
+    /// Arrivals are recorded by the evaluation in time between heartbeats supervision only.
+    /// Returns [`None`] if no arrival was recorded yet.
+    pub fn jitter_stats(&self) -> Option<HeartbeatJitterStatistics> {
+        self.inner.jitter.statistics()
+    }
+
+    /// Heartbeat state update statistics of this monitor.
+    pub fn update_statistics(&self) -> HeartbeatUpdateStatistics {
+        HeartbeatUpdateStatistics {
+            retries: self.inner.update_retries.load(Ordering::Relaxed),
+            failures: self.inner.update_failures.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Exclusive right to provide heartbeats to a [`HeartbeatMonitor`].
+/// Acquired with [`HeartbeatMonitor::producer`].
+pub struct HeartbeatProducer {
+    inner: Arc<HeartbeatMonitorInner>,
+}
+
+impl HeartbeatProducer {
     /// Provide a heartbeat.
     pub fn heartbeat(&self) {
         self.inner.heartbeat()
     }
+
+    /// Explicitly share the producer, opting into multiple places providing heartbeats.
+    /// All shares must be dropped before the producer can be acquired again.
+    pub fn share(&self) -> Self {
+        self.inner.active_producers.fetch_add(1, Ordering::AcqRel);
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl Drop for HeartbeatProducer {
+    fn drop(&mut self) {
+        self.inner.active_producers.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl core::fmt::Debug for HeartbeatProducer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HeartbeatProducer")
+            .field("monitor_tag", &self.inner.monitor_tag)
+            .finish()
+    }
 }
 
 impl Monitor for HeartbeatMonitor {
@@ -101,8 +538,26 @@ impl Monitor for HeartbeatMonitor {
         MonitorEvalHandle::new(Arc::new(HeartbeatMonitorHandle {
             inner: Arc::clone(&self.inner),
             start_timestamp: AtomicU64::new(0),
+            reported_update_failures: AtomicU64::new(0),
+            suspended: AtomicBool::new(false),
         }))
     }
+
+    fn clone_shared(&self) -> Self {
+        Self::new(Arc::clone(&self.inner))
+    }
+
+    fn shares_state(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    fn set_enabled(&self, enabled: bool) {
+        self.inner.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.inner.enabled.load(Ordering::Relaxed)
+    }
 }
 
 struct HeartbeatMonitorHandle {
@@ -112,16 +567,56 @@ struct HeartbeatMonitorHandle {
     /// `AtomicU64` is used to allow mutability inside `Arc`.
     /// Variable is only accessed by worker thread.
     start_timestamp: AtomicU64,
+    /// Number of dropped heartbeats already reported as internal faults.
+    /// Variable is only accessed by worker thread.
+    reported_update_failures: AtomicU64,
+    /// Evaluation was skipped because the monitor is disabled, new cycle is started once enabled.
+    /// Variable is only accessed by worker thread.
+    suspended: AtomicBool,
 }
 
 impl MonitorEvaluator for HeartbeatMonitorHandle {
-    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError)) {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.inner.monitor_tag
+    }
+
+    fn evaluate(
+        &self,
+        hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
+        if !self.inner.enabled.load(Ordering::Relaxed) || self.inner.suspended.load(Ordering::Acquire) {
+            self.suspended.store(true, Ordering::Relaxed);
+            return;
+        }
+        if let Some(resume_timestamp) = self.inner.take_resume_timestamp() {
+            // Cycle started when the owner resumed the monitor.
+            self.suspended.store(false, Ordering::Relaxed);
+            self.start_timestamp.store(resume_timestamp, Ordering::Release);
+        } else if self.suspended.swap(false, Ordering::Relaxed) {
+            // Heartbeats provided while disabled are discarded, supervision resumes with a new cycle.
+            self.rebaseline(hmon_starting_point, Duration::ZERO);
+            return;
+        }
+
         let start_timestamp = self.start_timestamp.load(Ordering::Acquire);
         let evaluate_result = self.inner.evaluate(start_timestamp, hmon_starting_point, on_error);
         if let Some(new_start_timestamp) = evaluate_result {
             self.start_timestamp.store(new_start_timestamp, Ordering::Release);
         }
     }
+
+    fn collect_internal_faults(&self, on_fault: &mut dyn FnMut(&MonitorTag, InternalFault)) {
+        let update_failures = self.inner.update_failures.load(Ordering::Relaxed);
+        if self.reported_update_failures.swap(update_failures, Ordering::Relaxed) != update_failures {
+            on_fault(&self.inner.monitor_tag, InternalFault::HeartbeatUpdateExhausted);
+        }
+    }
+
+    fn rebaseline(&self, hmon_starting_point: Instant, _gap: Duration) {
+        let new_start_timestamp = self.inner.rebaseline(hmon_starting_point);
+        self.start_timestamp.store(new_start_timestamp, Ordering::Release);
+    }
 }
 
 /// Time range using [`u64`].
@@ -139,24 +634,85 @@ impl InternalRange {
     }
 
     /// Create range with values offset by timestamp.
+    /// Range without upper bound stays unbounded.
     fn offset(&self, timestamp: u64) -> Self {
         let min = self
             .min
             .checked_add(timestamp)
             .expect("offset min overflow in InternalRange");
-        let max = self
-            .max
-            .checked_add(timestamp)
-            .expect("offset max overflow in InternalRange");
+        let max = if self.max == u64::MAX {
+            u64::MAX
+        } else {
+            self.max
+                .checked_add(timestamp)
+                .expect("offset max overflow in InternalRange")
+        };
         Self::new(min, max)
     }
+
+    /// Get position of `timestamp` in relation to this range.
+    fn position(&self, timestamp: u64) -> RangePosition {
+        range_position(self.min, self.max, timestamp)
+    }
+
+    /// Nominal time between heartbeats - middle of the range, or its lower bound if unbounded.
+    fn nominal(&self) -> u64 {
+        if self.max == u64::MAX {
+            self.min
+        } else {
+            self.min + (self.max - self.min) / 2
+        }
+    }
+}
+
+impl From<&InternalRange> for TimeRange {
+    fn from(value: &InternalRange) -> Self {
+        let min = Duration::from_millis(value.min);
+        if value.max == u64::MAX {
+            TimeRange::at_least(min)
+        } else {
+            TimeRange::new(min, Duration::from_millis(value.max))
+        }
+    }
 }
 
 impl From<TimeRange> for InternalRange {
     fn from(value: TimeRange) -> Self {
-        let min = duration_to_int(value.min);
-        let max = duration_to_int(value.max);
-        Self::new(min, max)
+        Self::new(value.min_ms(), value.max_ms_or_unbounded())
+    }
+}
+
+/// Atomic representation of [`InternalRange`], so the range can be replaced while the monitor is evaluated.
+/// Layout (u64) = | min: 32 bits | max: 32 bits |
+/// Bounds are saturated to [`u32::MAX`], which is an unbounded upper bound.
+struct AtomicRange(AtomicU64);
+
+const RANGE_MIN_OFFSET: u32 = 32;
+const RANGE_MAX_MASK: u64 = 0x00000000_FFFFFFFF;
+
+impl AtomicRange {
+    fn new(range: &InternalRange) -> Self {
+        Self(AtomicU64::new(Self::pack(range)))
+    }
+
+    fn load(&self) -> InternalRange {
+        let value = self.0.load(Ordering::Acquire);
+        let min = value >> RANGE_MIN_OFFSET;
+        let max = match value & RANGE_MAX_MASK {
+            RANGE_MAX_MASK => u64::MAX,
+            max => max,
+        };
+        InternalRange::new(min, max)
+    }
+
+    fn store(&self, range: &InternalRange) {
+        self.0.store(Self::pack(range), Ordering::Release);
+    }
+
+    fn pack(range: &InternalRange) -> u64 {
+        let min = u64::from(u32::try_from(range.min).unwrap_or(u32::MAX));
+        let max = u64::from(u32::try_from(range.max).unwrap_or(u32::MAX));
+        (min << RANGE_MIN_OFFSET) | max
     }
 }
 
@@ -164,8 +720,11 @@ pub(crate) struct HeartbeatMonitorInner {
     /// Tag of this monitor.
     monitor_tag: MonitorTag,
 
-    /// Time range between heartbeats.
-    range: InternalRange,
+    /// Time range between heartbeats, replaced with [`HeartbeatMonitor::update_range`].
+    range: AtomicRange,
+
+    /// Internal processing cycle in milliseconds, used to check replaced ranges.
+    internal_processing_cycle_ms: u64,
 
     /// Monitor starting point.
     monitor_starting_point: Instant,
@@ -173,17 +732,105 @@ pub(crate) struct HeartbeatMonitorInner {
     /// Current heartbeat state.
     /// Contains data in relation to [`Self::monitor_starting_point`].
     heartbeat_state: HeartbeatState,
+
+    /// Maximum number of heartbeat state update attempts.
+    max_update_attempts: u32,
+
+    /// Number of repeated heartbeat state update attempts.
+    update_retries: AtomicU64,
+
+    /// Number of heartbeats dropped due to exhausted update attempts.
+    update_failures: AtomicU64,
+
+    /// Last thread providing a heartbeat, [`None`] if thread attribution is disabled.
+    reporter: Option<ReporterSlot>,
+
+    /// Number of active producer shares, zero if no producer is acquired.
+    active_producers: AtomicU32,
+
+    /// Evaluation of the monitor is enabled.
+    enabled: AtomicBool,
+
+    /// Evaluation of the monitor is suspended by its owner, see [`HeartbeatMonitor::suspend`].
+    suspended: AtomicBool,
+
+    /// Timestamp at which the monitor was resumed by its owner and not evaluated since, [`NOT_RESUMED`] otherwise.
+    resume_timestamp: AtomicU64,
+
+    /// Number of consecutive late or missed heartbeats tolerated before reporting an error.
+    allowed_misses: u32,
+
+    /// Number of consecutive late or missed heartbeats.
+    /// Variable is only accessed by worker thread.
+    consecutive_misses: AtomicU32,
+
+    /// Number of heartbeats expected per reference window, [`None`] if time between heartbeats is supervised.
+    expected_count: Option<InternalExpectedCount>,
+
+    /// Number of heartbeats provided in the current reference window.
+    heartbeat_count: AtomicU32,
+
+    /// Deviations of the last heartbeat arrivals from the nominal period.
+    jitter: JitterRing,
+
+    /// Sources each providing a heartbeat every cycle, empty if heartbeats of the monitor are evaluated.
+    sources: Box<[SourceSlot]>,
+
+    /// Startup window for the first heartbeat in milliseconds, [`None`] if the first cycle is judged by the range.
+    first_beat_deadline: Option<u64>,
+
+    /// First heartbeat is not yet accepted within [`Self::first_beat_deadline`].
+    /// Variable is only accessed by worker thread.
+    awaiting_first_beat: AtomicBool,
+}
+
+/// Value of [`HeartbeatMonitorInner::resume_timestamp`] if the monitor was not resumed.
+const NOT_RESUMED: u64 = u64::MAX;
+
+/// Number of heartbeats expected per reference window, with window duration in milliseconds.
+struct InternalExpectedCount {
+    min: u32,
+    max: u32,
+    per: u64,
+}
+
+impl From<ExpectedCount> for InternalExpectedCount {
+    fn from(value: ExpectedCount) -> Self {
+        Self {
+            min: value.min,
+            max: value.max,
+            per: duration_to_int(value.per),
+        }
+    }
 }
 
 impl HeartbeatMonitorInner {
-    fn new(monitor_tag: MonitorTag, range: TimeRange) -> Self {
+    /// Create the monitor state from a validated `builder`.
+    fn new(monitor_tag: MonitorTag, builder: HeartbeatMonitorBuilder, internal_processing_cycle: Duration) -> Self {
         let monitor_starting_point = Instant::now();
         let heartbeat_state = HeartbeatState::new();
         Self {
             monitor_tag,
-            range: InternalRange::from(range),
+            range: AtomicRange::new(&InternalRange::from(builder.range)),
+            internal_processing_cycle_ms: duration_to_int(internal_processing_cycle),
             monitor_starting_point,
             heartbeat_state,
+            max_update_attempts: builder.max_update_attempts.unwrap_or(u32::MAX),
+            update_retries: AtomicU64::new(0),
+            update_failures: AtomicU64::new(0),
+            reporter: builder.thread_attribution.then(ReporterSlot::new),
+            active_producers: AtomicU32::new(0),
+            enabled: AtomicBool::new(true),
+            suspended: AtomicBool::new(false),
+            resume_timestamp: AtomicU64::new(NOT_RESUMED),
+            allowed_misses: builder.allowed_misses,
+            consecutive_misses: AtomicU32::new(0),
+            expected_count: builder.expected_count.map(InternalExpectedCount::from),
+            heartbeat_count: AtomicU32::new(0),
+            jitter: JitterRing::new(),
+            sources: builder.sources.into_iter().map(SourceSlot::new).collect(),
+            first_beat_deadline: builder.first_beat_deadline.map(duration_to_int),
+            awaiting_first_beat: AtomicBool::new(builder.first_beat_deadline.is_some()),
         }
     }
 
@@ -192,25 +839,126 @@ impl HeartbeatMonitorInner {
         // Get current timestamp.
         let monitor_now = duration_to_int(self.monitor_starting_point.elapsed());
 
+        if let Some(reporter) = &self.reporter {
+            reporter.record();
+        }
+
         // Set heartbeat timestamp and update counter.
-        let _ = self.heartbeat_state.update(|mut current_state| {
-            current_state.set_heartbeat_timestamp(monitor_now);
-            current_state.increment_counter();
-            Some(current_state)
-        });
+        let (outcome, retries) = self
+            .heartbeat_state
+            .update_bounded(self.max_update_attempts, |mut current_state| {
+                current_state.set_heartbeat_timestamp(monitor_now);
+                current_state.increment_counter();
+                Some(current_state)
+            });
+
+        if retries > 0 {
+            self.update_retries.fetch_add(retries as u64, Ordering::Relaxed);
+        }
+        if let BoundedUpdate::Exhausted = outcome {
+            self.update_failures.fetch_add(1, Ordering::Relaxed);
+            error!(
+                "Internal fault: heartbeat of monitor {:?} dropped, state update did not land within {} attempts",
+                self.monitor_tag, self.max_update_attempts
+            );
+        } else if self.expected_count.is_some() {
+            self.heartbeat_count.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+
+    /// Provide a heartbeat of the source under `index`.
+    pub(super) fn source_heartbeat(&self, index: usize) {
+        let monitor_now = duration_to_int(self.monitor_starting_point.elapsed());
+
+        if let Some(reporter) = &self.reporter {
+            reporter.record();
+        }
+
+        self.sources[index].record(monitor_now);
+    }
+
+    /// Tag of the source under `index`.
+    pub(super) fn source_tag(&self, index: usize) -> SourceTag {
+        self.sources[index].source_tag()
+    }
+
+    /// Resume evaluation suspended by the owner, starting a new cycle now.
+    fn resume(&self) {
+        if !self.suspended.load(Ordering::Acquire) {
+            return;
+        }
+
+        let monitor_now = duration_to_int(self.monitor_starting_point.elapsed());
+        let _ = self.heartbeat_state.reset();
+        self.heartbeat_count.store(0, Ordering::Release);
+        self.sources.iter().for_each(SourceSlot::clear);
+        self.resume_timestamp.store(monitor_now, Ordering::Release);
+        self.suspended.store(false, Ordering::Release);
+    }
+
+    /// Take the timestamp at which the owner resumed the monitor, [`None`] if not resumed since last evaluation.
+    fn take_resume_timestamp(&self) -> Option<u64> {
+        match self.resume_timestamp.swap(NOT_RESUMED, Ordering::AcqRel) {
+            NOT_RESUMED => None,
+            resume_timestamp => {
+                self.consecutive_misses.store(0, Ordering::Relaxed);
+                Some(resume_timestamp)
+            },
+        }
+    }
+
+    /// Last thread providing a heartbeat.
+    fn last_reporter(&self) -> Option<ReporterThread> {
+        self.reporter.as_ref().and_then(ReporterSlot::load)
+    }
+
+    /// Context of a violation outside of the range by `offset_ms`.
+    fn violation_context(&self, offset_ms: u64) -> ViolationContext {
+        ViolationContext {
+            offset: Some(Duration::from_millis(offset_ms)),
+            ..self.last_reporter().into()
+        }
+    }
+
+    /// Start a new cycle now, heartbeats provided so far are discarded.
+    /// Returns start timestamp of the new cycle.
+    fn rebaseline(&self, hmon_starting_point: Instant) -> u64 {
+        let offset: u64 = time_offset(hmon_starting_point, self.monitor_starting_point)
+            .expect("HMON starting point is earlier than monitor starting point");
+        let _ = self.heartbeat_state.reset();
+        self.heartbeat_count.store(0, Ordering::Release);
+        self.sources.iter().for_each(SourceSlot::clear);
+        self.consecutive_misses.store(0, Ordering::Relaxed);
+        offset + duration_to_int::<u64>(hmon_starting_point.elapsed())
+    }
+
+    /// Count a late or missed heartbeat, returns whether it is tolerated.
+    fn tolerate_miss(&self) -> bool {
+        let misses = self.consecutive_misses.load(Ordering::Relaxed).saturating_add(1);
+        self.consecutive_misses.store(misses, Ordering::Relaxed);
+        misses <= self.allowed_misses
     }
 
     pub fn evaluate(
         &self,
         start_timestamp: u64,
         hmon_starting_point: Instant,
-        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError),
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
     ) -> Option<u64> {
         // Get current timestamp, with offset to HMON time.
         let offset = time_offset(hmon_starting_point, self.monitor_starting_point)
             .expect("HMON starting point is earlier than monitor starting point");
         let monitor_now = offset + duration_to_int::<u64>(hmon_starting_point.elapsed());
 
+        if let Some(expected_count) = &self.expected_count {
+            let start_timestamp = if start_timestamp > 0 { start_timestamp } else { offset };
+            return self.evaluate_count(expected_count, start_timestamp, monitor_now, on_error);
+        }
+        if !self.sources.is_empty() {
+            let start_timestamp = if start_timestamp > 0 { start_timestamp } else { offset };
+            return self.evaluate_sources(start_timestamp, monitor_now, on_error);
+        }
+
         // Load and reset current monitor state.
         let snapshot = self.heartbeat_state.reset();
 
@@ -222,50 +970,245 @@ impl HeartbeatMonitorInner {
         let start_timestamp = if start_timestamp > 0 { start_timestamp } else { offset };
         let heartbeat_timestamp = snapshot.heartbeat_timestamp();
 
-        // Get allowed time range as absolute values.
-        let range = self.range.offset(start_timestamp);
+        if let Some(first_beat_deadline) = self.first_beat_deadline {
+            if self.awaiting_first_beat.load(Ordering::Relaxed) {
+                let deadline = start_timestamp.saturating_add(first_beat_deadline);
+                return self.evaluate_first_beat(
+                    deadline,
+                    snapshot.counter(),
+                    heartbeat_timestamp,
+                    monitor_now,
+                    on_error,
+                );
+            }
+        }
+
+        // Get allowed time range as absolute values.
+        // Range is loaded once, so a range replaced concurrently applies from the next evaluation.
+        let cycle_range = self.range.load();
+        let range = cycle_range.offset(start_timestamp);
 
         // Check current counter state.
         let counter = snapshot.counter();
-        // Disallow multiple heartbeats in same heartbeat cycle.
-        if counter > 1 {
+        // Disallow multiple heartbeats in same heartbeat cycle, unless range has no lower bound.
+        if counter > 1 && cycle_range.min > 0 {
             warn!("Multiple heartbeats detected");
-            on_error(&self.monitor_tag, HeartbeatEvaluationError::MultipleHeartbeats.into());
+            on_error(
+                &self.monitor_tag,
+                HeartbeatEvaluationError::MultipleHeartbeats.into(),
+                self.last_reporter().into(),
+            );
             return None;
         }
         // Handle no heartbeats.
         else if counter == 0 {
             // No heartbeats after time range is an error.
             // Otherwise it's accepted, but function should not continue.
-            if monitor_now > range.max {
-                let offset = monitor_now - range.max;
+            if let RangePosition::After(offset) = range.position(monitor_now) {
+                if self.tolerate_miss() {
+                    warn!(
+                        "No heartbeat detected, miss tolerated, observed after range: {}",
+                        offset
+                    );
+                    // Missed heartbeat is assumed at the end of the range.
+                    return Some(range.max);
+                }
                 warn!("No heartbeat detected, observed after range: {}", offset);
-                on_error(&self.monitor_tag, HeartbeatEvaluationError::TooLate.into());
+                on_error(
+                    &self.monitor_tag,
+                    HeartbeatEvaluationError::TooLate.into(),
+                    self.violation_context(offset),
+                );
             }
             // Either way - execution is stopped here.
             return None;
         }
 
+        self.judge_heartbeat(&cycle_range, start_timestamp, heartbeat_timestamp, on_error)
+    }
+
+    /// Judge the heartbeat completing the cycle started at `start_timestamp` against the `cycle_range`.
+    /// Returns start timestamp of the next cycle, [`None`] if the heartbeat violates the range.
+    fn judge_heartbeat(
+        &self,
+        cycle_range: &InternalRange,
+        start_timestamp: u64,
+        heartbeat_timestamp: u64,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) -> Option<u64> {
+        let range = cycle_range.offset(start_timestamp);
+
+        // Record arrival of the heartbeat in relation to the cycle start.
+        self.jitter.record(
+            heartbeat_timestamp as i64 - start_timestamp as i64,
+            cycle_range.nominal() as i64,
+        );
+
         // Check current heartbeat state.
-        // Heartbeat before allowed range.
-        if heartbeat_timestamp < range.min {
-            let offset = range.min - heartbeat_timestamp;
-            warn!("Heartbeat occurred too early, offset to range: {}", offset);
-            on_error(&self.monitor_tag, HeartbeatEvaluationError::TooEarly.into());
-            None
-        }
-        // Heartbeat after allowed range.
-        else if heartbeat_timestamp > range.max {
-            let offset = heartbeat_timestamp - range.max;
-            warn!("Heartbeat occurred too late, offset to range: {}", offset);
-            on_error(&self.monitor_tag, HeartbeatEvaluationError::TooLate.into());
-            None
-        }
-        // Heartbeat in allowed state.
-        else {
+        match range.position(heartbeat_timestamp) {
+            // Heartbeat before allowed range.
+            RangePosition::Before(offset) => {
+                warn!("Heartbeat occurred too early, offset to range: {}", offset);
+                on_error(
+                    &self.monitor_tag,
+                    HeartbeatEvaluationError::TooEarly.into(),
+                    self.violation_context(offset),
+                );
+                None
+            },
+            // Heartbeat after allowed range.
+            RangePosition::After(offset) if self.tolerate_miss() => {
+                warn!(
+                    "Heartbeat occurred too late, miss tolerated, offset to range: {}",
+                    offset
+                );
+                Some(heartbeat_timestamp)
+            },
+            RangePosition::After(offset) => {
+                warn!("Heartbeat occurred too late, offset to range: {}", offset);
+                on_error(
+                    &self.monitor_tag,
+                    HeartbeatEvaluationError::TooLate.into(),
+                    self.violation_context(offset),
+                );
+                None
+            },
+            // Heartbeat in allowed state.
             // Update heartbeat monitor state with a current heartbeat as a beginning of a new cycle.
-            Some(heartbeat_timestamp)
+            RangePosition::Within => {
+                self.consecutive_misses.store(0, Ordering::Relaxed);
+                Some(heartbeat_timestamp)
+            },
+        }
+    }
+
+    /// Evaluate the startup window ending at `deadline`, the first heartbeat accepted starts the first cycle.
+    /// Returns start timestamp of the first cycle.
+    fn evaluate_first_beat(
+        &self,
+        deadline: u64,
+        counter: u8,
+        heartbeat_timestamp: u64,
+        monitor_now: u64,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) -> Option<u64> {
+        // Heartbeat provided within the startup window, regardless of the range.
+        if counter > 0 && heartbeat_timestamp <= deadline {
+            self.awaiting_first_beat.store(false, Ordering::Relaxed);
+            return Some(heartbeat_timestamp);
         }
+
+        let observed = if counter > 0 { heartbeat_timestamp } else { monitor_now };
+        if observed > deadline {
+            let offset = observed - deadline;
+            warn!(
+                "No first heartbeat detected within startup deadline, offset: {}",
+                offset
+            );
+            on_error(
+                &self.monitor_tag,
+                HeartbeatEvaluationError::TooLate.into(),
+                self.violation_context(offset),
+            );
+        }
+        None
+    }
+
+    /// Evaluate heartbeats of all sources, cycle started at `start_timestamp` is complete once every source
+    /// provided a heartbeat. Returns start timestamp of the next cycle.
+    fn evaluate_sources(
+        &self,
+        start_timestamp: u64,
+        monitor_now: u64,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) -> Option<u64> {
+        let cycle_range = self.range.load();
+
+        // Cycle is completed by the last source providing a heartbeat.
+        let completion = self
+            .sources
+            .iter()
+            .try_fold(0, |completion, slot| slot.load().map(|beat| completion.max(beat)));
+        let Some(completion) = completion else {
+            let range = cycle_range.offset(start_timestamp);
+            if let RangePosition::After(offset) = range.position(monitor_now) {
+                if self.tolerate_miss() {
+                    warn!(
+                        "Heartbeat of a source not detected, miss tolerated, observed after range: {}",
+                        offset
+                    );
+                    // Missed heartbeats are assumed at the end of the range.
+                    self.sources.iter().for_each(SourceSlot::clear);
+                    return Some(range.max);
+                }
+                for slot in self.sources.iter().filter(|slot| slot.load().is_none()) {
+                    warn!(
+                        "No heartbeat of source {:?} detected, observed after range: {}",
+                        slot.source_tag(),
+                        offset
+                    );
+                    on_error(
+                        &self.monitor_tag,
+                        HeartbeatEvaluationError::TooLate.into(),
+                        ViolationContext {
+                            source_tag: Some(slot.source_tag()),
+                            ..self.violation_context(offset)
+                        },
+                    );
+                }
+            }
+            return None;
+        };
+
+        // Heartbeats provided after the completion are kept for the next cycle.
+        for slot in self.sources.iter() {
+            slot.complete(completion);
+        }
+        self.judge_heartbeat(&cycle_range, start_timestamp, completion, on_error)
+    }
+
+    /// Evaluate the number of heartbeats once the reference window started at `start_timestamp` is over.
+    /// Returns start timestamp of the next window.
+    fn evaluate_count(
+        &self,
+        expected_count: &InternalExpectedCount,
+        start_timestamp: u64,
+        monitor_now: u64,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) -> Option<u64> {
+        if monitor_now < start_timestamp.saturating_add(expected_count.per) {
+            return None;
+        }
+
+        // Heartbeats provided from now on are counted in the next window.
+        let _ = self.heartbeat_state.reset();
+        let count = self.heartbeat_count.swap(0, Ordering::AcqRel);
+        if count < expected_count.min {
+            if self.tolerate_miss() {
+                warn!(
+                    "Too few heartbeats detected, miss tolerated: {} < {}",
+                    count, expected_count.min
+                );
+            } else {
+                warn!("Too few heartbeats detected: {} < {}", count, expected_count.min);
+                on_error(
+                    &self.monitor_tag,
+                    HeartbeatEvaluationError::TooLate.into(),
+                    self.last_reporter().into(),
+                );
+            }
+        } else if count > expected_count.max {
+            warn!("Too many heartbeats detected: {} > {}", count, expected_count.max);
+            on_error(
+                &self.monitor_tag,
+                HeartbeatEvaluationError::MultipleHeartbeats.into(),
+                self.last_reporter().into(),
+            );
+        } else {
+            self.consecutive_misses.store(0, Ordering::Relaxed);
+        }
+
+        Some(monitor_now)
     }
 }
 
@@ -292,11 +1235,16 @@ mod test_common {
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::{Monitor, MonitorEvaluationError, MonitorEvaluator, TimeRange};
+    use crate::common::{
+        InternalFault, Monitor, MonitorEvaluationError, MonitorEvaluator, TimeRange, ViolationContext,
+    };
     use crate::heartbeat::heartbeat_monitor::test_common::{range_from_ms, sleep_until, TAG};
-    use crate::heartbeat::{HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder};
+    use crate::heartbeat::{
+        HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder, HeartbeatMonitorError,
+        HeartbeatUpdateStatistics,
+    };
     use crate::protected_memory::ProtectedMemoryAllocator;
-    use crate::tag::MonitorTag;
+    use crate::tag::{DeadlineTag, MonitorTag, SourceTag};
     use crate::HealthMonitorError;
     use core::sync::atomic::{AtomicBool, Ordering};
     use core::time::Duration;
@@ -324,6 +1272,181 @@ mod tests {
         assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
     }
 
+    #[test]
+    fn heartbeat_monitor_builder_build_invalid_max_update_attempts() {
+        let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
+        let monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let internal_processing_cycle = Duration::from_millis(100);
+        let allocator = ProtectedMemoryAllocator {};
+        let result = HeartbeatMonitorBuilder::new(range).with_max_update_attempts(0).build(
+            monitor_tag,
+            internal_processing_cycle,
+            &allocator,
+        );
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_build_deadline_source_not_wired() {
+        let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
+        let allocator = ProtectedMemoryAllocator {};
+        let result = HeartbeatMonitorBuilder::new(range)
+            .with_deadline_source(MonitorTag::from("deadline_monitor"), DeadlineTag::from("deadline"))
+            .build(
+                MonitorTag::from("heartbeat_monitor"),
+                Duration::from_millis(100),
+                &allocator,
+            );
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn heartbeat_monitor_producer_exclusive() {
+        let monitor = create_monitor_single_cycle(range_from_ms(80, 120));
+        let producer = monitor.producer().unwrap();
+        assert_eq!(monitor.producer().err(), Some(HeartbeatMonitorError::ProducerInUse));
+
+        drop(producer);
+        assert!(monitor.producer().is_ok());
+    }
+
+    #[test]
+    fn heartbeat_monitor_producer_share() {
+        let monitor = create_monitor_single_cycle(range_from_ms(80, 120));
+        let producer = monitor.producer().unwrap();
+        let shared = producer.share();
+
+        // Acquisition is rejected until all shares are dropped.
+        drop(producer);
+        assert_eq!(monitor.producer().err(), Some(HeartbeatMonitorError::ProducerInUse));
+        drop(shared);
+        assert!(monitor.producer().is_ok());
+    }
+
+    #[test]
+    fn heartbeat_monitor_producer_heartbeat() {
+        let monitor = create_monitor_single_cycle(range_from_ms(80, 120));
+        let hmon_starting_point = Instant::now();
+        let producer = monitor.producer().unwrap();
+
+        sleep_until(Duration::from_millis(100), hmon_starting_point);
+        producer.heartbeat();
+
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
+    }
+
+    #[test]
+    fn heartbeat_monitor_update_statistics_concurrent_heartbeats() {
+        let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
+        let monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let internal_processing_cycle = Duration::from_millis(100);
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = Arc::new(
+            HeartbeatMonitorBuilder::new(range)
+                .build(monitor_tag, internal_processing_cycle, &allocator)
+                .unwrap(),
+        );
+        assert_eq!(monitor.update_statistics(), HeartbeatUpdateStatistics::default());
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let monitor = monitor.clone();
+                spawn(move || {
+                    for _ in 0..1000 {
+                        monitor.heartbeat();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        // Unbounded mode - every heartbeat must land, retries depend on scheduling.
+        assert_eq!(monitor.update_statistics().failures, 0);
+    }
+
+    #[test]
+    fn heartbeat_monitor_reports_dropped_heartbeats_as_internal_fault() {
+        let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
+        let monitor = HeartbeatMonitorBuilder::new(range)
+            .build(
+                MonitorTag::from("heartbeat_monitor"),
+                Duration::from_millis(100),
+                &ProtectedMemoryAllocator {},
+            )
+            .unwrap();
+        let handle = monitor.get_eval_handle();
+        let collect = || {
+            let mut faults = vec![];
+            handle.collect_internal_faults(&mut |_, fault| faults.push(fault));
+            faults
+        };
+        assert!(collect().is_empty());
+
+        // Simulate heartbeats dropped due to exhausted update attempts.
+        monitor.inner.update_failures.fetch_add(2, Ordering::Relaxed);
+        assert_eq!(collect(), vec![InternalFault::HeartbeatUpdateExhausted]);
+        assert!(collect().is_empty());
+    }
+
+    #[test]
+    fn heartbeat_monitor_without_thread_attribution_reports_no_thread() {
+        let monitor = create_monitor_single_cycle(range_from_ms(10, 20));
+        let hmon_starting_point = Instant::now();
+        monitor.heartbeat();
+        monitor.heartbeat();
+
+        let mut reporters = vec![];
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |_, _, context| {
+                reporters.push(context.reporter)
+            });
+        assert_eq!(reporters, vec![None]);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn heartbeat_monitor_thread_attribution() {
+        let monitor = HeartbeatMonitorBuilder::new(range_from_ms(10, 20))
+            .with_thread_attribution()
+            .build(
+                MonitorTag::from(TAG),
+                Duration::from_millis(1),
+                &ProtectedMemoryAllocator {},
+            )
+            .unwrap();
+        let hmon_starting_point = Instant::now();
+        std::thread::scope(|scope| {
+            std::thread::Builder::new()
+                .name("hb_producer".to_string())
+                .spawn_scoped(scope, || {
+                    monitor.heartbeat();
+                    monitor.heartbeat();
+                })
+                .unwrap();
+        });
+
+        let mut reporters = vec![];
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |_, error, context| {
+                assert_eq!(
+                    error,
+                    MonitorEvaluationError::Heartbeat(HeartbeatEvaluationError::MultipleHeartbeats)
+                );
+                reporters.push(context.reporter);
+            });
+        assert_eq!(reporters.len(), 1);
+        let reporter = reporters[0].expect("reporter not attributed");
+        assert_eq!(reporter.name(), Some("hb_producer"));
+    }
+
     fn create_monitor_single_cycle(range: TimeRange) -> HeartbeatMonitor {
         let monitor_tag = MonitorTag::from(TAG);
         let internal_processing_cycle = Duration::from_millis(1);
@@ -342,11 +1465,63 @@ mod tests {
         // No beat happened, no error is expected.
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_rebaseline_starts_new_cycle() {
+        let range = range_from_ms(80, 120);
+        let monitor = create_monitor_single_cycle(range);
+        let hmon_starting_point = Instant::now();
+        let eval_handle = monitor.get_eval_handle();
+
+        // Beat is provided and range is exceeded during the gap.
+        monitor.heartbeat();
+        sleep_until(Duration::from_millis(150), hmon_starting_point);
+        eval_handle.rebaseline(hmon_starting_point, Duration::from_millis(150));
+
+        // Cycle starts after the gap, discarded beat is not evaluated.
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+        });
+        sleep_until(Duration::from_millis(250), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+        });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_disabled_starts_new_cycle_when_enabled() {
+        let range = range_from_ms(80, 120);
+        let monitor = create_monitor_single_cycle(range);
+        let hmon_starting_point = Instant::now();
+        let eval_handle = monitor.get_eval_handle();
+
+        // Disabled monitor is not evaluated, range is exceeded without errors.
+        monitor.set_enabled(false);
+        monitor.heartbeat();
+        sleep_until(Duration::from_millis(150), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+        });
+
+        // First evaluation after enabling starts a new cycle.
+        monitor.set_enabled(true);
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+        });
+        sleep_until(Duration::from_millis(250), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+        });
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn heartbeat_monitor_no_beat_evaluate_in_range() {
@@ -360,11 +1535,68 @@ mod tests {
         // No beat happened, no error is expected.
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_at_least_no_beat_never_late() {
+        let monitor = create_monitor_single_cycle(TimeRange::at_least(Duration::from_millis(80)));
+        let hmon_starting_point = Instant::now();
+
+        sleep_until(Duration::from_millis(150), hmon_starting_point);
+
+        // No upper bound, no error is expected.
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
     }
 
+    #[test]
+    fn heartbeat_monitor_at_least_beat_early() {
+        let monitor = create_monitor_single_cycle(TimeRange::at_least(Duration::from_millis(80)));
+        let hmon_starting_point = Instant::now();
+
+        monitor.heartbeat();
+
+        let mut errors = Vec::new();
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooEarly.into()]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_at_most_multiple_beats() {
+        let monitor_tag = MonitorTag::from(TAG);
+        let internal_processing_cycle = Duration::from_millis(100);
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = HeartbeatMonitorBuilder::new(TimeRange::at_most(Duration::from_millis(120)))
+            .build(monitor_tag, internal_processing_cycle, &allocator)
+            .unwrap();
+        let hmon_starting_point = Instant::now();
+        let eval_handle = monitor.get_eval_handle();
+
+        // Beats faster than the processing cycle are accepted.
+        monitor.heartbeat();
+        monitor.heartbeat();
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+        });
+
+        // Upper bound is still supervised.
+        sleep_until(Duration::from_millis(150), hmon_starting_point);
+        let mut errors = Vec::new();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooLate.into()]);
+    }
+
     #[test]
     fn heartbeat_monitor_no_beat_evaluate_late() {
         let range = range_from_ms(80, 120);
@@ -377,16 +1609,322 @@ mod tests {
         // No beat happened, too late error is expected.
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
             });
     }
 
+    #[test]
+    fn heartbeat_monitor_allowed_misses() {
+        let range = range_from_ms(40, 80);
+        let monitor_tag = MonitorTag::from(TAG);
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = HeartbeatMonitorBuilder::new(range)
+            .with_allowed_misses(2)
+            .build(monitor_tag, Duration::from_millis(1), &allocator)
+            .unwrap();
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+        let mut errors = vec![];
+
+        // Missed heartbeat is assumed at 80 ms, late heartbeat at 180 ms starts the next cycle.
+        sleep_until(Duration::from_millis(90), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        sleep_until(Duration::from_millis(180), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // Heartbeat within the range resets the count.
+        sleep_until(Duration::from_millis(240), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // Third consecutive miss is reported.
+        for miss_time in [330, 410, 490] {
+            sleep_until(Duration::from_millis(miss_time), hmon_starting_point);
+            eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        }
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_update_range() {
+        let monitor = create_monitor_single_cycle(range_from_ms(80, 120));
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+
+        // Heartbeat is early for the previous range, within the new one.
+        monitor.update_range(range_from_ms(40, 80)).unwrap();
+        assert_eq!(monitor.range(), range_from_ms(40, 80));
+        sleep_until(Duration::from_millis(50), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+        });
+
+        // Range without upper bound stays unbounded.
+        monitor
+            .update_range(TimeRange::at_least(Duration::from_millis(40)))
+            .unwrap();
+        assert_eq!(monitor.range(), TimeRange::at_least(Duration::from_millis(40)));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_suspend_and_resume() {
+        let monitor = create_monitor_single_cycle(range_from_ms(80, 120));
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+        let mut errors = vec![];
+
+        // Missing heartbeats are not reported while suspended.
+        monitor.suspend();
+        assert!(monitor.is_suspended());
+        sleep_until(Duration::from_millis(150), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // Cycle starts on resume, heartbeat is judged against it.
+        monitor.resume();
+        assert!(!monitor.is_suspended());
+        sleep_until(Duration::from_millis(200), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        sleep_until(Duration::from_millis(250), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // Supervision continues after the resumed cycle.
+        sleep_until(Duration::from_millis(400), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_jitter_stats() {
+        let monitor = create_monitor_single_cycle(range_from_ms(80, 120));
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+        assert_eq!(monitor.jitter_stats(), None);
+
+        // Arrivals 90 ms and 110 ms after the cycle start, nominal period is 100 ms.
+        for beat_time in [90, 200] {
+            sleep_until(Duration::from_millis(beat_time), hmon_starting_point);
+            monitor.heartbeat();
+            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
+        }
+
+        let jitter_stats = monitor.jitter_stats().unwrap();
+        assert_eq!(jitter_stats.samples, 2);
+        assert!((-10..0).contains(&jitter_stats.min_deviation_ms));
+        assert!((5..20).contains(&jitter_stats.max_deviation_ms));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_sources() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = HeartbeatMonitorBuilder::new(range_from_ms(80, 120))
+            .add_source(SourceTag::from("worker_1"))
+            .add_source(SourceTag::from("worker_2"))
+            .build(MonitorTag::from(TAG), Duration::from_millis(1), &allocator)
+            .unwrap();
+        let worker_1 = monitor.source(SourceTag::from("worker_1")).unwrap();
+        let worker_2 = monitor.source(SourceTag::from("worker_2")).unwrap();
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+        let mut errors = vec![];
+
+        // Cycle is completed by the last source, within the range.
+        sleep_until(Duration::from_millis(50), hmon_starting_point);
+        worker_1.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, context| {
+            errors.push((error, context.source_tag))
+        });
+        sleep_until(Duration::from_millis(90), hmon_starting_point);
+        worker_2.clone().heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, context| {
+            errors.push((error, context.source_tag))
+        });
+        assert!(errors.is_empty());
+
+        // Silent source is reported.
+        sleep_until(Duration::from_millis(180), hmon_starting_point);
+        worker_1.heartbeat();
+        sleep_until(Duration::from_millis(230), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, context| {
+            errors.push((error, context.source_tag))
+        });
+        assert_eq!(
+            errors,
+            vec![(
+                HeartbeatEvaluationError::TooLate.into(),
+                Some(SourceTag::from("worker_2"))
+            )]
+        );
+    }
+
+    #[test]
+    fn heartbeat_monitor_source_not_found() {
+        let monitor = create_monitor_single_cycle(range_from_ms(80, 120));
+        let result = monitor.source(SourceTag::from("worker"));
+        assert!(result.is_err_and(|e| e == HeartbeatMonitorError::SourceNotFound));
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_build_invalid_sources() {
+        let allocator = ProtectedMemoryAllocator {};
+        let result = HeartbeatMonitorBuilder::new(range_from_ms(500, 1000))
+            .add_source(SourceTag::from("worker"))
+            .with_expected_count(1, 2, Duration::from_millis(500))
+            .build(MonitorTag::from(TAG), Duration::from_millis(100), &allocator);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_build_invalid_first_beat_deadline() {
+        let allocator = ProtectedMemoryAllocator {};
+        let result = HeartbeatMonitorBuilder::new(range_from_ms(500, 1000))
+            .with_first_beat_deadline(Duration::from_millis(100))
+            .build(MonitorTag::from(TAG), Duration::from_millis(100), &allocator);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+
+        let result = HeartbeatMonitorBuilder::new(range_from_ms(500, 1000))
+            .with_first_beat_deadline(Duration::from_millis(2000))
+            .add_source(SourceTag::from("worker"))
+            .build(MonitorTag::from(TAG), Duration::from_millis(100), &allocator);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_first_beat_deadline_missed() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = HeartbeatMonitorBuilder::new(range_from_ms(20, 40))
+            .with_first_beat_deadline(Duration::from_millis(100))
+            .build(MonitorTag::from(TAG), Duration::from_millis(1), &allocator)
+            .unwrap();
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+        let mut errors = vec![];
+
+        // After the range, but within the startup window.
+        sleep_until(Duration::from_millis(60), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // After the startup window.
+        sleep_until(Duration::from_millis(110), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_first_beat_deadline_starts_first_cycle() {
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = HeartbeatMonitorBuilder::new(range_from_ms(20, 40))
+            .with_first_beat_deadline(Duration::from_millis(100))
+            .build(MonitorTag::from(TAG), Duration::from_millis(1), &allocator)
+            .unwrap();
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+        let mut errors = vec![];
+
+        // First heartbeat after the range, but within the startup window.
+        sleep_until(Duration::from_millis(60), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // Next heartbeat is judged by the range of the first cycle.
+        sleep_until(Duration::from_millis(90), hmon_starting_point);
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        sleep_until(Duration::from_millis(150), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn heartbeat_monitor_update_range_invalid() {
+        let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = HeartbeatMonitorBuilder::new(range)
+            .build(MonitorTag::from(TAG), Duration::from_millis(100), &allocator)
+            .unwrap();
+
+        let result = monitor.update_range(range_from_ms(20, 40));
+        assert_eq!(result, Err(HeartbeatMonitorError::InvalidRange));
+        assert_eq!(monitor.range(), range);
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_build_invalid_expected_count() {
+        let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
+        let allocator = ProtectedMemoryAllocator {};
+        for (min, max, per_ms) in [(3, 2, 500), (1, 2, 100)] {
+            let result = HeartbeatMonitorBuilder::new(range)
+                .with_expected_count(min, max, Duration::from_millis(per_ms))
+                .build(MonitorTag::from(TAG), Duration::from_millis(100), &allocator);
+            assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn heartbeat_monitor_expected_count() {
+        // Range is not used, multiple heartbeats per window are accepted.
+        let range = range_from_ms(80, 120);
+        let allocator = ProtectedMemoryAllocator {};
+        let monitor = HeartbeatMonitorBuilder::new(range)
+            .with_expected_count(2, 3, Duration::from_millis(100))
+            .build(MonitorTag::from(TAG), Duration::from_millis(1), &allocator)
+            .unwrap();
+        let eval_handle = monitor.get_eval_handle();
+        let hmon_starting_point = Instant::now();
+        let mut errors = vec![];
+
+        // Window is not over yet.
+        monitor.heartbeat();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // Two heartbeats in the first window.
+        monitor.heartbeat();
+        sleep_until(Duration::from_millis(110), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(errors.is_empty());
+
+        // One heartbeat in the second window.
+        monitor.heartbeat();
+        sleep_until(Duration::from_millis(220), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooLate.into()]);
+
+        // Four heartbeats in the third window.
+        errors.clear();
+        for _ in 0..4 {
+            monitor.heartbeat();
+        }
+        sleep_until(Duration::from_millis(330), hmon_starting_point);
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::MultipleHeartbeats.into()]);
+    }
+
     fn beat_eval_test(
         beat_time: Duration,
         eval_time: Duration,
-        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError),
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
     ) {
         let range = range_from_ms(80, 120);
         let monitor = create_monitor_single_cycle(range);
@@ -402,7 +1940,7 @@ mod tests {
     }
 
     fn beat_early_test(eval_time: Duration) {
-        beat_eval_test(Duration::from_millis(25), eval_time, &mut |monitor_tag, error| {
+        beat_eval_test(Duration::from_millis(25), eval_time, &mut |monitor_tag, error, _| {
             assert_eq!(*monitor_tag, MonitorTag::from(TAG));
             assert_eq!(error, HeartbeatEvaluationError::TooEarly.into());
         });
@@ -424,7 +1962,7 @@ mod tests {
     }
 
     fn beat_in_range_test(eval_time: Duration) {
-        beat_eval_test(Duration::from_millis(90), eval_time, &mut |monitor_tag, error| {
+        beat_eval_test(Duration::from_millis(90), eval_time, &mut |monitor_tag, error, _| {
             panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
         });
     }
@@ -444,7 +1982,7 @@ mod tests {
         beat_eval_test(
             Duration::from_millis(150),
             Duration::from_millis(200),
-            &mut |monitor_tag, error| {
+            &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
             },
@@ -467,7 +2005,7 @@ mod tests {
         sleep_until(eval_time, hmon_starting_point);
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::MultipleHeartbeats.into());
             });
@@ -547,7 +2085,7 @@ mod tests {
         while !heartbeat_finished.load(Ordering::Acquire) {
             sleep(cycle);
             // Too early error is expected.
-            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooEarly.into());
             });
@@ -582,7 +2120,7 @@ mod tests {
         while !heartbeat_finished.load(Ordering::Acquire) {
             sleep(cycle);
             // No error is expected.
-            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
         }
@@ -624,7 +2162,7 @@ mod tests {
         while !heartbeat_finished.load(Ordering::Acquire) {
             sleep(cycle);
             // No heartbeat or too late error is expected.
-            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
             });
@@ -650,7 +2188,7 @@ mod tests {
         sleep_until(Duration::from_millis(100), hmon_starting_point);
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
     }
@@ -692,7 +2230,7 @@ mod loom_tests {
             // Evaluate.
             monitor
                 .get_eval_handle()
-                .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                     assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                     assert_eq!(error, HeartbeatEvaluationError::TooEarly.into());
                 });
@@ -718,7 +2256,7 @@ mod loom_tests {
             // Evaluate.
             monitor
                 .get_eval_handle()
-                .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                     panic!("error happened, tag: {monitor_tag:?}, error: {error:?}");
                 });
 
@@ -744,7 +2282,7 @@ mod loom_tests {
             let mut error_detected = false;
             monitor
                 .get_eval_handle()
-                .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                     assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                     assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
                     error_detected = true;
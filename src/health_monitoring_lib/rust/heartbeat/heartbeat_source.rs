@@ -0,0 +1,115 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::heartbeat::heartbeat_monitor::HeartbeatMonitorInner;
+use crate::tag::SourceTag;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Source providing heartbeats to a [`super::HeartbeatMonitor`], acquired with [`super::HeartbeatMonitor::source`].
+/// Cheap to clone, all clones provide heartbeats of the same source.
+#[derive(Clone)]
+pub struct HeartbeatSource {
+    inner: Arc<HeartbeatMonitorInner>,
+    index: usize,
+}
+
+impl HeartbeatSource {
+    pub(super) fn new(inner: Arc<HeartbeatMonitorInner>, index: usize) -> Self {
+        Self { inner, index }
+    }
+
+    /// Provide a heartbeat of this source.
+    pub fn heartbeat(&self) {
+        self.inner.source_heartbeat(self.index)
+    }
+}
+
+impl core::fmt::Debug for HeartbeatSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HeartbeatSource")
+            .field("source_tag", &self.inner.source_tag(self.index))
+            .finish()
+    }
+}
+
+const NO_BEAT: u64 = u64::MAX;
+
+/// Last heartbeat of a source in the current cycle.
+///
+/// Multiple writers - clones of the source, single reader - evaluation of the monitor.
+pub(super) struct SourceSlot {
+    source_tag: SourceTag,
+    beat: AtomicU64,
+}
+
+impl SourceSlot {
+    pub(super) fn new(source_tag: SourceTag) -> Self {
+        Self {
+            source_tag,
+            beat: AtomicU64::new(NO_BEAT),
+        }
+    }
+
+    pub(super) fn source_tag(&self) -> SourceTag {
+        self.source_tag
+    }
+
+    /// Record heartbeat at `timestamp`.
+    pub(super) fn record(&self, timestamp: u64) {
+        self.beat.store(timestamp, Ordering::Release);
+    }
+
+    /// Timestamp of the last heartbeat, [`None`] if the source is silent in the current cycle.
+    pub(super) fn load(&self) -> Option<u64> {
+        match self.beat.load(Ordering::Acquire) {
+            NO_BEAT => None,
+            timestamp => Some(timestamp),
+        }
+    }
+
+    /// End the cycle completed at `timestamp`, heartbeats provided later are kept for the next cycle.
+    pub(super) fn complete(&self, timestamp: u64) {
+        let _ = self.beat.fetch_update(Ordering::AcqRel, Ordering::Acquire, |beat| {
+            (beat <= timestamp).then_some(NO_BEAT)
+        });
+    }
+
+    /// Discard the heartbeat of the current cycle.
+    pub(super) fn clear(&self) {
+        self.beat.store(NO_BEAT, Ordering::Release);
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::heartbeat::heartbeat_source::SourceSlot;
+    use crate::tag::SourceTag;
+
+    #[test]
+    fn source_slot_complete_keeps_later_heartbeat() {
+        let slot = SourceSlot::new(SourceTag::from("source"));
+        assert_eq!(slot.load(), None);
+
+        slot.record(100);
+        slot.complete(120);
+        assert_eq!(slot.load(), None);
+
+        slot.record(130);
+        slot.complete(120);
+        assert_eq!(slot.load(), Some(130));
+
+        slot.clear();
+        assert_eq!(slot.load(), None);
+    }
+}
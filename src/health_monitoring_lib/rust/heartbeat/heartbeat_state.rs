@@ -69,6 +69,17 @@ impl From<u64> for HeartbeatStateSnapshot {
     }
 }
 
+/// Outcome of [`HeartbeatState::update_bounded`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoundedUpdate {
+    /// State was updated.
+    Updated,
+    /// Closure returned [`None`], state was not updated.
+    Rejected,
+    /// Update did not land within allowed number of attempts.
+    Exhausted,
+}
+
 /// Atomic representation of [`HeartbeatStateSnapshot`].
 #[derive(Default)]
 pub struct HeartbeatState(AtomicU64);
@@ -80,7 +91,7 @@ impl HeartbeatState {
     }
 
     /// Return a snapshot of the current heartbeat state.
-    #[allow(dead_code)]
+    #[cfg(test)]
     pub fn snapshot(&self) -> HeartbeatStateSnapshot {
         HeartbeatStateSnapshot::from(self.0.load(Ordering::Acquire))
     }
@@ -88,6 +99,7 @@ impl HeartbeatState {
     /// Update the heartbeat state using the provided closure.
     /// Closure receives the current state and should return an [`Option`] containing a new state.
     /// If [`None`] is returned then the state was not updated.
+    #[cfg(test)]
     pub fn update<F: FnMut(HeartbeatStateSnapshot) -> Option<HeartbeatStateSnapshot>>(
         &self,
         mut f: F,
@@ -101,6 +113,40 @@ impl HeartbeatState {
             .map_err(HeartbeatStateSnapshot::from)
     }
 
+    /// Update the heartbeat state using the provided closure, with a limited number of attempts.
+    /// Closure receives the current state and should return an [`Option`] containing a new state.
+    /// Each failed compare-and-swap (concurrent modification) consumes an attempt and re-runs the closure.
+    /// Strong compare-and-swap is used, so spurious failures do not consume attempts.
+    ///
+    /// Returns the outcome and the number of retries (failed attempts) that were needed.
+    pub fn update_bounded<F: FnMut(HeartbeatStateSnapshot) -> Option<HeartbeatStateSnapshot>>(
+        &self,
+        max_attempts: u32,
+        mut f: F,
+    ) -> (BoundedUpdate, u32) {
+        let mut current = self.0.load(Ordering::Acquire);
+        let mut retries = 0;
+        while retries < max_attempts {
+            let new = match f(HeartbeatStateSnapshot::from(current)) {
+                Some(new_snapshot) => new_snapshot.as_u64(),
+                None => return (BoundedUpdate::Rejected, retries),
+            };
+
+            match self
+                .0
+                .compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return (BoundedUpdate::Updated, retries),
+                Err(actual) => {
+                    current = actual;
+                    retries += 1;
+                },
+            }
+        }
+
+        (BoundedUpdate::Exhausted, retries)
+    }
+
     /// Reset the heartbeat state, returning the previous one.
     pub fn reset(&self) -> HeartbeatStateSnapshot {
         self.0
@@ -111,7 +157,7 @@ impl HeartbeatState {
 
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::heartbeat::heartbeat_state::{HeartbeatState, HeartbeatStateSnapshot, BEAT_OFFSET};
+    use crate::heartbeat::heartbeat_state::{BoundedUpdate, HeartbeatState, HeartbeatStateSnapshot, BEAT_OFFSET};
     use core::cmp::min;
     use core::sync::atomic::Ordering;
 
@@ -256,4 +302,56 @@ mod tests {
         assert_eq!(snapshot_initial.as_u64(), snapshot_from_reset.as_u64());
         assert_eq!(snapshot_after_reset.as_u64(), 0);
     }
+
+    #[test]
+    fn state_update_bounded_no_contention() {
+        let state = HeartbeatState::new();
+        let (outcome, retries) = state.update_bounded(1, |_| Some(HeartbeatStateSnapshot::from(0xDEADBEEF_DEADBEEF)));
+
+        assert_eq!(outcome, BoundedUpdate::Updated);
+        assert_eq!(retries, 0);
+        assert_eq!(state.snapshot().as_u64(), 0xDEADBEEF_DEADBEEF);
+    }
+
+    #[test]
+    fn state_update_bounded_rejected() {
+        let state = HeartbeatState::new();
+        let (outcome, retries) = state.update_bounded(1, |_| None);
+
+        assert_eq!(outcome, BoundedUpdate::Rejected);
+        assert_eq!(retries, 0);
+        assert_eq!(state.snapshot().as_u64(), 0);
+    }
+
+    #[test]
+    fn state_update_bounded_retries_on_concurrent_modification() {
+        let state = HeartbeatState::new();
+        let mut calls = 0;
+        let (outcome, retries) = state.update_bounded(3, |current| {
+            calls += 1;
+            // Simulate concurrent modification during first attempt.
+            if calls == 1 {
+                state.0.store(0x10, Ordering::Release);
+            }
+            Some(HeartbeatStateSnapshot::from(current.as_u64() + 1))
+        });
+
+        assert_eq!(outcome, BoundedUpdate::Updated);
+        assert_eq!(retries, 1);
+        assert_eq!(state.snapshot().as_u64(), 0x11);
+    }
+
+    #[test]
+    fn state_update_bounded_exhausted() {
+        let state = HeartbeatState::new();
+        let (outcome, retries) = state.update_bounded(2, |current| {
+            // Every attempt is interrupted by a concurrent modification.
+            state.0.fetch_add(1, Ordering::AcqRel);
+            Some(HeartbeatStateSnapshot::from(current.as_u64() + 100))
+        });
+
+        assert_eq!(outcome, BoundedUpdate::Exhausted);
+        assert_eq!(retries, 2);
+        assert_eq!(state.snapshot().as_u64(), 2);
+    }
 }
@@ -0,0 +1,120 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use core::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+
+/// Number of heartbeat arrivals kept for jitter statistics.
+pub(super) const JITTER_SAMPLES: usize = 64;
+
+/// Deviation of heartbeat arrivals from the nominal period, see [`super::HeartbeatMonitor::jitter_stats`].
+/// Nominal period is the middle of the range, or its lower bound if the range is unbounded.
+/// Negative deviation is an arrival before the nominal period.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HeartbeatJitterStatistics {
+    /// Number of arrivals the statistics are computed from, at most the last 64 arrivals are kept.
+    pub samples: usize,
+    /// Smallest deviation in milliseconds.
+    pub min_deviation_ms: i64,
+    /// Largest deviation in milliseconds.
+    pub max_deviation_ms: i64,
+    /// Mean deviation in milliseconds, rounded towards zero.
+    pub mean_deviation_ms: i64,
+}
+
+/// Ring buffer of the deviations of the last heartbeat arrivals.
+///
+/// Single writer - evaluation of the monitor, multiple readers.
+pub(super) struct JitterRing {
+    deviations: [AtomicI64; JITTER_SAMPLES],
+    /// Total number of recorded deviations.
+    recorded: AtomicU64,
+}
+
+impl JitterRing {
+    pub(super) fn new() -> Self {
+        Self {
+            deviations: core::array::from_fn(|_| AtomicI64::new(0)),
+            recorded: AtomicU64::new(0),
+        }
+    }
+
+    /// Record deviation of an arrival after `interval_ms` from the `nominal_ms` period.
+    pub(super) fn record(&self, interval_ms: i64, nominal_ms: i64) {
+        let recorded = self.recorded.load(Ordering::Relaxed);
+        let index = (recorded % JITTER_SAMPLES as u64) as usize;
+        self.deviations[index].store(interval_ms.saturating_sub(nominal_ms), Ordering::Relaxed);
+        self.recorded.store(recorded + 1, Ordering::Release);
+    }
+
+    /// Statistics of the kept deviations, [`None`] if nothing was recorded.
+    pub(super) fn statistics(&self) -> Option<HeartbeatJitterStatistics> {
+        let recorded = self.recorded.load(Ordering::Acquire);
+        let samples = recorded.min(JITTER_SAMPLES as u64) as usize;
+        if samples == 0 {
+            return None;
+        }
+
+        let deviations = self.deviations[..samples]
+            .iter()
+            .map(|deviation| deviation.load(Ordering::Relaxed));
+        let (min, max, sum) = deviations.fold((i64::MAX, i64::MIN, 0i128), |(min, max, sum), deviation| {
+            (min.min(deviation), max.max(deviation), sum + i128::from(deviation))
+        });
+        Some(HeartbeatJitterStatistics {
+            samples,
+            min_deviation_ms: min,
+            max_deviation_ms: max,
+            mean_deviation_ms: (sum / samples as i128) as i64,
+        })
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::heartbeat::jitter::{HeartbeatJitterStatistics, JitterRing, JITTER_SAMPLES};
+
+    #[test]
+    fn jitter_ring_empty() {
+        assert_eq!(JitterRing::new().statistics(), None);
+    }
+
+    #[test]
+    fn jitter_ring_statistics() {
+        let ring = JitterRing::new();
+        for interval_ms in [90, 100, 125] {
+            ring.record(interval_ms, 100);
+        }
+        assert_eq!(
+            ring.statistics(),
+            Some(HeartbeatJitterStatistics {
+                samples: 3,
+                min_deviation_ms: -10,
+                max_deviation_ms: 25,
+                mean_deviation_ms: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn jitter_ring_keeps_last_samples() {
+        let ring = JitterRing::new();
+        ring.record(0, 100);
+        for _ in 0..JITTER_SAMPLES {
+            ring.record(110, 100);
+        }
+        let statistics = ring.statistics().unwrap();
+        assert_eq!(statistics.samples, JITTER_SAMPLES);
+        assert_eq!(statistics.min_deviation_ms, 10);
+        assert_eq!(statistics.max_deviation_ms, 10);
+    }
+}
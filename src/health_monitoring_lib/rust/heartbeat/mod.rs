@@ -12,10 +12,16 @@
 // *******************************************************************************
 
 mod heartbeat_monitor;
+mod heartbeat_source;
 mod heartbeat_state;
+mod jitter;
 
-pub(crate) use heartbeat_monitor::HeartbeatEvaluationError;
-pub use heartbeat_monitor::{HeartbeatMonitor, HeartbeatMonitorBuilder};
+pub use heartbeat_monitor::{
+    HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder, HeartbeatMonitorError, HeartbeatProducer,
+    HeartbeatUpdateStatistics,
+};
+pub use heartbeat_source::HeartbeatSource;
+pub use jitter::HeartbeatJitterStatistics;
 
 // FFI bindings
 pub(super) mod ffi;
@@ -0,0 +1,19 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Supervision of local worker processes by a single reporting process.
+//! Workers send liveness reports to a [`SupervisionHub`], which feeds them into its own health monitor.
+
+mod supervision_hub;
+
+pub use supervision_hub::{HubReporter, SupervisionHub, MAX_WORKER_TAG_LENGTH};
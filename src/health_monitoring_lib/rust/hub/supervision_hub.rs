@@ -0,0 +1,291 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::heartbeat::HeartbeatMonitor;
+use crate::log::{debug, warn};
+use crate::tag::MonitorTag;
+use crate::worker::StopToken;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::io;
+use std::os::unix::net::UnixDatagram;
+use std::path::{Path, PathBuf};
+
+/// Maximum length of a worker tag in bytes.
+pub const MAX_WORKER_TAG_LENGTH: usize = 255;
+
+/// Identifies a liveness report datagram.
+const MAGIC: [u8; 2] = *b"HL";
+
+/// Version of the liveness report encoding.
+const VERSION: u8 = 1;
+
+/// Size of the liveness report header.
+const HEADER_SIZE: usize = MAGIC.len() + 1;
+
+/// Encode a liveness report of the worker.
+/// Encoded as magic `HL`, version and UTF-8 tag of the worker.
+fn encode_report(worker_tag: &str) -> Vec<u8> {
+    let mut report = Vec::with_capacity(HEADER_SIZE + worker_tag.len());
+    report.extend_from_slice(&MAGIC);
+    report.push(VERSION);
+    report.extend_from_slice(worker_tag.as_bytes());
+    report
+}
+
+/// Parse a liveness report, [`None`] if the datagram is not a valid report.
+fn parse_report(datagram: &[u8]) -> Option<&str> {
+    let (header, worker_tag) = datagram.split_at_checked(HEADER_SIZE)?;
+    if header[..MAGIC.len()] != MAGIC || header[MAGIC.len()] != VERSION || worker_tag.is_empty() {
+        return None;
+    }
+    core::str::from_utf8(worker_tag).ok()
+}
+
+/// Sends liveness reports of a worker process to a [`SupervisionHub`].
+pub struct HubReporter {
+    socket: UnixDatagram,
+    report: Vec<u8>,
+}
+
+impl HubReporter {
+    /// Connect to the hub listening on `hub_path`.
+    ///
+    /// - `hub_path` - path of the hub socket.
+    /// - `worker_tag` - tag under which the worker is registered in the hub.
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if `worker_tag` is empty or longer than [`MAX_WORKER_TAG_LENGTH`].
+    pub fn connect(hub_path: impl AsRef<Path>, worker_tag: MonitorTag) -> io::Result<Self> {
+        let worker_tag = worker_tag.as_str();
+        if worker_tag.is_empty() || worker_tag.len() > MAX_WORKER_TAG_LENGTH {
+            return Err(io::Error::from(io::ErrorKind::InvalidInput));
+        }
+
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(hub_path)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket,
+            report: encode_report(worker_tag),
+        })
+    }
+
+    /// Report liveness of the worker, expected once per its heartbeat range.
+    /// Never blocks, report is dropped if the hub is not receiving.
+    pub fn report(&self) -> io::Result<()> {
+        self.socket.send(&self.report).map(|_| ())
+    }
+}
+
+/// Receives liveness reports of worker processes and provides them as heartbeats to the heartbeat monitors
+/// of the hosting process, so workers are supervised the same way as its own monitors.
+///
+/// Reports are processed by [`SupervisionHub::poll`], which must be called more often than the shortest
+/// heartbeat range of the workers, e.g. from a dedicated thread with [`SupervisionHub::run`].
+pub struct SupervisionHub {
+    socket: UnixDatagram,
+    path: PathBuf,
+    workers: HashMap<MonitorTag, HeartbeatMonitor>,
+    rejected_reports: u64,
+}
+
+impl SupervisionHub {
+    /// Listen for liveness reports on `path`.
+    /// Socket file is removed when the hub is dropped.
+    ///
+    /// - `path` - path of the hub socket, must not exist.
+    pub fn bind(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let socket = UnixDatagram::bind(&path)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket,
+            path,
+            workers: HashMap::new(),
+            rejected_reports: 0,
+        })
+    }
+
+    /// Supervise the worker reporting with `worker_tag` by the given heartbeat monitor.
+    /// Heartbeat range of the monitor is the expected interval between reports of the worker.
+    ///
+    /// - `worker_tag` - tag used by the worker [`HubReporter`].
+    /// - `monitor` - heartbeat monitor taken from the health monitor of the hosting process.
+    ///
+    /// # Note
+    ///
+    /// If a worker with the same tag already exists, it will be overwritten.
+    pub fn add_worker(&mut self, worker_tag: MonitorTag, monitor: HeartbeatMonitor) {
+        self.workers.insert(worker_tag, monitor);
+    }
+
+    /// Process all pending liveness reports.
+    /// Returns number of accepted reports, reports of unknown workers and malformed datagrams are rejected.
+    pub fn poll(&mut self) -> usize {
+        let mut buffer = [0u8; HEADER_SIZE + MAX_WORKER_TAG_LENGTH];
+        let mut accepted = 0;
+        loop {
+            let size = match self.socket.recv(&mut buffer) {
+                Ok(size) => size,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(_) => {
+                    warn!("Failed to receive liveness report.");
+                    break;
+                },
+            };
+
+            let Some(worker_tag) = parse_report(&buffer[..size]) else {
+                warn!("Malformed liveness report rejected.");
+                self.rejected_reports += 1;
+                continue;
+            };
+            // Tag is only used for lookup, so it can borrow the buffer.
+            match self.workers.get(&MonitorTag::new(worker_tag)) {
+                Some(monitor) => {
+                    monitor.heartbeat();
+                    accepted += 1;
+                },
+                None => {
+                    warn!("Liveness report of unknown worker {} rejected.", worker_tag);
+                    self.rejected_reports += 1;
+                },
+            }
+        }
+        accepted
+    }
+
+    /// Process liveness reports every `poll_interval` until `stop_token` is stopped.
+    ///
+    /// - `poll_interval` - interval between processing of pending reports.
+    /// - `stop_token` - token requesting the loop to exit.
+    pub fn run(&mut self, poll_interval: Duration, stop_token: &StopToken) {
+        debug!("Supervision hub started.");
+        while !stop_token.is_stopped() {
+            self.poll();
+            std::thread::sleep(poll_interval);
+        }
+        debug!("Supervision hub stopped.");
+    }
+
+    /// Number of rejected reports since the hub was created.
+    pub fn rejected_reports(&self) -> u64 {
+        self.rejected_reports
+    }
+}
+
+impl Drop for SupervisionHub {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::{Monitor, MonitorEvaluator, TimeRange};
+    use crate::heartbeat::{HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder};
+    use crate::hub::supervision_hub::{encode_report, parse_report, HEADER_SIZE};
+    use crate::hub::{HubReporter, SupervisionHub, MAX_WORKER_TAG_LENGTH};
+    use crate::protected_memory::ProtectedMemoryAllocator;
+    use crate::tag::MonitorTag;
+    use core::time::Duration;
+    use std::io;
+    use std::os::unix::net::UnixDatagram;
+    use std::path::PathBuf;
+    use std::time::Instant;
+
+    fn hub_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("hmon_hub_{}_{}", std::process::id(), name))
+    }
+
+    fn create_monitor(monitor_tag: MonitorTag) -> HeartbeatMonitor {
+        let range = TimeRange::new(Duration::from_millis(100), Duration::from_millis(200));
+        HeartbeatMonitorBuilder::new(range)
+            .build(monitor_tag, Duration::from_millis(100), &ProtectedMemoryAllocator {})
+            .unwrap()
+    }
+
+    #[test]
+    fn report_encode_parse() {
+        let report = encode_report("worker");
+        assert_eq!(report.len(), HEADER_SIZE + "worker".len());
+        assert_eq!(parse_report(&report), Some("worker"));
+    }
+
+    #[test]
+    fn report_parse_invalid() {
+        assert_eq!(parse_report(b""), None);
+        assert_eq!(parse_report(b"HL\x01"), None);
+        assert_eq!(parse_report(b"XX\x01worker"), None);
+        assert_eq!(parse_report(b"HL\x02worker"), None);
+        assert_eq!(parse_report(b"HL\x01\xFF"), None);
+    }
+
+    #[test]
+    fn hub_reporter_invalid_tag() {
+        let path = hub_path("invalid_tag");
+        let _hub = SupervisionHub::bind(&path).unwrap();
+
+        let result = HubReporter::connect(&path, MonitorTag::from(""));
+        assert!(result.is_err_and(|e| e.kind() == io::ErrorKind::InvalidInput));
+        let result = HubReporter::connect(&path, MonitorTag::from("w".repeat(MAX_WORKER_TAG_LENGTH + 1)));
+        assert!(result.is_err_and(|e| e.kind() == io::ErrorKind::InvalidInput));
+    }
+
+    #[test]
+    fn hub_poll_feeds_worker_monitor() {
+        let path = hub_path("feeds_worker");
+        let worker_tag = MonitorTag::from("worker");
+        let monitor = create_monitor(worker_tag);
+        let mut hub = SupervisionHub::bind(&path).unwrap();
+        hub.add_worker(worker_tag, monitor.clone_shared());
+
+        let reporter = HubReporter::connect(&path, worker_tag).unwrap();
+        reporter.report().unwrap();
+
+        assert_eq!(hub.poll(), 1);
+        assert_eq!(hub.poll(), 0);
+        assert_eq!(hub.rejected_reports(), 0);
+
+        // Report was provided as a heartbeat, before the range of the monitor.
+        let mut errors = Vec::new();
+        monitor
+            .get_eval_handle()
+            .evaluate(Instant::now(), &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooEarly.into()]);
+    }
+
+    #[test]
+    fn hub_poll_rejects_unknown_and_malformed() {
+        let path = hub_path("rejects");
+        let mut hub = SupervisionHub::bind(&path).unwrap();
+        hub.add_worker(MonitorTag::from("worker"), create_monitor(MonitorTag::from("worker")));
+
+        let reporter = HubReporter::connect(&path, MonitorTag::from("other_worker")).unwrap();
+        reporter.report().unwrap();
+        let socket = UnixDatagram::unbound().unwrap();
+        socket.send_to(b"garbage", &path).unwrap();
+
+        assert_eq!(hub.poll(), 0);
+        assert_eq!(hub.rejected_reports(), 2);
+    }
+
+    #[test]
+    fn hub_drop_removes_socket() {
+        let path = hub_path("drop");
+        let hub = SupervisionHub::bind(&path).unwrap();
+        assert!(path.exists());
+        drop(hub);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,191 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Status based heartbeat API of the previous health monitor, implemented on top of the evaluation API.
+//!
+//! Meant for consumers migrating to the consolidated library only, new code uses [`crate::heartbeat`] directly.
+
+use crate::common::Monitor;
+use crate::health_status::StatusBoard;
+use crate::heartbeat::{HeartbeatMonitor, HeartbeatMonitorError};
+use crate::log::{error, ScoreDebug};
+use crate::tag::MonitorTag;
+use crate::{HealthMonitor, HealthMonitorError};
+use std::sync::Arc;
+
+/// Status of a monitor, as reported by the previous health monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ScoreDebug)]
+pub enum Status {
+    /// Monitoring is running and the monitor reported no error.
+    Running,
+    /// Monitor is disabled, its errors are not reported.
+    Disabled,
+    /// Monitor reported an error since monitoring was started.
+    Failed,
+    /// Monitoring is not started or was stopped.
+    Stopped,
+}
+
+/// Errors of the previous health monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ScoreDebug)]
+pub enum Error {
+    /// Provided parameter is invalid or refers to an unknown entry.
+    BadParameter,
+    /// Operation is not allowed in the current state.
+    NotAllowed,
+    /// Resources could not be allocated.
+    OutOfMemory,
+    /// Any other error.
+    Generic,
+}
+
+impl From<HealthMonitorError> for Error {
+    fn from(value: HealthMonitorError) -> Self {
+        match value {
+            HealthMonitorError::NotFound | HealthMonitorError::InvalidArgument => Error::BadParameter,
+            HealthMonitorError::WrongState => Error::NotAllowed,
+        }
+    }
+}
+
+impl From<HeartbeatMonitorError> for Error {
+    fn from(value: HeartbeatMonitorError) -> Self {
+        match value {
+            HeartbeatMonitorError::ProducerInUse => Error::NotAllowed,
+            HeartbeatMonitorError::InvalidRange | HeartbeatMonitorError::SourceNotFound => Error::BadParameter,
+        }
+    }
+}
+
+/// Heartbeat monitor with the status based API of the previous health monitor.
+/// Heartbeats are evaluated by the monitoring logic of the [`HealthMonitor`], status is read from its last cycle.
+pub struct LegacyHeartbeatMonitor {
+    monitor_tag: MonitorTag,
+    monitor: HeartbeatMonitor,
+    status_board: Arc<StatusBoard>,
+}
+
+impl LegacyHeartbeatMonitor {
+    /// Take the heartbeat monitor with the given tag from the `health_monitor`,
+    /// same as [`HealthMonitor::get_heartbeat_monitor`].
+    ///
+    /// Returns [`Error::BadParameter`] if the monitor does not exist or is already taken.
+    pub fn take(health_monitor: &mut HealthMonitor, monitor_tag: MonitorTag) -> Result<Self, Error> {
+        let Some(monitor) = health_monitor.get_heartbeat_monitor(monitor_tag) else {
+            error!("Heartbeat monitor {:?} not found or already taken.", monitor_tag);
+            return Err(Error::BadParameter);
+        };
+
+        Ok(Self {
+            monitor_tag,
+            monitor,
+            status_board: Arc::clone(&health_monitor.status_board),
+        })
+    }
+
+    /// Provide a heartbeat and return the status of the monitor.
+    ///
+    /// Returns [`Error::NotAllowed`] if the monitor is disabled, the heartbeat is not provided.
+    pub fn report_heartbeat(&self) -> Result<Status, Error> {
+        if !self.monitor.is_enabled() {
+            return Err(Error::NotAllowed);
+        }
+        self.monitor.heartbeat();
+        Ok(self.evaluate())
+    }
+
+    /// Resume supervision of the monitor, see [`HealthMonitor::enable_monitor`].
+    pub fn enable(&self) -> Result<(), Error> {
+        self.monitor.set_enabled(true);
+        Ok(())
+    }
+
+    /// Suspend supervision of the monitor, see [`HealthMonitor::disable_monitor`].
+    pub fn disable(&self) -> Result<(), Error> {
+        self.monitor.set_enabled(false);
+        Ok(())
+    }
+
+    /// Status of the monitor as of the last internal processing cycle.
+    pub fn evaluate(&self) -> Status {
+        if !self.status_board.is_running() {
+            Status::Stopped
+        } else if !self.monitor.is_enabled() {
+            Status::Disabled
+        } else if self.status_board.is_failing(&self.monitor_tag) {
+            Status::Failed
+        } else {
+            Status::Running
+        }
+    }
+
+    /// Release the adapter, e.g. to return the monitor with [`HealthMonitor::return_heartbeat_monitor`].
+    pub fn into_inner(self) -> HeartbeatMonitor {
+        self.monitor
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::heartbeat::HeartbeatMonitorBuilder;
+    use crate::legacy::{Error, LegacyHeartbeatMonitor, Status};
+    use crate::tag::MonitorTag;
+    use crate::{HealthMonitorBuilder, TimeRange};
+    use core::time::Duration;
+
+    fn heartbeat_monitor_builder() -> HeartbeatMonitorBuilder {
+        HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_millis(100), Duration::from_millis(200)))
+    }
+
+    #[test]
+    fn legacy_heartbeat_monitor_take_unknown() {
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), heartbeat_monitor_builder())
+            .build()
+            .unwrap();
+        assert!(
+            LegacyHeartbeatMonitor::take(&mut health_monitor, MonitorTag::from("unknown"))
+                .is_err_and(|e| e == Error::BadParameter)
+        );
+        assert!(LegacyHeartbeatMonitor::take(&mut health_monitor, MonitorTag::from("heartbeat_monitor")).is_ok());
+        assert!(
+            LegacyHeartbeatMonitor::take(&mut health_monitor, MonitorTag::from("heartbeat_monitor"))
+                .is_err_and(|e| e == Error::BadParameter)
+        );
+    }
+
+    #[test]
+    fn legacy_heartbeat_monitor_status() {
+        let monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(monitor_tag, heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let monitor = LegacyHeartbeatMonitor::take(&mut health_monitor, monitor_tag).unwrap();
+        assert_eq!(monitor.evaluate(), Status::Stopped);
+
+        health_monitor.start().unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(monitor.evaluate(), Status::Running);
+
+        monitor.disable().unwrap();
+        assert_eq!(monitor.evaluate(), Status::Disabled);
+        assert_eq!(monitor.report_heartbeat(), Err(Error::NotAllowed));
+
+        health_monitor.stop().unwrap();
+        monitor.enable().unwrap();
+        assert_eq!(monitor.report_heartbeat(), Ok(Status::Stopped));
+    }
+}
@@ -11,26 +11,107 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+mod armed_start;
+mod build_info;
+mod challenge;
+mod clock_granularity;
 mod common;
+mod config_summary;
+mod early_supervision;
+mod fail_fast;
+mod failure_statistics;
 mod ffi;
+mod group;
+mod handoff_queue;
+mod health_forwarder;
+mod health_monitor_config;
+mod health_status;
+mod health_trajectory;
 mod log;
+mod monitoring_assembly;
+mod ordered_map;
+mod panic_reporting;
 mod protected_memory;
+mod resource_budget;
+mod signal_export;
+mod snapshot;
 mod supervisor_api_client;
 mod tag;
+mod thread_attribution;
+mod tolerance;
+mod update_mode;
+mod validation;
+mod violation_history;
+mod violation_persistence;
+mod violation_sink;
 mod worker;
+mod worker_supervision;
 
+pub mod artifact;
 pub mod deadline;
 pub mod heartbeat;
-
-use crate::common::{Monitor, MonitorEvalHandle};
-use crate::deadline::{DeadlineMonitor, DeadlineMonitorBuilder};
-use crate::heartbeat::{HeartbeatMonitor, HeartbeatMonitorBuilder};
-use crate::log::{error, ScoreDebug};
-pub use common::TimeRange;
-use containers::fixed_capacity::FixedCapacityVec;
+#[cfg(unix)]
+pub mod hub;
+pub mod legacy;
+pub mod prelude;
+
+use crate::armed_start::ArmGuard;
+use crate::artifact::ArtifactFreshnessMonitor;
+use crate::common::{Monitor, MonitorEvalHandle, MonitorEvaluator};
+use crate::failure_statistics::FailureStatistics;
+use crate::handoff_queue::HandoffQueue;
+use crate::health_monitor_config::HealthMonitorConfig;
+use crate::health_status::StatusBoard;
+use crate::health_trajectory::TrajectoryGuard;
+use crate::log::{error, info, ScoreDebug};
+use crate::ordered_map::OrderedMap;
+use crate::panic_reporting::PanicReporter;
+use crate::update_mode::UpdateModeGuard;
+use crate::violation_history::ViolationHistory;
+use crate::violation_sink::ViolationFanOut;
+use crate::worker_supervision::{WorkerState, DEFAULT_WORKER_STALL_CYCLES};
+pub use armed_start::ArmedStart;
+pub use artifact::ArtifactFreshnessMonitorBuilder;
+pub use build_info::{enabled_features, library_version};
+pub use challenge::{ChallengeHandle, ChallengeResponse};
+pub use clock_granularity::DEFAULT_CLOCK_GRANULARITY_MULTIPLE;
+pub use common::{InternalFault, MonitorEvaluationError, TimeRange};
+pub use config_summary::ConfigSummary;
+use core::sync::atomic::{AtomicBool, Ordering};
 use core::time::Duration;
+pub use deadline::{
+    Deadline, DeadlineGroup, DeadlineGuard, DeadlineHandle, DeadlineMonitor, DeadlineMonitorBuilder, StartedDeadline,
+};
+pub use early_supervision::EarlySupervision;
+pub use fail_fast::FailFastPolicy;
+pub use failure_statistics::MonitorStatistics;
+pub use group::{GroupPolicy, MonitorGroup};
+pub use health_forwarder::{
+    ForwardedStatus, HealthSummary, HealthSummaryParseError, UdpHealthForwarder, HEALTH_SUMMARY_SIZE,
+};
+pub use health_status::HealthStatus;
+pub use health_trajectory::{HealthState, HealthTrajectory};
+pub use heartbeat::{HeartbeatMonitor, HeartbeatMonitorBuilder, HeartbeatProducer};
+pub use resource_budget::{CapacityReport, ResourceBudget};
+pub use signal_export::{LoggingSignalSink, SignalExport, SignalSink, SignalValue};
+pub use snapshot::{HealthMonitorSnapshot, MonitorConfig, MonitorOwnership, MonitorSnapshot};
 use std::collections::HashMap;
-pub use tag::{DeadlineTag, MonitorTag};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+pub use tag::{ChannelTag, DeadlineTag, GroupTag, MonitorTag, SourceTag};
+pub use thread_attribution::ReporterThread;
+pub use tolerance::{FailureTolerance, RecoveryPolicy, MAX_TOLERANCE_CYCLES};
+pub use update_mode::UpdateMode;
+pub use validation::{
+    ConfigIssue, ValidationReport, STRICT_MAX_INTERNAL_PROCESSING_CYCLE, STRICT_MAX_SUPERVISOR_API_CYCLE,
+};
+pub use violation_history::{InternalFaultRecord, ViolationRecord};
+pub use violation_persistence::{
+    ArchiveInfo, ArchiveIntegrity, PersistentViolationSink, RotationPolicy, DEFAULT_MAX_FILE_BYTES,
+};
+pub use violation_sink::{LoggingViolationSink, ViolationSink};
+pub use worker::{RunExit, StopToken};
+pub use worker_supervision::WorkerFault;
 
 /// Health monitor errors.
 #[derive(PartialEq, Eq, Debug, ScoreDebug)]
@@ -44,22 +125,20 @@ pub enum HealthMonitorError {
 }
 
 /// Builder for the [`HealthMonitor`].
+///
+/// Monitors are evaluated in a deterministic order - deadline, heartbeat and artifact freshness monitors,
+/// each kind in order of addition, followed by monitors added at runtime.
+/// Storage of monitors, including slots for runtime monitors, is allocated by [`Self::build`].
 #[derive(Default)]
 pub struct HealthMonitorBuilder {
-    deadline_monitor_builders: HashMap<MonitorTag, DeadlineMonitorBuilder>,
-    heartbeat_monitor_builders: HashMap<MonitorTag, HeartbeatMonitorBuilder>,
-    supervisor_api_cycle: Duration,
-    internal_processing_cycle: Duration,
+    config: HealthMonitorConfig,
 }
 
 impl HealthMonitorBuilder {
     /// Create a new [`HealthMonitorBuilder`] instance.
     pub fn new() -> Self {
         Self {
-            deadline_monitor_builders: HashMap::new(),
-            heartbeat_monitor_builders: HashMap::new(),
-            supervisor_api_cycle: Duration::from_millis(500),
-            internal_processing_cycle: Duration::from_millis(100),
+            config: HealthMonitorConfig::new(),
         }
     }
 
@@ -72,7 +151,9 @@ impl HealthMonitorBuilder {
     ///
     /// If a deadline monitor with the same tag already exists, it will be overwritten.
     pub fn add_deadline_monitor(mut self, monitor_tag: MonitorTag, monitor_builder: DeadlineMonitorBuilder) -> Self {
-        self.add_deadline_monitor_internal(monitor_tag, monitor_builder);
+        self.config
+            .deadline_monitor_builders
+            .insert(monitor_tag, monitor_builder);
         self
     }
 
@@ -85,7 +166,36 @@ impl HealthMonitorBuilder {
     ///
     /// If a heartbeat monitor with the same tag already exists, it will be overwritten.
     pub fn add_heartbeat_monitor(mut self, monitor_tag: MonitorTag, monitor_builder: HeartbeatMonitorBuilder) -> Self {
-        self.add_heartbeat_monitor_internal(monitor_tag, monitor_builder);
+        self.config
+            .heartbeat_monitor_builders
+            .insert(monitor_tag, monitor_builder);
+        self
+    }
+
+    /// Add an artifact freshness monitor for the given [`MonitorTag`].
+    /// Unlike other monitors, it is not taken by the application and is evaluated from the start.
+    ///
+    /// - `monitor_tag` - unique tag for the artifact freshness monitor.
+    /// - `monitor_builder` - monitor builder to finalize.
+    ///
+    /// # Note
+    ///
+    /// If an artifact freshness monitor with the same tag already exists, it will be overwritten.
+    pub fn add_artifact_freshness_monitor(
+        mut self,
+        monitor_tag: MonitorTag,
+        monitor_builder: ArtifactFreshnessMonitorBuilder,
+    ) -> Self {
+        self.config
+            .artifact_freshness_monitor_builders
+            .insert(monitor_tag, monitor_builder);
+        self
+    }
+
+    /// Absorb monitors created during early initialization, before the [`HealthMonitor`] is built.
+    /// Monitors are considered taken, early supervision continues until [`HealthMonitor::start`] takes over.
+    pub fn with_early_supervision(mut self, early_supervision: EarlySupervision) -> Self {
+        self.config.early_supervision = Some(early_supervision);
         self
     }
 
@@ -94,7 +204,7 @@ impl HealthMonitorBuilder {
     ///
     /// - `cycle_duration` - interval between notifications.
     pub fn with_supervisor_api_cycle(mut self, cycle_duration: Duration) -> Self {
-        self.with_supervisor_api_cycle_internal(cycle_duration);
+        self.config.supervisor_api_cycle = cycle_duration;
         self
     }
 
@@ -102,81 +212,468 @@ impl HealthMonitorBuilder {
     ///
     /// - `cycle_duration` - interval between evaluations.
     pub fn with_internal_processing_cycle(mut self, cycle_duration: Duration) -> Self {
-        self.with_internal_processing_cycle_internal(cycle_duration);
+        self.config.internal_processing_cycle = cycle_duration;
+        self
+    }
+
+    /// Set the sizes of the bounded buffers used for health monitor bookkeeping.
+    ///
+    /// - `resource_budget` - buffer sizes, validated on [`Self::build`].
+    pub fn with_resource_budget(mut self, resource_budget: ResourceBudget) -> Self {
+        self.config.resource_budget = resource_budget;
+        self
+    }
+
+    /// Add a supervisor notification channel for the given [`ChannelTag`].
+    /// Allows a process hosting multiple supervised entities to report liveness of each of them separately.
+    ///
+    /// - `channel_tag` - unique tag for the channel, identifying the supervised entity.
+    /// - `monitor_tags` - monitors assigned to the channel.
+    ///
+    /// Errors of monitors assigned to a channel stop notifications of this channel only.
+    /// Errors of monitors not assigned to any channel stop all notifications, including the process one.
+    ///
+    /// # Note
+    ///
+    /// If a channel with the same tag already exists, it will be overwritten.
+    /// Each monitor can be assigned to at most one channel, this is validated on [`Self::build`].
+    pub fn add_notification_channel(mut self, channel_tag: ChannelTag, monitor_tags: &[MonitorTag]) -> Self {
+        self.config
+            .notification_channels
+            .insert(channel_tag, monitor_tags.to_vec());
+        self
+    }
+
+    /// Add a [`MonitorGroup`] for the given [`GroupTag`].
+    /// Violations of members are aggregated according to the group policy and reported as a single monitor,
+    /// with a [`MonitorTag`] created from `group_tag`, to failure callback, statistics and failure tolerance.
+    ///
+    /// - `group_tag` - unique tag for the group, must differ from tags of monitors.
+    /// - `group` - group members and policy.
+    ///
+    /// # Note
+    ///
+    /// If a group with the same tag already exists, it will be overwritten.
+    /// Each monitor can be a member of at most one group and members of a group must be assigned to the same
+    /// notification channel, or to none. This is validated on [`Self::build`].
+    pub fn add_group(mut self, group_tag: GroupTag, group: MonitorGroup) -> Self {
+        self.config.monitor_groups.insert(group_tag, group);
+        self
+    }
+
+    /// Set the callback invoked on every monitor violation.
+    /// Allows the application to react to violations, e.g. enter a degraded mode or flush data.
+    ///
+    /// - `callback` - called with the failing monitor tag and the error.
+    ///
+    /// # Note
+    ///
+    /// Callback is invoked from the monitoring thread and delays monitor evaluation, it should return quickly.
+    /// Panics of the callback are caught and reported as [`InternalFault::CallbackPanicked`].
+    ///
+    /// # Reentrancy
+    ///
+    /// APIs not waiting for the monitoring thread can be called from the callback: starting and stopping deadlines,
+    /// heartbeats, enabling and disabling monitors and reading history, statistics and status of the [`HealthMonitor`].
+    /// [`HealthMonitor::start`], [`HealthMonitor::stop`], [`HealthMonitor::restart`]
+    /// and [`HealthMonitor::run_on_current_thread`] are rejected with [`HealthMonitorError::WrongState`],
+    /// as the monitoring thread cannot wait for itself. Same applies to [`SignalSink`] implementations.
+    pub fn with_failure_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&MonitorTag, MonitorEvaluationError) + Send + 'static,
+    {
+        self.config.failure_callback = Some(Arc::new(Mutex::new(callback)));
+        self
+    }
+
+    /// Set the time budget of a single failure callback invocation, 10 ms by default.
+    /// Callback is not interrupted, invocation exceeding the budget is reported as [`InternalFault::CallbackOverrun`].
+    ///
+    /// - `budget` - maximum duration of the callback.
+    pub fn with_failure_callback_budget(mut self, budget: Duration) -> Self {
+        self.config.failure_callback_budget = budget;
+        self
+    }
+
+    /// Set how long the monitoring thread may not complete an evaluation cycle before it is treated as stalled,
+    /// four internal processing cycles by default.
+    ///
+    /// - `threshold` - maximum time between evaluation cycles, must be longer than the internal processing cycle.
+    pub fn with_worker_stall_threshold(mut self, threshold: Duration) -> Self {
+        self.config.worker_stall_threshold = Some(threshold);
+        self
+    }
+
+    /// Set a callback invoked when the monitoring thread itself stalls or panics,
+    /// so a dead monitoring thread does not silently end supervision.
+    /// Monitoring thread is watched by a separate watchdog thread once per internal processing cycle.
+    ///
+    /// - `callback` - called with the fault, stall is reported again only after the monitoring thread recovered.
+    ///
+    /// # Note
+    ///
+    /// Callback is invoked from the watchdog thread, same reentrancy rules as for
+    /// [`Self::with_failure_callback`] apply. Panics of the callback are caught and logged.
+    pub fn with_worker_fault_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(WorkerFault) + Send + 'static,
+    {
+        self.config.worker_fault_callback = Some(Arc::new(Mutex::new(callback)));
+        self
+    }
+
+    /// Enable detection of clock gaps, e.g. caused by system suspend or a paused process.
+    /// Evaluation delayed by more than `threshold` is skipped and all monitors are re-baselined,
+    /// so the gap is reported as a single [`InternalFault::ClockGapDetected`] instead of a burst of violations.
+    ///
+    /// - `threshold` - delay of an evaluation over the internal processing cycle treated as a clock gap.
+    pub fn with_clock_gap_threshold(mut self, threshold: Duration) -> Self {
+        self.config.clock_gap_threshold = Some(threshold);
+        self
+    }
+
+    /// Export statuses of selected monitors as named signals, e.g. for a vehicle abstraction layer.
+    /// Signals are published from the monitoring thread when monitoring starts and when a monitor fails.
+    ///
+    /// - `signal_export` - mapping of monitors to signal names, with the sink to publish through.
+    ///
+    /// # Note
+    ///
+    /// Exported monitors must be added before build, unless runtime monitor capacity is reserved
+    /// with [`ResourceBudget::with_runtime_monitor_capacity`].
+    pub fn with_signal_export(mut self, signal_export: SignalExport) -> Self {
+        self.config.signal_export = Some(signal_export);
+        self
+    }
+
+    /// Add a sink receiving every monitor violation, e.g. a DLT or persistence writer or a GPIO line.
+    /// Violations are dispatched to all sinks in order of addition, after the [`LoggingViolationSink`]
+    /// unless disabled with [`Self::without_violation_logging`].
+    ///
+    /// - `sink` - consumer of violations.
+    ///
+    /// # Note
+    ///
+    /// Sinks are invoked from the monitoring thread, same reentrancy rules as for [`Self::with_failure_callback`] apply.
+    /// Panics of a sink are caught and reported as [`InternalFault::CallbackPanicked`], other sinks are still invoked.
+    pub fn add_violation_sink(mut self, sink: impl ViolationSink + 'static) -> Self {
+        self.config.violation_sinks.push(Box::new(sink));
+        self
+    }
+
+    /// Do not log violations, e.g. when all violations are reported through sinks added with
+    /// [`Self::add_violation_sink`].
+    pub fn without_violation_logging(mut self) -> Self {
+        self.config.violation_logging_disabled = true;
+        self
+    }
+
+    /// Tolerate monitor violations before the process is treated as failed and supervisor notifications stop.
+    /// Applies to all monitors without own tolerance set with [`Self::with_monitor_failure_tolerance`].
+    /// By default no violation is tolerated.
+    ///
+    /// - `tolerance` - violations tolerated by each monitor.
+    pub fn with_failure_tolerance(mut self, tolerance: FailureTolerance) -> Self {
+        self.config.failure_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Tolerate violations of a single monitor, overriding tolerance set with [`Self::with_failure_tolerance`].
+    ///
+    /// - `monitor_tag` - tag of the monitor.
+    /// - `tolerance` - violations tolerated by the monitor.
+    pub fn with_monitor_failure_tolerance(mut self, monitor_tag: MonitorTag, tolerance: FailureTolerance) -> Self {
+        self.config.monitor_failure_tolerances.insert(monitor_tag, tolerance);
+        self
+    }
+
+    /// Set what happens after a monitor fails, by default monitoring stops and the supervisor is never notified again.
+    /// With [`RecoveryPolicy::ResumeAfter`] notifications are suspended only until the monitor evaluates clean again,
+    /// so transient failures do not stop monitoring for good.
+    ///
+    /// - `policy` - recovery policy applied to all monitors.
+    pub fn with_recovery_policy(mut self, policy: RecoveryPolicy) -> Self {
+        self.config.recovery_policy = policy;
+        self
+    }
+
+    /// Allow entering a software update mode with [`HealthMonitor::enter_update_mode`].
+    /// While update mode is active, violations of its advisory monitors do not degrade the health status,
+    /// whole update window is supervised instead.
+    ///
+    /// - `update_mode` - advisory monitors and the update window.
+    pub fn with_update_mode(mut self, update_mode: UpdateMode) -> Self {
+        self.config.update_mode = Some(update_mode);
+        self
+    }
+
+    /// Forward health summaries to an aggregator, e.g. for cross-ECU health collection in bench environments.
+    /// Summary is sent every supervisor API cycle and when the process is treated as failed.
+    ///
+    /// - `health_forwarder` - forwarder with the aggregator address.
+    pub fn with_health_forwarder(mut self, health_forwarder: UdpHealthForwarder) -> Self {
+        self.config.health_forwarder = Some(health_forwarder);
+        self
+    }
+
+    /// Report panics of the process to the supervisor, disabled by default.
+    /// Installs a panic hook which, while monitoring is running, notifies the supervisor the process is terminating,
+    /// flushes failure statistics to the log and forwards a failed health summary, if a forwarder is set.
+    /// Supervisor learns about the panic before alive notifications are missed, also if the process aborts on panic.
+    /// Launch manager daemon fails the supervision of the process on the termination checkpoint (ID 2),
+    /// which therefore must not be configured for the process.
+    ///
+    /// Only panics ending the process are reported: any panic if panics abort (`panic = "abort"`),
+    /// otherwise panics of the main thread. Panics of other threads and panics contained by the monitoring logic,
+    /// e.g. of the failure callback, are not reported. Panic hook cannot detect [`std::panic::catch_unwind`],
+    /// so a panic of the main thread caught by the application is reported as well, and as the first panic only
+    /// is reported, enable this only if the main thread does not recover from panics.
+    ///
+    /// - `enabled` - report panics.
+    pub fn with_panic_reporting(mut self, enabled: bool) -> Self {
+        self.config.panic_reporting = enabled;
+        self
+    }
+
+    /// Treat monitor violations as advisory for `grace` after monitoring is started,
+    /// e.g. for processes whose first cycles legitimately miss deadlines or heartbeats during cache warmup.
+    /// Advisory violations are logged and stored in the history, but do not degrade the health status
+    /// or stop supervisor notifications. Grace period applies again after every start and restart.
+    ///
+    /// - `grace` - duration of the grace period.
+    pub fn with_startup_grace(mut self, grace: Duration) -> Self {
+        self.config.startup_grace = Some(grace);
+        self
+    }
+
+    /// Allow starting the monitoring in two stages with [`HealthMonitor::arm`] and [`HealthMonitor::go`],
+    /// e.g. for processes bringing up many components under relaxed supervision.
+    /// While armed, violations are advisory as during the startup grace period,
+    /// time from arming to go is supervised instead.
+    ///
+    /// - `armed_start` - guard tag and timeout from arming to go.
+    pub fn with_armed_start(mut self, armed_start: ArmedStart) -> Self {
+        self.config.armed_start = Some(armed_start);
+        self
+    }
+
+    /// Check transitions of the aggregate health of every evaluation pass, e.g. failed process becoming healthy
+    /// without recovering. Invalid transitions are reported as logic violations under the trajectory monitor tag.
+    ///
+    /// - `health_trajectory` - monitor tag and allowed transitions.
+    pub fn with_health_trajectory(mut self, health_trajectory: HealthTrajectory) -> Self {
+        self.config.health_trajectory = Some(health_trajectory);
+        self
+    }
+
+    /// Notify the supervisor under `identity` instead of the identifier given by the `IDENTIFIER` env,
+    /// so multiple health monitors in one process report as distinct supervised entities.
+    ///
+    /// - `identity` - identifier of the supervised entity, must not be empty.
+    pub fn with_supervisor_identity(mut self, identity: &str) -> Self {
+        self.config.supervisor_identity = Some(identity.to_string());
+        self
+    }
+
+    /// Terminate the process on monitor failures selected by `policy`, disabled by default.
+    /// Meant for deployments where the platform restarts failed processes
+    /// and a lingering degraded process is worse than a restart.
+    ///
+    /// - `policy` - selected failures and the exit code.
+    pub fn with_fail_fast_policy(mut self, policy: FailFastPolicy) -> Self {
+        self.config.fail_fast = Some(policy);
+        self
+    }
+
+    /// Promote suspicious configurations, otherwise only logged as warnings, to errors, e.g. for production images.
+    /// Following conditions are checked by [`Self::validate`] and [`Self::build`]:
+    /// - supervisor API cycle longer than [`STRICT_MAX_SUPERVISOR_API_CYCLE`],
+    /// - internal processing cycle longer than [`STRICT_MAX_INTERNAL_PROCESSING_CYCLE`],
+    /// - deadline or artifact freshness monitor without deadlines or artifacts,
+    /// - deadline or heartbeat range shorter than the multiple of the clock granularity,
+    ///   see [`Self::with_clock_granularity_multiple`].
+    ///
+    /// [`HealthMonitor::start`] fails with [`HealthMonitorError::WrongState`]
+    /// if the stub supervisor API client is used in place of the supervisor.
+    ///
+    /// - `strict` - promote warnings to errors.
+    pub fn with_strict_validation(mut self, strict: bool) -> Self {
+        self.config.strict_validation = strict;
+        self
+    }
+
+    /// Set how many ticks of the monotonic clock the shortest deadline or heartbeat range must span,
+    /// [`DEFAULT_CLOCK_GRANULARITY_MULTIPLE`] by default. Clock granularity is probed once per process,
+    /// on some targets it is 10 ms, which makes shorter ranges meaningless.
+    /// Shorter ranges are logged as warnings, or rejected with [`Self::with_strict_validation`].
+    ///
+    /// - `multiple` - clock ticks the shortest range must span, zero disables the check.
+    pub fn with_clock_granularity_multiple(mut self, multiple: u32) -> Self {
+        self.config.clock_granularity_multiple = multiple;
         self
     }
 
+    /// Check the configuration and report all found issues at once.
+    /// [`HealthMonitorBuilder::build`] fails with the error of the first reported issue.
+    pub fn validate(&self) -> ValidationReport {
+        validation::validate_health_monitor_config(&self.config)
+    }
+
     /// Build a new [`HealthMonitor`] instance based on provided parameters.
     pub fn build(self) -> Result<HealthMonitor, HealthMonitorError> {
-        // Check cycle values.
-        // `supervisor_api_cycle` must be a multiple of `internal_processing_cycle`.
-        let supervisor_api_cycle_ms = self.supervisor_api_cycle.as_millis() as u64;
-        let internal_processing_cycle_ms = self.internal_processing_cycle.as_millis() as u64;
-        if !supervisor_api_cycle_ms.is_multiple_of(internal_processing_cycle_ms) {
-            error!(
-                "Supervisor API cycle duration ({} ms) must be a multiple of internal processing cycle interval ({} ms).",
-                supervisor_api_cycle_ms, internal_processing_cycle_ms
-            );
-            return Err(HealthMonitorError::InvalidArgument);
+        if let Some(error) = self.validate().error() {
+            return Err(error);
         }
+        let config = self.config;
 
-        // Check number of monitors.
-        let num_monitors = self.deadline_monitor_builders.len() + self.heartbeat_monitor_builders.len();
-        if num_monitors == 0 {
-            error!("No monitors have been added. HealthMonitor cannot be created.");
-            return Err(HealthMonitorError::WrongState);
-        }
+        let capacity_report = config.resource_budget.validate(config.num_monitors())?;
+
+        let config_summary = config_summary::summarize_health_monitor_config(&config);
 
         // Create allocator.
         let allocator = protected_memory::ProtectedMemoryAllocator {};
 
+        // Monitors of early supervision are already taken.
+        // Storage of monitors includes slots for monitors added at runtime, so it is never reallocated.
+        let runtime_monitor_capacity = config.resource_budget.runtime_monitor_capacity();
+        let early_supervision = config.early_supervision;
+        let (early_deadline_monitors, early_heartbeat_monitors) =
+            early_supervision.as_ref().map_or((0, 0), |early_supervision| {
+                (
+                    early_supervision.deadline_monitors().count(),
+                    early_supervision.heartbeat_monitors().count(),
+                )
+            });
+
+        // Create heartbeat monitors, producers of monitors derived from deadlines are handed to deadline monitors.
+        let mut heartbeat_monitors = OrderedMap::with_capacity(
+            early_heartbeat_monitors + config.heartbeat_monitor_builders.len() + runtime_monitor_capacity,
+        );
+        for (tag, (monitor, _)) in early_supervision.iter().flat_map(EarlySupervision::heartbeat_monitors) {
+            heartbeat_monitors.insert(*tag, Some(MonitorState::Taken(monitor.clone_shared())));
+        }
+        let mut heartbeat_sources: HashMap<MonitorTag, Vec<_>> = HashMap::new();
+        for (tag, mut builder) in config.heartbeat_monitor_builders {
+            let deadline_source = builder.take_deadline_source();
+            let monitor = builder.build(tag, config.internal_processing_cycle, &allocator)?;
+            if let Some((deadline_monitor_tag, deadline_tag)) = deadline_source {
+                // Producer of a new monitor is always available.
+                let producer = monitor.producer().map_err(|_| HealthMonitorError::WrongState)?;
+                heartbeat_sources
+                    .entry(deadline_monitor_tag)
+                    .or_default()
+                    .push((deadline_tag, producer));
+            }
+            heartbeat_monitors.insert(tag, Some(MonitorState::Available(monitor)));
+        }
+
         // Create deadline monitors.
-        let mut deadline_monitors = HashMap::new();
-        for (tag, builder) in self.deadline_monitor_builders {
+        let mut deadline_monitors = OrderedMap::with_capacity(
+            early_deadline_monitors + config.deadline_monitor_builders.len() + runtime_monitor_capacity,
+        );
+        for (tag, (monitor, _)) in early_supervision.iter().flat_map(EarlySupervision::deadline_monitors) {
+            deadline_monitors.insert(*tag, Some(MonitorState::Taken(monitor.clone_shared())));
+        }
+        for (tag, mut builder) in config.deadline_monitor_builders {
+            for (deadline_tag, producer) in heartbeat_sources.remove(&tag).into_iter().flatten() {
+                builder.with_heartbeat_internal(deadline_tag, producer);
+            }
             let monitor = builder.build(tag, &allocator);
             deadline_monitors.insert(tag, Some(MonitorState::Available(monitor)));
         }
 
-        // Create heartbeat monitors.
-        let mut heartbeat_monitors = HashMap::new();
-        for (tag, builder) in self.heartbeat_monitor_builders {
-            let monitor = builder.build(tag, self.internal_processing_cycle, &allocator)?;
-            heartbeat_monitors.insert(tag, Some(MonitorState::Available(monitor)));
+        // Create artifact freshness monitors.
+        let mut artifact_freshness_monitors = Vec::with_capacity(config.artifact_freshness_monitor_builders.len());
+        for (tag, builder) in config.artifact_freshness_monitor_builders {
+            let monitor = builder.build(tag, config.internal_processing_cycle, &allocator)?;
+            artifact_freshness_monitors.push(Arc::new(monitor));
+        }
+
+        let update_mode = config
+            .update_mode
+            .map(|update_mode| Arc::new(UpdateModeGuard::new(update_mode)));
+        let armed_start = config
+            .armed_start
+            .map(|armed_start| Arc::new(ArmGuard::new(armed_start)));
+        let health_trajectory = config
+            .health_trajectory
+            .map(|health_trajectory| Arc::new(TrajectoryGuard::new(health_trajectory)));
+
+        let monitor_tags = deadline_monitors
+            .keys()
+            .chain(heartbeat_monitors.keys())
+            .copied()
+            .chain(artifact_freshness_monitors.iter().map(|monitor| monitor.monitor_tag()))
+            .chain(update_mode.as_ref().map(|update_mode| update_mode.monitor_tag()))
+            .chain(armed_start.as_ref().map(|armed_start| armed_start.monitor_tag()))
+            .chain(
+                health_trajectory
+                    .as_ref()
+                    .map(|health_trajectory| health_trajectory.monitor_tag()),
+            )
+            .collect();
+
+        let mut violation_sinks: Vec<Box<dyn ViolationSink>> = Vec::with_capacity(config.violation_sinks.len() + 1);
+        if !config.violation_logging_disabled {
+            violation_sinks.push(Box::new(LoggingViolationSink));
         }
+        violation_sinks.extend(config.violation_sinks);
 
+        info!("HealthMonitor built: {:?}.", config_summary);
         Ok(HealthMonitor {
             deadline_monitors,
             heartbeat_monitors,
-            worker: worker::UniqueThreadRunner::new(self.internal_processing_cycle),
-            supervisor_api_cycle: self.supervisor_api_cycle,
+            artifact_freshness_monitors,
+            worker: worker::UniqueThreadRunner::new(
+                config.internal_processing_cycle,
+                config
+                    .worker_stall_threshold
+                    .unwrap_or(config.internal_processing_cycle * DEFAULT_WORKER_STALL_CYCLES),
+            )
+            .with_worker_fault_callback(config.worker_fault_callback),
+            internal_processing_cycle: config.internal_processing_cycle,
+            supervisor_api_cycle: config.supervisor_api_cycle,
+            violation_history: Arc::new(ViolationHistory::new(
+                config.resource_budget.violation_history_capacity(),
+                config.resource_budget.internal_fault_history_capacity(),
+            )),
+            starting_point: Instant::now(),
+            failure_statistics: Arc::new(FailureStatistics::new(capacity_report.monitors)),
+            capacity_report,
+            notification_channels: config.notification_channels.into_iter().collect(),
+            monitor_groups: config.monitor_groups.into_iter().collect(),
+            status_board: Arc::new(StatusBoard::new(
+                monitor_tags,
+                config.resource_budget.runtime_monitor_capacity(),
+            )),
+            config_summary,
+            failure_callback: config.failure_callback,
+            failure_callback_budget: config.failure_callback_budget,
+            clock_gap_threshold: config.clock_gap_threshold,
+            signal_export: config
+                .signal_export
+                .map(|signal_export| Arc::new(Mutex::new(signal_export))),
+            violation_sinks: Arc::new(Mutex::new(ViolationFanOut::new(violation_sinks))),
+            runtime_monitors: Arc::new(HandoffQueue::new()),
+            runtime_monitor_capacity: config.resource_budget.runtime_monitor_capacity(),
+            failure_tolerance: config.failure_tolerance,
+            monitor_failure_tolerances: config.monitor_failure_tolerances,
+            health_forwarder: config.health_forwarder.map(Arc::new),
+            recovery_policy: config.recovery_policy,
+            update_mode,
+            panic_reporting: config.panic_reporting,
+            panic_reporter: None,
+            fail_fast: config.fail_fast,
+            startup_grace: config.startup_grace,
+            armed_start,
+            health_trajectory,
+            early_supervision,
+            supervisor_identity: config.supervisor_identity,
+            challenge: ChallengeHandle::new(),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            strict_validation: config.strict_validation,
         })
     }
-
-    // Used by FFI and config parsing code which prefer not to move builder instance
-
-    pub(crate) fn add_deadline_monitor_internal(
-        &mut self,
-        monitor_tag: MonitorTag,
-        monitor_builder: DeadlineMonitorBuilder,
-    ) {
-        self.deadline_monitor_builders.insert(monitor_tag, monitor_builder);
-    }
-
-    pub(crate) fn add_heartbeat_monitor_internal(
-        &mut self,
-        monitor_tag: MonitorTag,
-        monitor_builder: HeartbeatMonitorBuilder,
-    ) {
-        self.heartbeat_monitor_builders.insert(monitor_tag, monitor_builder);
-    }
-
-    pub(crate) fn with_supervisor_api_cycle_internal(&mut self, cycle_duration: Duration) {
-        self.supervisor_api_cycle = cycle_duration;
-    }
-
-    pub(crate) fn with_internal_processing_cycle_internal(&mut self, cycle_duration: Duration) {
-        self.internal_processing_cycle = cycle_duration;
-    }
 }
 
 /// Monitor ownership state in the [`HealthMonitor`].
@@ -184,43 +681,218 @@ enum MonitorState<M> {
     /// Monitor is available.
     Available(M),
     /// Monitor is already taken.
-    Taken(MonitorEvalHandle),
+    /// Instance sharing state with the taken monitor is kept for evaluation and to make the monitor available again.
+    Taken(M),
+    /// Monitor was returned by its owner, evaluation is suspended until the monitor is taken again.
+    Returned(M),
 }
 
 /// Monitor container.
 /// - Must be an option to ensure monitor can be taken out (not referenced).
-/// - Must be an enum to ensure evaluation handle is still available for HMON after monitor is taken.
+/// - Must be an enum to ensure monitor is still available for HMON after monitor is taken.
 type MonitorContainer<M> = Option<MonitorState<M>>;
 
 /// Health monitor.
 pub struct HealthMonitor {
-    deadline_monitors: HashMap<MonitorTag, MonitorContainer<DeadlineMonitor>>,
-    heartbeat_monitors: HashMap<MonitorTag, MonitorContainer<HeartbeatMonitor>>,
+    deadline_monitors: OrderedMap<MonitorTag, MonitorContainer<DeadlineMonitor>>,
+    heartbeat_monitors: OrderedMap<MonitorTag, MonitorContainer<HeartbeatMonitor>>,
+    /// Artifact freshness monitors are never taken, so they are always ready for evaluation.
+    artifact_freshness_monitors: Vec<Arc<ArtifactFreshnessMonitor>>,
     worker: worker::UniqueThreadRunner,
+    internal_processing_cycle: Duration,
     supervisor_api_cycle: Duration,
+    violation_history: Arc<ViolationHistory>,
+    /// Time base of internal faults found while starting, see [`InternalFaultRecord::timestamp`].
+    starting_point: Instant,
+    failure_statistics: Arc<FailureStatistics>,
+    capacity_report: CapacityReport,
+    notification_channels: Vec<(ChannelTag, Vec<MonitorTag>)>,
+    monitor_groups: Vec<(GroupTag, MonitorGroup)>,
+    status_board: Arc<StatusBoard>,
+    config_summary: ConfigSummary,
+    failure_callback: Option<worker::FailureCallback>,
+    failure_callback_budget: Duration,
+    clock_gap_threshold: Option<Duration>,
+    signal_export: Option<worker::SharedSignalExport>,
+    violation_sinks: worker::SharedViolationSinks,
+    /// Monitors added while monitoring is running, handed over to the monitoring logic.
+    runtime_monitors: Arc<HandoffQueue<MonitorEvalHandle>>,
+    /// Number of monitors which can still be added at runtime.
+    runtime_monitor_capacity: usize,
+    failure_tolerance: Option<FailureTolerance>,
+    monitor_failure_tolerances: HashMap<MonitorTag, FailureTolerance>,
+    health_forwarder: Option<Arc<UdpHealthForwarder>>,
+    recovery_policy: RecoveryPolicy,
+    /// Update mode state, evaluated as a monitor supervising the update window.
+    update_mode: Option<Arc<UpdateModeGuard>>,
+    panic_reporting: bool,
+    /// Reporter registered in the panic hook while monitoring is running.
+    panic_reporter: Option<Arc<dyn PanicReporter>>,
+    fail_fast: Option<FailFastPolicy>,
+    startup_grace: Option<Duration>,
+    /// Armed state of the two-stage start, evaluated as a monitor supervising the time from arming to go.
+    armed_start: Option<Arc<ArmGuard>>,
+    /// Health states of evaluation passes, evaluated as a logic monitor checking their transitions.
+    health_trajectory: Option<Arc<TrajectoryGuard>>,
+    /// Supervision of monitors created before the build, handed over on the first start.
+    early_supervision: Option<EarlySupervision>,
+    supervisor_identity: Option<String>,
+    /// Challenges answered by the monitoring logic, shared by all handles given out.
+    challenge: ChallengeHandle,
+    /// Orderly shutdown was begun with [`Self::begin_shutdown`].
+    shutdown: Arc<AtomicBool>,
+    strict_validation: bool,
 }
 
 impl HealthMonitor {
-    fn get_monitor<M: Monitor>(
-        monitors: &mut HashMap<MonitorTag, MonitorContainer<M>>,
-        monitor_tag: MonitorTag,
-    ) -> Option<M> {
-        let monitor_state = monitors.get_mut(&monitor_tag)?;
-
+    /// Take the monitor stored in `monitor_state`.
+    fn take_monitor<M: Monitor>(monitor_state: &mut MonitorContainer<M>) -> Option<M> {
         match monitor_state.take() {
             Some(MonitorState::Available(monitor)) => {
-                monitor_state.replace(MonitorState::Taken(monitor.get_eval_handle()));
+                monitor_state.replace(MonitorState::Taken(monitor.clone_shared()));
                 Some(monitor)
             },
-            Some(MonitorState::Taken(handle)) => {
-                // Taken handle is inserted back.
-                monitor_state.replace(MonitorState::Taken(handle));
+            Some(MonitorState::Taken(monitor)) => {
+                // Taken monitor is inserted back.
+                monitor_state.replace(MonitorState::Taken(monitor));
                 None
             },
+            Some(MonitorState::Returned(monitor)) => {
+                monitor.set_enabled(true);
+                monitor_state.replace(MonitorState::Taken(monitor.clone_shared()));
+                Some(monitor)
+            },
             None => None,
         }
     }
 
+    /// Return the `monitor` into `monitor_state`.
+    fn return_monitor<M: Monitor>(
+        monitor_state: Option<&mut MonitorContainer<M>>,
+        monitor_tag: MonitorTag,
+        monitor: M,
+    ) -> Result<(), HealthMonitorError> {
+        let Some(monitor_state) = monitor_state else {
+            error!("Monitor {:?} not found.", monitor_tag);
+            return Err(HealthMonitorError::NotFound);
+        };
+
+        match monitor_state {
+            Some(MonitorState::Taken(taken_monitor)) if taken_monitor.shares_state(&monitor) => {
+                monitor.set_enabled(false);
+                monitor_state.replace(MonitorState::Returned(monitor));
+                info!("Monitor {:?} returned.", monitor_tag);
+                Ok(())
+            },
+            Some(MonitorState::Taken(_)) => {
+                error!("Returned monitor is not an instance of monitor {:?}.", monitor_tag);
+                Err(HealthMonitorError::InvalidArgument)
+            },
+            _ => {
+                error!("Monitor {:?} cannot be returned, it is not taken.", monitor_tag);
+                Err(HealthMonitorError::WrongState)
+            },
+        }
+    }
+
+    /// Check a monitor with the `monitor_tag` can be added at runtime.
+    fn check_runtime_monitor(&self, monitor_tag: MonitorTag) -> Result<(), HealthMonitorError> {
+        if self.deadline_monitors.contains_key(&monitor_tag)
+            || self.heartbeat_monitors.contains_key(&monitor_tag)
+            || self
+                .artifact_freshness_monitors
+                .iter()
+                .any(|monitor| monitor.monitor_tag() == monitor_tag)
+        {
+            error!("Monitor {:?} already exists.", monitor_tag);
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+
+        if self.runtime_monitor_capacity == 0 {
+            error!(
+                "Monitor {:?} cannot be added, runtime monitor capacity is exhausted.",
+                monitor_tag
+            );
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        Ok(())
+    }
+
+    /// Register the monitor added at runtime, returns the monitor taken by the caller.
+    ///
+    /// - `runtime_monitors` - queue to hand the monitor over to the running monitoring logic.
+    ///   Not provided if monitoring is not started, the monitor is then collected on start.
+    fn register_runtime_monitor<M: Monitor>(
+        monitors: &mut OrderedMap<MonitorTag, MonitorContainer<M>>,
+        monitor_tag: MonitorTag,
+        monitor: M,
+        runtime_monitors: Option<&HandoffQueue<MonitorEvalHandle>>,
+    ) -> Result<M, HealthMonitorError> {
+        if monitors
+            .try_insert(monitor_tag, Some(MonitorState::Taken(monitor.clone_shared())))
+            .is_err()
+        {
+            // Should not fail - capacity was preallocated for runtime monitors.
+            error!("Failed to store monitor {:?} added at runtime.", monitor_tag);
+            return Err(HealthMonitorError::WrongState);
+        }
+        if let Some(runtime_monitors) = runtime_monitors {
+            runtime_monitors.push(monitor.get_eval_handle());
+        }
+        info!("Monitor {:?} added at runtime.", monitor_tag);
+        Ok(monitor)
+    }
+
+    /// Take a runtime monitor slot, [`Self::check_runtime_monitor`] must succeed first.
+    fn reserve_runtime_monitor_slot(&mut self, monitor_tag: MonitorTag) {
+        self.runtime_monitor_capacity -= 1;
+        // Status board has a spare slot for each runtime monitor, registration cannot fail.
+        self.status_board.register(monitor_tag);
+    }
+
+    /// Build and add a [`DeadlineMonitor`] after the [`HealthMonitor`] is built, also while monitoring is running.
+    /// Number of monitors added this way is limited by [`ResourceBudget::with_runtime_monitor_capacity`].
+    ///
+    /// - `monitor_tag` - unique tag for the [`DeadlineMonitor`].
+    /// - `monitor_builder` - monitor builder to finalize.
+    ///
+    /// Returns the added monitor, already taken by the caller.
+    pub fn add_deadline_monitor_runtime(
+        &mut self,
+        monitor_tag: MonitorTag,
+        monitor_builder: DeadlineMonitorBuilder,
+    ) -> Result<DeadlineMonitor, HealthMonitorError> {
+        self.check_runtime_monitor(monitor_tag)?;
+        let allocator = protected_memory::ProtectedMemoryAllocator {};
+        let monitor = monitor_builder.build(monitor_tag, &allocator);
+
+        self.reserve_runtime_monitor_slot(monitor_tag);
+        let runtime_monitors = self.worker.is_started().then_some(&*self.runtime_monitors);
+        Self::register_runtime_monitor(&mut self.deadline_monitors, monitor_tag, monitor, runtime_monitors)
+    }
+
+    /// Build and add a [`HeartbeatMonitor`] after the [`HealthMonitor`] is built, also while monitoring is running.
+    /// Number of monitors added this way is limited by [`ResourceBudget::with_runtime_monitor_capacity`].
+    ///
+    /// - `monitor_tag` - unique tag for the [`HeartbeatMonitor`].
+    /// - `monitor_builder` - monitor builder to finalize.
+    ///
+    /// Returns the added monitor, already taken by the caller.
+    pub fn add_heartbeat_monitor_runtime(
+        &mut self,
+        monitor_tag: MonitorTag,
+        monitor_builder: HeartbeatMonitorBuilder,
+    ) -> Result<HeartbeatMonitor, HealthMonitorError> {
+        self.check_runtime_monitor(monitor_tag)?;
+        let allocator = protected_memory::ProtectedMemoryAllocator {};
+        let monitor = monitor_builder.build(monitor_tag, self.internal_processing_cycle, &allocator)?;
+
+        self.reserve_runtime_monitor_slot(monitor_tag);
+        let runtime_monitors = self.worker.is_started().then_some(&*self.runtime_monitors);
+        Self::register_runtime_monitor(&mut self.heartbeat_monitors, monitor_tag, monitor, runtime_monitors)
+    }
+
     /// Get and pass ownership of a [`DeadlineMonitor`] for the given [`MonitorTag`].
     ///
     /// - `monitor_tag` - unique tag for the [`DeadlineMonitor`].
@@ -228,7 +900,9 @@ impl HealthMonitor {
     /// Returns [`Some`] containing [`DeadlineMonitor`] if found and not taken.
     /// Otherwise returns [`None`].
     pub fn get_deadline_monitor(&mut self, monitor_tag: MonitorTag) -> Option<DeadlineMonitor> {
-        Self::get_monitor(&mut self.deadline_monitors, monitor_tag)
+        self.deadline_monitors
+            .get_mut(&monitor_tag)
+            .and_then(Self::take_monitor)
     }
 
     /// Get and pass ownership of a [`HeartbeatMonitor`] for the given [`MonitorTag`].
@@ -238,41 +912,43 @@ impl HealthMonitor {
     /// Returns [`Some`] containing [`HeartbeatMonitor`] if found and not taken.
     /// Otherwise returns [`None`].
     pub fn get_heartbeat_monitor(&mut self, monitor_tag: MonitorTag) -> Option<HeartbeatMonitor> {
-        Self::get_monitor(&mut self.heartbeat_monitors, monitor_tag)
+        self.heartbeat_monitors
+            .get_mut(&monitor_tag)
+            .and_then(Self::take_monitor)
+    }
+
+    /// Return a [`DeadlineMonitor`] taken with [`Self::get_deadline_monitor`], e.g. when its subsystem is shut down.
+    /// Evaluation of the monitor is suspended until it is taken again.
+    ///
+    /// - `monitor_tag` - unique tag for the [`DeadlineMonitor`].
+    /// - `monitor` - taken monitor.
+    ///
+    /// Returns [`HealthMonitorError::NotFound`] if the monitor does not exist,
+    /// [`HealthMonitorError::WrongState`] if it is not taken
+    /// and [`HealthMonitorError::InvalidArgument`] if `monitor` is not the taken monitor.
+    pub fn return_deadline_monitor(
+        &mut self,
+        monitor_tag: MonitorTag,
+        monitor: DeadlineMonitor,
+    ) -> Result<(), HealthMonitorError> {
+        Self::return_monitor(self.deadline_monitors.get_mut(&monitor_tag), monitor_tag, monitor)
     }
 
-    fn collect_given_monitors<M>(
-        monitors_to_collect: &mut HashMap<MonitorTag, MonitorContainer<M>>,
-        collected_monitors: &mut FixedCapacityVec<MonitorEvalHandle>,
+    /// Return a [`HeartbeatMonitor`] taken with [`Self::get_heartbeat_monitor`], e.g. when its subsystem is shut down.
+    /// Evaluation of the monitor is suspended until it is taken again.
+    ///
+    /// - `monitor_tag` - unique tag for the [`HeartbeatMonitor`].
+    /// - `monitor` - taken monitor.
+    ///
+    /// Returns [`HealthMonitorError::NotFound`] if the monitor does not exist,
+    /// [`HealthMonitorError::WrongState`] if it is not taken
+    /// and [`HealthMonitorError::InvalidArgument`] if `monitor` is not the taken monitor.
+    pub fn return_heartbeat_monitor(
+        &mut self,
+        monitor_tag: MonitorTag,
+        monitor: HeartbeatMonitor,
     ) -> Result<(), HealthMonitorError> {
-        for (tag, monitor) in monitors_to_collect.iter_mut() {
-            match monitor.take() {
-                Some(MonitorState::Taken(handle)) => {
-                    if collected_monitors.push(handle).is_err() {
-                        // Should not fail - capacity was preallocated.
-                        error!("Failed to push monitor handle.");
-                        return Err(HealthMonitorError::WrongState);
-                    }
-                },
-                Some(MonitorState::Available(m)) => {
-                    // Reinsert into collection.
-                    monitor.replace(MonitorState::Available(m));
-                    error!(
-                        "All monitors must be taken before starting HealthMonitor but {:?} is not taken.",
-                        tag
-                    );
-                    return Err(HealthMonitorError::WrongState);
-                },
-                None => {
-                    error!(
-                        "Invalid monitor ({:?}) state encountered while starting HealthMonitor.",
-                        tag
-                    );
-                    return Err(HealthMonitorError::WrongState);
-                },
-            }
-        }
-        Ok(())
+        Self::return_monitor(self.heartbeat_monitors.get_mut(&monitor_tag), monitor_tag, monitor)
     }
 
     /// Start the health monitoring logic in a separate thread.
@@ -284,257 +960,2232 @@ impl HealthMonitor {
     /// This method shall be called before `Lifecycle.running()`.
     /// Otherwise the supervisor might consider the process not alive.
     ///
-    /// Health monitoring logic stops when the [`HealthMonitor`] is dropped.
+    /// Health monitoring logic stops when [`Self::stop`] is called or the [`HealthMonitor`] is dropped.
     pub fn start(&mut self) -> Result<(), HealthMonitorError> {
-        // Collect all monitors.
-        let num_monitors = self.deadline_monitors.len() + self.heartbeat_monitors.len();
-        let mut collected_monitors = FixedCapacityVec::new(num_monitors);
-        Self::collect_given_monitors(&mut self.deadline_monitors, &mut collected_monitors)?;
-        Self::collect_given_monitors(&mut self.heartbeat_monitors, &mut collected_monitors)?;
-
-        // Start monitoring logic.
-        let monitoring_logic = worker::MonitoringLogic::new(
-            collected_monitors,
-            self.supervisor_api_cycle,
-            #[cfg(not(any(test, feature = "stub_supervisor_api_client")))]
-            supervisor_api_client::score_supervisor_api_client::ScoreSupervisorAPIClient::new(),
-            #[cfg(any(test, feature = "stub_supervisor_api_client"))]
-            supervisor_api_client::stub_supervisor_api_client::StubSupervisorAPIClient::new(),
-        );
+        let monitoring_logic = self.create_monitoring_logic()?;
+        self.worker.start(monitoring_logic);
+        Ok(())
+    }
 
+    /// Start the health monitoring logic armed, first stage of the start configured with
+    /// [`HealthMonitorBuilder::with_armed_start`].
+    ///
+    /// Same as [`Self::start`], the supervisor is notified from this point,
+    /// but violations of all monitors are advisory until [`Self::go`] is called.
+    /// Not calling [`Self::go`] within the configured timeout is reported under the guard tag
+    /// and begins strict supervision.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if armed start is not configured or monitoring cannot be started.
+    pub fn arm(&mut self) -> Result<(), HealthMonitorError> {
+        let Some(armed_start) = self.armed_start.clone() else {
+            error!("Armed start is not configured.");
+            return Err(HealthMonitorError::WrongState);
+        };
+        let monitoring_logic = self.create_monitoring_logic()?;
+        armed_start.arm();
         self.worker.start(monitoring_logic);
         Ok(())
     }
 
-    //TODO: Add possibility to run HM in the current thread - ie in main
-}
+    /// Begin strict supervision of monitoring armed with [`Self::arm`], e.g. once all components reported ready.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if armed start is not configured or monitoring is not armed,
+    /// e.g. after the timeout was exceeded.
+    pub fn go(&self) -> Result<(), HealthMonitorError> {
+        let Some(armed_start) = &self.armed_start else {
+            error!("Armed start is not configured.");
+            return Err(HealthMonitorError::WrongState);
+        };
+        armed_start.go().map(|_| ())
+    }
+
+    /// Stop the health monitoring logic started with [`Self::start`].
+    ///
+    /// Joins the monitoring thread and returns all monitors to the available state,
+    /// so they can be acquired again and monitoring can be started again.
+    /// Monitor instances still held by the application remain usable and share state with the returned ones.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if monitoring is not started.
+    pub fn stop(&mut self) -> Result<(), HealthMonitorError> {
+        Self::check_not_in_callback()?;
+        if !self.worker.is_started() {
+            error!("HealthMonitor cannot be stopped, monitoring is not started.");
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        self.worker.join();
+        self.panic_reporter = None;
+        Self::release_given_monitors(self.deadline_monitors.values_mut());
+        Self::release_given_monitors(self.heartbeat_monitors.values_mut());
+        info!("Monitoring stopped.");
+        Ok(())
+    }
+
+    /// Restart the health monitoring logic started with [`Self::start`].
+    ///
+    /// Joins the monitoring thread and starts it again with a fresh supervisor cycle.
+    /// Monitors remain taken by the application.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if monitoring is not started.
+    pub fn restart(&mut self) -> Result<(), HealthMonitorError> {
+        Self::check_not_in_callback()?;
+        if !self.worker.is_started() {
+            error!("HealthMonitor cannot be restarted, monitoring is not started.");
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        self.worker.join();
+        info!("Monitoring restarting.");
+        self.start()
+    }
+
+    /// Monitoring cannot be controlled from callbacks invoked by the monitoring logic,
+    /// the monitoring thread would wait for itself.
+    fn check_not_in_callback() -> Result<(), HealthMonitorError> {
+        if worker::in_monitoring_callback() {
+            error!("Monitoring cannot be started or stopped from a monitoring callback.");
+            return Err(HealthMonitorError::WrongState);
+        }
+        Ok(())
+    }
+
+    fn release_given_monitors<'a, M: 'a>(monitors_to_release: impl IntoIterator<Item = &'a mut MonitorContainer<M>>) {
+        for monitor in monitors_to_release {
+            match monitor.take() {
+                Some(MonitorState::Taken(m)) => {
+                    monitor.replace(MonitorState::Available(m));
+                },
+                // Returned monitors stay returned, evaluation is resumed once they are taken again.
+                other => *monitor = other,
+            }
+        }
+    }
+
+    fn set_given_monitor_enabled<M: Monitor>(
+        monitors: &OrderedMap<MonitorTag, MonitorContainer<M>>,
+        monitor_tag: MonitorTag,
+        enabled: bool,
+    ) -> bool {
+        match monitors.get(&monitor_tag) {
+            Some(Some(
+                MonitorState::Available(monitor) | MonitorState::Taken(monitor) | MonitorState::Returned(monitor),
+            )) => {
+                monitor.set_enabled(enabled);
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn set_monitor_enabled(&self, monitor_tag: MonitorTag, enabled: bool) -> Result<(), HealthMonitorError> {
+        let found = Self::set_given_monitor_enabled(&self.deadline_monitors, monitor_tag, enabled)
+            || Self::set_given_monitor_enabled(&self.heartbeat_monitors, monitor_tag, enabled)
+            || match self
+                .artifact_freshness_monitors
+                .iter()
+                .find(|monitor| monitor.monitor_tag() == monitor_tag)
+            {
+                Some(monitor) => {
+                    monitor.set_enabled(enabled);
+                    true
+                },
+                None => false,
+            };
+
+        if !found {
+            error!("Monitor {:?} not found.", monitor_tag);
+            return Err(HealthMonitorError::NotFound);
+        }
+
+        info!(
+            "Monitor {:?} {}.",
+            monitor_tag,
+            if enabled { "enabled" } else { "disabled" }
+        );
+        Ok(())
+    }
+
+    /// Suspend supervision of the monitor, e.g. during calibration or diagnostic sessions.
+    /// Disabled monitor reports no errors, the monitor can still be used by the application.
+    ///
+    /// - `monitor_tag` - tag of the monitor to disable.
+    ///
+    /// Returns [`HealthMonitorError::NotFound`] if the monitor does not exist.
+    pub fn disable_monitor(&self, monitor_tag: MonitorTag) -> Result<(), HealthMonitorError> {
+        self.set_monitor_enabled(monitor_tag, false)
+    }
+
+    /// Resume supervision of the monitor disabled with [`Self::disable_monitor`].
+    /// Supervision starts from scratch, activity while disabled is not evaluated.
+    ///
+    /// - `monitor_tag` - tag of the monitor to enable.
+    ///
+    /// Returns [`HealthMonitorError::NotFound`] if the monitor does not exist.
+    pub fn enable_monitor(&self, monitor_tag: MonitorTag) -> Result<(), HealthMonitorError> {
+        self.set_monitor_enabled(monitor_tag, true)
+    }
+
+    /// Begin an orderly shutdown of the process.
+    ///
+    /// All monitors are disabled, so deadlines and heartbeats ending abruptly during termination are not reported.
+    /// Within one internal processing cycle the monitoring logic sends a final stopping notification
+    /// to supervisors supporting it and stops, [`Self::stop`] is still needed to join it.
+    /// Launch manager daemon is not notified, it stops supervising the process once its state is reported off.
+    /// Monitors stay disabled if monitoring is started again.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if monitoring is not started.
+    pub fn begin_shutdown(&self) -> Result<(), HealthMonitorError> {
+        if !self.worker.is_started() {
+            error!("Shutdown cannot begin, monitoring is not started.");
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        for monitor_state in self.deadline_monitors.values().flatten() {
+            let (MonitorState::Available(monitor) | MonitorState::Taken(monitor) | MonitorState::Returned(monitor)) =
+                monitor_state;
+            monitor.set_enabled(false);
+        }
+        for monitor_state in self.heartbeat_monitors.values().flatten() {
+            let (MonitorState::Available(monitor) | MonitorState::Taken(monitor) | MonitorState::Returned(monitor)) =
+                monitor_state;
+            monitor.set_enabled(false);
+        }
+        for monitor in self.artifact_freshness_monitors.iter() {
+            monitor.set_enabled(false);
+        }
+        self.shutdown.store(true, Ordering::Release);
+        info!("Orderly shutdown begun, all monitors disabled.");
+        Ok(())
+    }
+
+    /// Enter the software update mode configured with [`HealthMonitorBuilder::with_update_mode`].
+    /// Violations of advisory monitors are logged and stored in the history only,
+    /// exceeding the update window is reported under the guard tag and ends the update mode.
+    ///
+    /// - `token` - identifier of the update, required to exit and reported as correlation ID on window violation.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if update mode is not configured or already active.
+    pub fn enter_update_mode(&self, token: u64) -> Result<(), HealthMonitorError> {
+        let Some(update_mode) = &self.update_mode else {
+            error!("Update mode is not configured.");
+            return Err(HealthMonitorError::WrongState);
+        };
+        update_mode.enter(token)
+    }
+
+    /// Exit the software update mode entered with [`Self::enter_update_mode`], restoring normal severity.
+    ///
+    /// - `token` - identifier of the update passed on entry.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if update mode is not configured or not active,
+    /// e.g. after the update window was exceeded,
+    /// [`HealthMonitorError::InvalidArgument`] if `token` does not match.
+    pub fn exit_update_mode(&self, token: u64) -> Result<(), HealthMonitorError> {
+        let Some(update_mode) = &self.update_mode else {
+            error!("Update mode is not configured.");
+            return Err(HealthMonitorError::WrongState);
+        };
+        update_mode.exit(token).map(|_| ())
+    }
+
+    /// Run the health monitoring logic in the current thread.
+    /// Alternative to [`Self::start`] for applications driving the monitoring loop themselves, e.g. from `main()`.
+    ///
+    /// - `stop_token` - token requesting the loop to exit, checked once per internal processing cycle.
+    ///
+    /// Blocks until stop is requested or a monitor reports an error.
+    /// Returns reason of the exit, or an error if monitoring could not be started.
+    ///
+    /// # Notes
+    ///
+    /// Same as for [`Self::start`], all monitors must be taken before calling this method.
+    pub fn run_on_current_thread(&mut self, stop_token: &StopToken) -> Result<RunExit, HealthMonitorError> {
+        let mut monitoring_logic = self.create_monitoring_logic()?;
+        info!("Monitoring started on current thread.");
+        let run_exit = monitoring_logic.run_loop(self.internal_processing_cycle, stop_token);
+        self.panic_reporter = None;
+        info!("Monitoring on current thread exited: {:?}.", run_exit);
+        Ok(run_exit)
+    }
+
+    /// Most recent violations reported by the monitors, oldest first.
+    /// Number of kept violations is limited by [`ResourceBudget::with_violation_history_capacity`].
+    pub fn violation_history(&self) -> Vec<ViolationRecord> {
+        self.violation_history.records()
+    }
+
+    /// Most recent faults of the health monitoring library itself, oldest first.
+    /// Number of kept faults is limited by [`ResourceBudget::with_internal_fault_history_capacity`].
+    pub fn internal_faults(&self) -> Vec<InternalFaultRecord> {
+        self.violation_history.internal_faults()
+    }
+
+    /// Failure counters of all monitors which failed at least once, ordered by monitor tag.
+    /// Counters are accumulated since the health monitor was built, including restarts.
+    pub fn statistics(&self) -> Vec<(MonitorTag, MonitorStatistics)> {
+        self.failure_statistics.all()
+    }
+
+    /// Failure counters of the monitor, all zero if the monitor never failed.
+    ///
+    /// - `monitor_tag` - tag of the monitor.
+    ///
+    /// Returns [`HealthMonitorError::NotFound`] if the monitor does not exist.
+    pub fn monitor_statistics(&self, monitor_tag: MonitorTag) -> Result<MonitorStatistics, HealthMonitorError> {
+        let found = self.deadline_monitors.contains_key(&monitor_tag)
+            || self.heartbeat_monitors.contains_key(&monitor_tag)
+            || self
+                .artifact_freshness_monitors
+                .iter()
+                .any(|monitor| monitor.monitor_tag() == monitor_tag);
+        if !found {
+            return Err(HealthMonitorError::NotFound);
+        }
+
+        Ok(self.failure_statistics.get(monitor_tag))
+    }
+
+    /// Failure counters of the group, all zero if the group never failed.
+    ///
+    /// - `group_tag` - tag of the group.
+    ///
+    /// Returns [`HealthMonitorError::NotFound`] if the group does not exist.
+    pub fn group_statistics(&self, group_tag: GroupTag) -> Result<MonitorStatistics, HealthMonitorError> {
+        if !self.monitor_groups.iter().any(|(tag, _)| *tag == group_tag) {
+            return Err(HealthMonitorError::NotFound);
+        }
+
+        Ok(self.failure_statistics.get(MonitorTag::from(group_tag)))
+    }
+
+    /// Current health status, updated by the monitoring logic on every internal processing cycle.
+    /// Does not block the monitoring logic, can be called from any thread.
+    pub fn status(&self) -> HealthStatus {
+        self.status_board.status()
+    }
+
+    /// Monitoring loop is running and completed an evaluation cycle within the worker stall threshold,
+    /// see [`HealthMonitorBuilder::with_worker_stall_threshold`].
+    /// Returns `false` if monitoring is not running, e.g. after a monitor failed, or if the monitoring thread panicked.
+    /// Does not block the monitoring logic, can be called from any thread.
+    pub fn worker_healthy(&self) -> bool {
+        self.worker.liveness().state() == WorkerState::Healthy
+    }
+
+    /// Number of evaluation cycles which took longer than the internal processing cycle.
+    /// Overrunning cycle is followed by the next one immediately and reported as [`InternalFault::WorkerOverrun`].
+    /// Does not block the monitoring logic, can be called from any thread.
+    pub fn worker_overruns(&self) -> u64 {
+        self.worker.liveness().overruns()
+    }
+
+    /// Handle to challenge the monitoring logic with an immediate out-of-cycle evaluation,
+    /// answered to supervisors supporting challenge responses in addition to periodic notifications.
+    /// Handle can be moved to another thread, e.g. one receiving challenges of the supervisor.
+    pub fn challenge_handle(&self) -> ChallengeHandle {
+        self.challenge.clone()
+    }
+
+    /// Summary of the configuration this health monitor was built with.
+    pub fn config_summary(&self) -> ConfigSummary {
+        self.config_summary
+    }
+
+    /// Capacities and memory usage of the bounded buffers, as configured by [`ResourceBudget`].
+    pub fn capacity_report(&self) -> CapacityReport {
+        self.capacity_report
+    }
+
+    /// Snapshot of the live supervision state for diagnostics, e.g. dumped on a signal or requested over FFI.
+    /// Contains all monitors with their configuration, current state and recent violations.
+    /// Does not block the monitoring logic, can be called from any thread.
+    pub fn snapshot(&self) -> HealthMonitorSnapshot {
+        let status = self.status();
+        let failing = match &status {
+            HealthStatus::Degraded(monitor_tags) => monitor_tags.clone(),
+            _ => Vec::new(),
+        };
+        let violations = self.violation_history();
+        let monitor_snapshot = |monitor_tag: MonitorTag, config, ownership, enabled| MonitorSnapshot {
+            monitor_tag,
+            config,
+            ownership,
+            enabled,
+            failing: failing.contains(&monitor_tag),
+            recent_violations: violations
+                .iter()
+                .filter(|record| record.monitor_tag == monitor_tag)
+                .copied()
+                .collect(),
+        };
+
+        let mut monitors = Vec::new();
+        for (monitor_tag, monitor_state) in self.deadline_monitors.iter() {
+            if let Some((monitor, ownership)) = Self::monitor_ownership(monitor_state) {
+                let config = MonitorConfig::Deadline {
+                    deadlines: monitor.deadline_ranges(),
+                };
+                monitors.push(monitor_snapshot(*monitor_tag, config, ownership, monitor.is_enabled()));
+            }
+        }
+        for (monitor_tag, monitor_state) in self.heartbeat_monitors.iter() {
+            if let Some((monitor, ownership)) = Self::monitor_ownership(monitor_state) {
+                let config = MonitorConfig::Heartbeat { range: monitor.range() };
+                monitors.push(monitor_snapshot(*monitor_tag, config, ownership, monitor.is_enabled()));
+            }
+        }
+        for monitor in self.artifact_freshness_monitors.iter() {
+            let config = MonitorConfig::ArtifactFreshness {
+                artifacts: monitor.artifacts(),
+            };
+            monitors.push(monitor_snapshot(
+                monitor.monitor_tag(),
+                config,
+                MonitorOwnership::Internal,
+                monitor.is_enabled(),
+            ));
+        }
+
+        HealthMonitorSnapshot {
+            status,
+            config_summary: self.config_summary,
+            monitors,
+        }
+    }
+
+    fn monitor_ownership<M>(monitor_state: &MonitorContainer<M>) -> Option<(&M, MonitorOwnership)> {
+        match monitor_state.as_ref()? {
+            MonitorState::Available(monitor) => Some((monitor, MonitorOwnership::Available)),
+            MonitorState::Taken(monitor) => Some((monitor, MonitorOwnership::Taken)),
+            MonitorState::Returned(monitor) => Some((monitor, MonitorOwnership::Returned)),
+        }
+    }
+}
 
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::TimeRange;
-    use crate::deadline::DeadlineMonitorBuilder;
-    use crate::heartbeat::HeartbeatMonitorBuilder;
-    use crate::tag::MonitorTag;
-    use crate::{HealthMonitorBuilder, HealthMonitorError};
+    use crate::artifact::ArtifactFreshnessMonitorBuilder;
+    use crate::common::{Monitor, MonitorEvaluator, TimeRange};
+    use crate::deadline::{DeadlineMonitorBuilder, DeadlineMonitorError};
+    use crate::heartbeat::{HeartbeatMonitorBuilder, HeartbeatMonitorError};
+    use crate::tag::{ChannelTag, DeadlineTag, GroupTag, MonitorTag};
+    use crate::{
+        ArmedStart, ChallengeResponse, ConfigIssue, EarlySupervision, FailFastPolicy, FailureTolerance, GroupPolicy,
+        HealthMonitor, HealthMonitorBuilder, HealthMonitorError, HealthStatus, HealthTrajectory, InternalFault,
+        LoggingSignalSink, MonitorConfig, MonitorEvaluationError, MonitorGroup, MonitorOwnership, MonitorSnapshot,
+        MonitorStatistics, RecoveryPolicy, ResourceBudget, RunExit, SignalExport, SignalSink, SignalValue, StopToken,
+        UpdateMode, ViolationRecord, ViolationSink, MAX_TOLERANCE_CYCLES,
+    };
     use core::time::Duration;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    fn def_range() -> TimeRange {
+        TimeRange::new(Duration::from_millis(100), Duration::from_millis(200))
+    }
+
+    fn def_heartbeat_monitor_builder() -> HeartbeatMonitorBuilder {
+        let range = TimeRange::new(Duration::from_millis(100), Duration::from_millis(200));
+        HeartbeatMonitorBuilder::new(range)
+    }
+
+    #[test]
+    fn health_monitor_builder_new_succeeds() {
+        let health_monitor_builder = HealthMonitorBuilder::new();
+        assert_eq!(health_monitor_builder.config.deadline_monitor_builders.len(), 0);
+        assert_eq!(health_monitor_builder.config.heartbeat_monitor_builders.len(), 0);
+        assert_eq!(
+            health_monitor_builder.config.supervisor_api_cycle,
+            Duration::from_millis(500)
+        );
+        assert_eq!(
+            health_monitor_builder.config.internal_processing_cycle,
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn health_monitor_builder_build_succeeds() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_cycles() {
+        let result = HealthMonitorBuilder::new()
+            .with_supervisor_api_cycle(Duration::from_millis(123))
+            .with_internal_processing_cycle(Duration::from_millis(100))
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_resource_budget() {
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .with_resource_budget(ResourceBudget::new().with_violation_history_capacity(32))
+            .build()
+            .unwrap();
+
+        let report = health_monitor.capacity_report();
+        assert_eq!(report.monitors, 2);
+        assert_eq!(report.violation_history_capacity, 32);
+        assert_eq!(
+            report.total_bytes,
+            report.monitors_bytes
+                + report.violation_history_bytes
+                + report.internal_fault_history_bytes
+                + report.failure_statistics_bytes
+        );
+        assert!(report.clock_granularity_ns > 0);
+        assert!(health_monitor.violation_history().is_empty());
+        assert!(health_monitor.internal_faults().is_empty());
+    }
+
+    #[test]
+    fn health_monitor_builder_build_config_summary() {
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(
+                MonitorTag::from("deadline_monitor"),
+                DeadlineMonitorBuilder::new()
+                    .add_deadline(DeadlineTag::from("deadline_1"), def_range())
+                    .add_deadline(DeadlineTag::from("deadline_2"), def_range())
+                    .with_thread_attribution(),
+            )
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .add_notification_channel(ChannelTag::from("tenant_a"), &[MonitorTag::from("deadline_monitor")])
+            .build()
+            .unwrap();
+
+        let summary = health_monitor.config_summary();
+        assert_eq!(summary.deadline_monitors, 1);
+        assert_eq!(summary.deadlines, 2);
+        assert_eq!(summary.heartbeat_monitors, 1);
+        assert_eq!(summary.thread_attribution_monitors, 1);
+        assert_eq!(summary.notification_channels, 1);
+        assert_eq!(summary.supervisor_api_cycle_ms, 500);
+        assert_eq!(summary.internal_processing_cycle_ms, 100);
+    }
+
+    #[test]
+    fn health_monitor_builder_build_config_digest() {
+        let build_digest = |deadline_max: Duration, reversed: bool| {
+            let first = (DeadlineTag::from("deadline_1"), def_range());
+            let second = (
+                DeadlineTag::from("deadline_2"),
+                TimeRange::new(Duration::from_millis(100), deadline_max),
+            );
+            let (first, second) = if reversed { (second, first) } else { (first, second) };
+            HealthMonitorBuilder::new()
+                .add_deadline_monitor(
+                    MonitorTag::from("deadline_monitor"),
+                    DeadlineMonitorBuilder::new()
+                        .add_deadline(first.0, first.1)
+                        .add_deadline(second.0, second.1),
+                )
+                .build()
+                .unwrap()
+                .config_summary()
+                .digest
+        };
+
+        // Order of adding items does not matter.
+        let digest = build_digest(Duration::from_millis(200), false);
+        assert_eq!(digest, build_digest(Duration::from_millis(200), true));
+        // Any change of configuration changes the digest.
+        assert_ne!(digest, build_digest(Duration::from_millis(201), false));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_resource_budget_exceeded() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .with_resource_budget(ResourceBudget::new().with_memory_limit(1))
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_notification_channels() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .add_notification_channel(ChannelTag::from("tenant_a"), &[MonitorTag::from("deadline_monitor")])
+            .add_notification_channel(ChannelTag::from("tenant_b"), &[MonitorTag::from("heartbeat_monitor")])
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn health_monitor_builder_build_notification_channel_unknown_monitor() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .add_notification_channel(ChannelTag::from("tenant_a"), &[MonitorTag::from("unknown")])
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::NotFound));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_monitor_groups() {
+        let group_tag = GroupTag::from("group");
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .add_group(
+                group_tag,
+                MonitorGroup::new(GroupPolicy::AnyMember).with_members(&[
+                    MonitorTag::from("deadline_monitor"),
+                    MonitorTag::from("heartbeat_monitor"),
+                ]),
+            )
+            .add_notification_channel(
+                ChannelTag::from("tenant_a"),
+                &[
+                    MonitorTag::from("deadline_monitor"),
+                    MonitorTag::from("heartbeat_monitor"),
+                ],
+            )
+            .with_monitor_failure_tolerance(MonitorTag::from(group_tag), FailureTolerance::Consecutive(2))
+            .build()
+            .unwrap();
+        assert_eq!(
+            health_monitor.group_statistics(group_tag),
+            Ok(MonitorStatistics::default())
+        );
+        assert_eq!(
+            health_monitor.group_statistics(GroupTag::from("unknown")),
+            Err(HealthMonitorError::NotFound)
+        );
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_monitor_groups() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let report = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .add_notification_channel(ChannelTag::from("tenant_a"), &[monitor_tag])
+            .add_group(
+                GroupTag::from("deadline_monitor"),
+                MonitorGroup::new(GroupPolicy::AllMembers)
+                    .with_members(&[monitor_tag, MonitorTag::from("heartbeat_monitor")]),
+            )
+            .validate();
+        assert_eq!(
+            report.issues(),
+            &[
+                ConfigIssue::DuplicateMonitorTag(monitor_tag),
+                ConfigIssue::GroupInMultipleChannels(GroupTag::from("deadline_monitor")),
+            ]
+        );
+
+        let group_tag = GroupTag::from("group");
+        let report = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .add_group(
+                group_tag,
+                MonitorGroup::new(GroupPolicy::AnyMember).with_members(&[monitor_tag, MonitorTag::from("unknown")]),
+            )
+            .validate();
+        assert_eq!(
+            report.issues(),
+            &[ConfigIssue::UnknownGroupMember {
+                group_tag,
+                monitor_tag: MonitorTag::from("unknown"),
+            }]
+        );
+        assert_eq!(report.error(), Some(HealthMonitorError::NotFound));
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_succeeds() {
+        let report = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .validate();
+        assert!(report.is_valid());
+        assert_eq!(report.error(), None);
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_reports_all_issues() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let short_range = TimeRange::new(Duration::from_millis(10), Duration::from_millis(20));
+        let report = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(monitor_tag, HeartbeatMonitorBuilder::new(short_range))
+            .with_supervisor_api_cycle(Duration::from_millis(123))
+            .with_internal_processing_cycle(Duration::from_millis(100))
+            .with_recovery_policy(RecoveryPolicy::ResumeAfter(0))
+            .validate();
+        assert_eq!(
+            report.issues(),
+            &[
+                ConfigIssue::CycleMismatch,
+                ConfigIssue::DuplicateMonitorTag(monitor_tag),
+                ConfigIssue::HeartbeatRangeTooShort(monitor_tag),
+                ConfigIssue::InvalidRecoveryPolicy,
+            ]
+        );
+        assert_eq!(report.error(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_no_monitors() {
+        let report = HealthMonitorBuilder::new().validate();
+        assert_eq!(report.issues(), &[ConfigIssue::NoMonitors]);
+        assert_eq!(report.error(), Some(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_strict() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let builder = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_supervisor_api_cycle(Duration::from_secs(20))
+            .with_internal_processing_cycle(Duration::from_secs(2));
+        assert!(builder.validate().is_valid());
+
+        let report = builder.with_strict_validation(true).validate();
+        assert_eq!(
+            report.issues(),
+            &[
+                ConfigIssue::LongSupervisorAPICycle,
+                ConfigIssue::LongInternalProcessingCycle,
+                ConfigIssue::EmptyMonitor(deadline_monitor_tag),
+            ]
+        );
+        assert_eq!(report.error(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_range_below_clock_granularity() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline(
+            DeadlineTag::from("deadline"),
+            TimeRange::new(Duration::ZERO, Duration::from_millis(1)),
+        );
+        let heartbeat_monitor_builder =
+            HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_secs(1), Duration::from_secs(2)));
+        let builder = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .with_clock_granularity_multiple(u32::MAX);
+        assert!(builder.validate().is_valid());
+
+        let builder = builder.with_strict_validation(true);
+        let report = builder.validate();
+        assert_eq!(
+            report.issues(),
+            &[
+                ConfigIssue::RangeBelowClockGranularity(deadline_monitor_tag),
+                ConfigIssue::RangeBelowClockGranularity(heartbeat_monitor_tag),
+            ]
+        );
+        assert!(builder.with_clock_granularity_multiple(0).validate().is_valid());
+    }
+
+    #[test]
+    fn health_monitor_start_strict_stub_client() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder =
+            DeadlineMonitorBuilder::new().add_deadline(DeadlineTag::from("deadline"), def_range());
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .with_strict_validation(true)
+            .build()
+            .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+
+        let result = health_monitor.start();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_heartbeat_from_deadline() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(
+                deadline_monitor_tag,
+                DeadlineMonitorBuilder::new().add_deadline(deadline_tag, TimeRange::at_most(Duration::from_secs(1))),
+            )
+            .add_heartbeat_monitor(
+                heartbeat_monitor_tag,
+                HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_secs(1), Duration::from_secs(2)))
+                    .with_deadline_source(deadline_monitor_tag, deadline_tag),
+            )
+            .build()
+            .unwrap();
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        // Producer is held by the deadline.
+        assert!(heartbeat_monitor
+            .producer()
+            .is_err_and(|e| e == HeartbeatMonitorError::ProducerInUse));
+
+        let hmon_starting_point = Instant::now();
+        let eval_handle = heartbeat_monitor.get_eval_handle();
+        eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+            panic!("Monitor {monitor_tag:?} reported error {error:?} without heartbeats");
+        });
+
+        // Heartbeat derived from the deadline arrives before the range of the heartbeat monitor.
+        let mut deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        deadline.start().unwrap().stop();
+        let mut errors = Vec::new();
+        eval_handle.evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_unknown_heartbeat_source() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let report = HealthMonitorBuilder::new()
+            .add_deadline_monitor(
+                deadline_monitor_tag,
+                DeadlineMonitorBuilder::new().add_deadline(DeadlineTag::from("deadline"), def_range()),
+            )
+            .add_heartbeat_monitor(
+                heartbeat_monitor_tag,
+                HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_secs(1), Duration::from_secs(2)))
+                    .with_deadline_source(deadline_monitor_tag, DeadlineTag::from("unknown")),
+            )
+            .validate();
+        assert_eq!(
+            report.issues(),
+            &[ConfigIssue::UnknownHeartbeatSource(heartbeat_monitor_tag)]
+        );
+        assert_eq!(report.error(), Some(HealthMonitorError::NotFound));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_unbounded_deadline() {
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline(
+            DeadlineTag::from("deadline"),
+            TimeRange::at_least(Duration::from_millis(100)),
+        );
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), deadline_monitor_builder)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_deadline_instances() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline_concurrent(
+            DeadlineTag::from("deadline"),
+            TimeRange::new(Duration::from_millis(10), Duration::from_millis(100)),
+            0,
+        );
+        let report = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, deadline_monitor_builder)
+            .validate();
+        assert_eq!(report.issues(), &[ConfigIssue::InvalidDeadlineInstances(monitor_tag)]);
+        assert_eq!(report.error(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_invalid_budget() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_budget(
+            DeadlineTag::from("budget"),
+            Duration::from_millis(200),
+            Duration::from_millis(100),
+        );
+        let report = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, deadline_monitor_builder)
+            .validate();
+        assert_eq!(report.issues(), &[ConfigIssue::InvalidBudget(monitor_tag)]);
+        assert_eq!(report.error(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_invalid_warning_threshold() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let range = TimeRange::new(Duration::from_millis(10), Duration::from_millis(100));
+        for (warning_tag, warning_at) in [
+            (deadline_tag, Duration::from_millis(100)),
+            (deadline_tag, Duration::from_millis(5)),
+            (DeadlineTag::from("unknown"), Duration::from_millis(50)),
+        ] {
+            let deadline_monitor_builder = DeadlineMonitorBuilder::new()
+                .add_deadline(deadline_tag, range)
+                .with_warning_at(warning_tag, warning_at);
+            let report = HealthMonitorBuilder::new()
+                .add_deadline_monitor(monitor_tag, deadline_monitor_builder)
+                .validate();
+            assert_eq!(report.issues(), &[ConfigIssue::InvalidWarningThreshold(monitor_tag)]);
+        }
+    }
+
+    #[test]
+    fn health_monitor_builder_validate_invalid_deadline_chain() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let upstream_tag = DeadlineTag::from("upstream");
+        let downstream_tag = DeadlineTag::from("downstream");
+        let range = TimeRange::new(Duration::from_millis(10), Duration::from_millis(100));
+        for (from_tag, to_tag) in [
+            (upstream_tag, upstream_tag),
+            (upstream_tag, DeadlineTag::from("unknown")),
+            (DeadlineTag::from("unknown"), downstream_tag),
+        ] {
+            let deadline_monitor_builder = DeadlineMonitorBuilder::new()
+                .add_deadline(upstream_tag, range)
+                .add_deadline(downstream_tag, range)
+                .chain(from_tag, to_tag);
+            let report = HealthMonitorBuilder::new()
+                .add_deadline_monitor(monitor_tag, deadline_monitor_builder)
+                .validate();
+            assert_eq!(report.issues(), &[ConfigIssue::InvalidDeadlineChain(monitor_tag)]);
+        }
+    }
+
+    #[test]
+    fn health_monitor_builder_build_duplicate_monitor_tag() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(monitor_tag, def_heartbeat_monitor_builder())
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_notification_channels_share_monitor() {
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .add_notification_channel(ChannelTag::from("tenant_a"), &[monitor_tag])
+            .add_notification_channel(ChannelTag::from("tenant_b"), &[monitor_tag])
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_no_monitors() {
+        let result = HealthMonitorBuilder::new().build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_get_deadline_monitor_available() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .build()
+            .unwrap();
+
+        let result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn health_monitor_deadline_monitor_evaluated_manually() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline(
+            deadline_tag,
+            TimeRange::new(Duration::from_millis(10), Duration::from_millis(100)),
+        );
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .build()
+            .unwrap();
+
+        // Monitoring is not started, violations are reported to the caller only.
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let mut deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        deadline.start().unwrap().stop();
+        let mut errors = Vec::new();
+        deadline_monitor
+            .evaluate_now(&mut |monitor_tag, error| errors.push((*monitor_tag, error)))
+            .unwrap();
+        assert_eq!(
+            errors,
+            vec![(
+                deadline_monitor_tag,
+                crate::deadline::DeadlineEvaluationError::TooEarly.into()
+            )]
+        );
+        assert_eq!(health_monitor.status(), HealthStatus::Stopped);
+    }
+
+    #[test]
+    fn health_monitor_deadline_monitor_evaluated_manually_while_started() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline(
+            DeadlineTag::from("deadline"),
+            TimeRange::new(Duration::from_millis(10), Duration::from_millis(100)),
+        );
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .build()
+            .unwrap();
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+
+        // Evaluation is single reader, monitor evaluated by the monitoring thread cannot be evaluated manually.
+        health_monitor.start().unwrap();
+        let result = deadline_monitor.evaluate_now(&mut |_, _| {});
+        assert_eq!(result, Err(DeadlineMonitorError::WrongState));
+
+        health_monitor.stop().unwrap();
+        assert!(deadline_monitor.evaluate_now(&mut |_, _| {}).is_ok());
+    }
+
+    #[test]
+    fn health_monitor_get_deadline_monitor_taken() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .build()
+            .unwrap();
+
+        let _ = health_monitor.get_deadline_monitor(deadline_monitor_tag);
+        let result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn health_monitor_get_deadline_monitor_unknown() {
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), deadline_monitor_builder)
+            .build()
+            .unwrap();
+
+        let result = health_monitor.get_deadline_monitor(MonitorTag::from("undefined_monitor"));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn health_monitor_get_deadline_monitor_invalid_state() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .build()
+            .unwrap();
+
+        // Inject broken state - unreachable otherwise.
+        health_monitor.deadline_monitors.insert(deadline_monitor_tag, None);
+
+        let result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn health_monitor_get_heartbeat_monitor_available() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .build()
+            .unwrap();
+
+        let result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn health_monitor_get_heartbeat_monitor_taken() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .build()
+            .unwrap();
+
+        let _ = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
+        let result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn health_monitor_get_heartbeat_monitor_unknown() {
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), heartbeat_monitor_builder)
+            .build()
+            .unwrap();
+
+        let result = health_monitor.get_heartbeat_monitor(MonitorTag::from("undefined_monitor"));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn health_monitor_get_heartbeat_monitor_invalid_state() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .build()
+            .unwrap();
+
+        // Inject broken state - unreachable otherwise.
+        health_monitor.heartbeat_monitors.insert(heartbeat_monitor_tag, None);
+
+        let result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn health_monitor_start_succeeds() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+
+        let result = health_monitor.start();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn health_monitor_start_monitors_not_taken() {
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), deadline_monitor_builder)
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), heartbeat_monitor_builder)
+            .build()
+            .unwrap();
+
+        let result = health_monitor.start();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_start_corrupted_state_records_fault_time() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+        *health_monitor.deadline_monitors.get_mut(&deadline_monitor_tag).unwrap() = None;
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(health_monitor.start(), Err(HealthMonitorError::WrongState));
+        let faults = health_monitor.internal_faults();
+        assert_eq!(faults.len(), 1);
+        assert_eq!(faults[0].fault, InternalFault::StateCorruption);
+        assert_eq!(faults[0].monitor_tag, Some(deadline_monitor_tag));
+        assert!(faults[0].timestamp >= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn health_monitor_start_not_taken_then_restart() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .build()
+            .unwrap();
+
+        // Start without taking any monitor.
+        let start_result = health_monitor.start();
+        assert!(start_result.is_err_and(|e| e == HealthMonitorError::WrongState));
+
+        // Take monitors.
+        let get_deadline_monitor_result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
+        assert!(get_deadline_monitor_result.is_some());
+        let get_heartbeat_monitor_result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
+        assert!(get_heartbeat_monitor_result.is_some());
+
+        // Try to start again, this time should be successful.
+        let start_result = health_monitor.start();
+        assert!(start_result.is_ok());
+    }
+
+    #[test]
+    fn health_monitor_start_already_started() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+
+        assert!(health_monitor.start().is_ok());
+        let result = health_monitor.start();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_stop_returns_monitors() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .build()
+            .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        assert!(health_monitor.start().is_ok());
+
+        assert!(health_monitor.stop().is_ok());
+
+        // Monitors are available again and must be taken before next start.
+        let start_result = health_monitor.start();
+        assert!(start_result.is_err_and(|e| e == HealthMonitorError::WrongState));
+        assert!(health_monitor.get_deadline_monitor(deadline_monitor_tag).is_some());
+        assert!(health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).is_some());
+        assert!(health_monitor.start().is_ok());
+    }
+
+    #[test]
+    fn health_monitor_return_monitors_and_take_again() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .build()
+            .unwrap();
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+
+        assert!(health_monitor
+            .return_deadline_monitor(deadline_monitor_tag, deadline_monitor)
+            .is_ok());
+        assert!(health_monitor
+            .return_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor)
+            .is_ok());
+
+        // Returned monitors do not block the start and can be taken again.
+        assert!(health_monitor.start().is_ok());
+        assert!(health_monitor.get_deadline_monitor(deadline_monitor_tag).is_some());
+        assert!(health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).is_some());
+        assert!(health_monitor.get_deadline_monitor(deadline_monitor_tag).is_none());
+    }
+
+    #[test]
+    fn health_monitor_return_monitor_invalid() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let build = || {
+            HealthMonitorBuilder::new()
+                .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+                .build()
+                .unwrap()
+        };
+        let mut health_monitor = build();
+        let mut other_health_monitor = build();
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let other_deadline_monitor = other_health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+
+        let result =
+            health_monitor.return_deadline_monitor(MonitorTag::from("unknown"), deadline_monitor.clone_shared());
+        assert!(result.is_err_and(|e| e == HealthMonitorError::NotFound));
+        let result = health_monitor.return_deadline_monitor(deadline_monitor_tag, other_deadline_monitor);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+        assert!(health_monitor
+            .return_deadline_monitor(deadline_monitor_tag, deadline_monitor.clone_shared())
+            .is_ok());
+        let result = health_monitor.return_deadline_monitor(deadline_monitor_tag, deadline_monitor);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_stop_not_started() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+
+        let result = health_monitor.stop();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_restart_succeeds() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        assert!(health_monitor.start().is_ok());
+
+        assert!(health_monitor.restart().is_ok());
+
+        // Monitors remain taken.
+        assert!(health_monitor.get_deadline_monitor(deadline_monitor_tag).is_none());
+        assert!(health_monitor.stop().is_ok());
+    }
+
+    #[test]
+    fn health_monitor_restart_not_started() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+
+        let result = health_monitor.restart();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_run_on_current_thread_stopped() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+
+        let stop_token = StopToken::new();
+        let stopper = {
+            let stop_token = stop_token.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                stop_token.stop();
+            })
+        };
+
+        let result = health_monitor.run_on_current_thread(&stop_token);
+        assert_eq!(result, Ok(RunExit::Stopped));
+        assert_eq!(health_monitor.status(), HealthStatus::Stopped);
+        stopper.join().unwrap();
+    }
+
+    #[test]
+    fn health_monitor_run_on_current_thread_monitoring_failed() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+
+        // No heartbeats are provided, loop exits once the heartbeat range is exceeded.
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Ok(RunExit::MonitoringFailed));
+        assert_eq!(
+            health_monitor.status(),
+            HealthStatus::Degraded(vec![heartbeat_monitor_tag])
+        );
+    }
+
+    #[test]
+    fn health_monitor_failure_callback() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let failures = Arc::new(Mutex::new(Vec::new()));
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_failure_callback({
+                let failures = failures.clone();
+                move |monitor_tag, error| failures.lock().unwrap().push((*monitor_tag, error))
+            })
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Ok(RunExit::MonitoringFailed));
+        let failures = failures.lock().unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, heartbeat_monitor_tag);
+        assert!(matches!(failures[0].1, MonitorEvaluationError::Heartbeat(_)));
+    }
+
+    #[test]
+    fn health_monitor_stop_from_failure_callback_rejected() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let shared_health_monitor: Arc<Mutex<Option<HealthMonitor>>> = Arc::new(Mutex::new(None));
+        let (result_sender, result_receiver) = std::sync::mpsc::channel();
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_failure_callback({
+                let shared_health_monitor = shared_health_monitor.clone();
+                move |_, _| {
+                    if let Ok(mut health_monitor) = shared_health_monitor.try_lock() {
+                        if let Some(health_monitor) = health_monitor.as_mut() {
+                            let _ = result_sender.send(health_monitor.stop());
+                        }
+                    }
+                }
+            })
+            .build()
+            .unwrap();
+        {
+            let mut guard = shared_health_monitor.lock().unwrap();
+            let health_monitor = guard.insert(health_monitor);
+            let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+            health_monitor.start().unwrap();
+        }
+
+        // Monitoring thread cannot join itself, stop is rejected instead of deadlocking.
+        let result = result_receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(result, Err(HealthMonitorError::WrongState));
+        assert!(shared_health_monitor.lock().unwrap().as_mut().unwrap().stop().is_ok());
+    }
+
+    #[test]
+    fn health_monitor_builder_build_failure_tolerance() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let builder = || {
+            HealthMonitorBuilder::new().add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+        };
+
+        let summary = builder().build().unwrap().config_summary();
+        let tolerant_summary = builder()
+            .with_failure_tolerance(FailureTolerance::WithinCycles {
+                violations: 2,
+                cycles: 10,
+            })
+            .with_monitor_failure_tolerance(heartbeat_monitor_tag, FailureTolerance::Consecutive(3))
+            .build()
+            .unwrap()
+            .config_summary();
+        assert_ne!(summary.digest, tolerant_summary.digest);
+
+        let result = builder()
+            .with_failure_tolerance(FailureTolerance::Consecutive(MAX_TOLERANCE_CYCLES + 1))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+
+        let result = builder()
+            .with_monitor_failure_tolerance(heartbeat_monitor_tag, FailureTolerance::Consecutive(0))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+
+        let result = builder()
+            .with_monitor_failure_tolerance(MonitorTag::from("unknown"), FailureTolerance::Consecutive(2))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::NotFound));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_recovery_policy() {
+        let result = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .with_recovery_policy(RecoveryPolicy::ResumeAfter(0))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_update_mode() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let builder = || {
+            HealthMonitorBuilder::new()
+                .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+                .with_internal_processing_cycle(Duration::from_millis(10))
+        };
+
+        let result = builder()
+            .with_update_mode(UpdateMode::new(heartbeat_monitor_tag, Duration::from_secs(1)))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+
+        let result = builder()
+            .with_update_mode(
+                UpdateMode::new(MonitorTag::from("update_guard"), Duration::from_secs(1))
+                    .with_advisory_monitors(&[MonitorTag::from("unknown")]),
+            )
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::NotFound));
+
+        let result = builder()
+            .with_update_mode(UpdateMode::new(
+                MonitorTag::from("update_guard"),
+                Duration::from_millis(5),
+            ))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_update_mode_not_configured() {
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .build()
+            .unwrap();
+        assert_eq!(health_monitor.enter_update_mode(1), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.exit_update_mode(1), Err(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_update_mode_advisory_violation() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_update_mode(
+                UpdateMode::new(MonitorTag::from("update_guard"), Duration::from_secs(5))
+                    .with_advisory_monitors(&[heartbeat_monitor_tag]),
+            )
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        health_monitor.enter_update_mode(7).unwrap();
+        assert_eq!(health_monitor.enter_update_mode(8), Err(HealthMonitorError::WrongState));
+
+        // No heartbeats are provided, violation is only stored in the history.
+        health_monitor.start().unwrap();
+        std::thread::sleep(Duration::from_millis(400));
+        assert_eq!(health_monitor.status(), HealthStatus::Healthy);
+        assert!(health_monitor
+            .violation_history()
+            .iter()
+            .any(|record| record.monitor_tag == heartbeat_monitor_tag));
+
+        assert_eq!(
+            health_monitor.exit_update_mode(8),
+            Err(HealthMonitorError::InvalidArgument)
+        );
+        health_monitor.exit_update_mode(7).unwrap();
+        assert_eq!(health_monitor.exit_update_mode(7), Err(HealthMonitorError::WrongState));
+        health_monitor.stop().unwrap();
+    }
+
+    #[test]
+    fn health_monitor_update_mode_window_exceeded() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let guard_tag = MonitorTag::from("update_guard");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_update_mode(
+                UpdateMode::new(guard_tag, Duration::from_millis(30)).with_advisory_monitors(&[heartbeat_monitor_tag]),
+            )
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        health_monitor.enter_update_mode(7).unwrap();
+
+        // Update window is exceeded before the heartbeat range.
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Ok(RunExit::MonitoringFailed));
+        assert_eq!(health_monitor.status(), HealthStatus::Degraded(vec![guard_tag]));
+        let violation_history = health_monitor.violation_history();
+        assert_eq!(violation_history.len(), 1);
+        assert_eq!(violation_history[0].monitor_tag, guard_tag);
+        assert_eq!(violation_history[0].correlation_id, Some(7));
+        assert_eq!(health_monitor.exit_update_mode(7), Err(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_worker_healthy() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_worker_fault_callback(|_| {})
+            .build()
+            .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        assert!(!health_monitor.worker_healthy());
+
+        health_monitor.start().unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(health_monitor.worker_healthy());
+
+        health_monitor.stop().unwrap();
+        assert!(!health_monitor.worker_healthy());
+        assert_eq!(health_monitor.worker_overruns(), 0);
+    }
 
-    fn def_heartbeat_monitor_builder() -> HeartbeatMonitorBuilder {
-        let range = TimeRange::new(Duration::from_millis(100), Duration::from_millis(200));
-        HeartbeatMonitorBuilder::new(range)
+    #[test]
+    fn health_monitor_builder_build_invalid_fail_fast_policy() {
+        let builder = || {
+            HealthMonitorBuilder::new()
+                .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+        };
+
+        let result = builder()
+            .with_fail_fast_policy(FailFastPolicy::new(3).with_monitors(&[MonitorTag::from("unknown")]))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::NotFound));
+
+        let result = builder().with_fail_fast_policy(FailFastPolicy::new(0)).build();
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+
+        let result = builder()
+            .with_fail_fast_policy(FailFastPolicy::new(3).with_monitors(&[MonitorTag::from("heartbeat_monitor")]))
+            .build();
+        assert!(result.is_ok());
     }
 
     #[test]
-    fn health_monitor_builder_new_succeeds() {
-        let health_monitor_builder = HealthMonitorBuilder::new();
-        assert!(health_monitor_builder.deadline_monitor_builders.is_empty());
-        assert!(health_monitor_builder.heartbeat_monitor_builders.is_empty());
-        assert_eq!(health_monitor_builder.supervisor_api_cycle, Duration::from_millis(500));
+    fn health_monitor_startup_grace() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_startup_grace(Duration::from_millis(400))
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+
+        // No heartbeats are provided, violations within the grace period are only stored in the history.
+        health_monitor.start().unwrap();
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(health_monitor.status(), HealthStatus::Healthy);
+        assert!(!health_monitor.violation_history().is_empty());
+
+        std::thread::sleep(Duration::from_millis(400));
         assert_eq!(
-            health_monitor_builder.internal_processing_cycle,
-            Duration::from_millis(100)
+            health_monitor.status(),
+            HealthStatus::Degraded(vec![heartbeat_monitor_tag])
         );
+        health_monitor.stop().unwrap();
     }
 
     #[test]
-    fn health_monitor_builder_build_succeeds() {
-        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+    fn health_monitor_builder_build_invalid_armed_start() {
         let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+        let builder = || {
+            HealthMonitorBuilder::new()
+                .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+                .with_internal_processing_cycle(Duration::from_millis(10))
+        };
+
+        let report = builder()
+            .with_armed_start(ArmedStart::new(heartbeat_monitor_tag, Duration::from_secs(1)))
+            .validate();
+        assert_eq!(
+            report.issues(),
+            &[ConfigIssue::DuplicateMonitorTag(heartbeat_monitor_tag)]
+        );
+
+        let report = builder()
+            .with_armed_start(ArmedStart::new(MonitorTag::from("arm_guard"), Duration::from_millis(5)))
+            .validate();
+        assert_eq!(report.issues(), &[ConfigIssue::ArmTimeoutTooShort]);
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_health_trajectory() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let guard_tag = MonitorTag::from("arm_guard");
+        let report = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_armed_start(ArmedStart::new(guard_tag, Duration::from_secs(1)))
+            .with_health_trajectory(HealthTrajectory::new(guard_tag))
+            .validate();
+        assert_eq!(report.issues(), &[ConfigIssue::DuplicateMonitorTag(guard_tag)]);
 
         let result = HealthMonitorBuilder::new()
-            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
-            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_health_trajectory(HealthTrajectory::new(MonitorTag::from("trajectory")))
             .build();
         assert!(result.is_ok());
     }
 
     #[test]
-    fn health_monitor_builder_build_invalid_cycles() {
+    fn health_monitor_armed_start_not_configured() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        assert_eq!(health_monitor.arm(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.go(), Err(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_armed_start_arm_and_go() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_armed_start(ArmedStart::new(MonitorTag::from("arm_guard"), Duration::from_secs(5)))
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        assert_eq!(health_monitor.go(), Err(HealthMonitorError::WrongState));
+
+        // No heartbeats are provided, violations while armed are only stored in the history.
+        health_monitor.arm().unwrap();
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(health_monitor.status(), HealthStatus::Healthy);
+        assert!(!health_monitor.violation_history().is_empty());
+
+        health_monitor.go().unwrap();
+        assert_eq!(health_monitor.go(), Err(HealthMonitorError::WrongState));
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(
+            health_monitor.status(),
+            HealthStatus::Degraded(vec![heartbeat_monitor_tag])
+        );
+        health_monitor.stop().unwrap();
+    }
+
+    #[test]
+    fn health_monitor_armed_start_timeout_exceeded() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let guard_tag = MonitorTag::from("arm_guard");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_armed_start(ArmedStart::new(guard_tag, Duration::from_millis(30)))
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+
+        // Timeout is exceeded before the heartbeat range.
+        health_monitor.arm().unwrap();
+        std::thread::sleep(Duration::from_millis(150));
+        assert_eq!(health_monitor.status(), HealthStatus::Degraded(vec![guard_tag]));
+        assert!(health_monitor
+            .violation_history()
+            .iter()
+            .any(|record| record.monitor_tag == guard_tag));
+        assert_eq!(health_monitor.go(), Err(HealthMonitorError::WrongState));
+        health_monitor.stop().unwrap();
+
+        // Single-stage start is not armed.
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        health_monitor.start().unwrap();
+        assert_eq!(health_monitor.go(), Err(HealthMonitorError::WrongState));
+        health_monitor.stop().unwrap();
+    }
+
+    #[test]
+    fn health_monitor_early_supervision_handover() {
+        let early_monitor_tag = MonitorTag::from("early_monitor");
+        let mut early_supervision = EarlySupervision::new(Duration::from_millis(10));
+        let heartbeat_monitor = early_supervision
+            .add_heartbeat_monitor(early_monitor_tag, def_heartbeat_monitor_builder())
+            .unwrap();
+
+        // No heartbeats are provided during early initialization.
+        std::thread::sleep(Duration::from_millis(300));
+        assert!(!early_supervision.violation_history().is_empty());
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_early_supervision(early_supervision)
+            .build()
+            .unwrap();
+        assert_eq!(health_monitor.config_summary().heartbeat_monitors, 1);
+        assert!(health_monitor.get_heartbeat_monitor(early_monitor_tag).is_none());
+
+        // Monitor is already taken, violations of early supervision are carried over.
+        health_monitor.start().unwrap();
+        assert!(health_monitor
+            .violation_history()
+            .iter()
+            .any(|record| record.monitor_tag == early_monitor_tag));
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(health_monitor.status(), HealthStatus::Degraded(vec![early_monitor_tag]));
+
+        health_monitor.stop().unwrap();
+        drop(heartbeat_monitor);
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_early_supervision() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let mut early_supervision = EarlySupervision::new(Duration::from_millis(50));
+        let _deadline_monitor = early_supervision
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .unwrap();
+
+        let report = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_early_supervision(early_supervision)
+            .validate();
+        assert_eq!(
+            report.issues(),
+            &[
+                ConfigIssue::DuplicateMonitorTag(monitor_tag),
+                ConfigIssue::EarlySupervisionCycleMismatch
+            ]
+        );
+    }
+
+    #[test]
+    fn health_monitor_multiple_supervisor_identities() {
+        let build = |identity| {
+            HealthMonitorBuilder::new()
+                .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+                .with_supervisor_identity(identity)
+                .build()
+                .unwrap()
+        };
+        let mut health_monitor1 = build("component1");
+        let mut health_monitor2 = build("component2");
+        assert_ne!(
+            health_monitor1.config_summary().digest,
+            health_monitor2.config_summary().digest
+        );
+
+        let _heartbeat_monitor1 = health_monitor1
+            .get_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"))
+            .unwrap();
+        let _heartbeat_monitor2 = health_monitor2
+            .get_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"))
+            .unwrap();
+        health_monitor1.start().unwrap();
+        health_monitor2.start().unwrap();
+        health_monitor1.stop().unwrap();
+        health_monitor2.stop().unwrap();
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_supervisor_identity() {
         let result = HealthMonitorBuilder::new()
-            .with_supervisor_api_cycle(Duration::from_millis(123))
-            .with_internal_processing_cycle(Duration::from_millis(100))
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .with_supervisor_identity("")
             .build();
         assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
     }
 
     #[test]
-    fn health_monitor_builder_build_no_monitors() {
-        let result = HealthMonitorBuilder::new().build();
-        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    fn health_monitor_challenge() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let heartbeat_monitor_builder =
+            HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_secs(1), Duration::from_secs(3)));
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .with_internal_processing_cycle(Duration::from_secs(1))
+            .with_supervisor_api_cycle(Duration::from_secs(1))
+            .build()
+            .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        let challenge_handle = health_monitor.challenge_handle();
+        assert_eq!(challenge_handle.last_response(), None);
+
+        // Challenge is answered long before the next regular cycle.
+        health_monitor.start().unwrap();
+        challenge_handle.challenge(42);
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(
+            challenge_handle.last_response(),
+            Some(ChallengeResponse {
+                challenge_id: 42,
+                healthy: true
+            })
+        );
+        health_monitor.stop().unwrap();
     }
 
     #[test]
-    fn health_monitor_get_deadline_monitor_available() {
+    fn health_monitor_begin_shutdown() {
         let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let deadline_tag = DeadlineTag::from("deadline");
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_deadline_monitor(
+                deadline_monitor_tag,
+                DeadlineMonitorBuilder::new().add_deadline(deadline_tag, def_range()),
+            )
+            .with_internal_processing_cycle(Duration::from_millis(10))
             .build()
             .unwrap();
-
-        let result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
-        assert!(result.is_some());
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        assert_eq!(health_monitor.begin_shutdown(), Err(HealthMonitorError::WrongState));
+
+        // Deadline is abandoned during shutdown and never finished.
+        health_monitor.start().unwrap();
+        let mut deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        let handle = deadline.start().unwrap();
+        health_monitor.begin_shutdown().unwrap();
+        std::thread::sleep(Duration::from_millis(300));
+        drop(handle);
+
+        assert!(!health_monitor.worker_healthy());
+        assert!(health_monitor.violation_history().is_empty());
+        health_monitor.stop().unwrap();
     }
 
     #[test]
-    fn health_monitor_get_deadline_monitor_taken() {
+    fn health_monitor_panic_reporting() {
         let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_panic_reporting(true)
             .build()
             .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        assert!(health_monitor.panic_reporter.is_none());
 
-        let _ = health_monitor.get_deadline_monitor(deadline_monitor_tag);
-        let result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
-        assert!(result.is_none());
+        // Reporter is registered only while monitoring is running.
+        health_monitor.start().unwrap();
+        assert!(health_monitor.panic_reporter.is_some());
+        health_monitor.stop().unwrap();
+        assert!(health_monitor.panic_reporter.is_none());
     }
 
     #[test]
-    fn health_monitor_get_deadline_monitor_unknown() {
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+    fn health_monitor_builder_build_invalid_worker_stall_threshold() {
+        let result = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_worker_stall_threshold(Duration::from_millis(10))
+            .build();
+        assert_eq!(result.err(), Some(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_statistics() {
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), deadline_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
             .build()
             .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        assert!(health_monitor.statistics().is_empty());
+        assert_eq!(
+            health_monitor.monitor_statistics(heartbeat_monitor_tag),
+            Ok(MonitorStatistics::default())
+        );
 
-        let result = health_monitor.get_deadline_monitor(MonitorTag::from("undefined_monitor"));
-        assert!(result.is_none());
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Ok(RunExit::MonitoringFailed));
+
+        let statistics = health_monitor.monitor_statistics(heartbeat_monitor_tag).unwrap();
+        assert_eq!(statistics.total(), 1);
+        assert!(statistics.last_failure.is_some());
+        assert_eq!(health_monitor.statistics(), vec![(heartbeat_monitor_tag, statistics)]);
+        assert_eq!(
+            health_monitor.monitor_statistics(MonitorTag::from("unknown")),
+            Err(HealthMonitorError::NotFound)
+        );
     }
 
     #[test]
-    fn health_monitor_get_deadline_monitor_invalid_state() {
+    fn health_monitor_run_on_current_thread_artifact_unavailable() {
+        let artifact_monitor_tag = MonitorTag::from("artifact_monitor");
+        let path = std::env::temp_dir().join(format!("hmon_lib_artifact_{}_missing", std::process::id()));
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_artifact_freshness_monitor(
+                artifact_monitor_tag,
+                ArtifactFreshnessMonitorBuilder::new().add_file(path, Duration::from_millis(50)),
+            )
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        assert_eq!(health_monitor.config_summary().artifact_freshness_monitors, 1);
+        assert_eq!(health_monitor.config_summary().artifacts, 1);
+
+        // Artifact freshness monitor is not taken, loop exits once the artifact is missing for too long.
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Ok(RunExit::MonitoringFailed));
+        assert_eq!(
+            health_monitor.status(),
+            HealthStatus::Degraded(vec![artifact_monitor_tag])
+        );
+    }
+
+    #[test]
+    fn health_monitor_add_monitor_runtime_while_running() {
         let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_resource_budget(ResourceBudget::new().with_runtime_monitor_capacity(2))
             .build()
             .unwrap();
+        health_monitor.start().unwrap();
 
-        // Inject broken state - unreachable otherwise.
-        health_monitor.deadline_monitors.insert(deadline_monitor_tag, None);
+        let _deadline_monitor = health_monitor
+            .add_deadline_monitor_runtime(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .unwrap();
+        let _heartbeat_monitor = health_monitor
+            .add_heartbeat_monitor_runtime(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .unwrap();
 
-        let result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
-        assert!(result.is_none());
+        // No heartbeats are provided, heartbeat monitor added at runtime is evaluated and fails.
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(
+            health_monitor.status(),
+            HealthStatus::Degraded(vec![heartbeat_monitor_tag])
+        );
+        health_monitor.stop().unwrap();
+
+        // Monitors added at runtime are returned on stop like other monitors.
+        assert!(health_monitor.get_deadline_monitor(deadline_monitor_tag).is_some());
+        assert!(health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).is_some());
     }
 
     #[test]
-    fn health_monitor_get_heartbeat_monitor_available() {
-        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+    fn health_monitor_add_monitor_runtime_before_start() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_resource_budget(ResourceBudget::new().with_runtime_monitor_capacity(1))
             .build()
             .unwrap();
 
-        let result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
-        assert!(result.is_some());
+        let _deadline_monitor = health_monitor
+            .add_deadline_monitor_runtime(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .unwrap();
+        // Monitor is already taken, monitoring can be started.
+        assert!(health_monitor.get_deadline_monitor(deadline_monitor_tag).is_none());
+        health_monitor.start().unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(health_monitor.status(), HealthStatus::Healthy);
+        health_monitor.stop().unwrap();
     }
 
     #[test]
-    fn health_monitor_get_heartbeat_monitor_taken() {
-        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+    fn health_monitor_add_monitor_runtime_rejected() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_resource_budget(ResourceBudget::new().with_runtime_monitor_capacity(1))
             .build()
             .unwrap();
 
-        let _ = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
-        let result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
-        assert!(result.is_none());
+        // Tag already in use.
+        let result =
+            health_monitor.add_heartbeat_monitor_runtime(deadline_monitor_tag, def_heartbeat_monitor_builder());
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+
+        // Capacity exhausted.
+        let _heartbeat_monitor = health_monitor
+            .add_heartbeat_monitor_runtime(MonitorTag::from("heartbeat_monitor"), def_heartbeat_monitor_builder())
+            .unwrap();
+        let result = health_monitor
+            .add_deadline_monitor_runtime(MonitorTag::from("other_monitor"), DeadlineMonitorBuilder::new());
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
     }
 
     #[test]
-    fn health_monitor_get_heartbeat_monitor_unknown() {
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+    fn health_monitor_signal_export() {
+        #[derive(Clone, Default)]
+        struct RecordingSink(Arc<Mutex<Vec<(String, SignalValue)>>>);
+
+        impl SignalSink for RecordingSink {
+            fn publish(&mut self, signal_name: &str, value: SignalValue) {
+                self.0.lock().unwrap().push((signal_name.to_string(), value));
+            }
+        }
+
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let sink = RecordingSink::default();
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), heartbeat_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_signal_export(SignalExport::new(sink.clone()).add_signal(heartbeat_monitor_tag, "Health.Heartbeat"))
             .build()
             .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
 
-        let result = health_monitor.get_heartbeat_monitor(MonitorTag::from("undefined_monitor"));
-        assert!(result.is_none());
+        // No heartbeats are provided, signal changes once the heartbeat range is exceeded.
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Ok(RunExit::MonitoringFailed));
+        assert_eq!(
+            *sink.0.lock().unwrap(),
+            vec![
+                ("Health.Heartbeat".to_string(), SignalValue::Healthy),
+                ("Health.Heartbeat".to_string(), SignalValue::Failed)
+            ]
+        );
     }
 
     #[test]
-    fn health_monitor_get_heartbeat_monitor_invalid_state() {
+    fn health_monitor_violation_sinks() {
+        #[derive(Clone, Default)]
+        struct RecordingSink(Arc<Mutex<Vec<MonitorTag>>>);
+
+        impl ViolationSink for RecordingSink {
+            fn on_violation(&mut self, record: &ViolationRecord) {
+                self.0.lock().unwrap().push(record.monitor_tag);
+            }
+        }
+
         let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+        let first_sink = RecordingSink::default();
+        let second_sink = RecordingSink::default();
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .without_violation_logging()
+            .add_violation_sink(first_sink.clone())
+            .add_violation_sink(second_sink.clone())
             .build()
             .unwrap();
+        let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
 
-        // Inject broken state - unreachable otherwise.
-        health_monitor.heartbeat_monitors.insert(heartbeat_monitor_tag, None);
+        // No heartbeats are provided, violation is dispatched to both sinks.
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Ok(RunExit::MonitoringFailed));
+        assert_eq!(*first_sink.0.lock().unwrap(), vec![heartbeat_monitor_tag]);
+        assert_eq!(*second_sink.0.lock().unwrap(), vec![heartbeat_monitor_tag]);
+    }
 
-        let result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
-        assert!(result.is_none());
+    #[test]
+    fn health_monitor_builder_build_signal_export_unknown_monitor() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .with_signal_export(
+                SignalExport::new(LoggingSignalSink).add_signal(MonitorTag::from("unknown_monitor"), "Health.Unknown"),
+            )
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::NotFound));
     }
 
     #[test]
-    fn health_monitor_start_succeeds() {
-        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+    fn health_monitor_disable_monitor() {
         let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
-
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
-            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_internal_processing_cycle(Duration::from_millis(10))
             .build()
             .unwrap();
-
-        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
         let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
 
-        let result = health_monitor.start();
-        assert!(result.is_ok());
+        // No heartbeats are provided, disabled monitor reports no errors.
+        health_monitor.disable_monitor(heartbeat_monitor_tag).unwrap();
+        health_monitor.start().unwrap();
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(health_monitor.status(), HealthStatus::Healthy);
+
+        // Supervision resumes with a new cycle, monitor fails once its range is exceeded.
+        health_monitor.enable_monitor(heartbeat_monitor_tag).unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(health_monitor.status(), HealthStatus::Healthy);
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(
+            health_monitor.status(),
+            HealthStatus::Degraded(vec![heartbeat_monitor_tag])
+        );
     }
 
     #[test]
-    fn health_monitor_start_monitors_not_taken() {
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
+    fn health_monitor_disable_monitor_unknown() {
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+        let result = health_monitor.disable_monitor(MonitorTag::from("unknown_monitor"));
+        assert!(result.is_err_and(|e| e == HealthMonitorError::NotFound));
+        let result = health_monitor.enable_monitor(MonitorTag::from("unknown_monitor"));
+        assert!(result.is_err_and(|e| e == HealthMonitorError::NotFound));
+    }
 
+    #[test]
+    fn health_monitor_status() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), deadline_monitor_builder)
-            .add_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"), heartbeat_monitor_builder)
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(10))
             .build()
             .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        assert_eq!(health_monitor.status(), HealthStatus::Stopped);
 
-        let result = health_monitor.start();
-        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+        health_monitor.start().unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(health_monitor.status(), HealthStatus::Healthy);
+
+        health_monitor.stop().unwrap();
+        assert_eq!(health_monitor.status(), HealthStatus::Stopped);
     }
 
     #[test]
-    fn health_monitor_start_not_taken_then_restart() {
+    fn health_monitor_snapshot() {
         let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
-        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let deadline_tag = DeadlineTag::from("deadline");
         let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
-        let heartbeat_monitor_builder = def_heartbeat_monitor_builder();
-
+        let artifact_monitor_tag = MonitorTag::from("artifact_monitor");
         let mut health_monitor = HealthMonitorBuilder::new()
-            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
-            .add_heartbeat_monitor(heartbeat_monitor_tag, heartbeat_monitor_builder)
+            .add_deadline_monitor(
+                deadline_monitor_tag,
+                DeadlineMonitorBuilder::new().add_deadline(deadline_tag, def_range()),
+            )
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .add_artifact_freshness_monitor(
+                artifact_monitor_tag,
+                ArtifactFreshnessMonitorBuilder::new().add_file("/tmp/artifact", Duration::from_secs(1)),
+            )
             .build()
             .unwrap();
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        health_monitor.disable_monitor(heartbeat_monitor_tag).unwrap();
 
-        // Start without taking any monitor.
-        let start_result = health_monitor.start();
-        assert!(start_result.is_err_and(|e| e == HealthMonitorError::WrongState));
+        let snapshot = health_monitor.snapshot();
+        assert_eq!(snapshot.status, HealthStatus::Stopped);
+        assert_eq!(snapshot.config_summary, health_monitor.config_summary());
+        assert_eq!(
+            snapshot.monitors,
+            vec![
+                MonitorSnapshot {
+                    monitor_tag: deadline_monitor_tag,
+                    config: MonitorConfig::Deadline {
+                        deadlines: vec![(deadline_tag, def_range())],
+                    },
+                    ownership: MonitorOwnership::Taken,
+                    enabled: true,
+                    failing: false,
+                    recent_violations: vec![],
+                },
+                MonitorSnapshot {
+                    monitor_tag: heartbeat_monitor_tag,
+                    config: MonitorConfig::Heartbeat { range: def_range() },
+                    ownership: MonitorOwnership::Available,
+                    enabled: false,
+                    failing: false,
+                    recent_violations: vec![],
+                },
+                MonitorSnapshot {
+                    monitor_tag: artifact_monitor_tag,
+                    config: MonitorConfig::ArtifactFreshness {
+                        artifacts: vec![(PathBuf::from("/tmp/artifact"), Duration::from_secs(1))],
+                    },
+                    ownership: MonitorOwnership::Internal,
+                    enabled: true,
+                    failing: false,
+                    recent_violations: vec![],
+                },
+            ]
+        );
+    }
 
-        // Take monitors.
-        let get_deadline_monitor_result = health_monitor.get_deadline_monitor(deadline_monitor_tag);
-        assert!(get_deadline_monitor_result.is_some());
-        let get_heartbeat_monitor_result = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag);
-        assert!(get_heartbeat_monitor_result.is_some());
+    #[test]
+    fn health_monitor_run_on_current_thread_monitors_not_taken() {
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
 
-        // Try to start again, this time should be successful.
-        let start_result = health_monitor.start();
-        assert!(start_result.is_ok());
+        let result = health_monitor.run_on_current_thread(&StopToken::new());
+        assert_eq!(result, Err(HealthMonitorError::WrongState));
     }
 }
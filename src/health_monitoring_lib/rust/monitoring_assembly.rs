@@ -0,0 +1,284 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Assembly of the monitoring logic run by [`HealthMonitor`] from its monitors and configuration.
+
+use crate::common::{InternalFault, Monitor, MonitorEvalHandle};
+use crate::group::GroupTracker;
+use crate::log::error;
+use crate::panic_reporting::{self, HealthMonitorPanicReporter, PanicReporter};
+use crate::supervisor_api_client::{self, SharedSupervisorAPIClient, SupervisorAPIClient};
+use crate::tag::{ChannelTag, MonitorTag};
+use crate::tolerance::{RecoveryTracker, ToleranceTracker};
+use crate::violation_history::{InternalFaultRecord, ViolationHistory};
+use crate::worker;
+use crate::{HealthMonitor, HealthMonitorError, MonitorContainer, MonitorState};
+use containers::fixed_capacity::FixedCapacityVec;
+use core::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Create the supervisor API client selected at build time, notifying `channel_tags` in addition to the process.
+/// Process is notified under `identity`, or the identifier given by the environment if not provided.
+#[cfg_attr(any(test, feature = "stub_supervisor_api_client"), allow(unused_variables))]
+#[allow(clippy::let_and_return)]
+pub(crate) fn create_supervisor_api_client(
+    identity: Option<&str>,
+    channel_tags: &[ChannelTag],
+) -> impl SupervisorAPIClient + Send + 'static {
+    #[cfg(not(any(
+        test,
+        feature = "stub_supervisor_api_client",
+        feature = "supervisor_api_client_selection"
+    )))]
+    let supervisor_api_client =
+        supervisor_api_client::score_supervisor_api_client::ScoreSupervisorAPIClient::new(identity, channel_tags);
+    #[cfg(all(
+        not(any(test, feature = "stub_supervisor_api_client")),
+        feature = "supervisor_api_client_selection"
+    ))]
+    let supervisor_api_client =
+        supervisor_api_client::selected_supervisor_api_client::SelectedSupervisorAPIClient::from_env(
+            identity,
+            channel_tags,
+        );
+    #[cfg(any(test, feature = "stub_supervisor_api_client"))]
+    let supervisor_api_client = supervisor_api_client::stub_supervisor_api_client::StubSupervisorAPIClient::new();
+    supervisor_api_client
+}
+
+/// Push `handle` of a collected monitor, storage of collected monitors is preallocated.
+fn push_monitor_handle(
+    collected_monitors: &mut FixedCapacityVec<MonitorEvalHandle>,
+    handle: MonitorEvalHandle,
+) -> Result<(), HealthMonitorError> {
+    if collected_monitors.push(handle).is_err() {
+        // Should not fail - capacity was preallocated.
+        error!("Failed to push monitor handle.");
+        return Err(HealthMonitorError::WrongState);
+    }
+    Ok(())
+}
+
+impl HealthMonitor {
+    pub(crate) fn collect_given_monitors<'a, M: Monitor + 'a>(
+        monitors_to_collect: impl IntoIterator<Item = (&'a MonitorTag, &'a MonitorContainer<M>)>,
+        collected_monitors: &mut FixedCapacityVec<MonitorEvalHandle>,
+        violation_history: &ViolationHistory,
+        starting_point: Instant,
+    ) -> Result<(), HealthMonitorError> {
+        let report_internal_fault = |tag: &MonitorTag, fault| {
+            violation_history.push_internal_fault(InternalFaultRecord {
+                monitor_tag: Some(*tag),
+                fault,
+                timestamp: starting_point.elapsed(),
+            })
+        };
+
+        for (tag, monitor) in monitors_to_collect {
+            match monitor {
+                // Returned monitors are collected disabled, so subsystems shut down do not block the start.
+                Some(MonitorState::Taken(monitor) | MonitorState::Returned(monitor)) => {
+                    if let Err(error) = push_monitor_handle(collected_monitors, monitor.get_eval_handle()) {
+                        report_internal_fault(tag, InternalFault::MonitorHandlePush);
+                        return Err(error);
+                    }
+                },
+                Some(MonitorState::Available(_)) => {
+                    error!(
+                        "All monitors must be taken before starting HealthMonitor but {:?} is not taken.",
+                        tag
+                    );
+                    return Err(HealthMonitorError::WrongState);
+                },
+                None => {
+                    error!(
+                        "Invalid monitor ({:?}) state encountered while starting HealthMonitor.",
+                        tag
+                    );
+                    report_internal_fault(tag, InternalFault::StateCorruption);
+                    return Err(HealthMonitorError::WrongState);
+                },
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn create_monitoring_logic(
+        &mut self,
+    ) -> Result<worker::MonitoringLogic<impl SupervisorAPIClient + Send + 'static>, HealthMonitorError> {
+        Self::check_not_in_callback()?;
+        if self.worker.is_started() {
+            error!("Monitoring is already started.");
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        // Monitors added while stopped are collected below together with other monitors.
+        drop(self.runtime_monitors.take_all());
+        self.shutdown.store(false, Ordering::Release);
+        if let Some(armed_start) = &self.armed_start {
+            armed_start.disarm();
+        }
+        if let Some(health_trajectory) = &self.health_trajectory {
+            health_trajectory.reset();
+        }
+
+        // Collect all monitors, reserving slots for monitors added at runtime.
+        let num_monitors = self.deadline_monitors.len()
+            + self.heartbeat_monitors.len()
+            + self.artifact_freshness_monitors.len()
+            + usize::from(self.update_mode.is_some())
+            + usize::from(self.armed_start.is_some())
+            + usize::from(self.health_trajectory.is_some())
+            + self.runtime_monitor_capacity;
+        let mut collected_monitors = FixedCapacityVec::new(num_monitors);
+        Self::collect_given_monitors(
+            self.deadline_monitors.iter(),
+            &mut collected_monitors,
+            &self.violation_history,
+            self.starting_point,
+        )?;
+        Self::collect_given_monitors(
+            self.heartbeat_monitors.iter(),
+            &mut collected_monitors,
+            &self.violation_history,
+            self.starting_point,
+        )?;
+        for monitor in self.artifact_freshness_monitors.iter() {
+            push_monitor_handle(&mut collected_monitors, MonitorEvalHandle::new(monitor.clone()))?;
+        }
+        if let Some(update_mode) = &self.update_mode {
+            push_monitor_handle(&mut collected_monitors, MonitorEvalHandle::new(update_mode.clone()))?;
+        }
+        if let Some(armed_start) = &self.armed_start {
+            push_monitor_handle(&mut collected_monitors, MonitorEvalHandle::new(armed_start.clone()))?;
+        }
+        if let Some(health_trajectory) = &self.health_trajectory {
+            push_monitor_handle(
+                &mut collected_monitors,
+                MonitorEvalHandle::new(health_trajectory.clone()),
+            )?;
+        }
+
+        // Create monitoring logic.
+        // Groups are assigned to the channel of their members.
+        let channels = self
+            .notification_channels
+            .iter()
+            .map(|(channel_tag, monitor_tags)| {
+                let group_tags = self
+                    .monitor_groups
+                    .iter()
+                    .filter(|(_, group)| group.members().iter().any(|member| monitor_tags.contains(member)))
+                    .map(|(group_tag, _)| MonitorTag::from(*group_tag));
+                worker::NotificationChannel::new(*channel_tag, monitor_tags.iter().copied().chain(group_tags).collect())
+            })
+            .collect();
+        let groups = self
+            .monitor_groups
+            .iter()
+            .map(|(group_tag, group)| GroupTracker::new(*group_tag, group))
+            .collect();
+        let channel_tags: Vec<_> = self
+            .notification_channels
+            .iter()
+            .map(|(channel_tag, _)| *channel_tag)
+            .collect();
+        let supervisor_api_client = SharedSupervisorAPIClient::new(create_supervisor_api_client(
+            self.supervisor_identity.as_deref(),
+            &channel_tags,
+        ));
+
+        if self.strict_validation && supervisor_api_client.is_stub() {
+            error!("Stub supervisor API client is rejected by strict validation.");
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        if self.panic_reporting {
+            let panic_reporter: Arc<dyn PanicReporter> = Arc::new(HealthMonitorPanicReporter::new(
+                supervisor_api_client.clone_shared(),
+                self.failure_statistics.clone(),
+                self.health_forwarder.clone(),
+            ));
+            panic_reporting::register(&panic_reporter);
+            self.panic_reporter = Some(panic_reporter);
+        }
+
+        // Monitors of early supervision keep their state, evaluation is continued by the monitoring logic.
+        if let Some(early_supervision) = self.early_supervision.take() {
+            early_supervision.hand_over(&self.violation_history);
+        }
+
+        let monitoring_logic = worker::MonitoringLogic::new(
+            collected_monitors,
+            self.supervisor_api_cycle,
+            supervisor_api_client,
+            self.violation_history.clone(),
+        )
+        .with_channels(channels)
+        .with_groups(groups)
+        .with_evaluation_budget(self.internal_processing_cycle)
+        .with_status_board(self.status_board.clone())
+        .with_runtime_monitors(self.runtime_monitors.clone())
+        .with_failure_statistics(self.failure_statistics.clone())
+        .with_failure_tolerance(ToleranceTracker::new(
+            self.failure_tolerance,
+            self.monitor_failure_tolerances.clone(),
+        ))
+        .with_recovery(RecoveryTracker::new(self.recovery_policy))
+        .with_worker_liveness(self.worker.liveness().clone())
+        .with_challenge(self.challenge.clone())
+        .with_shutdown(self.shutdown.clone())
+        .with_violation_sinks(self.violation_sinks.clone());
+        let monitoring_logic = match &self.failure_callback {
+            Some(failure_callback) => {
+                monitoring_logic.with_failure_callback(failure_callback.clone(), self.failure_callback_budget)
+            },
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match self.clock_gap_threshold {
+            Some(clock_gap_threshold) => monitoring_logic.with_clock_gap_threshold(clock_gap_threshold),
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match &self.signal_export {
+            Some(signal_export) => monitoring_logic.with_signal_export(signal_export.clone()),
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match &self.health_forwarder {
+            Some(health_forwarder) => monitoring_logic.with_health_forwarder(health_forwarder.clone()),
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match &self.update_mode {
+            Some(update_mode) => monitoring_logic.with_update_mode(update_mode.clone()),
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match self.startup_grace {
+            Some(startup_grace) => monitoring_logic.with_startup_grace(startup_grace),
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match &self.armed_start {
+            Some(armed_start) => monitoring_logic.with_armed_start(armed_start.clone()),
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match &self.health_trajectory {
+            Some(health_trajectory) => monitoring_logic.with_health_trajectory(health_trajectory.clone()),
+            None => monitoring_logic,
+        };
+        let monitoring_logic = match &self.fail_fast {
+            Some(fail_fast) => monitoring_logic.with_fail_fast(fail_fast.clone()),
+            None => monitoring_logic,
+        };
+
+        Ok(monitoring_logic)
+    }
+}
@@ -0,0 +1,185 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+/// Map keeping entries in insertion order, so iteration order is deterministic.
+///
+/// Entries are stored in a vector and looked up linearly, maps hold a few monitors only.
+/// Map created with [`OrderedMap::with_capacity`] never reallocates, see [`OrderedMap::try_insert`].
+#[derive(Clone, Debug)]
+pub(crate) struct OrderedMap<K, V> {
+    entries: Vec<(K, V)>,
+    /// Maximum number of entries, [`None`] if the map can grow.
+    capacity: Option<usize>,
+}
+
+impl<K: PartialEq, V> OrderedMap<K, V> {
+    /// Create an empty map which can grow.
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            capacity: None,
+        }
+    }
+
+    /// Create an empty map with storage for `capacity` entries preallocated.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            capacity: Some(capacity),
+        }
+    }
+
+    /// Insert value of the key, replacing the previous value in place.
+    /// Returns the previous value, [`None`] if the key was not present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map has a fixed capacity which is exhausted, use [`Self::try_insert`] for such maps.
+    pub(crate) fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.try_insert(key, value) {
+            Ok(previous) => previous,
+            Err(_) => panic!("OrderedMap capacity exhausted"),
+        }
+    }
+
+    /// Insert value of the key, replacing the previous value in place.
+    /// Returns the previous value, [`None`] if the key was not present.
+    /// New key is rejected if the map is full, the value is then returned back as an error.
+    pub(crate) fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, V> {
+        if let Some(current) = self.get_mut(&key) {
+            return Ok(Some(core::mem::replace(current, value)));
+        }
+        if self.capacity.is_some_and(|capacity| self.entries.len() >= capacity) {
+            return Err(value);
+        }
+
+        self.entries.push((key, value));
+        Ok(None)
+    }
+
+    pub(crate) fn get(&self, key: &K) -> Option<&V> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub(crate) fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub(crate) fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Entries in insertion order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&K, &V)> + Clone {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Keys in insertion order.
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Values in insertion order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Mutable values in insertion order.
+    pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.entries.iter_mut().map(|(_, value)| value)
+    }
+}
+
+impl<K: PartialEq, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    /// Entries in insertion order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::ordered_map::OrderedMap;
+
+    #[test]
+    fn ordered_map_keeps_insertion_order() {
+        let mut map = OrderedMap::new();
+        for key in ["c", "a", "b"] {
+            assert_eq!(map.insert(key, key.len()), None);
+        }
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec!["c", "a", "b"]);
+        assert_eq!(
+            map.into_iter().map(|(key, _)| key).collect::<Vec<_>>(),
+            vec!["c", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn ordered_map_replaces_in_place() {
+        let mut map = OrderedMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        assert_eq!(map.insert("a", 3), Some(1));
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&"a", &3), (&"b", &2)]);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn ordered_map_lookup() {
+        let mut map = OrderedMap::new();
+        assert_eq!(map.len(), 0);
+        map.insert("a", 1);
+        assert_eq!(map.get(&"a"), Some(&1));
+        assert_eq!(map.get(&"b"), None);
+        *map.get_mut(&"a").unwrap() = 2;
+        assert!(map.contains_key(&"a"));
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn ordered_map_fixed_capacity() {
+        let mut map = OrderedMap::with_capacity(1);
+        assert_eq!(map.try_insert("a", 1), Ok(None));
+        assert_eq!(map.try_insert("b", 2), Err(2));
+        // Replacing an existing key needs no additional capacity.
+        assert_eq!(map.try_insert("a", 3), Ok(Some(1)));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "OrderedMap capacity exhausted")]
+    fn ordered_map_insert_into_full_map_panics() {
+        let mut map = OrderedMap::with_capacity(0);
+        map.insert("a", 1);
+    }
+}
@@ -0,0 +1,243 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::failure_statistics::FailureStatistics;
+use crate::health_forwarder::{ForwardedStatus, UdpHealthForwarder};
+use crate::log::{error, info};
+use crate::supervisor_api_client::{SharedSupervisorAPIClient, SupervisorAPIClient};
+use crate::worker::in_monitoring_callback;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Once, Weak};
+use std::time::Instant;
+
+/// Reports a panic of the process, invoked from the panic hook.
+pub(crate) trait PanicReporter: Send + Sync {
+    fn report_panic(&self);
+}
+
+/// Reporters of all health monitors with panic reporting enabled.
+static PANIC_REPORTERS: Mutex<Vec<Weak<dyn PanicReporter>>> = Mutex::new(Vec::new());
+
+/// Panic hook is installed once per process and chained to the previously installed hook.
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// Register `reporter` to be invoked on panics, until it is dropped.
+/// Installs the panic hook on first registration.
+pub(crate) fn register(reporter: &Arc<dyn PanicReporter>) {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |panic_info| {
+            if panic_ends_process() {
+                report_panic();
+            }
+            previous_hook(panic_info);
+        }));
+        info!("Panic hook installed.");
+    });
+
+    let mut reporters = PANIC_REPORTERS.lock().unwrap_or_else(|e| e.into_inner());
+    reporters.retain(|reporter| reporter.strong_count() > 0);
+    reporters.push(Arc::downgrade(reporter));
+}
+
+/// Panic ends the process if panics abort, or if it unwinds the main thread.
+/// Panics of other threads end the thread only, they are joined or ignored while the process keeps running.
+/// Panic hook cannot tell whether a panic of the main thread is caught with [`std::panic::catch_unwind`],
+/// such panics are reported as well.
+fn panic_ends_process() -> bool {
+    cfg!(panic = "abort") || std::thread::current().name() == Some("main")
+}
+
+/// Invoke all registered reporters.
+/// Panics of monitoring callbacks are contained by the monitoring logic, so they are not reported.
+fn report_panic() {
+    if in_monitoring_callback() {
+        return;
+    }
+    // Registration is never interrupted by a panic, so the lock is held only briefly by another thread.
+    let Ok(reporters) = PANIC_REPORTERS.try_lock() else {
+        error!("Panic cannot be reported, panic reporters are being registered.");
+        return;
+    };
+    for reporter in reporters.iter().filter_map(Weak::upgrade) {
+        reporter.report_panic();
+    }
+}
+
+/// Reports a panic of the process on behalf of a health monitor.
+/// Notifies the supervisor, flushes failure statistics to the log and forwards a failed health summary.
+pub(crate) struct HealthMonitorPanicReporter<T> {
+    client: SharedSupervisorAPIClient<T>,
+    failure_statistics: Arc<FailureStatistics>,
+    health_forwarder: Option<Arc<UdpHealthForwarder>>,
+    hmon_starting_point: Instant,
+    /// Panic was already reported, only the first panic is reported.
+    reported: AtomicBool,
+}
+
+impl<T: SupervisorAPIClient> HealthMonitorPanicReporter<T> {
+    pub(crate) fn new(
+        client: SharedSupervisorAPIClient<T>,
+        failure_statistics: Arc<FailureStatistics>,
+        health_forwarder: Option<Arc<UdpHealthForwarder>>,
+    ) -> Self {
+        Self {
+            client,
+            failure_statistics,
+            health_forwarder,
+            hmon_starting_point: Instant::now(),
+            reported: AtomicBool::new(false),
+        }
+    }
+}
+
+impl<T: SupervisorAPIClient + Send> PanicReporter for HealthMonitorPanicReporter<T> {
+    fn report_panic(&self) {
+        if self.reported.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        if self.client.try_with(T::notify_terminating) {
+            error!("Panic reported to the supervisor.");
+        } else {
+            error!("Panic could not be reported to the supervisor.");
+        }
+
+        let failing_monitors = match self.failure_statistics.try_totals() {
+            Some(totals) => {
+                for (monitor_tag, total) in totals.iter() {
+                    error!("Monitor {:?} failed {} times before the panic.", monitor_tag, total);
+                }
+                totals.len()
+            },
+            None => {
+                error!("Failure statistics could not be flushed, they are being updated.");
+                0
+            },
+        };
+
+        if let Some(health_forwarder) = &self.health_forwarder {
+            let failing_monitors = u16::try_from(failing_monitors).unwrap_or(u16::MAX);
+            if !health_forwarder.forward(
+                self.hmon_starting_point.elapsed(),
+                ForwardedStatus::Failed,
+                failing_monitors,
+            ) {
+                error!("Failed to forward health summary of the panic.");
+            }
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::failure_statistics::FailureStatistics;
+    use crate::panic_reporting::{register, HealthMonitorPanicReporter, PanicReporter};
+    use crate::supervisor_api_client::{SharedSupervisorAPIClient, SupervisorAPIClient};
+    use crate::tag::ChannelTag;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct TerminatingClient {
+        terminating: Arc<AtomicUsize>,
+    }
+
+    impl SupervisorAPIClient for TerminatingClient {
+        fn notify_alive(&self) -> bool {
+            true
+        }
+
+        fn notify_channel_alive(&self, _channel_tag: ChannelTag) -> bool {
+            true
+        }
+
+        fn notify_terminating(&self) -> bool {
+            self.terminating.fetch_add(1, Ordering::Relaxed);
+            true
+        }
+    }
+
+    #[test]
+    fn health_monitor_panic_reporter_reports_once() {
+        let client = TerminatingClient::default();
+        let reporter = HealthMonitorPanicReporter::new(
+            SharedSupervisorAPIClient::new(client.clone()),
+            Arc::new(FailureStatistics::new(1)),
+            None,
+        );
+        reporter.report_panic();
+        reporter.report_panic();
+        assert_eq!(client.terminating.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn health_monitor_panic_reporter_client_in_use() {
+        let client = TerminatingClient::default();
+        let shared_client = SharedSupervisorAPIClient::new(client.clone());
+        let reporter =
+            HealthMonitorPanicReporter::new(shared_client.clone_shared(), Arc::new(FailureStatistics::new(1)), None);
+        shared_client.try_with(|_| {
+            reporter.report_panic();
+            true
+        });
+        assert_eq!(client.terminating.load(Ordering::Relaxed), 0);
+    }
+
+    /// Panic hook is installed for the whole process, so it is tested in a child process of the test binary
+    /// running [`panic_hook_invokes_registered_reporters_isolated`] only.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn panic_hook_invokes_registered_reporters() {
+        let status = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "panic_reporting::tests::panic_hook_invokes_registered_reporters_isolated",
+                "--ignored",
+                "--test-threads=1",
+            ])
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    #[ignore = "installs the panic hook of the process, run by panic_hook_invokes_registered_reporters"]
+    fn panic_hook_invokes_registered_reporters_isolated() {
+        let client = TerminatingClient::default();
+        let reporter: Arc<dyn PanicReporter> = Arc::new(HealthMonitorPanicReporter::new(
+            SharedSupervisorAPIClient::new(client.clone()),
+            Arc::new(FailureStatistics::new(1)),
+            None,
+        ));
+        register(&reporter);
+
+        // Panic of a thread joined by the process does not end the process.
+        let result = std::thread::spawn(|| panic!("joined panic")).join();
+        assert!(result.is_err());
+        assert_eq!(client.terminating.load(Ordering::Relaxed), 0);
+
+        // Test runs on a thread of the test harness, panic of the main thread is simulated by its name.
+        let main_thread = std::thread::Builder::new().name("main".to_string());
+        let result = main_thread.spawn(|| panic!("reported panic")).unwrap().join();
+        assert!(result.is_err());
+        assert_eq!(client.terminating.load(Ordering::Relaxed), 1);
+
+        // Dropped reporter is no longer invoked.
+        drop(reporter);
+        let main_thread = std::thread::Builder::new().name("main".to_string());
+        let result = main_thread.spawn(|| panic!("unreported panic")).unwrap().join();
+        assert!(result.is_err());
+        assert_eq!(client.terminating.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,59 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Types needed by most applications - builders, monitors and their handles, tags, ranges and errors.
+//! Import with `use health_monitoring_lib::prelude::*;`.
+
+pub use crate::artifact::{ArtifactEvaluationError, ArtifactFreshnessMonitorBuilder};
+pub use crate::deadline::{
+    Deadline, DeadlineError, DeadlineEvaluationError, DeadlineGroup, DeadlineGuard, DeadlineHandle, DeadlineMonitor,
+    DeadlineMonitorBuilder, DeadlineMonitorError, StartedDeadline,
+};
+pub use crate::heartbeat::{
+    HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder, HeartbeatMonitorError, HeartbeatProducer,
+};
+pub use crate::{
+    DeadlineTag, HealthMonitor, HealthMonitorBuilder, HealthMonitorError, HealthStatus, MonitorEvaluationError,
+    MonitorTag, TimeRange,
+};
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn prelude_builds_health_monitor() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(
+                deadline_monitor_tag,
+                DeadlineMonitorBuilder::new()
+                    .add_deadline(deadline_tag, TimeRange::new(Duration::ZERO, Duration::from_millis(100))),
+            )
+            .add_heartbeat_monitor(
+                MonitorTag::from("heartbeat_monitor"),
+                HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_millis(100), Duration::from_millis(200))),
+            )
+            .build()
+            .unwrap();
+
+        let deadline_monitor: DeadlineMonitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let mut deadline: Deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        let handle: DeadlineHandle = deadline.start().unwrap();
+        handle.stop();
+        assert_eq!(health_monitor.status(), HealthStatus::Stopped);
+    }
+}
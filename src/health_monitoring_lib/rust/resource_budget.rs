@@ -0,0 +1,298 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::clock_granularity::clock_granularity;
+use crate::common::MonitorEvalHandle;
+use crate::config_summary::ConfigDigest;
+use crate::failure_statistics::MonitorStatistics;
+use crate::log::{error, ScoreDebug};
+use crate::tag::MonitorTag;
+use crate::violation_history::{InternalFaultRecord, ViolationRecord};
+use crate::HealthMonitorError;
+
+/// Sizes of the bounded buffers used by the [`crate::HealthMonitor`] for its own bookkeeping.
+///
+/// All buffers are allocated once when the [`crate::HealthMonitor`] is built and never grow afterwards.
+///
+/// # Note
+///
+/// Budget covers the bookkeeping of the health monitor itself. Following allocations are outside of it:
+/// - state of each monitor, including duration histograms of every deadline
+///   (see [`crate::deadline::DeadlineMonitor::stats`]), allocated when the monitor is built,
+/// - process-wide registry of threads for [`crate::deadline::DeadlineMonitorBuilder::with_thread_attribution`],
+///   growing up to 256 entries as threads report for the first time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResourceBudget {
+    violation_history_capacity: usize,
+    internal_fault_history_capacity: usize,
+    runtime_monitor_capacity: usize,
+    memory_limit: Option<usize>,
+}
+
+impl ResourceBudget {
+    /// Default number of violations kept in the history.
+    pub const DEFAULT_VIOLATION_HISTORY_CAPACITY: usize = 16;
+
+    /// Maximum number of violations kept in the history.
+    pub const MAX_VIOLATION_HISTORY_CAPACITY: usize = 4096;
+
+    /// Default number of internal faults kept in the history.
+    pub const DEFAULT_INTERNAL_FAULT_HISTORY_CAPACITY: usize = 16;
+
+    /// Maximum number of internal faults kept in the history.
+    pub const MAX_INTERNAL_FAULT_HISTORY_CAPACITY: usize = 1024;
+
+    /// Create a new [`ResourceBudget`] with default capacities and no memory limit.
+    pub fn new() -> Self {
+        Self {
+            violation_history_capacity: Self::DEFAULT_VIOLATION_HISTORY_CAPACITY,
+            internal_fault_history_capacity: Self::DEFAULT_INTERNAL_FAULT_HISTORY_CAPACITY,
+            runtime_monitor_capacity: 0,
+            memory_limit: None,
+        }
+    }
+
+    /// Set the number of violations kept in the history.
+    /// Oldest violations are overwritten when the history is full, `0` disables the history.
+    ///
+    /// - `capacity` - number of violations, up to [`Self::MAX_VIOLATION_HISTORY_CAPACITY`].
+    pub fn with_violation_history_capacity(mut self, capacity: usize) -> Self {
+        self.violation_history_capacity = capacity;
+        self
+    }
+
+    /// Set the number of internal faults kept in the history.
+    /// Oldest faults are overwritten when the history is full, `0` disables the history.
+    ///
+    /// - `capacity` - number of faults, up to [`Self::MAX_INTERNAL_FAULT_HISTORY_CAPACITY`].
+    pub fn with_internal_fault_history_capacity(mut self, capacity: usize) -> Self {
+        self.internal_fault_history_capacity = capacity;
+        self
+    }
+
+    /// Set the number of monitors which can be added after the [`crate::HealthMonitor`] is built.
+    /// Slots are reserved upfront, so monitors can be added while monitoring is running.
+    ///
+    /// - `capacity` - number of monitors added with `add_*_monitor_runtime` methods of [`crate::HealthMonitor`].
+    pub fn with_runtime_monitor_capacity(mut self, capacity: usize) -> Self {
+        self.runtime_monitor_capacity = capacity;
+        self
+    }
+
+    /// Set the upper limit of memory used by all buffers combined.
+    /// [`crate::HealthMonitorBuilder::build`] fails if the budget exceeds the limit.
+    ///
+    /// - `bytes` - memory limit in bytes.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Validate the budget and calculate the memory required by the buffers.
+    ///
+    /// - `num_monitors` - number of monitors known at build time, runtime monitor capacity is added to it.
+    pub(crate) fn validate(&self, num_monitors: usize) -> Result<CapacityReport, HealthMonitorError> {
+        if self.violation_history_capacity > Self::MAX_VIOLATION_HISTORY_CAPACITY {
+            error!(
+                "Violation history capacity ({}) exceeds the maximum ({}).",
+                self.violation_history_capacity,
+                Self::MAX_VIOLATION_HISTORY_CAPACITY
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+
+        if self.internal_fault_history_capacity > Self::MAX_INTERNAL_FAULT_HISTORY_CAPACITY {
+            error!(
+                "Internal fault history capacity ({}) exceeds the maximum ({}).",
+                self.internal_fault_history_capacity,
+                Self::MAX_INTERNAL_FAULT_HISTORY_CAPACITY
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+
+        let report = CapacityReport::new(
+            num_monitors + self.runtime_monitor_capacity,
+            self.violation_history_capacity,
+            self.internal_fault_history_capacity,
+        );
+        if let Some(memory_limit) = self.memory_limit {
+            if report.total_bytes > memory_limit {
+                error!(
+                    "Resource budget requires {} bytes, exceeding the memory limit of {} bytes.",
+                    report.total_bytes, memory_limit
+                );
+                return Err(HealthMonitorError::InvalidArgument);
+            }
+        }
+
+        Ok(report)
+    }
+
+    pub(crate) fn violation_history_capacity(&self) -> usize {
+        self.violation_history_capacity
+    }
+
+    pub(crate) fn internal_fault_history_capacity(&self) -> usize {
+        self.internal_fault_history_capacity
+    }
+
+    pub(crate) fn runtime_monitor_capacity(&self) -> usize {
+        self.runtime_monitor_capacity
+    }
+
+    /// Write the budget into the `digest`.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_u64(self.violation_history_capacity as u64);
+        digest.write_u64(self.internal_fault_history_capacity as u64);
+        digest.write_u64(self.runtime_monitor_capacity as u64);
+        digest.write_option(self.memory_limit, |digest, memory_limit| {
+            digest.write_u64(memory_limit as u64)
+        });
+    }
+}
+
+impl Default for ResourceBudget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Capacities and memory usage of the bounded buffers of the [`crate::HealthMonitor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ScoreDebug)]
+pub struct CapacityReport {
+    /// Number of monitors evaluated by the health monitor, including slots reserved for runtime monitors.
+    pub monitors: usize,
+    /// Memory used by the monitor evaluation handles.
+    pub monitors_bytes: usize,
+    /// Number of violations kept in the history.
+    pub violation_history_capacity: usize,
+    /// Memory used by the violation history.
+    pub violation_history_bytes: usize,
+    /// Number of internal faults kept in the history.
+    pub internal_fault_history_capacity: usize,
+    /// Memory used by the internal fault history.
+    pub internal_fault_history_bytes: usize,
+    /// Memory reserved for failure counters of the monitors.
+    pub failure_statistics_bytes: usize,
+    /// Memory used by all buffers combined.
+    pub total_bytes: usize,
+    /// Granularity of the monotonic clock measured on this target, in nanoseconds.
+    pub clock_granularity_ns: u64,
+}
+
+impl CapacityReport {
+    fn new(monitors: usize, violation_history_capacity: usize, internal_fault_history_capacity: usize) -> Self {
+        let monitors_bytes = monitors * size_of::<MonitorEvalHandle>();
+        let violation_history_bytes = violation_history_capacity * size_of::<ViolationRecord>();
+        let internal_fault_history_bytes = internal_fault_history_capacity * size_of::<InternalFaultRecord>();
+        let failure_statistics_bytes = monitors * size_of::<(MonitorTag, MonitorStatistics)>();
+        Self {
+            monitors,
+            monitors_bytes,
+            violation_history_capacity,
+            violation_history_bytes,
+            internal_fault_history_capacity,
+            internal_fault_history_bytes,
+            failure_statistics_bytes,
+            total_bytes: monitors_bytes
+                + violation_history_bytes
+                + internal_fault_history_bytes
+                + failure_statistics_bytes,
+            clock_granularity_ns: clock_granularity().as_nanos() as u64,
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::MonitorEvalHandle;
+    use crate::resource_budget::ResourceBudget;
+    use crate::violation_history::{InternalFaultRecord, ViolationRecord};
+    use crate::HealthMonitorError;
+
+    #[test]
+    fn resource_budget_new_succeeds() {
+        let budget = ResourceBudget::new();
+        assert_eq!(
+            budget.violation_history_capacity(),
+            ResourceBudget::DEFAULT_VIOLATION_HISTORY_CAPACITY
+        );
+        assert_eq!(
+            budget.internal_fault_history_capacity(),
+            ResourceBudget::DEFAULT_INTERNAL_FAULT_HISTORY_CAPACITY
+        );
+        assert_eq!(budget.memory_limit, None);
+        assert_eq!(budget, ResourceBudget::default());
+    }
+
+    #[test]
+    fn resource_budget_validate_reports_total() {
+        let report = ResourceBudget::new()
+            .with_violation_history_capacity(8)
+            .with_internal_fault_history_capacity(4)
+            .validate(3)
+            .unwrap();
+        assert_eq!(report.monitors, 3);
+        assert_eq!(report.violation_history_capacity, 8);
+        assert_eq!(report.violation_history_bytes, 8 * size_of::<ViolationRecord>());
+        assert_eq!(report.internal_fault_history_capacity, 4);
+        assert_eq!(
+            report.internal_fault_history_bytes,
+            4 * size_of::<InternalFaultRecord>()
+        );
+        assert!(report.failure_statistics_bytes > 0);
+        assert_eq!(
+            report.total_bytes,
+            report.monitors_bytes
+                + report.violation_history_bytes
+                + report.internal_fault_history_bytes
+                + report.failure_statistics_bytes
+        );
+    }
+
+    #[test]
+    fn resource_budget_validate_reserves_runtime_monitors() {
+        let report = ResourceBudget::new()
+            .with_runtime_monitor_capacity(2)
+            .validate(3)
+            .unwrap();
+        assert_eq!(report.monitors, 5);
+        assert_eq!(report.monitors_bytes, 5 * size_of::<MonitorEvalHandle>());
+    }
+
+    #[test]
+    fn resource_budget_validate_too_large_history() {
+        let result = ResourceBudget::new()
+            .with_violation_history_capacity(ResourceBudget::MAX_VIOLATION_HISTORY_CAPACITY + 1)
+            .validate(1);
+        assert_eq!(result, Err(HealthMonitorError::InvalidArgument));
+
+        let result = ResourceBudget::new()
+            .with_internal_fault_history_capacity(ResourceBudget::MAX_INTERNAL_FAULT_HISTORY_CAPACITY + 1)
+            .validate(1);
+        assert_eq!(result, Err(HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn resource_budget_validate_memory_limit() {
+        let budget = ResourceBudget::new().with_violation_history_capacity(8);
+        let total_bytes = budget.validate(1).unwrap().total_bytes;
+
+        assert!(budget.with_memory_limit(total_bytes).validate(1).is_ok());
+        assert_eq!(
+            budget.with_memory_limit(total_bytes - 1).validate(1),
+            Err(HealthMonitorError::InvalidArgument)
+        );
+    }
+}
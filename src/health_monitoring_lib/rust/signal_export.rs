@@ -0,0 +1,219 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::log::{info, ScoreDebug};
+use crate::tag::MonitorTag;
+
+/// Value of a signal exported for a monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum SignalValue {
+    /// Monitor reported no errors since monitoring was started.
+    Healthy,
+    /// Monitor reported errors since monitoring was started.
+    Failed,
+}
+
+impl SignalValue {
+    /// String representation of the value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignalValue::Healthy => "Healthy",
+            SignalValue::Failed => "Failed",
+        }
+    }
+}
+
+/// Destination of exported signals, e.g. a client of a vehicle abstraction layer.
+pub trait SignalSink: Send {
+    /// Publish a new value of the signal.
+    /// Called from the monitoring thread only when the value changes, and for all signals when monitoring is started.
+    ///
+    /// - `signal_name` - name of the signal, e.g. `Vehicle.Private.Health.Planner`.
+    /// - `value` - new value of the signal.
+    fn publish(&mut self, signal_name: &str, value: SignalValue);
+}
+
+/// Reference [`SignalSink`] logging signals as key/value pairs.
+#[derive(Debug, Default)]
+pub struct LoggingSignalSink;
+
+impl SignalSink for LoggingSignalSink {
+    fn publish(&mut self, signal_name: &str, value: SignalValue) {
+        info!("Signal {} = {}", signal_name, value.as_str());
+    }
+}
+
+struct ExportedSignal {
+    monitor_tag: MonitorTag,
+    signal_name: String,
+    failing: bool,
+    /// Last published value, [`None`] if not published since monitoring was started.
+    published: Option<SignalValue>,
+}
+
+/// Export of selected monitor statuses as named signals through a [`SignalSink`].
+pub struct SignalExport {
+    sink: Box<dyn SignalSink>,
+    signals: Vec<ExportedSignal>,
+}
+
+impl SignalExport {
+    /// Create a new [`SignalExport`] publishing through the `sink`.
+    pub fn new(sink: impl SignalSink + 'static) -> Self {
+        Self {
+            sink: Box::new(sink),
+            signals: Vec::new(),
+        }
+    }
+
+    /// Export status of the monitor as a signal.
+    ///
+    /// - `monitor_tag` - tag of the exported monitor.
+    /// - `signal_name` - name of the signal, e.g. `Vehicle.Private.Health.Planner`.
+    ///
+    /// # Note
+    ///
+    /// If the monitor is already exported, its signal name is overwritten.
+    pub fn add_signal(mut self, monitor_tag: MonitorTag, signal_name: &str) -> Self {
+        self.add_signal_internal(monitor_tag, signal_name);
+        self
+    }
+
+    /// Tags of the exported monitors.
+    pub(crate) fn monitor_tags(&self) -> impl Iterator<Item = &MonitorTag> {
+        self.signals.iter().map(|signal| &signal.monitor_tag)
+    }
+
+    /// Forget published values, so all signals are published after the next evaluation pass.
+    pub(crate) fn reset(&mut self) {
+        for signal in self.signals.iter_mut() {
+            signal.failing = false;
+            signal.published = None;
+        }
+    }
+
+    /// Mark the monitor as failing until [`Self::reset`].
+    /// Monitors which are not exported are ignored.
+    pub(crate) fn mark_failing(&mut self, monitor_tag: &MonitorTag) {
+        if let Some(signal) = self
+            .signals
+            .iter_mut()
+            .find(|signal| signal.monitor_tag == *monitor_tag)
+        {
+            signal.failing = true;
+        }
+    }
+
+    /// Publish signals with changed values.
+    pub(crate) fn publish_changes(&mut self) {
+        for signal in self.signals.iter_mut() {
+            let value = if signal.failing {
+                SignalValue::Failed
+            } else {
+                SignalValue::Healthy
+            };
+            if signal.published != Some(value) {
+                self.sink.publish(&signal.signal_name, value);
+                signal.published = Some(value);
+            }
+        }
+    }
+
+    // Used by FFI and config parsing code which prefer not to move builder instance
+
+    pub(crate) fn add_signal_internal(&mut self, monitor_tag: MonitorTag, signal_name: &str) {
+        match self.signals.iter_mut().find(|signal| signal.monitor_tag == monitor_tag) {
+            Some(signal) => signal.signal_name = signal_name.to_string(),
+            None => self.signals.push(ExportedSignal {
+                monitor_tag,
+                signal_name: signal_name.to_string(),
+                failing: false,
+                published: None,
+            }),
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::signal_export::{SignalExport, SignalSink, SignalValue};
+    use crate::tag::MonitorTag;
+    use std::sync::{Arc, Mutex};
+
+    /// Sink recording published signals.
+    #[derive(Clone, Default)]
+    struct RecordingSink(Arc<Mutex<Vec<(String, SignalValue)>>>);
+
+    impl RecordingSink {
+        fn take(&self) -> Vec<(String, SignalValue)> {
+            core::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    impl SignalSink for RecordingSink {
+        fn publish(&mut self, signal_name: &str, value: SignalValue) {
+            self.0.lock().unwrap().push((signal_name.to_string(), value));
+        }
+    }
+
+    #[test]
+    fn signal_export_publishes_changes_only() {
+        let sink = RecordingSink::default();
+        let mut export = SignalExport::new(sink.clone())
+            .add_signal(MonitorTag::from("monitor_a"), "Health.A")
+            .add_signal(MonitorTag::from("monitor_b"), "Health.B");
+
+        // All signals are published initially.
+        export.publish_changes();
+        assert_eq!(
+            sink.take(),
+            vec![
+                ("Health.A".to_string(), SignalValue::Healthy),
+                ("Health.B".to_string(), SignalValue::Healthy)
+            ]
+        );
+        export.publish_changes();
+        assert!(sink.take().is_empty());
+
+        export.mark_failing(&MonitorTag::from("monitor_b"));
+        export.mark_failing(&MonitorTag::from("not_exported"));
+        export.publish_changes();
+        assert_eq!(sink.take(), vec![("Health.B".to_string(), SignalValue::Failed)]);
+        export.publish_changes();
+        assert!(sink.take().is_empty());
+    }
+
+    #[test]
+    fn signal_export_reset_publishes_all() {
+        let sink = RecordingSink::default();
+        let mut export = SignalExport::new(sink.clone()).add_signal(MonitorTag::from("monitor_a"), "Health.A");
+        export.mark_failing(&MonitorTag::from("monitor_a"));
+        export.publish_changes();
+        assert_eq!(sink.take(), vec![("Health.A".to_string(), SignalValue::Failed)]);
+
+        export.reset();
+        export.publish_changes();
+        assert_eq!(sink.take(), vec![("Health.A".to_string(), SignalValue::Healthy)]);
+    }
+
+    #[test]
+    fn signal_export_add_signal_overwrites_name() {
+        let sink = RecordingSink::default();
+        let mut export = SignalExport::new(sink.clone())
+            .add_signal(MonitorTag::from("monitor_a"), "Health.Old")
+            .add_signal(MonitorTag::from("monitor_a"), "Health.New");
+        export.publish_changes();
+        assert_eq!(sink.take(), vec![("Health.New".to_string(), SignalValue::Healthy)]);
+    }
+}
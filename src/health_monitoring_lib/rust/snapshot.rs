@@ -0,0 +1,354 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::TimeRange;
+use crate::config_summary::ConfigSummary;
+use crate::health_status::HealthStatus;
+use crate::tag::{DeadlineTag, MonitorTag};
+use crate::violation_history::ViolationRecord;
+use core::fmt::Write;
+use core::time::Duration;
+use std::path::PathBuf;
+
+/// Ownership state of a monitor in the [`crate::HealthMonitor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonitorOwnership {
+    /// Monitor was not taken by the application yet.
+    Available,
+    /// Monitor is taken by the application.
+    Taken,
+    /// Monitor was returned by the application, evaluation is suspended.
+    Returned,
+    /// Monitor is owned and evaluated by the health monitor only, e.g. artifact freshness monitor.
+    Internal,
+}
+
+impl MonitorOwnership {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MonitorOwnership::Available => "available",
+            MonitorOwnership::Taken => "taken",
+            MonitorOwnership::Returned => "returned",
+            MonitorOwnership::Internal => "internal",
+        }
+    }
+}
+
+/// Configuration of a monitor, variant determines the monitor type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MonitorConfig {
+    /// Deadline monitor with its deadlines, in order of evaluation.
+    Deadline { deadlines: Vec<(DeadlineTag, TimeRange)> },
+    /// Heartbeat monitor with the range between heartbeats.
+    Heartbeat { range: TimeRange },
+    /// Artifact freshness monitor with its artifacts and their maximum age, in order of declaration.
+    ArtifactFreshness { artifacts: Vec<(PathBuf, Duration)> },
+}
+
+impl MonitorConfig {
+    fn kind(&self) -> &'static str {
+        match self {
+            MonitorConfig::Deadline { .. } => "deadline",
+            MonitorConfig::Heartbeat { .. } => "heartbeat",
+            MonitorConfig::ArtifactFreshness { .. } => "artifact_freshness",
+        }
+    }
+}
+
+/// State of a single monitor at the time of the snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonitorSnapshot {
+    /// Tag of the monitor.
+    pub monitor_tag: MonitorTag,
+    /// Configuration of the monitor.
+    pub config: MonitorConfig,
+    /// Ownership state of the monitor.
+    pub ownership: MonitorOwnership,
+    /// Evaluation of the monitor is enabled.
+    pub enabled: bool,
+    /// Monitor reported an error since monitoring was started.
+    pub failing: bool,
+    /// Most recent violations of the monitor kept in the violation history, oldest first.
+    pub recent_violations: Vec<ViolationRecord>,
+}
+
+/// Live supervision state of a [`crate::HealthMonitor`], for diagnostics of a running process.
+/// Created with [`crate::HealthMonitor::snapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthMonitorSnapshot {
+    /// Current health status.
+    pub status: HealthStatus,
+    /// Summary of the configuration the health monitor was built with.
+    pub config_summary: ConfigSummary,
+    /// All monitors, including monitors added at runtime.
+    pub monitors: Vec<MonitorSnapshot>,
+}
+
+impl HealthMonitorSnapshot {
+    /// Serialize the snapshot as a single-line JSON document.
+    /// Durations are in milliseconds, unbounded range maximum is `null`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        // Writing into `String` cannot fail.
+        let _ = self.write_json(&mut out);
+        out
+    }
+
+    fn write_json(&self, out: &mut String) -> core::fmt::Result {
+        let status = match self.status {
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Degraded(_) => "degraded",
+            HealthStatus::Stopped => "stopped",
+        };
+        write!(
+            out,
+            "{{\"status\":\"{}\",\"config_digest\":{},\"monitors\":[",
+            status, self.config_summary.digest
+        )?;
+        for (index, monitor) in self.monitors.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            monitor.write_json(out)?;
+        }
+        out.push_str("]}");
+        Ok(())
+    }
+}
+
+impl MonitorSnapshot {
+    fn write_json(&self, out: &mut String) -> core::fmt::Result {
+        out.push_str("{\"tag\":");
+        write_json_str(out, self.monitor_tag.as_str());
+        write!(
+            out,
+            ",\"type\":\"{}\",\"ownership\":\"{}\",\"enabled\":{},\"failing\":{},",
+            self.config.kind(),
+            self.ownership.as_str(),
+            self.enabled,
+            self.failing
+        )?;
+        match &self.config {
+            MonitorConfig::Deadline { deadlines } => {
+                out.push_str("\"deadlines\":[");
+                for (index, (deadline_tag, range)) in deadlines.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    out.push_str("{\"tag\":");
+                    write_json_str(out, deadline_tag.as_str());
+                    out.push(',');
+                    write_json_range(out, range)?;
+                    out.push('}');
+                }
+                out.push(']');
+            },
+            MonitorConfig::Heartbeat { range } => {
+                out.push_str("\"range\":{");
+                write_json_range(out, range)?;
+                out.push('}');
+            },
+            MonitorConfig::ArtifactFreshness { artifacts } => {
+                out.push_str("\"artifacts\":[");
+                for (index, (path, max_age)) in artifacts.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    out.push_str("{\"path\":");
+                    write_json_str(out, &path.to_string_lossy());
+                    write!(out, ",\"max_age_ms\":{}}}", max_age.as_millis())?;
+                }
+                out.push(']');
+            },
+        }
+        out.push_str(",\"recent_violations\":[");
+        for (index, record) in self.recent_violations.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"error\":");
+            write_json_str(out, &format!("{:?}", record.error));
+            write!(out, ",\"timestamp_ms\":{}", record.timestamp.as_millis())?;
+            if let Some(deadline_tag) = record.deadline_tag {
+                out.push_str(",\"deadline\":");
+                write_json_str(out, deadline_tag.as_str());
+            }
+            if let Some(source_tag) = record.source_tag {
+                out.push_str(",\"source\":");
+                write_json_str(out, source_tag.as_str());
+            }
+            if let Some(offset) = record.offset {
+                write!(out, ",\"offset_ms\":{}", offset.as_millis())?;
+            }
+            if let Some(reporter) = record.reporter {
+                write!(out, ",\"reporter_tid\":{}", reporter.tid)?;
+            }
+            if let Some(correlation_id) = record.correlation_id {
+                write!(out, ",\"correlation_id\":{}", correlation_id)?;
+            }
+            if let Some(scheduling) = record.scheduling {
+                write!(
+                    out,
+                    ",\"run_time_ms\":{},\"run_delay_ms\":{},\"involuntary_switches\":{}",
+                    scheduling.run_time.as_millis(),
+                    scheduling.run_delay.as_millis(),
+                    scheduling.involuntary_switches
+                )?;
+            }
+            out.push('}');
+        }
+        out.push_str("]}");
+        Ok(())
+    }
+}
+
+/// Write range bounds as `"min_ms":..,"max_ms":..` members.
+fn write_json_range(out: &mut String, range: &TimeRange) -> core::fmt::Result {
+    write!(out, "\"min_ms\":{},\"max_ms\":", range.min.as_millis())?;
+    if range.has_upper_bound() {
+        write!(out, "{}", range.max.as_millis())
+    } else {
+        out.push_str("null");
+        Ok(())
+    }
+}
+
+/// Write `value` as a quoted JSON string.
+fn write_json_str(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::TimeRange;
+    use crate::config_summary::ConfigSummary;
+    use crate::deadline::{DeadlineEvaluationError, SchedulingStatistics};
+    use crate::health_status::HealthStatus;
+    use crate::snapshot::{write_json_str, HealthMonitorSnapshot, MonitorConfig, MonitorOwnership, MonitorSnapshot};
+    use crate::tag::{DeadlineTag, MonitorTag};
+    use crate::violation_history::ViolationRecord;
+    use core::time::Duration;
+    use std::path::PathBuf;
+
+    fn def_config_summary() -> ConfigSummary {
+        ConfigSummary {
+            deadline_monitors: 1,
+            deadlines: 1,
+            heartbeat_monitors: 1,
+            artifact_freshness_monitors: 1,
+            artifacts: 1,
+            thread_attribution_monitors: 0,
+            notification_channels: 0,
+            supervisor_api_cycle_ms: 500,
+            internal_processing_cycle_ms: 100,
+            violation_history_capacity: 16,
+            internal_fault_history_capacity: 16,
+            digest: 42,
+        }
+    }
+
+    #[test]
+    fn write_json_str_escapes() {
+        let mut out = String::new();
+        write_json_str(&mut out, "a\"b\\c\nd\u{1}");
+        assert_eq!(out, "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn health_monitor_snapshot_to_json() {
+        let snapshot = HealthMonitorSnapshot {
+            status: HealthStatus::Degraded(vec![MonitorTag::from("deadline_monitor")]),
+            config_summary: def_config_summary(),
+            monitors: vec![
+                MonitorSnapshot {
+                    monitor_tag: MonitorTag::from("deadline_monitor"),
+                    config: MonitorConfig::Deadline {
+                        deadlines: vec![(
+                            DeadlineTag::from("deadline"),
+                            TimeRange::new(Duration::from_millis(100), Duration::from_millis(200)),
+                        )],
+                    },
+                    ownership: MonitorOwnership::Taken,
+                    enabled: true,
+                    failing: true,
+                    recent_violations: vec![ViolationRecord {
+                        monitor_tag: MonitorTag::from("deadline_monitor"),
+                        error: DeadlineEvaluationError::TooLate.into(),
+                        timestamp: Duration::from_millis(1500),
+                        reporter: None,
+                        correlation_id: Some(7),
+                        scheduling: Some(SchedulingStatistics {
+                            run_time: Duration::from_millis(20),
+                            run_delay: Duration::from_millis(180),
+                            involuntary_switches: 5,
+                        }),
+                        deadline_tag: Some(DeadlineTag::from("deadline")),
+                        source_tag: None,
+                        offset: Some(Duration::from_millis(35)),
+                    }],
+                },
+                MonitorSnapshot {
+                    monitor_tag: MonitorTag::from("heartbeat_monitor"),
+                    config: MonitorConfig::Heartbeat {
+                        range: TimeRange::at_least(Duration::from_millis(50)),
+                    },
+                    ownership: MonitorOwnership::Available,
+                    enabled: false,
+                    failing: false,
+                    recent_violations: vec![],
+                },
+                MonitorSnapshot {
+                    monitor_tag: MonitorTag::from("artifact_monitor"),
+                    config: MonitorConfig::ArtifactFreshness {
+                        artifacts: vec![(PathBuf::from("/tmp/artifact"), Duration::from_secs(1))],
+                    },
+                    ownership: MonitorOwnership::Internal,
+                    enabled: true,
+                    failing: false,
+                    recent_violations: vec![],
+                },
+            ],
+        };
+
+        assert_eq!(
+            snapshot.to_json(),
+            concat!(
+                "{\"status\":\"degraded\",\"config_digest\":42,\"monitors\":[",
+                "{\"tag\":\"deadline_monitor\",\"type\":\"deadline\",\"ownership\":\"taken\",\"enabled\":true,",
+                "\"failing\":true,\"deadlines\":[{\"tag\":\"deadline\",\"min_ms\":100,\"max_ms\":200}],",
+                "\"recent_violations\":[{\"error\":\"Deadline(TooLate)\",\"timestamp_ms\":1500,\"deadline\":\"deadline\",",
+                "\"offset_ms\":35,\"correlation_id\":7,",
+                "\"run_time_ms\":20,\"run_delay_ms\":180,\"involuntary_switches\":5}]},",
+                "{\"tag\":\"heartbeat_monitor\",\"type\":\"heartbeat\",\"ownership\":\"available\",\"enabled\":false,",
+                "\"failing\":false,\"range\":{\"min_ms\":50,\"max_ms\":null},\"recent_violations\":[]},",
+                "{\"tag\":\"artifact_monitor\",\"type\":\"artifact_freshness\",\"ownership\":\"internal\",",
+                "\"enabled\":true,\"failing\":false,\"artifacts\":[{\"path\":\"/tmp/artifact\",\"max_age_ms\":1000}],",
+                "\"recent_violations\":[]}]}"
+            )
+        );
+    }
+}
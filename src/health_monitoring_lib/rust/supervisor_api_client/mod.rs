@@ -14,15 +14,121 @@
 //! Module providing [`SupervisorAPIClient`] implementations.
 //! Currently `ScoreSupervisorAPIClient` and `StubSupervisorAPIClient` are supported.
 //! The latter is meant for testing purposes.
+//! With `supervisor_api_client_selection` feature the client is selected on start with an environment variable,
+//! see `SelectedSupervisorAPIClient`.
+
+use crate::tag::ChannelTag;
+use std::sync::{Arc, Mutex, TryLockError};
 
 /// An abstraction over the API used to notify the supervisor about process liveness.
 pub trait SupervisorAPIClient {
-    fn notify_alive(&self);
+    /// Notify the supervisor about process liveness.
+    /// Returns `false` if the notification failed.
+    fn notify_alive(&self) -> bool;
+
+    /// Notify the supervisor about liveness of a single supervised entity hosted by the process.
+    /// Returns `false` if the notification failed.
+    ///
+    /// - `channel_tag` - notification channel identifying the supervised entity.
+    fn notify_channel_alive(&self, channel_tag: ChannelTag) -> bool;
+
+    /// Notify the supervisor the process is terminating after a failure, e.g. a panic,
+    /// so the failure is detected before alive notifications are missed.
+    /// Returns `false` if the notification failed or is not supported by the client.
+    fn notify_terminating(&self) -> bool {
+        false
+    }
+
+    /// Notify the supervisor the process is stopping in order, so missing alive notifications are expected.
+    /// Returns `false` if the notification failed or is not supported by the client.
+    fn notify_stopping(&self) -> bool {
+        false
+    }
+
+    /// Respond to a challenge of the supervisor with the result of the evaluation it triggered.
+    /// Returns `false` if the response failed or is not supported by the client.
+    ///
+    /// - `challenge_id` - identifier the challenge was issued with.
+    /// - `healthy` - no monitor failed in the evaluation.
+    fn respond_challenge(&self, _challenge_id: u64, _healthy: bool) -> bool {
+        false
+    }
+
+    /// Client is a stub, the supervisor is not notified.
+    fn is_stub(&self) -> bool {
+        false
+    }
+}
+
+/// Client shared by the monitoring logic and the panic hook.
+pub(crate) struct SharedSupervisorAPIClient<T> {
+    client: Arc<Mutex<T>>,
+}
+
+impl<T: SupervisorAPIClient> SharedSupervisorAPIClient<T> {
+    pub(crate) fn new(client: T) -> Self {
+        Self {
+            client: Arc::new(Mutex::new(client)),
+        }
+    }
+
+    /// Another handle to the same client.
+    pub(crate) fn clone_shared(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+        }
+    }
+
+    /// Run `f` on the client unless it is in use, e.g. by a thread which panicked while notifying.
+    /// Returns `false` if the client is in use.
+    pub(crate) fn try_with(&self, f: impl FnOnce(&T) -> bool) -> bool {
+        match self.client.try_lock() {
+            Ok(client) => f(&client),
+            Err(TryLockError::Poisoned(client)) => f(&client.into_inner()),
+            Err(TryLockError::WouldBlock) => false,
+        }
+    }
+
+    fn with(&self, f: impl FnOnce(&T) -> bool) -> bool {
+        f(&self.client.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+impl<T: SupervisorAPIClient> SupervisorAPIClient for SharedSupervisorAPIClient<T> {
+    fn notify_alive(&self) -> bool {
+        self.with(T::notify_alive)
+    }
+
+    fn notify_channel_alive(&self, channel_tag: ChannelTag) -> bool {
+        self.with(|client| client.notify_channel_alive(channel_tag))
+    }
+
+    fn notify_terminating(&self) -> bool {
+        self.with(T::notify_terminating)
+    }
+
+    fn notify_stopping(&self) -> bool {
+        self.with(T::notify_stopping)
+    }
+
+    fn respond_challenge(&self, challenge_id: u64, healthy: bool) -> bool {
+        self.with(|client| client.respond_challenge(challenge_id, healthy))
+    }
+
+    fn is_stub(&self) -> bool {
+        self.with(T::is_stub)
+    }
 }
 
 // NOTE: various implementations are not mutually exclusive.
 
 #[cfg(not(feature = "stub_supervisor_api_client"))]
 pub mod score_supervisor_api_client;
-#[cfg(feature = "stub_supervisor_api_client")]
+// Unit tests always use the stub client, see `create_supervisor_api_client`.
+#[cfg(all(
+    not(any(test, feature = "stub_supervisor_api_client")),
+    feature = "supervisor_api_client_selection"
+))]
+pub mod selected_supervisor_api_client;
+#[cfg(any(feature = "stub_supervisor_api_client", feature = "supervisor_api_client_selection"))]
 pub mod stub_supervisor_api_client;
@@ -15,26 +15,78 @@
 
 use crate::log::debug;
 use crate::supervisor_api_client::SupervisorAPIClient;
+use crate::tag::ChannelTag;
 use crate::worker::Checks;
+use std::collections::HashMap;
 
+/// Client notifying the launch manager daemon with checkpoints of the `IDENTIFIER` monitor interface.
+///
+/// Alive notifications report checkpoint ID 1, which has to be supervised by an alive supervision of the process.
+/// Termination is reported with checkpoint ID 2, which must not be configured for the process - the daemon fails
+/// the supervision of a process reporting a checkpoint without configured supervision.
 pub struct ScoreSupervisorAPIClient {
     supervisor_link: monitor_rs::Monitor<Checks>,
+    channel_links: HashMap<ChannelTag, monitor_rs::Monitor<Checks>>,
 }
 
 unsafe impl Send for ScoreSupervisorAPIClient {} // Just assuming it's safe to send across threads, this is a temporary solution
 
 impl ScoreSupervisorAPIClient {
-    pub fn new() -> Self {
-        let value = std::env::var("IDENTIFIER").expect("IDENTIFIER env not set");
+    /// Create a client notifying the process entity and an entity per notification channel.
+    /// Channel tag is used as the identifier of the supervised entity.
+    ///
+    /// - `identity` - identifier of the process entity, taken from `IDENTIFIER` env if not provided.
+    /// - `channel_tags` - notification channels.
+    pub fn new(identity: Option<&str>, channel_tags: &[ChannelTag]) -> Self {
+        let value = match identity {
+            Some(identity) => identity.to_string(),
+            None => std::env::var("IDENTIFIER").expect("IDENTIFIER env not set"),
+        };
         debug!("ScoreSupervisorAPIClient: Creating with IDENTIFIER={}", value);
         // This is only temporary usage so unwrap is fine here.
         let supervisor_link = monitor_rs::Monitor::<Checks>::new(&value).expect("Failed to create supervisor_link");
-        Self { supervisor_link }
+        let channel_links = channel_tags
+            .iter()
+            .map(|channel_tag| {
+                debug!("ScoreSupervisorAPIClient: Creating channel {:?}", channel_tag);
+                let link = monitor_rs::Monitor::<Checks>::new(channel_tag.as_str())
+                    .expect("Failed to create channel supervisor_link");
+                (*channel_tag, link)
+            })
+            .collect();
+        Self {
+            supervisor_link,
+            channel_links,
+        }
     }
 }
 
 impl SupervisorAPIClient for ScoreSupervisorAPIClient {
-    fn notify_alive(&self) {
+    fn notify_alive(&self) -> bool {
+        // Reporting a checkpoint does not indicate failures.
         self.supervisor_link.report_checkpoint(Checks::WorkerCheckpoint);
+        true
     }
+
+    fn notify_channel_alive(&self, channel_tag: ChannelTag) -> bool {
+        match self.channel_links.get(&channel_tag) {
+            Some(link) => {
+                link.report_checkpoint(Checks::WorkerCheckpoint);
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn notify_terminating(&self) -> bool {
+        // Checkpoint has no supervision configured, the daemon fails the supervision of the process on reception.
+        self.supervisor_link.report_checkpoint(Checks::Terminating);
+        true
+    }
+
+    // Stopping notifications are not supported - the daemon learns about an orderly stop from the process state
+    // reported by the launch manager, so the default implementation reports the notification as failed.
+
+    // Challenge responses are not supported - checkpoints carry no data and the daemon has no supervision
+    // to observe a response checkpoint with, so the default implementation reports the response as failed.
 }
@@ -0,0 +1,87 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::log::{info, warn};
+use crate::supervisor_api_client::score_supervisor_api_client::ScoreSupervisorAPIClient;
+use crate::supervisor_api_client::stub_supervisor_api_client::StubSupervisorAPIClient;
+use crate::supervisor_api_client::SupervisorAPIClient;
+use crate::tag::ChannelTag;
+
+/// Environment variable selecting the supervisor API client on start.
+/// `stub` selects `StubSupervisorAPIClient`, e.g. in CI containers without a supervisor.
+/// Any other value or unset variable selects `ScoreSupervisorAPIClient`.
+pub const SUPERVISOR_API_CLIENT_ENV: &str = "HM_SUPERVISOR_API_CLIENT";
+
+/// Supervisor API client selected on start, so the same binary runs with and without a supervisor.
+pub enum SelectedSupervisorAPIClient {
+    Score(ScoreSupervisorAPIClient),
+    Stub(StubSupervisorAPIClient),
+}
+
+impl SelectedSupervisorAPIClient {
+    /// Create the client selected with [`SUPERVISOR_API_CLIENT_ENV`].
+    /// Identity of the process entity is used by the score client only.
+    pub fn from_env(identity: Option<&str>, channel_tags: &[ChannelTag]) -> Self {
+        match std::env::var(SUPERVISOR_API_CLIENT_ENV).as_deref() {
+            Ok("stub") => {
+                warn!("SelectedSupervisorAPIClient: stub client selected, supervisor is not notified");
+                Self::Stub(StubSupervisorAPIClient::new())
+            },
+            _ => {
+                info!("SelectedSupervisorAPIClient: score client selected");
+                Self::Score(ScoreSupervisorAPIClient::new(identity, channel_tags))
+            },
+        }
+    }
+}
+
+impl SupervisorAPIClient for SelectedSupervisorAPIClient {
+    fn notify_alive(&self) -> bool {
+        match self {
+            Self::Score(client) => client.notify_alive(),
+            Self::Stub(client) => client.notify_alive(),
+        }
+    }
+
+    fn notify_channel_alive(&self, channel_tag: ChannelTag) -> bool {
+        match self {
+            Self::Score(client) => client.notify_channel_alive(channel_tag),
+            Self::Stub(client) => client.notify_channel_alive(channel_tag),
+        }
+    }
+
+    fn notify_terminating(&self) -> bool {
+        match self {
+            Self::Score(client) => client.notify_terminating(),
+            Self::Stub(client) => client.notify_terminating(),
+        }
+    }
+
+    fn notify_stopping(&self) -> bool {
+        match self {
+            Self::Score(client) => client.notify_stopping(),
+            Self::Stub(client) => client.notify_stopping(),
+        }
+    }
+
+    fn respond_challenge(&self, challenge_id: u64, healthy: bool) -> bool {
+        match self {
+            Self::Score(client) => client.respond_challenge(challenge_id, healthy),
+            Self::Stub(client) => client.respond_challenge(challenge_id, healthy),
+        }
+    }
+
+    fn is_stub(&self) -> bool {
+        matches!(self, Self::Stub(_))
+    }
+}
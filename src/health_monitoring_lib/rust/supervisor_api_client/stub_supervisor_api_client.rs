@@ -15,18 +15,84 @@
 
 use crate::log::warn;
 use crate::supervisor_api_client::SupervisorAPIClient;
+use crate::tag::ChannelTag;
+use std::fs::File;
+use std::io::Write;
+
+/// Environment variable with a path of the file alive notifications are appended to.
+/// Allows tests running the application in a separate process to observe its liveness.
+/// Process notifications are written as `alive`, channel notifications as `alive:<channel>`,
+/// orderly shutdown as `stopping` and challenge responses as `challenge:<id>:healthy` or `challenge:<id>:failed`.
+pub const ALIVE_FILE_ENV: &str = "HM_STUB_SUPERVISOR_ALIVE_FILE";
 
 /// A stub implementation of the SupervisorAPIClient that logs alive notifications.
-pub struct StubSupervisorAPIClient;
+pub struct StubSupervisorAPIClient {
+    alive_file: Option<File>,
+}
 
 impl StubSupervisorAPIClient {
     pub fn new() -> Self {
-        Self
+        let alive_file = std::env::var_os(ALIVE_FILE_ENV).and_then(|path| {
+            File::options()
+                .create(true)
+                .append(true)
+                .open(path)
+                .inspect_err(|_| warn!("StubSupervisorAPIClient: failed to open alive file"))
+                .ok()
+        });
+        Self { alive_file }
     }
 }
 
 impl SupervisorAPIClient for StubSupervisorAPIClient {
-    fn notify_alive(&self) {
+    fn notify_alive(&self) -> bool {
         warn!("StubSupervisorAPIClient: notify_alive called");
+        match self.alive_file.as_ref() {
+            Some(mut file) => file.write_all(b"alive\n").is_ok(),
+            None => true,
+        }
+    }
+
+    fn notify_channel_alive(&self, channel_tag: ChannelTag) -> bool {
+        warn!(
+            "StubSupervisorAPIClient: notify_channel_alive called for {:?}",
+            channel_tag
+        );
+        match self.alive_file.as_ref() {
+            Some(mut file) => writeln!(file, "alive:{}", channel_tag.as_str()).is_ok(),
+            None => true,
+        }
+    }
+
+    fn notify_terminating(&self) -> bool {
+        warn!("StubSupervisorAPIClient: notify_terminating called");
+        match self.alive_file.as_ref() {
+            Some(mut file) => file.write_all(b"terminating\n").is_ok(),
+            None => true,
+        }
+    }
+
+    fn notify_stopping(&self) -> bool {
+        warn!("StubSupervisorAPIClient: notify_stopping called");
+        match self.alive_file.as_ref() {
+            Some(mut file) => file.write_all(b"stopping\n").is_ok(),
+            None => true,
+        }
+    }
+
+    fn respond_challenge(&self, challenge_id: u64, healthy: bool) -> bool {
+        warn!(
+            "StubSupervisorAPIClient: respond_challenge called for {}, healthy: {}",
+            challenge_id, healthy
+        );
+        let result = if healthy { "healthy" } else { "failed" };
+        match self.alive_file.as_ref() {
+            Some(mut file) => writeln!(file, "challenge:{}:{}", challenge_id, result).is_ok(),
+            None => true,
+        }
+    }
+
+    fn is_stub(&self) -> bool {
+        true
     }
 }
@@ -30,6 +30,12 @@ impl Tag {
             length: value.len(),
         }
     }
+
+    fn as_str(&self) -> &str {
+        // SAFETY: the underlying data was created from a valid `&str`.
+        let bytes = unsafe { core::slice::from_raw_parts(self.data, self.length) };
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
 }
 
 unsafe impl Send for Tag {}
@@ -99,6 +105,11 @@ impl MonitorTag {
     pub const fn new(value: &str) -> Self {
         MonitorTag(Tag::new(value))
     }
+
+    /// Tag value as a string slice.
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
 }
 
 impl fmt::Debug for MonitorTag {
@@ -140,6 +151,11 @@ impl DeadlineTag {
     pub const fn new(value: &str) -> Self {
         DeadlineTag(Tag::new(value))
     }
+
+    /// Tag value as a string slice.
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
 }
 
 impl fmt::Debug for DeadlineTag {
@@ -172,10 +188,158 @@ impl From<&str> for DeadlineTag {
     }
 }
 
+/// Supervisor notification channel tag.
+/// Identifies the supervised entity notified through the channel.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct ChannelTag(Tag);
+
+impl ChannelTag {
+    pub const fn new(value: &str) -> Self {
+        ChannelTag(Tag::new(value))
+    }
+
+    /// Tag value as a string slice.
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl fmt::Debug for ChannelTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SAFETY: the underlying data was created from a valid `&str`.
+        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        write!(f, "ChannelTag({})", s)
+    }
+}
+
+impl log::ScoreDebug for ChannelTag {
+    fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
+        // SAFETY: the underlying data was created from a valid `&str`.
+        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        log::score_write!(f, "ChannelTag({})", s)
+    }
+}
+
+impl From<String> for ChannelTag {
+    fn from(value: String) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
+impl From<&str> for ChannelTag {
+    fn from(value: &str) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
+/// Monitor group tag.
+/// Violations of a group are reported under a [`MonitorTag`] with the same value.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct GroupTag(Tag);
+
+impl GroupTag {
+    pub const fn new(value: &str) -> Self {
+        GroupTag(Tag::new(value))
+    }
+
+    /// Tag value as a string slice.
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl fmt::Debug for GroupTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SAFETY: the underlying data was created from a valid `&str`.
+        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        write!(f, "GroupTag({})", s)
+    }
+}
+
+impl log::ScoreDebug for GroupTag {
+    fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
+        // SAFETY: the underlying data was created from a valid `&str`.
+        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        log::score_write!(f, "GroupTag({})", s)
+    }
+}
+
+impl From<String> for GroupTag {
+    fn from(value: String) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
+impl From<&str> for GroupTag {
+    fn from(value: &str) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
+impl From<GroupTag> for MonitorTag {
+    fn from(value: GroupTag) -> Self {
+        // Underlying data is shared, it is static or leaked.
+        Self(value.0)
+    }
+}
+
+/// Heartbeat source tag.
+/// Identifies a source providing heartbeats to a heartbeat monitor, e.g. a worker thread of a pool.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct SourceTag(Tag);
+
+impl SourceTag {
+    pub const fn new(value: &str) -> Self {
+        SourceTag(Tag::new(value))
+    }
+
+    /// Tag value as a string slice.
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl fmt::Debug for SourceTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SAFETY: the underlying data was created from a valid `&str`.
+        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        write!(f, "SourceTag({})", s)
+    }
+}
+
+impl log::ScoreDebug for SourceTag {
+    fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
+        // SAFETY: the underlying data was created from a valid `&str`.
+        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        log::score_write!(f, "SourceTag({})", s)
+    }
+}
+
+impl From<String> for SourceTag {
+    fn from(value: String) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
+impl From<&str> for SourceTag {
+    fn from(value: &str) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
 #[cfg(all(test, not(loom)))]
 mod tests {
     use crate::log::score_write;
-    use crate::tag::{DeadlineTag, MonitorTag, Tag};
+    use crate::tag::{ChannelTag, DeadlineTag, GroupTag, MonitorTag, SourceTag, Tag};
     use core::fmt::Write;
     use core::hash::{Hash, Hasher};
     use score_log::fmt::{Error, FormatSpec, Result as FmtResult, ScoreWrite};
@@ -413,4 +577,107 @@ mod tests {
         let tag = DeadlineTag::from(example_str);
         compare_tag(tag.0, example_str);
     }
+
+    #[test]
+    fn channel_tag_new() {
+        const EXAMPLE_STR: &str = "EXAMPLE";
+        const TAG: ChannelTag = ChannelTag::new(EXAMPLE_STR);
+        compare_tag(TAG.0, EXAMPLE_STR);
+    }
+
+    #[test]
+    fn channel_tag_debug() {
+        let example_str = "EXAMPLE";
+        let tag = ChannelTag::from(example_str.to_string());
+        assert_eq!(format!("{:?}", tag), "ChannelTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn channel_tag_score_debug() {
+        let example_str = "EXAMPLE";
+        let tag = ChannelTag::from(example_str.to_string());
+        let mut writer = StringWriter::new();
+        assert!(score_write!(&mut writer, "{:?}", tag).is_ok());
+        assert_eq!(writer.get(), "ChannelTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn channel_tag_from_string() {
+        let example_str = "EXAMPLE";
+        let tag = ChannelTag::from(example_str.to_string());
+        compare_tag(tag.0, example_str);
+    }
+
+    #[test]
+    fn channel_tag_from_str() {
+        let example_str = "EXAMPLE";
+        let tag = ChannelTag::from(example_str);
+        compare_tag(tag.0, example_str);
+    }
+
+    #[test]
+    fn group_tag_new() {
+        const EXAMPLE_STR: &str = "EXAMPLE";
+        const TAG: GroupTag = GroupTag::new(EXAMPLE_STR);
+        compare_tag(TAG.0, EXAMPLE_STR);
+    }
+
+    #[test]
+    fn group_tag_debug() {
+        let example_str = "EXAMPLE";
+        let tag = GroupTag::from(example_str.to_string());
+        assert_eq!(format!("{:?}", tag), "GroupTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn group_tag_score_debug() {
+        let example_str = "EXAMPLE";
+        let tag = GroupTag::from(example_str.to_string());
+        let mut writer = StringWriter::new();
+        assert!(score_write!(&mut writer, "{:?}", tag).is_ok());
+        assert_eq!(writer.get(), "GroupTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn group_tag_from_str() {
+        let example_str = "EXAMPLE";
+        let tag = GroupTag::from(example_str);
+        compare_tag(tag.0, example_str);
+    }
+
+    #[test]
+    fn group_tag_into_monitor_tag() {
+        let tag = GroupTag::from("EXAMPLE");
+        assert_eq!(MonitorTag::from(tag), MonitorTag::new("EXAMPLE"));
+    }
+
+    #[test]
+    fn source_tag_new() {
+        const EXAMPLE_STR: &str = "EXAMPLE";
+        const TAG: SourceTag = SourceTag::new(EXAMPLE_STR);
+        compare_tag(TAG.0, EXAMPLE_STR);
+    }
+
+    #[test]
+    fn source_tag_debug() {
+        let example_str = "EXAMPLE";
+        let tag = SourceTag::from(example_str.to_string());
+        assert_eq!(format!("{:?}", tag), "SourceTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn source_tag_score_debug() {
+        let example_str = "EXAMPLE";
+        let tag = SourceTag::from(example_str.to_string());
+        let mut writer = StringWriter::new();
+        assert!(score_write!(&mut writer, "{:?}", tag).is_ok());
+        assert_eq!(writer.get(), "SourceTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn source_tag_from_str() {
+        let example_str = "EXAMPLE";
+        let tag = SourceTag::from(example_str);
+        compare_tag(tag.0, example_str);
+    }
 }
@@ -0,0 +1,235 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+/// Maximum stored length of a thread name, matches Linux `TASK_COMM_LEN` without terminator.
+const THREAD_NAME_CAPACITY: usize = 15;
+
+/// Number of threads remembered by the registry, oldest entries are overwritten when full.
+const REGISTRY_CAPACITY: usize = 256;
+
+/// TID value used by [`ReporterSlot`] when no thread reported yet.
+const NO_REPORTER: u32 = 0;
+
+/// OS thread which reported to a monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReporterThread {
+    /// OS thread ID.
+    pub tid: u32,
+    name: [u8; THREAD_NAME_CAPACITY],
+    name_len: u8,
+}
+
+impl ReporterThread {
+    /// Create [`ReporterThread`], `name` is truncated to [`THREAD_NAME_CAPACITY`] bytes.
+    fn new(tid: u32, name: Option<&str>) -> Self {
+        let mut buffer = [0; THREAD_NAME_CAPACITY];
+        let mut name_len = 0;
+        if let Some(name) = name {
+            // Truncate on char boundary to keep the name valid UTF-8.
+            let mut end = name.len().min(THREAD_NAME_CAPACITY);
+            while !name.is_char_boundary(end) {
+                end -= 1;
+            }
+            buffer[..end].copy_from_slice(&name.as_bytes()[..end]);
+            name_len = end as u8;
+        }
+
+        Self {
+            tid,
+            name: buffer,
+            name_len,
+        }
+    }
+
+    /// Name of the thread, [`None`] if unnamed or not captured.
+    pub fn name(&self) -> Option<&str> {
+        if self.name_len == 0 {
+            return None;
+        }
+        core::str::from_utf8(&self.name[..self.name_len as usize]).ok()
+    }
+}
+
+/// Threads captured so far, used to resolve TIDs stored by monitors.
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry::new());
+
+struct Registry {
+    threads: Vec<ReporterThread>,
+    next: usize,
+}
+
+impl Registry {
+    const fn new() -> Self {
+        Self {
+            threads: Vec::new(),
+            next: 0,
+        }
+    }
+
+    fn register(&mut self, thread: ReporterThread) {
+        if self.threads.len() < REGISTRY_CAPACITY {
+            self.threads.push(thread);
+        } else {
+            self.threads[self.next] = thread;
+        }
+        self.next = (self.next + 1) % REGISTRY_CAPACITY;
+    }
+
+    fn lookup(&self, tid: u32) -> Option<ReporterThread> {
+        // TIDs are reused by the OS, prefer the most recently registered entry.
+        let (oldest, newest) = self.threads.split_at(self.next.min(self.threads.len()));
+        newest
+            .iter()
+            .rev()
+            .chain(oldest.iter().rev())
+            .find(|thread| thread.tid == tid)
+            .copied()
+    }
+}
+
+thread_local! {
+    /// TID of the current thread, captured once on first report.
+    static CURRENT_TID: Option<u32> = capture_current();
+}
+
+/// Capture current thread and add it to the registry.
+fn capture_current() -> Option<u32> {
+    let thread = current_os_thread()?;
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .register(thread);
+    Some(thread.tid)
+}
+
+#[cfg(target_os = "linux")]
+fn current_os_thread() -> Option<ReporterThread> {
+    // Link target has `<pid>/task/<tid>` format.
+    let link = std::fs::read_link("/proc/thread-self").ok()?;
+    let tid = link.file_name()?.to_str()?.parse().ok()?;
+    let name = std::fs::read_to_string("/proc/thread-self/comm").ok();
+    Some(ReporterThread::new(tid, name.as_deref().map(str::trim_end)))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn current_os_thread() -> Option<ReporterThread> {
+    // Attribution is not supported on this platform.
+    None
+}
+
+/// TID of the current thread, [`None`] if not supported on this platform.
+pub(crate) fn current_tid() -> Option<u32> {
+    CURRENT_TID.with(|tid| *tid)
+}
+
+/// Last thread which reported to a monitored object.
+/// Recording is lock-free, thread details are captured once per thread.
+pub(crate) struct ReporterSlot(AtomicU32);
+
+impl ReporterSlot {
+    pub(crate) fn new() -> Self {
+        Self(AtomicU32::new(NO_REPORTER))
+    }
+
+    /// Record current thread as the last reporter.
+    pub(crate) fn record(&self) {
+        if let Some(tid) = CURRENT_TID.with(|tid| *tid) {
+            self.0.store(tid, Ordering::Relaxed);
+        }
+    }
+
+    /// Get the last reporter, [`None`] if nothing was recorded.
+    pub(crate) fn load(&self) -> Option<ReporterThread> {
+        let tid = self.0.load(Ordering::Relaxed);
+        if tid == NO_REPORTER {
+            return None;
+        }
+
+        let registry = REGISTRY.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        Some(registry.lookup(tid).unwrap_or(ReporterThread::new(tid, None)))
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::thread_attribution::{Registry, ReporterSlot, ReporterThread, REGISTRY_CAPACITY};
+    use std::thread;
+
+    #[test]
+    fn reporter_thread_name_truncated() {
+        let thread = ReporterThread::new(1, Some("a_very_long_thread_name"));
+        assert_eq!(thread.name(), Some("a_very_long_thr"));
+    }
+
+    #[test]
+    fn reporter_thread_name_truncated_on_char_boundary() {
+        let thread = ReporterThread::new(1, Some("thread_name_żółw"));
+        assert_eq!(thread.name(), Some("thread_name_ż"));
+    }
+
+    #[test]
+    fn reporter_thread_unnamed() {
+        let thread = ReporterThread::new(1, None);
+        assert_eq!(thread.name(), None);
+        let thread = ReporterThread::new(1, Some(""));
+        assert_eq!(thread.name(), None);
+    }
+
+    #[test]
+    fn registry_prefers_newest_entry() {
+        let mut registry = Registry::new();
+        registry.register(ReporterThread::new(7, Some("old")));
+        registry.register(ReporterThread::new(8, Some("other")));
+        registry.register(ReporterThread::new(7, Some("new")));
+        assert_eq!(registry.lookup(7).unwrap().name(), Some("new"));
+        assert_eq!(registry.lookup(8).unwrap().name(), Some("other"));
+        assert!(registry.lookup(9).is_none());
+    }
+
+    #[test]
+    fn registry_overwrites_oldest_when_full() {
+        let mut registry = Registry::new();
+        for tid in 1..=(REGISTRY_CAPACITY as u32 + 1) {
+            registry.register(ReporterThread::new(tid, None));
+        }
+        assert!(registry.lookup(1).is_none());
+        assert!(registry.lookup(2).is_some());
+        assert!(registry.lookup(REGISTRY_CAPACITY as u32 + 1).is_some());
+    }
+
+    #[test]
+    fn reporter_slot_empty() {
+        let slot = ReporterSlot::new();
+        assert!(slot.load().is_none());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn reporter_slot_records_thread_name() {
+        let slot = ReporterSlot::new();
+        thread::scope(|scope| {
+            thread::Builder::new()
+                .name("hm_reporter".to_string())
+                .spawn_scoped(scope, || slot.record())
+                .unwrap();
+        });
+
+        let reporter = slot.load().expect("reporter not recorded");
+        assert_ne!(reporter.tid, 0);
+        assert_eq!(reporter.name(), Some("hm_reporter"));
+    }
+}
@@ -0,0 +1,351 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::config_summary::ConfigDigest;
+use crate::log::{error, info, warn, ScoreDebug};
+use crate::tag::MonitorTag;
+use std::collections::HashMap;
+
+/// Maximum number of evaluation cycles a [`FailureTolerance`] can span.
+pub const MAX_TOLERANCE_CYCLES: u32 = u64::BITS;
+
+/// Number of monitor violations tolerated before the process is treated as failed.
+///
+/// Violations are counted per evaluation cycle - a monitor reporting multiple errors in a single cycle counts once.
+/// Tolerated violations are still logged, stored in the history and passed to the failure callback,
+/// only supervisor notifications continue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum FailureTolerance {
+    /// Process fails after the given number of consecutive cycles with violations.
+    /// `Consecutive(1)` is equal to no tolerance.
+    Consecutive(u32),
+    /// Process fails after `violations` cycles with violations within the last `cycles` cycles.
+    WithinCycles { violations: u32, cycles: u32 },
+}
+
+impl FailureTolerance {
+    /// Tolerance is within supported limits.
+    pub(crate) fn is_valid(&self) -> bool {
+        match *self {
+            FailureTolerance::Consecutive(count) => (1..=MAX_TOLERANCE_CYCLES).contains(&count),
+            FailureTolerance::WithinCycles { violations, cycles } => {
+                violations >= 1 && violations <= cycles && cycles <= MAX_TOLERANCE_CYCLES
+            },
+        }
+    }
+
+    /// Tolerance is exceeded by the `window` of cycles, most recent cycle in the lowest bit.
+    fn is_exceeded(&self, window: u64) -> bool {
+        match *self {
+            FailureTolerance::Consecutive(count) => window.trailing_ones() >= count,
+            FailureTolerance::WithinCycles { violations, cycles } => {
+                (window & low_bits(cycles)).count_ones() >= violations
+            },
+        }
+    }
+
+    /// Write the tolerance into the `digest`.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        match *self {
+            FailureTolerance::Consecutive(count) => {
+                digest.write_u64(0);
+                digest.write_u64(u64::from(count));
+            },
+            FailureTolerance::WithinCycles { violations, cycles } => {
+                digest.write_u64(1);
+                digest.write_u64(u64::from(violations));
+                digest.write_u64(u64::from(cycles));
+            },
+        }
+    }
+}
+
+/// What happens after the tolerance of a monitor is exceeded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum RecoveryPolicy {
+    /// Monitoring stops, supervisor is never notified again.
+    #[default]
+    Never,
+    /// Notifications are suspended until the failed monitor evaluates clean for the given number of consecutive cycles.
+    ResumeAfter(u32),
+}
+
+impl RecoveryPolicy {
+    /// Policy is within supported limits.
+    pub(crate) fn is_valid(&self) -> bool {
+        !matches!(self, RecoveryPolicy::ResumeAfter(0))
+    }
+
+    /// Write the policy into the `digest`.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        match *self {
+            RecoveryPolicy::Never => digest.write_u64(0),
+            RecoveryPolicy::ResumeAfter(clean_cycles) => {
+                digest.write_u64(1);
+                digest.write_u64(u64::from(clean_cycles));
+            },
+        }
+    }
+}
+
+/// Mask with `count` lowest bits set.
+fn low_bits(count: u32) -> u64 {
+    u64::MAX.checked_shr(u64::BITS - count).unwrap_or(0)
+}
+
+/// Tracks cycles with violations of each monitor against its failure tolerance.
+pub(crate) struct ToleranceTracker {
+    /// Tolerance of monitors without own tolerance, no violation is tolerated if [`None`].
+    default_tolerance: Option<FailureTolerance>,
+    monitor_tolerances: HashMap<MonitorTag, FailureTolerance>,
+    /// Recent cycles of monitors with violations, most recent cycle in the lowest bit.
+    /// Monitors without violations in the tracked cycles are removed.
+    windows: HashMap<MonitorTag, u64>,
+}
+
+impl ToleranceTracker {
+    pub(crate) fn new(
+        default_tolerance: Option<FailureTolerance>,
+        monitor_tolerances: HashMap<MonitorTag, FailureTolerance>,
+    ) -> Self {
+        Self {
+            default_tolerance,
+            monitor_tolerances,
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Start a new evaluation cycle.
+    pub(crate) fn start_cycle(&mut self) {
+        self.windows.retain(|_, window| {
+            *window <<= 1;
+            *window != 0
+        });
+    }
+
+    /// Record violation of the monitor in the current cycle.
+    /// Returns `true` if the tolerance of the monitor is exceeded and the process is treated as failed.
+    pub(crate) fn record_violation(&mut self, monitor_tag: MonitorTag) -> bool {
+        let Some(tolerance) = self
+            .monitor_tolerances
+            .get(&monitor_tag)
+            .or(self.default_tolerance.as_ref())
+        else {
+            return true;
+        };
+
+        let window = self.windows.entry(monitor_tag).or_insert(0);
+        *window |= 1;
+        if tolerance.is_exceeded(*window) {
+            error!(
+                "Monitor {:?} exceeded its failure tolerance {:?}.",
+                monitor_tag, tolerance
+            );
+            true
+        } else {
+            warn!("Violation of monitor {:?} tolerated.", monitor_tag);
+            false
+        }
+    }
+}
+
+/// Tracks clean cycles of failed monitors against the recovery policy.
+pub(crate) struct RecoveryTracker {
+    policy: RecoveryPolicy,
+    /// Failed monitors with number of consecutive clean cycles since their last failure.
+    failed: HashMap<MonitorTag, u32>,
+}
+
+impl RecoveryTracker {
+    pub(crate) fn new(policy: RecoveryPolicy) -> Self {
+        Self {
+            policy,
+            failed: HashMap::new(),
+        }
+    }
+
+    /// Failed monitors can recover.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.policy != RecoveryPolicy::Never
+    }
+
+    /// Start a new evaluation cycle, counted as clean unless a failure is recorded.
+    pub(crate) fn start_cycle(&mut self) {
+        for clean_cycles in self.failed.values_mut() {
+            *clean_cycles = clean_cycles.saturating_add(1);
+        }
+    }
+
+    /// Record failure of the monitor in the current cycle.
+    pub(crate) fn record_failure(&mut self, monitor_tag: MonitorTag) {
+        self.failed.insert(monitor_tag, 0);
+    }
+
+    /// Finish the evaluation cycle, releasing monitors which were clean for long enough.
+    pub(crate) fn end_cycle(&mut self) {
+        let RecoveryPolicy::ResumeAfter(required) = self.policy else {
+            return;
+        };
+        self.failed.retain(|monitor_tag, clean_cycles| {
+            if *clean_cycles < required {
+                return true;
+            }
+            info!(
+                "Monitor {:?} recovered after {} clean cycles.",
+                monitor_tag, *clean_cycles
+            );
+            false
+        });
+    }
+
+    /// Monitor failed and did not recover yet.
+    pub(crate) fn is_failed(&self, monitor_tag: &MonitorTag) -> bool {
+        self.failed.contains_key(monitor_tag)
+    }
+
+    /// Monitors failed and not yet recovered.
+    pub(crate) fn failed_monitors(&self) -> impl Iterator<Item = &MonitorTag> {
+        self.failed.keys()
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::tag::MonitorTag;
+    use crate::tolerance::{FailureTolerance, RecoveryPolicy, RecoveryTracker, ToleranceTracker, MAX_TOLERANCE_CYCLES};
+    use std::collections::HashMap;
+
+    /// Run `cycles`, `true` for cycles with violation, and collect results of recorded violations.
+    fn run_cycles(tracker: &mut ToleranceTracker, monitor_tag: MonitorTag, cycles: &[bool]) -> Vec<bool> {
+        let mut results = vec![];
+        for violated in cycles {
+            tracker.start_cycle();
+            if *violated {
+                results.push(tracker.record_violation(monitor_tag));
+            }
+        }
+        results
+    }
+
+    #[test]
+    fn failure_tolerance_validity() {
+        assert!(FailureTolerance::Consecutive(1).is_valid());
+        assert!(FailureTolerance::Consecutive(MAX_TOLERANCE_CYCLES).is_valid());
+        assert!(!FailureTolerance::Consecutive(0).is_valid());
+        assert!(!FailureTolerance::Consecutive(MAX_TOLERANCE_CYCLES + 1).is_valid());
+        assert!(FailureTolerance::WithinCycles {
+            violations: 3,
+            cycles: MAX_TOLERANCE_CYCLES
+        }
+        .is_valid());
+        assert!(!FailureTolerance::WithinCycles {
+            violations: 0,
+            cycles: 10
+        }
+        .is_valid());
+        assert!(!FailureTolerance::WithinCycles {
+            violations: 11,
+            cycles: 10
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn tolerance_tracker_without_tolerance_fails_immediately() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let mut tracker = ToleranceTracker::new(None, HashMap::new());
+        assert_eq!(run_cycles(&mut tracker, monitor_tag, &[true]), vec![true]);
+    }
+
+    #[test]
+    fn tolerance_tracker_consecutive() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let mut tracker = ToleranceTracker::new(Some(FailureTolerance::Consecutive(3)), HashMap::new());
+        assert_eq!(
+            run_cycles(&mut tracker, monitor_tag, &[true, true, false, true, true, true]),
+            vec![false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn tolerance_tracker_within_cycles() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let tolerance = FailureTolerance::WithinCycles {
+            violations: 2,
+            cycles: 3,
+        };
+        let mut tracker = ToleranceTracker::new(Some(tolerance), HashMap::new());
+        assert_eq!(
+            run_cycles(&mut tracker, monitor_tag, &[true, false, false, true, false, true]),
+            vec![false, false, true]
+        );
+    }
+
+    #[test]
+    fn tolerance_tracker_monitor_tolerance_overrides_default() {
+        let tolerant_tag = MonitorTag::from("tolerant");
+        let strict_tag = MonitorTag::from("strict");
+        let mut tracker =
+            ToleranceTracker::new(None, HashMap::from([(tolerant_tag, FailureTolerance::Consecutive(2))]));
+        tracker.start_cycle();
+        assert!(!tracker.record_violation(tolerant_tag));
+        assert!(tracker.record_violation(strict_tag));
+    }
+
+    #[test]
+    fn recovery_policy_validity() {
+        assert!(RecoveryPolicy::Never.is_valid());
+        assert!(RecoveryPolicy::ResumeAfter(1).is_valid());
+        assert!(!RecoveryPolicy::ResumeAfter(0).is_valid());
+    }
+
+    #[test]
+    fn recovery_tracker_releases_after_clean_cycles() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let mut tracker = RecoveryTracker::new(RecoveryPolicy::ResumeAfter(2));
+        assert!(tracker.is_enabled());
+
+        tracker.start_cycle();
+        tracker.record_failure(monitor_tag);
+        tracker.end_cycle();
+        assert_eq!(tracker.failed_monitors().count(), 1);
+
+        // Failure restarts counting of clean cycles.
+        tracker.start_cycle();
+        tracker.end_cycle();
+        tracker.start_cycle();
+        tracker.record_failure(monitor_tag);
+        tracker.end_cycle();
+        tracker.start_cycle();
+        tracker.end_cycle();
+        assert_eq!(tracker.failed_monitors().count(), 1);
+
+        tracker.start_cycle();
+        tracker.end_cycle();
+        assert_eq!(tracker.failed_monitors().count(), 0);
+    }
+
+    #[test]
+    fn recovery_tracker_never_releases() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let mut tracker = RecoveryTracker::new(RecoveryPolicy::Never);
+        assert!(!tracker.is_enabled());
+
+        tracker.record_failure(monitor_tag);
+        for _ in 0..10 {
+            tracker.start_cycle();
+            tracker.end_cycle();
+        }
+        assert_eq!(tracker.failed_monitors().count(), 1);
+    }
+}
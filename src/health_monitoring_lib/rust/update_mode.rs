@@ -0,0 +1,311 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::{MonitorEvaluationError, MonitorEvaluator, ViolationContext};
+use crate::config_summary::ConfigDigest;
+use crate::deadline::DeadlineEvaluationError;
+use crate::log::{error, info, warn};
+use crate::tag::MonitorTag;
+use crate::HealthMonitorError;
+use core::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use core::time::Duration;
+use std::time::Instant;
+
+/// Software update mode, entered with [`crate::HealthMonitor::enter_update_mode`].
+///
+/// While update mode is active, violations of advisory monitors are only logged and stored in the history.
+/// Whole update window is supervised instead, exceeding it is reported as a deadline violation
+/// under the guard tag and ends the update mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMode {
+    guard_tag: MonitorTag,
+    window: Duration,
+    advisory_monitors: Vec<MonitorTag>,
+}
+
+impl UpdateMode {
+    /// Create update mode without advisory monitors.
+    ///
+    /// - `guard_tag` - tag under which exceeding of the update window is reported, must not be used by a monitor.
+    /// - `window` - longest allowed duration of the update mode.
+    pub fn new(guard_tag: MonitorTag, window: Duration) -> Self {
+        Self {
+            guard_tag,
+            window,
+            advisory_monitors: Vec::new(),
+        }
+    }
+
+    /// Make violations of `monitor_tags` advisory while update mode is active, monitors already added are skipped.
+    pub fn with_advisory_monitors(mut self, monitor_tags: &[MonitorTag]) -> Self {
+        for monitor_tag in monitor_tags {
+            if !self.advisory_monitors.contains(monitor_tag) {
+                self.advisory_monitors.push(*monitor_tag);
+            }
+        }
+        self
+    }
+
+    pub(crate) fn guard_tag(&self) -> MonitorTag {
+        self.guard_tag
+    }
+
+    pub(crate) fn window(&self) -> Duration {
+        self.window
+    }
+
+    pub(crate) fn advisory_monitors(&self) -> &[MonitorTag] {
+        &self.advisory_monitors
+    }
+
+    /// Write the update mode into the `digest`, advisory monitors in order of their tags.
+    pub(crate) fn write_digest(&self, digest: &mut ConfigDigest) {
+        digest.write_str(self.guard_tag.as_str());
+        digest.write_duration(self.window);
+        let mut advisory_monitors: Vec<_> = self.advisory_monitors.iter().map(MonitorTag::as_str).collect();
+        advisory_monitors.sort_unstable();
+        digest.write_u64(advisory_monitors.len() as u64);
+        for monitor_tag in advisory_monitors {
+            digest.write_str(monitor_tag);
+        }
+    }
+}
+
+/// Update mode is not active.
+const INACTIVE: u8 = 0;
+/// Update mode is being entered, token and entry time are not published yet.
+const ENTERING: u8 = 1;
+/// Update mode is active.
+const ACTIVE: u8 = 2;
+
+/// Update mode state shared by the health monitor and the monitoring logic.
+/// Evaluated as a monitor supervising the update window, entering and exiting does not block evaluation.
+pub(crate) struct UpdateModeGuard {
+    config: UpdateMode,
+    /// Reference point of `entered_at_ms`.
+    reference: Instant,
+    state: AtomicU8,
+    token: AtomicU64,
+    entered_at_ms: AtomicU64,
+}
+
+impl UpdateModeGuard {
+    pub(crate) fn new(config: UpdateMode) -> Self {
+        Self {
+            config,
+            reference: Instant::now(),
+            state: AtomicU8::new(INACTIVE),
+            token: AtomicU64::new(0),
+            entered_at_ms: AtomicU64::new(0),
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        self.reference.elapsed().as_millis() as u64
+    }
+
+    /// Enter update mode identified by `token`.
+    /// Returns [`HealthMonitorError::WrongState`] if update mode is already active.
+    pub(crate) fn enter(&self, token: u64) -> Result<(), HealthMonitorError> {
+        if self
+            .state
+            .compare_exchange(INACTIVE, ENTERING, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            error!(
+                "Update mode {} cannot be entered, update mode is already active.",
+                token
+            );
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        self.token.store(token, Ordering::Relaxed);
+        self.entered_at_ms.store(self.now_ms(), Ordering::Relaxed);
+        self.state.store(ACTIVE, Ordering::Release);
+        info!(
+            "Update mode {} entered, {} advisory monitors, window {} ms.",
+            token,
+            self.config.advisory_monitors.len(),
+            self.config.window.as_millis() as u64
+        );
+        Ok(())
+    }
+
+    /// Exit update mode identified by `token`, returns time spent in update mode.
+    /// Returns [`HealthMonitorError::WrongState`] if update mode is not active
+    /// and [`HealthMonitorError::InvalidArgument`] if it was entered with another token.
+    pub(crate) fn exit(&self, token: u64) -> Result<Duration, HealthMonitorError> {
+        if self.state.load(Ordering::Acquire) != ACTIVE {
+            error!("Update mode {} cannot be exited, update mode is not active.", token);
+            return Err(HealthMonitorError::WrongState);
+        }
+        let active_token = self.token.load(Ordering::Relaxed);
+        if active_token != token {
+            error!(
+                "Update mode {} cannot be exited, update mode {} is active.",
+                token, active_token
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+        let elapsed_ms = self.now_ms().saturating_sub(self.entered_at_ms.load(Ordering::Relaxed));
+        if self
+            .state
+            .compare_exchange(ACTIVE, INACTIVE, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            // Window expired meanwhile, already reported by the evaluation.
+            error!("Update mode {} cannot be exited, update window expired.", token);
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        info!("Update mode {} exited after {} ms.", token, elapsed_ms);
+        Ok(Duration::from_millis(elapsed_ms))
+    }
+
+    /// Update mode is active.
+    pub(crate) fn is_active(&self) -> bool {
+        self.state.load(Ordering::Acquire) == ACTIVE
+    }
+
+    /// Violations of the monitor are advisory, update mode is active and the monitor is declared advisory.
+    pub(crate) fn is_advisory(&self, monitor_tag: &MonitorTag) -> bool {
+        self.is_active() && self.config.advisory_monitors.contains(monitor_tag)
+    }
+}
+
+impl MonitorEvaluator for UpdateModeGuard {
+    fn monitor_tag(&self) -> MonitorTag {
+        self.config.guard_tag
+    }
+
+    fn evaluate(
+        &self,
+        _hmon_starting_point: Instant,
+        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+    ) {
+        if !self.is_active() {
+            return;
+        }
+
+        let elapsed_ms = self.now_ms().saturating_sub(self.entered_at_ms.load(Ordering::Relaxed));
+        if elapsed_ms <= self.config.window.as_millis() as u64 {
+            return;
+        }
+
+        // Normal severity is restored, so advisory monitors are not masked by a hanging update.
+        let token = self.token.load(Ordering::Relaxed);
+        if self
+            .state
+            .compare_exchange(ACTIVE, INACTIVE, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            return;
+        }
+        warn!(
+            "Update mode {} exceeded its window of {} ms, update mode ended.",
+            token,
+            self.config.window.as_millis() as u64
+        );
+        on_error(
+            &self.config.guard_tag,
+            DeadlineEvaluationError::TooLate.into(),
+            ViolationContext {
+                correlation_id: Some(token),
+                ..ViolationContext::default()
+            },
+        );
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::{MonitorEvaluator, ViolationContext};
+    use crate::config_summary::ConfigDigest;
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::tag::MonitorTag;
+    use crate::update_mode::{UpdateMode, UpdateModeGuard};
+    use crate::HealthMonitorError;
+    use core::time::Duration;
+    use std::time::Instant;
+
+    fn def_update_mode(window: Duration) -> UpdateMode {
+        UpdateMode::new(MonitorTag::from("update_guard"), window)
+            .with_advisory_monitors(&[MonitorTag::from("monitor1"), MonitorTag::from("monitor1")])
+    }
+
+    #[test]
+    fn update_mode_skips_duplicate_advisory_monitors() {
+        let update_mode = def_update_mode(Duration::from_secs(1));
+        assert_eq!(update_mode.advisory_monitors(), &[MonitorTag::from("monitor1")]);
+    }
+
+    #[test]
+    fn update_mode_digest_ignores_order() {
+        let update_mode1 = UpdateMode::new(MonitorTag::from("update_guard"), Duration::from_secs(1))
+            .with_advisory_monitors(&[MonitorTag::from("monitor1"), MonitorTag::from("monitor2")]);
+        let update_mode2 = UpdateMode::new(MonitorTag::from("update_guard"), Duration::from_secs(1))
+            .with_advisory_monitors(&[MonitorTag::from("monitor2"), MonitorTag::from("monitor1")]);
+        let mut digest1 = ConfigDigest::new();
+        update_mode1.write_digest(&mut digest1);
+        let mut digest2 = ConfigDigest::new();
+        update_mode2.write_digest(&mut digest2);
+        assert_eq!(digest1.finish(), digest2.finish());
+    }
+
+    #[test]
+    fn update_mode_guard_enter_and_exit() {
+        let guard = UpdateModeGuard::new(def_update_mode(Duration::from_secs(1)));
+        assert!(!guard.is_advisory(&MonitorTag::from("monitor1")));
+
+        guard.enter(7).unwrap();
+        assert!(guard.is_advisory(&MonitorTag::from("monitor1")));
+        assert!(!guard.is_advisory(&MonitorTag::from("monitor2")));
+        assert_eq!(guard.enter(8), Err(HealthMonitorError::WrongState));
+        assert_eq!(guard.exit(8), Err(HealthMonitorError::InvalidArgument));
+
+        assert!(guard.exit(7).is_ok());
+        assert!(!guard.is_advisory(&MonitorTag::from("monitor1")));
+        assert_eq!(guard.exit(7), Err(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn update_mode_guard_reports_expired_window() {
+        let guard = UpdateModeGuard::new(def_update_mode(Duration::from_millis(20)));
+        let hmon_starting_point = Instant::now();
+        let mut violations = Vec::new();
+        let mut on_error = |monitor_tag: &MonitorTag, error, context: ViolationContext| {
+            violations.push((*monitor_tag, error, context.correlation_id));
+        };
+
+        // Nothing is reported while update mode is inactive or within its window.
+        guard.evaluate(hmon_starting_point, &mut on_error);
+        guard.enter(7).unwrap();
+        guard.evaluate(hmon_starting_point, &mut on_error);
+
+        std::thread::sleep(Duration::from_millis(40));
+        guard.evaluate(hmon_starting_point, &mut on_error);
+        guard.evaluate(hmon_starting_point, &mut on_error);
+        assert_eq!(
+            violations,
+            vec![(
+                MonitorTag::from("update_guard"),
+                DeadlineEvaluationError::TooLate.into(),
+                Some(7)
+            )]
+        );
+
+        // Update mode ended with the expired window.
+        assert!(!guard.is_active());
+        assert_eq!(guard.exit(7), Err(HealthMonitorError::WrongState));
+    }
+}
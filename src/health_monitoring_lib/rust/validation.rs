@@ -0,0 +1,598 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::clock_granularity;
+use crate::deadline::DeadlineMonitorBuilder;
+use crate::early_supervision::EarlySupervision;
+use crate::health_monitor_config::HealthMonitorConfig;
+use crate::heartbeat::HeartbeatMonitorBuilder;
+use crate::log::{error, info, warn, ScoreDebug};
+use crate::tag::{ChannelTag, GroupTag, MonitorTag};
+use crate::HealthMonitorError;
+use core::time::Duration;
+use std::collections::{HashMap, HashSet};
+
+/// Longest supervisor API cycle accepted with strict validation.
+pub const STRICT_MAX_SUPERVISOR_API_CYCLE: Duration = Duration::from_secs(10);
+
+/// Longest internal processing cycle accepted with strict validation.
+pub const STRICT_MAX_INTERNAL_PROCESSING_CYCLE: Duration = Duration::from_secs(1);
+
+/// Configuration issue found by [`crate::HealthMonitorBuilder::validate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum ConfigIssue {
+    /// Supervisor API cycle is not a multiple of the internal processing cycle.
+    CycleMismatch,
+    /// No monitors were added and no runtime monitor slots are reserved.
+    NoMonitors,
+    /// Same tag is used by monitors of different kinds, or by a monitor and a group.
+    DuplicateMonitorTag(MonitorTag),
+    /// Deadline has zero concurrent instances.
+    InvalidDeadlineInstances(MonitorTag),
+    /// Execution budget is zero or longer than its window.
+    InvalidBudget(MonitorTag),
+    /// Warning threshold is set for an unknown deadline or is not within its range.
+    InvalidWarningThreshold(MonitorTag),
+    /// Deadline chain links unknown deadlines, a deadline to itself, or a downstream deadline which is concurrent,
+    /// cyclic, measures CPU time or is chained to multiple upstream deadlines.
+    InvalidDeadlineChain(MonitorTag),
+    /// Shortest allowed heartbeat range is not longer than half of the internal processing cycle.
+    /// Ranges without lower bound are accepted.
+    HeartbeatRangeTooShort(MonitorTag),
+    /// Maximum number of heartbeat update attempts is zero.
+    InvalidHeartbeatUpdateAttempts(MonitorTag),
+    /// Expected number of heartbeats has minimum greater than maximum,
+    /// or window not longer than the internal processing cycle.
+    InvalidHeartbeatExpectedCount(MonitorTag),
+    /// Heartbeat sources are combined with expected number of heartbeats or a deadline source.
+    InvalidHeartbeatSources(MonitorTag),
+    /// Deadline of the first heartbeat is not longer than the internal processing cycle,
+    /// or is combined with expected number of heartbeats or heartbeat sources.
+    InvalidHeartbeatFirstBeatDeadline(MonitorTag),
+    /// Deadline providing heartbeats to the monitor does not exist.
+    UnknownHeartbeatSource(MonitorTag),
+    /// Maximum age of an artifact is not longer than the internal processing cycle.
+    ArtifactMaxAgeTooShort(MonitorTag),
+    /// Monitor assigned to a notification channel does not exist.
+    UnknownChannelMonitor {
+        channel_tag: ChannelTag,
+        monitor_tag: MonitorTag,
+    },
+    /// Monitor is assigned to more than one notification channel.
+    MonitorInMultipleChannels(MonitorTag),
+    /// Member of a monitor group does not exist.
+    UnknownGroupMember {
+        group_tag: GroupTag,
+        monitor_tag: MonitorTag,
+    },
+    /// Monitor is a member of more than one group.
+    MonitorInMultipleGroups(MonitorTag),
+    /// Members of a group are assigned to different notification channels.
+    GroupInMultipleChannels(GroupTag),
+    /// Monitor exported as a signal does not exist.
+    UnknownSignalMonitor(MonitorTag),
+    /// Monitor with failure tolerance does not exist.
+    UnknownToleranceMonitor(MonitorTag),
+    /// Failure tolerance is out of supported limits, [`None`] for the default tolerance.
+    InvalidFailureTolerance(Option<MonitorTag>),
+    /// Recovery policy is out of supported limits.
+    InvalidRecoveryPolicy,
+    /// Advisory monitor of the update mode does not exist.
+    UnknownAdvisoryMonitor(MonitorTag),
+    /// Update window is not longer than the internal processing cycle.
+    UpdateWindowTooShort,
+    /// Timeout from arming to go is shorter than the internal processing cycle.
+    ArmTimeoutTooShort,
+    /// Worker stall threshold is not longer than the internal processing cycle.
+    WorkerStallThresholdTooShort,
+    /// Monitor selected by the fail-fast policy does not exist.
+    UnknownFailFastMonitor(MonitorTag),
+    /// Fail-fast policy exits the process with exit code zero.
+    InvalidFailFastExitCode,
+    /// Supervisor identity is empty.
+    InvalidSupervisorIdentity,
+    /// Internal processing cycle of the early supervision differs from the one of the health monitor.
+    EarlySupervisionCycleMismatch,
+    /// Resource budget exceeds the supported capacities or its memory limit.
+    ResourceBudgetExceeded,
+    /// Supervisor API cycle is longer than [`STRICT_MAX_SUPERVISOR_API_CYCLE`], reported in strict mode only.
+    LongSupervisorAPICycle,
+    /// Internal processing cycle is longer than [`STRICT_MAX_INTERNAL_PROCESSING_CYCLE`], reported in strict mode only.
+    LongInternalProcessingCycle,
+    /// Monitor has nothing to supervise, reported in strict mode only.
+    EmptyMonitor(MonitorTag),
+    /// Group has no members, reported in strict mode only.
+    EmptyGroup(GroupTag),
+    /// Monitor has a range shorter than the configured multiple of the clock granularity, reported in strict mode only.
+    RangeBelowClockGranularity(MonitorTag),
+}
+
+impl ConfigIssue {
+    /// Error returned by [`crate::HealthMonitorBuilder::build`] for this issue.
+    pub fn error(&self) -> HealthMonitorError {
+        match self {
+            ConfigIssue::NoMonitors => HealthMonitorError::WrongState,
+            ConfigIssue::UnknownChannelMonitor { .. }
+            | ConfigIssue::UnknownGroupMember { .. }
+            | ConfigIssue::UnknownSignalMonitor(_)
+            | ConfigIssue::UnknownToleranceMonitor(_)
+            | ConfigIssue::UnknownAdvisoryMonitor(_)
+            | ConfigIssue::UnknownFailFastMonitor(_)
+            | ConfigIssue::UnknownHeartbeatSource(_) => HealthMonitorError::NotFound,
+            _ => HealthMonitorError::InvalidArgument,
+        }
+    }
+}
+
+/// All configuration issues of a [`crate::HealthMonitorBuilder`], in order of checking.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    issues: Vec<ConfigIssue>,
+}
+
+impl ValidationReport {
+    pub(crate) fn new() -> Self {
+        Self { issues: Vec::new() }
+    }
+
+    pub(crate) fn push(&mut self, issue: ConfigIssue) {
+        self.issues.push(issue);
+    }
+
+    /// No issues were found, [`crate::HealthMonitorBuilder::build`] will succeed.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Found issues.
+    pub fn issues(&self) -> &[ConfigIssue] {
+        &self.issues
+    }
+
+    /// Error of the first found issue, [`None`] if the configuration is valid.
+    pub fn error(&self) -> Option<HealthMonitorError> {
+        self.issues.first().map(ConfigIssue::error)
+    }
+}
+
+/// Check cycle values.
+/// `supervisor_api_cycle` must be a multiple of `internal_processing_cycle`.
+fn validate_cycles(supervisor_api_cycle: Duration, internal_processing_cycle: Duration, report: &mut ValidationReport) {
+    let supervisor_api_cycle_ms = supervisor_api_cycle.as_millis() as u64;
+    let internal_processing_cycle_ms = internal_processing_cycle.as_millis() as u64;
+    if !supervisor_api_cycle_ms.is_multiple_of(internal_processing_cycle_ms) {
+        error!(
+            "Supervisor API cycle duration ({} ms) must be a multiple of internal processing cycle interval ({} ms).",
+            supervisor_api_cycle_ms, internal_processing_cycle_ms
+        );
+        report.push(ConfigIssue::CycleMismatch);
+    }
+}
+
+/// Check deadline and heartbeat monitors.
+/// Heartbeats derived from deadlines must refer to deadlines of `deadline_monitor_builders`.
+fn validate_monitors<'a, D, H>(
+    deadline_monitor_builders: D,
+    heartbeat_monitor_builders: H,
+    internal_processing_cycle: Duration,
+    report: &mut ValidationReport,
+) where
+    D: Iterator<Item = (&'a MonitorTag, &'a DeadlineMonitorBuilder)> + Clone,
+    H: Iterator<Item = (&'a MonitorTag, &'a HeartbeatMonitorBuilder)>,
+{
+    for (tag, builder) in deadline_monitor_builders.clone() {
+        if builder.has_empty_instance_pool() {
+            error!("Deadlines of monitor {:?} must have at least one instance.", tag);
+            report.push(ConfigIssue::InvalidDeadlineInstances(*tag));
+        }
+        if builder.has_invalid_budget() {
+            error!(
+                "Budgets of monitor {:?} must not be zero or longer than their window.",
+                tag
+            );
+            report.push(ConfigIssue::InvalidBudget(*tag));
+        }
+        if builder.has_invalid_warning() {
+            error!(
+                "Warning thresholds of monitor {:?} must be set for known deadlines and within their range.",
+                tag
+            );
+            report.push(ConfigIssue::InvalidWarningThreshold(*tag));
+        }
+        if builder.has_invalid_chain() {
+            error!(
+                "Chains of monitor {:?} must link known deadlines which can be started by their upstream deadline.",
+                tag
+            );
+            report.push(ConfigIssue::InvalidDeadlineChain(*tag));
+        }
+    }
+    for (tag, builder) in heartbeat_monitor_builders {
+        builder.validate(*tag, internal_processing_cycle, report);
+        if let Some((deadline_monitor_tag, deadline_tag)) = builder.deadline_source() {
+            let has_source = deadline_monitor_builders
+                .clone()
+                .find(|(other_tag, _)| **other_tag == deadline_monitor_tag)
+                .is_some_and(|(_, deadline_monitor_builder)| deadline_monitor_builder.has_deadline(&deadline_tag));
+            if !has_source {
+                error!(
+                    "Deadline {:?} of monitor {:?} providing heartbeats to monitor {:?} does not exist.",
+                    deadline_tag, deadline_monitor_tag, tag
+                );
+                report.push(ConfigIssue::UnknownHeartbeatSource(*tag));
+            }
+        }
+    }
+}
+
+/// Check the health monitor configuration, see [`crate::HealthMonitorBuilder::validate`].
+pub(crate) fn validate_health_monitor_config(config: &HealthMonitorConfig) -> ValidationReport {
+    let mut report = ValidationReport::new();
+
+    // Check cycle values.
+    validate_cycles(
+        config.supervisor_api_cycle,
+        config.internal_processing_cycle,
+        &mut report,
+    );
+
+    // Check number of monitors.
+    let num_monitors = config.num_monitors();
+    if num_monitors == 0 && config.resource_budget.runtime_monitor_capacity() == 0 {
+        error!("No monitors have been added. HealthMonitor cannot be created.");
+        report.push(ConfigIssue::NoMonitors);
+    }
+
+    // Check monitor tags are unique across monitor kinds.
+    let mut monitor_tags = HashSet::new();
+    for monitor_tag in config
+        .deadline_monitor_builders
+        .keys()
+        .chain(config.heartbeat_monitor_builders.keys())
+        .chain(config.artifact_freshness_monitor_builders.keys())
+        .chain(config.early_supervision.iter().flat_map(EarlySupervision::monitor_tags))
+    {
+        if !monitor_tags.insert(*monitor_tag) {
+            error!("Monitor tag {:?} is used by multiple monitors.", monitor_tag);
+            report.push(ConfigIssue::DuplicateMonitorTag(*monitor_tag));
+        }
+    }
+
+    // Check monitors.
+    validate_monitors(
+        config.deadline_monitor_builders.iter(),
+        config.heartbeat_monitor_builders.iter(),
+        config.internal_processing_cycle,
+        &mut report,
+    );
+    for (tag, monitor_builder) in config.artifact_freshness_monitor_builders.iter() {
+        monitor_builder.validate(*tag, config.internal_processing_cycle, &mut report);
+    }
+
+    // Check notification channels.
+    let mut assigned_monitors = HashMap::new();
+    for (channel_tag, monitor_tags) in config.notification_channels.iter() {
+        for monitor_tag in monitor_tags {
+            if !config.contains_monitor(monitor_tag) {
+                error!(
+                    "Monitor {:?} assigned to channel {:?} does not exist.",
+                    monitor_tag, channel_tag
+                );
+                report.push(ConfigIssue::UnknownChannelMonitor {
+                    channel_tag: *channel_tag,
+                    monitor_tag: *monitor_tag,
+                });
+            }
+            if let Some(other_channel_tag) = assigned_monitors.insert(*monitor_tag, *channel_tag) {
+                error!(
+                    "Monitor {:?} is assigned to multiple channels ({:?}, {:?}).",
+                    monitor_tag, other_channel_tag, channel_tag
+                );
+                report.push(ConfigIssue::MonitorInMultipleChannels(*monitor_tag));
+            }
+        }
+    }
+
+    // Check monitor groups.
+    let mut grouped_monitors = HashMap::new();
+    for (group_tag, group) in config.monitor_groups.iter() {
+        let group_monitor_tag = MonitorTag::from(*group_tag);
+        if monitor_tags.contains(&group_monitor_tag) {
+            error!("Group tag {:?} is used by a monitor.", group_tag);
+            report.push(ConfigIssue::DuplicateMonitorTag(group_monitor_tag));
+        }
+        for monitor_tag in group.members() {
+            if !config.contains_monitor(monitor_tag) {
+                error!("Member {:?} of group {:?} does not exist.", monitor_tag, group_tag);
+                report.push(ConfigIssue::UnknownGroupMember {
+                    group_tag: *group_tag,
+                    monitor_tag: *monitor_tag,
+                });
+            }
+            if let Some(other_group_tag) = grouped_monitors.insert(*monitor_tag, *group_tag) {
+                error!(
+                    "Monitor {:?} is a member of multiple groups ({:?}, {:?}).",
+                    monitor_tag, other_group_tag, group_tag
+                );
+                report.push(ConfigIssue::MonitorInMultipleGroups(*monitor_tag));
+            }
+        }
+        let mut member_channels = group
+            .members()
+            .iter()
+            .map(|monitor_tag| assigned_monitors.get(monitor_tag));
+        if let Some(first_channel) = member_channels.next() {
+            if member_channels.any(|channel| channel != first_channel) {
+                error!("Members of group {:?} are assigned to different channels.", group_tag);
+                report.push(ConfigIssue::GroupInMultipleChannels(*group_tag));
+            }
+        }
+    }
+
+    // Check signal export.
+    if let Some(signal_export) = &config.signal_export {
+        if config.resource_budget.runtime_monitor_capacity() == 0 {
+            for monitor_tag in signal_export.monitor_tags() {
+                if !config.contains_monitor(monitor_tag) {
+                    error!("Monitor {:?} exported as a signal does not exist.", monitor_tag);
+                    report.push(ConfigIssue::UnknownSignalMonitor(*monitor_tag));
+                }
+            }
+        }
+    }
+
+    // Check failure tolerances.
+    for (monitor_tag, tolerance) in config.monitor_failure_tolerances.iter() {
+        if !config.contains_monitor(monitor_tag) && !config.contains_group(monitor_tag) {
+            error!("Monitor {:?} with failure tolerance does not exist.", monitor_tag);
+            report.push(ConfigIssue::UnknownToleranceMonitor(*monitor_tag));
+        }
+        if !tolerance.is_valid() {
+            error!(
+                "Invalid failure tolerance {:?} of monitor {:?}.",
+                tolerance, monitor_tag
+            );
+            report.push(ConfigIssue::InvalidFailureTolerance(Some(*monitor_tag)));
+        }
+    }
+    if let Some(tolerance) = config.failure_tolerance.filter(|tolerance| !tolerance.is_valid()) {
+        error!("Invalid failure tolerance {:?}.", tolerance);
+        report.push(ConfigIssue::InvalidFailureTolerance(None));
+    }
+
+    // Check update mode.
+    if let Some(update_mode) = &config.update_mode {
+        let guard_tag = update_mode.guard_tag();
+        if monitor_tags.contains(&guard_tag) || config.contains_group(&guard_tag) {
+            error!("Update mode guard tag {:?} is used by a monitor.", guard_tag);
+            report.push(ConfigIssue::DuplicateMonitorTag(guard_tag));
+        }
+        for monitor_tag in update_mode.advisory_monitors() {
+            if !config.contains_monitor(monitor_tag) {
+                error!("Advisory monitor {:?} of update mode does not exist.", monitor_tag);
+                report.push(ConfigIssue::UnknownAdvisoryMonitor(*monitor_tag));
+            }
+        }
+        if update_mode.window() < config.internal_processing_cycle {
+            error!(
+                "Update window ({} ms) is shorter than internal processing cycle ({} ms).",
+                update_mode.window().as_millis() as u64,
+                config.internal_processing_cycle.as_millis() as u64
+            );
+            report.push(ConfigIssue::UpdateWindowTooShort);
+        }
+    }
+
+    // Check armed start.
+    if let Some(armed_start) = &config.armed_start {
+        let guard_tag = armed_start.guard_tag();
+        if monitor_tags.contains(&guard_tag)
+            || config.contains_group(&guard_tag)
+            || config
+                .update_mode
+                .as_ref()
+                .is_some_and(|update_mode| update_mode.guard_tag() == guard_tag)
+        {
+            error!("Armed start guard tag {:?} is used by a monitor.", guard_tag);
+            report.push(ConfigIssue::DuplicateMonitorTag(guard_tag));
+        }
+        if armed_start.timeout() < config.internal_processing_cycle {
+            error!(
+                "Armed start timeout ({} ms) is shorter than internal processing cycle ({} ms).",
+                armed_start.timeout().as_millis() as u64,
+                config.internal_processing_cycle.as_millis() as u64
+            );
+            report.push(ConfigIssue::ArmTimeoutTooShort);
+        }
+    }
+
+    // Check health trajectory.
+    if let Some(health_trajectory) = &config.health_trajectory {
+        let monitor_tag = health_trajectory.monitor_tag();
+        if monitor_tags.contains(&monitor_tag)
+            || config.contains_group(&monitor_tag)
+            || config
+                .update_mode
+                .as_ref()
+                .is_some_and(|update_mode| update_mode.guard_tag() == monitor_tag)
+            || config
+                .armed_start
+                .as_ref()
+                .is_some_and(|armed_start| armed_start.guard_tag() == monitor_tag)
+        {
+            error!("Health trajectory monitor tag {:?} is used by a monitor.", monitor_tag);
+            report.push(ConfigIssue::DuplicateMonitorTag(monitor_tag));
+        }
+    }
+
+    // Check worker stall threshold.
+    if config
+        .worker_stall_threshold
+        .is_some_and(|threshold| threshold <= config.internal_processing_cycle)
+    {
+        error!("Worker stall threshold must be longer than internal processing cycle.");
+        report.push(ConfigIssue::WorkerStallThresholdTooShort);
+    }
+
+    // Check supervisor identity.
+    if config
+        .supervisor_identity
+        .as_ref()
+        .is_some_and(|identity| identity.is_empty())
+    {
+        error!("Supervisor identity must not be empty.");
+        report.push(ConfigIssue::InvalidSupervisorIdentity);
+    }
+
+    // Check early supervision, its heartbeat monitors were validated against its cycle.
+    if let Some(early_supervision) = &config.early_supervision {
+        if early_supervision.internal_processing_cycle() != config.internal_processing_cycle {
+            error!(
+                "Early supervision cycle ({} ms) differs from internal processing cycle ({} ms).",
+                early_supervision.internal_processing_cycle().as_millis() as u64,
+                config.internal_processing_cycle.as_millis() as u64
+            );
+            report.push(ConfigIssue::EarlySupervisionCycleMismatch);
+        }
+    }
+
+    // Check fail-fast policy.
+    if let Some(fail_fast) = &config.fail_fast {
+        for monitor_tag in fail_fast.monitors() {
+            if !config.contains_monitor(monitor_tag) && !config.contains_group(monitor_tag) {
+                error!("Monitor {:?} selected by fail-fast policy does not exist.", monitor_tag);
+                report.push(ConfigIssue::UnknownFailFastMonitor(*monitor_tag));
+            }
+        }
+        if fail_fast.exit_code() == 0 {
+            error!("Fail-fast policy must not exit the process with exit code zero.");
+            report.push(ConfigIssue::InvalidFailFastExitCode);
+        }
+    }
+
+    // Check recovery policy.
+    if !config.recovery_policy.is_valid() {
+        error!("Invalid recovery policy {:?}.", config.recovery_policy);
+        report.push(ConfigIssue::InvalidRecoveryPolicy);
+    }
+
+    // Check resource budget.
+    if config.resource_budget.validate(num_monitors).is_err() {
+        report.push(ConfigIssue::ResourceBudgetExceeded);
+    }
+
+    // Check suspicious configurations, errors only in strict mode.
+    let mut suspicious_issues = Vec::new();
+    if config.supervisor_api_cycle > STRICT_MAX_SUPERVISOR_API_CYCLE {
+        suspicious_issues.push(ConfigIssue::LongSupervisorAPICycle);
+    }
+    if config.internal_processing_cycle > STRICT_MAX_INTERNAL_PROCESSING_CYCLE {
+        suspicious_issues.push(ConfigIssue::LongInternalProcessingCycle);
+    }
+    for (tag, monitor_builder) in config.deadline_monitor_builders.iter() {
+        if monitor_builder.num_deadlines() == 0 && monitor_builder.num_budgets() == 0 {
+            suspicious_issues.push(ConfigIssue::EmptyMonitor(*tag));
+        }
+    }
+    for (tag, monitor_builder) in config.artifact_freshness_monitor_builders.iter() {
+        if monitor_builder.num_artifacts() == 0 {
+            suspicious_issues.push(ConfigIssue::EmptyMonitor(*tag));
+        }
+    }
+    for (tag, group) in config.monitor_groups.iter() {
+        if group.members().is_empty() {
+            suspicious_issues.push(ConfigIssue::EmptyGroup(*tag));
+        }
+    }
+    if config.clock_granularity_multiple > 0 {
+        let granularity = clock_granularity::clock_granularity();
+        let shortest_range =
+            clock_granularity::shortest_supported_range(granularity, config.clock_granularity_multiple);
+        let deadline_ranges = config
+            .deadline_monitor_builders
+            .iter()
+            .filter_map(|(tag, monitor_builder)| monitor_builder.shortest_deadline().map(|max| (tag, max)));
+        let heartbeat_ranges = config
+            .heartbeat_monitor_builders
+            .iter()
+            .map(|(tag, monitor_builder)| (tag, monitor_builder.range()))
+            .filter(|(_, range)| range.has_upper_bound())
+            .map(|(tag, range)| (tag, range.max));
+        for (tag, max) in deadline_ranges.chain(heartbeat_ranges) {
+            if max < shortest_range {
+                info!(
+                    "Range of monitor {:?} ({} us) spans less than {} ticks of the clock ({} ns).",
+                    tag,
+                    max.as_micros() as u64,
+                    config.clock_granularity_multiple,
+                    granularity.as_nanos() as u64
+                );
+                suspicious_issues.push(ConfigIssue::RangeBelowClockGranularity(*tag));
+            }
+        }
+    }
+    for issue in suspicious_issues {
+        if config.strict_validation {
+            error!("Suspicious configuration rejected by strict validation: {:?}.", issue);
+            report.push(issue);
+        } else {
+            warn!("Suspicious configuration: {:?}.", issue);
+        }
+    }
+
+    report
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::tag::{ChannelTag, MonitorTag};
+    use crate::validation::{ConfigIssue, ValidationReport};
+    use crate::HealthMonitorError;
+
+    #[test]
+    fn validation_report_empty_is_valid() {
+        let report = ValidationReport::new();
+        assert!(report.is_valid());
+        assert!(report.issues().is_empty());
+        assert_eq!(report.error(), None);
+    }
+
+    #[test]
+    fn validation_report_error_of_first_issue() {
+        let mut report = ValidationReport::new();
+        report.push(ConfigIssue::UnknownChannelMonitor {
+            channel_tag: ChannelTag::from("channel"),
+            monitor_tag: MonitorTag::from("monitor"),
+        });
+        report.push(ConfigIssue::NoMonitors);
+        assert!(!report.is_valid());
+        assert_eq!(report.issues().len(), 2);
+        assert_eq!(report.error(), Some(HealthMonitorError::NotFound));
+    }
+
+    #[test]
+    fn config_issue_error() {
+        let monitor_tag = MonitorTag::from("monitor");
+        assert_eq!(ConfigIssue::NoMonitors.error(), HealthMonitorError::WrongState);
+        assert_eq!(
+            ConfigIssue::UnknownSignalMonitor(monitor_tag).error(),
+            HealthMonitorError::NotFound
+        );
+        assert_eq!(ConfigIssue::CycleMismatch.error(), HealthMonitorError::InvalidArgument);
+        assert_eq!(
+            ConfigIssue::HeartbeatRangeTooShort(monitor_tag).error(),
+            HealthMonitorError::InvalidArgument
+        );
+    }
+}
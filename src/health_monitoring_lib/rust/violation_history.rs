@@ -0,0 +1,301 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::common::{InternalFault, MonitorEvaluationError};
+use crate::deadline::SchedulingStatistics;
+use crate::tag::{DeadlineTag, MonitorTag, SourceTag};
+use crate::thread_attribution::ReporterThread;
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+
+/// Violation reported by a monitor during evaluation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ViolationRecord {
+    /// Tag of the failing monitor.
+    pub monitor_tag: MonitorTag,
+    /// Reported error.
+    pub error: MonitorEvaluationError,
+    /// Time of the evaluation, relative to the start of the health monitor.
+    pub timestamp: Duration,
+    /// Last thread which reported to the failing object, [`None`] if thread attribution is disabled.
+    pub reporter: Option<ReporterThread>,
+    /// Correlation ID the failing object was started with, [`None`] if not provided.
+    /// See [`crate::deadline::Deadline::start_with_id`].
+    pub correlation_id: Option<u64>,
+    /// Scheduler statistics of the violated activation, [`None`] if not sampled.
+    /// See [`crate::deadline::DeadlineMonitorBuilder::with_scheduling_statistics`].
+    pub scheduling: Option<SchedulingStatistics>,
+    /// Tag of the violated deadline, [`None`] for other monitors.
+    pub deadline_tag: Option<DeadlineTag>,
+    /// Tag of the silent heartbeat source, [`None`] for other violations.
+    /// See [`crate::heartbeat::HeartbeatMonitorBuilder::add_source`].
+    pub source_tag: Option<SourceTag>,
+    /// Time outside of the range, e.g. overrun of a deadline.
+    /// [`None`] for errors without a range.
+    pub offset: Option<Duration>,
+}
+
+/// Fault of the health monitoring library itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InternalFaultRecord {
+    /// Tag of the faulty monitor, [`None`] for faults not related to a single monitor.
+    pub monitor_tag: Option<MonitorTag>,
+    /// Reported fault.
+    pub fault: InternalFault,
+    /// Time of the fault, relative to the start of monitoring.
+    /// Faults found while starting monitoring are relative to creation of the health monitor.
+    pub timestamp: Duration,
+}
+
+/// Ring buffers of the most recent violations and internal faults.
+/// Capacity is allocated up front, oldest records are overwritten when full.
+/// Storing never blocks or allocates, so records are stored from the monitoring thread without locks.
+pub(crate) struct ViolationHistory {
+    violations: Ring<ViolationRecord>,
+    internal_faults: Ring<InternalFaultRecord>,
+}
+
+/// Slot state bit set while the record is being written or copied.
+const BUSY: u64 = 1;
+
+/// Slot of a [`Ring`].
+struct Slot<T> {
+    /// Position of the stored record plus one, shifted left by one, with [`BUSY`] bit. Zero if empty.
+    state: AtomicU64,
+    record: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// Lock-free ring buffer of `Copy` records.
+///
+/// Writers claim positions with a single counter and never wait: a record is dropped if its slot is busy,
+/// i.e. being copied by a reader or written by a concurrent writer, or a newer record was already stored to it.
+/// Readers copy each slot exclusively and spin while it is busy, which is bounded by copying a single record.
+struct Ring<T> {
+    slots: Box<[Slot<T>]>,
+    next: AtomicU64,
+}
+
+// SAFETY: Records are only accessed by the thread which set the `BUSY` bit of their slot.
+unsafe impl<T: Copy + Send> Sync for Ring<T> {}
+
+impl<T: Copy> Ring<T> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            slots: (0..capacity)
+                .map(|_| Slot {
+                    state: AtomicU64::new(0),
+                    record: UnsafeCell::new(MaybeUninit::uninit()),
+                })
+                .collect(),
+            next: AtomicU64::new(0),
+        }
+    }
+
+    fn push(&self, record: T) {
+        if self.slots.is_empty() {
+            return;
+        }
+
+        let position = self.next.fetch_add(1, Ordering::Relaxed);
+        let slot = &self.slots[(position % self.slots.len() as u64) as usize];
+        let stored = (position + 1) << 1;
+        let current = slot.state.load(Ordering::Relaxed);
+        if current & BUSY != 0
+            || current >= stored
+            || slot
+                .state
+                .compare_exchange(current, current | BUSY, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+        {
+            return;
+        }
+
+        // SAFETY: `BUSY` bit grants exclusive access to the record.
+        unsafe { (*slot.record.get()).write(record) };
+        slot.state.store(stored, Ordering::Release);
+    }
+
+    /// Stored records, oldest first.
+    fn records(&self) -> Vec<T> {
+        let mut records = Vec::with_capacity(self.slots.len());
+        for slot in self.slots.iter() {
+            let mut current = slot.state.load(Ordering::Relaxed);
+            loop {
+                if current == 0 {
+                    break;
+                }
+                if current & BUSY != 0 {
+                    core::hint::spin_loop();
+                    current = slot.state.load(Ordering::Relaxed);
+                    continue;
+                }
+                match slot
+                    .state
+                    .compare_exchange_weak(current, current | BUSY, Ordering::Acquire, Ordering::Relaxed)
+                {
+                    Ok(_) => {
+                        // SAFETY: `BUSY` bit grants exclusive access, non-zero state marks an initialized record.
+                        records.push((current, unsafe { (*slot.record.get()).assume_init() }));
+                        slot.state.store(current, Ordering::Release);
+                        break;
+                    },
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        records.sort_unstable_by_key(|(state, _)| *state);
+        records.into_iter().map(|(_, record)| record).collect()
+    }
+}
+
+impl ViolationHistory {
+    pub(crate) fn new(capacity: usize, internal_fault_capacity: usize) -> Self {
+        Self {
+            violations: Ring::new(capacity),
+            internal_faults: Ring::new(internal_fault_capacity),
+        }
+    }
+
+    /// Store the violation, overwriting the oldest one if the history is full.
+    pub(crate) fn push(&self, record: ViolationRecord) {
+        self.violations.push(record);
+    }
+
+    /// Store the internal fault, overwriting the oldest one if the history is full.
+    pub(crate) fn push_internal_fault(&self, record: InternalFaultRecord) {
+        self.internal_faults.push(record);
+    }
+
+    /// Stored violations, oldest first.
+    pub(crate) fn records(&self) -> Vec<ViolationRecord> {
+        self.violations.records()
+    }
+
+    /// Stored internal faults, oldest first.
+    pub(crate) fn internal_faults(&self) -> Vec<InternalFaultRecord> {
+        self.internal_faults.records()
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::{InternalFault, MonitorEvaluationError};
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::tag::MonitorTag;
+    use crate::violation_history::{InternalFaultRecord, ViolationHistory, ViolationRecord};
+    use core::time::Duration;
+
+    fn record(timestamp_ms: u64) -> ViolationRecord {
+        ViolationRecord {
+            monitor_tag: MonitorTag::from("monitor"),
+            error: MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooLate),
+            timestamp: Duration::from_millis(timestamp_ms),
+            reporter: None,
+            correlation_id: None,
+            scheduling: None,
+            deadline_tag: None,
+            source_tag: None,
+            offset: None,
+        }
+    }
+
+    fn timestamps(history: &ViolationHistory) -> Vec<u64> {
+        history
+            .records()
+            .iter()
+            .map(|r| r.timestamp.as_millis() as u64)
+            .collect()
+    }
+
+    #[test]
+    fn violation_history_keeps_records_in_order() {
+        let history = ViolationHistory::new(3, 0);
+        assert!(history.records().is_empty());
+
+        history.push(record(1));
+        history.push(record(2));
+        assert_eq!(timestamps(&history), vec![1, 2]);
+    }
+
+    #[test]
+    fn violation_history_overwrites_oldest() {
+        let history = ViolationHistory::new(3, 0);
+        for timestamp in 1..=5 {
+            history.push(record(timestamp));
+        }
+        assert_eq!(timestamps(&history), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn violation_history_zero_capacity() {
+        let history = ViolationHistory::new(0, 0);
+        history.push(record(1));
+        assert!(history.records().is_empty());
+    }
+
+    #[test]
+    fn violation_history_concurrent_push_and_read() {
+        let history = std::sync::Arc::new(ViolationHistory::new(4, 0));
+        let writers: Vec<_> = (0..2)
+            .map(|writer| {
+                let history = history.clone();
+                std::thread::spawn(move || {
+                    for timestamp in 0..50 {
+                        history.push(record(writer * 1000 + timestamp));
+                    }
+                })
+            })
+            .collect();
+        for _ in 0..20 {
+            assert!(history.records().len() <= 4);
+        }
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        // Records of each writer are kept in order.
+        let timestamps = timestamps(&history);
+        assert_eq!(timestamps.len(), 4);
+        for writer in 0..2 {
+            let own: Vec<_> = timestamps.iter().filter(|t| **t / 1000 == writer).collect();
+            assert!(own.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn violation_history_keeps_internal_faults_separately() {
+        let history = ViolationHistory::new(3, 2);
+        history.push(record(1));
+        for fault in [
+            InternalFault::WorkerOverrun,
+            InternalFault::NotifyFailure,
+            InternalFault::StateCorruption,
+        ] {
+            history.push_internal_fault(InternalFaultRecord {
+                monitor_tag: None,
+                fault,
+                timestamp: Duration::ZERO,
+            });
+        }
+
+        assert_eq!(timestamps(&history), vec![1]);
+        let faults: Vec<_> = history.internal_faults().iter().map(|r| r.fault).collect();
+        assert_eq!(
+            faults,
+            vec![InternalFault::NotifyFailure, InternalFault::StateCorruption]
+        );
+    }
+}
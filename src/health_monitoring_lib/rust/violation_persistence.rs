@@ -0,0 +1,644 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::health_forwarder::{crc32, crc32_update};
+use crate::log::{error, info};
+use crate::violation_history::ViolationRecord;
+use crate::violation_sink::ViolationSink;
+use core::time::Duration;
+use std::borrow::Cow;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::thread::JoinHandle;
+use std::time::Instant;
+
+/// Prefix of names of violation log files, followed by the sequence number.
+const FILE_PREFIX: &str = "violations-";
+
+/// Extension of violation log files.
+const FILE_EXTENSION: &str = "log";
+
+/// First line of a violation log file, followed by the sequence number.
+const HEADER: &str = "#hm-violations v1 seq=";
+
+/// Last line of a sealed violation log file, followed by the number of records and the CRC-32 of preceding content.
+const TRAILER: &str = "#end records=";
+
+/// Default size of a violation log file before it is rotated.
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 64 * 1024;
+
+/// When violation log files are rotated and how many are retained, see [`PersistentViolationSink`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RotationPolicy {
+    max_file_bytes: u64,
+    max_file_age: Option<Duration>,
+    max_archives: Option<usize>,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FILE_BYTES)
+    }
+}
+
+impl RotationPolicy {
+    /// Rotate files reaching `max_file_bytes`, all archives are retained.
+    /// File receiving a record is rotated after the record is written, so files can be larger by one record.
+    pub fn new(max_file_bytes: u64) -> Self {
+        Self {
+            max_file_bytes: max_file_bytes.max(1),
+            max_file_age: None,
+            max_archives: None,
+        }
+    }
+
+    /// Also rotate files older than `max_file_age` when the next record is written,
+    /// so records of a time period are kept together.
+    pub fn with_max_file_age(mut self, max_file_age: Duration) -> Self {
+        self.max_file_age = Some(max_file_age);
+        self
+    }
+
+    /// Retain at most `max_archives` sealed files, oldest ones are pruned on rotation.
+    pub fn with_max_archives(mut self, max_archives: usize) -> Self {
+        self.max_archives = Some(max_archives);
+        self
+    }
+}
+
+/// Integrity of a violation log file, see [`PersistentViolationSink::archives`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveIntegrity {
+    /// File is sealed and its content matches the CRC.
+    Valid,
+    /// File is not sealed, e.g. it is being written or the writing process was terminated.
+    Unsealed,
+    /// Header, trailer or CRC of the file does not match its content.
+    Corrupted,
+}
+
+/// Violation log file in the directory of a [`PersistentViolationSink`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveInfo {
+    /// Sequence number of the file, increasing with each rotation, also across restarts.
+    pub sequence: u64,
+    /// Path of the file.
+    pub path: PathBuf,
+    /// Size of the file in bytes.
+    pub bytes: u64,
+    /// Integrity of the file.
+    pub integrity: ArchiveIntegrity,
+}
+
+/// Number of records waiting for the writer thread, records reported while the queue is full are dropped.
+const QUEUE_CAPACITY: usize = 256;
+
+/// Request to the writer thread of a [`PersistentViolationSink`].
+enum Request {
+    /// Write a formatted record line.
+    Record(String),
+    /// Seal the current file and reply with the result.
+    Rotate(SyncSender<io::Result<()>>),
+    /// Reply once all preceding requests are handled.
+    Flush(SyncSender<()>),
+}
+
+/// [`ViolationSink`] persisting violations into rotated text files, e.g. on a dedicated flash partition.
+///
+/// Each file starts with a header holding its sequence number and is sealed on rotation with a trailer holding
+/// the number of records and a CRC-32 of the content, so truncated or corrupted files are detected when read back.
+/// Every process run starts a new file, the file of a terminated run is left unsealed.
+///
+/// Records are formatted on the monitoring thread and written, synced and pruned by a dedicated writer thread,
+/// so monitoring is never blocked by the storage. Records reported while the queue of the writer is full
+/// are dropped and counted, see [`Self::dropped_records`]. Write errors are logged and the record is dropped.
+pub struct PersistentViolationSink {
+    directory: PathBuf,
+    policy: RotationPolicy,
+    requests: Option<SyncSender<Request>>,
+    writer: Option<JoinHandle<()>>,
+    dropped_records: u64,
+    /// Queue was full when the last record was reported, drops are logged once per full queue.
+    queue_full: bool,
+}
+
+impl PersistentViolationSink {
+    /// Create a sink writing into `directory`, created if missing, and start its writer thread.
+    /// Sequence numbers continue after files already in the directory.
+    pub fn new(directory: impl Into<PathBuf>, policy: RotationPolicy) -> io::Result<Self> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+        let next_sequence = list_files(&directory)?
+            .last()
+            .map_or(0, |(sequence, _)| sequence.saturating_add(1));
+
+        let writer = ViolationLogWriter {
+            directory: directory.clone(),
+            policy,
+            active: None,
+            next_sequence,
+        };
+        let (requests, receiver) = sync_channel(QUEUE_CAPACITY);
+        let writer = std::thread::Builder::new()
+            .name("hm_violation_log".to_string())
+            .spawn(move || writer.run(receiver))?;
+        Ok(Self {
+            directory,
+            policy,
+            requests: Some(requests),
+            writer: Some(writer),
+            dropped_records: 0,
+            queue_full: false,
+        })
+    }
+
+    /// Enumerate violation log files in `directory` in order of their sequence numbers, checking their integrity.
+    pub fn archives(directory: &Path) -> io::Result<Vec<ArchiveInfo>> {
+        list_files(directory)?
+            .into_iter()
+            .map(|(sequence, path)| {
+                let content = fs::read(&path)?;
+                Ok(ArchiveInfo {
+                    sequence,
+                    bytes: content.len() as u64,
+                    integrity: check_integrity(sequence, &content),
+                    path,
+                })
+            })
+            .collect()
+    }
+
+    /// Remove all but the `keep` most recent files from `directory`, regardless of their integrity,
+    /// so unsealed files left by terminated runs do not fill the storage, e.g. in a crash loop.
+    /// Must not be called for a directory written by a running sink, its current file may be removed.
+    /// Returns the number of removed files.
+    pub fn prune(directory: &Path, keep: usize) -> io::Result<usize> {
+        let files = list_files(directory)?;
+        let excess = files.len().saturating_sub(keep);
+        for (_, path) in &files[..excess] {
+            fs::remove_file(path)?;
+        }
+        Ok(excess)
+    }
+
+    /// Seal the current file, the next record starts a new one.
+    /// Waits for the writer thread to write all records reported so far.
+    pub fn rotate(&mut self) -> io::Result<()> {
+        let (reply, result) = sync_channel(1);
+        self.request(Request::Rotate(reply))?;
+        result.recv().map_err(|_| writer_stopped())?
+    }
+
+    /// Wait for the writer thread to write all records reported so far.
+    pub fn flush(&self) -> io::Result<()> {
+        let (reply, result) = sync_channel(1);
+        self.request(Request::Flush(reply))?;
+        result.recv().map_err(|_| writer_stopped())
+    }
+
+    /// Number of records dropped because the queue of the writer thread was full.
+    pub fn dropped_records(&self) -> u64 {
+        self.dropped_records
+    }
+
+    /// Send a blocking request to the writer thread.
+    fn request(&self, request: Request) -> io::Result<()> {
+        self.requests
+            .as_ref()
+            .ok_or_else(writer_stopped)?
+            .send(request)
+            .map_err(|_| writer_stopped())
+    }
+
+    fn persist(&mut self, record: &ViolationRecord, advisory_reason: Option<&str>) {
+        let Some(requests) = &self.requests else {
+            return;
+        };
+
+        match requests.try_send(Request::Record(format_record(record, advisory_reason))) {
+            Ok(()) => self.queue_full = false,
+            Err(TrySendError::Full(_)) => {
+                self.dropped_records += 1;
+                if !self.queue_full {
+                    error!("Violation could not be persisted, queue of the violation log writer is full.");
+                }
+                self.queue_full = true;
+            },
+            Err(TrySendError::Disconnected(_)) => {
+                error!("Violation could not be persisted, violation log writer stopped.");
+                self.requests = None;
+            },
+        }
+    }
+}
+
+impl ViolationSink for PersistentViolationSink {
+    fn on_violation(&mut self, record: &ViolationRecord) {
+        self.persist(record, None);
+    }
+
+    fn on_advisory_violation(&mut self, record: &ViolationRecord, reason: &str) {
+        self.persist(record, Some(reason));
+    }
+}
+
+impl Drop for PersistentViolationSink {
+    fn drop(&mut self) {
+        // Writer thread seals the current file once all queued records are written.
+        self.requests = None;
+        if let Some(writer) = self.writer.take() {
+            if writer.join().is_err() {
+                error!("Violation log writer panicked.");
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for PersistentViolationSink {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PersistentViolationSink")
+            .field("directory", &self.directory)
+            .field("policy", &self.policy)
+            .field("dropped_records", &self.dropped_records)
+            .finish()
+    }
+}
+
+/// File currently written by the writer thread.
+struct ActiveFile {
+    file: File,
+    sequence: u64,
+    bytes: u64,
+    records: u64,
+    /// CRC-32 state of the content written so far.
+    crc: u32,
+    created: Instant,
+}
+
+/// Writer thread of a [`PersistentViolationSink`], owning the files of its directory.
+struct ViolationLogWriter {
+    directory: PathBuf,
+    policy: RotationPolicy,
+    active: Option<ActiveFile>,
+    next_sequence: u64,
+}
+
+impl ViolationLogWriter {
+    /// Handle requests until the sink is dropped, then seal the current file.
+    fn run(mut self, requests: Receiver<Request>) {
+        for request in requests {
+            match request {
+                Request::Record(line) => {
+                    if let Err(e) = self.write_line(&line) {
+                        error!("Violation could not be persisted: {}.", e.to_string());
+                        // Start over with a new file, the failed one stays unsealed.
+                        self.active = None;
+                    }
+                },
+                Request::Rotate(reply) => {
+                    let _ = reply.send(self.rotate());
+                },
+                Request::Flush(reply) => {
+                    let _ = reply.send(());
+                },
+            }
+        }
+
+        if let Err(e) = self.rotate() {
+            error!("Violation log could not be sealed: {}.", e.to_string());
+        }
+    }
+
+    /// Seal the current file, the next record starts a new one.
+    fn rotate(&mut self) -> io::Result<()> {
+        let Some(mut active) = self.active.take() else {
+            return Ok(());
+        };
+        let trailer = format!("{TRAILER}{} crc={:08x}\n", active.records, !active.crc);
+        active.file.write_all(trailer.as_bytes())?;
+        active.file.sync_all()?;
+        info!(
+            "Violation log {} sealed with {} records.",
+            active.sequence, active.records
+        );
+
+        // No file is written until the next record, so all files can be pruned.
+        if let Some(max_archives) = self.policy.max_archives {
+            PersistentViolationSink::prune(&self.directory, max_archives)?;
+        }
+        Ok(())
+    }
+
+    fn open_next(&mut self) -> io::Result<&mut ActiveFile> {
+        let sequence = self.next_sequence;
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(file_path(&self.directory, sequence))?;
+        self.next_sequence = sequence.saturating_add(1);
+
+        let header = format!("{HEADER}{sequence}\n");
+        file.write_all(header.as_bytes())?;
+        Ok(self.active.insert(ActiveFile {
+            file,
+            sequence,
+            bytes: header.len() as u64,
+            records: 0,
+            crc: crc32_update(u32::MAX, header.as_bytes()),
+            created: Instant::now(),
+        }))
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let policy = self.policy;
+        let active = match self.active.as_mut() {
+            Some(active) => active,
+            None => self.open_next()?,
+        };
+        active.file.write_all(line.as_bytes())?;
+        active.bytes += line.len() as u64;
+        active.records += 1;
+        active.crc = crc32_update(active.crc, line.as_bytes());
+
+        let is_full = active.bytes >= policy.max_file_bytes;
+        let is_old = policy
+            .max_file_age
+            .is_some_and(|max_file_age| active.created.elapsed() >= max_file_age);
+        if is_full || is_old {
+            self.rotate()?;
+        }
+        Ok(())
+    }
+}
+
+fn writer_stopped() -> io::Error {
+    io::Error::other("violation log writer stopped")
+}
+
+/// Format `record` as a single line, text fields are escaped so each record is exactly one line.
+fn format_record(record: &ViolationRecord, advisory_reason: Option<&str>) -> String {
+    let mut line = format!(
+        "{} {} {:?}",
+        record.timestamp.as_millis(),
+        escape(record.monitor_tag.as_str()),
+        record.error
+    );
+    if let Some(deadline_tag) = record.deadline_tag {
+        line.push_str(&format!(" deadline={}", escape(deadline_tag.as_str())));
+    }
+    if let Some(source_tag) = record.source_tag {
+        line.push_str(&format!(" source={}", escape(source_tag.as_str())));
+    }
+    if let Some(offset) = record.offset {
+        line.push_str(&format!(" offset_ms={}", offset.as_millis()));
+    }
+    if let Some(correlation_id) = record.correlation_id {
+        line.push_str(&format!(" correlation={correlation_id}"));
+    }
+    if let Some(reason) = advisory_reason {
+        line.push_str(&format!(" advisory={}", escape(reason)));
+    }
+    line.push('\n');
+    line
+}
+
+/// Escape backslashes and line breaks of a text field.
+fn escape(field: &str) -> Cow<'_, str> {
+    if !field.contains(['\\', '\n', '\r']) {
+        return Cow::Borrowed(field);
+    }
+    let mut escaped = String::with_capacity(field.len() + 2);
+    for character in field.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            character => escaped.push(character),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+fn file_path(directory: &Path, sequence: u64) -> PathBuf {
+    directory.join(format!("{FILE_PREFIX}{sequence:010}.{FILE_EXTENSION}"))
+}
+
+/// Violation log files in `directory` with their sequence numbers, in order of the sequence numbers.
+fn list_files(directory: &Path) -> io::Result<Vec<(u64, PathBuf)>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let sequence = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(FILE_PREFIX))
+            .and_then(|name| name.strip_suffix(FILE_EXTENSION))
+            .and_then(|name| name.strip_suffix('.'))
+            .and_then(|sequence| sequence.parse::<u64>().ok());
+        if let Some(sequence) = sequence {
+            files.push((sequence, path));
+        }
+    }
+    files.sort_unstable();
+    Ok(files)
+}
+
+/// Check header and trailer of the file with `sequence` against its `content`.
+fn check_integrity(sequence: u64, content: &[u8]) -> ArchiveIntegrity {
+    let header = format!("{HEADER}{sequence}\n");
+    if !content.starts_with(header.as_bytes()) {
+        return ArchiveIntegrity::Corrupted;
+    }
+
+    // Trailer is the last line, content before it is covered by the CRC.
+    let body_end = content[..content.len().saturating_sub(1)]
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |position| position + 1);
+    let Some(trailer) = core::str::from_utf8(&content[body_end..])
+        .ok()
+        .and_then(|line| line.strip_prefix(TRAILER))
+    else {
+        return ArchiveIntegrity::Unsealed;
+    };
+
+    let body = &content[..body_end];
+    let records = body.iter().filter(|byte| **byte == b'\n').count() - 1;
+    let expected = format!("{records} crc={:08x}\n", crc32(body));
+    if trailer == expected {
+        ArchiveIntegrity::Valid
+    } else {
+        ArchiveIntegrity::Corrupted
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::MonitorEvaluationError;
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::tag::MonitorTag;
+    use crate::violation_history::ViolationRecord;
+    use crate::violation_persistence::{ArchiveIntegrity, PersistentViolationSink, RotationPolicy};
+    use crate::violation_sink::ViolationSink;
+    use core::time::Duration;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("hm_violation_persistence_{}_{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn record(timestamp_ms: u64) -> ViolationRecord {
+        ViolationRecord {
+            monitor_tag: MonitorTag::from("deadline_monitor"),
+            error: MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooLate),
+            timestamp: Duration::from_millis(timestamp_ms),
+            reporter: None,
+            correlation_id: None,
+            scheduling: None,
+            deadline_tag: None,
+            source_tag: None,
+            offset: None,
+        }
+    }
+
+    fn sequences(directory: &Path) -> Vec<(u64, ArchiveIntegrity)> {
+        PersistentViolationSink::archives(directory)
+            .unwrap()
+            .into_iter()
+            .map(|archive| (archive.sequence, archive.integrity))
+            .collect()
+    }
+
+    #[test]
+    fn persistent_violation_sink_rotates_by_size() {
+        let directory = directory("size");
+        let mut sink = PersistentViolationSink::new(&directory, RotationPolicy::new(100)).unwrap();
+        for timestamp_ms in 0..7 {
+            sink.on_violation(&record(timestamp_ms));
+        }
+        sink.flush().unwrap();
+        assert_eq!(
+            sequences(&directory),
+            vec![
+                (0, ArchiveIntegrity::Valid),
+                (1, ArchiveIntegrity::Valid),
+                (2, ArchiveIntegrity::Unsealed)
+            ]
+        );
+        let content = fs::read_to_string(directory.join("violations-0000000000.log")).unwrap();
+        assert!(content.starts_with("#hm-violations v1 seq=0\n0 deadline_monitor Deadline(TooLate)\n"));
+
+        // Sequence continues after files of the previous run.
+        drop(sink);
+        let mut sink = PersistentViolationSink::new(&directory, RotationPolicy::new(100)).unwrap();
+        sink.on_advisory_violation(&record(7), "startup grace period");
+        drop(sink);
+        assert_eq!(sequences(&directory).last(), Some(&(3, ArchiveIntegrity::Valid)));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn persistent_violation_sink_rotates_by_age_and_prunes() {
+        let directory = directory("age");
+        let policy = RotationPolicy::new(u64::MAX)
+            .with_max_file_age(Duration::from_millis(10))
+            .with_max_archives(2);
+        let mut sink = PersistentViolationSink::new(&directory, policy).unwrap();
+        for timestamp_ms in 0..4 {
+            sink.on_violation(&record(timestamp_ms));
+            std::thread::sleep(Duration::from_millis(20));
+            sink.on_violation(&record(timestamp_ms));
+        }
+        sink.flush().unwrap();
+        assert_eq!(
+            sequences(&directory),
+            vec![(2, ArchiveIntegrity::Valid), (3, ArchiveIntegrity::Valid)]
+        );
+        drop(sink);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn persistent_violation_sink_detects_corruption_and_prunes() {
+        let directory = directory("corruption");
+        let mut sink = PersistentViolationSink::new(&directory, RotationPolicy::new(1)).unwrap();
+        sink.on_violation(&record(1));
+        sink.on_violation(&record(2));
+        sink.on_violation(&record(3));
+        drop(sink);
+        fs::write(directory.join("violations-0000000003.log"), "#hm-violations v1 seq=3\n").unwrap();
+
+        let path = directory.join("violations-0000000001.log");
+        let content = fs::read_to_string(&path).unwrap().replace("TooLate", "TooEarly");
+        fs::write(&path, content).unwrap();
+        assert_eq!(
+            sequences(&directory),
+            vec![
+                (0, ArchiveIntegrity::Valid),
+                (1, ArchiveIntegrity::Corrupted),
+                (2, ArchiveIntegrity::Valid),
+                (3, ArchiveIntegrity::Unsealed)
+            ]
+        );
+
+        assert_eq!(PersistentViolationSink::prune(&directory, 2).unwrap(), 2);
+        assert_eq!(
+            sequences(&directory),
+            vec![(2, ArchiveIntegrity::Valid), (3, ArchiveIntegrity::Unsealed)]
+        );
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn persistent_violation_sink_prunes_unsealed_files_of_terminated_runs() {
+        let directory = directory("crash_loop");
+        fs::create_dir_all(&directory).unwrap();
+        for sequence in 0..5 {
+            fs::write(
+                directory.join(format!("violations-{sequence:010}.log")),
+                format!("#hm-violations v1 seq={sequence}\n"),
+            )
+            .unwrap();
+        }
+
+        // Each run of a crash loop leaves an unsealed file, they are retained up to the limit.
+        let policy = RotationPolicy::new(1).with_max_archives(2);
+        let mut sink = PersistentViolationSink::new(&directory, policy).unwrap();
+        sink.on_violation(&record(1));
+        sink.flush().unwrap();
+        assert_eq!(
+            sequences(&directory),
+            vec![(4, ArchiveIntegrity::Unsealed), (5, ArchiveIntegrity::Valid)]
+        );
+        drop(sink);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn persistent_violation_sink_escapes_line_breaks() {
+        let directory = directory("escape");
+        let mut sink = PersistentViolationSink::new(&directory, RotationPolicy::default()).unwrap();
+        sink.on_advisory_violation(&record(1), "startup\ngrace period");
+        sink.rotate().unwrap();
+        assert_eq!(sequences(&directory), vec![(0, ArchiveIntegrity::Valid)]);
+        let content = fs::read_to_string(directory.join("violations-0000000000.log")).unwrap();
+        assert!(content.contains(" advisory=startup\\ngrace period\n#end records=1 "));
+        drop(sink);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,198 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::common::MonitorEvaluationError;
+use crate::log::{error, warn};
+use crate::violation_history::ViolationRecord;
+use core::panic::AssertUnwindSafe;
+use std::panic::catch_unwind;
+
+/// Consumer of monitor violations, e.g. a logger, a DLT or persistence writer, or a GPIO line.
+/// All sinks added to the builder receive every violation, see [`crate::HealthMonitorBuilder::add_violation_sink`].
+pub trait ViolationSink: Send {
+    /// Handle a violation reported by a monitor.
+    /// Called from the monitoring thread before the violation is handled by failure tolerance and recovery.
+    fn on_violation(&mut self, record: &ViolationRecord);
+
+    /// Handle a violation which is advisory, e.g. during the startup grace period.
+    /// Advisory violations are not handled further. Ignored by default.
+    ///
+    /// - `reason` - why the violation is advisory, e.g. `startup grace period`.
+    fn on_advisory_violation(&mut self, record: &ViolationRecord, reason: &str) {
+        let _ = (record, reason);
+    }
+}
+
+/// Reference [`ViolationSink`] logging violations through the library logger, added by default.
+#[derive(Debug, Default)]
+pub struct LoggingViolationSink;
+
+impl ViolationSink for LoggingViolationSink {
+    fn on_violation(&mut self, record: &ViolationRecord) {
+        let monitor_tag = record.monitor_tag;
+        match record.error {
+            MonitorEvaluationError::Deadline(deadline_evaluation_error) => {
+                warn!(
+                    "Deadline monitor with tag {:?} reported error: {:?}.",
+                    monitor_tag, deadline_evaluation_error
+                )
+            },
+            MonitorEvaluationError::Heartbeat(heartbeat_evaluation_error) => {
+                warn!(
+                    "Heartbeat monitor with tag {:?} reported error: {:?}.",
+                    monitor_tag, heartbeat_evaluation_error
+                )
+            },
+            MonitorEvaluationError::Artifact(artifact_evaluation_error) => {
+                warn!(
+                    "Artifact freshness monitor with tag {:?} reported error: {:?}.",
+                    monitor_tag, artifact_evaluation_error
+                )
+            },
+            MonitorEvaluationError::Logic => {
+                warn!(
+                    "Logic monitor with tag {:?} reported invalid state transition.",
+                    monitor_tag
+                )
+            },
+        }
+        if let Some(offset) = record.offset {
+            match record.deadline_tag {
+                Some(deadline_tag) => warn!(
+                    "Monitor with tag {:?} violated range of deadline {:?} by {} ms.",
+                    monitor_tag,
+                    deadline_tag,
+                    offset.as_millis() as u64
+                ),
+                None => warn!(
+                    "Monitor with tag {:?} violated range by {} ms.",
+                    monitor_tag,
+                    offset.as_millis() as u64
+                ),
+            }
+        }
+        if let Some(source_tag) = record.source_tag {
+            warn!(
+                "Monitor with tag {:?} reported silent heartbeat source {:?}.",
+                monitor_tag, source_tag
+            );
+        }
+        if let Some(reporter) = record.reporter {
+            warn!(
+                "Monitor with tag {:?} last reported by thread {}.",
+                monitor_tag, reporter.tid
+            );
+        }
+        if let Some(correlation_id) = record.correlation_id {
+            warn!(
+                "Monitor with tag {:?} violated activation with correlation ID {}.",
+                monitor_tag, correlation_id
+            );
+        }
+        if let Some(scheduling) = record.scheduling {
+            warn!(
+                "Monitor with tag {:?} violated activation ran {} ms, waited for CPU {} ms, preempted {} times.",
+                monitor_tag,
+                scheduling.run_time.as_millis() as u64,
+                scheduling.run_delay.as_millis() as u64,
+                scheduling.involuntary_switches
+            );
+        }
+    }
+
+    fn on_advisory_violation(&mut self, record: &ViolationRecord, reason: &str) {
+        warn!(
+            "Monitor with tag {:?} reported error during {}, error is advisory: {:?}.",
+            record.monitor_tag, reason, record.error
+        );
+    }
+}
+
+/// Sinks receiving every violation, invoked in order they were added.
+pub(crate) struct ViolationFanOut {
+    sinks: Vec<Box<dyn ViolationSink>>,
+}
+
+impl ViolationFanOut {
+    pub(crate) fn new(sinks: Vec<Box<dyn ViolationSink>>) -> Self {
+        Self { sinks }
+    }
+
+    /// Invoke `f` for every sink, containing their panics, so a panicking sink does not starve the others.
+    /// Returns `false` if any sink panicked.
+    pub(crate) fn dispatch(&mut self, mut f: impl FnMut(&mut dyn ViolationSink)) -> bool {
+        let mut all_succeeded = true;
+        for (index, sink) in self.sinks.iter_mut().enumerate() {
+            if catch_unwind(AssertUnwindSafe(|| f(sink.as_mut()))).is_err() {
+                error!("Violation sink {} panicked.", index);
+                all_succeeded = false;
+            }
+        }
+        all_succeeded
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::tag::MonitorTag;
+    use crate::violation_history::ViolationRecord;
+    use crate::violation_sink::{LoggingViolationSink, ViolationFanOut, ViolationSink};
+    use core::time::Duration;
+    use std::sync::{Arc, Mutex};
+
+    struct RecordingSink(Arc<Mutex<Vec<MonitorTag>>>);
+
+    impl ViolationSink for RecordingSink {
+        fn on_violation(&mut self, record: &ViolationRecord) {
+            self.0.lock().unwrap().push(record.monitor_tag);
+        }
+    }
+
+    struct PanickingSink;
+
+    impl ViolationSink for PanickingSink {
+        fn on_violation(&mut self, _record: &ViolationRecord) {
+            panic!("sink failed");
+        }
+    }
+
+    #[test]
+    fn violation_fan_out_dispatches_to_all_sinks() {
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let mut fan_out = ViolationFanOut::new(vec![
+            Box::new(LoggingViolationSink),
+            Box::new(PanickingSink),
+            Box::new(RecordingSink(recorded.clone())),
+        ]);
+        let record = ViolationRecord {
+            monitor_tag: MonitorTag::from("monitor"),
+            error: DeadlineEvaluationError::TooLate.into(),
+            timestamp: Duration::from_millis(10),
+            reporter: None,
+            correlation_id: Some(7),
+            scheduling: None,
+            deadline_tag: None,
+            source_tag: None,
+            offset: None,
+        };
+
+        // Panicking sink does not prevent dispatch to the following sinks.
+        assert!(!fan_out.dispatch(|sink| sink.on_violation(&record)));
+        assert_eq!(*recorded.lock().unwrap(), vec![MonitorTag::from("monitor")]);
+
+        // Advisory violations are ignored by default.
+        assert!(fan_out.dispatch(|sink| sink.on_advisory_violation(&record, "startup grace period")));
+        assert_eq!(recorded.lock().unwrap().len(), 1);
+    }
+}
@@ -10,20 +10,156 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
-use crate::common::{MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator};
-use crate::log::{info, warn};
+use crate::armed_start::ArmGuard;
+use crate::challenge::{ChallengeHandle, ChallengeResponse};
+use crate::common::{InternalFault, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator};
+use crate::fail_fast::FailFastPolicy;
+use crate::failure_statistics::FailureStatistics;
+use crate::group::GroupTracker;
+use crate::handoff_queue::HandoffQueue;
+use crate::health_forwarder::{ForwardedStatus, UdpHealthForwarder};
+use crate::health_status::StatusBoard;
+use crate::health_trajectory::{HealthState, TrajectoryGuard};
+use crate::log::{error, info, warn, ScoreDebug};
+use crate::signal_export::SignalExport;
 use crate::supervisor_api_client::SupervisorAPIClient;
+use crate::tag::{ChannelTag, MonitorTag};
+use crate::tolerance::{RecoveryPolicy, RecoveryTracker, ToleranceTracker};
+use crate::update_mode::UpdateModeGuard;
+use crate::violation_history::{InternalFaultRecord, ViolationHistory, ViolationRecord};
+use crate::violation_sink::{LoggingViolationSink, ViolationFanOut, ViolationSink};
+use crate::worker_supervision::{WorkerFault, WorkerLiveness, WorkerWatchdog};
 use containers::fixed_capacity::FixedCapacityVec;
+use core::cell::Cell;
+use core::panic::AssertUnwindSafe;
 use core::sync::atomic::{AtomicBool, Ordering};
 use core::time::Duration;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::panic::catch_unwind;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+/// Callback invoked from the monitoring logic on every monitor violation.
+/// Shared, so it outlives monitoring logic recreated on restart.
+pub(super) type FailureCallback = Arc<Mutex<dyn FnMut(&MonitorTag, MonitorEvaluationError) + Send>>;
+
+/// Signal export updated from the monitoring logic.
+/// Shared, so it outlives monitoring logic recreated on restart.
+pub(super) type SharedSignalExport = Arc<Mutex<SignalExport>>;
+
+/// Sinks receiving every violation from the monitoring logic.
+/// Shared, so they outlive monitoring logic recreated on restart.
+pub(super) type SharedViolationSinks = Arc<Mutex<ViolationFanOut>>;
+
+/// Callback invoked from the watchdog thread when the monitoring thread stalls or panics.
+pub(super) type WorkerFaultCallback = Arc<Mutex<dyn FnMut(WorkerFault) + Send>>;
+
+std::thread_local! {
+    /// Application callback is running on this thread.
+    static IN_MONITORING_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks the current thread as running an application callback until dropped, also when the callback panics.
+pub(crate) struct MonitoringCallbackScope {
+    previous: bool,
+}
+
+impl MonitoringCallbackScope {
+    pub(crate) fn enter() -> Self {
+        Self {
+            previous: IN_MONITORING_CALLBACK.replace(true),
+        }
+    }
+}
+
+impl Drop for MonitoringCallbackScope {
+    fn drop(&mut self) {
+        IN_MONITORING_CALLBACK.set(self.previous);
+    }
+}
+
+/// Check if the current thread is running an application callback invoked by the monitoring logic,
+/// i.e. the failure callback, a signal sink, a violation sink or a deadline callback.
+pub(super) fn in_monitoring_callback() -> bool {
+    IN_MONITORING_CALLBACK.get()
+}
+
+/// Supervisor notification channel with assigned monitors.
+pub(super) struct NotificationChannel {
+    channel_tag: ChannelTag,
+    monitor_tags: Vec<MonitorTag>,
+    /// Assigned monitor reported an error, channel is no longer notified.
+    failed: bool,
+}
+
+impl NotificationChannel {
+    pub(super) fn new(channel_tag: ChannelTag, monitor_tags: Vec<MonitorTag>) -> Self {
+        Self {
+            channel_tag,
+            monitor_tags,
+            failed: false,
+        }
+    }
+}
+
 pub(super) struct MonitoringLogic<T: SupervisorAPIClient> {
     monitors: FixedCapacityVec<MonitorEvalHandle>,
     client: T,
     last_notification: Instant,
     supervisor_api_cycle: Duration,
+    violation_history: Arc<ViolationHistory>,
+    channels: Vec<NotificationChannel>,
+    /// Maximum duration of a single evaluation pass, unbounded if [`None`].
+    evaluation_budget: Option<Duration>,
+    /// Index of the monitor evaluated first in the next pass.
+    /// Moved to the first skipped monitor, so no monitor is starved by a slow one.
+    next_monitor: usize,
+    /// Board to publish health status on, not published if [`None`].
+    status_board: Option<Arc<StatusBoard>>,
+    /// Monitors which reported errors since the loop was started.
+    failing_mask: Box<[u64]>,
+    failure_callback: Option<FailureCallback>,
+    /// Maximum duration of a single failure callback invocation, longer invocations are reported as internal faults.
+    failure_callback_budget: Duration,
+    /// Monitors added while the loop is running, appended to [`Self::monitors`] before each pass.
+    runtime_monitors: Option<Arc<HandoffQueue<MonitorEvalHandle>>>,
+    /// Delay of an evaluation pass treated as a clock gap, gaps are not detected if [`None`].
+    clock_gap_threshold: Option<Duration>,
+    /// Sinks receiving every violation, logging only unless configured.
+    violation_sinks: SharedViolationSinks,
+    /// Export of monitor statuses as signals, not exported if [`None`].
+    signal_export: Option<SharedSignalExport>,
+    /// Per-monitor failure counters, not counted if [`None`].
+    failure_statistics: Option<Arc<FailureStatistics>>,
+    /// Violations tolerated before the process is treated as failed.
+    tolerance: ToleranceTracker,
+    /// Failed monitors waiting for recovery, notifications resume once none is left.
+    recovery: RecoveryTracker,
+    /// Forwarder of health summaries to an aggregator, not forwarded if [`None`].
+    health_forwarder: Option<Arc<UdpHealthForwarder>>,
+    last_forward: Instant,
+    /// Groups reporting violations of their members as a single monitor.
+    groups: Vec<GroupTracker>,
+    /// Update mode making violations of its advisory monitors advisory while active.
+    update_mode: Option<Arc<UpdateModeGuard>>,
+    /// Liveness of the loop published for self-supervision, not published if [`None`].
+    liveness: Option<Arc<WorkerLiveness>>,
+    /// Policy terminating the process on selected failures, not applied if [`None`].
+    fail_fast: Option<FailFastPolicy>,
+    /// Terminates the process, replaced in tests.
+    exit_process: fn(i32),
+    /// Period after the start during which violations are advisory, no grace if [`None`].
+    startup_grace: Option<Duration>,
+    /// Two-stage start making violations advisory while armed.
+    armed_start: Option<Arc<ArmGuard>>,
+    /// Logic monitor checking transitions of the aggregate health, not published if [`None`].
+    health_trajectory: Option<Arc<TrajectoryGuard>>,
+    /// Challenges triggering out-of-cycle evaluations, not accepted if [`None`].
+    challenge: Option<ChallengeHandle>,
+    /// No monitor failed and no monitor was skipped in the last evaluation pass.
+    last_pass_healthy: bool,
+    /// Orderly shutdown was begun, not observed if [`None`].
+    shutdown: Option<Arc<AtomicBool>>,
 }
 
 impl<T: SupervisorAPIClient> MonitoringLogic<T> {
@@ -32,111 +168,913 @@ impl<T: SupervisorAPIClient> MonitoringLogic<T> {
     /// * `monitors` - A vector of monitor evaluation handles.
     /// * `supervisor_api_cycle` - Duration between alive notifications to the supervisor.
     /// * `client` - An implementation of the SupervisorAPIClient trait.
+    /// * `violation_history` - History to store reported violations in.
     pub(super) fn new(
         monitors: FixedCapacityVec<MonitorEvalHandle>,
         supervisor_api_cycle: Duration,
         client: T,
+        violation_history: Arc<ViolationHistory>,
     ) -> Self {
         Self {
             monitors,
             client,
             supervisor_api_cycle,
             last_notification: Instant::now(),
+            violation_history,
+            channels: Vec::new(),
+            evaluation_budget: None,
+            next_monitor: 0,
+            status_board: None,
+            failing_mask: Box::new([]),
+            failure_callback: None,
+            failure_callback_budget: Duration::MAX,
+            runtime_monitors: None,
+            clock_gap_threshold: None,
+            violation_sinks: Arc::new(Mutex::new(ViolationFanOut::new(vec![Box::new(LoggingViolationSink)]))),
+            signal_export: None,
+            failure_statistics: None,
+            tolerance: ToleranceTracker::new(None, HashMap::new()),
+            recovery: RecoveryTracker::new(RecoveryPolicy::Never),
+            health_forwarder: None,
+            last_forward: Instant::now(),
+            groups: Vec::new(),
+            update_mode: None,
+            liveness: None,
+            fail_fast: None,
+            exit_process,
+            startup_grace: None,
+            armed_start: None,
+            health_trajectory: None,
+            challenge: None,
+            last_pass_healthy: true,
+            shutdown: None,
+        }
+    }
+
+    /// Recover from monitor failures as configured by `recovery`, instead of stopping monitoring.
+    /// Process and channel notifications are suspended while their monitors are failed.
+    pub(super) fn with_recovery(mut self, recovery: RecoveryTracker) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Forward health summary every supervisor API cycle and when monitoring fails.
+    pub(super) fn with_health_forwarder(mut self, health_forwarder: Arc<UdpHealthForwarder>) -> Self {
+        self.health_forwarder = Some(health_forwarder);
+        self
+    }
+
+    /// Tolerate monitor violations as configured in `tolerance`, before stopping supervisor notifications.
+    pub(super) fn with_failure_tolerance(mut self, tolerance: ToleranceTracker) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Count violations of every monitor in `failure_statistics`.
+    pub(super) fn with_failure_statistics(mut self, failure_statistics: Arc<FailureStatistics>) -> Self {
+        self.failure_statistics = Some(failure_statistics);
+        self
+    }
+
+    /// Dispatch every violation to `violation_sinks`, replacing the default logging sink.
+    pub(super) fn with_violation_sinks(mut self, violation_sinks: SharedViolationSinks) -> Self {
+        self.violation_sinks = violation_sinks;
+        self
+    }
+
+    /// Export statuses of monitors selected by `signal_export` after every evaluation pass.
+    pub(super) fn with_signal_export(mut self, signal_export: SharedSignalExport) -> Self {
+        self.signal_export = Some(signal_export);
+        self
+    }
+
+    /// Detect clock gaps, e.g. after system suspend.
+    /// Pass delayed by more than `clock_gap_threshold` is skipped and all monitors are re-baselined,
+    /// so violations caused by the gap are replaced by a single [`InternalFault::ClockGapDetected`].
+    pub(super) fn with_clock_gap_threshold(mut self, clock_gap_threshold: Duration) -> Self {
+        self.clock_gap_threshold = Some(clock_gap_threshold);
+        self
+    }
+
+    /// Take monitors added at runtime from the `runtime_monitors` queue.
+    /// Capacity of the monitors vector must include slots for runtime monitors.
+    pub(super) fn with_runtime_monitors(mut self, runtime_monitors: Arc<HandoffQueue<MonitorEvalHandle>>) -> Self {
+        self.runtime_monitors = Some(runtime_monitors);
+        self
+    }
+
+    /// Invoke `failure_callback` on every monitor violation, after it is logged and stored in the history.
+    /// Panics of the callback are caught, they and invocations exceeding `budget` are reported as internal faults.
+    pub(super) fn with_failure_callback(mut self, failure_callback: FailureCallback, budget: Duration) -> Self {
+        self.failure_callback = Some(failure_callback);
+        self.failure_callback_budget = budget;
+        self
+    }
+
+    /// Publish health status on the `status_board` after every evaluation pass.
+    pub(super) fn with_status_board(mut self, status_board: Arc<StatusBoard>) -> Self {
+        self.failing_mask = status_board.empty_mask();
+        self.status_board = Some(status_board);
+        self
+    }
+
+    /// Limit the duration of a single evaluation pass.
+    /// Evaluation of a monitor is not interrupted, budget is checked before evaluating each monitor.
+    /// Monitors remaining after the budget is exceeded are skipped and reported as internal faults.
+    /// Pass with skipped monitors does not notify the supervisor, as liveness of skipped monitors is unknown.
+    pub(super) fn with_evaluation_budget(mut self, evaluation_budget: Duration) -> Self {
+        self.evaluation_budget = Some(evaluation_budget);
+        self
+    }
+
+    /// Partition notifications into channels.
+    /// Errors of monitors assigned to a channel stop notifications of that channel only.
+    /// Errors of unassigned monitors stop all notifications.
+    pub(super) fn with_channels(mut self, channels: Vec<NotificationChannel>) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Aggregate violations of group members into failures of their `groups`.
+    /// Violations of members are logged and stored in the history, other handling applies to group failures only.
+    pub(super) fn with_groups(mut self, groups: Vec<GroupTracker>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// Handle violations of advisory monitors of the active `update_mode` as advisory.
+    /// Advisory violations are logged and stored in the history only.
+    /// Update window is supervised by the guard, which must be evaluated as one of the monitors.
+    pub(super) fn with_update_mode(mut self, update_mode: Arc<UpdateModeGuard>) -> Self {
+        self.update_mode = Some(update_mode);
+        self
+    }
+
+    /// Publish liveness of the loop on `liveness`, updated on every evaluation cycle.
+    pub(super) fn with_worker_liveness(mut self, liveness: Arc<WorkerLiveness>) -> Self {
+        self.liveness = Some(liveness);
+        self
+    }
+
+    /// Handle violations reported within `startup_grace` after the start of the loop as advisory.
+    /// Advisory violations are logged and stored in the history only.
+    pub(super) fn with_startup_grace(mut self, startup_grace: Duration) -> Self {
+        self.startup_grace = Some(startup_grace);
+        self
+    }
+
+    /// Handle violations reported while `armed_start` is armed as advisory.
+    /// Advisory violations are logged and stored in the history only.
+    /// Time from arming to go is supervised by the guard, which must be evaluated as one of the monitors.
+    pub(super) fn with_armed_start(mut self, armed_start: Arc<ArmGuard>) -> Self {
+        self.armed_start = Some(armed_start);
+        self
+    }
+
+    /// Publish aggregate health of every evaluation pass to `health_trajectory`.
+    /// Transitions are checked by the guard, which must be evaluated as one of the monitors.
+    pub(super) fn with_health_trajectory(mut self, health_trajectory: Arc<TrajectoryGuard>) -> Self {
+        self.health_trajectory = Some(health_trajectory);
+        self
+    }
+
+    /// Answer challenges issued with `challenge` by an immediate out-of-cycle evaluation pass.
+    /// Out-of-cycle passes count as regular cycles for failure tolerance and recovery.
+    pub(super) fn with_challenge(mut self, challenge: ChallengeHandle) -> Self {
+        self.challenge = Some(challenge);
+        self
+    }
+
+    /// Stop the loop with a final stopping notification once `shutdown` is set.
+    pub(super) fn with_shutdown(mut self, shutdown: Arc<AtomicBool>) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Terminate the process on failures selected by `fail_fast`.
+    pub(super) fn with_fail_fast(mut self, fail_fast: FailFastPolicy) -> Self {
+        self.fail_fast = Some(fail_fast);
+        self
+    }
+
+    /// Replace termination of the process, so fail-fast can be tested.
+    #[cfg(test)]
+    pub(super) fn with_exit_process(mut self, exit_process: fn(i32)) -> Self {
+        self.exit_process = exit_process;
+        self
+    }
+
+    /// Append monitors added at runtime since the previous pass.
+    fn take_runtime_monitors(&mut self, hmon_starting_point: Instant) {
+        let Some(runtime_monitors) = &self.runtime_monitors else {
+            return;
+        };
+
+        for monitor in runtime_monitors.take_all() {
+            let monitor_tag = monitor.monitor_tag();
+            if self.monitors.push(monitor).is_err() {
+                // Should not fail - capacity was reserved by the resource budget.
+                report_internal_fault(
+                    &self.violation_history,
+                    Some(monitor_tag),
+                    InternalFault::MonitorHandlePush,
+                    hmon_starting_point,
+                );
+                continue;
+            }
+            info!("Monitor with tag {:?} added at runtime.", monitor_tag);
         }
     }
 
     fn run(&mut self, hmon_starting_point: Instant) -> bool {
+        self.take_runtime_monitors(hmon_starting_point);
+        self.tolerance.start_cycle();
+        self.recovery.start_cycle();
+        for group in self.groups.iter_mut() {
+            group.start_cycle();
+        }
+
         let mut has_any_error = false;
+        let mut has_violations = false;
+        let mut fail_fast_failure = None;
+        let pass_start = Instant::now();
+        let in_startup_grace = self
+            .startup_grace
+            .is_some_and(|startup_grace| hmon_starting_point.elapsed() < startup_grace);
+        let num_monitors = self.monitors.len();
+        let first_monitor = self.next_monitor;
+        let mut skipped_monitors = 0;
 
-        for monitor in self.monitors.iter() {
-            monitor.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
-                has_any_error = true;
-
-                match error {
-                    MonitorEvaluationError::Deadline(deadline_evaluation_error) => {
-                        warn!(
-                            "Deadline monitor with tag {:?} reported error: {:?}.",
-                            monitor_tag, deadline_evaluation_error
-                        )
-                    },
-                    MonitorEvaluationError::Heartbeat(heartbeat_evaluation_error) => {
-                        warn!(
-                            "Heartbeat monitor with tag {:?} reported error: {:?}.",
-                            monitor_tag, heartbeat_evaluation_error
-                        )
+        let ordered_monitors = self
+            .monitors
+            .iter()
+            .skip(first_monitor)
+            .chain(self.monitors.iter().take(first_monitor));
+        for (position, monitor) in ordered_monitors.enumerate() {
+            if self
+                .evaluation_budget
+                .is_some_and(|budget| pass_start.elapsed() > budget)
+            {
+                if skipped_monitors == 0 {
+                    self.next_monitor = (first_monitor + position) % num_monitors;
+                }
+                skipped_monitors += 1;
+                report_internal_fault(
+                    &self.violation_history,
+                    Some(monitor.monitor_tag()),
+                    InternalFault::EvaluationSkipped,
+                    hmon_starting_point,
+                );
+                continue;
+            }
+
+            monitor.evaluate(hmon_starting_point, &mut |monitor_tag, error, context| {
+                let advisory_reason = if in_startup_grace {
+                    Some("startup grace period")
+                } else if self
+                    .armed_start
+                    .as_ref()
+                    .is_some_and(|armed_start| armed_start.is_armed())
+                {
+                    Some("armed start")
+                } else if self
+                    .update_mode
+                    .as_ref()
+                    .is_some_and(|update_mode| update_mode.is_advisory(monitor_tag))
+                {
+                    Some("update mode")
+                } else {
+                    None
+                };
+                let timestamp = hmon_starting_point.elapsed();
+                let record = ViolationRecord {
+                    monitor_tag: *monitor_tag,
+                    error,
+                    timestamp,
+                    reporter: context.reporter,
+                    correlation_id: context.correlation_id,
+                    scheduling: context.scheduling,
+                    deadline_tag: context.deadline_tag,
+                    source_tag: context.source_tag,
+                    offset: context.offset,
+                };
+                if let Some(advisory_reason) = advisory_reason {
+                    if !dispatch_violation(&self.violation_sinks, |sink| {
+                        sink.on_advisory_violation(&record, advisory_reason)
+                    }) {
+                        report_internal_fault(
+                            &self.violation_history,
+                            Some(*monitor_tag),
+                            InternalFault::CallbackPanicked,
+                            hmon_starting_point,
+                        );
+                    }
+                    self.violation_history.push(record);
+                    return;
+                }
+                has_violations = true;
+                if let Some(status_board) = &self.status_board {
+                    status_board.mark_failing(&mut self.failing_mask, monitor_tag);
+                }
+                with_signal_export(&self.signal_export, |signal_export| {
+                    signal_export.mark_failing(monitor_tag)
+                });
+                if !dispatch_violation(&self.violation_sinks, |sink| sink.on_violation(&record)) {
+                    report_internal_fault(
+                        &self.violation_history,
+                        Some(*monitor_tag),
+                        InternalFault::CallbackPanicked,
+                        hmon_starting_point,
+                    );
+                }
+
+                // Violations of group members are handled as failures of their group.
+                let failed_tag = match self.groups.iter_mut().find(|group| group.contains(monitor_tag)) {
+                    Some(group) => {
+                        self.violation_history.push(record);
+                        if !group.record_violation(monitor_tag) {
+                            return;
+                        }
+                        warn!("Monitor group with tag {:?} failed.", group.monitor_tag());
+                        group.monitor_tag()
                     },
-                    MonitorEvaluationError::Logic => unimplemented!(),
+                    None => *monitor_tag,
+                };
+
+                if self.tolerance.record_violation(failed_tag) {
+                    if self
+                        .fail_fast
+                        .as_ref()
+                        .is_some_and(|fail_fast| fail_fast.applies_to(&failed_tag, error))
+                    {
+                        fail_fast_failure.get_or_insert((failed_tag, error));
+                    }
+                    self.recovery.record_failure(failed_tag);
+                    match self
+                        .channels
+                        .iter_mut()
+                        .find(|channel| channel.monitor_tags.contains(&failed_tag))
+                    {
+                        Some(channel) => {
+                            if !channel.failed {
+                                warn!(
+                                    "Monitor with tag {:?} failed, stopping notifications of channel {:?}.",
+                                    failed_tag, channel.channel_tag
+                                );
+                            }
+                            channel.failed = true;
+                        },
+                        None => has_any_error = true,
+                    }
                 }
+                self.violation_history.push(ViolationRecord {
+                    monitor_tag: failed_tag,
+                    ..record
+                });
+                if let Some(failure_statistics) = &self.failure_statistics {
+                    failure_statistics.record(failed_tag, error, timestamp, record.offset);
+                }
+                if let Some(failure_callback) = &self.failure_callback {
+                    if let Some(fault) =
+                        invoke_failure_callback(failure_callback, self.failure_callback_budget, &failed_tag, error)
+                    {
+                        report_internal_fault(&self.violation_history, Some(failed_tag), fault, hmon_starting_point);
+                    }
+                }
+            });
+
+            monitor.collect_internal_faults(&mut |monitor_tag, fault| {
+                // Corrupted state cannot be evaluated, supervised application liveness is unknown.
+                if fault == InternalFault::StateCorruption {
+                    has_any_error = true;
+                    has_violations = true;
+                    self.recovery.record_failure(*monitor_tag);
+                    if let Some(status_board) = &self.status_board {
+                        status_board.mark_failing(&mut self.failing_mask, monitor_tag);
+                    }
+                    with_signal_export(&self.signal_export, |signal_export| {
+                        signal_export.mark_failing(monitor_tag)
+                    });
+                }
+                report_internal_fault(&self.violation_history, Some(*monitor_tag), fault, hmon_starting_point);
             });
         }
 
-        if !has_any_error {
-            if self.last_notification.elapsed() > self.supervisor_api_cycle {
-                self.last_notification = Instant::now();
-                self.client.notify_alive();
-            }
-        } else {
-            warn!("One or more monitors reported errors, skipping AliveAPI notification.");
+        let has_new_failure = has_any_error;
+        if self.recovery.is_enabled() {
+            has_any_error |= self.apply_recovery();
+        }
+        self.last_pass_healthy = !has_any_error && skipped_monitors == 0;
+        if let Some(health_trajectory) = &self.health_trajectory {
+            health_trajectory.publish(if has_new_failure {
+                HealthState::Failed
+            } else if has_any_error {
+                HealthState::Recovering
+            } else if has_violations {
+                HealthState::Degraded
+            } else {
+                HealthState::Healthy
+            });
+        }
+
+        if let Some(status_board) = &self.status_board {
+            status_board.publish(&self.failing_mask);
+        }
+        with_signal_export(&self.signal_export, SignalExport::publish_changes);
+        if has_any_error || self.last_forward.elapsed() > self.supervisor_api_cycle {
+            self.forward_health(hmon_starting_point, has_any_error);
+        }
+
+        if let Some((monitor_tag, error)) = fail_fast_failure {
+            self.fail_fast(monitor_tag, error);
             return false;
         }
 
+        if has_any_error {
+            if !self.recovery.is_enabled() {
+                warn!("One or more monitors reported errors, skipping AliveAPI notification.");
+                return false;
+            }
+            warn!("One or more monitors failed, skipping AliveAPI notification until they recover.");
+        }
+
+        if skipped_monitors > 0 {
+            warn!(
+                "Evaluation pass exceeded its budget, {} monitors skipped, skipping AliveAPI notification.",
+                skipped_monitors
+            );
+            return true;
+        }
+        self.next_monitor = 0;
+        if has_any_error {
+            return true;
+        }
+
+        if self.last_notification.elapsed() > self.supervisor_api_cycle {
+            self.last_notification = Instant::now();
+            if !self.client.notify_alive() {
+                report_internal_fault(
+                    &self.violation_history,
+                    None,
+                    InternalFault::NotifyFailure,
+                    hmon_starting_point,
+                );
+            }
+            for channel in self.channels.iter().filter(|channel| !channel.failed) {
+                if !self.client.notify_channel_alive(channel.channel_tag) {
+                    error!("Failed to notify channel {:?}.", channel.channel_tag);
+                    report_internal_fault(
+                        &self.violation_history,
+                        None,
+                        InternalFault::NotifyFailure,
+                        hmon_starting_point,
+                    );
+                }
+            }
+        }
+
         true
     }
+
+    /// Run evaluation cycles every `interval` until `stop_token` is stopped or monitoring fails.
+    /// On failure published status remains degraded, as monitoring did not stop on request.
+    pub(super) fn run_loop(&mut self, interval: Duration, stop_token: &StopToken) -> RunExit {
+        let hmon_starting_point = Instant::now();
+        let mut next_sleep_time = interval;
+        self.failing_mask.fill(0);
+        if let Some(status_board) = &self.status_board {
+            status_board.set_running();
+        }
+        with_signal_export(&self.signal_export, SignalExport::reset);
+        if let Some(liveness) = &self.liveness {
+            liveness.set_running();
+        }
+
+        let mut last_pass = hmon_starting_point;
+        while !stop_token.is_stopped() {
+            let challenge_id = match &self.challenge {
+                Some(challenge) => challenge.wait(next_sleep_time),
+                None => {
+                    std::thread::sleep(next_sleep_time);
+                    None
+                },
+            };
+            if let Some(liveness) = &self.liveness {
+                liveness.record_cycle();
+            }
+
+            if self
+                .shutdown
+                .as_ref()
+                .is_some_and(|shutdown| shutdown.load(Ordering::Acquire))
+            {
+                info!("Orderly shutdown begun, stopping.");
+                if !self.client.notify_stopping() {
+                    error!("Failed to notify the supervisor the process is stopping.");
+                }
+                break;
+            }
+
+            let now = Instant::now();
+            let delay = now.duration_since(last_pass).saturating_sub(interval);
+            last_pass = now;
+
+            if self.clock_gap_threshold.is_some_and(|threshold| delay > threshold) {
+                self.handle_clock_gap(hmon_starting_point, delay);
+                // Challenge is answered by the next pass.
+                if let (Some(challenge), Some(challenge_id)) = (&self.challenge, challenge_id) {
+                    challenge.challenge(challenge_id);
+                }
+                next_sleep_time = interval;
+                continue;
+            }
+
+            let succeeded = self.run(hmon_starting_point);
+            if let Some(challenge_id) = challenge_id {
+                self.answer_challenge(hmon_starting_point, challenge_id);
+            }
+            if !succeeded {
+                info!("Monitoring logic failed, stopping.");
+                if let Some(liveness) = &self.liveness {
+                    liveness.set_exited();
+                }
+                return RunExit::MonitoringFailed;
+            }
+
+            // Overrunning pass is followed by the next one immediately, the missed time is not caught up.
+            let pass_duration = now.elapsed();
+            next_sleep_time = match interval.checked_sub(pass_duration) {
+                Some(sleep_time) => sleep_time,
+                None => {
+                    self.report_worker_overrun(hmon_starting_point, pass_duration.saturating_sub(interval));
+                    Duration::ZERO
+                },
+            };
+        }
+
+        if let Some(status_board) = &self.status_board {
+            status_board.set_stopped();
+        }
+        if let Some(liveness) = &self.liveness {
+            liveness.set_exited();
+        }
+        RunExit::Stopped
+    }
+
+    /// Respond to the challenge with the result of the last evaluation pass.
+    fn answer_challenge(&self, hmon_starting_point: Instant, challenge_id: u64) {
+        let response = ChallengeResponse {
+            challenge_id,
+            healthy: self.last_pass_healthy,
+        };
+        info!("Challenge {} answered, healthy: {}.", challenge_id, response.healthy);
+        if let Some(challenge) = &self.challenge {
+            challenge.respond(response);
+        }
+        if !self.client.respond_challenge(challenge_id, response.healthy) {
+            error!("Failed to respond to challenge {}.", challenge_id);
+            report_internal_fault(
+                &self.violation_history,
+                None,
+                InternalFault::NotifyFailure,
+                hmon_starting_point,
+            );
+        }
+    }
+
+    /// Flush the violation history, notify the supervisor and terminate the process after a fail-fast failure.
+    fn fail_fast(&self, monitor_tag: MonitorTag, error: MonitorEvaluationError) {
+        let Some(fail_fast) = &self.fail_fast else {
+            return;
+        };
+        error!(
+            "Monitor with tag {:?} failed with {:?}, fail-fast policy terminates the process with exit code {}.",
+            monitor_tag,
+            error,
+            fail_fast.exit_code()
+        );
+        for record in self.violation_history.records() {
+            error!(
+                "Violation of monitor {:?} at {} ms: {:?}.",
+                record.monitor_tag,
+                record.timestamp.as_millis() as u64,
+                record.error
+            );
+        }
+        if !self.client.notify_terminating() {
+            error!("Failed to notify the supervisor the process is terminating.");
+        }
+        (self.exit_process)(fail_fast.exit_code());
+    }
+
+    /// Release recovered monitors and derive failed channels from monitors which did not recover yet.
+    /// Returns `true` if a monitor not assigned to any channel did not recover yet.
+    fn apply_recovery(&mut self) -> bool {
+        self.recovery.end_cycle();
+
+        for channel in self.channels.iter_mut() {
+            let failed = channel
+                .monitor_tags
+                .iter()
+                .any(|monitor_tag| self.recovery.is_failed(monitor_tag));
+            if channel.failed && !failed {
+                info!("Notifications of channel {:?} resumed.", channel.channel_tag);
+            }
+            channel.failed = failed;
+        }
+
+        self.recovery.failed_monitors().any(|monitor_tag| {
+            !self
+                .channels
+                .iter()
+                .any(|channel| channel.monitor_tags.contains(monitor_tag))
+        })
+    }
+
+    /// Send health summary to the aggregator, if configured.
+    fn forward_health(&mut self, hmon_starting_point: Instant, failed: bool) {
+        let Some(health_forwarder) = &self.health_forwarder else {
+            return;
+        };
+        self.last_forward = Instant::now();
+
+        let failing_monitors = self.failing_mask.iter().map(|word| word.count_ones()).sum::<u32>();
+        let status = if failed {
+            ForwardedStatus::Failed
+        } else if failing_monitors > 0 {
+            ForwardedStatus::Degraded
+        } else {
+            ForwardedStatus::Healthy
+        };
+        let failing_monitors = u16::try_from(failing_monitors).unwrap_or(u16::MAX);
+        if !health_forwarder.forward(hmon_starting_point.elapsed(), status, failing_monitors) {
+            warn!("Failed to forward health summary.");
+            report_internal_fault(
+                &self.violation_history,
+                None,
+                InternalFault::NotifyFailure,
+                hmon_starting_point,
+            );
+        }
+    }
+
+    /// Re-baseline all monitors after a clock gap, instead of evaluating them.
+    fn handle_clock_gap(&self, hmon_starting_point: Instant, gap: Duration) {
+        warn!(
+            "Clock gap of {} ms detected, re-baselining monitors.",
+            gap.as_millis() as u64
+        );
+        for monitor in self.monitors.iter() {
+            monitor.rebaseline(hmon_starting_point, gap);
+        }
+        report_internal_fault(
+            &self.violation_history,
+            None,
+            InternalFault::ClockGapDetected,
+            hmon_starting_point,
+        );
+    }
+
+    /// Report an evaluation cycle that took longer than the internal processing cycle.
+    fn report_worker_overrun(&self, hmon_starting_point: Instant, overrun: Duration) {
+        warn!(
+            "Evaluation pass overran the interval by {} ms.",
+            overrun.as_millis() as u64
+        );
+        if let Some(liveness) = &self.liveness {
+            liveness.record_overrun();
+        }
+        report_internal_fault(
+            &self.violation_history,
+            None,
+            InternalFault::WorkerOverrun,
+            hmon_starting_point,
+        );
+    }
+}
+
+/// Invoke the failure callback, containing its panics.
+/// Returns internal fault if the callback panicked or exceeded its `budget`.
+fn invoke_failure_callback(
+    failure_callback: &FailureCallback,
+    budget: Duration,
+    monitor_tag: &MonitorTag,
+    error: MonitorEvaluationError,
+) -> Option<InternalFault> {
+    let Ok(mut failure_callback) = failure_callback.lock() else {
+        error!("Failure callback is unusable after an earlier panic.");
+        return None;
+    };
+
+    let start = Instant::now();
+    // Panic is caught while the lock is held, so the callback is not poisoned and is invoked again on next violation.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _scope = MonitoringCallbackScope::enter();
+        failure_callback(monitor_tag, error)
+    }));
+    let elapsed = start.elapsed();
+
+    if result.is_err() {
+        error!("Failure callback panicked on violation of monitor {:?}.", monitor_tag);
+        return Some(InternalFault::CallbackPanicked);
+    }
+    if elapsed > budget {
+        warn!(
+            "Failure callback took {} ms, exceeding its budget of {} ms.",
+            elapsed.as_millis() as u64,
+            budget.as_millis() as u64
+        );
+        return Some(InternalFault::CallbackOverrun);
+    }
+    None
+}
+
+/// Terminate the process with `exit_code`.
+fn exit_process(exit_code: i32) {
+    std::process::exit(exit_code)
+}
+
+/// Invoke the worker fault callback, containing its panics.
+fn invoke_worker_fault_callback(worker_fault_callback: &WorkerFaultCallback, fault: WorkerFault) {
+    let Ok(mut worker_fault_callback) = worker_fault_callback.lock() else {
+        error!("Worker fault callback is unusable after an earlier panic.");
+        return;
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _scope = MonitoringCallbackScope::enter();
+        worker_fault_callback(fault)
+    }));
+    if result.is_err() {
+        error!("Worker fault callback panicked on fault {:?}.", fault);
+    }
+}
+
+/// Run `f` on the signal export, if configured.
+fn with_signal_export(signal_export: &Option<SharedSignalExport>, f: impl FnOnce(&mut SignalExport)) {
+    if let Some(signal_export) = signal_export {
+        match signal_export.lock() {
+            Ok(mut signal_export) => {
+                let _scope = MonitoringCallbackScope::enter();
+                f(&mut signal_export)
+            },
+            Err(_) => error!("Signal export is unusable after an earlier panic."),
+        }
+    }
+}
+
+/// Dispatch a violation to all sinks with `f`.
+/// Returns `false` if a sink panicked.
+fn dispatch_violation(violation_sinks: &SharedViolationSinks, f: impl FnMut(&mut dyn ViolationSink)) -> bool {
+    match violation_sinks.lock() {
+        Ok(mut violation_sinks) => {
+            let _scope = MonitoringCallbackScope::enter();
+            violation_sinks.dispatch(f)
+        },
+        Err(_) => {
+            error!("Violation sinks are unusable after an earlier panic.");
+            true
+        },
+    }
+}
+
+/// Log the internal fault and store it in the history.
+fn report_internal_fault(
+    violation_history: &ViolationHistory,
+    monitor_tag: Option<MonitorTag>,
+    fault: InternalFault,
+    hmon_starting_point: Instant,
+) {
+    error!("Internal fault {:?} (monitor: {:?}).", fault, monitor_tag);
+    violation_history.push_internal_fault(InternalFaultRecord {
+        monitor_tag,
+        fault,
+        timestamp: hmon_starting_point.elapsed(),
+    });
+}
+
+/// Reason of the monitoring loop exit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ScoreDebug)]
+pub enum RunExit {
+    /// Stop was requested with a [`StopToken`].
+    Stopped,
+    /// A monitor reported an error, supervisor is no longer notified.
+    MonitoringFailed,
+}
+
+/// Token requesting the monitoring loop to exit.
+/// Clones share the same state, stopping any of them stops the loop.
+#[derive(Clone, Debug, Default)]
+pub struct StopToken {
+    should_stop: Arc<AtomicBool>,
+}
+
+impl StopToken {
+    /// Create a new [`StopToken`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request the monitoring loop to exit.
+    /// Loop exits after the current evaluation cycle.
+    pub fn stop(&self) {
+        self.should_stop.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if stop was requested.
+    pub fn is_stopped(&self) -> bool {
+        self.should_stop.load(Ordering::Relaxed)
+    }
 }
 
 /// A struct that manages a unique thread for running monitoring logic periodically.
+/// Thread is supervised by a watchdog thread if a worker fault callback is set.
 pub struct UniqueThreadRunner {
     handle: Option<std::thread::JoinHandle<()>>,
-    should_stop: Arc<AtomicBool>,
+    watchdog_handle: Option<std::thread::JoinHandle<()>>,
+    stop_token: StopToken,
     internal_duration_cycle: Duration,
+    liveness: Arc<WorkerLiveness>,
+    worker_fault_callback: Option<WorkerFaultCallback>,
 }
 
 impl UniqueThreadRunner {
-    pub(super) fn new(internal_duration_cycle: Duration) -> Self {
+    pub(super) fn new(internal_duration_cycle: Duration, stall_threshold: Duration) -> Self {
         Self {
             handle: None,
-            should_stop: Arc::new(AtomicBool::new(false)),
+            watchdog_handle: None,
+            stop_token: StopToken::new(),
             internal_duration_cycle,
+            liveness: Arc::new(WorkerLiveness::new(stall_threshold)),
+            worker_fault_callback: None,
         }
     }
 
+    /// Report stalls and panics of the monitoring thread to `worker_fault_callback`.
+    pub(super) fn with_worker_fault_callback(mut self, worker_fault_callback: Option<WorkerFaultCallback>) -> Self {
+        self.worker_fault_callback = worker_fault_callback;
+        self
+    }
+
+    /// Liveness of the monitoring loop, to be published by the monitoring logic.
+    pub(super) fn liveness(&self) -> &Arc<WorkerLiveness> {
+        &self.liveness
+    }
+
     pub(super) fn start<T>(&mut self, mut monitoring_logic: MonitoringLogic<T>)
     where
         T: SupervisorAPIClient + Send + 'static,
     {
+        // Token of previous run is already stopped.
+        self.stop_token = StopToken::new();
+        // Panic of previous run is already reported.
+        self.liveness.set_exited();
         self.handle = Some({
-            let should_stop = self.should_stop.clone();
+            let stop_token = self.stop_token.clone();
             let interval = self.internal_duration_cycle;
+            let liveness = self.liveness.clone();
 
             std::thread::spawn(move || {
                 info!("Monitoring thread started.");
-                let hmon_starting_point = Instant::now();
-                let mut next_sleep_time = interval;
-
-                // TODO Add some checks and log if cyclicly here is not met.
-                while !should_stop.load(Ordering::Relaxed) {
-                    std::thread::sleep(next_sleep_time);
-
-                    let now = Instant::now();
+                let result = catch_unwind(AssertUnwindSafe(|| monitoring_logic.run_loop(interval, &stop_token)));
+                if result.is_err() {
+                    liveness.set_panicked();
+                }
+                info!("Monitoring thread exiting.");
+            })
+        });
+        self.watchdog_handle = self.worker_fault_callback.clone().map(|worker_fault_callback| {
+            let stop_token = self.stop_token.clone();
+            let interval = self.internal_duration_cycle;
+            let liveness = self.liveness.clone();
 
-                    if !monitoring_logic.run(hmon_starting_point) {
-                        info!("Monitoring logic failed, stopping thread.");
-                        break;
+            std::thread::spawn(move || {
+                let mut watchdog = WorkerWatchdog::new();
+                while !stop_token.is_stopped() {
+                    std::thread::sleep(interval);
+                    if let Some(fault) = watchdog.check(&liveness) {
+                        invoke_worker_fault_callback(&worker_fault_callback, fault);
+                        if fault == WorkerFault::Panicked {
+                            break;
+                        }
                     }
-
-                    next_sleep_time = interval - now.elapsed();
                 }
-
-                info!("Monitoring thread exiting.");
             })
         });
     }
 
+    pub(super) fn is_started(&self) -> bool {
+        self.handle.is_some()
+    }
+
     pub fn join(&mut self) {
-        self.should_stop.store(true, Ordering::Relaxed);
+        self.stop_token.stop();
         if let Some(handle) = self.handle.take() {
             let _ = handle.join();
         }
+        if let Some(watchdog_handle) = self.watchdog_handle.take() {
+            let _ = watchdog_handle.join();
+        }
     }
 }
 
@@ -150,12 +1088,15 @@ impl Drop for UniqueThreadRunner {
 #[derive(Copy, Clone)]
 pub(crate) enum Checks {
     WorkerCheckpoint,
+    /// Reported when the process is terminating after a failure.
+    Terminating,
 }
 
 impl From<Checks> for u32 {
     fn from(value: Checks) -> Self {
         match value {
             Checks::WorkerCheckpoint => 1,
+            Checks::Terminating => 2,
         }
     }
 }
@@ -163,40 +1104,468 @@ impl From<Checks> for u32 {
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::Monitor;
-    use crate::deadline::{DeadlineMonitor, DeadlineMonitorBuilder};
+    use crate::common::{
+        InternalFault, Monitor, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator, ViolationContext,
+    };
+    use crate::deadline::{DeadlineEvaluationError, DeadlineMonitor, DeadlineMonitorBuilder};
+    use crate::fail_fast::FailFastPolicy;
+    use crate::failure_statistics::FailureStatistics;
+    use crate::group::{GroupPolicy, GroupTracker, MonitorGroup};
+    use crate::handoff_queue::HandoffQueue;
+    use crate::health_forwarder::{ForwardedStatus, HealthSummary, UdpHealthForwarder};
+    use crate::health_status::StatusBoard;
+    use crate::health_trajectory::{HealthState, HealthTrajectory, TrajectoryGuard};
     use crate::protected_memory::ProtectedMemoryAllocator;
     use crate::supervisor_api_client::SupervisorAPIClient;
-    use crate::tag::{DeadlineTag, MonitorTag};
-    use crate::worker::{MonitoringLogic, UniqueThreadRunner};
+    use crate::tag::{ChannelTag, DeadlineTag, GroupTag, MonitorTag};
+    use crate::tolerance::{FailureTolerance, RecoveryPolicy, RecoveryTracker, ToleranceTracker};
+    use crate::violation_history::ViolationHistory;
+    use crate::worker::{
+        in_monitoring_callback, MonitoringLogic, NotificationChannel, RunExit, StopToken, UniqueThreadRunner,
+    };
+    use crate::worker_supervision::{WorkerFault, WorkerLiveness};
     use crate::TimeRange;
     use containers::fixed_capacity::FixedCapacityVec;
-    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering};
     use core::time::Duration;
-    use std::sync::Arc;
+    use std::collections::HashMap;
+    use std::net::UdpSocket;
+    use std::sync::{Arc, Mutex};
     use std::time::Instant;
 
-    #[derive(Clone)]
-    struct MockSupervisorAPIClient {
-        pub notify_called: Arc<AtomicUsize>,
+    #[derive(Clone)]
+    struct MockSupervisorAPIClient {
+        pub notify_called: Arc<AtomicUsize>,
+        pub notified_channels: Arc<Mutex<Vec<ChannelTag>>>,
+    }
+
+    impl MockSupervisorAPIClient {
+        pub fn new() -> Self {
+            Self {
+                notify_called: Arc::new(AtomicUsize::new(0)),
+                notified_channels: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        fn get_notify_count(&self) -> usize {
+            self.notify_called.load(Ordering::Acquire)
+        }
+
+        fn take_notified_channels(&self) -> Vec<ChannelTag> {
+            core::mem::take(&mut *self.notified_channels.lock().unwrap())
+        }
+    }
+
+    impl SupervisorAPIClient for MockSupervisorAPIClient {
+        fn notify_alive(&self) -> bool {
+            self.notify_called.fetch_add(1, Ordering::AcqRel);
+            true
+        }
+
+        fn notify_channel_alive(&self, channel_tag: ChannelTag) -> bool {
+            self.notified_channels.lock().unwrap().push(channel_tag);
+            true
+        }
+    }
+
+    #[test]
+    fn monitoring_logic_takes_runtime_monitors() {
+        let initial_monitor = SlowMonitor::new("initial_monitor", Duration::ZERO);
+        let runtime_monitor = SlowMonitor::new("runtime_monitor", Duration::ZERO);
+        let overflow_monitor = SlowMonitor::new("overflow_monitor", Duration::ZERO);
+        let runtime_monitors = Arc::new(HandoffQueue::new());
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(MonitorEvalHandle::new(initial_monitor.clone())).unwrap();
+                vec
+            },
+            Duration::from_nanos(0),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_runtime_monitors(runtime_monitors.clone());
+
+        assert!(logic.run(hmon_starting_point));
+        runtime_monitors.push(MonitorEvalHandle::new(runtime_monitor.clone()));
+        runtime_monitors.push(MonitorEvalHandle::new(overflow_monitor.clone()));
+        assert!(logic.run(hmon_starting_point));
+
+        // Monitor exceeding the capacity is dropped and reported as an internal fault.
+        assert_eq!(initial_monitor.evaluations.load(Ordering::Relaxed), 2);
+        assert_eq!(runtime_monitor.evaluations.load(Ordering::Relaxed), 1);
+        assert_eq!(overflow_monitor.evaluations.load(Ordering::Relaxed), 0);
+        let faults = logic.violation_history.internal_faults();
+        assert_eq!(faults.len(), 1);
+        assert_eq!(faults[0].fault, InternalFault::MonitorHandlePush);
+        assert_eq!(faults[0].monitor_tag, Some(MonitorTag::from("overflow_monitor")));
+    }
+
+    #[test]
+    fn monitoring_logic_clock_gap_rebaselines_monitors() {
+        // Evaluation longer than the threshold delays the next pass, which is treated as a clock gap.
+        let slow_monitor = SlowMonitor::new("slow_monitor", Duration::from_millis(60));
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(1);
+                vec.push(MonitorEvalHandle::new(slow_monitor.clone())).unwrap();
+                vec
+            },
+            Duration::from_millis(10),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 16)),
+        )
+        .with_clock_gap_threshold(Duration::from_millis(30));
+
+        let stop_token = StopToken::new();
+        std::thread::scope(|scope| {
+            scope.spawn(|| logic.run_loop(Duration::from_millis(10), &stop_token));
+            std::thread::sleep(Duration::from_millis(200));
+            stop_token.stop();
+        });
+
+        // Passes after the gap are skipped, so evaluations and rebaselines alternate.
+        let evaluations = slow_monitor.evaluations.load(Ordering::Relaxed);
+        let rebaselines = slow_monitor.rebaselines.load(Ordering::Relaxed);
+        assert!(rebaselines > 0);
+        assert!(evaluations.abs_diff(rebaselines) <= 1);
+        assert!(logic
+            .violation_history
+            .internal_faults()
+            .iter()
+            .any(|record| record.fault == InternalFault::ClockGapDetected));
+    }
+
+    #[test]
+    fn monitoring_logic_slow_monitor_overruns_interval() {
+        // Evaluation longer than the interval, without clock gap threshold every pass overruns.
+        let slow_monitor = SlowMonitor::new("slow_monitor", Duration::from_millis(30));
+        let liveness = Arc::new(WorkerLiveness::new(Duration::from_secs(1)));
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(1);
+                vec.push(MonitorEvalHandle::new(slow_monitor.clone())).unwrap();
+                vec
+            },
+            Duration::from_millis(10),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 16)),
+        )
+        .with_worker_liveness(liveness.clone());
+
+        let stop_token = StopToken::new();
+        let exit = std::thread::scope(|scope| {
+            let handle = scope.spawn(|| logic.run_loop(Duration::from_millis(10), &stop_token));
+            std::thread::sleep(Duration::from_millis(200));
+            stop_token.stop();
+            handle.join().unwrap()
+        });
+
+        // Loop keeps running, each overrunning pass is followed by the next one immediately.
+        assert_eq!(exit, RunExit::Stopped);
+        let evaluations = slow_monitor.evaluations.load(Ordering::Relaxed) as u64;
+        assert!(evaluations >= 3);
+        assert!(liveness.overruns() >= evaluations - 1);
+        assert_eq!(slow_monitor.rebaselines.load(Ordering::Relaxed), 0);
+        let faults = logic.violation_history.internal_faults();
+        assert!(!faults.is_empty());
+        assert!(faults.iter().all(|record| record.fault == InternalFault::WorkerOverrun));
+    }
+
+    #[test]
+    fn monitoring_logic_fast_monitor_no_overrun() {
+        let fast_monitor = SlowMonitor::new("fast_monitor", Duration::ZERO);
+        let liveness = Arc::new(WorkerLiveness::new(Duration::from_secs(1)));
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(1);
+                vec.push(MonitorEvalHandle::new(fast_monitor.clone())).unwrap();
+                vec
+            },
+            Duration::from_millis(20),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 16)),
+        )
+        .with_worker_liveness(liveness.clone());
+
+        let stop_token = StopToken::new();
+        std::thread::scope(|scope| {
+            scope.spawn(|| logic.run_loop(Duration::from_millis(20), &stop_token));
+            std::thread::sleep(Duration::from_millis(100));
+            stop_token.stop();
+        });
+
+        assert!(fast_monitor.evaluations.load(Ordering::Relaxed) > 0);
+        assert_eq!(liveness.overruns(), 0);
+        assert!(logic.violation_history.internal_faults().is_empty());
+    }
+
+    struct FailingSupervisorAPIClient;
+
+    impl SupervisorAPIClient for FailingSupervisorAPIClient {
+        fn notify_alive(&self) -> bool {
+            false
+        }
+
+        fn notify_channel_alive(&self, _channel_tag: ChannelTag) -> bool {
+            false
+        }
+    }
+
+    /// Monitor taking a fixed time to evaluate, never reporting errors.
+    struct SlowMonitor {
+        monitor_tag: MonitorTag,
+        evaluation_time: Duration,
+        evaluations: AtomicUsize,
+        rebaselines: AtomicUsize,
+    }
+
+    impl SlowMonitor {
+        fn new(monitor_tag: &str, evaluation_time: Duration) -> Arc<Self> {
+            Arc::new(Self {
+                monitor_tag: MonitorTag::from(monitor_tag),
+                evaluation_time,
+                evaluations: AtomicUsize::new(0),
+                rebaselines: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    impl MonitorEvaluator for SlowMonitor {
+        fn monitor_tag(&self) -> MonitorTag {
+            self.monitor_tag
+        }
+
+        fn evaluate(
+            &self,
+            _hmon_starting_point: Instant,
+            _on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+        ) {
+            self.evaluations.fetch_add(1, Ordering::Relaxed);
+            std::thread::sleep(self.evaluation_time);
+        }
+
+        fn rebaseline(&self, _hmon_starting_point: Instant, _gap: Duration) {
+            self.rebaselines.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Monitor panicking on evaluation.
+    struct PanickingMonitor;
+
+    impl MonitorEvaluator for PanickingMonitor {
+        fn monitor_tag(&self) -> MonitorTag {
+            MonitorTag::from("panicking_monitor")
+        }
+
+        fn evaluate(
+            &self,
+            _hmon_starting_point: Instant,
+            _on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+        ) {
+            panic!("evaluation failed");
+        }
+    }
+
+    /// Monitor reporting an error on every evaluation while failing.
+    struct FlakyMonitor {
+        monitor_tag: MonitorTag,
+        failing: AtomicBool,
+    }
+
+    impl MonitorEvaluator for FlakyMonitor {
+        fn monitor_tag(&self) -> MonitorTag {
+            self.monitor_tag
+        }
+
+        fn evaluate(
+            &self,
+            _hmon_starting_point: Instant,
+            on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError, ViolationContext),
+        ) {
+            if self.failing.load(Ordering::Relaxed) {
+                on_error(
+                    &self.monitor_tag,
+                    DeadlineEvaluationError::TooLate.into(),
+                    ViolationContext::default(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn monitoring_logic_resumes_notifications_after_recovery() {
+        let flaky_monitor = Arc::new(FlakyMonitor {
+            monitor_tag: MonitorTag::from("flaky_monitor"),
+            failing: AtomicBool::new(true),
+        });
+        let hmon_starting_point = Instant::now();
+        let client = MockSupervisorAPIClient::new();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(1);
+                vec.push(MonitorEvalHandle::new(flaky_monitor.clone())).unwrap();
+                vec
+            },
+            Duration::ZERO,
+            client.clone(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_recovery(RecoveryTracker::new(RecoveryPolicy::ResumeAfter(2)));
+
+        // Failed monitor suspends notifications, monitoring continues.
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(client.get_notify_count(), 0);
+
+        flaky_monitor.failing.store(false, Ordering::Relaxed);
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(client.get_notify_count(), 0);
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(client.get_notify_count(), 1);
+    }
+
+    fn create_trajectory_logic(
+        flaky_monitor: &Arc<FlakyMonitor>,
+        health_trajectory: &Arc<TrajectoryGuard>,
+        recovery_policy: RecoveryPolicy,
+        violation_history: &Arc<ViolationHistory>,
+    ) -> MonitoringLogic<MockSupervisorAPIClient> {
+        MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(MonitorEvalHandle::new(flaky_monitor.clone())).unwrap();
+                vec.push(MonitorEvalHandle::new(health_trajectory.clone())).unwrap();
+                vec
+            },
+            Duration::ZERO,
+            MockSupervisorAPIClient::new(),
+            violation_history.clone(),
+        )
+        .with_recovery(RecoveryTracker::new(recovery_policy))
+        .with_health_trajectory(health_trajectory.clone())
+    }
+
+    #[test]
+    fn monitoring_logic_publishes_health_trajectory() {
+        let flaky_monitor = Arc::new(FlakyMonitor {
+            monitor_tag: MonitorTag::from("flaky_monitor"),
+            failing: AtomicBool::new(true),
+        });
+        let health_trajectory = Arc::new(TrajectoryGuard::new(HealthTrajectory::new(MonitorTag::from(
+            "trajectory",
+        ))));
+        let violation_history = Arc::new(ViolationHistory::new(8, 4));
+        let hmon_starting_point = Instant::now();
+        let mut logic = create_trajectory_logic(
+            &flaky_monitor,
+            &health_trajectory,
+            RecoveryPolicy::ResumeAfter(2),
+            &violation_history,
+        );
+
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(health_trajectory.state(), Some(HealthState::Failed));
+
+        flaky_monitor.failing.store(false, Ordering::Relaxed);
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(health_trajectory.state(), Some(HealthState::Recovering));
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(health_trajectory.state(), Some(HealthState::Healthy));
+        assert!(logic.run(hmon_starting_point));
+
+        // Failed monitor passed recovering, only its own violation is in the history.
+        assert_eq!(violation_history.records().len(), 1);
     }
 
-    impl MockSupervisorAPIClient {
-        pub fn new() -> Self {
-            Self {
-                notify_called: Arc::new(AtomicUsize::new(0)),
-            }
-        }
+    #[test]
+    fn monitoring_logic_reports_invalid_health_transition() {
+        let flaky_monitor = Arc::new(FlakyMonitor {
+            monitor_tag: MonitorTag::from("flaky_monitor"),
+            failing: AtomicBool::new(true),
+        });
+        let trajectory_tag = MonitorTag::from("trajectory");
+        let health_trajectory = Arc::new(TrajectoryGuard::new(HealthTrajectory::new(trajectory_tag)));
+        let violation_history = Arc::new(ViolationHistory::new(8, 4));
+        let hmon_starting_point = Instant::now();
+        let mut logic = create_trajectory_logic(
+            &flaky_monitor,
+            &health_trajectory,
+            RecoveryPolicy::ResumeAfter(1),
+            &violation_history,
+        );
 
-        fn get_notify_count(&self) -> usize {
-            self.notify_called.load(Ordering::Acquire)
-        }
+        // Single clean cycle recovers the failed monitor without recovering state.
+        assert!(logic.run(hmon_starting_point));
+        flaky_monitor.failing.store(false, Ordering::Relaxed);
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(health_trajectory.state(), Some(HealthState::Healthy));
+
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(health_trajectory.state(), Some(HealthState::Failed));
+        assert!(violation_history
+            .records()
+            .iter()
+            .any(|record| record.monitor_tag == trajectory_tag && record.error == MonitorEvaluationError::Logic));
     }
 
-    impl SupervisorAPIClient for MockSupervisorAPIClient {
-        fn notify_alive(&self) {
-            self.notify_called.fetch_add(1, Ordering::AcqRel);
-        }
+    #[test]
+    fn monitoring_logic_reports_group_failures() {
+        let member_a = Arc::new(FlakyMonitor {
+            monitor_tag: MonitorTag::from("member_a"),
+            failing: AtomicBool::new(true),
+        });
+        let member_b = Arc::new(FlakyMonitor {
+            monitor_tag: MonitorTag::from("member_b"),
+            failing: AtomicBool::new(false),
+        });
+        let group_tag = GroupTag::from("group");
+        let hmon_starting_point = Instant::now();
+        let violation_history = Arc::new(ViolationHistory::new(8, 4));
+        let failure_statistics = Arc::new(FailureStatistics::new(1));
+        let reported_tags = Arc::new(Mutex::new(Vec::new()));
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(MonitorEvalHandle::new(member_a.clone())).unwrap();
+                vec.push(MonitorEvalHandle::new(member_b.clone())).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            violation_history.clone(),
+        )
+        .with_groups(vec![GroupTracker::new(
+            group_tag,
+            &MonitorGroup::new(GroupPolicy::AllMembers).with_members(&[member_a.monitor_tag, member_b.monitor_tag]),
+        )])
+        .with_failure_statistics(failure_statistics.clone())
+        .with_failure_callback(
+            {
+                let reported_tags = reported_tags.clone();
+                Arc::new(Mutex::new(move |monitor_tag: &MonitorTag, _| {
+                    reported_tags.lock().unwrap().push(*monitor_tag);
+                }))
+            },
+            Duration::from_secs(1),
+        );
+
+        // Single failing member does not fail the group, its violation is kept in the history.
+        assert!(logic.run(hmon_starting_point));
+        assert!(reported_tags.lock().unwrap().is_empty());
+        assert_eq!(violation_history.records().len(), 1);
+
+        // Both members failing in a single cycle are reported once, under the group tag.
+        member_b.failing.store(true, Ordering::Relaxed);
+        assert!(!logic.run(hmon_starting_point));
+        assert_eq!(*reported_tags.lock().unwrap(), vec![MonitorTag::from(group_tag)]);
+        let statistics = failure_statistics.all();
+        assert_eq!(statistics.len(), 1);
+        assert_eq!(statistics[0].0, MonitorTag::from(group_tag));
+        assert_eq!(statistics[0].1.too_late, 1);
     }
 
     fn create_monitor_with_deadlines() -> DeadlineMonitor {
@@ -228,6 +1597,7 @@ mod tests {
             },
             Duration::from_secs(1),
             alive_mock.clone(),
+            Arc::new(ViolationHistory::new(4, 4)),
         );
 
         let mut deadline = deadline_monitor
@@ -239,6 +1609,261 @@ mod tests {
 
         assert!(!logic.run(hmon_starting_point));
         assert_eq!(alive_mock.get_notify_count(), 0);
+
+        let records = logic.violation_history.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].monitor_tag, MonitorTag::from("deadline_monitor"));
+        assert_eq!(
+            records[0].error,
+            MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooEarly)
+        );
+    }
+
+    #[test]
+    fn monitoring_logic_forwards_failed_health() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let aggregator = UdpSocket::bind("127.0.0.1:0").unwrap();
+        aggregator.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let health_forwarder = UdpHealthForwarder::new(aggregator.local_addr().unwrap(), 3).unwrap();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_status_board(Arc::new(StatusBoard::new(
+            vec![MonitorTag::from("deadline_monitor")],
+            0,
+        )))
+        .with_health_forwarder(Arc::new(health_forwarder));
+
+        // Healthy pass within the supervisor cycle is not forwarded.
+        assert!(logic.run(hmon_starting_point));
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        deadline.start().unwrap().stop();
+        assert!(!logic.run(hmon_starting_point));
+
+        let mut buffer = [0; 64];
+        let (size, _) = aggregator.recv_from(&mut buffer).unwrap();
+        let summary = HealthSummary::parse(&buffer[..size]).unwrap();
+        assert_eq!(summary.source_id, 3);
+        assert_eq!(summary.sequence, 0);
+        assert_eq!(summary.status, ForwardedStatus::Failed);
+        assert_eq!(summary.failing_monitors, 1);
+    }
+
+    static FAIL_FAST_EXIT_CODE: AtomicI32 = AtomicI32::new(0);
+
+    #[test]
+    fn monitoring_logic_fail_fast() {
+        let flaky_monitor = Arc::new(FlakyMonitor {
+            monitor_tag: MonitorTag::from("flaky_monitor"),
+            failing: AtomicBool::new(true),
+        });
+        let hmon_starting_point = Instant::now();
+        let create_logic = |fail_fast: FailFastPolicy| {
+            MonitoringLogic::new(
+                {
+                    let mut vec = FixedCapacityVec::new(1);
+                    vec.push(MonitorEvalHandle::new(flaky_monitor.clone())).unwrap();
+                    vec
+                },
+                Duration::ZERO,
+                MockSupervisorAPIClient::new(),
+                Arc::new(ViolationHistory::new(4, 4)),
+            )
+            .with_channels(vec![NotificationChannel::new(
+                ChannelTag::from("channel"),
+                vec![MonitorTag::from("flaky_monitor")],
+            )])
+            .with_fail_fast(fail_fast)
+            .with_exit_process(|exit_code| FAIL_FAST_EXIT_CODE.store(exit_code, Ordering::Relaxed))
+        };
+
+        // Failure limited to the channel is not selected by the policy.
+        let mut logic = create_logic(FailFastPolicy::new(3).with_monitors(&[MonitorTag::from("other_monitor")]));
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(FAIL_FAST_EXIT_CODE.load(Ordering::Relaxed), 0);
+
+        let mut logic = create_logic(FailFastPolicy::new(3).with_errors(&[DeadlineEvaluationError::TooLate.into()]));
+        assert!(!logic.run(hmon_starting_point));
+        assert_eq!(FAIL_FAST_EXIT_CODE.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn monitoring_logic_tolerates_violations() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_failure_tolerance(ToleranceTracker::new(
+            None,
+            HashMap::from([(monitor_tag, FailureTolerance::Consecutive(2))]),
+        ));
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        deadline.start().unwrap().stop();
+
+        // Deadline stays underrun and is reported on every cycle.
+        assert!(logic.run(hmon_starting_point));
+        assert!(!logic.run(hmon_starting_point));
+    }
+
+    #[test]
+    fn monitoring_logic_contains_failure_callback_panic_and_overrun() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let violation_history = Arc::new(ViolationHistory::new(4, 4));
+        let invocations = Arc::new(AtomicUsize::new(0));
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            violation_history.clone(),
+        )
+        .with_failure_callback(
+            {
+                let invocations = invocations.clone();
+                Arc::new(Mutex::new(move |_: &MonitorTag, _| {
+                    if invocations.fetch_add(1, Ordering::Relaxed) == 0 {
+                        panic!("Failure callback panic");
+                    }
+                    std::thread::sleep(Duration::from_millis(20));
+                }))
+            },
+            Duration::from_millis(5),
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        deadline.start().unwrap().stop();
+        assert!(!logic.run(hmon_starting_point));
+        assert!(!logic.run(hmon_starting_point));
+
+        // Callback is still invoked after the panic.
+        assert_eq!(invocations.load(Ordering::Relaxed), 2);
+        let faults: Vec<_> = violation_history
+            .internal_faults()
+            .iter()
+            .map(|record| record.fault)
+            .collect();
+        assert_eq!(
+            faults,
+            vec![InternalFault::CallbackPanicked, InternalFault::CallbackOverrun]
+        );
+    }
+
+    #[test]
+    fn monitoring_logic_failure_callback_reentrant_reporting() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let in_callback = Arc::new(AtomicBool::new(false));
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_failure_callback(
+            {
+                let in_callback = in_callback.clone();
+                let mut deadline = deadline_monitor
+                    .get_deadline(DeadlineTag::from("deadline_fast"))
+                    .unwrap();
+                Arc::new(Mutex::new(move |_: &MonitorTag, _| {
+                    in_callback.store(in_monitoring_callback(), Ordering::Relaxed);
+                    // Reporting APIs can be used from the callback.
+                    deadline.start().unwrap().stop();
+                }))
+            },
+            Duration::from_secs(1),
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        deadline.start().unwrap().stop();
+
+        assert!(!in_monitoring_callback());
+        assert!(!logic.run(hmon_starting_point));
+        assert!(in_callback.load(Ordering::Relaxed));
+        assert!(!in_monitoring_callback());
+    }
+
+    #[test]
+    fn monitoring_logic_invokes_failure_callback() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let failures = Arc::new(Mutex::new(Vec::new()));
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_failure_callback(
+            {
+                let failures = failures.clone();
+                Arc::new(Mutex::new(move |monitor_tag: &MonitorTag, error| {
+                    failures.lock().unwrap().push((*monitor_tag, error))
+                }))
+            },
+            Duration::from_secs(1),
+        );
+
+        assert!(logic.run(hmon_starting_point));
+        assert!(failures.lock().unwrap().is_empty());
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        deadline.start().unwrap().stop();
+
+        assert!(!logic.run(hmon_starting_point));
+        assert_eq!(
+            *failures.lock().unwrap(),
+            vec![(
+                MonitorTag::from("deadline_monitor"),
+                MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooEarly)
+            )]
+        );
     }
 
     #[test]
@@ -255,6 +1880,7 @@ mod tests {
             },
             Duration::from_nanos(0), // Make sure each call notifies alive
             alive_mock.clone(),
+            Arc::new(ViolationHistory::new(4, 4)),
         );
 
         let mut deadline = deadline_monitor
@@ -271,6 +1897,115 @@ mod tests {
         assert_eq!(alive_mock.get_notify_count(), 5);
     }
 
+    #[test]
+    fn monitoring_logic_reports_notify_failure_as_internal_fault() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            FailingSupervisorAPIClient,
+            Arc::new(ViolationHistory::new(4, 4)),
+        );
+
+        assert!(logic.run(hmon_starting_point));
+
+        // Internal faults are kept apart from violations.
+        assert!(logic.violation_history.records().is_empty());
+        let faults = logic.violation_history.internal_faults();
+        assert_eq!(faults.len(), 1);
+        assert_eq!(faults[0].fault, InternalFault::NotifyFailure);
+        assert_eq!(faults[0].monitor_tag, None);
+    }
+
+    #[test]
+    fn monitoring_logic_notification_channels_are_partitioned() {
+        let failing_monitor = create_monitor_with_deadlines();
+        let healthy_monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline_long"),
+                TimeRange::new(Duration::from_secs(1), Duration::from_secs(50)),
+            )
+            .build(MonitorTag::from("healthy_monitor"), &ProtectedMemoryAllocator {});
+        let alive_mock = MockSupervisorAPIClient::new();
+        let hmon_starting_point = Instant::now();
+
+        let tenant_a = ChannelTag::from("tenant_a");
+        let tenant_b = ChannelTag::from("tenant_b");
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(failing_monitor.get_eval_handle()).unwrap();
+                vec.push(healthy_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            alive_mock.clone(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_channels(vec![
+            NotificationChannel::new(tenant_a, vec![MonitorTag::from("deadline_monitor")]),
+            NotificationChannel::new(tenant_b, vec![MonitorTag::from("healthy_monitor")]),
+        ]);
+
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(alive_mock.take_notified_channels(), vec![tenant_a, tenant_b]);
+
+        // Fail monitor assigned to the first channel.
+        let mut deadline = failing_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        deadline.start().unwrap().stop();
+
+        // Process and other channel are still notified.
+        assert!(logic.run(hmon_starting_point));
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(alive_mock.take_notified_channels(), vec![tenant_b, tenant_b]);
+        assert_eq!(alive_mock.get_notify_count(), 3);
+        assert_eq!(logic.violation_history.records().len(), 2);
+    }
+
+    #[test]
+    fn monitoring_logic_evaluation_budget_skips_remaining_monitors() {
+        let slow_monitor = SlowMonitor::new("slow_monitor", Duration::from_millis(30));
+        let fast_monitor = SlowMonitor::new("fast_monitor", Duration::ZERO);
+        let alive_mock = MockSupervisorAPIClient::new();
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(MonitorEvalHandle::new(slow_monitor.clone())).unwrap();
+                vec.push(MonitorEvalHandle::new(fast_monitor.clone())).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            alive_mock.clone(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_evaluation_budget(Duration::from_millis(10));
+
+        // Slow monitor exceeds the budget, fast one is skipped and supervisor is not notified.
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(fast_monitor.evaluations.load(Ordering::Relaxed), 0);
+        assert_eq!(alive_mock.get_notify_count(), 0);
+        let faults = logic.violation_history.internal_faults();
+        assert_eq!(faults.len(), 1);
+        assert_eq!(faults[0].fault, InternalFault::EvaluationSkipped);
+        assert_eq!(faults[0].monitor_tag, Some(MonitorTag::from("fast_monitor")));
+
+        // Next pass starts with the skipped monitor.
+        assert!(logic.run(hmon_starting_point));
+        assert_eq!(fast_monitor.evaluations.load(Ordering::Relaxed), 1);
+        assert_eq!(slow_monitor.evaluations.load(Ordering::Relaxed), 2);
+        assert_eq!(alive_mock.get_notify_count(), 1);
+    }
+
     #[test]
     fn monitoring_logic_report_alive_respect_cycle() {
         let deadline_monitor = create_monitor_with_deadlines();
@@ -285,6 +2020,7 @@ mod tests {
             },
             Duration::from_millis(30),
             alive_mock.clone(),
+            Arc::new(ViolationHistory::new(4, 4)),
         );
 
         let mut deadline = deadline_monitor
@@ -326,9 +2062,10 @@ mod tests {
             },
             Duration::from_nanos(0), // Make sure each call notifies alive
             alive_mock.clone(),
+            Arc::new(ViolationHistory::new(4, 4)),
         );
 
-        let mut worker = UniqueThreadRunner::new(Duration::from_millis(10));
+        let mut worker = UniqueThreadRunner::new(Duration::from_millis(10), Duration::from_millis(40));
         worker.start(logic);
 
         let mut deadline = deadline_monitor
@@ -351,4 +2088,59 @@ mod tests {
         assert_eq!(alive_mock.get_notify_count(), current_count);
         handle.stop();
     }
+
+    fn create_runner_with_fault_log() -> (UniqueThreadRunner, Arc<Mutex<Vec<WorkerFault>>>) {
+        let faults = Arc::new(Mutex::new(Vec::new()));
+        let worker = UniqueThreadRunner::new(Duration::from_millis(10), Duration::from_millis(40))
+            .with_worker_fault_callback(Some({
+                let faults = faults.clone();
+                Arc::new(Mutex::new(move |fault| faults.lock().unwrap().push(fault)))
+            }));
+        (worker, faults)
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn unique_thread_runner_reports_stall() {
+        let slow_monitor = SlowMonitor::new("slow_monitor", Duration::from_millis(150));
+        let (mut worker, faults) = create_runner_with_fault_log();
+        let logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(1);
+                vec.push(MonitorEvalHandle::new(slow_monitor)).unwrap();
+                vec
+            },
+            Duration::from_millis(100),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_worker_liveness(worker.liveness().clone());
+
+        worker.start(logic);
+        std::thread::sleep(Duration::from_millis(120));
+        worker.join();
+        assert_eq!(*faults.lock().unwrap(), vec![WorkerFault::Stalled]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn unique_thread_runner_reports_panic() {
+        let (mut worker, faults) = create_runner_with_fault_log();
+        let logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(1);
+                vec.push(MonitorEvalHandle::new(Arc::new(PanickingMonitor))).unwrap();
+                vec
+            },
+            Duration::from_millis(100),
+            MockSupervisorAPIClient::new(),
+            Arc::new(ViolationHistory::new(4, 4)),
+        )
+        .with_worker_liveness(worker.liveness().clone());
+
+        worker.start(logic);
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(*faults.lock().unwrap(), vec![WorkerFault::Panicked]);
+        worker.join();
+    }
 }
@@ -0,0 +1,219 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::log::{error, warn, ScoreDebug};
+use core::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use core::time::Duration;
+use std::time::Instant;
+
+/// Fault of the monitoring thread itself, reported to the callback set with
+/// [`crate::HealthMonitorBuilder::with_worker_fault_callback`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ScoreDebug)]
+pub enum WorkerFault {
+    /// Monitoring thread did not complete an evaluation cycle within the stall threshold.
+    Stalled,
+    /// Monitoring thread panicked, monitors are no longer evaluated.
+    Panicked,
+}
+
+/// Default worker stall threshold, in internal processing cycles.
+pub(crate) const DEFAULT_WORKER_STALL_CYCLES: u32 = 4;
+
+/// Monitoring loop is not running.
+const IDLE: u8 = 0;
+/// Monitoring loop is running.
+const RUNNING: u8 = 1;
+/// Monitoring loop panicked.
+const PANICKED: u8 = 2;
+
+/// Current state of the monitoring loop, as observed from another thread.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum WorkerState {
+    /// Monitoring loop is not running, it was not started or exited.
+    Idle,
+    /// Monitoring loop completed an evaluation cycle within the stall threshold.
+    Healthy,
+    /// Monitoring loop did not complete an evaluation cycle within the stall threshold.
+    Stalled,
+    /// Monitoring loop panicked.
+    Panicked,
+}
+
+/// Liveness of the monitoring loop, published by the loop on every cycle and read by other threads.
+/// Stores a cycle counter and the time of the last completed cycle, so reading never blocks the loop.
+pub(crate) struct WorkerLiveness {
+    stall_threshold: Duration,
+    /// Reference point of `last_cycle_ms`.
+    reference: Instant,
+    state: AtomicU8,
+    cycles: AtomicU64,
+    last_cycle_ms: AtomicU64,
+    overruns: AtomicU64,
+}
+
+impl WorkerLiveness {
+    pub(crate) fn new(stall_threshold: Duration) -> Self {
+        Self {
+            stall_threshold,
+            reference: Instant::now(),
+            state: AtomicU8::new(IDLE),
+            cycles: AtomicU64::new(0),
+            last_cycle_ms: AtomicU64::new(0),
+            overruns: AtomicU64::new(0),
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        self.reference.elapsed().as_millis() as u64
+    }
+
+    /// Mark the monitoring loop as running, stall threshold applies from now.
+    pub(crate) fn set_running(&self) {
+        self.last_cycle_ms.store(self.now_ms(), Ordering::Relaxed);
+        self.state.store(RUNNING, Ordering::Release);
+    }
+
+    /// Record an evaluation cycle completed by the monitoring loop.
+    pub(crate) fn record_cycle(&self) {
+        self.cycles.fetch_add(1, Ordering::Relaxed);
+        self.last_cycle_ms.store(self.now_ms(), Ordering::Relaxed);
+    }
+
+    /// Record an evaluation cycle which took longer than the interval.
+    pub(crate) fn record_overrun(&self) {
+        self.overruns.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Mark the monitoring loop as exited, either on request or after monitoring failed.
+    pub(crate) fn set_exited(&self) {
+        self.state.store(IDLE, Ordering::Release);
+    }
+
+    /// Mark the monitoring loop as panicked.
+    pub(crate) fn set_panicked(&self) {
+        error!("Monitoring thread panicked, monitors are no longer evaluated.");
+        self.state.store(PANICKED, Ordering::Release);
+    }
+
+    /// Number of evaluation cycles completed since the health monitor was built.
+    pub(crate) fn cycles(&self) -> u64 {
+        self.cycles.load(Ordering::Relaxed)
+    }
+
+    /// Number of evaluation cycles which took longer than the interval since the health monitor was built.
+    pub(crate) fn overruns(&self) -> u64 {
+        self.overruns.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn stall_threshold(&self) -> Duration {
+        self.stall_threshold
+    }
+
+    pub(crate) fn state(&self) -> WorkerState {
+        match self.state.load(Ordering::Acquire) {
+            RUNNING => {
+                let age_ms = self.now_ms().saturating_sub(self.last_cycle_ms.load(Ordering::Relaxed));
+                if age_ms > self.stall_threshold.as_millis() as u64 {
+                    WorkerState::Stalled
+                } else {
+                    WorkerState::Healthy
+                }
+            },
+            PANICKED => WorkerState::Panicked,
+            _ => WorkerState::Idle,
+        }
+    }
+}
+
+/// Watches [`WorkerLiveness`] from outside of the monitoring thread and reports faults.
+/// Stall is reported once, again only after the monitoring loop recovered in between.
+pub(crate) struct WorkerWatchdog {
+    stalled: bool,
+}
+
+impl WorkerWatchdog {
+    pub(crate) fn new() -> Self {
+        Self { stalled: false }
+    }
+
+    /// Check the `liveness`, returns fault to report, if any.
+    pub(crate) fn check(&mut self, liveness: &WorkerLiveness) -> Option<WorkerFault> {
+        match liveness.state() {
+            WorkerState::Stalled if !self.stalled => {
+                self.stalled = true;
+                warn!(
+                    "Monitoring thread stalled for more than {} ms after {} cycles.",
+                    liveness.stall_threshold().as_millis() as u64,
+                    liveness.cycles()
+                );
+                Some(WorkerFault::Stalled)
+            },
+            WorkerState::Stalled => None,
+            WorkerState::Panicked => Some(WorkerFault::Panicked),
+            WorkerState::Healthy | WorkerState::Idle => {
+                self.stalled = false;
+                None
+            },
+        }
+    }
+}
+
+#[score_testing_macros::test_mod_with_log]
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::worker_supervision::{WorkerFault, WorkerLiveness, WorkerState, WorkerWatchdog};
+    use core::time::Duration;
+
+    #[test]
+    fn worker_liveness_states() {
+        let liveness = WorkerLiveness::new(Duration::from_millis(20));
+        assert_eq!(liveness.state(), WorkerState::Idle);
+
+        liveness.set_running();
+        assert_eq!(liveness.state(), WorkerState::Healthy);
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(liveness.state(), WorkerState::Stalled);
+
+        liveness.record_cycle();
+        assert_eq!(liveness.state(), WorkerState::Healthy);
+        assert_eq!(liveness.cycles(), 1);
+        assert_eq!(liveness.overruns(), 0);
+        liveness.record_overrun();
+        assert_eq!(liveness.overruns(), 1);
+
+        liveness.set_panicked();
+        assert_eq!(liveness.state(), WorkerState::Panicked);
+        liveness.set_exited();
+        assert_eq!(liveness.state(), WorkerState::Idle);
+    }
+
+    #[test]
+    fn worker_watchdog_reports_stall_once() {
+        let liveness = WorkerLiveness::new(Duration::from_millis(20));
+        let mut watchdog = WorkerWatchdog::new();
+        liveness.set_running();
+        assert_eq!(watchdog.check(&liveness), None);
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(watchdog.check(&liveness), Some(WorkerFault::Stalled));
+        assert_eq!(watchdog.check(&liveness), None);
+
+        // Stall is reported again after recovery.
+        liveness.record_cycle();
+        assert_eq!(watchdog.check(&liveness), None);
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(watchdog.check(&liveness), Some(WorkerFault::Stalled));
+
+        liveness.set_panicked();
+        assert_eq!(watchdog.check(&liveness), Some(WorkerFault::Panicked));
+    }
+}
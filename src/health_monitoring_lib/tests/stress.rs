@@ -0,0 +1,378 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Soak/stress test with randomized supervision load.
+//!
+//! Creates hundreds of monitors, drives them with randomized report patterns from multiple threads while the
+//! monitoring worker is running and compares the observable outcome against a ground-truth model:
+//! - deadlines always stopped within their range are never reported as failed (no false positives),
+//! - canary deadlines deliberately stopped outside their range are always reported as failed (no false negatives),
+//! - live heap memory does not grow while the load is running (no leaks), counted by the global allocator,
+//! - no evaluation pass overruns the internal processing cycle (bounded worker jitter).
+//!
+//! The test is ignored by default. Run it explicitly, e.g. on target hardware during qualification:
+//!
+//! ```text
+//! HM_STRESS_DURATION_SECS=7200 cargo test -p health_monitoring_lib --features stub_supervisor_api_client \
+//!     --release --test stress -- --ignored --nocapture
+//! ```
+//!
+//! - `HM_STRESS_DURATION_SECS` - test duration, defaults to 10 seconds.
+//! - `HM_STRESS_SEED` - seed of the pseudo-random generator, printed at start to allow reproduction.
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use core::time::Duration;
+use health_monitoring_lib::deadline::{DeadlineError, DeadlineMonitor, DeadlineMonitorBuilder};
+use health_monitoring_lib::heartbeat::{HeartbeatMonitorBuilder, HeartbeatProducer};
+use health_monitoring_lib::{DeadlineTag, HealthMonitorBuilder, InternalFault, MonitorTag, TimeRange};
+use std::alloc::System;
+use std::sync::Arc;
+use std::time::Instant;
+
+const DEADLINE_MONITORS: usize = 100;
+const DEADLINES_PER_MONITOR: usize = 3;
+const HEARTBEAT_MONITORS: usize = 100;
+const DEADLINE_THREADS: usize = 8;
+const HEARTBEAT_THREADS: usize = 4;
+const CANARIES: usize = 50;
+/// Allowed growth of live heap memory between the end of the warm-up and the end of the load.
+const LEAK_TOLERANCE_BYTES: usize = 256 * 1024;
+
+/// System allocator counting live heap bytes of the whole test process.
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: All requests are forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: Contract of `GlobalAlloc::alloc` is upheld by the caller.
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: Contract of `GlobalAlloc::dealloc` is upheld by the caller.
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: Contract of `GlobalAlloc::realloc` is upheld by the caller.
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            LIVE_BYTES.fetch_add(new_size, Ordering::Relaxed);
+            LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Minimal xorshift generator - deterministic for a given seed, no external dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Random value from `<min; max>`.
+    fn range(&mut self, min: u64, max: u64) -> u64 {
+        min + self.next_u64() % (max - min + 1)
+    }
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    std::env::var(name).ok().map(|value| {
+        value
+            .parse()
+            .unwrap_or_else(|_| panic!("{name} must be an unsigned integer"))
+    })
+}
+
+fn tag(prefix: &str, index: usize) -> String {
+    format!("{prefix}_{index}")
+}
+
+/// Drives randomized start/stop patterns well within the deadline range and checks none of them was judged failed.
+fn drive_deadlines(
+    monitors: Vec<Arc<DeadlineMonitor>>,
+    thread_index: usize,
+    seed: u64,
+    stop: Arc<AtomicBool>,
+    activations: Arc<AtomicU64>,
+) {
+    let mut rng = Rng::new(seed);
+    let mut deadlines: Vec<_> = monitors
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| index % DEADLINE_THREADS == thread_index)
+        .flat_map(|(_, monitor)| {
+            (0..DEADLINES_PER_MONITOR).map(|i| {
+                monitor
+                    .get_deadline(DeadlineTag::from(tag("load", i)))
+                    .expect("load deadline not available")
+            })
+        })
+        .collect();
+
+    while !stop.load(Ordering::Relaxed) {
+        let index = rng.range(0, deadlines.len() as u64 - 1) as usize;
+        let deadline = &mut deadlines[index];
+        let handle = match deadline.start() {
+            Ok(handle) => handle,
+            Err(error) => {
+                panic!("false positive: deadline {index} of thread {thread_index} reported {error:?} (seed {seed})")
+            },
+        };
+
+        match rng.range(0, 3) {
+            0 => std::thread::sleep(Duration::from_micros(rng.range(0, 2000))),
+            1 => std::thread::yield_now(),
+            _ => {},
+        }
+        handle.stop();
+        activations.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Provides heartbeats with randomized intervals well within the heartbeat range.
+fn drive_heartbeats(producers: Vec<HeartbeatProducer>, seed: u64, stop: Arc<AtomicBool>, beats: Arc<AtomicU64>) {
+    let mut rng = Rng::new(seed);
+    let start = Instant::now();
+    let mut next_beats: Vec<_> = producers
+        .iter()
+        .map(|_| start + Duration::from_millis(rng.range(400, 600)))
+        .collect();
+
+    while !stop.load(Ordering::Relaxed) {
+        let now = Instant::now();
+        for (producer, next_beat) in producers.iter().zip(next_beats.iter_mut()) {
+            if now >= *next_beat {
+                producer.heartbeat();
+                beats.fetch_add(1, Ordering::Relaxed);
+                *next_beat = now + Duration::from_millis(rng.range(400, 600));
+            }
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+/// Violates canary deadlines at random points in time and checks every violation was detected.
+fn drive_canaries(monitor: DeadlineMonitor, seed: u64, stop: Arc<AtomicBool>, duration: Duration) -> usize {
+    let mut rng = Rng::new(seed);
+    let pause = duration / (2 * CANARIES as u32);
+    let mut checked = 0;
+
+    for index in 0..CANARIES {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let too_early = rng.range(0, 1) == 0;
+        let name = tag(if too_early { "early" } else { "late" }, index);
+        let mut deadline = monitor
+            .get_deadline(DeadlineTag::from(name.clone()))
+            .expect("canary deadline not available");
+
+        let handle = deadline
+            .start()
+            .unwrap_or_else(|_| panic!("false positive: canary {name} failed before violation (seed {seed})"));
+        if !too_early {
+            std::thread::sleep(Duration::from_millis(rng.range(10, 30)));
+        }
+        handle.stop();
+
+        assert_eq!(
+            deadline.start().err(),
+            Some(DeadlineError::DeadlineAlreadyFailed),
+            "false negative: violation of canary {name} not detected (seed {seed})"
+        );
+        checked += 1;
+
+        std::thread::sleep(pause);
+    }
+
+    checked
+}
+
+#[test]
+#[ignore = "long running stress test, run explicitly"]
+fn stress_randomized_supervision_load() {
+    let duration = Duration::from_secs(env_u64("HM_STRESS_DURATION_SECS").unwrap_or(10));
+    let seed = env_u64("HM_STRESS_SEED").unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time before UNIX epoch")
+            .as_nanos() as u64
+    });
+    println!("stress: duration {duration:?}, seed {seed}");
+
+    // Monitors under load, evaluated by the running worker.
+    let load_range = TimeRange::new(Duration::ZERO, Duration::from_secs(10));
+    let heartbeat_range = TimeRange::new(Duration::from_millis(200), Duration::from_millis(2000));
+    let mut builder = HealthMonitorBuilder::new().with_internal_processing_cycle(Duration::from_millis(10));
+    for m in 0..DEADLINE_MONITORS {
+        let deadline_monitor_builder = (0..DEADLINES_PER_MONITOR).fold(DeadlineMonitorBuilder::new(), |b, i| {
+            b.add_deadline(DeadlineTag::from(tag("load", i)), load_range)
+        });
+        builder = builder.add_deadline_monitor(MonitorTag::from(tag("deadline", m)), deadline_monitor_builder);
+    }
+    for m in 0..HEARTBEAT_MONITORS {
+        builder = builder.add_heartbeat_monitor(
+            MonitorTag::from(tag("heartbeat", m)),
+            HeartbeatMonitorBuilder::new(heartbeat_range),
+        );
+    }
+    let mut health_monitor = builder.build().expect("failed to build health monitor");
+
+    let deadline_monitors: Vec<_> = (0..DEADLINE_MONITORS)
+        .map(|m| {
+            let monitor = health_monitor.get_deadline_monitor(MonitorTag::from(tag("deadline", m)));
+            Arc::new(monitor.expect("deadline monitor not available"))
+        })
+        .collect();
+    let mut heartbeat_producers: Vec<Vec<_>> = (0..HEARTBEAT_THREADS).map(|_| Vec::new()).collect();
+    for m in 0..HEARTBEAT_MONITORS {
+        let monitor = health_monitor.get_heartbeat_monitor(MonitorTag::from(tag("heartbeat", m)));
+        let producer = monitor.expect("heartbeat monitor not available").producer();
+        heartbeat_producers[m % HEARTBEAT_THREADS].push(producer.expect("heartbeat producer not available"));
+    }
+
+    // Canaries are kept in a separate health monitor - their violations must not stop evaluation of the load.
+    let canary_monitor_tag = MonitorTag::from("canary");
+    let canary_builder = (0..CANARIES).fold(DeadlineMonitorBuilder::new(), |b, i| {
+        b.add_deadline(
+            DeadlineTag::from(tag("early", i)),
+            TimeRange::new(Duration::from_secs(10), Duration::from_secs(20)),
+        )
+        .add_deadline(
+            DeadlineTag::from(tag("late", i)),
+            TimeRange::new(Duration::ZERO, Duration::from_millis(1)),
+        )
+    });
+    let mut canary_health_monitor = HealthMonitorBuilder::new()
+        .add_deadline_monitor(canary_monitor_tag, canary_builder)
+        .build()
+        .expect("failed to build canary health monitor");
+    let canary_monitor = canary_health_monitor
+        .get_deadline_monitor(canary_monitor_tag)
+        .expect("canary monitor not available");
+
+    health_monitor.start().expect("failed to start health monitor");
+    canary_health_monitor
+        .start()
+        .expect("failed to start canary health monitor");
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let activations = Arc::new(AtomicU64::new(0));
+    let beats = Arc::new(AtomicU64::new(0));
+    let mut rng = Rng::new(seed);
+
+    let deadline_threads: Vec<_> = (0..DEADLINE_THREADS)
+        .map(|thread_index| {
+            let (monitors, stop, activations) = (deadline_monitors.clone(), stop.clone(), activations.clone());
+            let seed = rng.next_u64();
+            std::thread::spawn(move || drive_deadlines(monitors, thread_index, seed, stop, activations))
+        })
+        .collect();
+    let heartbeat_threads: Vec<_> = heartbeat_producers
+        .into_iter()
+        .map(|producers| {
+            let (stop, beats) = (stop.clone(), beats.clone());
+            let seed = rng.next_u64();
+            std::thread::spawn(move || drive_heartbeats(producers, seed, stop, beats))
+        })
+        .collect();
+    let canary_thread = {
+        let stop = stop.clone();
+        let seed = rng.next_u64();
+        std::thread::spawn(move || drive_canaries(canary_monitor, seed, stop, duration))
+    };
+
+    let started = Instant::now();
+    let warm_up = duration / 10;
+    let mut warm_bytes = None;
+    while started.elapsed() < duration {
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(
+            !deadline_threads
+                .iter()
+                .chain(heartbeat_threads.iter())
+                .any(|t| t.is_finished()),
+            "load thread exited prematurely (seed {seed})"
+        );
+        if warm_bytes.is_none() && started.elapsed() >= warm_up {
+            warm_bytes = Some(LIVE_BYTES.load(Ordering::Relaxed));
+        }
+    }
+    let loaded_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+    stop.store(true, Ordering::Relaxed);
+
+    for thread in deadline_threads.into_iter().chain(heartbeat_threads) {
+        thread.join().expect("load thread panicked");
+    }
+    let canaries = canary_thread.join().expect("canary thread panicked");
+
+    println!(
+        "stress: {} deadline activations, {} heartbeats, {} canary violations detected",
+        activations.load(Ordering::Relaxed),
+        beats.load(Ordering::Relaxed),
+        canaries
+    );
+    assert!(activations.load(Ordering::Relaxed) > 0);
+    assert!(canaries > 0);
+
+    // Load is never reported as failed by the monitoring worker, canary violations are.
+    assert_eq!(
+        health_monitor.violation_history(),
+        vec![],
+        "false positive reported by the monitoring worker (seed {seed})"
+    );
+    assert!(
+        !canary_health_monitor.violation_history().is_empty(),
+        "false negative: canary violations not reported by the monitoring worker (seed {seed})"
+    );
+
+    // Evaluation passes exceeding the internal processing cycle are reported as worker overruns.
+    let overruns = health_monitor
+        .internal_faults()
+        .iter()
+        .filter(|record| record.fault == InternalFault::WorkerOverrun)
+        .count();
+    assert_eq!(
+        overruns, 0,
+        "evaluation overran the internal processing cycle (seed {seed})"
+    );
+
+    let warm_bytes = warm_bytes.expect("warm-up did not finish");
+    println!("stress: live heap {warm_bytes} bytes after warm-up, {loaded_bytes} bytes at the end");
+    assert!(
+        loaded_bytes <= warm_bytes + LEAK_TOLERANCE_BYTES,
+        "live heap grew from {warm_bytes} to {loaded_bytes} bytes under load (seed {seed})"
+    );
+}
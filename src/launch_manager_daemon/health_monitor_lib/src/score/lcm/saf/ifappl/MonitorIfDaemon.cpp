@@ -93,6 +93,12 @@ void MonitorIfDaemon::checkForNewData(const timers::NanoSecondType f_syncTimesta
                 break;
             }
 
+            if (unknownCheckpointId.has_value())
+            {
+                handleUnknownCheckpoint(*unknownCheckpointId);
+                break;
+            }
+
             if (isDeactivateRequest)
             {
                 // Process got deactivated in the last cycle
@@ -113,6 +119,25 @@ void MonitorIfDaemon::checkForNewData(const timers::NanoSecondType f_syncTimesta
             }
             break;
         }
+        case EInternalState::kInactiveUnknownCheckpoint:
+        {
+            if (isProcessRestarted)
+            {
+                // Restarted process is supervised again, checkpoints of the failed process are discarded.
+                isProcessRestarted = false;
+                unknownCheckpointId.reset();
+                for (auto& observer : checkpointObservers)
+                {
+                    observer->setDataLossEvent(false);
+                }
+                status = EInternalState::kActive;
+            }
+            else
+            {
+                discardNewData(f_syncTimestamp);
+            }
+            break;
+        }
         case EInternalState::kInactive:
         {
             // Nothing to do here, process is not running
@@ -132,15 +157,36 @@ void MonitorIfDaemon::handleOverflow()
     status = EInternalState::kInactiveOverflow;
 }
 
-void MonitorIfDaemon::pushCheckpointToObservers(CheckpointBufferElement& f_elem_r)
+void MonitorIfDaemon::handleUnknownCheckpoint(const uint32_t f_checkpointId)
 {
+    logger_r.LogError() << "MonitorInterface: Checkpoint without configured supervision received, supervision fails."
+                        << "Instance:" << k_interfaceName << "Checkpoint:" << f_checkpointId;
+    pushOverflowInfoToCheckpointObservers();
+    status = EInternalState::kInactiveUnknownCheckpoint;
+}
+
+void MonitorIfDaemon::discardNewData(const timers::NanoSecondType f_syncTimestamp)
+{
+    CheckpointBufferElement* elem_p{nullptr};
+    while ((ipcserver_r.peek(elem_p) == CheckpointIpcServer::EIpcPeekResult::kOk) &&
+           (elem_p->timestamp <= f_syncTimestamp) && ipcserver_r.pop())
+    {
+        elem_p = nullptr;
+    }
+}
+
+bool MonitorIfDaemon::pushCheckpointToObservers(CheckpointBufferElement& f_elem_r)
+{
+    bool isObserved{false};
     for (auto& observer : checkpointObservers)
     {
         if (f_elem_r.checkpointId == observer->getId())
         {
             observer->pushData(f_elem_r.timestamp);
+            isObserved = true;
         }
     }
+    return isObserved;
 }
 
 bool MonitorIfDaemon::pushNewDataToCheckpointObservers(const timers::NanoSecondType f_syncTimestamp)
@@ -159,7 +205,11 @@ bool MonitorIfDaemon::pushNewDataToCheckpointObservers(const timers::NanoSecondT
         if ((result == IpcResult::kOk) && (elem_p->timestamp <= f_syncTimestamp))
         {
             // Checkpoint belongs to this cycle, push it to observers
-            pushCheckpointToObservers(*elem_p);
+            if (!pushCheckpointToObservers(*elem_p))
+            {
+                // Reading is continued, data loss event is pushed once the cycle is read.
+                unknownCheckpointId = elem_p->checkpointId;
+            }
             ++amountOfReceivedCheckpoints;
             elem_p = nullptr;
             if (ipcserver_r.pop())
@@ -14,6 +14,7 @@
 #ifndef MonitorIfDaemon_HPP_INCLUDED
 #define MonitorIfDaemon_HPP_INCLUDED
 
+#include <optional>
 #include <string>
 #include <vector>
 #include "score/lcm/saf/ifappl/Checkpoint.hpp"
@@ -95,7 +96,10 @@ public:
     void updateData(const ifexm::ProcessState& f_observable_r) noexcept(true) override;
 
     /// @brief Check for new data
-    /// @details Check Monitor interface for new data from application side
+    /// @details Check Monitor interface for new data from application side.
+    /// A checkpoint without configured observer, e.g. reported by a process terminating after a failure,
+    /// is pushed to all observers as data loss event, so the supervision of the process fails immediately.
+    /// Checkpoints are discarded until the process is restarted.
     /// @param [in]  f_syncTimestamp    Timestamp till data shall be read, newer data will not be considered
     void checkForNewData(const score::lcm::saf::timers::NanoSecondType f_syncTimestamp) noexcept(true);
 
@@ -116,6 +120,14 @@ private:
     /// @brief Move to kInactiveOverflow state and push overflow event to observers
     void handleOverflow(void);
 
+    /// @brief Move to kInactiveUnknownCheckpoint state and push data loss event to observers
+    /// @param [in]  f_checkpointId     ID of the checkpoint without configured observer
+    void handleUnknownCheckpoint(const uint32_t f_checkpointId);
+
+    /// @brief Discard data of the IPC channel, which is not pushed to observers
+    /// @param [in]  f_syncTimestamp        Timestamp till data shall be read, newer data will not be considered
+    void discardNewData(const score::lcm::saf::timers::NanoSecondType f_syncTimestamp);
+
     /// @brief Push new data to checkpoint observer
     /// @details The checkpoint ring buffer data is pushed to checkpoint specific objects.
     /// @param [in]  f_syncTimestamp        Timestamp till data shall be read, newer data will not be considered
@@ -124,14 +136,16 @@ private:
 
     /// @brief Push a single checkpoint to observers
     /// @param[in] f_elem_r The checkpoint to push to observers
-    void pushCheckpointToObservers(CheckpointBufferElement& f_elem_r);
+    /// @returns True if an observer is configured for the checkpoint, else false
+    bool pushCheckpointToObservers(CheckpointBufferElement& f_elem_r);
 
     /// Internal states for instances of this class
     enum class EInternalState : std::uint8_t
     {
         kActive,           ///< Interface active
         kInactive,         ///< Interface inactive
-        kInactiveOverflow  ///< Interface inactive due to overflow
+        kInactiveOverflow,          ///< Interface inactive due to overflow
+        kInactiveUnknownCheckpoint  ///< Interface inactive due to checkpoint without observer, until restart
     };
 
     /// Current internal state
@@ -146,6 +160,9 @@ private:
     /// @brief Process restart status
     bool isProcessRestarted{false};
 
+    /// @brief ID of the last received checkpoint without configured observer, if any
+    std::optional<uint32_t> unknownCheckpointId{};
+
     /// Interface name
     const std::string k_interfaceName;
 
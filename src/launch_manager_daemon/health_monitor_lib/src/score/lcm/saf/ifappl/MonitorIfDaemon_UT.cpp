@@ -0,0 +1,124 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+#include <gtest/gtest.h>
+#include <unistd.h>
+
+#include <cstdint>
+#include <string>
+
+#include "score/lcm/saf/ifappl/Checkpoint.hpp"
+#include "score/lcm/saf/ifappl/DataStructures.hpp"
+#include "score/lcm/saf/ifappl/MonitorIfDaemon.hpp"
+#include "score/lcm/saf/ifexm/ProcessCfg.hpp"
+#include "score/lcm/saf/ifexm/ProcessState.hpp"
+#include "score/lcm/saf/ipc/IpcClient.hpp"
+
+using namespace testing;
+
+using score::lcm::saf::ifappl::Checkpoint;
+using score::lcm::saf::ifappl::CheckpointBufferElement;
+using score::lcm::saf::ifappl::CheckpointIpcServer;
+using score::lcm::saf::ifappl::k_maxCheckpointBufferElements;
+using score::lcm::saf::ifappl::MonitorIfDaemon;
+using score::lcm::saf::ifexm::ProcessCfg;
+using score::lcm::saf::ifexm::ProcessState;
+using score::lcm::saf::timers::NanoSecondType;
+
+using CheckpointIpcClient = score::lcm::saf::ipc::IpcClient<CheckpointBufferElement, k_maxCheckpointBufferElements>;
+
+/// Checkpoint ID reported by alive notifications of the health monitoring library.
+constexpr uint32_t kAliveCheckpointId{1U};
+/// Checkpoint ID reported by the health monitoring library when the process is terminating after a failure.
+constexpr uint32_t kTerminatingCheckpointId{2U};
+
+class MonitorIfDaemonTest : public ::testing::Test
+{
+  protected:
+    void SetUp() override
+    {
+        RecordProperty("TestType", "interface-test");
+        RecordProperty("DerivationTechnique", "explorative-testing ");
+
+        const std::string ipcName{"MonitorIfDaemon_UT_" + std::to_string(getpid())};
+        ASSERT_EQ(server.init(ipcName), CheckpointIpcServer::EIpcInitResult::kOk);
+        ASSERT_EQ(client.init(ipcName), CheckpointIpcClient::EIpcInitResult::kOk);
+        monitorIf.attachCheckpoint(alive);
+        setProcessState(ProcessState::EProcState::running);
+    }
+
+    void setProcessState(const ProcessState::EProcState f_state)
+    {
+        processState.setState(f_state);
+        monitorIf.updateData(processState);
+    }
+
+    void report(const NanoSecondType f_timestamp, const uint32_t f_checkpointId)
+    {
+        ASSERT_TRUE(client.sendEmplace(NanoSecondType{f_timestamp}, uint32_t{f_checkpointId}));
+    }
+
+    CheckpointIpcServer server{};
+    CheckpointIpcClient client{};
+    ProcessCfg processCfg{};
+    ProcessState processState{processCfg};
+    Checkpoint alive{"alive", kAliveCheckpointId, &processState};
+    MonitorIfDaemon monitorIf{server, "MonitorIfDaemon_UT"};
+};
+
+TEST_F(MonitorIfDaemonTest, ConfiguredCheckpoint_dispatched)
+{
+    RecordProperty("Description",
+                   "This test verifies that a checkpoint with configured supervision is pushed to its observer.");
+    report(100U, kAliveCheckpointId);
+    monitorIf.checkForNewData(200U);
+    EXPECT_EQ(alive.getTimestamp(), 100U);
+    EXPECT_FALSE(alive.getDataLossEvent());
+}
+
+TEST_F(MonitorIfDaemonTest, UnconfiguredCheckpoint_failsSupervision)
+{
+    RecordProperty("Description",
+                   "This test verifies that a checkpoint without configured supervision, e.g. the termination "
+                   "checkpoint of the health monitoring library, fails the supervision of the process and that "
+                   "following checkpoints are discarded.");
+    report(100U, kAliveCheckpointId);
+    report(150U, kTerminatingCheckpointId);
+    monitorIf.checkForNewData(200U);
+    EXPECT_TRUE(alive.getDataLossEvent());
+    EXPECT_EQ(alive.getTimestamp(), 0U);
+
+    report(250U, kAliveCheckpointId);
+    monitorIf.checkForNewData(300U);
+    EXPECT_TRUE(alive.getDataLossEvent());
+    EXPECT_EQ(alive.getTimestamp(), 0U);
+}
+
+TEST_F(MonitorIfDaemonTest, UnconfiguredCheckpoint_supervisedAgainAfterRestart)
+{
+    RecordProperty("Description",
+                   "This test verifies that a process failed by a checkpoint without configured supervision "
+                   "is supervised again once it is restarted.");
+    report(100U, kTerminatingCheckpointId);
+    monitorIf.checkForNewData(200U);
+    EXPECT_TRUE(alive.getDataLossEvent());
+
+    setProcessState(ProcessState::EProcState::off);
+    monitorIf.checkForNewData(300U);
+    setProcessState(ProcessState::EProcState::starting);
+    monitorIf.checkForNewData(400U);
+
+    report(500U, kAliveCheckpointId);
+    monitorIf.checkForNewData(600U);
+    EXPECT_FALSE(alive.getDataLossEvent());
+    EXPECT_EQ(alive.getTimestamp(), 500U);
+}
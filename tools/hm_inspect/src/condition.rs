@@ -0,0 +1,187 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Conditions evaluated on a snapshot.
+
+use crate::json::Value;
+use clap::ValueEnum;
+
+/// Snapshot condition which makes `hm-inspect` exit with a failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum FailOn {
+    /// Any monitor has a violation in its recent violation history.
+    Violation,
+    /// Any monitor is currently failing.
+    Failing,
+    /// Health status is degraded.
+    Degraded,
+    /// Health monitor is not running.
+    Stopped,
+}
+
+/// Summary of a snapshot, see `HealthMonitorSnapshot::to_json` for the document layout.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub status: String,
+    pub monitors: usize,
+    /// Tags of currently failing monitors.
+    pub failing: Vec<String>,
+    /// Tags of monitors with recent violations, with the number of violations.
+    pub violations: Vec<(String, usize)>,
+}
+
+impl Summary {
+    /// Summarize a parsed snapshot document.
+    pub fn from_snapshot(snapshot: &Value) -> Result<Self, &'static str> {
+        let status = snapshot
+            .get("status")
+            .and_then(Value::as_str)
+            .ok_or("missing health status")?
+            .to_string();
+        let monitors = snapshot
+            .get("monitors")
+            .and_then(Value::as_array)
+            .ok_or("missing monitors")?;
+
+        let mut failing = Vec::new();
+        let mut violations = Vec::new();
+        for monitor in monitors {
+            let tag = monitor
+                .get("tag")
+                .and_then(Value::as_str)
+                .ok_or("missing monitor tag")?;
+            if monitor
+                .get("failing")
+                .and_then(Value::as_bool)
+                .ok_or("missing monitor state")?
+            {
+                failing.push(tag.to_string());
+            }
+            let num_violations = monitor
+                .get("recent_violations")
+                .and_then(Value::as_array)
+                .ok_or("missing monitor violations")?
+                .len();
+            if num_violations > 0 {
+                violations.push((tag.to_string(), num_violations));
+            }
+        }
+
+        Ok(Self {
+            status,
+            monitors: monitors.len(),
+            failing,
+            violations,
+        })
+    }
+
+    /// Whether the snapshot meets the condition.
+    pub fn meets(&self, condition: FailOn) -> bool {
+        match condition {
+            FailOn::Violation => !self.violations.is_empty(),
+            FailOn::Failing => !self.failing.is_empty(),
+            FailOn::Degraded => self.status == "degraded",
+            FailOn::Stopped => self.status == "stopped",
+        }
+    }
+
+    /// First of `conditions` met by the snapshot.
+    pub fn first_met(&self, conditions: &[FailOn]) -> Option<FailOn> {
+        conditions.iter().copied().find(|condition| self.meets(*condition))
+    }
+}
+
+impl core::fmt::Display for Summary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "status={} monitors={} failing=[{}] violations=[",
+            self.status,
+            self.monitors,
+            self.failing.join(",")
+        )?;
+        for (index, (tag, count)) in self.violations.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}:{}", tag, count)?;
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::condition::{FailOn, Summary};
+    use crate::json::parse;
+
+    const DEGRADED_SNAPSHOT: &str = "{\"status\":\"degraded\",\"config_digest\":42,\"monitors\":[\
+        {\"tag\":\"mon1\",\"type\":\"deadline\",\"ownership\":\"taken\",\"enabled\":true,\"failing\":true,\
+        \"deadlines\":[{\"tag\":\"deadline1\",\"min_ms\":50,\"max_ms\":150}],\
+        \"recent_violations\":[{\"error\":\"TooLate\",\"timestamp_ms\":1500},{\"error\":\"TooLate\",\"timestamp_ms\":1600}]},\
+        {\"tag\":\"hb1\",\"type\":\"heartbeat\",\"ownership\":\"taken\",\"enabled\":true,\"failing\":false,\
+        \"range\":{\"min_ms\":30,\"max_ms\":null},\"recent_violations\":[{\"error\":\"TooEarly\",\"timestamp_ms\":700}]}]}";
+
+    const HEALTHY_SNAPSHOT: &str = "{\"status\":\"healthy\",\"config_digest\":42,\"monitors\":[\
+        {\"tag\":\"hb1\",\"type\":\"heartbeat\",\"ownership\":\"taken\",\"enabled\":true,\"failing\":false,\
+        \"range\":{\"min_ms\":30,\"max_ms\":200},\"recent_violations\":[]}]}";
+
+    #[test]
+    fn summary_degraded() {
+        let summary = Summary::from_snapshot(&parse(DEGRADED_SNAPSHOT).unwrap()).unwrap();
+        assert_eq!(
+            summary,
+            Summary {
+                status: "degraded".to_string(),
+                monitors: 2,
+                failing: vec!["mon1".to_string()],
+                violations: vec![("mon1".to_string(), 2), ("hb1".to_string(), 1)],
+            }
+        );
+        assert_eq!(
+            summary.to_string(),
+            "status=degraded monitors=2 failing=[mon1] violations=[mon1:2,hb1:1]"
+        );
+        assert!(summary.meets(FailOn::Violation));
+        assert!(summary.meets(FailOn::Failing));
+        assert!(summary.meets(FailOn::Degraded));
+        assert!(!summary.meets(FailOn::Stopped));
+        assert_eq!(
+            summary.first_met(&[FailOn::Stopped, FailOn::Failing]),
+            Some(FailOn::Failing)
+        );
+    }
+
+    #[test]
+    fn summary_healthy() {
+        let summary = Summary::from_snapshot(&parse(HEALTHY_SNAPSHOT).unwrap()).unwrap();
+        assert_eq!(
+            summary.to_string(),
+            "status=healthy monitors=1 failing=[] violations=[]"
+        );
+        assert_eq!(
+            summary.first_met(&[FailOn::Violation, FailOn::Failing, FailOn::Degraded, FailOn::Stopped]),
+            None
+        );
+    }
+
+    #[test]
+    fn summary_not_a_snapshot() {
+        assert!(Summary::from_snapshot(&parse("{\"monitors\":[]}").unwrap()).is_err());
+        assert!(Summary::from_snapshot(&parse("{\"status\":\"healthy\"}").unwrap()).is_err());
+        assert!(
+            Summary::from_snapshot(&parse("{\"status\":\"healthy\",\"monitors\":[{\"tag\":\"hb1\"}]}").unwrap())
+                .is_err()
+        );
+    }
+}
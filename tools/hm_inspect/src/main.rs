@@ -0,0 +1,161 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! `hm-inspect` - inspect the health monitor snapshot of a running process.
+//!
+//! Reads the snapshot file written by the supervised process, see `HealthMonitorSnapshot::to_json`
+//! and the `--snapshot-file` option of `rust_supervised_app`. The file must be replaced atomically.
+//!
+//! Without `--watch` the snapshot is read once. With `--watch` the file is polled until a `--fail-on`
+//! condition is met or `--timeout-ms` elapses:
+//!
+//! ```text
+//! hm-inspect /tmp/app.snapshot.json --watch --fail-on violation,stopped --timeout-ms 10000
+//! ```
+//!
+//! Exit codes:
+//! - `0` - no condition met.
+//! - `1` - a `--fail-on` condition is met.
+//! - `2` - snapshot cannot be read or invalid arguments.
+
+mod condition;
+mod json;
+
+use clap::Parser;
+use condition::{FailOn, Summary};
+use core::time::Duration;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+/// Exit code if a `--fail-on` condition is met.
+const EXIT_CONDITION_MET: u8 = 1;
+/// Exit code if the snapshot cannot be read, same as for invalid arguments.
+const EXIT_ERROR: u8 = 2;
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Snapshot file written by the supervised process.
+    snapshot: PathBuf,
+
+    /// Poll the snapshot until a `--fail-on` condition is met or `--timeout-ms` elapses.
+    /// A snapshot file which does not exist yet is waited for.
+    #[arg(long)]
+    watch: bool,
+
+    /// Polling interval in watch mode.
+    #[arg(long, default_value_t = 200)]
+    interval_ms: u64,
+
+    /// Stop watching after this time and exit successfully. Watch forever if not set.
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+
+    /// Conditions which make the tool exit with code 1, comma-separated or repeated.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    fail_on: Vec<FailOn>,
+}
+
+#[derive(Debug)]
+enum ReadError {
+    NotFound,
+    Io(std::io::Error),
+    Invalid(String),
+}
+
+impl core::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReadError::NotFound => f.write_str("snapshot file not found"),
+            ReadError::Io(error) => write!(f, "cannot read snapshot file: {}", error),
+            ReadError::Invalid(reason) => write!(f, "invalid snapshot: {}", reason),
+        }
+    }
+}
+
+fn read_summary(path: &Path) -> Result<Summary, ReadError> {
+    let content = std::fs::read_to_string(path).map_err(|error| match error.kind() {
+        ErrorKind::NotFound => ReadError::NotFound,
+        _ => ReadError::Io(error),
+    })?;
+    let snapshot = json::parse(&content).map_err(|error| ReadError::Invalid(error.to_string()))?;
+    Summary::from_snapshot(&snapshot).map_err(|reason| ReadError::Invalid(reason.to_string()))
+}
+
+/// Report the condition met by `summary`, if any.
+fn check(summary: &Summary, conditions: &[FailOn]) -> Option<ExitCode> {
+    let condition = summary.first_met(conditions)?;
+    eprintln!("hm-inspect: condition '{:?}' met: {}", condition, summary);
+    Some(ExitCode::from(EXIT_CONDITION_MET))
+}
+
+fn inspect_once(args: &Args) -> ExitCode {
+    match read_summary(&args.snapshot) {
+        Ok(summary) => {
+            println!("{}", summary);
+            check(&summary, &args.fail_on).unwrap_or(ExitCode::SUCCESS)
+        },
+        Err(error) => {
+            eprintln!("hm-inspect: {}", error);
+            ExitCode::from(EXIT_ERROR)
+        },
+    }
+}
+
+fn watch(args: &Args) -> ExitCode {
+    let started = Instant::now();
+    let timeout = args.timeout_ms.map(Duration::from_millis);
+    let mut last_printed = None;
+
+    loop {
+        match read_summary(&args.snapshot) {
+            Ok(summary) => {
+                // Print only changes, so the output stays readable with short intervals.
+                let line = summary.to_string();
+                if last_printed.as_ref() != Some(&line) {
+                    println!("{}", line);
+                    last_printed = Some(line);
+                }
+                if let Some(exit_code) = check(&summary, &args.fail_on) {
+                    return exit_code;
+                }
+            },
+            // The process has not written its first snapshot yet.
+            Err(ReadError::NotFound) if last_printed.is_none() => {},
+            Err(error) => {
+                eprintln!("hm-inspect: {}", error);
+                return ExitCode::from(EXIT_ERROR);
+            },
+        }
+
+        if timeout.is_some_and(|timeout| started.elapsed() >= timeout) {
+            if last_printed.is_none() {
+                eprintln!("hm-inspect: {}", ReadError::NotFound);
+                return ExitCode::from(EXIT_ERROR);
+            }
+            return ExitCode::SUCCESS;
+        }
+        std::thread::sleep(Duration::from_millis(args.interval_ms));
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if args.watch {
+        watch(&args)
+    } else {
+        inspect_once(&args)
+    }
+}